@@ -7,11 +7,15 @@ pub use cached::Cached;
 #[cfg(test)]
 pub async fn clear_all_caches() {
     STANDINGS_CACHE.lock().await.cache_clear();
+    SEASON_STANDINGS_CACHE.lock().await.cache_clear();
     SCHEDULE_CACHE.lock().await.cache_clear();
     GAME_CACHE.lock().await.cache_clear();
     BOXSCORE_CACHE.lock().await.cache_clear();
     CLUB_STATS_CACHE.lock().await.cache_clear();
     PLAYER_INFO_CACHE.lock().await.cache_clear();
+    PLAYER_GAME_LOG_CACHE.lock().await.cache_clear();
+    ROSTER_CACHE.lock().await.cache_clear();
+    TEAM_SCHEDULE_CACHE.lock().await.cache_clear();
 }
 
 #[cfg(test)]
@@ -51,6 +55,20 @@ pub async fn fetch_standings_cached(
     client.current_league_standings().await
 }
 
+#[cached(
+    name = "SEASON_STANDINGS_CACHE",
+    type = "cached::TimedSizedCache<i64, Vec<Standing>>",
+    create = "{ cached::TimedSizedCache::with_size_and_lifespan(10, 3600) }",
+    convert = r#"{ season }"#,
+    result = true
+)]
+pub async fn fetch_season_standings_cached(
+    client: &dyn NHLDataProvider,
+    season: i64,
+) -> Result<Vec<Standing>, NHLApiError> {
+    client.league_standings_for_season(season).await
+}
+
 #[cached(
     name = "SCHEDULE_CACHE",
     type = "cached::TimedSizedCache<String, DailySchedule>",
@@ -110,6 +128,34 @@ pub async fn fetch_club_stats_cached(
         .await
 }
 
+#[cached(
+    name = "ROSTER_CACHE",
+    type = "cached::TimedSizedCache<String, nhl_api::Roster>",
+    create = "{ cached::TimedSizedCache::with_size_and_lifespan(32, 3600) }",
+    convert = r#"{ team_abbrev.to_string() }"#,
+    result = true
+)]
+pub async fn fetch_roster_cached(
+    client: &dyn NHLDataProvider,
+    team_abbrev: &str,
+) -> Result<nhl_api::Roster, NHLApiError> {
+    client.roster_current(team_abbrev).await
+}
+
+#[cached(
+    name = "TEAM_SCHEDULE_CACHE",
+    type = "cached::TimedSizedCache<String, nhl_api::TeamScheduleResponse>",
+    create = "{ cached::TimedSizedCache::with_size_and_lifespan(32, 60) }",
+    convert = r#"{ team_abbrev.to_string() }"#,
+    result = true
+)]
+pub async fn fetch_team_schedule_cached(
+    client: &dyn NHLDataProvider,
+    team_abbrev: &str,
+) -> Result<nhl_api::TeamScheduleResponse, NHLApiError> {
+    client.team_weekly_schedule(team_abbrev, None).await
+}
+
 #[cached(
     name = "PLAYER_INFO_CACHE",
     type = "cached::TimedSizedCache<i64, nhl_api::PlayerLanding>",
@@ -124,6 +170,23 @@ pub async fn fetch_player_landing_cached(
     client.player_landing(player_id).await
 }
 
+#[cached(
+    name = "PLAYER_GAME_LOG_CACHE",
+    type = "cached::TimedSizedCache<String, nhl_api::PlayerGameLog>",
+    create = "{ cached::TimedSizedCache::with_size_and_lifespan(100, 3600) }",
+    convert = r#"{ format!("{}:{}", player_id, season) }"#,
+    result = true
+)]
+pub async fn fetch_player_game_log_cached(
+    client: &dyn NHLDataProvider,
+    player_id: i64,
+    season: i32,
+) -> Result<nhl_api::PlayerGameLog, NHLApiError> {
+    client
+        .player_game_log(player_id, season, nhl_api::GameType::RegularSeason)
+        .await
+}
+
 pub async fn refresh_standings(client: &dyn NHLDataProvider) -> Result<Vec<Standing>, NHLApiError> {
     STANDINGS_CACHE.lock().await.cache_clear();
     fetch_standings_cached(client).await
@@ -1,11 +1,12 @@
 use crate::commands::parse_game_date;
+use crate::config::Config;
 use crate::data_provider::NHLDataProvider;
 use crate::layout_constants::{SCHEDULE_BOX_CONTENT_WIDTH, SCHEDULE_BOX_TOTAL_WIDTH};
+use crate::timezone::format_start_time;
 use anyhow::{Context, Result};
-use chrono::{DateTime, Local};
 use nhl_api::DailySchedule;
 
-pub fn format_schedule(schedule: &DailySchedule) -> String {
+pub fn format_schedule(schedule: &DailySchedule, config: &Config) -> String {
     let mut output = String::new();
 
     // Display schedule header
@@ -43,13 +44,12 @@ pub fn format_schedule(schedule: &DailySchedule) -> String {
                 width = SCHEDULE_BOX_CONTENT_WIDTH
             ));
 
-            let time_display =
-                if let Ok(parsed) = DateTime::parse_from_rfc3339(&game.start_time_utc) {
-                    let local_time: DateTime<Local> = parsed.into();
-                    local_time.format("%I:%M %p").to_string()
-                } else {
-                    game.start_time_utc.clone()
-                };
+            let time_display = format_start_time(
+                &game.start_time_utc,
+                &config.display.timezone,
+                &config.start_time_format,
+            )
+            .unwrap_or_else(|| game.start_time_utc.clone());
             let time_line = format!("Time: {}", time_display);
             output.push_str(&format!(
                 "│ {:<width$} │\n",
@@ -85,14 +85,18 @@ pub fn format_schedule(schedule: &DailySchedule) -> String {
     output
 }
 
-pub async fn run(client: &dyn NHLDataProvider, date: Option<String>) -> Result<()> {
+pub async fn run(
+    client: &dyn NHLDataProvider,
+    date: Option<String>,
+    config: &Config,
+) -> Result<()> {
     let game_date = parse_game_date(date)?;
     let schedule = client
         .daily_schedule(Some(game_date))
         .await
         .context("Failed to fetch schedule")?;
 
-    print!("{}", format_schedule(&schedule));
+    print!("{}", format_schedule(&schedule, config));
     display_navigation(&schedule);
     Ok(())
 }
@@ -142,9 +146,17 @@ mod tests {
         }
     }
 
-    #[test]
-    fn test_game_box_output() {
-        let schedule = DailySchedule {
+    fn config_in(timezone: &str, start_time_format: &str) -> Config {
+        let mut config = Config {
+            start_time_format: start_time_format.to_string(),
+            ..Config::default()
+        };
+        config.display.timezone = timezone.to_string();
+        config
+    }
+
+    fn live_schedule() -> DailySchedule {
+        DailySchedule {
             date: "2024-11-03".to_string(),
             number_of_games: 1,
             previous_start_date: None,
@@ -157,9 +169,14 @@ mod tests {
                 Some(0),
                 Some(0),
             )],
-        };
+        }
+    }
+
+    #[test]
+    fn test_game_box_output() {
+        let schedule = live_schedule();
 
-        let output = format_schedule(&schedule);
+        let output = format_schedule(&schedule, &config_in("America/Vancouver", "%I:%M %p"));
         let lines: Vec<&str> = output.lines().skip(4).take(8).collect();
         assert_eq!(lines.len(), 8, "Should be 8 lines of output");
         assert_eq!(
@@ -178,17 +195,9 @@ mod tests {
             lines[3], "│ Status: LIVE                                                 │",
             "Status line"
         );
-        // Time varies by timezone, so just check the format
-        assert!(
-            lines[4].starts_with("│ Time: ") && lines[4].ends_with(" │"),
-            "Time line should have correct format, got: {}",
-            lines[4]
-        );
-        // Verify it contains a time pattern like "HH:MM AM/PM"
-        assert!(
-            lines[4].contains(":00 AM") || lines[4].contains(":00 PM"),
-            "Time line should contain a time, got: {}",
-            lines[4]
+        assert_eq!(
+            lines[4], "│ Time: 07:00 PM                                               │",
+            "Time line"
         );
         assert_eq!(
             lines[5], "├──────────────────────────────────────────────────────────────┤",
@@ -203,4 +212,26 @@ mod tests {
             "Bottom border line"
         );
     }
+
+    #[test]
+    fn test_start_time_uses_timezone_and_start_time_format() {
+        let output = format_schedule(&live_schedule(), &config_in("America/Toronto", "%H:%M"));
+        let time_line = output.lines().nth(8).unwrap();
+        assert_eq!(
+            time_line,
+            "│ Time: 22:00                                                  │"
+        );
+    }
+
+    #[test]
+    fn test_default_start_time_has_no_seconds() {
+        let mut config = Config::default();
+        config.display.timezone = "America/Vancouver".to_string();
+        let output = format_schedule(&live_schedule(), &config);
+        let time_line = output.lines().nth(8).unwrap();
+        assert_eq!(
+            time_line,
+            "│ Time: 7:00 PM                                                │"
+        );
+    }
 }
@@ -2,6 +2,7 @@ use crate::commands::parse_game_date;
 use crate::config::{Config, DisplayConfig};
 use crate::data_provider::NHLDataProvider;
 use crate::formatting::format_header;
+use crate::team_abbrev::{abbrev_to_conference, abbrev_to_division};
 use anyhow::{Context, Result};
 use nhl_api::Standing;
 use std::collections::BTreeMap;
@@ -238,6 +239,17 @@ fn format_division_view(
     output
 }
 
+/// Ordering key for conference groups: the preferred conference first, the
+/// other one second, and anything unexpected (e.g. "Unknown") after them.
+fn conference_sort_key(conference: &str, western_first: bool) -> (u8, &str) {
+    let rank = match (conference, western_first) {
+        ("Western", true) | ("Eastern", false) => 0,
+        ("Eastern", true) | ("Western", false) => 1,
+        _ => 2,
+    };
+    (rank, conference)
+}
+
 /// Formats standings in conference view with two-column layout
 fn format_conference_view(
     sorted_standings: Vec<Standing>,
@@ -253,15 +265,10 @@ fn format_conference_view(
         grouped.entry(conference).or_default().push(standing);
     }
 
-    // Convert to vec to split into columns
     let mut groups: Vec<_> = grouped.into_iter().collect();
-
-    // If western_first is true, reverse to show Western first
-    if western_first && groups.len() == 2 {
-        // BTreeMap gives us Eastern, Western alphabetically
-        // Reverse to get Western, Eastern
-        groups.reverse();
-    }
+    groups.sort_by(|(a, _), (b, _)| {
+        conference_sort_key(a, western_first).cmp(&conference_sort_key(b, western_first))
+    });
 
     let mut output = String::new();
     output.push('\n');
@@ -416,9 +423,26 @@ fn format_wildcard_view(
     output
 }
 
+/// Fill in division and conference names missing from the API response
+///
+/// Uses the league alignment of `season` so older seasons group teams the way
+/// they were aligned at the time instead of today's divisions.
+fn fill_missing_alignment(standing: &mut Standing, season: Option<i64>) {
+    let abbrev = standing.team_abbrev.default.as_str();
+    if standing.division_name.is_empty() {
+        if let Some(division) = abbrev_to_division(abbrev, season) {
+            standing.division_name = division.to_string();
+        }
+    }
+    if standing.conference_name.is_none() {
+        standing.conference_name = abbrev_to_conference(abbrev, season).map(str::to_string);
+    }
+}
+
 pub fn format_standings_by_group(
     standings: &[Standing],
     by: GroupBy,
+    season: Option<i64>,
     western_first: bool,
     display: &DisplayConfig,
 ) -> String {
@@ -427,6 +451,9 @@ pub fn format_standings_by_group(
     }
 
     let mut sorted_standings = standings.to_vec();
+    for standing in &mut sorted_standings {
+        fill_missing_alignment(standing, season);
+    }
     sorted_standings.sort_by(|a, b| b.points.cmp(&a.points));
 
     match by {
@@ -465,8 +492,13 @@ pub async fn run(
             .context("Failed to fetch current standings")?
     };
 
-    // Use the shared formatting function (CLI always uses default order)
-    let output = format_standings_by_group(&standings, by, false, &config.display);
+    let output = format_standings_by_group(
+        &standings,
+        by,
+        season,
+        config.display_standings_western_first,
+        &config.display,
+    );
     print!("{}", output);
 
     Ok(())
@@ -475,6 +507,7 @@ pub async fn run(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::tui::testing::create_test_standings;
 
     #[test]
     fn test_groupby_name() {
@@ -555,10 +588,94 @@ mod tests {
     fn test_format_standings_by_group_empty() {
         let display = DisplayConfig::default();
         let standings = vec![];
-        let output = format_standings_by_group(&standings, GroupBy::Division, false, &display);
+        let output =
+            format_standings_by_group(&standings, GroupBy::Division, None, false, &display);
         assert_eq!(output, "Loading standings...");
     }
 
+    #[test]
+    fn test_conference_sort_key_follows_western_first() {
+        let mut groups = vec!["Unknown", "Western", "Eastern"];
+        groups.sort_by_key(|c| conference_sort_key(c, true));
+        assert_eq!(groups, vec!["Western", "Eastern", "Unknown"]);
+
+        groups.sort_by_key(|c| conference_sort_key(c, false));
+        assert_eq!(groups, vec!["Eastern", "Western", "Unknown"]);
+    }
+
+    #[test]
+    fn test_conference_view_renders_western_first() {
+        let display = DisplayConfig::default();
+        let standings = create_test_standings();
+
+        let output =
+            format_standings_by_group(&standings, GroupBy::Conference, None, true, &display);
+        let western = output.find("Western").unwrap();
+        let eastern = output.find("Eastern").unwrap();
+        assert!(western < eastern);
+
+        let output =
+            format_standings_by_group(&standings, GroupBy::Conference, None, false, &display);
+        let western = output.find("Western").unwrap();
+        let eastern = output.find("Eastern").unwrap();
+        assert!(eastern < western);
+    }
+
+    #[test]
+    fn test_conference_view_fallback_respects_western_first() {
+        let display = DisplayConfig::default();
+        let mut standings = create_test_standings();
+        standings[0].conference_name = None;
+        standings[0].team_abbrev.default = "XYZ".to_string();
+
+        let output =
+            format_standings_by_group(&standings, GroupBy::Conference, None, true, &display);
+        let western = output.find("Western").unwrap();
+        let eastern = output.find("Eastern").unwrap();
+        let unknown = output.find("Unknown").unwrap();
+        assert!(western < eastern && eastern < unknown);
+    }
+
+    #[test]
+    fn test_missing_alignment_uses_current_divisions() {
+        let mut standing = create_test_standings()[0].clone();
+        standing.team_abbrev.default = "UTA".to_string();
+        standing.division_name = String::new();
+        standing.conference_name = None;
+
+        fill_missing_alignment(&mut standing, None);
+        assert_eq!(standing.division_name, "Central");
+        assert_eq!(standing.conference_name.as_deref(), Some("Western"));
+    }
+
+    #[test]
+    fn test_missing_alignment_follows_season() {
+        let mut standing = create_test_standings()[0].clone();
+        standing.team_abbrev.default = "ARI".to_string();
+        standing.division_name = String::new();
+        standing.conference_name = None;
+
+        fill_missing_alignment(&mut standing, Some(20222023));
+        assert_eq!(standing.division_name, "Central");
+
+        // Arizona has no division in the current alignment
+        let mut standing = create_test_standings()[0].clone();
+        standing.team_abbrev.default = "ARI".to_string();
+        standing.division_name = String::new();
+        standing.conference_name = None;
+        fill_missing_alignment(&mut standing, None);
+        assert_eq!(standing.division_name, "");
+        assert_eq!(standing.conference_name, None);
+    }
+
+    #[test]
+    fn test_missing_alignment_keeps_api_values() {
+        let mut standing = create_test_standings()[0].clone();
+        fill_missing_alignment(&mut standing, Some(20222023));
+        assert_eq!(standing.division_name, "Atlantic");
+        assert_eq!(standing.conference_name.as_deref(), Some("Eastern"));
+    }
+
     #[test]
     fn test_merge_columns_equal_length() {
         let left = vec!["Left1".to_string(), "Left2".to_string()];
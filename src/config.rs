@@ -1,15 +1,64 @@
+use crate::formatting::BoxChars;
+use crate::keybindings::KeyMap;
+use crate::season::{current_season_id, is_valid_season};
+use crate::team_abbrev::abbrev_to_common_name;
+use crate::timezone::{DisplayTimezone, LOCAL_TIMEZONE};
+use chrono::{DateTime, Local};
+use nhl_api::GameType;
 use phf::phf_map;
 use ratatui::style::{Color, Modifier};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt::Write;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::OnceLock;
 
 const DARKENING_FACTOR: f32 = 0.5;
+/// How far fg3 is darkened to get the zebra-stripe row background
+const STRIPE_FACTOR: f32 = 0.2;
+
+/// Minimum WCAG contrast ratio for theme text (AA level for large text and UI)
+pub const MIN_CONTRAST_RATIO: f32 = 3.0;
+
+/// Background assumed when checking theme contrast (terminals are usually dark)
+const ASSUMED_BACKGROUND: Color = Color::Black;
 
 /// Default refresh interval in seconds for background data fetching
 pub const DEFAULT_REFRESH_INTERVAL_SECONDS: u32 = 60;
 
+/// Log levels accepted for `log_level`, from most to least verbose
+pub const LOG_LEVELS: [&str; 5] = ["trace", "debug", "info", "warn", "error"];
+
+/// Log level used when none is set or the configured one is unknown
+pub const DEFAULT_LOG_LEVEL: &str = "info";
+
+/// Time format used when none is set or the configured one can't be rendered
+pub const DEFAULT_TIME_FORMAT: &str = "%H:%M:%S";
+
+/// Format for game start times when none is set or the configured one can't be rendered
+pub const DEFAULT_START_TIME_FORMAT: &str = "%-I:%M %p";
+
+/// Boxscore skater columns that `display.boxscore_sort` can name
+pub const BOXSCORE_SORT_COLUMNS: [&str; 10] = [
+    "number",
+    "goals",
+    "assists",
+    "points",
+    "plus_minus",
+    "sog",
+    "hits",
+    "blocks",
+    "pim",
+    "toi",
+];
+
+/// Default minimum terminal width; narrower terminals show a "too small" warning
+pub const DEFAULT_MIN_TERMINAL_WIDTH: u16 = 40;
+
+/// Default minimum terminal height; shorter terminals show a "too small" warning
+pub const DEFAULT_MIN_TERMINAL_HEIGHT: u16 = 10;
+
 /// Style modifier for selected items (reversed and bold)
 pub const SELECTION_STYLE_MODIFIER: Modifier = Modifier::REVERSED.union(Modifier::BOLD);
 
@@ -21,7 +70,31 @@ pub struct Config {
     pub refresh_interval: u32,
     pub display_standings_western_first: bool,
     pub time_format: String,
+    /// Format for game start times in score boxes, team schedules and `nhl schedule`
+    pub start_time_format: String,
+    /// Reopen the last viewed tab and standings selection on startup
+    pub restore_session: bool,
+    /// Keep standings, schedules and boxscores on disk, fresh for `refresh_interval`
+    pub disk_cache: bool,
+    /// Team abbreviations (e.g. "TOR") the scores tab can filter to; scores and standings accent them
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub favorite_teams: Vec<String>,
+    /// Season the standings open on, e.g. 20232024; the current season when unset
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub season: Option<i64>,
+    /// Game type the scores tab lists (1 preseason, 2 regular season, 3 playoffs);
+    /// every game when unset
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub game_type: Option<GameType>,
     pub display: DisplayConfig,
+    /// Extra keys per action name, e.g. `nav_down = "j"`; defaults keep working
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub keybindings: BTreeMap<String, String>,
+    #[serde(skip)]
+    pub keymap: KeyMap,
+    /// Problems found while reading the config file, logged once logging is up
+    #[serde(skip)]
+    pub warnings: Vec<String>,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -231,6 +304,11 @@ impl Default for Theme {
 }
 
 impl Theme {
+    /// Foreground for content that should stand out from regular fg2 text
+    pub fn emphasis_fg(&self) -> Color {
+        self.fg1
+    }
+
     /// Get a 50% darker version of fg2, computed lazily and cached
     pub fn fg2_dark(&self) -> Color {
         *self
@@ -259,20 +337,289 @@ pub struct DisplayConfig {
     #[serde(serialize_with = "serialize_color")]
     pub error_fg: Color,
     #[serde(skip)]
-    pub box_chars: crate::formatting::BoxChars,
+    pub box_chars: BoxChars,
+    /// Default sort for boxscore player tables, e.g. "points_desc" or "number_asc"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub boxscore_sort: Option<String>,
+    /// Minimum terminal width (columns) before showing the "too small" warning
+    pub min_width: u16,
+    /// Minimum terminal height (rows) before showing the "too small" warning
+    pub min_height: u16,
+    /// Draw big score digits in each team's primary color
+    pub colored_score_digits: bool,
+    /// Line style for unicode box borders
+    pub box_style: BoxStyle,
+    /// Older spelling of `box_style = "square"`, read but never written back
+    #[serde(skip_serializing)]
+    pub square_corners: Option<bool>,
+    /// Drop decorative blank lines between document sections to save rows
+    pub compact_spacing: bool,
+    /// Frames used by the loading animation
+    pub loading_style: LoadingStyle,
+    /// Ticks each loading animation frame is held for; raise it to slow the animation down
+    pub loading_frame_divisor: u8,
+    /// Columns left blank before the breadcrumb trail
+    pub breadcrumb_margin: u16,
+    /// Accent favorite teams in scores and standings even when not filtering to them
+    pub accent_favorites: bool,
+    /// How the scores tab draws each game
+    pub scores_layout: ScoresLayout,
+    /// Add a column comparing each goalie's save percentage to .900 in boxscores
+    pub goalie_save_diff: bool,
+    /// Wrap list focus from the last item to the first (and back) instead of stopping
+    pub focus_wrap: bool,
+    /// Show live game status in the emphasis color and finals muted
+    pub status_accent: bool,
+    /// Zone game start times are shown in: "local", an IANA name like "America/Toronto",
+    /// or an offset like "-05:00"
+    pub timezone: String,
+    /// Decimal places and leading zeros for percentage stats
+    pub numbers: NumberFormat,
+    /// Group thousands in counts such as career totals with `numbers.thousands_separator`
+    pub number_grouping: bool,
+    /// Per-character replacements merged over the `box_style` preset, keyed by `BoxChars` field
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub box_overrides: BTreeMap<String, String>,
+}
+
+/// Line style for unicode box borders (ASCII borders are unaffected)
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum BoxStyle {
+    /// Light lines with rounded corners (`╭╮╰╯`)
+    #[default]
+    Rounded,
+    /// Light lines with sharp corners (`┌┐└┘`)
+    Square,
+    /// Thick lines (`┏┓┗┛━┃`) for high-contrast displays
+    Heavy,
+}
+
+/// How the scores tab draws each game
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ScoresLayout {
+    /// A grid of boxed cards with the score on its own line per team
+    #[default]
+    Cards,
+    /// One dense line per game, for nights with many games
+    Compact,
+}
+
+/// Animation shown while data is loading
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum LoadingStyle {
+    /// A dot bouncing between three circles
+    #[default]
+    Dots,
+    /// A braille spinner
+    Braille,
+    /// A `-\|/` spinner that works on any terminal
+    Ascii,
+}
+
+/// How decimal stats such as SV% and FO% are written
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(default)]
+pub struct NumberFormat {
+    /// Decimal places for save percentages, e.g. 3 for "0.920"
+    pub save_pctg_decimals: usize,
+    /// Decimal places for percentages out of 100 such as FO%, e.g. 1 for "55.0"
+    pub percent_decimals: usize,
+    /// Keep the zero before the point in save percentages ("0.920" rather than ".920")
+    pub leading_zero: bool,
+    /// Put between thousands in counts when `number_grouping` is on, e.g. ',' or ' '
+    pub thousands_separator: char,
+}
+
+impl Default for NumberFormat {
+    fn default() -> Self {
+        NumberFormat {
+            save_pctg_decimals: 3,
+            percent_decimals: 1,
+            leading_zero: true,
+            thousands_separator: ',',
+        }
+    }
+}
+
+impl NumberFormat {
+    /// Format a save percentage given as a fraction, e.g. 0.92
+    pub fn save_pctg(&self, pctg: f64) -> String {
+        let formatted = format!("{:.*}", self.save_pctg_decimals, pctg);
+        if self.leading_zero {
+            return formatted;
+        }
+        match formatted.strip_prefix("0.") {
+            Some(decimals) => format!(".{}", decimals),
+            None => formatted,
+        }
+    }
+
+    /// Format a percentage out of 100, e.g. 55.0
+    pub fn percent(&self, pct: f64) -> String {
+        format!("{:.*}", self.percent_decimals, pct)
+    }
 }
 
 impl Default for Config {
     fn default() -> Self {
         Config {
-            log_level: "info".to_string(),
+            log_level: DEFAULT_LOG_LEVEL.to_string(),
             log_file: "/dev/null".to_string(),
             refresh_interval: DEFAULT_REFRESH_INTERVAL_SECONDS,
             display_standings_western_first: false,
-            time_format: "%H:%M:%S".to_string(),
+            time_format: DEFAULT_TIME_FORMAT.to_string(),
+            start_time_format: DEFAULT_START_TIME_FORMAT.to_string(),
+            restore_session: true,
+            disk_cache: true,
+            favorite_teams: Vec::new(),
+            season: None,
+            game_type: None,
             display: DisplayConfig::default(),
+            keybindings: BTreeMap::new(),
+            keymap: KeyMap::default(),
+            warnings: Vec::new(),
+        }
+    }
+}
+
+impl Config {
+    /// Favorite teams as uppercase abbreviations, without unknown ones
+    ///
+    /// `favorite_teams` keeps the list as written, so saving the config never
+    /// drops entries; match teams against this list instead.
+    pub fn known_favorite_teams(&self) -> Vec<String> {
+        self.favorite_teams
+            .iter()
+            .map(|abbrev| abbrev.trim().to_uppercase())
+            .filter(|abbrev| abbrev_to_common_name(abbrev).is_some())
+            .collect()
+    }
+
+    /// Record a warning for each unknown favorite team abbreviation
+    pub fn validate_favorite_teams(&mut self) {
+        for abbrev in &self.favorite_teams {
+            let abbrev = abbrev.trim().to_uppercase();
+            if abbrev_to_common_name(&abbrev).is_none() {
+                self.warnings
+                    .push(format!("Ignoring unknown favorite team '{}'", abbrev));
+            }
+        }
+    }
+
+    /// Normalize `log_level` to lowercase, falling back to "info" if it is unknown
+    ///
+    /// An unknown level is recorded in `warnings`.
+    pub fn validate_log_level(&mut self) {
+        let level = self.log_level.trim().to_lowercase();
+        if LOG_LEVELS.contains(&level.as_str()) {
+            self.log_level = level;
+        } else {
+            self.warnings.push(format!(
+                "Unknown log level '{}', using '{}'",
+                self.log_level, DEFAULT_LOG_LEVEL
+            ));
+            self.log_level = DEFAULT_LOG_LEVEL.to_string();
+        }
+    }
+
+    /// Fall back to the default `time_format` if it can't format a time
+    ///
+    /// A rejected format is recorded in `warnings`.
+    pub fn validate_time_format(&mut self) {
+        if format_time(&Local::now(), &self.time_format).is_none() {
+            self.warnings.push(format!(
+                "Invalid time format '{}', using '{}'",
+                self.time_format, DEFAULT_TIME_FORMAT
+            ));
+            self.time_format = DEFAULT_TIME_FORMAT.to_string();
+        }
+    }
+
+    /// Fall back to the default `start_time_format` if it can't format a time
+    ///
+    /// A rejected format is recorded in `warnings`.
+    pub fn validate_start_time_format(&mut self) {
+        if format_time(&Local::now(), &self.start_time_format).is_none() {
+            self.warnings.push(format!(
+                "Invalid start time format '{}', using '{}'",
+                self.start_time_format, DEFAULT_START_TIME_FORMAT
+            ));
+            self.start_time_format = DEFAULT_START_TIME_FORMAT.to_string();
+        }
+    }
+
+    /// Follow the current season if `season` isn't a season id up to the current one
+    ///
+    /// A rejected season is recorded in `warnings`.
+    pub fn validate_season(&mut self) {
+        let Some(season) = self.season else {
+            return;
+        };
+        if !is_valid_season(season, current_season_id(Local::now().date_naive())) {
+            self.warnings.push(format!(
+                "Invalid season {}, using the current season",
+                season
+            ));
+            self.season = None;
+        }
+    }
+
+    /// Fall back to the system local zone if `display.timezone` isn't a known zone
+    pub fn validate_timezone(&mut self) {
+        if DisplayTimezone::parse(&self.display.timezone).is_none() {
+            self.warnings.push(format!(
+                "Unknown timezone '{}', using local time",
+                self.display.timezone
+            ));
+            self.display.timezone = LOCAL_TIMEZONE.to_string();
         }
     }
+
+    /// Record a warning when the theme or error color is hard to read on a dark terminal
+    pub fn validate_contrast(&mut self) {
+        self.warnings.extend(self.display.contrast_warning());
+    }
+
+    /// Drop a `display.boxscore_sort` that names no sortable column or direction
+    ///
+    /// A rejected sort is recorded in `warnings`; boxscores then keep API order.
+    pub fn validate_boxscore_sort(&mut self) {
+        let Some(value) = &self.display.boxscore_sort else {
+            return;
+        };
+        if parse_boxscore_sort(value).is_none() {
+            self.warnings.push(format!(
+                "Invalid boxscore sort '{}', using API order",
+                value
+            ));
+            self.display.boxscore_sort = None;
+        }
+    }
+
+    /// Carry an old `display.square_corners = true` over to `box_style = "square"`
+    ///
+    /// An explicit `box_style` wins. The old setting is recorded in `warnings`.
+    pub fn migrate_square_corners(&mut self) {
+        let Some(square_corners) = self.display.square_corners.take() else {
+            return;
+        };
+        if square_corners && self.display.box_style == BoxStyle::Rounded {
+            self.display.box_style = BoxStyle::Square;
+        }
+        self.warnings.push(
+            "display.square_corners is deprecated, use box_style = \"square\" instead".to_string(),
+        );
+    }
+
+    /// Rebuild keymap from keybindings, recording ignored entries in `warnings`
+    pub fn rebuild_keymap(&mut self) {
+        let (keymap, warnings) = KeyMap::from_config(&self.keybindings);
+        self.keymap = keymap;
+        self.warnings.extend(warnings);
+    }
 }
 
 impl Default for DisplayConfig {
@@ -282,12 +629,70 @@ impl Default for DisplayConfig {
             theme_name: None,
             theme: None,
             error_fg: Color::Rgb(255, 0, 0), // Red
-            box_chars: crate::formatting::BoxChars::unicode(),
+            box_chars: BoxChars::unicode(),
+            boxscore_sort: None,
+            min_width: DEFAULT_MIN_TERMINAL_WIDTH,
+            min_height: DEFAULT_MIN_TERMINAL_HEIGHT,
+            colored_score_digits: false,
+            box_style: BoxStyle::default(),
+            square_corners: None,
+            compact_spacing: false,
+            loading_style: LoadingStyle::default(),
+            loading_frame_divisor: 1,
+            breadcrumb_margin: 0,
+            accent_favorites: true,
+            scores_layout: ScoresLayout::default(),
+            goalie_save_diff: false,
+            focus_wrap: false,
+            status_accent: true,
+            timezone: LOCAL_TIMEZONE.to_string(),
+            numbers: NumberFormat::default(),
+            number_grouping: false,
+            box_overrides: BTreeMap::new(),
         }
     }
 }
 
 impl DisplayConfig {
+    /// Separator for thousands in counts, or None when `number_grouping` is off
+    pub fn count_separator(&self) -> Option<char> {
+        self.number_grouping
+            .then_some(self.numbers.thousands_separator)
+    }
+
+    /// Rebuild box_chars from use_unicode, box_style and box_overrides
+    ///
+    /// Returns a warning for each override that was ignored.
+    pub fn rebuild_box_chars(&mut self) -> Vec<String> {
+        self.box_chars = BoxChars::from_use_unicode(self.use_unicode, self.box_style);
+        self.box_chars.apply_overrides(&self.box_overrides)
+    }
+
+    /// Warning listing the colors likely unreadable on a dark terminal, if any
+    ///
+    /// Checks the theme's fg2, used for text and (reversed) for the selection,
+    /// and `error_fg`.
+    pub fn contrast_warning(&self) -> Option<String> {
+        let theme_text = self
+            .theme
+            .as_ref()
+            .map(|theme| (format!("theme '{}'", theme.name), theme.fg2));
+        let low: Vec<String> = theme_text
+            .into_iter()
+            .chain([("error color".to_string(), self.error_fg)])
+            .filter_map(|(name, color)| {
+                let ratio = contrast_ratio(color, ASSUMED_BACKGROUND);
+                (ratio < MIN_CONTRAST_RATIO).then(|| format!("{} ({:.1}:1)", name, ratio))
+            })
+            .collect();
+        (!low.is_empty()).then(|| {
+            format!(
+                "low contrast for {}, text may be hard to read",
+                low.join(", ")
+            )
+        })
+    }
+
     /// Apply theme from theme_name by looking it up in THEMES map
     pub fn apply_theme(&mut self) {
         self.theme = self
@@ -317,6 +722,54 @@ impl DisplayConfig {
             .unwrap_or_default()
     }
 
+    /// Get the emphasis style using the theme's emphasis_fg
+    ///
+    /// Falls back to bold when no theme is set.
+    pub fn emphasis_style(&self) -> ratatui::style::Style {
+        self.theme
+            .as_ref()
+            .map(|t| ratatui::style::Style::default().fg(t.emphasis_fg()))
+            .unwrap_or_else(|| ratatui::style::Style::default().add_modifier(Modifier::BOLD))
+    }
+
+    /// Get the accent style for favorite teams, patched over their normal style
+    ///
+    /// Italic emphasis_fg, so it differs from both emphasized rows and the
+    /// selection; italic alone without a theme, and no change when disabled.
+    pub fn favorite_style(&self) -> ratatui::style::Style {
+        if !self.accent_favorites {
+            return ratatui::style::Style::default();
+        }
+        let style = ratatui::style::Style::default().add_modifier(Modifier::ITALIC);
+        match &self.theme {
+            Some(theme) => style.fg(theme.emphasis_fg()),
+            None => style,
+        }
+    }
+
+    /// Get the background style for alternate (zebra-striped) table rows
+    ///
+    /// A heavily darkened fg3 from the theme; no stripe without a theme.
+    pub fn stripe_style(&self) -> ratatui::style::Style {
+        self.theme
+            .as_ref()
+            .map(|t| ratatui::style::Style::default().bg(darken_color(t.fg3, STRIPE_FACTOR)))
+            .unwrap_or_default()
+    }
+
+    /// Get the style patched over find matches in a document
+    ///
+    /// Reversed, so matches stand out in any theme; the current match is also
+    /// bold and underlined.
+    pub fn find_match_style(&self, current: bool) -> ratatui::style::Style {
+        let style = ratatui::style::Style::default().add_modifier(Modifier::REVERSED);
+        if current {
+            style.add_modifier(Modifier::BOLD | Modifier::UNDERLINED)
+        } else {
+            style
+        }
+    }
+
     /// Get a heading style with bold modifier
     pub fn heading_style(&self, level: u8) -> ratatui::style::Style {
         let base = self.text_style();
@@ -341,6 +794,62 @@ fn darken_color(color: Color, factor: f32) -> Color {
     }
 }
 
+/// RGB components of a color, using xterm defaults for the named ANSI colors
+///
+/// Returns None for colors whose value depends on the terminal (Reset, 256-color indexes).
+fn color_to_rgb(color: Color) -> Option<(u8, u8, u8)> {
+    let rgb = match color {
+        Color::Rgb(r, g, b) => (r, g, b),
+        Color::Black => (0, 0, 0),
+        Color::Red => (205, 0, 0),
+        Color::Green => (0, 205, 0),
+        Color::Yellow => (205, 205, 0),
+        Color::Blue => (0, 0, 238),
+        Color::Magenta => (205, 0, 205),
+        Color::Cyan => (0, 205, 205),
+        Color::Gray => (229, 229, 229),
+        Color::DarkGray => (127, 127, 127),
+        Color::LightRed => (255, 0, 0),
+        Color::LightGreen => (0, 255, 0),
+        Color::LightYellow => (255, 255, 0),
+        Color::LightBlue => (92, 92, 255),
+        Color::LightMagenta => (255, 0, 255),
+        Color::LightCyan => (0, 255, 255),
+        Color::White => (255, 255, 255),
+        _ => return None,
+    };
+    Some(rgb)
+}
+
+/// WCAG relative luminance of a color (0.0 = black, 1.0 = white)
+fn relative_luminance(color: Color) -> Option<f32> {
+    let linear = |c: u8| {
+        let c = c as f32 / 255.0;
+        if c <= 0.03928 {
+            c / 12.92
+        } else {
+            ((c + 0.055) / 1.055).powf(2.4)
+        }
+    };
+    let (r, g, b) = color_to_rgb(color)?;
+    Some(0.2126 * linear(r) + 0.7152 * linear(g) + 0.0722 * linear(b))
+}
+
+/// WCAG contrast ratio between two colors, from 1.0 (identical) to 21.0 (black on white)
+///
+/// Colors that can't be resolved to RGB are assumed readable and give 21.0.
+pub fn contrast_ratio(a: Color, b: Color) -> f32 {
+    match (relative_luminance(a), relative_luminance(b)) {
+        (Some(la), Some(lb)) => (la.max(lb) + 0.05) / (la.min(lb) + 0.05),
+        _ => 21.0,
+    }
+}
+
+/// Whether text in `fg` on `bg` falls below [`MIN_CONTRAST_RATIO`]
+pub fn is_low_contrast(fg: Color, bg: Color) -> bool {
+    contrast_ratio(fg, bg) < MIN_CONTRAST_RATIO
+}
+
 /// Deserialize a color from a string (supports named colors, RGB hex, or RGB tuple)
 fn deserialize_color<'de, D>(deserializer: D) -> Result<Color, D::Error>
 where
@@ -386,7 +895,7 @@ where
 // }
 
 /// Format a color as a string (RGB format for serialization)
-fn format_color(color: &Color) -> String {
+pub fn format_color(color: &Color) -> String {
     match color {
         Color::Rgb(r, g, b) => format!("{},{},{}", r, g, b),
         Color::Black => "black".to_string(),
@@ -412,9 +921,9 @@ fn format_color(color: &Color) -> String {
 /// Parse a color string into a ratatui Color
 /// Supports:
 /// - Named colors: "red", "blue", "cyan", "orange", etc.
-/// - Hex colors: "#FF6600", "#f60"
+/// - Hex colors: "#FF6600", "#f60", "#FF660080" (alpha is accepted but ignored)
 /// - RGB tuples: "255,165,0"
-fn parse_color(s: &str) -> Option<Color> {
+pub fn parse_color(s: &str) -> Option<Color> {
     let s = s.trim().to_lowercase();
 
     // Named colors
@@ -462,9 +971,13 @@ fn parse_color(s: &str) -> Option<Color> {
         _ => {}
     }
 
-    // Hex colors (#FF6600 or #f60)
+    // Hex colors (#FF6600, #f60, or #FF660080)
     if let Some(hex) = s.strip_prefix('#') {
-        if hex.len() == 6 {
+        if hex.len() == 8 {
+            // Terminals can't blend, so the alpha channel is validated and dropped
+            u8::from_str_radix(hex.get(6..8)?, 16).ok()?;
+            return parse_color(&format!("#{}", hex.get(0..6)?));
+        } else if hex.len() == 6 {
             let r = u8::from_str_radix(&hex[0..2], 16).ok()?;
             let g = u8::from_str_radix(&hex[2..4], 16).ok()?;
             let b = u8::from_str_radix(&hex[4..6], 16).ok()?;
@@ -491,7 +1004,59 @@ fn parse_color(s: &str) -> Option<Color> {
     None
 }
 
-pub fn get_config_path() -> Option<PathBuf> {
+/// Boxscore sort named by `display.boxscore_sort`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BoxscoreSort {
+    /// One of `BOXSCORE_SORT_COLUMNS`
+    pub column: &'static str,
+    pub ascending: bool,
+}
+
+/// Parse a boxscore sort such as `"points_desc"` or `"number_asc"`
+///
+/// Returns None for unknown columns or directions.
+pub fn parse_boxscore_sort(value: &str) -> Option<BoxscoreSort> {
+    let (name, direction) = value.trim().rsplit_once('_')?;
+    let ascending = match direction {
+        "asc" => true,
+        "desc" => false,
+        _ => return None,
+    };
+    let column = BOXSCORE_SORT_COLUMNS.iter().find(|c| **c == name)?;
+    Some(BoxscoreSort { column, ascending })
+}
+
+/// Name of the active profile, set once at startup from `--profile`
+static ACTIVE_PROFILE: OnceLock<String> = OnceLock::new();
+
+/// Select the profile used by all subsequent config reads and writes
+///
+/// Must be called before `read()`. Profile names are used as directory names,
+/// so path separators and relative components are rejected.
+pub fn set_profile(name: &str) -> anyhow::Result<()> {
+    validate_profile_name(name)?;
+    ACTIVE_PROFILE
+        .set(name.to_string())
+        .map_err(|_| anyhow::anyhow!("Profile already set"))
+}
+
+/// Get the name of the active profile, if any
+pub fn active_profile() -> Option<&'static str> {
+    ACTIVE_PROFILE.get().map(String::as_str)
+}
+
+fn validate_profile_name(name: &str) -> anyhow::Result<()> {
+    if name.is_empty() || name == "." || name == ".." {
+        anyhow::bail!("Invalid profile name: '{}'", name);
+    }
+    if name.contains(['/', '\\']) {
+        anyhow::bail!("Profile name cannot contain path separators: '{}'", name);
+    }
+    Ok(())
+}
+
+/// Get the base configuration directory (e.g. `~/.config/nhl`)
+fn base_config_dir() -> Option<PathBuf> {
     let pgm = env!("CARGO_PKG_NAME");
 
     // On Unix, use XDG-style ~/.config for backward compatibility
@@ -502,7 +1067,37 @@ pub fn get_config_path() -> Option<PathBuf> {
     #[cfg(windows)]
     let config_dir = dirs::config_dir()?;
 
-    Some(config_dir.join(pgm).join("config.toml"))
+    Some(config_dir.join(pgm))
+}
+
+/// Get the directory holding config and session files for a profile
+///
+/// The default profile lives directly in the base directory, named profiles
+/// live in `profiles/<name>/` under it.
+pub fn profile_dir(profile: Option<&str>) -> Option<PathBuf> {
+    let base = base_config_dir()?;
+    Some(match profile {
+        Some(name) => base.join("profiles").join(name),
+        None => base,
+    })
+}
+
+/// Get the config file path for a profile
+pub fn config_path_for_profile(profile: Option<&str>) -> Option<PathBuf> {
+    Some(profile_dir(profile)?.join("config.toml"))
+}
+
+/// Get the session file path for a profile
+pub fn session_path_for_profile(profile: Option<&str>) -> Option<PathBuf> {
+    Some(profile_dir(profile)?.join("session.toml"))
+}
+
+pub fn get_config_path() -> Option<PathBuf> {
+    config_path_for_profile(active_profile())
+}
+
+pub fn get_session_path() -> Option<PathBuf> {
+    session_path_for_profile(active_profile())
 }
 
 pub fn read() -> Config {
@@ -522,21 +1117,48 @@ pub fn read() -> Config {
     };
 
     let mut config: Config = toml::from_str(&content).unwrap_or_else(|_| Config::default());
+    config.migrate_square_corners();
 
-    // Initialize box_chars based on use_unicode (since it's not serialized)
-    config.display.box_chars =
-        crate::formatting::BoxChars::from_use_unicode(config.display.use_unicode);
+    // Initialize box_chars from the display settings (since it's not serialized)
+    let box_warnings = config.display.rebuild_box_chars();
+    config.warnings.extend(box_warnings);
 
     // Apply theme based on theme_name (since it's not serialized)
     config.display.apply_theme();
 
+    config.validate_favorite_teams();
+    config.validate_log_level();
+    config.validate_time_format();
+    config.validate_start_time_format();
+    config.validate_season();
+    config.validate_timezone();
+    config.validate_boxscore_sort();
+    config.validate_contrast();
+
+    // Parse keymap from keybindings (since it's not serialized)
+    config.rebuild_keymap();
+
     config
 }
 
+/// Format a time with a strftime-style format, or None if the format is invalid or empty
+pub fn format_time(time: &DateTime<Local>, format: &str) -> Option<String> {
+    if format.is_empty() {
+        return None;
+    }
+    let mut formatted = String::new();
+    write!(formatted, "{}", time.format(format)).ok()?;
+    Some(formatted)
+}
+
 /// Write a config to the config file
 pub fn write(config: &Config) -> Result<(), Box<dyn std::error::Error>> {
     let config_path = get_config_path().ok_or("Failed to get config path")?;
+    write_to(config, &config_path)
+}
 
+/// Write a config to the given path, creating its directory if needed
+pub fn write_to(config: &Config, config_path: &Path) -> Result<(), Box<dyn std::error::Error>> {
     // Create parent directory if it doesn't exist
     if let Some(parent) = config_path.parent() {
         fs::create_dir_all(parent)?;
@@ -546,7 +1168,7 @@ pub fn write(config: &Config) -> Result<(), Box<dyn std::error::Error>> {
     let toml_string = toml::to_string_pretty(config)?;
 
     // Write to file
-    fs::write(&config_path, toml_string)?;
+    fs::write(config_path, toml_string)?;
 
     Ok(())
 }
@@ -600,6 +1222,68 @@ mod tests {
         assert_eq!(parse_color("#GGGGGG"), None);
     }
 
+    #[test]
+    fn test_contrast_ratio_black_on_white_is_high() {
+        let ratio = contrast_ratio(Color::Black, Color::White);
+        assert!((ratio - 21.0).abs() < 0.01);
+        assert_eq!(ratio, contrast_ratio(Color::White, Color::Black));
+        assert!(!is_low_contrast(Color::Black, Color::White));
+    }
+
+    #[test]
+    fn test_contrast_ratio_dark_gray_on_black_is_low() {
+        let ratio = contrast_ratio(Color::Rgb(40, 40, 40), Color::Black);
+        assert!(ratio < 1.5);
+        assert!(is_low_contrast(Color::Rgb(40, 40, 40), Color::Black));
+    }
+
+    #[test]
+    fn test_contrast_ratio_unresolved_color_is_assumed_readable() {
+        assert_eq!(contrast_ratio(Color::Reset, Color::Black), 21.0);
+    }
+
+    #[test]
+    fn test_contrast_warning() {
+        let mut display = DisplayConfig::default();
+        assert_eq!(display.contrast_warning(), None);
+        for theme in THEMES.values() {
+            display.theme = Some((*theme).clone());
+            assert_eq!(display.contrast_warning(), None, "{}", theme.name);
+        }
+
+        display.theme = Some(Theme {
+            name: "Dark",
+            fg2: Color::Rgb(30, 30, 60),
+            ..Theme::default()
+        });
+        display.error_fg = Color::Rgb(40, 0, 0);
+        assert_eq!(
+            display.contrast_warning().as_deref(),
+            Some("low contrast for theme 'Dark' (1.3:1), error color (1.1:1), text may be hard to read")
+        );
+
+        display.theme = None;
+        assert_eq!(
+            display.contrast_warning().as_deref(),
+            Some("low contrast for error color (1.1:1), text may be hard to read")
+        );
+    }
+
+    #[test]
+    fn test_parse_color_hex_8_digit_ignores_alpha() {
+        assert_eq!(parse_color("#FF660080"), Some(Color::Rgb(255, 102, 0)));
+        assert_eq!(parse_color("#ff6600ff"), Some(Color::Rgb(255, 102, 0)));
+        assert_eq!(parse_color("#00FF0000"), Some(Color::Rgb(0, 255, 0)));
+    }
+
+    #[test]
+    fn test_parse_color_hex_8_digit_invalid() {
+        assert_eq!(parse_color("#GG660080"), None);
+        assert_eq!(parse_color("#FF6600ZZ"), None);
+        assert_eq!(parse_color("#FF66008"), None);
+        assert_eq!(parse_color("#FF6600é"), None);
+    }
+
     #[test]
     fn test_serialize_color_rgb() {
         let color = Color::Rgb(255, 165, 0);
@@ -619,7 +1303,37 @@ mod tests {
         config.refresh_interval = 30;
         config.log_level = "debug".to_string();
         config.display_standings_western_first = true;
+        config.restore_session = false;
+        config.disk_cache = false;
+        config.favorite_teams = vec!["TOR".to_string(), "MTL".to_string()];
+        config.season = Some(20232024);
+        config.game_type = Some(GameType::Playoffs);
         config.display.use_unicode = false;
+        config.display.boxscore_sort = Some("points_desc".to_string());
+        config.display.min_width = 60;
+        config.display.colored_score_digits = true;
+        config.display.box_style = BoxStyle::Heavy;
+        config.display.compact_spacing = true;
+        config.display.loading_style = LoadingStyle::Braille;
+        config.display.loading_frame_divisor = 2;
+        config.display.breadcrumb_margin = 1;
+        config.display.accent_favorites = false;
+        config.display.scores_layout = ScoresLayout::Compact;
+        config.display.goalie_save_diff = true;
+        config.display.focus_wrap = true;
+        config.display.status_accent = false;
+        config.display.timezone = "America/Toronto".to_string();
+        config.display.numbers.save_pctg_decimals = 2;
+        config.display.numbers.leading_zero = false;
+        config.display.numbers.thousands_separator = ' ';
+        config.display.number_grouping = true;
+        config
+            .display
+            .box_overrides
+            .insert("selector".to_string(), "→".to_string());
+        config
+            .keybindings
+            .insert("nav_down".to_string(), "j".to_string());
 
         let toml_str = toml::to_string_pretty(&config).unwrap();
 
@@ -628,14 +1342,311 @@ log_file = "/dev/null"
 refresh_interval = 30
 display_standings_western_first = true
 time_format = "%H:%M:%S"
+start_time_format = "%-I:%M %p"
+restore_session = false
+disk_cache = false
+favorite_teams = [
+    "TOR",
+    "MTL",
+]
+season = 20232024
+game_type = 3
 
 [display]
 use_unicode = false
 error_fg = "255,0,0"
+boxscore_sort = "points_desc"
+min_width = 60
+min_height = 10
+colored_score_digits = true
+box_style = "heavy"
+compact_spacing = true
+loading_style = "braille"
+loading_frame_divisor = 2
+breadcrumb_margin = 1
+accent_favorites = false
+scores_layout = "compact"
+goalie_save_diff = true
+focus_wrap = true
+status_accent = false
+timezone = "America/Toronto"
+number_grouping = true
+
+[display.numbers]
+save_pctg_decimals = 2
+percent_decimals = 1
+leading_zero = false
+thousands_separator = " "
+
+[display.box_overrides]
+selector = "→"
+
+[keybindings]
+nav_down = "j"
 "#;
         assert_eq!(toml_str.trim(), expected.trim());
     }
 
+    #[test]
+    fn test_validate_favorite_teams() {
+        let mut config = Config {
+            favorite_teams: vec!["tor".to_string(), "XYZ".to_string(), "MTL".to_string()],
+            ..Config::default()
+        };
+        config.validate_favorite_teams();
+
+        assert_eq!(config.known_favorite_teams(), vec!["TOR", "MTL"]);
+        assert_eq!(
+            config.warnings,
+            vec!["Ignoring unknown favorite team 'XYZ'".to_string()]
+        );
+        // The list written back keeps what the user wrote
+        assert_eq!(config.favorite_teams, vec!["tor", "XYZ", "MTL"]);
+    }
+
+    #[test]
+    fn test_validate_log_level() {
+        let mut config: Config = toml::from_str(r#"log_level = "infformation""#).unwrap();
+        config.validate_log_level();
+
+        assert_eq!(config.log_level, "info");
+        assert_eq!(
+            config.warnings,
+            vec!["Unknown log level 'infformation', using 'info'".to_string()]
+        );
+
+        let mut config: Config = toml::from_str(r#"log_level = "DEBUG""#).unwrap();
+        config.validate_log_level();
+        assert_eq!(config.log_level, "debug");
+        assert!(config.warnings.is_empty());
+    }
+
+    #[test]
+    fn test_validate_time_format() {
+        let mut config = Config {
+            time_format: "%I:%M %p".to_string(),
+            ..Config::default()
+        };
+        config.validate_time_format();
+        assert_eq!(config.time_format, "%I:%M %p");
+        assert!(config.warnings.is_empty());
+
+        let mut config = Config {
+            time_format: "%H:%Q".to_string(),
+            ..Config::default()
+        };
+        config.validate_time_format();
+        assert_eq!(config.time_format, DEFAULT_TIME_FORMAT);
+        assert_eq!(
+            config.warnings,
+            vec!["Invalid time format '%H:%Q', using '%H:%M:%S'".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_validate_season() {
+        let mut config = Config {
+            season: Some(20232024),
+            ..Config::default()
+        };
+        config.validate_season();
+        assert_eq!(config.season, Some(20232024));
+        assert!(config.warnings.is_empty());
+
+        for season in [2024, 20242024, 99989999] {
+            let mut config = Config {
+                season: Some(season),
+                ..Config::default()
+            };
+            config.validate_season();
+            assert_eq!(config.season, None);
+            assert_eq!(
+                config.warnings,
+                vec![format!(
+                    "Invalid season {}, using the current season",
+                    season
+                )]
+            );
+        }
+    }
+
+    #[test]
+    fn test_validate_start_time_format() {
+        let mut config = Config::default();
+        config.validate_start_time_format();
+        assert_eq!(config.start_time_format, DEFAULT_START_TIME_FORMAT);
+        assert!(config.warnings.is_empty());
+
+        let mut config = Config {
+            start_time_format: "%l%Q".to_string(),
+            ..Config::default()
+        };
+        config.validate_start_time_format();
+        assert_eq!(config.start_time_format, DEFAULT_START_TIME_FORMAT);
+        assert_eq!(
+            config.warnings,
+            vec!["Invalid start time format '%l%Q', using '%-I:%M %p'".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_parse_boxscore_sort() {
+        assert_eq!(
+            parse_boxscore_sort("points_desc"),
+            Some(BoxscoreSort {
+                column: "points",
+                ascending: false,
+            })
+        );
+        assert_eq!(
+            parse_boxscore_sort(" plus_minus_asc "),
+            Some(BoxscoreSort {
+                column: "plus_minus",
+                ascending: true,
+            })
+        );
+        assert!(parse_boxscore_sort("").is_none());
+        assert!(parse_boxscore_sort("points").is_none());
+        assert!(parse_boxscore_sort("points_up").is_none());
+        assert!(parse_boxscore_sort("fo_pct_desc").is_none());
+    }
+
+    #[test]
+    fn test_validate_boxscore_sort() {
+        let mut config: Config = toml::from_str(
+            r#"
+[display]
+boxscore_sort = "toi_desc"
+"#,
+        )
+        .unwrap();
+        config.validate_boxscore_sort();
+        assert_eq!(config.display.boxscore_sort.as_deref(), Some("toi_desc"));
+        assert!(config.warnings.is_empty());
+
+        config.display.boxscore_sort = Some("fo_pct_desc".to_string());
+        config.validate_boxscore_sort();
+        assert_eq!(config.display.boxscore_sort, None);
+        assert_eq!(
+            config.warnings,
+            vec!["Invalid boxscore sort 'fo_pct_desc', using API order".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_migrate_square_corners() {
+        let mut config: Config = toml::from_str(
+            r#"
+[display]
+square_corners = true
+"#,
+        )
+        .unwrap();
+        config.migrate_square_corners();
+        assert_eq!(config.display.box_style, BoxStyle::Square);
+        assert_eq!(config.display.square_corners, None);
+        assert_eq!(
+            config.warnings,
+            vec![
+                r#"display.square_corners is deprecated, use box_style = "square" instead"#
+                    .to_string()
+            ]
+        );
+        assert!(!toml::to_string(&config).unwrap().contains("square_corners"));
+
+        let mut config: Config = toml::from_str(
+            r#"
+[display]
+square_corners = true
+box_style = "heavy"
+"#,
+        )
+        .unwrap();
+        config.migrate_square_corners();
+        assert_eq!(config.display.box_style, BoxStyle::Heavy);
+
+        let mut config = Config::default();
+        config.migrate_square_corners();
+        assert_eq!(config.display.box_style, BoxStyle::Rounded);
+        assert!(config.warnings.is_empty());
+    }
+
+    #[test]
+    fn test_validate_timezone() {
+        let mut config: Config = toml::from_str(
+            r#"
+[display]
+timezone = "America/Toronto"
+"#,
+        )
+        .unwrap();
+        config.validate_timezone();
+        assert_eq!(config.display.timezone, "America/Toronto");
+        assert!(config.warnings.is_empty());
+
+        config.display.timezone = "Toronto".to_string();
+        config.validate_timezone();
+        assert_eq!(config.display.timezone, LOCAL_TIMEZONE);
+        assert_eq!(
+            config.warnings,
+            vec!["Unknown timezone 'Toronto', using local time".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_number_format() {
+        let numbers = NumberFormat::default();
+        assert_eq!(numbers.save_pctg(0.92), "0.920");
+        assert_eq!(numbers.save_pctg(1.0), "1.000");
+        assert_eq!(numbers.percent(55.0), "55.0");
+
+        let numbers = NumberFormat {
+            save_pctg_decimals: 2,
+            percent_decimals: 0,
+            leading_zero: false,
+            ..NumberFormat::default()
+        };
+        assert_eq!(numbers.save_pctg(0.9234), ".92");
+        assert_eq!(numbers.save_pctg(1.0), "1.00");
+        assert_eq!(numbers.percent(55.5), "56");
+    }
+
+    #[test]
+    fn test_format_time() {
+        use chrono::TimeZone;
+
+        let time = Local.with_ymd_and_hms(2024, 10, 8, 19, 5, 0).unwrap();
+        assert_eq!(format_time(&time, "%H:%M:%S"), Some("19:05:00".to_string()));
+        assert_eq!(format_time(&time, "%I:%M %p"), Some("07:05 PM".to_string()));
+        assert_eq!(format_time(&time, "%Q"), None);
+        assert_eq!(format_time(&time, ""), None);
+    }
+
+    #[test]
+    fn test_rebuild_keymap_warns_on_invalid_binding() {
+        use crate::keybindings::KeyAction;
+        use crossterm::event::{KeyCode, KeyEvent};
+
+        let mut config: Config = toml::from_str(
+            r#"
+[keybindings]
+nav_down = "j"
+go_back = "not a key"
+"#,
+        )
+        .unwrap();
+        config.rebuild_keymap();
+
+        assert_eq!(
+            config.keymap.action_for(KeyEvent::from(KeyCode::Char('j'))),
+            Some(KeyAction::NavDown)
+        );
+        assert_eq!(
+            config.warnings,
+            vec!["Ignoring invalid key 'not a key' for 'go_back'".to_string()]
+        );
+    }
+
     #[test]
     fn test_roundtrip_serialization() {
         let mut config = Config::default();
@@ -802,6 +1813,37 @@ theme = "{}"
         }
     }
 
+    #[test]
+    fn test_profile_paths_default() {
+        let config_path = config_path_for_profile(None).unwrap();
+        let session_path = session_path_for_profile(None).unwrap();
+        assert!(config_path.ends_with("nhl/config.toml"));
+        assert!(session_path.ends_with("nhl/session.toml"));
+        assert_eq!(config_path.parent(), session_path.parent());
+    }
+
+    #[test]
+    fn test_profile_paths_named() {
+        let config_path = config_path_for_profile(Some("habs")).unwrap();
+        let session_path = session_path_for_profile(Some("habs")).unwrap();
+        assert!(config_path.ends_with("nhl/profiles/habs/config.toml"));
+        assert!(session_path.ends_with("nhl/profiles/habs/session.toml"));
+        assert_eq!(config_path.parent(), session_path.parent());
+        assert_ne!(config_path, config_path_for_profile(None).unwrap());
+        assert_ne!(config_path, config_path_for_profile(Some("leafs")).unwrap());
+    }
+
+    #[test]
+    fn test_validate_profile_name() {
+        assert!(validate_profile_name("habs").is_ok());
+        assert!(validate_profile_name("my-profile_2").is_ok());
+        assert!(validate_profile_name("").is_err());
+        assert!(validate_profile_name(".").is_err());
+        assert!(validate_profile_name("..").is_err());
+        assert!(validate_profile_name("../etc").is_err());
+        assert!(validate_profile_name("a\\b").is_err());
+    }
+
     #[test]
     fn test_theme_dark_colors_cached() {
         // Call twice to verify it returns the same value (cached)
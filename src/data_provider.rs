@@ -2,7 +2,7 @@
 use async_trait::async_trait;
 use nhl_api::{
     Boxscore, ClubStats, DailySchedule, Franchise, GameDate, GameMatchup, GameType, NHLApiError,
-    PlayerLanding, SeasonGameTypes, Standing,
+    PlayerGameLog, PlayerLanding, Roster, SeasonGameTypes, Standing, TeamScheduleResponse,
 };
 
 /// Trait for NHL data providers, implemented by both real Client and MockClient
@@ -32,9 +32,27 @@ pub trait NHLDataProvider: Send + Sync {
     async fn club_stats_season(&self, team_abbr: &str)
         -> Result<Vec<SeasonGameTypes>, NHLApiError>;
 
+    /// Get a team's current roster
+    async fn roster_current(&self, team_abbr: &str) -> Result<Roster, NHLApiError>;
+
+    /// Get a team's schedule for the week containing `date` (today if None)
+    async fn team_weekly_schedule(
+        &self,
+        team_abbr: &str,
+        date: Option<GameDate>,
+    ) -> Result<TeamScheduleResponse, NHLApiError>;
+
     /// Get player landing data
     async fn player_landing(&self, player_id: i64) -> Result<PlayerLanding, NHLApiError>;
 
+    /// Get a player's game-by-game log for a season
+    async fn player_game_log(
+        &self,
+        player_id: i64,
+        season: i32,
+        game_type: GameType,
+    ) -> Result<PlayerGameLog, NHLApiError>;
+
     /// Get all franchises
     async fn franchises(&self) -> Result<Vec<Franchise>, NHLApiError>;
 
@@ -86,10 +104,31 @@ impl NHLDataProvider for nhl_api::Client {
         self.club_stats_season(team_abbr).await
     }
 
+    async fn roster_current(&self, team_abbr: &str) -> Result<Roster, NHLApiError> {
+        self.roster_current(team_abbr).await
+    }
+
+    async fn team_weekly_schedule(
+        &self,
+        team_abbr: &str,
+        date: Option<GameDate>,
+    ) -> Result<TeamScheduleResponse, NHLApiError> {
+        self.team_weekly_schedule(team_abbr, date).await
+    }
+
     async fn player_landing(&self, player_id: i64) -> Result<PlayerLanding, NHLApiError> {
         self.player_landing(player_id).await
     }
 
+    async fn player_game_log(
+        &self,
+        player_id: i64,
+        season: i32,
+        game_type: GameType,
+    ) -> Result<PlayerGameLog, NHLApiError> {
+        self.player_game_log(player_id, season, game_type).await
+    }
+
     async fn franchises(&self) -> Result<Vec<Franchise>, NHLApiError> {
         self.franchises().await
     }
@@ -3,7 +3,7 @@ use crate::data_provider::NHLDataProvider;
 use async_trait::async_trait;
 use nhl_api::{
     Boxscore, ClubStats, DailySchedule, Franchise, GameDate, GameMatchup, GameType, NHLApiError,
-    PlayerLanding, SeasonGameTypes, Standing,
+    PlayerGameLog, PlayerLanding, Roster, SeasonGameTypes, Standing, TeamScheduleResponse,
 };
 use tracing::info;
 
@@ -79,6 +79,20 @@ impl NHLDataProvider for MockClient {
         ])
     }
 
+    async fn roster_current(&self, team_abbr: &str) -> Result<Roster, NHLApiError> {
+        info!("MockClient: Returning mock roster for {}", team_abbr);
+        Ok(fixtures::create_mock_roster(team_abbr))
+    }
+
+    async fn team_weekly_schedule(
+        &self,
+        team_abbr: &str,
+        _date: Option<GameDate>,
+    ) -> Result<TeamScheduleResponse, NHLApiError> {
+        info!("MockClient: Returning mock schedule for {}", team_abbr);
+        Ok(fixtures::create_mock_team_schedule(team_abbr))
+    }
+
     async fn player_landing(&self, player_id: i64) -> Result<PlayerLanding, NHLApiError> {
         info!(
             "MockClient: Returning mock player landing for {}",
@@ -87,6 +101,21 @@ impl NHLDataProvider for MockClient {
         Ok(fixtures::create_mock_player_landing(player_id))
     }
 
+    async fn player_game_log(
+        &self,
+        player_id: i64,
+        season: i32,
+        game_type: GameType,
+    ) -> Result<PlayerGameLog, NHLApiError> {
+        info!(
+            "MockClient: Returning mock game log for {} {} {}",
+            player_id, season, game_type
+        );
+        Ok(fixtures::create_mock_player_game_log(
+            player_id, season, game_type,
+        ))
+    }
+
     async fn franchises(&self) -> Result<Vec<Franchise>, NHLApiError> {
         info!("MockClient: Returning mock franchises");
         Ok(fixtures::create_mock_franchises())
@@ -0,0 +1,188 @@
+//! On-disk cache of API responses
+//!
+//! Responses are stored as JSON with the time they were fetched, one file per
+//! request, under the OS cache directory. An entry younger than the TTL is
+//! fresh and can be used without touching the network; an older one is stale
+//! but still worth showing while a new copy is fetched.
+use std::fs;
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use nhl_api::GameDate;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::error::Result;
+
+/// Cache key for the current league standings
+pub const STANDINGS_KEY: &str = "standings";
+
+/// Cache key for the final standings of a past season
+pub fn season_standings_key(season: i64) -> String {
+    format!("standings-{}", season)
+}
+
+/// Cache key for the schedule of a date
+///
+/// `GameDate::Now` has no key: its meaning changes at midnight.
+pub fn schedule_key(date: &GameDate) -> Option<String> {
+    match date {
+        GameDate::Now => None,
+        GameDate::Date(date) => Some(format!("schedule-{}", date.format("%Y-%m-%d"))),
+    }
+}
+
+/// Cache key for the boxscore of a game
+pub fn boxscore_key(game_id: i64) -> String {
+    format!("boxscore-{}", game_id)
+}
+
+/// A cached response and whether it is still within the TTL
+#[derive(Debug, Clone, PartialEq)]
+pub struct CachedEntry<T> {
+    pub data: T,
+    pub fresh: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+struct StoredEntry<T> {
+    /// Seconds since the Unix epoch
+    fetched_at: u64,
+    data: T,
+}
+
+/// Directory of cached responses with a common TTL
+#[derive(Debug, Clone)]
+pub struct DiskCache {
+    dir: PathBuf,
+    ttl: Duration,
+}
+
+impl DiskCache {
+    pub fn new(dir: PathBuf, ttl: Duration) -> Self {
+        Self { dir, ttl }
+    }
+
+    /// Open the cache under the OS cache directory (e.g. `~/.cache/nhl`)
+    pub fn open(ttl: Duration) -> Option<Self> {
+        let dir = dirs::cache_dir()?.join(env!("CARGO_PKG_NAME"));
+        Some(Self::new(dir, ttl))
+    }
+
+    /// Read an entry, or None if it is missing or unreadable
+    pub fn get<T: DeserializeOwned>(&self, key: &str) -> Option<CachedEntry<T>> {
+        let content = fs::read_to_string(self.path(key)).ok()?;
+        let entry: StoredEntry<T> = serde_json::from_str(&content).ok()?;
+        let age = now_secs().saturating_sub(entry.fetched_at);
+        Some(CachedEntry {
+            data: entry.data,
+            fresh: age < self.ttl.as_secs(),
+        })
+    }
+
+    /// Store an entry, stamped with the current time
+    pub fn put<T: Serialize>(&self, key: &str, data: &T) -> Result<()> {
+        fs::create_dir_all(&self.dir)?;
+        let entry = StoredEntry {
+            fetched_at: now_secs(),
+            data,
+        };
+        fs::write(self.path(key), serde_json::to_string(&entry)?)?;
+        Ok(())
+    }
+
+    fn path(&self, key: &str) -> PathBuf {
+        self.dir.join(format!("{}.json", key))
+    }
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::Error;
+    use chrono::NaiveDate;
+    use std::env;
+
+    fn temp_cache(name: &str, ttl: Duration) -> DiskCache {
+        let dir = env::temp_dir().join(format!(
+            "nhl_disk_cache_test_{}_{}",
+            name,
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&dir);
+        DiskCache::new(dir, ttl)
+    }
+
+    #[test]
+    fn test_put_and_get() {
+        let cache = temp_cache("put_get", Duration::from_secs(60));
+        assert_eq!(cache.get::<Vec<String>>("standings"), None);
+
+        cache
+            .put("standings", &vec!["TOR".to_string(), "MTL".to_string()])
+            .unwrap();
+
+        assert_eq!(
+            cache.get::<Vec<String>>("standings"),
+            Some(CachedEntry {
+                data: vec!["TOR".to_string(), "MTL".to_string()],
+                fresh: true,
+            })
+        );
+        let _ = fs::remove_dir_all(&cache.dir);
+    }
+
+    #[test]
+    fn test_entry_past_ttl_is_stale() {
+        let cache = temp_cache("stale", Duration::ZERO);
+        cache.put("boxscore-1", &42).unwrap();
+
+        let entry = cache.get::<i32>("boxscore-1").unwrap();
+        assert_eq!(entry.data, 42);
+        assert!(!entry.fresh);
+        let _ = fs::remove_dir_all(&cache.dir);
+    }
+
+    #[test]
+    fn test_unreadable_entry_is_missing() {
+        let cache = temp_cache("unreadable", Duration::from_secs(60));
+        fs::create_dir_all(&cache.dir).unwrap();
+        fs::write(cache.path("standings"), "not json").unwrap();
+        assert_eq!(cache.get::<i32>("standings"), None);
+
+        // Shape changed since it was written
+        cache.put("standings", &"text").unwrap();
+        assert_eq!(cache.get::<i32>("standings"), None);
+        let _ = fs::remove_dir_all(&cache.dir);
+    }
+
+    #[test]
+    fn test_put_into_unwritable_dir_is_io_error() {
+        let cache = temp_cache("unwritable", Duration::from_secs(60));
+        // A file where the cache directory should be
+        fs::write(&cache.dir, "").unwrap();
+
+        let err = cache.put("standings", &42).unwrap_err();
+        assert!(matches!(err, Error::Io(_)));
+        let _ = fs::remove_file(&cache.dir);
+    }
+
+    #[test]
+    fn test_keys() {
+        let date = NaiveDate::from_ymd_opt(2024, 10, 8).unwrap();
+        assert_eq!(
+            schedule_key(&GameDate::Date(date)),
+            Some("schedule-2024-10-08".to_string())
+        );
+        assert_eq!(schedule_key(&GameDate::Now), None);
+        assert_eq!(boxscore_key(2024020001), "boxscore-2024020001");
+        assert_eq!(season_standings_key(20232024), "standings-20232024");
+    }
+}
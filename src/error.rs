@@ -0,0 +1,165 @@
+//! Crate-level error type for the export, cache, session and clipboard modules
+//!
+//! The CLI commands return `anyhow::Result`; `Error` implements `std::error::Error`,
+//! so it converts into `anyhow::Error` with `?` at those boundaries.
+
+use std::num::{ParseFloatError, ParseIntError};
+
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum Error {
+    /// Reading or writing a file failed
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// Data could not be parsed (numbers, dates, payloads)
+    #[error("Parse error: {0}")]
+    Parse(String),
+
+    /// A value could not be encoded to or decoded from JSON
+    #[error("JSON error: {0}")]
+    Json(#[from] serde_json::Error),
+
+    /// A value could not be encoded as TOML
+    #[error("TOML error: {0}")]
+    Toml(#[from] toml::ser::Error),
+
+    /// No config directory could be determined for the active profile
+    #[error("No config directory found")]
+    NoConfigDir,
+
+    /// A request to the NHL API failed
+    #[error("Network error: {0}")]
+    Network(#[from] nhl_api::NHLApiError),
+
+    /// Configuration is missing or invalid
+    #[error("Config error: {0}")]
+    Config(String),
+
+    /// The system clipboard could not be reached or written
+    #[error("Clipboard error: {0}")]
+    Clipboard(String),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl From<ParseIntError> for Error {
+    fn from(e: ParseIntError) -> Self {
+        Error::Parse(e.to_string())
+    }
+}
+
+impl From<ParseFloatError> for Error {
+    fn from(e: ParseFloatError) -> Self {
+        Error::Parse(e.to_string())
+    }
+}
+
+impl From<chrono::ParseError> for Error {
+    fn from(e: chrono::ParseError) -> Self {
+        Error::Parse(e.to_string())
+    }
+}
+
+impl From<toml::de::Error> for Error {
+    fn from(e: toml::de::Error) -> Self {
+        Error::Config(e.message().to_string())
+    }
+}
+
+#[cfg(feature = "clipboard")]
+impl From<arboard::Error> for Error {
+    fn from(e: arboard::Error) -> Self {
+        Error::Clipboard(e.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io;
+
+    fn parse_number(s: &str) -> Result<i32> {
+        Ok(s.parse::<i32>()?)
+    }
+
+    #[test]
+    fn test_io_conversion() {
+        let err: Error = io::Error::new(io::ErrorKind::NotFound, "missing.md").into();
+        assert!(matches!(err, Error::Io(_)));
+        assert_eq!(err.to_string(), "I/O error: missing.md");
+    }
+
+    #[test]
+    fn test_parse_conversions() {
+        let err = parse_number("abc").unwrap_err();
+        assert!(matches!(err, Error::Parse(_)));
+        assert_eq!(
+            err.to_string(),
+            "Parse error: invalid digit found in string"
+        );
+
+        let err: Error = "x".parse::<f64>().unwrap_err().into();
+        assert_eq!(err.to_string(), "Parse error: invalid float literal");
+
+        let err: Error = chrono::NaiveDate::parse_from_str("2024-13-01", "%Y-%m-%d")
+            .unwrap_err()
+            .into();
+        assert_eq!(err.to_string(), "Parse error: input is out of range");
+    }
+
+    #[test]
+    fn test_json_conversion() {
+        let err: Error = serde_json::from_str::<i32>("[").unwrap_err().into();
+        assert!(matches!(err, Error::Json(_)));
+        assert!(err.to_string().starts_with("JSON error: "));
+    }
+
+    #[test]
+    fn test_network_conversion() {
+        let err: Error = nhl_api::NHLApiError::ResourceNotFound {
+            message: "game 1".to_string(),
+            status_code: 404,
+        }
+        .into();
+        assert!(matches!(err, Error::Network(_)));
+        assert_eq!(err.to_string(), "Network error: Resource not found: game 1");
+    }
+
+    #[test]
+    fn test_config_conversion() {
+        let err: Error = toml::from_str::<toml::Table>("refresh_interval = ")
+            .unwrap_err()
+            .into();
+        assert!(matches!(err, Error::Config(_)));
+        assert!(err.to_string().starts_with("Config error: "));
+
+        let err = Error::Config("unknown theme \"teal\"".to_string());
+        assert_eq!(err.to_string(), "Config error: unknown theme \"teal\"");
+    }
+
+    #[test]
+    fn test_session_errors() {
+        assert_eq!(Error::NoConfigDir.to_string(), "No config directory found");
+
+        let err: Error = toml::to_string(&1).unwrap_err().into();
+        assert!(matches!(err, Error::Toml(_)));
+        assert!(err.to_string().starts_with("TOML error: "));
+    }
+
+    #[test]
+    fn test_clipboard_error() {
+        let err = Error::Clipboard("clipboard lock poisoned".to_string());
+        assert_eq!(err.to_string(), "Clipboard error: clipboard lock poisoned");
+    }
+
+    #[test]
+    fn test_converts_into_anyhow() {
+        fn load() -> anyhow::Result<i32> {
+            Ok(parse_number("7x")?)
+        }
+        let err = load().unwrap_err();
+        assert!(err.downcast_ref::<Error>().is_some());
+    }
+}
@@ -7,9 +7,10 @@
 ///
 /// The fixtures represent realistic NHL data with all 32 teams and various game states.
 use nhl_api::{
-    Boxscore, BoxscoreTeam, DailySchedule, Franchise, GameClock, GameDate, GameMatchup, GameState,
-    Handedness, LocalizedString, PeriodDescriptor, PeriodType, PlayerByGameStats, PlayerLanding,
-    Position, ScheduleGame, ScheduleTeam, Standing, TeamPlayerStats,
+    Boxscore, BoxscoreTeam, DailySchedule, FeaturedStats, Franchise, GameClock, GameDate, GameLog,
+    GameMatchup, GameState, GameType, Handedness, HomeRoad, LocalizedString, PeriodDescriptor,
+    PeriodType, PlayerByGameStats, PlayerGameLog, PlayerLanding, PlayerStats, Position,
+    ScheduleGame, ScheduleTeam, Standing, TeamPlayerStats, TeamScheduleResponse,
 };
 
 /// Create mock standings data - reusing the test data structure
@@ -317,6 +318,37 @@ fn create_game_summary(_period: i32, _away_score: i32, _home_score: i32) -> nhl_
     }
 }
 
+/// Create a goal for a game summary's scoring list
+pub fn create_goal_summary(team_abbrev: &str, last_name: &str, time: &str) -> nhl_api::GoalSummary {
+    let localized = |s: &str| LocalizedString {
+        default: s.to_string(),
+    };
+    nhl_api::GoalSummary {
+        situation_code: "1551".to_string(),
+        event_id: 1,
+        strength: "ev".to_string(),
+        player_id: 8478402,
+        first_name: localized("Test"),
+        last_name: localized(last_name),
+        name: localized(&format!("T. {}", last_name)),
+        team_abbrev: localized(team_abbrev),
+        headshot: String::new(),
+        highlight_clip_sharing_url: None,
+        highlight_clip: None,
+        discrete_clip: None,
+        goals_to_date: Some(1),
+        away_score: 0,
+        home_score: 0,
+        leading_team_abbrev: None,
+        time_in_period: time.to_string(),
+        shot_type: "wrist".to_string(),
+        goal_modifier: "none".to_string(),
+        assists: Vec::new(),
+        home_team_defending_side: nhl_api::DefendingSide::Left,
+        is_home: false,
+    }
+}
+
 /// Create mock boxscore
 pub fn create_mock_boxscore(game_id: i64) -> Boxscore {
     let is_live = game_id == 2024020002 || game_id == 2024020003 || game_id == 2024020004;
@@ -487,6 +519,41 @@ pub fn create_mock_club_stats(
     }
 }
 
+/// Create mock roster, empty like the mock club stats
+pub fn create_mock_roster(_team: &str) -> nhl_api::Roster {
+    nhl_api::Roster {
+        forwards: vec![],
+        defensemen: vec![],
+        goalies: vec![],
+    }
+}
+
+/// Create a mock week of games for a team: two completed, one live, one upcoming
+pub fn create_mock_team_schedule(team: &str) -> TeamScheduleResponse {
+    let games = [
+        ("2024-11-18", "MTL", true, GameState::Final),
+        ("2024-11-20", "TOR", false, GameState::Final),
+        ("2024-11-22", "OTT", true, GameState::Live),
+        ("2024-11-24", "NYR", false, GameState::Future),
+    ];
+    let games = games
+        .into_iter()
+        .enumerate()
+        .map(|(i, (date, opponent, home, state))| {
+            let (away, home) = if home {
+                (opponent, team)
+            } else {
+                (team, opponent)
+            };
+            let mut game = create_mock_game(2024020100 + i as i64, away, home, state);
+            game.game_date = Some(date.to_string());
+            game.start_time_utc = format!("{}T00:00:00Z", date);
+            game
+        })
+        .collect();
+    TeamScheduleResponse { games }
+}
+
 /// Create mock player landing
 pub fn create_mock_player_landing(player_id: i64) -> PlayerLanding {
     PlayerLanding {
@@ -517,10 +584,83 @@ pub fn create_mock_player_landing(player_id: i64) -> PlayerLanding {
         shoots_catches: Handedness::Left,
         draft_details: None,
         player_slug: Some("connor-mcdavid-8478402".to_string()),
-        featured_stats: None,
+        featured_stats: Some(FeaturedStats {
+            season: 20242025,
+            regular_season: PlayerStats {
+                games_played: Some(5),
+                goals: Some(2),
+                assists: Some(4),
+                points: Some(6),
+                plus_minus: Some(-3),
+                pim: Some(0),
+                power_play_goals: None,
+                power_play_points: None,
+                short_handed_goals: None,
+                short_handed_points: None,
+                shots: Some(15),
+                shooting_pctg: None,
+                faceoff_win_pctg: None,
+                avg_toi: None,
+                wins: None,
+                losses: None,
+                ot_losses: None,
+                shutouts: None,
+                goals_against_avg: None,
+                save_pctg: None,
+            },
+            playoffs: None,
+        }),
         career_totals: None,
         season_totals: None,
         awards: None,
         last_five_games: None,
     }
 }
+
+/// Create a mock game log of five games, most recent first
+pub fn create_mock_player_game_log(
+    player_id: i64,
+    season: i32,
+    game_type: GameType,
+) -> PlayerGameLog {
+    let opponents = [
+        ("TOR", HomeRoad::Home),
+        ("MTL", HomeRoad::Road),
+        ("BOS", HomeRoad::Home),
+        ("OTT", HomeRoad::Road),
+        ("NJD", HomeRoad::Home),
+    ];
+    let game_log = opponents
+        .iter()
+        .enumerate()
+        .map(|(i, (opponent, home_road))| {
+            let goals = (i % 2) as i32;
+            let assists = (i % 3) as i32;
+            GameLog {
+                game_id: 2024020100 - i as i64,
+                game_date: format!("2024-11-{:02}", 20 - 2 * i),
+                team_abbrev: "EDM".to_string(),
+                home_road_flag: *home_road,
+                opponent_abbrev: opponent.to_string(),
+                goals,
+                assists,
+                points: goals + assists,
+                plus_minus: goals - 1,
+                power_play_goals: 0,
+                shots: 3,
+                shifts: 22,
+                toi: "21:34".to_string(),
+                game_winning_goals: None,
+                ot_goals: None,
+                pim: None,
+            }
+        })
+        .collect();
+
+    PlayerGameLog {
+        player_id,
+        season,
+        game_type,
+        game_log,
+    }
+}
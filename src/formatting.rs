@@ -1,4 +1,8 @@
-use crate::config::DisplayConfig;
+use std::collections::BTreeMap;
+
+use unicode_width::UnicodeWidthStr;
+
+use crate::config::{BoxStyle, DisplayConfig};
 
 /// Box-drawing characters for table borders
 #[derive(Debug, Clone, PartialEq)]
@@ -43,6 +47,18 @@ pub struct BoxChars {
     pub connector3: String,
     pub selector: String,
     pub breadcrumb_separator: String,
+
+    // Proportional bar segments (first and second value of a comparison)
+    pub bar_primary: String,
+    pub bar_secondary: String,
+
+    // Markers for content clipped off the left/right edge
+    pub clip_left: String,
+    pub clip_right: String,
+
+    // Scrollbar of documents taller than their viewport
+    pub scrollbar_track: String,
+    pub scrollbar_thumb: String,
 }
 
 impl BoxChars {
@@ -88,6 +104,68 @@ impl BoxChars {
             connector3: "┬".to_string(),
             selector: "▶".to_string(),
             breadcrumb_separator: "▶".to_string(),
+
+            // Bars
+            bar_primary: "█".to_string(),
+            bar_secondary: "░".to_string(),
+
+            // Clipping markers
+            clip_left: "◂".to_string(),
+            clip_right: "▸".to_string(),
+
+            // Scrollbar
+            scrollbar_track: "│".to_string(),
+            scrollbar_thumb: "█".to_string(),
+        }
+    }
+
+    /// Unicode box characters with sharp (`┌┐└┘`) instead of rounded corners
+    pub fn unicode_square() -> Self {
+        Self {
+            top_left: "┌".to_string(),
+            top_right: "┐".to_string(),
+            bottom_left: "└".to_string(),
+            bottom_right: "┘".to_string(),
+            ..Self::unicode()
+        }
+    }
+
+    /// Unicode box characters with heavy single lines for high-contrast displays
+    ///
+    /// Double lines stay double. Heavy and double lines never meet in a single
+    /// glyph, so the mixed double-horizontal corners use the heavy corners and the
+    /// mixed double-vertical junctions use the full double ones.
+    pub fn unicode_heavy() -> Self {
+        Self {
+            // Single-line
+            horizontal: "━".to_string(),
+            vertical: "┃".to_string(),
+            top_left: "┏".to_string(),
+            top_right: "┓".to_string(),
+            bottom_left: "┗".to_string(),
+            bottom_right: "┛".to_string(),
+            top_junction: "┳".to_string(),
+            bottom_junction: "┻".to_string(),
+            left_junction: "┣".to_string(),
+            right_junction: "┫".to_string(),
+            cross: "╋".to_string(),
+
+            // Mixed (double vertical, heavy horizontal)
+            mixed_left_junction: "╠".to_string(),
+            mixed_right_junction: "╣".to_string(),
+
+            // Mixed (double horizontal, heavy vertical)
+            mixed_dh_top_left: "┏".to_string(),
+            mixed_dh_top_right: "┓".to_string(),
+            mixed_dh_bottom_left: "┗".to_string(),
+            mixed_dh_bottom_right: "┛".to_string(),
+            mixed_dh_left_t: "┣".to_string(),
+            mixed_dh_right_t: "┫".to_string(),
+
+            // Other
+            connector2: "┻".to_string(),
+            connector3: "┳".to_string(),
+            ..Self::unicode()
         }
     }
 
@@ -133,14 +211,98 @@ impl BoxChars {
             connector3: "-".to_string(),
             selector: ">".to_string(),
             breadcrumb_separator: ">".to_string(),
+
+            // Bars
+            bar_primary: "#".to_string(),
+            bar_secondary: "-".to_string(),
+
+            // Clipping markers
+            clip_left: "<".to_string(),
+            clip_right: ">".to_string(),
+
+            // Scrollbar
+            scrollbar_track: "|".to_string(),
+            scrollbar_thumb: "#".to_string(),
         }
     }
 
-    pub fn from_use_unicode(use_unicode: bool) -> Self {
-        if use_unicode {
-            Self::unicode()
-        } else {
-            Self::ascii()
+    /// Replace preset characters with user overrides keyed by field name
+    ///
+    /// Every field is overridable. A value may span several code points (e.g. a
+    /// letter plus a combining mark) but must be exactly one column wide, since
+    /// borders and markers are laid out one cell at a time. Returns a warning for
+    /// each unknown key or rejected value; those keys keep the preset character.
+    pub fn apply_overrides(&mut self, overrides: &BTreeMap<String, String>) -> Vec<String> {
+        let mut warnings = Vec::new();
+        for (key, value) in overrides {
+            let Some(field) = self.field_mut(key) else {
+                warnings.push(format!("Ignoring unknown box override '{}'", key));
+                continue;
+            };
+            if value.width() != 1 {
+                warnings.push(format!(
+                    "Ignoring box override '{}': '{}' is not one column wide",
+                    key, value
+                ));
+                continue;
+            }
+            *field = value.clone();
+        }
+        warnings
+    }
+
+    fn field_mut(&mut self, key: &str) -> Option<&mut String> {
+        let field = match key {
+            "horizontal" => &mut self.horizontal,
+            "vertical" => &mut self.vertical,
+            "top_left" => &mut self.top_left,
+            "top_right" => &mut self.top_right,
+            "bottom_left" => &mut self.bottom_left,
+            "bottom_right" => &mut self.bottom_right,
+            "top_junction" => &mut self.top_junction,
+            "bottom_junction" => &mut self.bottom_junction,
+            "left_junction" => &mut self.left_junction,
+            "right_junction" => &mut self.right_junction,
+            "cross" => &mut self.cross,
+            "double_horizontal" => &mut self.double_horizontal,
+            "double_vertical" => &mut self.double_vertical,
+            "double_top_left" => &mut self.double_top_left,
+            "double_top_right" => &mut self.double_top_right,
+            "double_bottom_left" => &mut self.double_bottom_left,
+            "double_bottom_right" => &mut self.double_bottom_right,
+            "double_top_junction" => &mut self.double_top_junction,
+            "double_bottom_junction" => &mut self.double_bottom_junction,
+            "mixed_left_junction" => &mut self.mixed_left_junction,
+            "mixed_right_junction" => &mut self.mixed_right_junction,
+            "mixed_dh_top_left" => &mut self.mixed_dh_top_left,
+            "mixed_dh_top_right" => &mut self.mixed_dh_top_right,
+            "mixed_dh_bottom_left" => &mut self.mixed_dh_bottom_left,
+            "mixed_dh_bottom_right" => &mut self.mixed_dh_bottom_right,
+            "mixed_dh_left_t" => &mut self.mixed_dh_left_t,
+            "mixed_dh_right_t" => &mut self.mixed_dh_right_t,
+            "connector2" => &mut self.connector2,
+            "connector3" => &mut self.connector3,
+            "selector" => &mut self.selector,
+            "breadcrumb_separator" => &mut self.breadcrumb_separator,
+            "bar_primary" => &mut self.bar_primary,
+            "bar_secondary" => &mut self.bar_secondary,
+            "clip_left" => &mut self.clip_left,
+            "clip_right" => &mut self.clip_right,
+            "scrollbar_track" => &mut self.scrollbar_track,
+            "scrollbar_thumb" => &mut self.scrollbar_thumb,
+            _ => return None,
+        };
+        Some(field)
+    }
+
+    pub fn from_use_unicode(use_unicode: bool, style: BoxStyle) -> Self {
+        if !use_unicode {
+            return Self::ascii();
+        }
+        match style {
+            BoxStyle::Rounded => Self::unicode(),
+            BoxStyle::Square => Self::unicode_square(),
+            BoxStyle::Heavy => Self::unicode_heavy(),
         }
     }
 }
@@ -163,10 +325,51 @@ pub fn format_header(text: &str, double_line: bool, display: &DisplayConfig) ->
     format!("{}\n{}\n", text, separator_char.repeat(text.len()))
 }
 
+/// Format a count, putting `separator` between groups of three digits when given
+///
+/// `format_count(1234567, Some(','))` is "1,234,567"; without a separator the
+/// number is written as usual.
+pub fn format_count(value: impl Into<i64>, separator: Option<char>) -> String {
+    let value = value.into();
+    let Some(separator) = separator else {
+        return value.to_string();
+    };
+
+    let digits = value.unsigned_abs().to_string();
+    let mut grouped = String::with_capacity(digits.len() + digits.len() / 3 * separator.len_utf8());
+    if value < 0 {
+        grouped.push('-');
+    }
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i) % 3 == 0 {
+            grouped.push(separator);
+        }
+        grouped.push(digit);
+    }
+    grouped
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_format_count_grouped() {
+        assert_eq!(format_count(0, Some(',')), "0");
+        assert_eq!(format_count(999, Some(',')), "999");
+        assert_eq!(format_count(1000, Some(',')), "1,000");
+        assert_eq!(format_count(1_234_567, Some(',')), "1,234,567");
+        assert_eq!(format_count(-12_345, Some(',')), "-12,345");
+        assert_eq!(format_count(1887, Some('.')), "1.887");
+        assert_eq!(format_count(1887, Some('\u{202f}')), "1\u{202f}887");
+    }
+
+    #[test]
+    fn test_format_count_ungrouped() {
+        assert_eq!(format_count(1_234_567, None), "1234567");
+        assert_eq!(format_count(-1000, None), "-1000");
+    }
+
     #[test]
     fn test_format_header_single_line_unicode() {
         let display = DisplayConfig {
@@ -231,4 +434,87 @@ mod tests {
             "This is a very long header text\n═══════════════════════════════\n"
         );
     }
+
+    #[test]
+    fn test_unicode_square_only_changes_corners() {
+        let square = BoxChars::unicode_square();
+        assert_eq!(
+            [
+                square.top_left.as_str(),
+                square.top_right.as_str(),
+                square.bottom_left.as_str(),
+                square.bottom_right.as_str(),
+            ],
+            ["┌", "┐", "└", "┘"]
+        );
+        assert_eq!(
+            BoxChars {
+                top_left: "╭".to_string(),
+                top_right: "╮".to_string(),
+                bottom_left: "╰".to_string(),
+                bottom_right: "╯".to_string(),
+                ..square
+            },
+            BoxChars::unicode()
+        );
+    }
+
+    #[test]
+    fn test_from_use_unicode() {
+        assert_eq!(
+            BoxChars::from_use_unicode(true, BoxStyle::Rounded),
+            BoxChars::unicode()
+        );
+        assert_eq!(
+            BoxChars::from_use_unicode(true, BoxStyle::Square),
+            BoxChars::unicode_square()
+        );
+        assert_eq!(
+            BoxChars::from_use_unicode(true, BoxStyle::Heavy),
+            BoxChars::unicode_heavy()
+        );
+        assert_eq!(
+            BoxChars::from_use_unicode(false, BoxStyle::Heavy),
+            BoxChars::ascii()
+        );
+    }
+
+    #[test]
+    fn test_apply_overrides() {
+        let overrides = BTreeMap::from([
+            ("selector".to_string(), "→".to_string()),
+            // e + combining acute accent: two code points, one column
+            ("clip_left".to_string(), "e\u{301}".to_string()),
+        ]);
+        let mut chars = BoxChars::unicode();
+
+        let warnings = chars.apply_overrides(&overrides);
+
+        assert!(warnings.is_empty());
+        assert_eq!(chars.selector, "→");
+        assert_eq!(chars.clip_left, "e\u{301}");
+        assert_eq!(chars.horizontal, "─");
+    }
+
+    #[test]
+    fn test_apply_overrides_rejects_unknown_keys_and_wide_values() {
+        let overrides = BTreeMap::from([
+            ("checkmark".to_string(), "√".to_string()),
+            ("vertical".to_string(), "||".to_string()),
+            ("cross".to_string(), String::new()),
+        ]);
+        let mut chars = BoxChars::unicode();
+
+        let warnings = chars.apply_overrides(&overrides);
+
+        assert_eq!(
+            warnings,
+            vec![
+                "Ignoring unknown box override 'checkmark'".to_string(),
+                "Ignoring box override 'cross': '' is not one column wide".to_string(),
+                "Ignoring box override 'vertical': '||' is not one column wide".to_string(),
+            ]
+        );
+        assert_eq!(chars, BoxChars::unicode());
+    }
 }
@@ -0,0 +1,332 @@
+//! User key bindings from the `[keybindings]` config section
+//!
+//! Each entry maps an action name to a key spec, e.g. `nav_down = "j"` or
+//! `refresh = "ctrl+r"`. Configured keys work alongside the defaults: the
+//! key handler translates them into the action's default key before matching.
+use std::collections::{BTreeMap, HashMap};
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+/// Actions that can be bound to extra keys
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KeyAction {
+    NavUp,
+    NavDown,
+    NavLeft,
+    NavRight,
+    Select,
+    GoBack,
+    Refresh,
+    Quit,
+    Help,
+    Search,
+}
+
+impl KeyAction {
+    /// Look up an action by its config name
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "nav_up" => Some(Self::NavUp),
+            "nav_down" => Some(Self::NavDown),
+            "nav_left" => Some(Self::NavLeft),
+            "nav_right" => Some(Self::NavRight),
+            "select" => Some(Self::Select),
+            "go_back" => Some(Self::GoBack),
+            "refresh" => Some(Self::Refresh),
+            "quit" => Some(Self::Quit),
+            "help" => Some(Self::Help),
+            "search" => Some(Self::Search),
+            _ => None,
+        }
+    }
+
+    /// Key the handlers match for this action
+    pub fn default_key(self) -> KeyCode {
+        match self {
+            Self::NavUp => KeyCode::Up,
+            Self::NavDown => KeyCode::Down,
+            Self::NavLeft => KeyCode::Left,
+            Self::NavRight => KeyCode::Right,
+            Self::Select => KeyCode::Enter,
+            Self::GoBack => KeyCode::Esc,
+            Self::Refresh => KeyCode::Char('r'),
+            Self::Quit => KeyCode::Char('q'),
+            Self::Help => KeyCode::Char('?'),
+            Self::Search => KeyCode::Char('/'),
+        }
+    }
+}
+
+/// Configured keys and the actions they trigger
+#[derive(Debug, Clone, Default)]
+pub struct KeyMap {
+    bindings: HashMap<(KeyCode, KeyModifiers), KeyAction>,
+}
+
+impl KeyMap {
+    /// Build the keymap from `[keybindings]` entries
+    ///
+    /// Returns a warning for each entry that was ignored.
+    pub fn from_config(entries: &BTreeMap<String, String>) -> (Self, Vec<String>) {
+        let mut keymap = Self::default();
+        let mut warnings = Vec::new();
+        for (name, spec) in entries {
+            let Some(action) = KeyAction::from_name(name) else {
+                warnings.push(format!(
+                    "Ignoring key binding for unknown action '{}'",
+                    name
+                ));
+                continue;
+            };
+            let Some(key) = parse_key_spec(spec) else {
+                warnings.push(format!("Ignoring invalid key '{}' for '{}'", spec, name));
+                continue;
+            };
+            keymap.bindings.insert(normalize(key), action);
+        }
+        (keymap, warnings)
+    }
+
+    /// Action bound to a key, if it was configured
+    pub fn action_for(&self, key: KeyEvent) -> Option<KeyAction> {
+        self.bindings.get(&normalize(key)).copied()
+    }
+
+    /// Replace a configured key with its action's default key; other keys pass through
+    ///
+    /// The key's modifiers are kept, so handlers that look at them still see them,
+    /// except the Shift that is already part of a configured character.
+    pub fn translate(&self, key: KeyEvent) -> KeyEvent {
+        self.action_for(key).map_or(key, |action| {
+            let (_, modifiers) = normalize(key);
+            KeyEvent {
+                code: action.default_key(),
+                modifiers,
+                ..key
+            }
+        })
+    }
+
+    /// Configured keys that stand in for `code`, in a stable order
+    pub fn keys_for(&self, code: KeyCode) -> Vec<KeyEvent> {
+        let mut keys: Vec<KeyEvent> = self
+            .bindings
+            .iter()
+            .filter(|(_, action)| action.default_key() == code)
+            .map(|((code, modifiers), _)| KeyEvent::new(*code, *modifiers))
+            .collect();
+        keys.sort_by_key(|key| (key.modifiers.bits(), key.code.to_string()));
+        keys
+    }
+}
+
+/// Shift is already part of the character for letters and symbols
+fn normalize(key: KeyEvent) -> (KeyCode, KeyModifiers) {
+    let mut modifiers = key.modifiers;
+    if matches!(key.code, KeyCode::Char(_)) {
+        modifiers.remove(KeyModifiers::SHIFT);
+    }
+    (key.code, modifiers)
+}
+
+/// Parse a key spec such as "j", "G", "esc", "pagedown" or "ctrl+r"
+///
+/// Named keys and modifiers are case-insensitive; single characters are not.
+pub fn parse_key_spec(spec: &str) -> Option<KeyEvent> {
+    let spec = spec.trim();
+    // The key itself may be '+', as in "+" or "ctrl++"
+    let (modifier_names, key) = if spec == "+" {
+        ("", "+")
+    } else if let Some(modifier_names) = spec.strip_suffix("++") {
+        (modifier_names, "+")
+    } else {
+        spec.rsplit_once('+').unwrap_or(("", spec))
+    };
+
+    let mut modifiers = KeyModifiers::NONE;
+    for modifier in modifier_names.split('+').filter(|name| !name.is_empty()) {
+        modifiers |= match modifier.to_lowercase().as_str() {
+            "ctrl" => KeyModifiers::CONTROL,
+            "alt" => KeyModifiers::ALT,
+            "shift" => KeyModifiers::SHIFT,
+            _ => return None,
+        };
+    }
+
+    let mut chars = key.chars();
+    let code = match (chars.next(), chars.next()) {
+        (Some(c), None) => KeyCode::Char(c),
+        _ => match key.to_lowercase().as_str() {
+            "up" => KeyCode::Up,
+            "down" => KeyCode::Down,
+            "left" => KeyCode::Left,
+            "right" => KeyCode::Right,
+            "enter" => KeyCode::Enter,
+            "esc" | "escape" => KeyCode::Esc,
+            "tab" => KeyCode::Tab,
+            "backtab" => KeyCode::BackTab,
+            "backspace" => KeyCode::Backspace,
+            "delete" => KeyCode::Delete,
+            "space" => KeyCode::Char(' '),
+            "home" => KeyCode::Home,
+            "end" => KeyCode::End,
+            "pageup" => KeyCode::PageUp,
+            "pagedown" => KeyCode::PageDown,
+            name => {
+                let number = name.strip_prefix('f')?.parse().ok()?;
+                if !(1..=12).contains(&number) {
+                    return None;
+                }
+                KeyCode::F(number)
+            }
+        },
+    };
+    Some(KeyEvent::new(code, modifiers))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyEventKind;
+
+    fn entries(pairs: &[(&str, &str)]) -> BTreeMap<String, String> {
+        pairs
+            .iter()
+            .map(|(name, spec)| (name.to_string(), spec.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_key_spec() {
+        assert_eq!(
+            parse_key_spec("j"),
+            Some(KeyEvent::from(KeyCode::Char('j')))
+        );
+        assert_eq!(
+            parse_key_spec("G"),
+            Some(KeyEvent::from(KeyCode::Char('G')))
+        );
+        assert_eq!(parse_key_spec("Esc"), Some(KeyEvent::from(KeyCode::Esc)));
+        assert_eq!(parse_key_spec("f5"), Some(KeyEvent::from(KeyCode::F(5))));
+        assert_eq!(
+            parse_key_spec("ctrl+r"),
+            Some(KeyEvent::new(KeyCode::Char('r'), KeyModifiers::CONTROL))
+        );
+        assert_eq!(
+            parse_key_spec("ctrl++"),
+            Some(KeyEvent::new(KeyCode::Char('+'), KeyModifiers::CONTROL))
+        );
+        assert_eq!(
+            parse_key_spec("+"),
+            Some(KeyEvent::from(KeyCode::Char('+')))
+        );
+        assert_eq!(
+            parse_key_spec("é"),
+            Some(KeyEvent::from(KeyCode::Char('é')))
+        );
+    }
+
+    #[test]
+    fn test_parse_key_spec_invalid() {
+        assert_eq!(parse_key_spec(""), None);
+        assert_eq!(parse_key_spec("jk"), None);
+        assert_eq!(parse_key_spec("hyper+j"), None);
+        assert_eq!(parse_key_spec("f13"), None);
+    }
+
+    #[test]
+    fn test_translate_configured_keys() {
+        let (keymap, warnings) = KeyMap::from_config(&entries(&[
+            ("nav_down", "j"),
+            ("go_back", "backspace"),
+            ("refresh", "ctrl+r"),
+        ]));
+        assert!(warnings.is_empty());
+
+        let translate = |key| keymap.translate(key).code;
+        assert_eq!(translate(KeyEvent::from(KeyCode::Char('j'))), KeyCode::Down);
+        assert_eq!(translate(KeyEvent::from(KeyCode::Backspace)), KeyCode::Esc);
+        assert_eq!(
+            translate(KeyEvent::new(KeyCode::Char('r'), KeyModifiers::CONTROL)),
+            KeyCode::Char('r')
+        );
+        // Defaults and unbound keys pass through unchanged
+        assert_eq!(translate(KeyEvent::from(KeyCode::Down)), KeyCode::Down);
+        assert_eq!(
+            translate(KeyEvent::from(KeyCode::Char('k'))),
+            KeyCode::Char('k')
+        );
+    }
+
+    #[test]
+    fn test_translate_keeps_modifiers_and_kind() {
+        let (keymap, _) = KeyMap::from_config(&entries(&[("nav_down", "alt+j")]));
+
+        let key =
+            KeyEvent::new_with_kind(KeyCode::Char('j'), KeyModifiers::ALT, KeyEventKind::Repeat);
+        assert_eq!(
+            keymap.translate(key),
+            KeyEvent::new_with_kind(KeyCode::Down, KeyModifiers::ALT, KeyEventKind::Repeat)
+        );
+    }
+
+    #[test]
+    fn test_translate_drops_shift_of_uppercase_binding() {
+        let (keymap, _) = KeyMap::from_config(&entries(&[("nav_down", "J")]));
+
+        // Shift+Down scrolls instead of moving focus, so the Shift must not carry over
+        assert_eq!(
+            keymap.translate(KeyEvent::new(KeyCode::Char('J'), KeyModifiers::SHIFT)),
+            KeyEvent::from(KeyCode::Down)
+        );
+    }
+
+    #[test]
+    fn test_keys_for_default_key() {
+        let (keymap, _) = KeyMap::from_config(&entries(&[
+            ("nav_down", "j"),
+            ("select", "space"),
+            ("refresh", "ctrl+r"),
+        ]));
+
+        assert_eq!(
+            keymap.keys_for(KeyCode::Down),
+            vec![KeyEvent::from(KeyCode::Char('j'))]
+        );
+        assert_eq!(
+            keymap.keys_for(KeyCode::Char('r')),
+            vec![KeyEvent::new(KeyCode::Char('r'), KeyModifiers::CONTROL)]
+        );
+        assert!(keymap.keys_for(KeyCode::Up).is_empty());
+    }
+
+    #[test]
+    fn test_shifted_characters_match() {
+        let (keymap, _) = KeyMap::from_config(&entries(&[("nav_down", "G")]));
+
+        let key = KeyEvent::new(KeyCode::Char('G'), KeyModifiers::SHIFT);
+        assert_eq!(keymap.action_for(key), Some(KeyAction::NavDown));
+    }
+
+    #[test]
+    fn test_invalid_bindings_warn_and_fall_back() {
+        let (keymap, warnings) = KeyMap::from_config(&entries(&[
+            ("jump", "j"),
+            ("nav_up", "hyper+k"),
+            ("nav_left", "h"),
+        ]));
+
+        assert_eq!(
+            warnings,
+            vec![
+                "Ignoring key binding for unknown action 'jump'".to_string(),
+                "Ignoring invalid key 'hyper+k' for 'nav_up'".to_string(),
+            ]
+        );
+        assert_eq!(
+            keymap.action_for(KeyEvent::from(KeyCode::Char('h'))),
+            Some(KeyAction::NavLeft)
+        );
+        assert_eq!(keymap.action_for(KeyEvent::from(KeyCode::Char('k'))), None);
+    }
+}
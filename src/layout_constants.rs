@@ -15,6 +15,9 @@ pub const SCORE_BOX_WIDTH: u16 = 25;
 /// Height of the compact ScoreBox widget (6 rows: 1 status + 5 box)
 pub const SCORE_BOX_HEIGHT: u16 = 6;
 
+/// Width of a ScoreBox in the one-line compact layout
+pub const SCORE_LINE_WIDTH: u16 = 58;
+
 /// Total width of a game box including margins
 pub const GAME_BOX_WITH_MARGIN: u16 = GAME_BOX_WIDTH + GAME_BOX_MARGIN;
 
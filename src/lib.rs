@@ -3,9 +3,14 @@ pub mod cache;
 pub mod commands;
 pub mod config;
 pub mod data_provider;
+pub mod disk_cache;
+pub mod error;
 pub mod formatting;
+pub mod keybindings;
 pub mod layout_constants;
+pub mod season;
 pub mod team_abbrev;
+pub mod timezone;
 pub mod tui;
 pub mod types;
 
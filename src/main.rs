@@ -1,5 +1,5 @@
 use nhl::commands;
-use nhl::config;
+use nhl::config::{self, DEFAULT_LOG_LEVEL};
 use nhl::data_provider::NHLDataProvider;
 use nhl::tui;
 
@@ -13,9 +13,6 @@ use tracing::Level;
 use tracing_subscriber::FmtSubscriber;
 
 // Default Configuration Constants
-/// Default log level when not specified
-const DEFAULT_LOG_LEVEL: &str = "info";
-
 /// Default log file path (no logging to file)
 const DEFAULT_LOG_FILE: &str = "/dev/null";
 
@@ -34,6 +31,10 @@ struct Cli {
     #[arg(short = 'F', long, global = true, default_value = DEFAULT_LOG_FILE)]
     log_file: String,
 
+    /// Use a named profile (~/.config/nhl/profiles/<name>/) for config and session
+    #[arg(short = 'P', long, global = true)]
+    profile: Option<String>,
+
     /// Use mock data instead of real API calls (development feature only)
     #[cfg(feature = "development")]
     #[arg(long, global = true)]
@@ -173,6 +174,9 @@ fn handle_config_command() {
         path_str,
         if exists { "yes" } else { "no" }
     );
+    if let Some(profile) = config::active_profile() {
+        println!("Profile: {}", profile);
+    }
     println!();
     println!("Current Configuration:");
     println!("=====================");
@@ -184,6 +188,7 @@ fn handle_config_command() {
         cfg.display_standings_western_first
     );
     println!("time_format: {}", cfg.time_format);
+    println!("start_time_format: {}", cfg.start_time_format);
     println!();
     println!("[theme]");
 }
@@ -226,7 +231,7 @@ async fn execute_command(
             commands::standings::run(client, season, date, group_by, config).await
         }
         Commands::Boxscore { game_id } => commands::boxscore::run(client, game_id, config).await,
-        Commands::Schedule { date } => commands::schedule::run(client, date).await,
+        Commands::Schedule { date } => commands::schedule::run(client, date, config).await,
         Commands::Scores { date } => commands::scores::run(client, date).await,
         Commands::Franchises => commands::franchises::run(client).await,
     }
@@ -234,14 +239,25 @@ async fn execute_command(
 
 #[tokio::main]
 async fn main() {
-    let config = config::read();
     let cli = Cli::parse();
 
+    // Profile must be selected before the config file is resolved
+    if let Some(profile) = &cli.profile {
+        if let Err(e) = config::set_profile(profile) {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
+        }
+    }
+    let config = config::read();
+
     // Resolve and initialize logging
     let (log_level, log_file) = resolve_log_config(&cli, &config);
     if log_file != DEFAULT_LOG_FILE {
         init_logging(log_level, log_file);
     }
+    for warning in &config.warnings {
+        tracing::warn!("Config: {}", warning);
+    }
 
     // Extract mock flag (only available in development feature)
     #[cfg(feature = "development")]
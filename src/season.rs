@@ -0,0 +1,57 @@
+//! NHL season ids such as 20242025 (start year followed by end year)
+
+use chrono::{Datelike, NaiveDate};
+
+/// First month of a new season, counting preseason
+const SEASON_START_MONTH: u32 = 9;
+
+/// Oldest season the standings can be moved back to
+pub const FIRST_SEASON: i64 = 19171918;
+
+/// Id of the season in progress on `today`, e.g. 20242025
+pub fn current_season_id(today: NaiveDate) -> i64 {
+    let start_year = if today.month() >= SEASON_START_MONTH {
+        today.year()
+    } else {
+        today.year() - 1
+    } as i64;
+    start_year * 10000 + start_year + 1
+}
+
+/// Format a season id as "2023-2024"
+pub fn season_label(season: i64) -> String {
+    format!("{}-{}", season / 10000, season % 10000)
+}
+
+/// Whether `season` is a `YYYY(YYYY+1)` id between the first season and `current`
+pub fn is_valid_season(season: i64, current: i64) -> bool {
+    let (start_year, end_year) = (season / 10000, season % 10000);
+    end_year == start_year + 1 && (FIRST_SEASON..=current).contains(&season)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_current_season_id() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        assert_eq!(current_season_id(date(2024, 8, 31)), 20232024);
+        assert_eq!(current_season_id(date(2024, 9, 1)), 20242025);
+        assert_eq!(current_season_id(date(2025, 4, 15)), 20242025);
+        assert_eq!(season_label(20232024), "2023-2024");
+    }
+
+    #[test]
+    fn test_is_valid_season() {
+        let current = 20242025;
+        assert!(is_valid_season(20232024, current));
+        assert!(is_valid_season(current, current));
+        assert!(is_valid_season(FIRST_SEASON, current));
+
+        assert!(!is_valid_season(2024, current));
+        assert!(!is_valid_season(20242024, current));
+        assert!(!is_valid_season(20252026, current));
+        assert!(!is_valid_season(19161917, current));
+    }
+}
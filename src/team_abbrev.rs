@@ -1,14 +1,33 @@
 //TODO this should go in nhl_api
 // everywhere in the api where there a team info without an abbreviation, we should add a team_abbrev field and make it automatically set with this lookup table
 
+use std::collections::HashSet;
+use std::sync::{LazyLock, Mutex};
+
+use ratatui::style::Color;
+use tracing::warn;
+
+/// Abbreviations of the current teams
+pub const CURRENT_TEAMS: [&str; 32] = [
+    "ANA", "BOS", "BUF", "CGY", "CAR", "CHI", "COL", "CBJ", "DAL", "DET", "EDM", "FLA", "LAK",
+    "MIN", "MTL", "NSH", "NJD", "NYI", "NYR", "OTT", "PHI", "PIT", "SJS", "SEA", "STL", "TBL",
+    "TOR", "VAN", "VGK", "WSH", "WPG", "UTA",
+];
+
+/// Abbreviations already reported as missing from the lookup tables
+static WARNED_ABBREVS: LazyLock<Mutex<HashSet<String>>> =
+    LazyLock::new(|| Mutex::new(HashSet::new()));
+
 /// Map team common name to team abbreviation
 ///
 /// This function maps NHL team common names (e.g., "Maple Leafs")
 /// to their standard 3-letter abbreviations (e.g., "TOR").
+/// Names shared across a relocation resolve to the latest abbreviation,
+/// so a bare "Coyotes" is ARI; the full "Phoenix Coyotes" is still PHX.
 pub fn common_name_to_abbrev(common_name: &str) -> Option<&'static str> {
     match common_name {
         "Ducks" => Some("ANA"),
-        "Coyotes" => Some("ARI"),
+        "Coyotes" | "Arizona Coyotes" => Some("ARI"),
         "Bruins" => Some("BOS"),
         "Sabres" => Some("BUF"),
         "Flames" => Some("CGY"),
@@ -39,10 +58,518 @@ pub fn common_name_to_abbrev(common_name: &str) -> Option<&'static str> {
         "Golden Knights" => Some("VGK"),
         "Capitals" => Some("WSH"),
         "Jets" => Some("WPG"),
-        "Hockey Club" => Some("UTA"),
+        // Utah played its first season as the Utah Hockey Club
+        "Mammoth" | "Utah Mammoth" | "Hockey Club" | "Utah Hockey Club" => Some("UTA"),
         // Historical teams
         "Phoenix Coyotes" => Some("PHX"),
-        "Atlanta Thrashers" => Some("ATL"),
+        "Thrashers" | "Atlanta Thrashers" => Some("ATL"),
         _ => None,
     }
 }
+
+/// Map team place name to team abbreviation
+///
+/// Place names as the API sends them (e.g. "Tampa Bay", "Montréal").
+/// "New York" is shared by the Rangers and Islanders, so it maps to None.
+pub fn place_name_to_abbrev(place_name: &str) -> Option<&'static str> {
+    match place_name {
+        "Anaheim" => Some("ANA"),
+        "Arizona" => Some("ARI"),
+        "Boston" => Some("BOS"),
+        "Buffalo" => Some("BUF"),
+        "Calgary" => Some("CGY"),
+        "Carolina" => Some("CAR"),
+        "Chicago" => Some("CHI"),
+        "Colorado" => Some("COL"),
+        "Columbus" => Some("CBJ"),
+        "Dallas" => Some("DAL"),
+        "Detroit" => Some("DET"),
+        "Edmonton" => Some("EDM"),
+        "Florida" => Some("FLA"),
+        "Los Angeles" => Some("LAK"),
+        "Minnesota" => Some("MIN"),
+        "Montréal" | "Montreal" => Some("MTL"),
+        "Nashville" => Some("NSH"),
+        "New Jersey" => Some("NJD"),
+        "Ottawa" => Some("OTT"),
+        "Philadelphia" => Some("PHI"),
+        "Pittsburgh" => Some("PIT"),
+        "San Jose" => Some("SJS"),
+        "Seattle" => Some("SEA"),
+        "St. Louis" => Some("STL"),
+        "Tampa Bay" => Some("TBL"),
+        "Toronto" => Some("TOR"),
+        "Vancouver" => Some("VAN"),
+        "Vegas" => Some("VGK"),
+        "Washington" => Some("WSH"),
+        "Winnipeg" => Some("WPG"),
+        "Utah" => Some("UTA"),
+        // Historical teams
+        "Phoenix" => Some("PHX"),
+        "Atlanta" => Some("ATL"),
+        _ => None,
+    }
+}
+
+/// Map team abbreviation to the place name the API uses for it
+///
+/// The inverse of `place_name_to_abbrev`; both New York clubs map to "New York".
+pub fn abbrev_to_place_name(abbrev: &str) -> Option<&'static str> {
+    match abbrev {
+        "ANA" => Some("Anaheim"),
+        "ARI" => Some("Arizona"),
+        "BOS" => Some("Boston"),
+        "BUF" => Some("Buffalo"),
+        "CGY" => Some("Calgary"),
+        "CAR" => Some("Carolina"),
+        "CHI" => Some("Chicago"),
+        "COL" => Some("Colorado"),
+        "CBJ" => Some("Columbus"),
+        "DAL" => Some("Dallas"),
+        "DET" => Some("Detroit"),
+        "EDM" => Some("Edmonton"),
+        "FLA" => Some("Florida"),
+        "LAK" => Some("Los Angeles"),
+        "MIN" => Some("Minnesota"),
+        "MTL" => Some("Montréal"),
+        "NSH" => Some("Nashville"),
+        "NJD" => Some("New Jersey"),
+        "NYI" | "NYR" => Some("New York"),
+        "OTT" => Some("Ottawa"),
+        "PHI" => Some("Philadelphia"),
+        "PIT" => Some("Pittsburgh"),
+        "SJS" => Some("San Jose"),
+        "SEA" => Some("Seattle"),
+        "STL" => Some("St. Louis"),
+        "TBL" => Some("Tampa Bay"),
+        "TOR" => Some("Toronto"),
+        "VAN" => Some("Vancouver"),
+        "VGK" => Some("Vegas"),
+        "WSH" => Some("Washington"),
+        "WPG" => Some("Winnipeg"),
+        "UTA" => Some("Utah"),
+        // Historical teams
+        "PHX" => Some("Phoenix"),
+        "ATL" => Some("Atlanta"),
+        _ => None,
+    }
+}
+
+/// Map team abbreviation to the team's full name, e.g. "TOR" to "Toronto Maple Leafs"
+pub fn abbrev_to_full_name(abbrev: &str) -> Option<&'static str> {
+    match abbrev {
+        "ANA" => Some("Anaheim Ducks"),
+        "ARI" => Some("Arizona Coyotes"),
+        "BOS" => Some("Boston Bruins"),
+        "BUF" => Some("Buffalo Sabres"),
+        "CGY" => Some("Calgary Flames"),
+        "CAR" => Some("Carolina Hurricanes"),
+        "CHI" => Some("Chicago Blackhawks"),
+        "COL" => Some("Colorado Avalanche"),
+        "CBJ" => Some("Columbus Blue Jackets"),
+        "DAL" => Some("Dallas Stars"),
+        "DET" => Some("Detroit Red Wings"),
+        "EDM" => Some("Edmonton Oilers"),
+        "FLA" => Some("Florida Panthers"),
+        "LAK" => Some("Los Angeles Kings"),
+        "MIN" => Some("Minnesota Wild"),
+        "MTL" => Some("Montréal Canadiens"),
+        "NSH" => Some("Nashville Predators"),
+        "NJD" => Some("New Jersey Devils"),
+        "NYI" => Some("New York Islanders"),
+        "NYR" => Some("New York Rangers"),
+        "OTT" => Some("Ottawa Senators"),
+        "PHI" => Some("Philadelphia Flyers"),
+        "PIT" => Some("Pittsburgh Penguins"),
+        "SJS" => Some("San Jose Sharks"),
+        "SEA" => Some("Seattle Kraken"),
+        "STL" => Some("St. Louis Blues"),
+        "TBL" => Some("Tampa Bay Lightning"),
+        "TOR" => Some("Toronto Maple Leafs"),
+        "VAN" => Some("Vancouver Canucks"),
+        "VGK" => Some("Vegas Golden Knights"),
+        "WSH" => Some("Washington Capitals"),
+        "WPG" => Some("Winnipeg Jets"),
+        "UTA" => Some("Utah Mammoth"),
+        // Historical teams
+        "PHX" => Some("Phoenix Coyotes"),
+        "ATL" => Some("Atlanta Thrashers"),
+        _ => None,
+    }
+}
+
+/// Current teams matching free-form user input, in `CURRENT_TEAMS` order
+///
+/// Matches abbreviations, common names and place names case-insensitively.
+/// Teams matching exactly are returned alone; otherwise every team with a
+/// name containing the input is returned.
+pub fn matching_teams(input: &str) -> Vec<&'static str> {
+    let input = input.trim().to_lowercase();
+    if input.is_empty() {
+        return Vec::new();
+    }
+
+    let names = |abbrev: &'static str| {
+        [
+            Some(abbrev),
+            abbrev_to_common_name(abbrev),
+            abbrev_to_place_name(abbrev),
+        ]
+        .into_iter()
+        .flatten()
+        .map(str::to_lowercase)
+    };
+    let matching = |matches: &dyn Fn(&str) -> bool| -> Vec<&'static str> {
+        CURRENT_TEAMS
+            .into_iter()
+            .filter(|abbrev| names(abbrev).any(|name| matches(&name)))
+            .collect()
+    };
+
+    let exact = matching(&|name| name == input);
+    if exact.is_empty() {
+        matching(&|name| name.contains(&input))
+    } else {
+        exact
+    }
+}
+
+/// Resolve free-form user input ("leafs", "toronto", "tor") to a current team
+///
+/// Uses the candidates from `matching_teams`; input matching more than one
+/// team returns None rather than guessing.
+pub fn resolve_team(input: &str) -> Option<&'static str> {
+    match matching_teams(input)[..] {
+        [abbrev] => Some(abbrev),
+        _ => None,
+    }
+}
+
+/// Map team abbreviation to team common name
+///
+/// The inverse of `common_name_to_abbrev` for current teams,
+/// e.g. "TOR" to "Maple Leafs".
+pub fn abbrev_to_common_name(abbrev: &str) -> Option<&'static str> {
+    match abbrev {
+        "ANA" => Some("Ducks"),
+        "ARI" => Some("Coyotes"),
+        "BOS" => Some("Bruins"),
+        "BUF" => Some("Sabres"),
+        "CGY" => Some("Flames"),
+        "CAR" => Some("Hurricanes"),
+        "CHI" => Some("Blackhawks"),
+        "COL" => Some("Avalanche"),
+        "CBJ" => Some("Blue Jackets"),
+        "DAL" => Some("Stars"),
+        "DET" => Some("Red Wings"),
+        "EDM" => Some("Oilers"),
+        "FLA" => Some("Panthers"),
+        "LAK" => Some("Kings"),
+        "MIN" => Some("Wild"),
+        "MTL" => Some("Canadiens"),
+        "NSH" => Some("Predators"),
+        "NJD" => Some("Devils"),
+        "NYI" => Some("Islanders"),
+        "NYR" => Some("Rangers"),
+        "OTT" => Some("Senators"),
+        "PHI" => Some("Flyers"),
+        "PIT" => Some("Penguins"),
+        "SJS" => Some("Sharks"),
+        "SEA" => Some("Kraken"),
+        "STL" => Some("Blues"),
+        "TBL" => Some("Lightning"),
+        "TOR" => Some("Maple Leafs"),
+        "VAN" => Some("Canucks"),
+        "VGK" => Some("Golden Knights"),
+        "WSH" => Some("Capitals"),
+        "WPG" => Some("Jets"),
+        "UTA" => Some("Mammoth"),
+        _ => None,
+    }
+}
+
+/// Name to display for a team abbreviation
+///
+/// Resolves known abbreviations to their common name. Otherwise uses the
+/// non-empty `fallback` (usually the name the API sent), or the raw abbreviation,
+/// and logs a warning the first time the abbreviation is seen so the lookup
+/// table can be updated.
+pub fn display_team_name(abbrev: &str, fallback: Option<&str>) -> String {
+    if let Some(name) = abbrev_to_common_name(abbrev) {
+        return name.to_string();
+    }
+    let mut warned = WARNED_ABBREVS.lock().unwrap_or_else(|e| e.into_inner());
+    if warned.insert(abbrev.to_string()) {
+        warn!(
+            "Unknown team abbreviation '{}', update team_abbrev.rs",
+            abbrev
+        );
+    }
+    match fallback {
+        Some(name) if !name.trim().is_empty() => name.to_string(),
+        _ => abbrev.to_string(),
+    }
+}
+
+/// A division alignment, in effect from `first_season` until the next one starts
+struct Alignment {
+    /// Season in the API's 8-digit form, e.g. 20242025
+    first_season: i64,
+    division: fn(&str) -> Option<&'static str>,
+}
+
+/// Alignments from oldest to newest; add past realignments at the front
+const ALIGNMENTS: &[Alignment] = &[
+    Alignment {
+        first_season: 20212022,
+        division: division_2021,
+    },
+    Alignment {
+        first_season: 20242025,
+        division: division_2024,
+    },
+];
+
+/// Seattle joins the Pacific and Arizona moves to the Central
+fn division_2021(abbrev: &str) -> Option<&'static str> {
+    match abbrev {
+        "ARI" => Some("Central"),
+        "UTA" => None,
+        _ => division_2024(abbrev),
+    }
+}
+
+/// Utah takes Arizona's place in the Central
+fn division_2024(abbrev: &str) -> Option<&'static str> {
+    match abbrev {
+        "BOS" | "BUF" | "DET" | "FLA" | "MTL" | "OTT" | "TBL" | "TOR" => Some("Atlantic"),
+        "CAR" | "CBJ" | "NJD" | "NYI" | "NYR" | "PHI" | "PIT" | "WSH" => Some("Metropolitan"),
+        "CHI" | "COL" | "DAL" | "MIN" | "NSH" | "STL" | "UTA" | "WPG" => Some("Central"),
+        "ANA" | "CGY" | "EDM" | "LAK" | "SEA" | "SJS" | "VAN" | "VGK" => Some("Pacific"),
+        _ => None,
+    }
+}
+
+/// Map team abbreviation to its division name for a season
+///
+/// `season` uses the API's 8-digit form (e.g. 20232024) and defaults to the
+/// current alignment. Returns None for unknown teams and for seasons older
+/// than the earliest known alignment.
+pub fn abbrev_to_division(abbrev: &str, season: Option<i64>) -> Option<&'static str> {
+    let alignment = match season {
+        Some(season) => ALIGNMENTS
+            .iter()
+            .rev()
+            .find(|alignment| alignment.first_season <= season)?,
+        None => ALIGNMENTS.last()?,
+    };
+    (alignment.division)(abbrev)
+}
+
+/// Map team abbreviation to its conference name for a season
+///
+/// Follows the same season rules as `abbrev_to_division`.
+pub fn abbrev_to_conference(abbrev: &str, season: Option<i64>) -> Option<&'static str> {
+    match abbrev_to_division(abbrev, season)? {
+        "Atlantic" | "Metropolitan" => Some("Eastern"),
+        "Central" | "Pacific" => Some("Western"),
+        _ => None,
+    }
+}
+
+/// Map team abbreviation to its (primary, secondary) colors
+///
+/// Returns None for unknown or historical abbreviations.
+pub fn abbrev_to_colors(abbrev: &str) -> Option<(Color, Color)> {
+    match abbrev {
+        "ANA" => Some((Color::Rgb(252, 76, 2), Color::Rgb(185, 151, 91))),
+        "ARI" => Some((Color::Rgb(140, 38, 51), Color::Rgb(226, 214, 181))),
+        "BOS" => Some((Color::Rgb(252, 181, 20), Color::Rgb(0, 0, 0))),
+        "BUF" => Some((Color::Rgb(0, 48, 135), Color::Rgb(255, 184, 28))),
+        "CGY" => Some((Color::Rgb(200, 16, 46), Color::Rgb(241, 190, 72))),
+        "CAR" => Some((Color::Rgb(206, 17, 38), Color::Rgb(162, 170, 173))),
+        "CHI" => Some((Color::Rgb(207, 10, 44), Color::Rgb(255, 103, 27))),
+        "COL" => Some((Color::Rgb(111, 38, 61), Color::Rgb(35, 97, 146))),
+        "CBJ" => Some((Color::Rgb(0, 38, 84), Color::Rgb(206, 17, 38))),
+        "DAL" => Some((Color::Rgb(0, 104, 71), Color::Rgb(143, 143, 140))),
+        "DET" => Some((Color::Rgb(206, 17, 38), Color::Rgb(255, 255, 255))),
+        "EDM" => Some((Color::Rgb(252, 76, 0), Color::Rgb(4, 30, 66))),
+        "FLA" => Some((Color::Rgb(200, 16, 46), Color::Rgb(4, 30, 66))),
+        "LAK" => Some((Color::Rgb(162, 170, 173), Color::Rgb(17, 17, 17))),
+        "MIN" => Some((Color::Rgb(21, 71, 52), Color::Rgb(175, 35, 36))),
+        "MTL" => Some((Color::Rgb(175, 30, 45), Color::Rgb(25, 33, 104))),
+        "NSH" => Some((Color::Rgb(255, 184, 28), Color::Rgb(4, 30, 66))),
+        "NJD" => Some((Color::Rgb(206, 17, 38), Color::Rgb(0, 0, 0))),
+        "NYI" => Some((Color::Rgb(0, 83, 155), Color::Rgb(244, 125, 48))),
+        "NYR" => Some((Color::Rgb(0, 56, 168), Color::Rgb(206, 17, 38))),
+        "OTT" => Some((Color::Rgb(197, 32, 50), Color::Rgb(194, 145, 44))),
+        "PHI" => Some((Color::Rgb(247, 73, 2), Color::Rgb(0, 0, 0))),
+        "PIT" => Some((Color::Rgb(252, 181, 20), Color::Rgb(0, 0, 0))),
+        "SJS" => Some((Color::Rgb(0, 109, 117), Color::Rgb(234, 114, 0))),
+        "SEA" => Some((Color::Rgb(153, 217, 217), Color::Rgb(0, 22, 40))),
+        "STL" => Some((Color::Rgb(0, 47, 135), Color::Rgb(252, 181, 20))),
+        "TBL" => Some((Color::Rgb(0, 40, 104), Color::Rgb(255, 255, 255))),
+        "TOR" => Some((Color::Rgb(0, 32, 91), Color::Rgb(255, 255, 255))),
+        "VAN" => Some((Color::Rgb(0, 32, 91), Color::Rgb(0, 132, 61))),
+        "VGK" => Some((Color::Rgb(185, 151, 91), Color::Rgb(51, 63, 72))),
+        "WSH" => Some((Color::Rgb(200, 16, 46), Color::Rgb(4, 30, 66))),
+        "WPG" => Some((Color::Rgb(4, 30, 66), Color::Rgb(0, 76, 151))),
+        "UTA" => Some((Color::Rgb(113, 175, 229), Color::Rgb(9, 9, 9))),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_common_names_round_trip() {
+        for abbrev in CURRENT_TEAMS {
+            let name = abbrev_to_common_name(abbrev).expect(abbrev);
+            assert_eq!(common_name_to_abbrev(name), Some(abbrev), "{}", name);
+        }
+    }
+
+    #[test]
+    fn test_relocated_franchises() {
+        assert_eq!(abbrev_to_common_name("UTA"), Some("Mammoth"));
+        for name in ["Mammoth", "Utah Mammoth", "Hockey Club", "Utah Hockey Club"] {
+            assert_eq!(common_name_to_abbrev(name), Some("UTA"), "{}", name);
+        }
+        assert_eq!(common_name_to_abbrev("Coyotes"), Some("ARI"));
+        assert_eq!(common_name_to_abbrev("Arizona Coyotes"), Some("ARI"));
+        assert_eq!(common_name_to_abbrev("Phoenix Coyotes"), Some("PHX"));
+        assert_eq!(common_name_to_abbrev("Thrashers"), Some("ATL"));
+        assert_eq!(place_name_to_abbrev("Utah"), Some("UTA"));
+        assert_eq!(place_name_to_abbrev("Arizona"), Some("ARI"));
+        assert_eq!(place_name_to_abbrev("Phoenix"), Some("PHX"));
+    }
+
+    #[test]
+    fn test_place_names() {
+        assert_eq!(place_name_to_abbrev("Montréal"), Some("MTL"));
+        assert_eq!(place_name_to_abbrev("Montreal"), Some("MTL"));
+        assert_eq!(place_name_to_abbrev("Tampa Bay"), Some("TBL"));
+        // Shared by two teams
+        assert_eq!(place_name_to_abbrev("New York"), None);
+        // Every current team except the two New York clubs has its own place name
+        for abbrev in CURRENT_TEAMS {
+            let place = abbrev_to_place_name(abbrev).expect(abbrev);
+            let expected = if place == "New York" {
+                None
+            } else {
+                Some(abbrev)
+            };
+            assert_eq!(place_name_to_abbrev(place), expected, "{}", place);
+        }
+    }
+
+    #[test]
+    fn test_place_and_full_names() {
+        assert_eq!(abbrev_to_place_name("TOR"), Some("Toronto"));
+        assert_eq!(abbrev_to_full_name("TOR"), Some("Toronto Maple Leafs"));
+        assert_eq!(abbrev_to_full_name("MTL"), Some("Montréal Canadiens"));
+        assert_eq!(abbrev_to_full_name("NYI"), Some("New York Islanders"));
+        // Relocated franchises keep their own names
+        assert_eq!(abbrev_to_full_name("UTA"), Some("Utah Mammoth"));
+        assert_eq!(abbrev_to_full_name("ARI"), Some("Arizona Coyotes"));
+        assert_eq!(abbrev_to_full_name("PHX"), Some("Phoenix Coyotes"));
+        assert_eq!(abbrev_to_place_name("ATL"), Some("Atlanta"));
+        assert_eq!(abbrev_to_full_name("XYZ"), None);
+    }
+
+    #[test]
+    fn test_full_name_is_place_and_common_name() {
+        for abbrev in CURRENT_TEAMS {
+            let full_name = abbrev_to_full_name(abbrev).expect(abbrev);
+            let place = abbrev_to_place_name(abbrev).expect(abbrev);
+            let common = abbrev_to_common_name(abbrev).expect(abbrev);
+            assert_eq!(full_name, format!("{} {}", place, common));
+        }
+    }
+
+    #[test]
+    fn test_resolve_team() {
+        assert_eq!(resolve_team("leafs"), Some("TOR"));
+        assert_eq!(resolve_team("TOR"), Some("TOR"));
+        assert_eq!(resolve_team("maple"), Some("TOR"));
+        assert_eq!(resolve_team(" Toronto "), Some("TOR"));
+        assert_eq!(resolve_team("montréal"), Some("MTL"));
+        assert_eq!(resolve_team("utah"), Some("UTA"));
+    }
+
+    #[test]
+    fn test_resolve_team_prefers_exact_match() {
+        // Also inside "Columbus" (CBJ)
+        assert_eq!(resolve_team("col"), Some("COL"));
+        // Also inside "Canadiens" (MTL)
+        assert_eq!(resolve_team("ana"), Some("ANA"));
+    }
+
+    #[test]
+    fn test_matching_teams() {
+        assert_eq!(matching_teams("new"), vec!["NJD", "NYI", "NYR"]);
+        assert_eq!(matching_teams("new york"), vec!["NYI", "NYR"]);
+        // Exact matches hide partial ones
+        assert_eq!(matching_teams("col"), vec!["COL"]);
+        assert!(matching_teams(" ").is_empty());
+    }
+
+    #[test]
+    fn test_resolve_team_ambiguous() {
+        // New Jersey, New York Islanders and New York Rangers
+        assert_eq!(resolve_team("new"), None);
+        // Both New York clubs share the place name
+        assert_eq!(resolve_team("new york"), None);
+        assert_eq!(resolve_team(""), None);
+        assert_eq!(resolve_team("xyz"), None);
+    }
+
+    #[test]
+    fn test_current_alignment() {
+        assert_eq!(abbrev_to_division("TOR", None), Some("Atlantic"));
+        assert_eq!(abbrev_to_division("PIT", None), Some("Metropolitan"));
+        assert_eq!(abbrev_to_division("UTA", None), Some("Central"));
+        assert_eq!(abbrev_to_division("SEA", None), Some("Pacific"));
+        assert_eq!(abbrev_to_division("ARI", None), None);
+        assert_eq!(abbrev_to_conference("TOR", None), Some("Eastern"));
+        assert_eq!(abbrev_to_conference("VGK", None), Some("Western"));
+        assert_eq!(abbrev_to_conference("XYZ", None), None);
+    }
+
+    #[test]
+    fn test_every_current_division_has_eight_teams() {
+        for division in ["Atlantic", "Metropolitan", "Central", "Pacific"] {
+            let teams = CURRENT_TEAMS
+                .iter()
+                .filter(|abbrev| abbrev_to_division(abbrev, None) == Some(division))
+                .count();
+            assert_eq!(teams, 8, "{}", division);
+        }
+    }
+
+    #[test]
+    fn test_alignment_follows_season() {
+        assert_eq!(abbrev_to_division("UTA", Some(20252026)), Some("Central"));
+        assert_eq!(abbrev_to_division("UTA", Some(20242025)), Some("Central"));
+        assert_eq!(abbrev_to_division("UTA", Some(20232024)), None);
+        assert_eq!(abbrev_to_division("ARI", Some(20232024)), Some("Central"));
+        assert_eq!(abbrev_to_conference("ARI", Some(20212022)), Some("Western"));
+        // No alignment is recorded before 2021-22 yet
+        assert_eq!(abbrev_to_division("TOR", Some(20202021)), None);
+    }
+
+    #[test]
+    fn test_display_team_name_known_abbrev() {
+        assert_eq!(display_team_name("TOR", None), "Maple Leafs");
+        assert_eq!(display_team_name("TOR", Some("Leafs")), "Maple Leafs");
+    }
+
+    #[test]
+    fn test_display_team_name_unknown_abbrev() {
+        assert_eq!(display_team_name("XYZ", None), "XYZ");
+        assert_eq!(display_team_name("XYZ", Some("")), "XYZ");
+        assert_eq!(display_team_name("XYZ", Some("Expansion")), "Expansion");
+    }
+}
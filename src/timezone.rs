@@ -0,0 +1,157 @@
+//! Time zone handling for game start times
+//!
+//! The API reports start times in UTC; schedules and score boxes convert them with
+//! [`display_start_time`] or [`format_start_time`] so `display.timezone` applies to both.
+
+use std::fmt::Write;
+
+use chrono::{DateTime, FixedOffset, Local, Utc};
+use chrono_tz::Tz;
+
+/// `display.timezone` value for the system's local zone
+pub const LOCAL_TIMEZONE: &str = "local";
+
+/// Zone start times are converted to before formatting
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DisplayTimezone {
+    /// The system's local zone
+    Local,
+    /// An IANA zone such as "America/Toronto", following its daylight saving rules
+    Named(Tz),
+    /// A fixed offset such as "-04:00"
+    Fixed(FixedOffset),
+}
+
+impl DisplayTimezone {
+    /// Parse "local", an IANA zone name or a "+HH:MM" offset; None if it is none of those
+    pub fn parse(name: &str) -> Option<Self> {
+        let name = name.trim();
+        if name.is_empty() || name.eq_ignore_ascii_case(LOCAL_TIMEZONE) {
+            return Some(Self::Local);
+        }
+        if let Ok(tz) = name.parse::<Tz>() {
+            return Some(Self::Named(tz));
+        }
+        name.parse::<FixedOffset>().ok().map(Self::Fixed)
+    }
+
+    /// Like [`DisplayTimezone::parse`], falling back to the local zone for unknown names
+    pub fn from_config(name: &str) -> Self {
+        Self::parse(name).unwrap_or(Self::Local)
+    }
+
+    /// The same instant as seen in this zone
+    pub fn convert(self, time: DateTime<Utc>) -> DateTime<FixedOffset> {
+        match self {
+            Self::Local => time.with_timezone(&Local).fixed_offset(),
+            Self::Named(tz) => time.with_timezone(&tz).fixed_offset(),
+            Self::Fixed(offset) => time.with_timezone(&offset),
+        }
+    }
+}
+
+/// Parse an API start time such as "2024-10-04T23:00:00Z"
+pub fn parse_start_time(start_time_utc: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(start_time_utc)
+        .ok()
+        .map(|time| time.with_timezone(&Utc))
+}
+
+/// A start time converted to the configured zone, or None if it can't be parsed
+pub fn display_start_time(start_time_utc: &str, timezone: &str) -> Option<DateTime<FixedOffset>> {
+    parse_start_time(start_time_utc)
+        .map(|time| DisplayTimezone::from_config(timezone).convert(time))
+}
+
+/// A start time in the configured zone formatted with a strftime-style format,
+/// or None if the time can't be parsed or the format is invalid
+pub fn format_start_time(
+    start_time_utc: &str,
+    timezone: &str,
+    time_format: &str,
+) -> Option<String> {
+    let time = display_start_time(start_time_utc, timezone)?;
+    let mut formatted = String::new();
+    write!(formatted, "{}", time.format(time_format)).ok()?;
+    Some(formatted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PUCK_DROP: &str = "2024-10-04T23:00:00Z";
+
+    #[test]
+    fn test_parse_timezone() {
+        assert_eq!(
+            DisplayTimezone::parse("local"),
+            Some(DisplayTimezone::Local)
+        );
+        assert_eq!(
+            DisplayTimezone::parse("Local"),
+            Some(DisplayTimezone::Local)
+        );
+        assert_eq!(DisplayTimezone::parse(""), Some(DisplayTimezone::Local));
+        assert_eq!(
+            DisplayTimezone::parse("America/Toronto"),
+            Some(DisplayTimezone::Named(Tz::America__Toronto))
+        );
+        assert_eq!(
+            DisplayTimezone::parse("-04:00"),
+            Some(DisplayTimezone::Fixed(
+                FixedOffset::west_opt(4 * 3600).unwrap()
+            ))
+        );
+        assert_eq!(DisplayTimezone::parse("America/Hamilton"), None);
+    }
+
+    #[test]
+    fn test_format_start_time_in_named_zones() {
+        assert_eq!(
+            format_start_time(PUCK_DROP, "America/Toronto", "%H:%M"),
+            Some("19:00".to_string())
+        );
+        assert_eq!(
+            format_start_time(PUCK_DROP, "America/Vancouver", "%I:%M %p"),
+            Some("04:00 PM".to_string())
+        );
+        // Crosses midnight
+        assert_eq!(
+            format_start_time(PUCK_DROP, "Europe/Helsinki", "%a %H:%M"),
+            Some("Sat 02:00".to_string())
+        );
+    }
+
+    #[test]
+    fn test_format_start_time_follows_daylight_saving() {
+        // Same UTC time of day in January is an hour earlier in Toronto
+        assert_eq!(
+            format_start_time("2025-01-15T23:00:00Z", "America/Toronto", "%H:%M"),
+            Some("18:00".to_string())
+        );
+    }
+
+    #[test]
+    fn test_unknown_zone_falls_back_to_local() {
+        let local = parse_start_time(PUCK_DROP)
+            .unwrap()
+            .with_timezone(&Local)
+            .format("%H:%M")
+            .to_string();
+
+        assert_eq!(
+            format_start_time(PUCK_DROP, "Mars/Olympus_Mons", "%H:%M"),
+            Some(local)
+        );
+    }
+
+    #[test]
+    fn test_format_start_time_invalid_input() {
+        assert_eq!(
+            format_start_time("tonight", "America/Toronto", "%H:%M"),
+            None
+        );
+        assert_eq!(format_start_time(PUCK_DROP, "America/Toronto", "%Q"), None);
+    }
+}
@@ -1,8 +1,12 @@
 use crossterm::event::KeyEvent;
-use nhl_api::{Boxscore, ClubStats, DailySchedule, GameDate, GameMatchup, PlayerLanding, Standing};
+use nhl_api::{
+    Boxscore, ClubStats, DailySchedule, GameDate, GameLog, GameMatchup, PlayerLanding, Roster,
+    ScheduleGame, Standing,
+};
 use std::any::Any;
 
 use super::component::Effect;
+use super::document_nav::DocumentNavMsg;
 use super::types::{StackedDocument, Tab};
 
 /// Trait for type-erased component messages
@@ -36,7 +40,13 @@ pub enum Action {
     ExitContentFocus,  // Up key: move focus from content back to tab bar
     PushDocument(StackedDocument),
     PopDocument,
+    /// Pop documents until the stack has this many left (breadcrumb navigation)
+    PopToDepth(usize),
     ToggleCommandPalette,
+    CommandPalette(CommandPaletteAction),
+    ToggleHelp,
+    /// Show or hide the descriptions of the focused table's columns
+    ToggleColumnLegend,
 
     /// Unified "navigate up" action (ESC key)
     ///
@@ -52,20 +62,49 @@ pub enum Action {
     /// When a document is on the stack, key events are dispatched to the
     /// document's handle_key method for encapsulated navigation handling.
     StackedDocumentKey(KeyEvent),
+    /// Navigate the top stacked document directly (mouse clicks and scrolling)
+    StackedDocumentNav(DocumentNavMsg),
+    /// Find text within the top stacked document
+    Find(FindAction),
+    /// Forget the player marked for comparison
+    ClearComparePending,
+
+    /// Export the top stacked document to a Markdown file
+    ExportDocument,
+
+    /// Copy the focused player or team link to the system clipboard
+    CopyFocusedLink,
+
+    /// Copy a one-line summary of the focused or open game to the system clipboard
+    CopyGameSummary,
+
+    /// Freeze the displayed data (or resume showing live data)
+    ///
+    /// Fetches keep running while pinned; only rendering reads the snapshot.
+    TogglePin,
 
     // Data actions
     // SelectTeam(String),
     // SelectPlayer(i64),
+    /// Fetch what is on screen through the caches (startup)
+    LoadData,
+    /// Refetch what is on screen past the caches (user-triggered refresh)
     RefreshData,
     RefreshSchedule(GameDate), // Refresh schedule for specific date
 
     // Data loaded (from effects)
-    StandingsLoaded(Result<Vec<Standing>, String>),
+    /// Standings for a season (None for the current one)
+    StandingsLoaded(Option<i64>, Result<Vec<Standing>, String>),
     ScheduleLoaded(Result<DailySchedule, String>),
     GameDetailsLoaded(i64, Result<GameMatchup, String>),
     BoxscoreLoaded(i64, Result<Boxscore, String>),
     TeamRosterStatsLoaded(String, Result<ClubStats, String>),
+    TeamRosterLoaded(String, Result<Roster, String>),
+    TeamScheduleLoaded(String, Result<Vec<ScheduleGame>, String>),
     PlayerStatsLoaded(i64, Result<PlayerLanding, String>),
+    PlayerGameLogLoaded(i64, Result<Vec<GameLog>, String>),
+    /// A stale disk cache copy of a *Loaded action, shown while its fetch is still running
+    CachedDataLoaded(Box<Action>),
 
     // UI actions
     FocusNext,
@@ -76,10 +115,16 @@ pub enum Action {
 
     // Scores tab actions that modify global state
     SelectGame(i64),
+    RebuildScoresFocusable,
 
     // Standings tab actions that modify component state directly
     RebuildStandingsFocusable,
 
+    /// Move the standings back or forward by a number of seasons
+    ShiftSeason(i64),
+    /// Cycle the game type shown on the scores tab
+    CycleGameType,
+
     /// Dispatch a message to a specific component
     ///
     /// This is part of the React-like component system refactor.
@@ -104,6 +149,32 @@ pub enum Action {
     Tick,
 }
 
+/// Editing and picking in the open command palette
+#[derive(Debug, Clone)]
+pub enum CommandPaletteAction {
+    Input(char),
+    Backspace,
+    SelectNext,
+    SelectPrev,
+    /// Open the highlighted result and close the palette
+    Submit,
+}
+
+/// Typing a query and moving between matches of a find in a document
+#[derive(Debug, Clone)]
+pub enum FindAction {
+    /// Open an empty find prompt
+    Start,
+    Input(char),
+    Backspace,
+    /// Stop typing and keep the matches for n/N
+    Submit,
+    Next,
+    Prev,
+    /// Close the find and drop its highlights
+    Clear,
+}
+
 /// Tab-specific actions for Settings
 #[derive(Debug, Clone)]
 pub enum SettingsAction {
@@ -124,24 +195,47 @@ impl Clone for Action {
             Self::ExitContentFocus => Self::ExitContentFocus,
             Self::PushDocument(doc) => Self::PushDocument(doc.clone()),
             Self::PopDocument => Self::PopDocument,
+            Self::PopToDepth(depth) => Self::PopToDepth(*depth),
             Self::ToggleCommandPalette => Self::ToggleCommandPalette,
+            Self::CommandPalette(action) => Self::CommandPalette(action.clone()),
+            Self::ToggleHelp => Self::ToggleHelp,
+            Self::ToggleColumnLegend => Self::ToggleColumnLegend,
             Self::NavigateUp => Self::NavigateUp,
             Self::StackedDocumentKey(key) => Self::StackedDocumentKey(*key),
+            Self::StackedDocumentNav(msg) => Self::StackedDocumentNav(msg.clone()),
+            Self::Find(action) => Self::Find(action.clone()),
+            Self::ClearComparePending => Self::ClearComparePending,
+            Self::ExportDocument => Self::ExportDocument,
+            Self::TogglePin => Self::TogglePin,
+            Self::CopyFocusedLink => Self::CopyFocusedLink,
+            Self::CopyGameSummary => Self::CopyGameSummary,
+            Self::LoadData => Self::LoadData,
             Self::RefreshData => Self::RefreshData,
             Self::RefreshSchedule(date) => Self::RefreshSchedule(date.clone()),
-            Self::StandingsLoaded(result) => Self::StandingsLoaded(result.clone()),
+            Self::StandingsLoaded(season, result) => Self::StandingsLoaded(*season, result.clone()),
             Self::ScheduleLoaded(result) => Self::ScheduleLoaded(result.clone()),
             Self::GameDetailsLoaded(id, result) => Self::GameDetailsLoaded(*id, result.clone()),
             Self::BoxscoreLoaded(id, result) => Self::BoxscoreLoaded(*id, result.clone()),
             Self::TeamRosterStatsLoaded(abbrev, result) => {
                 Self::TeamRosterStatsLoaded(abbrev.clone(), result.clone())
             }
+            Self::TeamRosterLoaded(abbrev, result) => {
+                Self::TeamRosterLoaded(abbrev.clone(), result.clone())
+            }
+            Self::TeamScheduleLoaded(abbrev, result) => {
+                Self::TeamScheduleLoaded(abbrev.clone(), result.clone())
+            }
             Self::PlayerStatsLoaded(id, result) => Self::PlayerStatsLoaded(*id, result.clone()),
+            Self::PlayerGameLogLoaded(id, result) => Self::PlayerGameLogLoaded(*id, result.clone()),
+            Self::CachedDataLoaded(loaded) => Self::CachedDataLoaded(loaded.clone()),
             Self::FocusNext => Self::FocusNext,
             Self::FocusPrevious => Self::FocusPrevious,
             Self::SettingsAction(action) => Self::SettingsAction(action.clone()),
             Self::SelectGame(id) => Self::SelectGame(*id),
+            Self::RebuildScoresFocusable => Self::RebuildScoresFocusable,
             Self::RebuildStandingsFocusable => Self::RebuildStandingsFocusable,
+            Self::ShiftSeason(delta) => Self::ShiftSeason(*delta),
+            Self::CycleGameType => Self::CycleGameType,
             Self::ComponentMessage { path, message } => Self::ComponentMessage {
                 path: path.clone(),
                 message: message.clone_box(),
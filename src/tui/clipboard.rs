@@ -0,0 +1,42 @@
+//! System clipboard access
+//!
+//! Clipboard support is behind the `clipboard` feature to keep the platform
+//! clipboard libraries out of default builds. Without it, or without a
+//! clipboard to talk to (headless sessions, SSH without X forwarding), copying
+//! returns an error instead of panicking.
+
+use crate::error::{Error, Result};
+
+#[cfg(feature = "clipboard")]
+use std::sync::{Mutex, OnceLock};
+
+/// Clipboard handle kept alive for the whole session
+///
+/// On X11 and Wayland the copied text is served by this process, so dropping
+/// the handle right after writing could lose it.
+#[cfg(feature = "clipboard")]
+static CLIPBOARD: OnceLock<Mutex<Option<arboard::Clipboard>>> = OnceLock::new();
+
+/// Copy text to the system clipboard
+#[cfg(feature = "clipboard")]
+pub fn copy(text: &str) -> Result<()> {
+    let mut clipboard = CLIPBOARD
+        .get_or_init(|| Mutex::new(None))
+        .lock()
+        .map_err(|_| Error::Clipboard("clipboard lock poisoned".to_string()))?;
+    if clipboard.is_none() {
+        *clipboard = Some(arboard::Clipboard::new()?);
+    }
+    if let Some(clipboard) = clipboard.as_mut() {
+        clipboard.set_text(text)?;
+    }
+    Ok(())
+}
+
+/// Copy text to the system clipboard
+#[cfg(not(feature = "clipboard"))]
+pub fn copy(_text: &str) -> Result<()> {
+    Err(Error::Clipboard(
+        "support not built in (enable the `clipboard` feature)".to_string(),
+    ))
+}
@@ -1,8 +1,10 @@
+use nhl_api::GameDate;
 use ratatui::{buffer::Buffer, layout::Rect};
 use std::future::Future;
 use std::pin::Pin;
 
 use super::action::Action;
+use super::hit_test::HitRegion;
 use crate::config::DisplayConfig;
 
 /// Core component trait - like React.Component
@@ -105,14 +107,30 @@ pub enum Effect {
     Batch(Vec<Effect>),
     Async(Pin<Box<dyn Future<Output = Action> + Send>>),
     // Data fetch effects - returned by reducers to trigger async fetches
+    /// Fetch league standings for a season (None for the current one)
+    FetchStandings(Option<i64>),
+    /// Fetch the schedule for a date
+    FetchSchedule(GameDate),
     /// Fetch boxscore data for a game
     FetchBoxscore(i64),
     /// Fetch team roster/stats for a team
     FetchTeamRosterStats(String),
+    /// Fetch a team's current roster (sweater numbers)
+    FetchTeamRoster(String),
+    /// Fetch a team's schedule for the current week
+    FetchTeamSchedule(String),
     /// Fetch player stats
     FetchPlayerStats(i64),
     /// Fetch game details (period scores, etc.)
     FetchGameDetails(i64),
+    /// Fetch a player's regular season game log (player id, season)
+    FetchPlayerGameLog(i64, i32),
+    /// Fetch standings past the caches (user-triggered refresh)
+    RefetchStandings(Option<i64>),
+    /// Fetch the schedule for a date past the caches (user-triggered refresh)
+    RefetchSchedule(GameDate),
+    /// Fetch a boxscore past the caches (user-triggered refresh)
+    RefetchBoxscore(i64),
 }
 
 impl std::fmt::Debug for Effect {
@@ -123,17 +141,48 @@ impl std::fmt::Debug for Effect {
             Effect::Action(action) => f.debug_tuple("Effect::Action").field(action).finish(),
             Effect::Batch(effects) => f.debug_tuple("Effect::Batch").field(effects).finish(),
             Effect::Async(_) => write!(f, "Effect::Async(<future>)"),
+            Effect::FetchStandings(season) => f
+                .debug_tuple("Effect::FetchStandings")
+                .field(season)
+                .finish(),
+            Effect::FetchSchedule(date) => {
+                f.debug_tuple("Effect::FetchSchedule").field(date).finish()
+            }
             Effect::FetchBoxscore(id) => f.debug_tuple("Effect::FetchBoxscore").field(id).finish(),
             Effect::FetchTeamRosterStats(abbrev) => f
                 .debug_tuple("Effect::FetchTeamRosterStats")
                 .field(abbrev)
                 .finish(),
+            Effect::FetchTeamRoster(abbrev) => f
+                .debug_tuple("Effect::FetchTeamRoster")
+                .field(abbrev)
+                .finish(),
+            Effect::FetchTeamSchedule(abbrev) => f
+                .debug_tuple("Effect::FetchTeamSchedule")
+                .field(abbrev)
+                .finish(),
             Effect::FetchPlayerStats(id) => {
                 f.debug_tuple("Effect::FetchPlayerStats").field(id).finish()
             }
             Effect::FetchGameDetails(id) => {
                 f.debug_tuple("Effect::FetchGameDetails").field(id).finish()
             }
+            Effect::FetchPlayerGameLog(id, season) => f
+                .debug_tuple("Effect::FetchPlayerGameLog")
+                .field(id)
+                .field(season)
+                .finish(),
+            Effect::RefetchStandings(season) => f
+                .debug_tuple("Effect::RefetchStandings")
+                .field(season)
+                .finish(),
+            Effect::RefetchSchedule(date) => f
+                .debug_tuple("Effect::RefetchSchedule")
+                .field(date)
+                .finish(),
+            Effect::RefetchBoxscore(id) => {
+                f.debug_tuple("Effect::RefetchBoxscore").field(id).finish()
+            }
         }
     }
 }
@@ -171,6 +220,13 @@ pub trait ElementWidget: Send + Sync {
     fn preferred_width(&self) -> Option<u16> {
         None
     }
+
+    /// Screen regions of the focusable elements drawn by `render` into `area`
+    ///
+    /// Used to resolve mouse clicks. Widgets without focusable content keep the default.
+    fn hit_regions(&self, _area: Rect, _config: &DisplayConfig) -> Vec<HitRegion> {
+        Vec::new()
+    }
 }
 
 impl Clone for Box<dyn ElementWidget> {
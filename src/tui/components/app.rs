@@ -1,23 +1,32 @@
-use crate::tui::component::{vertical, Component, Constraint, Element};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use unicode_width::UnicodeWidthStr;
+
+use crate::config::DisplayConfig;
+use crate::tui::component::{vertical, Component, Constraint, Element, ElementWidget};
 use crate::tui::component_store::ComponentStateStore;
 #[cfg(feature = "development")]
 use crate::tui::constants::DEMO_TAB_PATH;
 use crate::tui::constants::{SCORES_TAB_PATH, SETTINGS_TAB_PATH, STANDINGS_TAB_PATH};
-use crate::tui::state::{AppState, LoadingKey};
+use crate::tui::state::{AppState, FetchKind, LoadingKey};
 
 #[cfg(feature = "development")]
 use super::demo_tab::DemoTabProps;
 #[cfg(feature = "development")]
 use super::DemoTab;
 use super::{
-    boxscore_document::{BoxscoreDocument, BoxscoreDocumentProps, TeamView},
+    boxscore_document::{team_records, BoxscoreDocument, BoxscoreDocumentProps, TeamView},
+    column_legend::ColumnLegendWidget,
+    command_palette::{palette_results, CommandPaletteWidget},
+    help_overlay::HelpOverlayWidget,
+    player_compare_document::PlayerCompareDocumentProps,
     player_detail_document::PlayerDetailDocumentProps,
     scores_tab::ScoresTabProps,
     settings_tab::SettingsTabProps,
     standings_tab::StandingsTabProps,
     team_detail_document::TeamDetailDocumentProps,
-    BreadcrumbWidget, PlayerDetailDocument, ScoresTab, SettingsTab, StandingsTab, StatusBar,
-    TabItem, TabbedPanel, TabbedPanelProps, TeamDetailDocument,
+    BreadcrumbWidget, PlayerCompareDocument, PlayerDetailDocument, ScoresTab, SettingsTab,
+    StandingsTab, StatusBar, TabItem, TabbedPanel, TabbedPanelProps, TeamDetailDocument,
 };
 use crate::tui::state::DocumentStackEntry;
 use crate::tui::types::StackedDocument;
@@ -34,41 +43,91 @@ impl Component for App {
     type Message = ();
 
     fn view(&self, props: &Self::Props, _state: &Self::State) -> Element {
-        vertical(
+        let base = vertical(
             [Constraint::Min(0), Constraint::Length(2)],
             vec![
                 self.render_main_tabs_without_states(props),
-                StatusBar.view(&props.system, &()),
+                StatusBar.view(props, &()),
             ],
-        )
+        );
+        self.with_overlays(props, base)
     }
 }
 
 impl App {
+    /// Build the root element for a terminal area
+    ///
+    /// Below the configured minimum size the layout is replaced by a warning.
+    pub fn build_for_area(
+        &self,
+        state: &AppState,
+        component_states: &mut ComponentStateStore,
+        area: Rect,
+    ) -> Element {
+        let display = &state.system.config.display;
+        if area.width < display.min_width || area.height < display.min_height {
+            return Element::Widget(Box::new(TerminalTooSmallWidget {
+                min_width: display.min_width,
+                min_height: display.min_height,
+            }));
+        }
+        self.build_with_component_states(state, component_states)
+    }
+
     pub fn build_with_component_states(
         &self,
         state: &AppState,
         component_states: &mut ComponentStateStore,
     ) -> Element {
-        vertical(
+        let base = vertical(
             [Constraint::Min(0), Constraint::Length(2)],
             vec![
                 self.render_main_tabs_with_states(state, component_states),
-                StatusBar.view(&state.system, &()),
+                StatusBar.view(state, &()),
             ],
-        )
+        );
+        self.with_overlays(state, base)
+    }
+
+    /// Draw the help overlay, column legend or command palette over `base` while open
+    fn with_overlays(&self, state: &AppState, base: Element) -> Element {
+        if state.navigation.help_open {
+            return Element::Overlay {
+                base: Box::new(base),
+                overlay: Box::new(Element::Widget(Box::new(HelpOverlayWidget {
+                    keymap: state.system.config.keymap.clone(),
+                }))),
+            };
+        }
+        if let Some(entries) = &state.navigation.column_legend {
+            return Element::Overlay {
+                base: Box::new(base),
+                overlay: Box::new(Element::Widget(Box::new(ColumnLegendWidget {
+                    entries: entries.clone(),
+                }))),
+            };
+        }
+        let Some(palette) = &state.navigation.command_palette else {
+            return base;
+        };
+        let results = palette_results(&palette.query, &state.data)
+            .iter()
+            .map(|result| result.label())
+            .collect();
+        Element::Overlay {
+            base: Box::new(base),
+            overlay: Box::new(Element::Widget(Box::new(CommandPaletteWidget {
+                query: palette.query.clone(),
+                results,
+                selected: palette.selected,
+            }))),
+        }
     }
 
     fn render_main_tabs_without_states(&self, state: &AppState) -> Element {
         use crate::tui::Tab;
 
-        let active_key = match state.navigation.current_tab {
-            Tab::Scores => "scores",
-            Tab::Standings => "standings",
-            Tab::Settings => "settings",
-            #[cfg(feature = "development")]
-            Tab::Demo => "demo",
-        };
+        let active_key = state.navigation.current_tab.key();
 
         let mut scores_content = Element::None;
         let mut standings_content = Element::None;
@@ -101,7 +160,7 @@ impl App {
                 demo_content = DemoTab.view(
                     &DemoTabProps {
                         content_focused: state.navigation.content_focused,
-                        standings: state.data.standings.clone(),
+                        standings: state.data.displayed().standings.clone(),
                     },
                     &Default::default(),
                 );
@@ -141,14 +200,7 @@ impl App {
     ) -> Element {
         use crate::tui::Tab;
 
-        // Convert Tab enum to string key
-        let active_key = match state.navigation.current_tab {
-            Tab::Scores => "scores",
-            Tab::Standings => "standings",
-            Tab::Settings => "settings",
-            #[cfg(feature = "development")]
-            Tab::Demo => "demo",
-        };
+        let active_key = state.navigation.current_tab.key();
 
         let mut scores_content = Element::None;
         let mut standings_content = Element::None;
@@ -188,7 +240,7 @@ impl App {
                 // Build Demo tab content
                 let demo_props = DemoTabProps {
                     content_focused: state.navigation.content_focused,
-                    standings: state.data.standings.clone(),
+                    standings: state.data.displayed().standings.clone(),
                 };
                 let demo_state =
                     component_states.get_or_init::<DemoTab>(DEMO_TAB_PATH, &demo_props);
@@ -222,15 +274,35 @@ impl App {
     }
 
     fn render_stacked_document(&self, state: &AppState, doc_entry: &DocumentStackEntry) -> Element {
+        let data = state.data.displayed();
         match &doc_entry.document {
             StackedDocument::Boxscore { game_id, .. } => {
+                let boxscore = data.boxscores.get(game_id);
                 let props = BoxscoreDocumentProps {
                     game_id: *game_id,
-                    boxscore: state.data.boxscores.get(game_id).cloned(),
-                    loading: state.data.loading.contains(&LoadingKey::Boxscore(*game_id)),
+                    boxscore: boxscore.cloned(),
+                    period_scores: data.period_scores.get(game_id).cloned(),
+                    game_summary: data
+                        .game_info
+                        .get(game_id)
+                        .and_then(|info| info.summary.clone()),
+                    records: boxscore.and_then(|boxscore| {
+                        team_records(
+                            data.standings
+                                .as_ref()
+                                .map(|standings| standings.as_slice()),
+                            &boxscore.away_team.abbrev,
+                            &boxscore.home_team.abbrev,
+                        )
+                    }),
+                    time_format: state.system.config.time_format.clone(),
                     team_view: TeamView::Away,
+                    sort: doc_entry.nav.sort,
+                    per_60: doc_entry.nav.per_60,
+                    column_offset: doc_entry.nav.column_offset,
                     selected_index: doc_entry.nav.focus_index,
                     scroll_offset: doc_entry.nav.scroll_offset,
+                    find: doc_entry.nav.find.clone(),
                     focused: true, // Document has focus when it's on the stack
                     animation_frame: state.system.animation_frame,
                 };
@@ -238,28 +310,27 @@ impl App {
             }
             StackedDocument::TeamDetail { abbrev } => {
                 // Find the standing for this team
-                let standing = state
-                    .data
-                    .standings
-                    .as_ref()
-                    .as_ref()
-                    .and_then(|standings| {
-                        standings
-                            .iter()
-                            .find(|s| s.team_abbrev.default == *abbrev)
-                            .cloned()
-                    });
+                let standing = data.standings.as_ref().and_then(|standings| {
+                    standings
+                        .iter()
+                        .find(|s| s.team_abbrev.default == *abbrev)
+                        .cloned()
+                });
                 //
                 let props = TeamDetailDocumentProps {
                     team_abbrev: abbrev.clone(),
                     standing,
-                    club_stats: state.data.team_roster_stats.get(abbrev).cloned(),
+                    club_stats: data.team_roster_stats.get(abbrev).cloned(),
+                    roster: data.team_rosters.get(abbrev).cloned(),
+                    schedule: data.team_schedules.get(abbrev).cloned(),
+                    start_time_format: state.system.config.start_time_format.clone(),
                     loading: state
                         .data
                         .loading
                         .contains(&LoadingKey::TeamRosterStats(abbrev.clone())),
                     selected_index: doc_entry.nav.focus_index,
                     scroll_offset: doc_entry.nav.scroll_offset,
+                    find: doc_entry.nav.find.clone(),
                     animation_frame: state.system.animation_frame,
                 };
                 TeamDetailDocument.view(&props, &())
@@ -267,17 +338,37 @@ impl App {
             StackedDocument::PlayerDetail { player_id, .. } => {
                 let props = PlayerDetailDocumentProps {
                     player_id: *player_id,
-                    player_data: state.data.player_data.get(player_id).cloned(),
+                    player_data: data.player_data.get(player_id).cloned(),
+                    game_log: data.player_game_logs.get(player_id).cloned(),
                     loading: state
                         .data
                         .loading
                         .contains(&LoadingKey::PlayerStats(*player_id)),
                     selected_index: doc_entry.nav.focus_index,
                     scroll_offset: doc_entry.nav.scroll_offset,
+                    find: doc_entry.nav.find.clone(),
                     animation_frame: state.system.animation_frame,
                 };
                 PlayerDetailDocument.view(&props, &())
             }
+            StackedDocument::PlayerCompare {
+                player_a, player_b, ..
+            } => {
+                let loading = &state.data.loading;
+                let props = PlayerCompareDocumentProps {
+                    player_a: *player_a,
+                    player_b: *player_b,
+                    player_a_data: data.player_data.get(player_a).cloned(),
+                    player_b_data: data.player_data.get(player_b).cloned(),
+                    loading: loading.contains(&LoadingKey::PlayerStats(*player_a))
+                        || loading.contains(&LoadingKey::PlayerStats(*player_b)),
+                    selected_index: doc_entry.nav.focus_index,
+                    scroll_offset: doc_entry.nav.scroll_offset,
+                    find: doc_entry.nav.find.clone(),
+                    animation_frame: state.system.animation_frame,
+                };
+                PlayerCompareDocument.view(&props, &())
+            }
         }
     }
     /// Render Scores tab content using component state store
@@ -288,12 +379,18 @@ impl App {
     ) -> Element {
         use crate::tui::components::scores_tab::ScoresTab;
 
+        let data = state.data.displayed();
         let props = ScoresTabProps {
-            schedule: state.data.schedule.clone(),
-            game_info: state.data.game_info.clone(),
-            period_scores: state.data.period_scores.clone(),
+            schedule: data.schedule.clone(),
+            game_info: data.game_info.clone(),
+            period_scores: data.period_scores.clone(),
+            favorite_teams: state.system.config.known_favorite_teams(),
+            start_time_format: state.system.config.start_time_format.clone(),
+            highlighted_games: state.ui.scores.score_changes.keys().copied().collect(),
+            game_type: state.ui.season.game_type,
             focused: state.navigation.content_focused,
             animation_frame: state.system.animation_frame,
+            load_error: state.data.errors.get(&FetchKind::Schedule).cloned(),
         };
 
         // Get or initialize component state from the component store
@@ -306,12 +403,18 @@ impl App {
     fn render_scores_tab(&self, state: &AppState) -> Element {
         use crate::tui::components::scores_tab::ScoresTabState;
 
+        let data = state.data.displayed();
         let props = ScoresTabProps {
-            schedule: state.data.schedule.clone(),
-            game_info: state.data.game_info.clone(),
-            period_scores: state.data.period_scores.clone(),
+            schedule: data.schedule.clone(),
+            game_info: data.game_info.clone(),
+            period_scores: data.period_scores.clone(),
+            favorite_teams: state.system.config.known_favorite_teams(),
+            start_time_format: state.system.config.start_time_format.clone(),
+            highlighted_games: state.ui.scores.score_changes.keys().copied().collect(),
+            game_type: state.ui.season.game_type,
             focused: state.navigation.content_focused,
             animation_frame: state.system.animation_frame,
+            load_error: state.data.errors.get(&FetchKind::Schedule).cloned(),
         };
         let component_state = ScoresTabState::default();
         ScoresTab.view(&props, &component_state)
@@ -324,12 +427,14 @@ impl App {
     ) -> Element {
         use crate::tui::components::standings_tab::StandingsTab;
 
+        let data = state.data.displayed();
         let props = StandingsTabProps {
-            standings: state.data.standings.clone(),
+            standings: data.standings.clone(),
             document_stack: state.navigation.document_stack.clone(),
             focused: state.navigation.content_focused,
             config: state.system.config.clone(),
             animation_frame: state.system.animation_frame,
+            load_error: state.data.errors.get(&FetchKind::Standings).cloned(),
         };
 
         let standings_state =
@@ -342,12 +447,14 @@ impl App {
     fn render_standings_tab(&self, state: &AppState) -> Element {
         use crate::tui::components::standings_tab::StandingsTabState;
 
+        let data = state.data.displayed();
         let props = StandingsTabProps {
-            standings: state.data.standings.clone(),
+            standings: data.standings.clone(),
             document_stack: state.navigation.document_stack.clone(),
             focused: state.navigation.content_focused,
             config: state.system.config.clone(),
             animation_frame: state.system.animation_frame,
+            load_error: state.data.errors.get(&FetchKind::Standings).cloned(),
         };
         let component_state = StandingsTabState::default();
         StandingsTab.view(&props, &component_state)
@@ -383,17 +490,58 @@ impl App {
     //
     /// Render breadcrumb navigation
     fn render_breadcrumb(&self, state: &AppState) -> Element {
-        Element::Widget(Box::new(BreadcrumbWidget::new(
+        let mut breadcrumb = BreadcrumbWidget::new(
             state.navigation.current_tab,
             state.navigation.document_stack.clone(),
-        )))
+        )
+        .with_scores_date(state.ui.scores.game_date.clone())
+        .with_season(state.ui.season);
+        if state.viewing_live_game() {
+            breadcrumb = breadcrumb.with_live_indicator(state.system.animation_frame);
+        }
+        Element::Widget(Box::new(breadcrumb))
+    }
+}
+
+/// Centered warning shown instead of the layout on undersized terminals
+#[derive(Clone)]
+struct TerminalTooSmallWidget {
+    min_width: u16,
+    min_height: u16,
+}
+
+impl ElementWidget for TerminalTooSmallWidget {
+    fn render(&self, area: Rect, buf: &mut Buffer, config: &DisplayConfig) {
+        let need = format!("need {}x{}", self.min_width, self.min_height);
+        let message = format!("Terminal too small ({})", need);
+        // Split over two lines when the one-line message doesn't fit
+        let lines = if message.width() as u16 <= area.width {
+            vec![message]
+        } else {
+            vec!["Terminal too small".to_string(), need]
+        };
+
+        let top = area.y + area.height.saturating_sub(lines.len() as u16) / 2;
+        for (y, line) in (top..area.bottom()).zip(&lines) {
+            let width = (line.width() as u16).min(area.width);
+            let x = area.x + (area.width - width) / 2;
+            buf.set_stringn(x, y, line, width as usize, config.text_style());
+        }
+    }
+
+    fn clone_box(&self) -> Box<dyn ElementWidget> {
+        Box::new(self.clone())
     }
 }
 //
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::tui::state::AppState;
+    use crate::fixtures::create_mock_boxscore;
+    use crate::tui::renderer::Renderer;
+    use crate::tui::state::{AppState, DocumentStackEntry};
+    use crate::tui::testing::{assert_buffer, buffer_lines};
+    use std::sync::Arc;
     //
     #[test]
     fn test_app_renders_with_default_state() {
@@ -418,4 +566,77 @@ mod tests {
             _ => panic!("Expected container element"),
         }
     }
+
+    fn render_app(state: &AppState, width: u16, height: u16) -> Buffer {
+        let area = Rect::new(0, 0, width, height);
+        let mut component_states = ComponentStateStore::new();
+        let element = App.build_for_area(state, &mut component_states, area);
+        let mut buf = Buffer::empty(area);
+        Renderer::new().render(element, area, &mut buf, &state.system.config.display);
+        buf
+    }
+
+    #[test]
+    fn test_small_terminal_renders_warning() {
+        let buf = render_app(&AppState::default(), 20, 5);
+
+        assert_buffer(
+            &buf,
+            &["", " Terminal too small", "     need 40x10", "", ""],
+        );
+    }
+
+    #[test]
+    fn test_warning_fits_on_one_line_when_wide_enough() {
+        let mut state = AppState::default();
+        Arc::make_mut(&mut state.system.config).display.min_height = 30;
+
+        let buf = render_app(&state, 80, 24);
+
+        assert_eq!(
+            buffer_lines(&buf)[11].trim(),
+            "Terminal too small (need 40x30)"
+        );
+    }
+
+    fn state_viewing_boxscore(game_id: i64) -> AppState {
+        let mut state = AppState::default();
+        state
+            .navigation
+            .document_stack
+            .push(DocumentStackEntry::new(StackedDocument::Boxscore {
+                game_id,
+                away_abbrev: "TOR".to_string(),
+                home_abbrev: "OTT".to_string(),
+                away_score: 2,
+                home_score: 3,
+            }));
+        Arc::make_mut(&mut state.data.boxscores)
+            .insert(game_id, Arc::new(create_mock_boxscore(game_id)));
+        state
+    }
+
+    #[test]
+    fn test_breadcrumb_marks_live_boxscore() {
+        let has_live = |state: &AppState| {
+            buffer_lines(&render_app(state, 100, 30))
+                .iter()
+                .any(|line| line.contains("▶ TOR:2-OTT:3  ● LIVE"))
+        };
+
+        // Mock game 2024020002 is in progress, 2024020001 is final
+        assert!(has_live(&state_viewing_boxscore(2024020002)));
+        assert!(!has_live(&state_viewing_boxscore(2024020001)));
+    }
+
+    #[test]
+    fn test_large_terminal_renders_layout() {
+        let buf = render_app(&AppState::default(), 80, 24);
+
+        let lines = buffer_lines(&buf);
+        assert!(lines
+            .iter()
+            .all(|line| !line.contains("Terminal too small")));
+        assert!(lines[0].contains("Scores"));
+    }
 }
@@ -1,35 +1,230 @@
+use std::collections::hash_map::DefaultHasher;
+use std::fmt::Write;
+use std::hash::{Hash, Hasher};
 use std::sync::Arc;
 
-use ratatui::{buffer::Buffer, layout::Rect};
+use chrono::FixedOffset;
 
-use nhl_api::{Boxscore, GoalieStats, SkaterStats};
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Modifier, Style},
+};
+
+use nhl_api::{
+    Boxscore, GameState, GameSummary, GoalieStats, PenaltySummary, PeriodDescriptor, PeriodType,
+    Position, SkaterStats, Standing, TeamGameStats, TeamPlayerStats, ThreeStar,
+};
 
 use super::table::TableWidget;
-use crate::config::DisplayConfig;
+use crate::commands::scores_format::PeriodScores;
+use crate::config::{parse_boxscore_sort, DisplayConfig, NumberFormat, DEFAULT_TIME_FORMAT};
+use crate::team_abbrev::abbrev_to_colors;
+use crate::timezone::{parse_start_time, DisplayTimezone};
 use crate::tui::component::{Component, Element, ElementWidget};
 use crate::tui::document::{
-    Document, DocumentBuilder, DocumentElement, DocumentView, FocusContext,
-    TEAM_BOXSCORE_SIDE_BY_SIDE_WIDTH,
+    team_boxscore_side_by_side_width, Document, DocumentBuilder, DocumentElement, DocumentView,
+    FindState, FocusContext, PeriodShots,
 };
-use crate::tui::widgets::{LoadingAnimation, StandaloneWidget};
-use crate::tui::{Alignment, CellValue, ColumnDef};
+use crate::tui::hit_test::HitRegion;
+use crate::tui::table::TableSort;
+use crate::tui::widgets::{BigScore, LoadingAnimation, ScoreBoxStatus, StandaloneWidget};
+use crate::tui::{Alignment, CellValue, ColumnDef, ColumnTotal};
 
 /// View mode for boxscore panel
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub enum TeamView {
     Away,
     Home,
 }
 
+/// Header of each column named in `BOXSCORE_SORT_COLUMNS`
+const SKATER_SORT_COLUMNS: [(&str, &str); 10] = [
+    ("number", "#"),
+    ("goals", "G"),
+    ("assists", "A"),
+    ("points", "PTS"),
+    ("plus_minus", "+/-"),
+    ("sog", "SOG"),
+    ("hits", "Hits"),
+    ("blocks", "Blk"),
+    ("pim", "PIM"),
+    ("toi", "TOI"),
+];
+
+/// Columns cycled through (descending) with `s`, after roster order
+const SORT_CYCLE: [&str; 4] = ["points", "goals", "toi", "plus_minus"];
+
+/// Skater table sort for a boxscore sort such as `"points_desc"`
+///
+/// Returns None (API order) for values `parse_boxscore_sort` rejects.
+pub fn boxscore_table_sort(value: &str) -> Option<TableSort> {
+    let sort = parse_boxscore_sort(value)?;
+    let (_, header) = SKATER_SORT_COLUMNS
+        .iter()
+        .find(|(name, _)| *name == sort.column)?;
+    let column = game_skater_columns(None, false, NumberFormat::default(), false)
+        .iter()
+        .position(|c| c.header == *header)?;
+    Some(TableSort {
+        column,
+        ascending: sort.ascending,
+    })
+}
+
+/// Next sort in the `s` cycle: roster order, then each cycle column descending
+pub fn next_boxscore_sort(current: Option<TableSort>) -> Option<TableSort> {
+    let cycle: Vec<TableSort> = SORT_CYCLE
+        .iter()
+        .filter_map(|name| boxscore_table_sort(&format!("{}_desc", name)))
+        .collect();
+    match current.and_then(|c| cycle.iter().position(|s| *s == c)) {
+        Some(idx) => cycle.get(idx + 1).copied(),
+        None => cycle.first().copied(),
+    }
+}
+
+/// Leading skater/goalie columns (`#` and Player) kept in place when scrolling
+const FROZEN_COLUMNS: usize = 2;
+
+/// Largest useful horizontal offset for the skater table
+pub fn max_column_offset() -> usize {
+    game_skater_columns(None, false, NumberFormat::default(), false)
+        .len()
+        .saturating_sub(FROZEN_COLUMNS + 1)
+}
+
+/// Header of the skater column a sort applies to
+fn sort_header(sort: TableSort) -> Option<&'static str> {
+    let columns = game_skater_columns(None, false, NumberFormat::default(), false);
+    let header = &columns.get(sort.column)?.header;
+    SKATER_SORT_COLUMNS
+        .iter()
+        .map(|(_, h)| *h)
+        .find(|h| h == header)
+}
+
+fn skater_sort_value(skater: &SkaterStats, header: &str) -> i32 {
+    match header {
+        "#" => skater.sweater_number,
+        "G" => skater.goals,
+        "A" => skater.assists,
+        "PTS" => skater.points,
+        "+/-" => skater.plus_minus,
+        "SOG" => skater.sog,
+        "Hits" => skater.hits,
+        "Blk" => skater.blocked_shots,
+        "PIM" => skater.pim,
+        "TOI" => toi_seconds(&skater.toi),
+        _ => 0,
+    }
+}
+
+/// Goalie tables only share the number, TOI and PIM columns with skaters
+const GOALIE_SORT_HEADERS: [&str; 3] = ["#", "TOI", "PIM"];
+
+fn goalie_sort_value(goalie: &GoalieStats, header: &str) -> i32 {
+    match header {
+        "#" => goalie.sweater_number,
+        "TOI" => toi_seconds(&goalie.toi),
+        "PIM" => goalie.pim.unwrap_or(0),
+        _ => 0,
+    }
+}
+
+/// Sort skaters by the given sort; ties keep roster order
+pub fn sort_skaters(skaters: &mut [SkaterStats], sort: Option<TableSort>) {
+    let Some((sort, header)) = sort.and_then(|s| Some((s, sort_header(s)?))) else {
+        return;
+    };
+    skaters.sort_by(|a, b| {
+        let ordering = skater_sort_value(a, header).cmp(&skater_sort_value(b, header));
+        if sort.ascending {
+            ordering
+        } else {
+            ordering.reverse()
+        }
+    });
+}
+
+/// Sort goalies by the given sort when it applies to them; ties keep roster order
+pub fn sort_goalies(goalies: &mut [GoalieStats], sort: Option<TableSort>) {
+    let Some((sort, header)) = sort.and_then(|s| Some((s, sort_header(s)?))) else {
+        return;
+    };
+    if !GOALIE_SORT_HEADERS.contains(&header) {
+        return;
+    }
+    goalies.sort_by(|a, b| {
+        let ordering = goalie_sort_value(a, header).cmp(&goalie_sort_value(b, header));
+        if sort.ascending {
+            ordering
+        } else {
+            ordering.reverse()
+        }
+    });
+}
+
+/// Away and home records ("W-L-OT") from standings, when both teams are listed
+pub fn team_records(
+    standings: Option<&[Standing]>,
+    away_abbrev: &str,
+    home_abbrev: &str,
+) -> Option<(String, String)> {
+    let standings = standings?;
+    let record = |abbrev: &str| {
+        standings
+            .iter()
+            .find(|s| s.team_abbrev.default == abbrev)
+            .map(|s| format!("{}-{}-{}", s.wins, s.losses, s.ot_losses))
+    };
+    Some((record(away_abbrev)?, record(home_abbrev)?))
+}
+
+/// Skaters tied for the team lead in points, with goals breaking ties
+///
+/// Empty when nobody has a point yet.
+fn game_leaders(team_stats: &TeamPlayerStats) -> Vec<&SkaterStats> {
+    let skaters = team_stats.forwards.iter().chain(&team_stats.defense);
+    let Some(best) = skaters.clone().map(|s| (s.points, s.goals)).max() else {
+        return Vec::new();
+    };
+    if best.0 == 0 {
+        return Vec::new();
+    }
+    skaters.filter(|s| (s.points, s.goals) == best).collect()
+}
+
+/// Parse a "MM:SS" time-on-ice string into seconds (0 if malformed)
+fn toi_seconds(toi: &str) -> i32 {
+    let mut parts = toi.splitn(2, ':');
+    let minutes = parts.next().and_then(|m| m.trim().parse::<i32>().ok());
+    let seconds = parts.next().and_then(|s| s.trim().parse::<i32>().ok());
+    match (minutes, seconds) {
+        (Some(m), Some(s)) => m * 60 + s,
+        _ => 0,
+    }
+}
+
 /// BoxscoreDocument component props
 #[derive(Clone)]
 pub struct BoxscoreDocumentProps {
     pub game_id: i64,
-    pub boxscore: Option<Boxscore>,
-    pub loading: bool,
+    pub boxscore: Option<Arc<Boxscore>>,
+    pub period_scores: Option<PeriodScores>,
+    pub game_summary: Option<GameSummary>,
+    /// Away and home records, shown under the big score before the game starts
+    pub records: Option<(String, String)>,
+    /// Format for the local start time of scheduled games (`Config.time_format`)
+    pub time_format: String,
     pub team_view: TeamView,
+    pub sort: Option<TableSort>,
+    /// Show skater counting stats per 60 minutes of ice time
+    pub per_60: bool,
+    pub column_offset: usize,
     pub selected_index: Option<usize>,
     pub scroll_offset: u16,
+    pub find: Option<FindState>,
     pub focused: bool,
     pub animation_frame: u8,
 }
@@ -46,10 +241,17 @@ impl Component for BoxscoreDocument {
         Element::Widget(Box::new(BoxscoreDocumentWidget {
             game_id: props.game_id,
             boxscore: props.boxscore.clone(),
-            loading: props.loading,
+            period_scores: props.period_scores.clone(),
+            game_summary: props.game_summary.clone(),
+            records: props.records.clone(),
+            time_format: props.time_format.clone(),
             team_view: props.team_view.clone(),
+            sort: props.sort,
+            per_60: props.per_60,
+            column_offset: props.column_offset,
             selected_index: props.selected_index,
             scroll_offset: props.scroll_offset,
+            find: props.find.clone(),
             focused: props.focused,
             animation_frame: props.animation_frame,
         }))
@@ -59,19 +261,109 @@ impl Component for BoxscoreDocument {
 /// Document content for boxscore view
 pub struct BoxscoreDocumentContent {
     pub game_id: i64,
-    pub boxscore: Boxscore,
+    pub boxscore: Arc<Boxscore>,
     pub team_view: TeamView,
+    /// Goals by period from the game summary (line score is omitted when missing)
+    pub period_scores: Option<PeriodScores>,
+    /// Game summary from the landing endpoint (special teams are omitted when missing)
+    pub game_summary: Option<GameSummary>,
+    /// Away and home records, shown under the big score for scheduled games
+    pub records: Option<(String, String)>,
+    /// Shots on goal by period (the chart is omitted when missing)
+    pub shots_by_period: Option<Vec<PeriodShots>>,
+    /// Sort key applied to the skater and goalie tables
+    pub sort: Option<TableSort>,
+    /// Whether skater counting stats are shown per 60 minutes of ice time
+    pub per_60: bool,
+    /// Horizontal scroll offset for the skater and goalie tables
+    pub column_offset: usize,
+    /// Whether the goalie tables compare save percentage to .900
+    pub goalie_save_diff: bool,
+    /// Format for the local start time of scheduled games
+    pub time_format: String,
+    /// How SV% and FO% are written
+    pub numbers: NumberFormat,
 }
 
 impl BoxscoreDocumentContent {
-    pub fn new(game_id: i64, boxscore: Boxscore, team_view: TeamView) -> Self {
+    pub fn new(game_id: i64, boxscore: impl Into<Arc<Boxscore>>, team_view: TeamView) -> Self {
         Self {
             game_id,
-            boxscore,
+            boxscore: boxscore.into(),
             team_view,
+            period_scores: None,
+            game_summary: None,
+            records: None,
+            shots_by_period: None,
+            sort: None,
+            per_60: false,
+            column_offset: 0,
+            goalie_save_diff: false,
+            time_format: DEFAULT_TIME_FORMAT.to_string(),
+            numbers: NumberFormat::default(),
         }
     }
 
+    /// Set the period-by-period scores used for the line score table
+    pub fn with_period_scores(mut self, period_scores: Option<PeriodScores>) -> Self {
+        self.period_scores = period_scores;
+        self
+    }
+
+    /// Set the game summary used for the special-teams section
+    pub fn with_game_summary(mut self, game_summary: Option<GameSummary>) -> Self {
+        self.game_summary = game_summary;
+        self
+    }
+
+    /// Set the team records shown under the big score before the game starts
+    pub fn with_records(mut self, records: Option<(String, String)>) -> Self {
+        self.records = records;
+        self
+    }
+
+    /// Set the per-period shots used for the shots chart
+    pub fn with_shots_by_period(mut self, shots_by_period: Option<Vec<PeriodShots>>) -> Self {
+        self.shots_by_period = shots_by_period;
+        self
+    }
+
+    /// Set the sort for the skater and goalie tables (None keeps API order)
+    pub fn with_sort(mut self, sort: Option<TableSort>) -> Self {
+        self.sort = sort;
+        self
+    }
+
+    /// Show skater counting stats per 60 minutes of ice time instead of totals
+    pub fn with_per_60(mut self, per_60: bool) -> Self {
+        self.per_60 = per_60;
+        self
+    }
+
+    /// Scroll the skater and goalie tables horizontally past the frozen columns
+    pub fn with_column_offset(mut self, column_offset: usize) -> Self {
+        self.column_offset = column_offset;
+        self
+    }
+
+    /// Add a column with each goalie's save percentage above or below .900
+    pub fn with_goalie_save_diff(mut self, goalie_save_diff: bool) -> Self {
+        self.goalie_save_diff = goalie_save_diff;
+        self
+    }
+
+    /// Set the format for the local start time of scheduled games
+    pub fn with_time_format(mut self, time_format: impl Into<String>) -> Self {
+        self.time_format = time_format.into();
+        self
+    }
+
+    /// Set how SV% and FO% are written
+    pub fn with_number_format(mut self, numbers: NumberFormat) -> Self {
+        self.numbers = numbers;
+        self
+    }
+
     /// Build header section with game info
     fn build_header(&self) -> Vec<DocumentElement> {
         let boxscore = &self.boxscore;
@@ -81,10 +373,13 @@ impl BoxscoreDocumentContent {
             boxscore.away_team.common_name.default, boxscore.home_team.common_name.default
         );
 
-        let date_venue = format!(
+        let mut date_venue = format!(
             "Date: {} | Venue: {}",
             boxscore.game_date, boxscore.venue.default
         );
+        if !boxscore.venue_location.default.is_empty() {
+            date_venue.push_str(&format!(", {}", boxscore.venue_location.default));
+        }
 
         let period_text = format_period_text(
             &boxscore.period_descriptor.number,
@@ -96,7 +391,21 @@ impl BoxscoreDocumentContent {
             period_text
         );
 
-        let time_info = if boxscore.clock.running || !boxscore.clock.in_intermission {
+        let scheduled = matches!(boxscore.game_state, GameState::Future | GameState::PreGame);
+        let start_time = scheduled
+            .then(|| {
+                venue_start_time(
+                    &boxscore.start_time_utc,
+                    &boxscore.venue_utc_offset,
+                    &self.time_format,
+                )
+            })
+            .flatten();
+
+        // The game clock means nothing before puck drop, so show the start time instead
+        let time_info = if let Some(start_time) = start_time {
+            format!("Start: {}", start_time)
+        } else if boxscore.clock.running || !boxscore.clock.in_intermission {
             format!("Time: {}", boxscore.clock.time_remaining)
         } else if boxscore.clock.in_intermission {
             "INTERMISSION".to_string()
@@ -112,17 +421,53 @@ impl BoxscoreDocumentContent {
         ]
     }
 
+    /// Away and home goals in the shootout, when the game summary has one
+    fn shootout_goals(&self) -> Option<(usize, usize)> {
+        let attempts = self.game_summary.as_ref()?.shootout.as_ref()?;
+        if attempts.is_empty() {
+            return None;
+        }
+        let goals = |abbrev: &str| {
+            attempts
+                .iter()
+                .filter(|attempt| {
+                    attempt.team_abbrev.default == abbrev
+                        && attempt.result.eq_ignore_ascii_case("goal")
+                })
+                .count()
+        };
+        Some((
+            goals(&self.boxscore.away_team.abbrev),
+            goals(&self.boxscore.home_team.abbrev),
+        ))
+    }
+
     /// Build score section - uses big digits if unicode enabled, otherwise text
     fn build_score(&self, focus: &FocusContext) -> Vec<DocumentElement> {
         let boxscore = &self.boxscore;
+        let mut elements = Vec::new();
 
         if focus.use_unicode {
-            vec![DocumentElement::big_score(
+            // Records give context before puck drop; once play starts the score is the focus
+            let records = match boxscore.game_state {
+                GameState::Future | GameState::PreGame => self.records.clone(),
+                _ => None,
+            };
+            let big_score = BigScore::new(
                 &boxscore.away_team.abbrev,
                 &boxscore.home_team.abbrev,
                 boxscore.away_team.score,
                 boxscore.home_team.score,
-            )]
+            )
+            .with_records(records)
+            .with_status(
+                boxscore
+                    .game_state
+                    .is_final()
+                    .then(|| ScoreBoxStatus::final_for(&boxscore.period_descriptor)),
+            )
+            .with_shootout_goals(self.shootout_goals());
+            elements.push(DocumentElement::BigScoreElement { big_score });
         } else {
             let score_text = format!(
                 "{}: {}  |  {}: {}",
@@ -131,11 +476,363 @@ impl BoxscoreDocumentContent {
                 boxscore.home_team.abbrev,
                 boxscore.home_team.score
             );
-            vec![
-                DocumentElement::heading(2, "SCORE"),
-                DocumentElement::text(&score_text),
-            ]
+            elements.push(DocumentElement::heading(2, "SCORE"));
+            elements.push(DocumentElement::text(&score_text));
+        }
+
+        if let Some(tv) = self.tv_line() {
+            elements.push(DocumentElement::text(tv));
+        }
+        elements
+    }
+
+    /// Broadcasters line, e.g. "TV: ESPN+, SN", for games not yet final
+    ///
+    /// None once the game is over or when no broadcasts are listed.
+    fn tv_line(&self) -> Option<String> {
+        let boxscore = &self.boxscore;
+        if boxscore.game_state.is_final() {
+            return None;
+        }
+
+        let mut broadcasts: Vec<_> = boxscore.tv_broadcasts.iter().collect();
+        broadcasts.sort_by_key(|broadcast| broadcast.sequence_number);
+        let mut networks: Vec<&str> = Vec::new();
+        for broadcast in broadcasts {
+            if !networks.contains(&broadcast.network.as_str()) {
+                networks.push(&broadcast.network);
+            }
+        }
+        if networks.is_empty() {
+            return None;
+        }
+        Some(format!("TV: {}", networks.join(", ")))
+    }
+
+    /// Build the period-by-period line score table
+    ///
+    /// Returns None for games that haven't started or when period scores
+    /// haven't been loaded yet.
+    fn build_line_score(&self) -> Option<TableWidget> {
+        let boxscore = &self.boxscore;
+        if matches!(boxscore.game_state, GameState::Future | GameState::PreGame) {
+            return None;
+        }
+        let period_scores = self.period_scores.as_ref()?;
+
+        let period_type = boxscore.period_descriptor.period_type;
+        let has_ot = matches!(period_type, PeriodType::Overtime | PeriodType::Shootout);
+        let has_so = period_type == PeriodType::Shootout;
+        let current_period = boxscore.period_descriptor.number;
+
+        let rows = vec![
+            LineScoreRow::new(
+                &boxscore.away_team.abbrev,
+                &period_scores.away_periods,
+                current_period,
+                has_ot,
+                has_so,
+                boxscore.away_team.score,
+                boxscore.away_team.sog,
+            ),
+            LineScoreRow::new(
+                &boxscore.home_team.abbrev,
+                &period_scores.home_periods,
+                current_period,
+                has_ot,
+                has_so,
+                boxscore.home_team.score,
+                boxscore.home_team.sog,
+            ),
+        ];
+
+        Some(TableWidget::from_data(
+            &line_score_columns(has_ot, has_so, true),
+            rows,
+        ))
+    }
+
+    /// Build the period-by-period shots table, in the line score's layout
+    ///
+    /// Returns None for games that haven't started or when shots by period
+    /// haven't been loaded yet. Shootout attempts aren't shots on goal, so
+    /// there is no SO column.
+    fn build_shots_line_score(&self) -> Option<TableWidget> {
+        let boxscore = &self.boxscore;
+        if matches!(boxscore.game_state, GameState::Future | GameState::PreGame) {
+            return None;
+        }
+        let shots = self.shots_by_period.as_ref()?;
+
+        let has_ot = matches!(
+            boxscore.period_descriptor.period_type,
+            PeriodType::Overtime | PeriodType::Shootout
+        );
+        let current_period = boxscore.period_descriptor.number;
+        let away: Vec<i32> = shots.iter().map(|period| period.away).collect();
+        let home: Vec<i32> = shots.iter().map(|period| period.home).collect();
+
+        let rows = vec![
+            LineScoreRow::new(
+                &boxscore.away_team.abbrev,
+                &away,
+                current_period,
+                has_ot,
+                false,
+                boxscore.away_team.sog,
+                boxscore.away_team.sog,
+            ),
+            LineScoreRow::new(
+                &boxscore.home_team.abbrev,
+                &home,
+                current_period,
+                has_ot,
+                false,
+                boxscore.home_team.sog,
+                boxscore.home_team.sog,
+            ),
+        ];
+
+        Some(TableWidget::from_data(
+            &line_score_columns(has_ot, false, false),
+            rows,
+        ))
+    }
+
+    /// Build the shots-by-period chart, limited to the periods played so far
+    ///
+    /// Returns None for games that haven't started or when shots by period
+    /// haven't been loaded yet.
+    fn build_shots_by_period(&self) -> Option<DocumentElement> {
+        let boxscore = &self.boxscore;
+        if matches!(boxscore.game_state, GameState::Future | GameState::PreGame) {
+            return None;
+        }
+        let played = boxscore.period_descriptor.number.max(0) as usize;
+        let periods: Vec<PeriodShots> = self
+            .shots_by_period
+            .as_ref()?
+            .iter()
+            .take(played)
+            .cloned()
+            .collect();
+        if periods.is_empty() {
+            return None;
+        }
+        Some(DocumentElement::shots_by_period(
+            &boxscore.away_team.abbrev,
+            &boxscore.home_team.abbrev,
+            periods,
+        ))
+    }
+
+    /// Build the team summary table: PP, PK, faceoff %, hits and blocks per team
+    ///
+    /// Returns None for games that haven't started. PP and PK show "-" until
+    /// the game summary (needed for penalty counts) has been loaded.
+    fn build_team_summary(&self) -> Option<TableWidget> {
+        let boxscore = &self.boxscore;
+        if matches!(boxscore.game_state, GameState::Future | GameState::PreGame) {
+            return None;
+        }
+
+        let players = &boxscore.player_by_game_stats;
+        let away_stats = TeamGameStats::from_team_player_stats(&players.away_team);
+        let home_stats = TeamGameStats::from_team_player_stats(&players.home_team);
+        let special_teams = self.game_summary.as_ref().map(|summary| {
+            (
+                SpecialTeams::new(
+                    away_stats.power_play_goals,
+                    power_play_opportunities(summary, &boxscore.home_team.abbrev),
+                ),
+                SpecialTeams::new(
+                    home_stats.power_play_goals,
+                    power_play_opportunities(summary, &boxscore.away_team.abbrev),
+                ),
+            )
+        });
+
+        let row = |abbrev: &str,
+                   stats: &TeamGameStats,
+                   players: &TeamPlayerStats,
+                   special: Option<(SpecialTeams, SpecialTeams)>| {
+            let (power_play, penalty_kill) = special.map_or_else(
+                || ("-".to_string(), "-".to_string()),
+                |(own, opponent)| (own.power_play(), own.penalty_kill(&opponent)),
+            );
+            TeamSummaryRow {
+                team: abbrev.to_string(),
+                power_play,
+                penalty_kill,
+                faceoff_pct: team_faceoff_pct(players),
+                hits: stats.hits,
+                blocks: stats.blocked_shots,
+            }
+        };
+        let rows = vec![
+            row(
+                &boxscore.away_team.abbrev,
+                &away_stats,
+                &players.away_team,
+                special_teams,
+            ),
+            row(
+                &boxscore.home_team.abbrev,
+                &home_stats,
+                &players.home_team,
+                special_teams.map(|(away, home)| (home, away)),
+            ),
+        ];
+
+        Some(TableWidget::from_data(
+            &team_summary_columns(self.numbers),
+            rows,
+        ))
+    }
+
+    /// Build the penalty summary: per team, a label in the team's color and a table
+    ///
+    /// Returns an empty list for games that haven't started, or when the game
+    /// summary hasn't been loaded or lists no penalties.
+    fn build_penalties(&self) -> Vec<DocumentElement> {
+        let boxscore = &self.boxscore;
+        if matches!(boxscore.game_state, GameState::Future | GameState::PreGame) {
+            return Vec::new();
+        }
+        let Some(summary) = self.game_summary.as_ref() else {
+            return Vec::new();
+        };
+
+        let mut elements = Vec::new();
+        for (abbrev, table_name) in [
+            (&boxscore.away_team.abbrev, AWAY_PENALTIES_TABLE),
+            (&boxscore.home_team.abbrev, HOME_PENALTIES_TABLE),
+        ] {
+            let rows: Vec<PenaltyRow> = summary
+                .penalties
+                .iter()
+                .flat_map(|period| {
+                    period
+                        .penalties
+                        .iter()
+                        .filter(|penalty| penalty.team_abbrev.default == *abbrev)
+                        .map(|penalty| PenaltyRow::new(&period.period_descriptor, penalty))
+                })
+                .collect();
+            if rows.is_empty() {
+                continue;
+            }
+
+            let team_style = abbrev_to_colors(abbrev)
+                .map(|(primary, _)| Style::default().fg(primary))
+                .unwrap_or_default()
+                .add_modifier(Modifier::BOLD);
+            elements.push(DocumentElement::styled_text(abbrev.clone(), team_style));
+            elements.push(DocumentElement::table(
+                table_name,
+                TableWidget::from_data(&penalty_columns(), rows),
+            ));
+        }
+        elements
+    }
+
+    /// Build the team-stats comparison bars (away vs home)
+    ///
+    /// Returns an empty list for games that haven't started.
+    fn build_team_comparison(&self) -> Vec<DocumentElement> {
+        let boxscore = &self.boxscore;
+        if matches!(boxscore.game_state, GameState::Future | GameState::PreGame) {
+            return Vec::new();
+        }
+
+        let away = TeamGameStats::from_team_player_stats(&boxscore.player_by_game_stats.away_team);
+        let home = TeamGameStats::from_team_player_stats(&boxscore.player_by_game_stats.home_team);
+        let away_fo = team_faceoff_pct(&boxscore.player_by_game_stats.away_team);
+        let home_fo = team_faceoff_pct(&boxscore.player_by_game_stats.home_team);
+        let fo_label = |pct: Option<f64>| {
+            pct.map_or("-".to_string(), |pct| {
+                format!("{}%", self.numbers.percent(pct))
+            })
+        };
+
+        let count_bar = |label: &str, away_value: i32, home_value: i32| {
+            DocumentElement::compare_bar(
+                label,
+                away_value.to_string(),
+                home_value.to_string(),
+                away_value as f64,
+                home_value as f64,
+            )
+        };
+
+        vec![
+            count_bar("Shots", boxscore.away_team.sog, boxscore.home_team.sog),
+            count_bar("Hits", away.hits, home.hits),
+            count_bar("PIM", away.penalty_minutes, home.penalty_minutes),
+            count_bar("PP Goals", away.power_play_goals, home.power_play_goals),
+            DocumentElement::compare_bar(
+                "Faceoff %",
+                fo_label(away_fo),
+                fo_label(home_fo),
+                away_fo.unwrap_or(0.0),
+                home_fo.unwrap_or(0.0),
+            ),
+        ]
+    }
+
+    /// Build the game leaders table: each team's top point-getter(s)
+    ///
+    /// Returns None for games that haven't started or where nobody has scored.
+    fn build_game_leaders(&self, focus: &FocusContext) -> Option<TableWidget> {
+        let boxscore = &self.boxscore;
+        if matches!(boxscore.game_state, GameState::Future | GameState::PreGame) {
+            return None;
+        }
+
+        let stats = &boxscore.player_by_game_stats;
+        let rows: Vec<GameLeader> = [
+            (&boxscore.away_team.abbrev, &stats.away_team),
+            (&boxscore.home_team.abbrev, &stats.home_team),
+        ]
+        .into_iter()
+        .flat_map(|(team, team_stats)| {
+            game_leaders(team_stats)
+                .into_iter()
+                .map(|skater| GameLeader {
+                    team: team.clone(),
+                    skater: skater.clone(),
+                })
+        })
+        .collect();
+        if rows.is_empty() {
+            return None;
+        }
+
+        Some(
+            TableWidget::from_data(&game_leader_columns(), rows)
+                .with_focused_row(focus.focused_table_row(GAME_LEADERS_TABLE)),
+        )
+    }
+
+    /// Build the three stars table for completed games
+    ///
+    /// Returns None before the game is final or when the game summary has no stars.
+    fn build_three_stars(&self, focus: &FocusContext) -> Option<TableWidget> {
+        if !self.boxscore.game_state.is_final() {
+            return None;
         }
+        let mut stars = self.game_summary.as_ref()?.three_stars.clone()?;
+        if stars.is_empty() {
+            return None;
+        }
+        stars.sort_by_key(|star| star.star);
+        let count = stars.len();
+
+        Some(
+            TableWidget::from_data(&three_star_columns(self.numbers), stars)
+                .with_emphasized_rows(count)
+                .with_focused_row(focus.focused_table_row(THREE_STARS_TABLE)),
+        )
     }
 
     /// Build a skater table (forwards or defense)
@@ -145,9 +842,15 @@ impl BoxscoreDocumentContent {
         table_id: &str,
         focus: &FocusContext,
     ) -> TableWidget {
-        let columns = game_skater_columns();
-        TableWidget::from_data(&columns, skaters.to_vec())
+        let columns = game_skater_columns(self.sort, focus.use_unicode, self.numbers, self.per_60);
+        let mut rows = skaters.to_vec();
+        sort_skaters(&mut rows, self.sort);
+        TableWidget::from_data(&columns, rows)
+            .with_totals(&columns, skaters)
             .with_focused_row(focus.focused_table_row(table_id))
+            .with_zebra(true)
+            .with_frozen_columns(FROZEN_COLUMNS)
+            .with_column_offset(self.column_offset)
     }
 
     /// Build a goalies table
@@ -157,9 +860,18 @@ impl BoxscoreDocumentContent {
         table_id: &str,
         focus: &FocusContext,
     ) -> TableWidget {
-        let columns = game_goalie_columns();
-        TableWidget::from_data(&columns, goalies.to_vec())
+        let columns = game_goalie_columns(
+            self.sort,
+            focus.use_unicode,
+            self.goalie_save_diff,
+            self.numbers,
+        );
+        let mut rows = goalies.to_vec();
+        sort_goalies(&mut rows, self.sort);
+        TableWidget::from_data(&columns, rows)
             .with_focused_row(focus.focused_table_row(table_id))
+            .with_frozen_columns(FROZEN_COLUMNS)
+            .with_column_offset(self.column_offset)
     }
 
     /// Build player stats section for one team using TeamBoxscore element
@@ -198,27 +910,79 @@ impl BoxscoreDocumentContent {
 
 impl Document for BoxscoreDocumentContent {
     fn build(&self, focus: &FocusContext) -> Vec<DocumentElement> {
-        let mut builder = DocumentBuilder::new();
+        let mut builder = DocumentBuilder::new().compact(focus.compact_spacing);
 
         // Header section
         for elem in self.build_header() {
             builder = builder.element(elem);
         }
-        builder = builder.spacer(1);
+        builder = builder.decorative_spacer(1);
+
+        // Special event (Winter Classic, Stadium Series, ...) called out above the score
+        if let Some(event) = &self.boxscore.special_event {
+            let star = if focus.use_unicode { "★" } else { "*" };
+            builder = builder.element(DocumentElement::banner(format!(
+                "{} {} {}",
+                star, event.name.default, star
+            )));
+            builder = builder.decorative_spacer(1);
+        }
 
         // Score section
         for elem in self.build_score(focus) {
             builder = builder.element(elem);
         }
-        builder = builder.spacer(1);
+        builder = builder.decorative_spacer(1);
+
+        // Line score (goals by period, then shots by period when loaded)
+        if let Some(line_score) = self.build_line_score() {
+            builder = builder.table("line_score", line_score);
+            match self.build_shots_line_score() {
+                Some(shots) => {
+                    builder = builder.decorative_spacer(1);
+                    builder = builder.table("line_score_shots", shots);
+                }
+                None => {
+                    builder = builder.element(DocumentElement::styled_text(
+                        "Shots by period unavailable",
+                        Style::default().add_modifier(Modifier::DIM),
+                    ));
+                }
+            }
+            builder = builder.decorative_spacer(1);
+        }
+
+        // Team summary (special teams, faceoffs, hits, blocks)
+        if let Some(team_summary) = self.build_team_summary() {
+            builder = builder.table("team_summary", team_summary);
+            builder = builder.decorative_spacer(1);
+        }
+
+        // Shots on goal by period
+        if let Some(shots) = self.build_shots_by_period() {
+            builder = builder.element(DocumentElement::section_title("Shots by Period", false));
+            builder = builder.element(shots);
+            builder = builder.decorative_spacer(1);
+        }
+
+        // Team stats comparison
+        let comparison = self.build_team_comparison();
+        if !comparison.is_empty() {
+            builder = builder.element(DocumentElement::section_title("Team Stats", false));
+            for elem in comparison {
+                builder = builder.element(elem);
+            }
+            builder = builder.decorative_spacer(1);
+        }
 
         // Player stats - side by side if wide enough, otherwise stacked
         let away_boxscore = self.build_team_boxscore(focus, true);
         let home_boxscore = self.build_team_boxscore(focus, false);
 
+        let side_by_side_width = team_boxscore_side_by_side_width(&away_boxscore, &home_boxscore);
         let wide_enough = focus
             .available_width
-            .map(|w| w >= TEAM_BOXSCORE_SIDE_BY_SIDE_WIDTH)
+            .map(|w| w >= side_by_side_width)
             .unwrap_or(false);
 
         if wide_enough {
@@ -229,6 +993,31 @@ impl Document for BoxscoreDocumentContent {
             builder = builder.element(home_boxscore);
         }
 
+        if let Some(leaders) = self.build_game_leaders(focus) {
+            builder = builder.decorative_spacer(1);
+            builder = builder.element(DocumentElement::group(vec![
+                DocumentElement::section_title("Game Leaders", false),
+                DocumentElement::table(GAME_LEADERS_TABLE, leaders),
+            ]));
+        }
+
+        let penalties = self.build_penalties();
+        if !penalties.is_empty() {
+            builder = builder.decorative_spacer(1);
+            builder = builder.element(DocumentElement::section_title("Penalties", false));
+            for elem in penalties {
+                builder = builder.element(elem);
+            }
+        }
+
+        if let Some(stars) = self.build_three_stars(focus) {
+            builder = builder.decorative_spacer(1);
+            builder = builder.element(DocumentElement::group(vec![
+                DocumentElement::section_title("Three Stars", false),
+                DocumentElement::table(THREE_STARS_TABLE, stars),
+            ]));
+        }
+
         builder.build()
     }
 
@@ -242,101 +1031,683 @@ impl Document for BoxscoreDocumentContent {
     fn id(&self) -> String {
         format!("boxscore_{}", self.game_id)
     }
+
+    /// Changes with the score, game state and clock, plus the view options
+    fn content_version(&self) -> u64 {
+        let boxscore = &self.boxscore;
+        let mut hasher = DefaultHasher::new();
+        self.game_id.hash(&mut hasher);
+        boxscore.away_team.score.hash(&mut hasher);
+        boxscore.home_team.score.hash(&mut hasher);
+        boxscore.game_state.hash(&mut hasher);
+        boxscore.period_descriptor.number.hash(&mut hasher);
+        boxscore.clock.time_remaining.hash(&mut hasher);
+        boxscore.clock.in_intermission.hash(&mut hasher);
+        self.period_scores.is_some().hash(&mut hasher);
+        self.game_summary.is_some().hash(&mut hasher);
+        self.game_summary
+            .as_ref()
+            .and_then(|summary| summary.three_stars.as_ref())
+            .map(Vec::len)
+            .hash(&mut hasher);
+        self.shootout_goals().hash(&mut hasher);
+        self.records.hash(&mut hasher);
+        self.shots_by_period.hash(&mut hasher);
+        self.team_view.hash(&mut hasher);
+        self.sort.hash(&mut hasher);
+        self.per_60.hash(&mut hasher);
+        self.column_offset.hash(&mut hasher);
+        self.goalie_save_diff.hash(&mut hasher);
+        self.time_format.hash(&mut hasher);
+        hasher.finish()
+    }
 }
 
-/// Column definitions for game-level skater stats
-fn game_skater_columns() -> Vec<ColumnDef<SkaterStats>> {
+/// Table name of the game leaders section
+const GAME_LEADERS_TABLE: &str = "game_leaders";
+
+/// A team's top point-getter in the game leaders table
+struct GameLeader {
+    team: String,
+    skater: SkaterStats,
+}
+
+fn game_leader_columns() -> Vec<ColumnDef<GameLeader>> {
     vec![
-        ColumnDef::new("Player", 20, Alignment::Left, |s: &SkaterStats| {
+        ColumnDef::new("Team", 4, Alignment::Left, |l: &GameLeader| {
+            CellValue::Text(l.team.clone())
+        }),
+        ColumnDef::new("Player", 20, Alignment::Left, |l: &GameLeader| {
             CellValue::PlayerLink {
-                display: s.name.default.clone(),
-                player_id: s.player_id,
+                display: l.skater.name.default.clone(),
+                player_id: l.skater.player_id,
             }
+        })
+        .auto_width()
+        .with_max_width(PLAYER_COLUMN_MAX_WIDTH),
+        ColumnDef::new("G", 2, Alignment::Right, |l: &GameLeader| {
+            CellValue::Text(l.skater.goals.to_string())
         }),
-        ColumnDef::new("Pos", 3, Alignment::Center, |s: &SkaterStats| {
-            CellValue::Text(s.position.to_string())
-        }),
-        ColumnDef::new("G", 2, Alignment::Right, |s: &SkaterStats| {
-            CellValue::Text(s.goals.to_string())
-        }),
-        ColumnDef::new("A", 2, Alignment::Right, |s: &SkaterStats| {
-            CellValue::Text(s.assists.to_string())
-        }),
-        ColumnDef::new("PTS", 3, Alignment::Right, |s: &SkaterStats| {
-            CellValue::Text(s.points.to_string())
-        }),
-        ColumnDef::new("+/-", 3, Alignment::Right, |s: &SkaterStats| {
-            CellValue::Text(format!("{:+}", s.plus_minus))
-        }),
-        ColumnDef::new("SOG", 3, Alignment::Right, |s: &SkaterStats| {
-            CellValue::Text(s.sog.to_string())
-        }),
-        ColumnDef::new("Hits", 4, Alignment::Right, |s: &SkaterStats| {
-            CellValue::Text(s.hits.to_string())
-        }),
-        ColumnDef::new("Blk", 3, Alignment::Right, |s: &SkaterStats| {
-            CellValue::Text(s.blocked_shots.to_string())
-        }),
-        ColumnDef::new("PIM", 3, Alignment::Right, |s: &SkaterStats| {
-            CellValue::Text(s.pim.to_string())
-        }),
-        ColumnDef::new("FO%", 5, Alignment::Right, |s: &SkaterStats| {
-            if s.faceoff_winning_pctg > 0.0 {
-                CellValue::Text(format!("{:.1}", s.faceoff_winning_pctg * 100.0))
-            } else {
-                CellValue::Text("-".to_string())
-            }
+        ColumnDef::new("A", 2, Alignment::Right, |l: &GameLeader| {
+            CellValue::Text(l.skater.assists.to_string())
         }),
-        ColumnDef::new("TOI", 6, Alignment::Right, |s: &SkaterStats| {
-            CellValue::Text(s.toi.clone())
+        ColumnDef::new("PTS", 3, Alignment::Right, |l: &GameLeader| {
+            CellValue::Text(l.skater.points.to_string())
         }),
     ]
 }
 
-/// Column definitions for game-level goalie stats
-fn game_goalie_columns() -> Vec<ColumnDef<GoalieStats>> {
-    vec![
-        ColumnDef::new("Player", 20, Alignment::Left, |g: &GoalieStats| {
-            CellValue::PlayerLink {
-                display: g.name.default.clone(),
-                player_id: g.player_id,
-            }
-        }),
-        ColumnDef::new("SA", 3, Alignment::Right, |g: &GoalieStats| {
-            CellValue::Text(g.shots_against.to_string())
-        }),
-        ColumnDef::new("GA", 2, Alignment::Right, |g: &GoalieStats| {
-            CellValue::Text(g.goals_against.to_string())
-        }),
-        ColumnDef::new("SV", 3, Alignment::Right, |g: &GoalieStats| {
-            CellValue::Text(g.saves.to_string())
-        }),
-        ColumnDef::new("SV%", 5, Alignment::Right, |g: &GoalieStats| {
-            if let Some(pct) = g.save_pctg {
-                CellValue::Text(format!("{:.3}", pct))
-            } else {
-                CellValue::Text("-".to_string())
+/// Table names of the penalty summary sections
+const AWAY_PENALTIES_TABLE: &str = "away_penalties";
+const HOME_PENALTIES_TABLE: &str = "home_penalties";
+
+/// One penalty in a team's penalty summary
+struct PenaltyRow {
+    period: String,
+    time: String,
+    player: String,
+    infraction: String,
+    minutes: i32,
+}
+
+impl PenaltyRow {
+    fn new(period: &PeriodDescriptor, penalty: &PenaltySummary) -> Self {
+        let player = if let Some(player) = &penalty.committed_by_player {
+            let initial = player.first_name.default.chars().next();
+            match initial {
+                Some(initial) => format!("{}. {}", initial, player.last_name.default),
+                None => player.last_name.default.clone(),
             }
+        } else if let Some(served_by) = &penalty.served_by {
+            format!("Bench ({})", served_by.default)
+        } else {
+            "Bench".to_string()
+        };
+
+        Self {
+            period: format_period_text(&period.number, period.period_type),
+            time: penalty.time_in_period.clone(),
+            player,
+            infraction: format_infraction(&penalty.desc_key),
+            minutes: penalty.duration,
+        }
+    }
+}
+
+/// Turn a penalty key such as "holding-the-stick" into "Holding the stick"
+fn format_infraction(desc_key: &str) -> String {
+    let words = desc_key.replace('-', " ");
+    let mut chars = words.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+fn penalty_columns() -> Vec<ColumnDef<PenaltyRow>> {
+    vec![
+        ColumnDef::new("Per", 3, Alignment::Left, |p: &PenaltyRow| {
+            CellValue::Text(p.period.clone())
         }),
-        ColumnDef::new("TOI", 7, Alignment::Right, |g: &GoalieStats| {
-            CellValue::Text(g.toi.clone())
+        ColumnDef::new("Time", 5, Alignment::Right, |p: &PenaltyRow| {
+            CellValue::Text(p.time.clone())
         }),
-        ColumnDef::new("PIM", 3, Alignment::Right, |g: &GoalieStats| {
-            if let Some(pim) = g.pim {
-                CellValue::Text(pim.to_string())
-            } else {
-                CellValue::Text("-".to_string())
-            }
+        ColumnDef::new("Player", 20, Alignment::Left, |p: &PenaltyRow| {
+            CellValue::Text(p.player.clone())
+        })
+        .auto_width()
+        .with_max_width(PLAYER_COLUMN_MAX_WIDTH),
+        ColumnDef::new("Infraction", 10, Alignment::Left, |p: &PenaltyRow| {
+            CellValue::Text(p.infraction.clone())
+        })
+        .auto_width(),
+        ColumnDef::new("Min", 3, Alignment::Right, |p: &PenaltyRow| {
+            CellValue::Text(p.minutes.to_string())
         }),
     ]
 }
 
-fn format_game_state(state: &nhl_api::GameState) -> &str {
-    match state {
-        nhl_api::GameState::Future => "SCHEDULED",
-        nhl_api::GameState::PreGame => "PRE-GAME",
-        nhl_api::GameState::Live => "LIVE",
-        nhl_api::GameState::Final => "FINAL",
+/// Table name of the three stars section
+const THREE_STARS_TABLE: &str = "three_stars";
+
+/// Key stat line for a star: points for skaters, save percentage and GAA for goalies
+fn three_star_stat_line(star: &ThreeStar, numbers: NumberFormat) -> String {
+    if star.position == Position::Goalie {
+        let save_pctg = star
+            .save_pctg
+            .map(|pctg| format!("{} SV%", numbers.save_pctg(pctg)))
+            .unwrap_or_default();
+        let gaa = star
+            .goals_against_average
+            .map(|gaa| format!("{:.2} GAA", gaa))
+            .unwrap_or_default();
+        format!("{} {}", save_pctg, gaa).trim().to_string()
+    } else {
+        format!(
+            "{}G {}A {}P",
+            star.goals.unwrap_or(0),
+            star.assists.unwrap_or(0),
+            star.points.unwrap_or(0)
+        )
+    }
+}
+
+fn three_star_columns(numbers: NumberFormat) -> Vec<ColumnDef<ThreeStar>> {
+    vec![
+        ColumnDef::new("#", 1, Alignment::Right, |s: &ThreeStar| {
+            CellValue::Text(s.star.to_string())
+        }),
+        ColumnDef::new("Team", 4, Alignment::Left, |s: &ThreeStar| {
+            CellValue::Text(s.team_abbrev.clone())
+        }),
+        ColumnDef::new("Player", 20, Alignment::Left, |s: &ThreeStar| {
+            CellValue::PlayerLink {
+                display: s.name.default.clone(),
+                player_id: s.player_id,
+            }
+        })
+        .auto_width()
+        .with_max_width(PLAYER_COLUMN_MAX_WIDTH),
+        ColumnDef::new("Stats", 10, Alignment::Left, move |s: &ThreeStar| {
+            CellValue::Text(three_star_stat_line(s, numbers))
+        })
+        .auto_width(),
+    ]
+}
+
+/// Number of regulation periods shown in the line score
+const REGULATION_PERIODS: usize = 3;
+
+/// One team's row in the line score table
+struct LineScoreRow {
+    team: String,
+    /// Goals (or shots) per displayed period column ("-" for periods not yet played)
+    periods: Vec<String>,
+    total: i32,
+    sog: i32,
+}
+
+impl LineScoreRow {
+    fn new(
+        team: &str,
+        per_period: &[i32],
+        current_period: i32,
+        has_ot: bool,
+        has_so: bool,
+        total: i32,
+        sog: i32,
+    ) -> Self {
+        let value_at = |idx: usize| per_period.get(idx).copied().unwrap_or(0).to_string();
+
+        let mut periods: Vec<String> = (0..REGULATION_PERIODS)
+            .map(|idx| {
+                if (idx as i32) < current_period {
+                    value_at(idx)
+                } else {
+                    "-".to_string()
+                }
+            })
+            .collect();
+        if has_ot {
+            periods.push(value_at(REGULATION_PERIODS));
+        }
+        if has_so {
+            periods.push(value_at(REGULATION_PERIODS + 1));
+        }
+
+        Self {
+            team: team.to_string(),
+            periods,
+            total,
+            sog,
+        }
+    }
+}
+
+/// Penalty types that put the other team on a power play
+const POWER_PLAY_PENALTY_TYPES: [&str; 3] = ["MIN", "MAJ", "BEN"];
+
+/// Count power-play opportunities drawn against `penalized_abbrev`
+///
+/// This counts minors, majors and bench minors taken by that team, so
+/// offsetting penalties are still counted as opportunities.
+fn power_play_opportunities(summary: &GameSummary, penalized_abbrev: &str) -> i32 {
+    summary
+        .penalties
+        .iter()
+        .flat_map(|period| &period.penalties)
+        .filter(|p| p.team_abbrev.default == penalized_abbrev)
+        .filter(|p| POWER_PLAY_PENALTY_TYPES.contains(&p.penalty_type.as_str()))
+        .count() as i32
+}
+
+/// One team's power-play results
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct SpecialTeams {
+    pp_goals: i32,
+    pp_opportunities: i32,
+}
+
+impl SpecialTeams {
+    fn new(pp_goals: i32, pp_opportunities: i32) -> Self {
+        Self {
+            pp_goals,
+            pp_opportunities,
+        }
+    }
+
+    /// Power-play goals over opportunities, e.g. "1/4"
+    fn power_play(&self) -> String {
+        format!("{}/{}", self.pp_goals, self.pp_opportunities)
+    }
+
+    /// Penalties killed over times shorthanded, derived from the opponent's
+    /// power play, e.g. "3/3"
+    fn penalty_kill(&self, opponent: &SpecialTeams) -> String {
+        let kills = opponent.pp_opportunities - opponent.pp_goals;
+        format!("{}/{}", kills.max(0), opponent.pp_opportunities)
+    }
+}
+
+/// Team faceoff win percentage, weighted by each skater's faceoffs taken
+///
+/// The boxscore has no per-player faceoff counts, so shifts stand in for
+/// faceoffs taken, as in `TeamGameStats`. Centers count even when they won
+/// none; other skaters count once they have won one. Returns None until some
+/// skater has won a faceoff.
+fn team_faceoff_pct(players: &TeamPlayerStats) -> Option<f64> {
+    let skaters = || players.forwards.iter().chain(&players.defense);
+    if skaters().all(|skater| skater.faceoff_winning_pctg <= 0.0) {
+        return None;
+    }
+    let (wins, taken) = skaters()
+        .filter(|skater| skater.position == Position::Center || skater.faceoff_winning_pctg > 0.0)
+        .fold((0.0, 0), |(wins, taken), skater| {
+            (
+                wins + skater.faceoff_winning_pctg * skater.shifts as f64,
+                taken + skater.shifts,
+            )
+        });
+    (taken > 0).then(|| wins / taken as f64 * 100.0)
+}
+
+/// One team's row in the team summary table
+struct TeamSummaryRow {
+    team: String,
+    /// Power play as "goals/opportunities", or "-" without the game summary
+    power_play: String,
+    /// Penalty kill as "kills/times shorthanded", or "-" without the game summary
+    penalty_kill: String,
+    faceoff_pct: Option<f64>,
+    hits: i32,
+    blocks: i32,
+}
+
+/// Column definitions for the team summary table
+fn team_summary_columns(numbers: NumberFormat) -> Vec<ColumnDef<TeamSummaryRow>> {
+    vec![
+        ColumnDef::new("Team", 4, Alignment::Left, |r: &TeamSummaryRow| {
+            CellValue::Text(r.team.clone())
+        }),
+        ColumnDef::new("PP", 5, Alignment::Right, |r: &TeamSummaryRow| {
+            CellValue::Text(r.power_play.clone())
+        }),
+        ColumnDef::new("PK", 5, Alignment::Right, |r: &TeamSummaryRow| {
+            CellValue::Text(r.penalty_kill.clone())
+        }),
+        ColumnDef::new("FO%", 5, Alignment::Right, move |r: &TeamSummaryRow| {
+            CellValue::Text(
+                r.faceoff_pct
+                    .map_or("-".to_string(), |pct| numbers.percent(pct)),
+            )
+        }),
+        ColumnDef::new("Hits", 4, Alignment::Right, |r: &TeamSummaryRow| {
+            CellValue::Text(r.hits.to_string())
+        }),
+        ColumnDef::new("Blk", 4, Alignment::Right, |r: &TeamSummaryRow| {
+            CellValue::Text(r.blocks.to_string())
+        }),
+    ]
+}
+
+/// Column definitions for the line score table; `sog` adds the total shots column
+fn line_score_columns(has_ot: bool, has_so: bool, sog: bool) -> Vec<ColumnDef<LineScoreRow>> {
+    let mut headers: Vec<&str> = vec!["1", "2", "3"];
+    if has_ot {
+        headers.push("OT");
+    }
+    if has_so {
+        headers.push("SO");
+    }
+
+    let mut columns = vec![ColumnDef::new(
+        "Team",
+        4,
+        Alignment::Left,
+        |r: &LineScoreRow| CellValue::Text(r.team.clone()),
+    )];
+    for (idx, header) in headers.into_iter().enumerate() {
+        columns.push(ColumnDef::new(
+            header,
+            3,
+            Alignment::Right,
+            move |r: &LineScoreRow| CellValue::Text(r.periods[idx].clone()),
+        ));
+    }
+    columns.push(ColumnDef::new(
+        "T",
+        3,
+        Alignment::Right,
+        |r: &LineScoreRow| CellValue::Text(r.total.to_string()),
+    ));
+    if sog {
+        columns.push(ColumnDef::new(
+            "SOG",
+            3,
+            Alignment::Right,
+            |r: &LineScoreRow| CellValue::Text(r.sog.to_string()),
+        ));
+    }
+    columns
+}
+
+/// Append the sort indicator to the header of the sorted column
+fn mark_sorted_column<T>(columns: &mut [ColumnDef<T>], sort: Option<TableSort>, use_unicode: bool) {
+    let Some((sort, header)) = sort.and_then(|s| Some((s, sort_header(s)?))) else {
+        return;
+    };
+    let indicator = match (sort.ascending, use_unicode) {
+        (true, true) => "▲",
+        (false, true) => "▼",
+        (true, false) => "^",
+        (false, false) => "v",
+    };
+    if let Some(column) = columns.iter_mut().find(|c| c.header == header) {
+        // Keep the sort key visible on narrow screens
+        column.priority = None;
+        column.header.push_str(indicator);
+        column.width = column.width.max(column.header.chars().count());
+    }
+}
+
+/// Widest the auto-sized Player column grows for long names
+const PLAYER_COLUMN_MAX_WIDTH: usize = 28;
+
+/// Skater columns shown as rates in per-60 mode
+const PER_60_HEADERS: [&str; 7] = ["G", "A", "PTS", "SOG", "Hits", "Blk", "PIM"];
+
+/// Width of a per-60 rate such as "12.5"
+const PER_60_WIDTH: usize = 4;
+
+/// Ice time below which per-60 rates are not shown, since a shift or two
+/// would blow them up (one goal in 0:30 is 120 per 60)
+const PER_60_MIN_TOI_SECONDS: i32 = 120;
+
+/// Time on ice in minutes, e.g. 15.5 for "15:30" (0 when malformed)
+fn toi_minutes(toi: &str) -> f64 {
+    f64::from(toi_seconds(toi)) / 60.0
+}
+
+/// `count` per 60 minutes of `toi`, or None when the ice time is negligible
+fn per_60_rate(count: i32, toi: &str) -> Option<f64> {
+    if toi_seconds(toi) < PER_60_MIN_TOI_SECONDS {
+        return None;
+    }
+    Some(f64::from(count) * 60.0 / toi_minutes(toi))
+}
+
+/// Cell for a skater counting stat: the total, or its per-60 rate ("-" when negligible)
+fn count_cell(count: i32, toi: &str, per_60: bool) -> CellValue {
+    if !per_60 {
+        return CellValue::Text(count.to_string());
+    }
+    CellValue::Text(per_60_rate(count, toi).map_or("-".to_string(), |rate| format!("{:.1}", rate)))
+}
+
+/// Column definitions for game-level skater stats
+///
+/// When the table is too narrow, PIM is hidden first, then FO%, Blk and Hits.
+/// With `per_60`, the counting stats (G through PIM, except +/-) are rates per
+/// 60 minutes of ice time and have no totals.
+fn game_skater_columns(
+    sort: Option<TableSort>,
+    use_unicode: bool,
+    numbers: NumberFormat,
+    per_60: bool,
+) -> Vec<ColumnDef<SkaterStats>> {
+    let mut columns = vec![
+        ColumnDef::new("#", 2, Alignment::Right, |s: &SkaterStats| {
+            CellValue::Text(s.sweater_number.to_string())
+        })
+        .with_description("Sweater number"),
+        ColumnDef::new("Player", 20, Alignment::Left, |s: &SkaterStats| {
+            CellValue::PlayerLink {
+                display: s.name.default.clone(),
+                player_id: s.player_id,
+            }
+        })
+        .auto_width()
+        .with_max_width(PLAYER_COLUMN_MAX_WIDTH)
+        .with_total(ColumnTotal::Label("TOTAL".to_string())),
+        ColumnDef::new("Pos", 3, Alignment::Center, |s: &SkaterStats| {
+            CellValue::Text(s.position.to_string())
+        })
+        .with_description("Position"),
+        ColumnDef::new("G", 2, Alignment::Right, move |s: &SkaterStats| {
+            count_cell(s.goals, &s.toi, per_60)
+        })
+        .with_description("Goals")
+        .with_total(ColumnTotal::Sum),
+        ColumnDef::new("A", 2, Alignment::Right, move |s: &SkaterStats| {
+            count_cell(s.assists, &s.toi, per_60)
+        })
+        .with_description("Assists")
+        .with_total(ColumnTotal::Sum),
+        ColumnDef::new("PTS", 3, Alignment::Right, move |s: &SkaterStats| {
+            count_cell(s.points, &s.toi, per_60)
+        })
+        .with_description("Points")
+        .with_total(ColumnTotal::Sum),
+        ColumnDef::new("+/-", 3, Alignment::Right, |s: &SkaterStats| {
+            CellValue::Text(format!("{:+}", s.plus_minus))
+        })
+        .with_description("Plus/minus")
+        .with_total(ColumnTotal::custom(|skaters: &[SkaterStats]| {
+            format!("{:+}", skaters.iter().map(|s| s.plus_minus).sum::<i32>())
+        })),
+        ColumnDef::new("SOG", 3, Alignment::Right, move |s: &SkaterStats| {
+            count_cell(s.sog, &s.toi, per_60)
+        })
+        .with_description("Shots on goal")
+        .with_total(ColumnTotal::Sum),
+        ColumnDef::new("Hits", 4, Alignment::Right, move |s: &SkaterStats| {
+            count_cell(s.hits, &s.toi, per_60)
+        })
+        .with_priority(4)
+        .with_total(ColumnTotal::Sum),
+        ColumnDef::new("Blk", 3, Alignment::Right, move |s: &SkaterStats| {
+            count_cell(s.blocked_shots, &s.toi, per_60)
+        })
+        .with_description("Blocked shots")
+        .with_priority(3)
+        .with_total(ColumnTotal::Sum),
+        ColumnDef::new("PIM", 3, Alignment::Right, move |s: &SkaterStats| {
+            count_cell(s.pim, &s.toi, per_60)
+        })
+        .with_description("Penalty minutes")
+        .with_priority(1)
+        .with_total(ColumnTotal::Sum),
+        ColumnDef::new("FO%", 5, Alignment::Right, move |s: &SkaterStats| {
+            if s.faceoff_winning_pctg > 0.0 {
+                CellValue::Text(numbers.percent(s.faceoff_winning_pctg * 100.0))
+            } else {
+                CellValue::Text("-".to_string())
+            }
+        })
+        .with_description("Faceoff win percentage")
+        .with_priority(2),
+        ColumnDef::new("TOI", 6, Alignment::Right, |s: &SkaterStats| {
+            CellValue::Text(s.toi.clone())
+        })
+        .with_description("Time on ice")
+        .with_total(ColumnTotal::custom(|skaters: &[SkaterStats]| {
+            let total: i32 = skaters.iter().map(|s| toi_seconds(&s.toi)).sum();
+            format!("{}:{:02}", total / 60, total % 60)
+        })),
+    ];
+    if per_60 {
+        for column in &mut columns {
+            if column.header == "Player" {
+                column.header = "Player (per 60)".to_string();
+            } else if PER_60_HEADERS.contains(&column.header.as_str()) {
+                let stat = column
+                    .description
+                    .take()
+                    .unwrap_or_else(|| column.header.clone());
+                column.description = Some(format!("{} per 60 minutes of ice time", stat));
+                column.width = column.width.max(PER_60_WIDTH);
+                column.total = ColumnTotal::Blank;
+            }
+        }
+    }
+    mark_sorted_column(&mut columns, sort, use_unicode);
+    columns
+}
+
+/// Save percentage at or above which a start counts as a quality start
+const QUALITY_START_SAVE_PCTG: f64 = 0.917;
+
+/// Lower save percentage accepted for a quality start on a light workload
+const QUALITY_START_LIGHT_SAVE_PCTG: f64 = 0.885;
+
+/// Shots against below which the light-workload threshold applies
+const QUALITY_START_LIGHT_SHOTS: i32 = 20;
+
+/// Minimum shots against for a performance to be rated at all
+const QUALITY_START_MIN_SHOTS: i32 = 10;
+
+/// Whether a goalie recorded a quality start
+///
+/// A quality start is a save percentage of at least .917, or at least .885
+/// when facing fewer than 20 shots. Goalies who faced fewer than 10 shots
+/// (e.g. relief appearances) are never rated.
+fn is_quality_start(goalie: &GoalieStats) -> bool {
+    if goalie.shots_against < QUALITY_START_MIN_SHOTS {
+        return false;
+    }
+    let save_pctg = goalie.save_pctg.unwrap_or_else(|| {
+        (goalie.shots_against - goalie.goals_against) as f64 / goalie.shots_against as f64
+    });
+    if goalie.shots_against < QUALITY_START_LIGHT_SHOTS {
+        save_pctg >= QUALITY_START_LIGHT_SAVE_PCTG
+    } else {
+        save_pctg >= QUALITY_START_SAVE_PCTG
+    }
+}
+
+/// Save percentage the goalie comparison column is measured against
+const SAVE_PCTG_BASELINE: f64 = 0.900;
+
+/// Save percentage relative to the baseline, e.g. "+.023" or "-.041"
+///
+/// No sign when it rounds to the baseline, so the cell isn't colored.
+fn format_save_pctg_diff(save_pctg: f64) -> String {
+    let diff = format!("{:+.3}", save_pctg - SAVE_PCTG_BASELINE);
+    match diff.as_str() {
+        "+0.000" | "-0.000" => ".000".to_string(),
+        _ => diff.replacen("0.", ".", 1),
+    }
+}
+
+/// Column definitions for game-level goalie stats
+///
+/// Goalies with a quality start get a `★` (or `QS` without unicode) after their name.
+/// With `save_diff`, a signed column after SV% compares it to .900. PIM is
+/// hidden when the table is too narrow.
+fn game_goalie_columns(
+    sort: Option<TableSort>,
+    use_unicode: bool,
+    save_diff: bool,
+    numbers: NumberFormat,
+) -> Vec<ColumnDef<GoalieStats>> {
+    let quality_start_marker = if use_unicode { "★" } else { "QS" };
+    let mut columns = vec![
+        ColumnDef::new("#", 2, Alignment::Right, |g: &GoalieStats| {
+            CellValue::Text(g.sweater_number.to_string())
+        })
+        .with_description("Sweater number"),
+        ColumnDef::new("Player", 20, Alignment::Left, move |g: &GoalieStats| {
+            let display = if is_quality_start(g) {
+                format!("{} {}", g.name.default, quality_start_marker)
+            } else {
+                g.name.default.clone()
+            };
+            CellValue::PlayerLink {
+                display,
+                player_id: g.player_id,
+            }
+        })
+        .auto_width()
+        .with_max_width(PLAYER_COLUMN_MAX_WIDTH),
+        ColumnDef::new("SA", 3, Alignment::Right, |g: &GoalieStats| {
+            CellValue::Text(g.shots_against.to_string())
+        })
+        .with_description("Shots against"),
+        ColumnDef::new("GA", 2, Alignment::Right, |g: &GoalieStats| {
+            CellValue::Text(g.goals_against.to_string())
+        })
+        .with_description("Goals against"),
+        ColumnDef::new("SV", 3, Alignment::Right, |g: &GoalieStats| {
+            CellValue::Text(g.saves.to_string())
+        })
+        .with_description("Saves"),
+        ColumnDef::new("SV%", 5, Alignment::Right, move |g: &GoalieStats| {
+            if let Some(pct) = g.save_pctg {
+                CellValue::Text(numbers.save_pctg(pct))
+            } else {
+                CellValue::Text("-".to_string())
+            }
+        })
+        .with_description("Save percentage"),
+        ColumnDef::new("TOI", 7, Alignment::Right, |g: &GoalieStats| {
+            CellValue::Text(g.toi.clone())
+        })
+        .with_description("Time on ice"),
+        ColumnDef::new("PIM", 3, Alignment::Right, |g: &GoalieStats| {
+            if let Some(pim) = g.pim {
+                CellValue::Text(pim.to_string())
+            } else {
+                CellValue::Text("-".to_string())
+            }
+        })
+        .with_description("Penalty minutes")
+        .with_priority(1),
+    ];
+    if save_diff {
+        let header = if use_unicode { "ΔSV%" } else { "dSV%" };
+        let column = ColumnDef::new(header, 5, Alignment::Right, |g: &GoalieStats| {
+            CellValue::Text(g.save_pctg.map_or("-".to_string(), format_save_pctg_diff))
+        })
+        .with_description("Save percentage above or below .900")
+        .signed();
+        let after_save_pctg = columns
+            .iter()
+            .position(|c| c.header == "SV%")
+            .map_or(columns.len(), |i| i + 1);
+        columns.insert(after_save_pctg, column);
+    }
+    let goalie_sort =
+        sort.filter(|s| sort_header(*s).is_some_and(|h| GOALIE_SORT_HEADERS.contains(&h)));
+    mark_sorted_column(&mut columns, goalie_sort, use_unicode);
+    columns
+}
+
+fn format_game_state(state: &nhl_api::GameState) -> &str {
+    match state {
+        nhl_api::GameState::Future => "SCHEDULED",
+        nhl_api::GameState::PreGame => "PRE-GAME",
+        nhl_api::GameState::Live => "LIVE",
+        nhl_api::GameState::Final => "FINAL",
         nhl_api::GameState::Off => "OFF",
         nhl_api::GameState::Postponed => "POSTPONED",
         nhl_api::GameState::Suspended => "SUSPENDED",
@@ -344,6 +1715,32 @@ fn format_game_state(state: &nhl_api::GameState) -> &str {
     }
 }
 
+/// Start time in the venue's local time, e.g. "19:00 local"
+///
+/// Falls back to UTC ("23:00 UTC") when the venue offset is malformed, and
+/// returns None when the start time itself can't be parsed.
+fn venue_start_time(
+    start_time_utc: &str,
+    venue_utc_offset: &str,
+    time_format: &str,
+) -> Option<String> {
+    let start = parse_start_time(start_time_utc)?;
+    let format = if time_format.is_empty() {
+        DEFAULT_TIME_FORMAT
+    } else {
+        time_format
+    };
+
+    let (time, zone) = match venue_utc_offset.parse::<FixedOffset>() {
+        Ok(offset) => (DisplayTimezone::Fixed(offset).convert(start), "local"),
+        Err(_) => (start.fixed_offset(), "UTC"),
+    };
+
+    let mut formatted = String::new();
+    write!(formatted, "{} {}", time.format(format), zone).ok()?;
+    Some(formatted)
+}
+
 fn format_period_text(number: &i32, period_type: nhl_api::PeriodType) -> String {
     match period_type {
         nhl_api::PeriodType::Regulation => format!("{}", number),
@@ -355,35 +1752,42 @@ fn format_period_text(number: &i32, period_type: nhl_api::PeriodType) -> String
 /// Widget for rendering boxscore document
 struct BoxscoreDocumentWidget {
     game_id: i64,
-    boxscore: Option<Boxscore>,
-    loading: bool,
+    boxscore: Option<Arc<Boxscore>>,
+    period_scores: Option<PeriodScores>,
+    game_summary: Option<GameSummary>,
+    records: Option<(String, String)>,
+    time_format: String,
     team_view: TeamView,
+    sort: Option<TableSort>,
+    per_60: bool,
+    column_offset: usize,
     selected_index: Option<usize>,
     scroll_offset: u16,
+    find: Option<FindState>,
     focused: bool,
     animation_frame: u8,
 }
 
-impl ElementWidget for BoxscoreDocumentWidget {
-    fn render(&self, area: Rect, buf: &mut Buffer, config: &DisplayConfig) {
-        // Show animation if loading or data hasn't arrived yet
-        if self.loading || self.boxscore.is_none() {
-            LoadingAnimation::new(self.animation_frame).render(area, buf, config);
-            return;
-        }
-
-        // Safe to unwrap since we checked is_none() above
-        let boxscore = self.boxscore.as_ref().unwrap();
-
-        if area.width == 0 || area.height == 0 {
-            return;
-        }
+impl BoxscoreDocumentWidget {
+    /// Document view, or None before data has arrived
+    ///
+    /// A refresh keeps the loaded boxscore on screen; the status bar shows it.
+    fn view(&self, area: Rect, config: &DisplayConfig) -> Option<DocumentView> {
+        let boxscore = self.boxscore.as_ref()?;
 
-        // Create document and render with DocumentView
         let doc =
-            BoxscoreDocumentContent::new(self.game_id, boxscore.clone(), self.team_view.clone());
-
-        let mut view = DocumentView::new(Arc::new(doc), area.height);
+            BoxscoreDocumentContent::new(self.game_id, boxscore.clone(), self.team_view.clone())
+                .with_period_scores(self.period_scores.clone())
+                .with_game_summary(self.game_summary.clone())
+                .with_records(self.records.clone())
+                .with_sort(self.sort)
+                .with_per_60(self.per_60)
+                .with_column_offset(self.column_offset)
+                .with_goalie_save_diff(config.goalie_save_diff)
+                .with_time_format(self.time_format.clone())
+                .with_number_format(config.numbers);
+
+        let mut view = DocumentView::new(Arc::new(doc), area.height).with_focused(self.focused);
 
         // Apply focus state
         if let Some(idx) = self.selected_index {
@@ -392,19 +1796,48 @@ impl ElementWidget for BoxscoreDocumentWidget {
 
         // Apply scroll offset
         view.set_scroll_offset(self.scroll_offset);
+        view.set_find(self.find.clone());
+        Some(view)
+    }
+}
+
+impl ElementWidget for BoxscoreDocumentWidget {
+    fn render(&self, area: Rect, buf: &mut Buffer, config: &DisplayConfig) {
+        // Show animation until the data has arrived
+        let Some(mut view) = self.view(area, config) else {
+            LoadingAnimation::new(self.animation_frame).render(area, buf, config);
+            return;
+        };
+
+        if area.width == 0 || area.height == 0 {
+            return;
+        }
 
         // Render the document
         view.render(area, buf, config);
     }
 
+    fn hit_regions(&self, area: Rect, config: &DisplayConfig) -> Vec<HitRegion> {
+        self.view(area, config)
+            .map(|view| view.hit_regions(area, config))
+            .unwrap_or_default()
+    }
+
     fn clone_box(&self) -> Box<dyn ElementWidget> {
         Box::new(BoxscoreDocumentWidget {
             game_id: self.game_id,
             boxscore: self.boxscore.clone(),
-            loading: self.loading,
+            period_scores: self.period_scores.clone(),
+            game_summary: self.game_summary.clone(),
+            records: self.records.clone(),
+            time_format: self.time_format.clone(),
             team_view: self.team_view.clone(),
+            sort: self.sort,
+            per_60: self.per_60,
+            column_offset: self.column_offset,
             selected_index: self.selected_index,
             scroll_offset: self.scroll_offset,
+            find: self.find.clone(),
             focused: self.focused,
             animation_frame: self.animation_frame,
         })
@@ -414,7 +1847,9 @@ impl ElementWidget for BoxscoreDocumentWidget {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::tui::document::FocusContext;
+    use crate::config::BOXSCORE_SORT_COLUMNS;
+    use crate::tui::document::{FocusContext, FocusableId, LinkTarget};
+    use crate::tui::testing::{buffer_lines, create_division_team};
     use nhl_api::{
         Boxscore, BoxscoreTeam, GameClock, GameState, GoalieDecision, GoalieStats, LocalizedString,
         PeriodDescriptor, PeriodType, PlayerByGameStats, Position, SkaterStats, TeamPlayerStats,
@@ -579,49 +2014,1507 @@ mod tests {
     }
 
     #[test]
-    fn test_document_metadata() {
-        let boxscore = create_test_boxscore();
-        let doc = BoxscoreDocumentContent::new(2024020001, boxscore, TeamView::Away);
+    fn test_compact_spacing_uses_fewer_rows() {
+        let doc = BoxscoreDocumentContent::new(2024020001, create_test_boxscore(), TeamView::Away)
+            .with_period_scores(Some(create_test_period_scores()));
+        let height = |focus: FocusContext| -> u16 {
+            doc.build(&focus)
+                .iter()
+                .map(|element| element.height())
+                .sum()
+        };
 
-        assert_eq!(doc.title(), "NJD @ BUF - Game 2024020001");
-        assert_eq!(doc.id(), "boxscore_2024020001");
+        let default = height(FocusContext::default());
+        let compact = height(FocusContext::default().with_compact_spacing(true));
+
+        assert!(compact < default, "{} >= {}", compact, default);
+    }
+
+    fn big_score(doc: &BoxscoreDocumentContent) -> BigScore {
+        let focus = FocusContext {
+            use_unicode: true,
+            ..Default::default()
+        };
+        doc.build(&focus)
+            .into_iter()
+            .find_map(|element| match element {
+                DocumentElement::BigScoreElement { big_score } => Some(big_score),
+                _ => None,
+            })
+            .expect("big score element")
+    }
+
+    fn big_score_records(doc: &BoxscoreDocumentContent) -> Option<(String, String)> {
+        big_score(doc).records
+    }
+
+    fn create_test_shootout_attempt(team: &str, result: &str) -> nhl_api::ShootoutAttempt {
+        nhl_api::ShootoutAttempt {
+            sequence: 1,
+            player_id: 1,
+            team_abbrev: LocalizedString {
+                default: team.to_string(),
+            },
+            first_name: LocalizedString {
+                default: "Test".to_string(),
+            },
+            last_name: LocalizedString {
+                default: "Shooter".to_string(),
+            },
+            shot_type: "wrist".to_string(),
+            result: result.to_string(),
+            headshot: String::new(),
+            game_winner: false,
+        }
+    }
+
+    fn banners(doc: &BoxscoreDocumentContent, use_unicode: bool) -> Vec<String> {
+        let focus = FocusContext {
+            use_unicode,
+            ..Default::default()
+        };
+        doc.build(&focus)
+            .into_iter()
+            .filter_map(|element| match element {
+                DocumentElement::Banner { content } => Some(content),
+                _ => None,
+            })
+            .collect()
     }
 
     #[test]
-    fn test_focusable_positions() {
-        let boxscore = create_test_boxscore();
+    fn test_special_event_banner() {
+        let mut boxscore = create_test_boxscore();
+        boxscore.special_event = Some(nhl_api::SpecialEvent {
+            parent_id: 1,
+            name: LocalizedString {
+                default: "Winter Classic".to_string(),
+            },
+            light_logo_url: LocalizedString {
+                default: String::new(),
+            },
+        });
         let doc = BoxscoreDocumentContent::new(2024020001, boxscore, TeamView::Away);
 
-        let positions = doc.focusable_positions();
+        assert_eq!(banners(&doc, true), vec!["★ Winter Classic ★"]);
+        assert_eq!(banners(&doc, false), vec!["* Winter Classic *"]);
+
+        // The banner comes before the score
+        let elements = doc.build(&FocusContext {
+            use_unicode: true,
+            ..Default::default()
+        });
+        let position = |pred: fn(&DocumentElement) -> bool| elements.iter().position(pred);
+        assert!(
+            position(|e| matches!(e, DocumentElement::Banner { .. }))
+                < position(|e| matches!(e, DocumentElement::BigScoreElement { .. }))
+        );
+    }
 
-        // Should have focusable positions for all players
-        // Away: 2 forwards + 1 defense + 1 goalie = 4
-        // Home: 2 forwards + 1 defense + 1 goalie = 4
-        // Total = 8
-        assert_eq!(positions.len(), 8);
+    #[test]
+    fn test_no_banner_without_special_event() {
+        let doc = BoxscoreDocumentContent::new(2024020001, create_test_boxscore(), TeamView::Away);
+
+        assert!(banners(&doc, true).is_empty());
     }
 
     #[test]
-    fn test_loading_state_renders() {
-        let widget = BoxscoreDocumentWidget {
-            game_id: 2024020001,
-            boxscore: None,
-            loading: true,
-            team_view: TeamView::Away,
-            selected_index: None,
-            scroll_offset: 0,
-            focused: true,
-            animation_frame: 0,
-        };
+    fn test_final_big_score_shows_status() {
+        let mut boxscore = create_test_boxscore();
+        boxscore.period_descriptor.number = 4;
+        boxscore.period_descriptor.period_type = PeriodType::Overtime;
+        let doc = BoxscoreDocumentContent::new(2024020001, boxscore, TeamView::Away);
 
-        let area = Rect::new(0, 0, 80, 30);
-        let mut buf = Buffer::empty(area);
-        let config = DisplayConfig::default();
+        let big_score = big_score(&doc);
 
-        widget.render(area, &mut buf, &config);
+        assert_eq!(
+            big_score.status.as_ref().map(ScoreBoxStatus::display),
+            Some("Final (OT)".to_string())
+        );
+        assert_eq!(big_score.shootout_goals, None);
+    }
 
-        // Should render without panic
-        assert_eq!(*buf.area(), area);
+    #[test]
+    fn test_shootout_big_score_counts_shootout_goals() {
+        let mut boxscore = create_test_boxscore();
+        boxscore.period_descriptor.number = 5;
+        boxscore.period_descriptor.period_type = PeriodType::Shootout;
+        let mut summary = create_test_game_summary();
+        summary.shootout = Some(vec![
+            create_test_shootout_attempt("NJD", "goal"),
+            create_test_shootout_attempt("BUF", "save"),
+            create_test_shootout_attempt("NJD", "miss"),
+            create_test_shootout_attempt("BUF", "goal"),
+            create_test_shootout_attempt("NJD", "goal"),
+        ]);
+        let doc = BoxscoreDocumentContent::new(2024020001, boxscore, TeamView::Away)
+            .with_game_summary(Some(summary));
+
+        let big_score = big_score(&doc);
+
+        assert_eq!(
+            big_score.status,
+            Some(ScoreBoxStatus::Final {
+                overtime: false,
+                shootout: true,
+            })
+        );
+        assert_eq!(big_score.shootout_goals, Some((2, 1)));
+    }
+
+    #[test]
+    fn test_live_big_score_has_no_status() {
+        let mut boxscore = create_test_boxscore();
+        boxscore.game_state = GameState::Live;
+        let doc = BoxscoreDocumentContent::new(2024020001, boxscore, TeamView::Away);
+
+        assert_eq!(big_score(&doc).status, None);
+    }
+
+    #[test]
+    fn test_scheduled_big_score_shows_records() {
+        let mut boxscore = create_test_boxscore();
+        boxscore.game_state = GameState::Future;
+        let standings = vec![
+            create_division_team("Devils", "NJD", "Metropolitan", "Eastern", 24, 10, 3, 51),
+            create_division_team("Sabres", "BUF", "Atlantic", "Eastern", 9, 12, 1, 19),
+        ];
+        let records = team_records(Some(&standings), "NJD", "BUF");
+        let doc = BoxscoreDocumentContent::new(2024020001, boxscore, TeamView::Away)
+            .with_records(records);
+
+        assert_eq!(
+            big_score_records(&doc),
+            Some(("24-10-3".to_string(), "9-12-1".to_string()))
+        );
+    }
+
+    fn create_test_broadcast(network: &str, sequence_number: i32) -> nhl_api::TvBroadcast {
+        nhl_api::TvBroadcast {
+            id: sequence_number as i64,
+            market: "N".to_string(),
+            country_code: "US".to_string(),
+            network: network.to_string(),
+            sequence_number,
+        }
+    }
+
+    #[test]
+    fn test_scheduled_game_renders_tv_broadcasts() {
+        let mut boxscore = create_test_boxscore();
+        boxscore.game_state = GameState::Future;
+        boxscore.tv_broadcasts = vec![
+            create_test_broadcast("SN", 2),
+            create_test_broadcast("ESPN+", 1),
+            create_test_broadcast("SN", 3),
+        ];
+        let doc = BoxscoreDocumentContent::new(2024020001, boxscore, TeamView::Away);
+        let focus = FocusContext {
+            use_unicode: true,
+            ..Default::default()
+        };
+
+        let (buf, height) = doc.render_full(80, &DisplayConfig::default(), &focus);
+
+        let lines: Vec<String> = (0..height)
+            .map(|y| {
+                (0..buf.area.width)
+                    .map(|x| buf[(x, y)].symbol())
+                    .collect::<String>()
+                    .trim_end()
+                    .to_string()
+            })
+            .collect();
+        assert!(
+            lines.iter().any(|line| line == "TV: ESPN+, SN"),
+            "{:?}",
+            lines
+        );
+    }
+
+    #[test]
+    fn test_tv_broadcasts_hidden_when_final_or_empty() {
+        let mut boxscore = create_test_boxscore();
+        boxscore.tv_broadcasts = vec![create_test_broadcast("ESPN+", 1)];
+        let final_doc = BoxscoreDocumentContent::new(2024020001, boxscore.clone(), TeamView::Away);
+        assert_eq!(final_doc.tv_line(), None);
+
+        boxscore.game_state = GameState::Future;
+        boxscore.tv_broadcasts.clear();
+        let doc = BoxscoreDocumentContent::new(2024020001, boxscore, TeamView::Away);
+        assert_eq!(doc.tv_line(), None);
+    }
+
+    #[test]
+    fn test_venue_start_time_converts_to_local() {
+        assert_eq!(
+            venue_start_time("2024-10-04T23:00:00Z", "-04:00", "%H:%M"),
+            Some("19:00 local".to_string())
+        );
+        assert_eq!(
+            venue_start_time("2024-10-05T02:30:00Z", "-07:00", "%I:%M %p"),
+            Some("07:30 PM local".to_string())
+        );
+    }
+
+    #[test]
+    fn test_venue_start_time_malformed_input() {
+        assert_eq!(
+            venue_start_time("2024-10-04T23:00:00Z", "bogus", "%H:%M"),
+            Some("23:00 UTC".to_string())
+        );
+        assert_eq!(venue_start_time("not a time", "-04:00", "%H:%M"), None);
+    }
+
+    #[test]
+    fn test_scheduled_game_header_shows_city_and_local_start() {
+        let mut boxscore = create_test_boxscore();
+        boxscore.game_state = GameState::Future;
+        let doc = BoxscoreDocumentContent::new(2024020001, boxscore, TeamView::Away)
+            .with_time_format("%H:%M");
+
+        let (buf, height) =
+            doc.render_full(80, &DisplayConfig::default(), &FocusContext::default());
+
+        let lines: Vec<String> = (0..height)
+            .map(|y| {
+                (0..buf.area.width)
+                    .map(|x| buf[(x, y)].symbol())
+                    .collect::<String>()
+                    .trim_end()
+                    .to_string()
+            })
+            .collect();
+        assert!(
+            lines
+                .iter()
+                .any(|line| line.ends_with("Venue: Test Arena, Test City")),
+            "{:?}",
+            lines
+        );
+        assert!(
+            lines
+                .iter()
+                .any(|line| line.ends_with("Start: 15:00 local")),
+            "{:?}",
+            lines
+        );
+    }
+
+    #[test]
+    fn test_final_big_score_omits_records() {
+        let records = Some(("24-10-3".to_string(), "9-12-1".to_string()));
+        let doc = BoxscoreDocumentContent::new(2024020001, create_test_boxscore(), TeamView::Away)
+            .with_records(records);
+
+        assert_eq!(big_score_records(&doc), None);
+    }
+
+    #[test]
+    fn test_team_records_requires_both_teams() {
+        let standings = vec![create_division_team(
+            "Devils",
+            "NJD",
+            "Metropolitan",
+            "Eastern",
+            24,
+            10,
+            3,
+            51,
+        )];
+
+        assert_eq!(team_records(Some(&standings), "NJD", "BUF"), None);
+        assert_eq!(team_records(None, "NJD", "BUF"), None);
+    }
+
+    #[test]
+    fn test_document_metadata() {
+        let boxscore = create_test_boxscore();
+        let doc = BoxscoreDocumentContent::new(2024020001, boxscore, TeamView::Away);
+
+        assert_eq!(doc.title(), "NJD @ BUF - Game 2024020001");
+        assert_eq!(doc.id(), "boxscore_2024020001");
+    }
+
+    #[test]
+    fn test_cache_key_matches_for_identical_inputs() {
+        let a = BoxscoreDocumentContent::new(2024020001, create_test_boxscore(), TeamView::Away);
+        let b = BoxscoreDocumentContent::new(2024020001, create_test_boxscore(), TeamView::Away);
+        assert_eq!(a.cache_key(), b.cache_key());
+    }
+
+    #[test]
+    fn test_cache_key_changes_with_clock() {
+        let mut later = create_test_boxscore();
+        later.clock.time_remaining = "09:59".to_string();
+        assert_ne!(
+            later.clock.time_remaining,
+            create_test_boxscore().clock.time_remaining
+        );
+
+        let a = BoxscoreDocumentContent::new(2024020001, create_test_boxscore(), TeamView::Away);
+        let b = BoxscoreDocumentContent::new(2024020001, later, TeamView::Away);
+        assert_ne!(a.cache_key(), b.cache_key());
+    }
+
+    #[test]
+    fn test_cache_key_changes_with_score_and_view() {
+        let base = BoxscoreDocumentContent::new(2024020001, create_test_boxscore(), TeamView::Away);
+
+        let mut scored = create_test_boxscore();
+        scored.home_team.score += 1;
+        let scored = BoxscoreDocumentContent::new(2024020001, scored, TeamView::Away);
+        assert_ne!(base.cache_key(), scored.cache_key());
+
+        let sorted =
+            BoxscoreDocumentContent::new(2024020001, create_test_boxscore(), TeamView::Away)
+                .with_sort(boxscore_table_sort("points_desc"));
+        assert_ne!(base.cache_key(), sorted.cache_key());
+
+        let rates =
+            BoxscoreDocumentContent::new(2024020001, create_test_boxscore(), TeamView::Away)
+                .with_per_60(true);
+        assert_ne!(base.cache_key(), rates.cache_key());
+    }
+
+    #[test]
+    fn test_team_boxscores_side_by_side_from_natural_width() {
+        let doc = BoxscoreDocumentContent::new(2024020001, create_test_boxscore(), TeamView::Away);
+        let boxscores: Vec<DocumentElement> = doc
+            .build(&FocusContext::default())
+            .into_iter()
+            .filter(|e| matches!(e, DocumentElement::TeamBoxscore { .. }))
+            .collect();
+        assert_eq!(boxscores.len(), 2, "stacked without a known width");
+        let threshold = team_boxscore_side_by_side_width(&boxscores[0], &boxscores[1]);
+
+        let is_side_by_side = |width: u16| {
+            doc.build(&FocusContext::default().with_width(width))
+                .iter()
+                .any(|e| match e {
+                    DocumentElement::Row { children, .. } => children
+                        .iter()
+                        .all(|c| matches!(c, DocumentElement::TeamBoxscore { .. })),
+                    _ => false,
+                })
+        };
+        assert!(is_side_by_side(threshold));
+        assert!(!is_side_by_side(threshold - 1));
+    }
+
+    #[test]
+    fn test_focusable_positions() {
+        let boxscore = create_test_boxscore();
+        let doc = BoxscoreDocumentContent::new(2024020001, boxscore, TeamView::Away);
+
+        let positions = doc.focusable_positions();
+
+        // Should have focusable positions for all players
+        // Away: 2 forwards + 1 defense + 1 goalie = 4
+        // Home: 2 forwards + 1 defense + 1 goalie = 4
+        // Game leaders: every skater is tied at 1G 2A, so 3 per team = 6
+        // Total = 14
+        assert_eq!(positions.len(), 14);
+    }
+
+    fn game_leaders_table(elements: &[DocumentElement]) -> Option<&DocumentElement> {
+        match elements.last() {
+            Some(DocumentElement::Group { children, .. }) => children.last(),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_game_leaders_pick_top_scorer_per_team() {
+        let mut boxscore = create_test_boxscore();
+        let stats = &mut boxscore.player_by_game_stats;
+        // Away: Forward2 ties Forward1 on points but wins on goals
+        stats.away_team.forwards[1].goals = 2;
+        stats.away_team.forwards[1].assists = 1;
+        // Home: Defense1 leads outright
+        stats.home_team.defense[0].assists = 3;
+        stats.home_team.defense[0].points = 4;
+        let doc = BoxscoreDocumentContent::new(2024020001, boxscore, TeamView::Away);
+
+        let elements = doc.build(&FocusContext::default());
+        let Some(DocumentElement::Table { widget, focusable }) = game_leaders_table(&elements)
+        else {
+            panic!("Expected the game leaders table last");
+        };
+
+        assert_eq!(widget.row_count(), 2);
+        let row = |r: usize| -> Vec<String> {
+            (0..widget.column_count())
+                .map(|c| {
+                    widget
+                        .get_cell_value(r, c)
+                        .unwrap()
+                        .display_text()
+                        .to_string()
+                })
+                .collect()
+        };
+        assert_eq!(row(0), ["NJD", "A. Forward2", "2", "1", "3"]);
+        assert_eq!(row(1), ["BUF", "H. Defense1", "1", "3", "4"]);
+
+        let targets: Vec<_> = focusable.iter().map(|f| f.link_target.clone()).collect();
+        assert_eq!(
+            targets,
+            [
+                Some(LinkTarget::Action("player:11".to_string())),
+                Some(LinkTarget::Action("player:21".to_string())),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_game_leaders_omitted_without_points() {
+        let mut boxscore = create_test_boxscore();
+        let stats = &mut boxscore.player_by_game_stats;
+        for skater in stats
+            .away_team
+            .forwards
+            .iter_mut()
+            .chain(&mut stats.away_team.defense)
+            .chain(&mut stats.home_team.forwards)
+            .chain(&mut stats.home_team.defense)
+        {
+            skater.goals = 0;
+            skater.assists = 0;
+            skater.points = 0;
+        }
+        let doc = BoxscoreDocumentContent::new(2024020001, boxscore, TeamView::Away);
+
+        let elements = doc.build(&FocusContext::default());
+        assert!(game_leaders_table(&elements).is_none());
+    }
+
+    #[test]
+    fn test_game_leaders_omitted_for_future_game() {
+        let mut boxscore = create_test_boxscore();
+        boxscore.game_state = GameState::Future;
+        let doc = BoxscoreDocumentContent::new(2024020001, boxscore, TeamView::Away);
+
+        let elements = doc.build(&FocusContext::default());
+        assert!(game_leaders_table(&elements).is_none());
+    }
+
+    fn create_test_star(star: i32, player_id: i64, name: &str, position: Position) -> ThreeStar {
+        let goalie = position == Position::Goalie;
+        ThreeStar {
+            star,
+            player_id,
+            team_abbrev: "NJD".to_string(),
+            headshot: String::new(),
+            name: LocalizedString {
+                default: name.to_string(),
+            },
+            sweater_no: 1,
+            position,
+            goals: (!goalie).then_some(2),
+            assists: (!goalie).then_some(1),
+            points: (!goalie).then_some(3),
+            goals_against_average: goalie.then_some(1.0),
+            save_pctg: goalie.then_some(0.967),
+        }
+    }
+
+    fn doc_with_three_stars(game_state: GameState) -> BoxscoreDocumentContent {
+        let mut boxscore = create_test_boxscore();
+        boxscore.game_state = game_state;
+        let mut summary = create_test_game_summary();
+        // Out of order to check the table sorts by star
+        summary.three_stars = Some(vec![
+            create_test_star(2, 11, "A. Forward1", Position::Center),
+            create_test_star(1, 31, "A. Goalie", Position::Goalie),
+            create_test_star(3, 21, "H. Forward1", Position::LeftWing),
+        ]);
+        BoxscoreDocumentContent::new(2024020001, boxscore, TeamView::Away)
+            .with_game_summary(Some(summary))
+    }
+
+    #[test]
+    fn test_three_stars_listed_for_final_game() {
+        let doc = doc_with_three_stars(GameState::Off);
+
+        let elements = doc.build(&FocusContext::default());
+        let Some(DocumentElement::Group { children, .. }) = elements.last() else {
+            panic!("Expected the three stars section last");
+        };
+        assert!(matches!(
+            &children[0],
+            DocumentElement::SectionTitle { content, .. } if content == "Three Stars"
+        ));
+        let DocumentElement::Table { widget, focusable } = &children[1] else {
+            panic!("Expected the three stars table");
+        };
+
+        let row = |r: usize| -> Vec<String> {
+            (0..widget.column_count())
+                .map(|c| {
+                    widget
+                        .get_cell_value(r, c)
+                        .unwrap()
+                        .display_text()
+                        .to_string()
+                })
+                .collect()
+        };
+        assert_eq!(row(0), ["1", "NJD", "A. Goalie", "0.967 SV% 1.00 GAA"]);
+        assert_eq!(row(1), ["2", "NJD", "A. Forward1", "2G 1A 3P"]);
+        assert_eq!(row(2), ["3", "NJD", "H. Forward1", "2G 1A 3P"]);
+
+        let targets: Vec<_> = focusable.iter().map(|f| f.link_target.clone()).collect();
+        assert_eq!(
+            targets,
+            [
+                Some(LinkTarget::Action("player:31".to_string())),
+                Some(LinkTarget::Action("player:11".to_string())),
+                Some(LinkTarget::Action("player:21".to_string())),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_three_stars_omitted_before_final_or_without_data() {
+        let has_stars = |doc: &BoxscoreDocumentContent| {
+            doc.build(&FocusContext::default())
+                .iter()
+                .any(|element| match element {
+                    DocumentElement::Group { children, .. } => children.iter().any(|child| {
+                        matches!(
+                            child,
+                            DocumentElement::SectionTitle { content, .. } if content == "Three Stars"
+                        )
+                    }),
+                    _ => false,
+                })
+        };
+
+        assert!(has_stars(&doc_with_three_stars(GameState::Final)));
+        assert!(!has_stars(&doc_with_three_stars(GameState::Live)));
+
+        let without_summary =
+            BoxscoreDocumentContent::new(2024020001, create_test_boxscore(), TeamView::Away);
+        assert!(!has_stars(&without_summary));
+
+        let without_stars =
+            BoxscoreDocumentContent::new(2024020001, create_test_boxscore(), TeamView::Away)
+                .with_game_summary(Some(create_test_game_summary()));
+        assert!(!has_stars(&without_stars));
+    }
+
+    fn create_test_period_scores() -> PeriodScores {
+        PeriodScores {
+            away_periods: vec![1, 2, 0],
+            home_periods: vec![0, 1, 1],
+            has_ot: false,
+            has_so: false,
+        }
+    }
+
+    fn find_line_score(elements: &[DocumentElement]) -> Option<&TableWidget> {
+        elements.iter().find_map(|e| match e {
+            DocumentElement::Table { widget, .. }
+                if widget.column_headers.last().is_some_and(|h| h == "SOG") =>
+            {
+                Some(widget)
+            }
+            _ => None,
+        })
+    }
+
+    fn header_texts(table: &TableWidget) -> Vec<String> {
+        table.column_headers.clone()
+    }
+
+    #[test]
+    fn test_line_score_for_final_game() {
+        let doc = BoxscoreDocumentContent::new(2024020001, create_test_boxscore(), TeamView::Away)
+            .with_period_scores(Some(create_test_period_scores()));
+
+        let elements = doc.build(&FocusContext::default());
+        let table = find_line_score(&elements).expect("line score table");
+
+        assert_eq!(header_texts(table), vec!["Team", "1", "2", "3", "T", "SOG"]);
+        assert_eq!(table.row_count(), 2);
+        assert_eq!(
+            table.get_cell_value(0, 0),
+            Some(CellValue::Text("NJD".to_string()))
+        );
+        assert_eq!(
+            table.get_cell_value(0, 2),
+            Some(CellValue::Text("2".to_string()))
+        );
+        assert_eq!(
+            table.get_cell_value(1, 4),
+            Some(CellValue::Text("2".to_string()))
+        );
+        assert_eq!(
+            table.get_cell_value(1, 5),
+            Some(CellValue::Text("25".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_line_score_overtime_and_shootout_columns() {
+        let mut boxscore = create_test_boxscore();
+        boxscore.period_descriptor = PeriodDescriptor {
+            number: 4,
+            period_type: PeriodType::Overtime,
+            max_regulation_periods: 3,
+        };
+        let mut scores = create_test_period_scores();
+        scores.away_periods.push(1);
+        scores.home_periods.push(0);
+        let doc = BoxscoreDocumentContent::new(2024020001, boxscore.clone(), TeamView::Away)
+            .with_period_scores(Some(scores.clone()));
+        let elements = doc.build(&FocusContext::default());
+        let table = find_line_score(&elements).unwrap();
+        assert_eq!(
+            header_texts(table),
+            vec!["Team", "1", "2", "3", "OT", "T", "SOG"]
+        );
+
+        boxscore.period_descriptor.number = 5;
+        boxscore.period_descriptor.period_type = PeriodType::Shootout;
+        scores.away_periods.push(1);
+        scores.home_periods.push(0);
+        let doc = BoxscoreDocumentContent::new(2024020001, boxscore, TeamView::Away)
+            .with_period_scores(Some(scores));
+        let elements = doc.build(&FocusContext::default());
+        let table = find_line_score(&elements).unwrap();
+        assert_eq!(
+            header_texts(table),
+            vec!["Team", "1", "2", "3", "OT", "SO", "T", "SOG"]
+        );
+    }
+
+    #[test]
+    fn test_line_score_unplayed_periods_show_dash() {
+        let mut boxscore = create_test_boxscore();
+        boxscore.game_state = GameState::Live;
+        boxscore.period_descriptor.number = 2;
+        let doc = BoxscoreDocumentContent::new(2024020001, boxscore, TeamView::Away)
+            .with_period_scores(Some(create_test_period_scores()));
+
+        let elements = doc.build(&FocusContext::default());
+        let table = find_line_score(&elements).unwrap();
+        assert_eq!(
+            table.get_cell_value(0, 3),
+            Some(CellValue::Text("-".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_line_score_omitted_for_future_game() {
+        for state in [GameState::Future, GameState::PreGame] {
+            let mut boxscore = create_test_boxscore();
+            boxscore.game_state = state;
+            let doc = BoxscoreDocumentContent::new(2024020001, boxscore, TeamView::Away)
+                .with_period_scores(Some(create_test_period_scores()));
+
+            let elements = doc.build(&FocusContext::default());
+            assert!(find_line_score(&elements).is_none());
+        }
+    }
+
+    #[test]
+    fn test_line_score_omitted_without_period_scores() {
+        let doc = BoxscoreDocumentContent::new(2024020001, create_test_boxscore(), TeamView::Away);
+        let elements = doc.build(&FocusContext::default());
+        assert!(find_line_score(&elements).is_none());
+    }
+
+    fn find_shots_line_score(elements: &[DocumentElement]) -> Option<&TableWidget> {
+        elements.iter().find_map(|e| match e {
+            DocumentElement::Table { widget, .. }
+                if widget.column_headers.last().is_some_and(|h| h == "T") =>
+            {
+                Some(widget)
+            }
+            _ => None,
+        })
+    }
+
+    fn has_shots_unavailable_note(elements: &[DocumentElement]) -> bool {
+        elements.iter().any(|e| {
+            matches!(e, DocumentElement::Text { content, .. } if content == "Shots by period unavailable")
+        })
+    }
+
+    #[test]
+    fn test_line_score_shots_per_period() {
+        let doc = BoxscoreDocumentContent::new(2024020001, create_test_boxscore(), TeamView::Away)
+            .with_period_scores(Some(create_test_period_scores()))
+            .with_shots_by_period(Some(create_test_shots_by_period()));
+
+        let elements = doc.build(&FocusContext::default());
+        let table = find_shots_line_score(&elements).expect("shots line score");
+
+        assert_eq!(header_texts(table), vec!["Team", "1", "2", "3", "T"]);
+        assert_eq!(table_row(table, 0)[1..4], ["12", "8", "10"]);
+        assert_eq!(table_row(table, 1)[1..4], ["6", "9", "4"]);
+        assert!(!has_shots_unavailable_note(&elements));
+    }
+
+    #[test]
+    fn test_line_score_notes_missing_shots_per_period() {
+        let doc = BoxscoreDocumentContent::new(2024020001, create_test_boxscore(), TeamView::Away)
+            .with_period_scores(Some(create_test_period_scores()));
+
+        let elements = doc.build(&FocusContext::default());
+        assert!(find_shots_line_score(&elements).is_none());
+        assert!(has_shots_unavailable_note(&elements));
+    }
+
+    #[test]
+    fn test_line_score_has_no_focusable_cells() {
+        let doc = BoxscoreDocumentContent::new(2024020001, create_test_boxscore(), TeamView::Away)
+            .with_period_scores(Some(create_test_period_scores()));
+        // Player tables (8) plus game leaders (6), nothing from the line score
+        assert_eq!(doc.focusable_positions().len(), 14);
+    }
+
+    fn create_test_penalty(team: &str, penalty_type: &str) -> nhl_api::PenaltySummary {
+        nhl_api::PenaltySummary {
+            time_in_period: "05:00".to_string(),
+            penalty_type: penalty_type.to_string(),
+            duration: 2,
+            committed_by_player: None,
+            team_abbrev: LocalizedString {
+                default: team.to_string(),
+            },
+            drawn_by: None,
+            desc_key: "tripping".to_string(),
+            served_by: None,
+            event_id: None,
+        }
+    }
+
+    fn table_row(widget: &TableWidget, row: usize) -> Vec<String> {
+        (0..widget.column_count())
+            .map(|c| {
+                widget
+                    .get_cell_value(row, c)
+                    .unwrap()
+                    .display_text()
+                    .to_string()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_penalties_grouped_by_team() {
+        let mut too_many_men = create_test_penalty("BUF", "MIN");
+        too_many_men.desc_key = "too-many-men-on-the-ice".to_string();
+        too_many_men.served_by = Some(LocalizedString {
+            default: "J. Skinner".to_string(),
+        });
+        let mut holding = create_test_penalty("NJD", "MIN");
+        holding.desc_key = "holding-the-stick".to_string();
+        holding.time_in_period = "12:34".to_string();
+        holding.committed_by_player = Some(nhl_api::PenaltyPlayer {
+            first_name: LocalizedString {
+                default: "Jack".to_string(),
+            },
+            last_name: LocalizedString {
+                default: "Hughes".to_string(),
+            },
+            sweater_number: 86,
+        });
+        let mut summary = create_test_game_summary();
+        summary.penalties = vec![
+            nhl_api::PeriodPenalties {
+                period_descriptor: PeriodDescriptor {
+                    number: 2,
+                    period_type: PeriodType::Regulation,
+                    max_regulation_periods: 3,
+                },
+                penalties: vec![too_many_men],
+            },
+            nhl_api::PeriodPenalties {
+                period_descriptor: PeriodDescriptor {
+                    number: 4,
+                    period_type: PeriodType::Overtime,
+                    max_regulation_periods: 3,
+                },
+                penalties: vec![holding],
+            },
+        ];
+        let doc = BoxscoreDocumentContent::new(123, create_test_boxscore(), TeamView::Away)
+            .with_game_summary(Some(summary));
+
+        let elements = doc.build_penalties();
+        assert_eq!(elements.len(), 4);
+
+        // Away team (NJD) first, labelled in its primary color
+        let (njd_color, _) = abbrev_to_colors("NJD").unwrap();
+        assert!(matches!(
+            &elements[0],
+            DocumentElement::Text { content, style: Some(style) }
+                if content == "NJD" && style.fg == Some(njd_color)
+        ));
+        let DocumentElement::Table { widget, .. } = &elements[1] else {
+            panic!("Expected the away penalties table");
+        };
+        assert_eq!(
+            table_row(widget, 0),
+            ["OT", "12:34", "J. Hughes", "Holding the stick", "2"]
+        );
+
+        let DocumentElement::Table { widget, .. } = &elements[3] else {
+            panic!("Expected the home penalties table");
+        };
+        assert_eq!(
+            table_row(widget, 0),
+            [
+                "2",
+                "05:00",
+                "Bench (J. Skinner)",
+                "Too many men on the ice",
+                "2"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_penalties_omitted_without_data_or_before_start() {
+        let doc = BoxscoreDocumentContent::new(123, create_test_boxscore(), TeamView::Away);
+        assert!(doc.build_penalties().is_empty());
+
+        let mut boxscore = create_test_boxscore();
+        boxscore.game_state = GameState::Future;
+        let doc = BoxscoreDocumentContent::new(123, boxscore, TeamView::Away)
+            .with_game_summary(Some(create_test_game_summary()));
+        assert!(doc.build_penalties().is_empty());
+
+        let mut summary = create_test_game_summary();
+        summary.penalties.clear();
+        let doc = BoxscoreDocumentContent::new(123, create_test_boxscore(), TeamView::Away)
+            .with_game_summary(Some(summary));
+        assert!(doc.build_penalties().is_empty());
+    }
+
+    fn create_test_game_summary() -> GameSummary {
+        GameSummary {
+            scoring: vec![],
+            shootout: None,
+            three_stars: None,
+            penalties: vec![nhl_api::PeriodPenalties {
+                period_descriptor: PeriodDescriptor {
+                    number: 1,
+                    period_type: PeriodType::Regulation,
+                    max_regulation_periods: 3,
+                },
+                penalties: vec![
+                    create_test_penalty("BUF", "MIN"),
+                    create_test_penalty("BUF", "MIN"),
+                    create_test_penalty("BUF", "MIS"),
+                    create_test_penalty("NJD", "MAJ"),
+                    create_test_penalty("NJD", "MIN"),
+                ],
+            }],
+        }
+    }
+
+    fn find_team_summary(elements: &[DocumentElement]) -> Option<&TableWidget> {
+        elements.iter().find_map(|e| match e {
+            DocumentElement::Table { widget, .. } if widget.column_headers[1] == "PP" => {
+                Some(widget)
+            }
+            _ => None,
+        })
+    }
+
+    #[test]
+    fn test_team_summary_rows() {
+        let mut boxscore = create_test_boxscore();
+        boxscore.player_by_game_stats.away_team.forwards[0].power_play_goals = 1;
+        boxscore.player_by_game_stats.away_team.forwards[0].faceoff_winning_pctg = 0.8;
+        boxscore.player_by_game_stats.away_team.forwards[0].shifts = 40;
+        let doc = BoxscoreDocumentContent::new(123, boxscore, TeamView::Away)
+            .with_game_summary(Some(create_test_game_summary()));
+        let elements = doc.build(&FocusContext::default());
+        let table = find_team_summary(&elements).expect("team summary table");
+
+        assert_eq!(
+            header_texts(table),
+            vec!["Team", "PP", "PK", "FO%", "Hits", "Blk"]
+        );
+        // (0.8 * 40 + 0.5 * 20 + 0.5 * 20) / 80 shifts
+        assert_eq!(table_row(table, 0), ["NJD", "1/2", "2/2", "65.0", "9", "3"]);
+        assert_eq!(table_row(table, 1)[..3], ["BUF", "0/2", "1/2"]);
+    }
+
+    #[test]
+    fn test_team_summary_without_game_summary_omits_special_teams() {
+        let doc = BoxscoreDocumentContent::new(123, create_test_boxscore(), TeamView::Away);
+        let elements = doc.build(&FocusContext::default());
+        let table = find_team_summary(&elements).expect("team summary table");
+
+        assert_eq!(table_row(table, 0)[..4], ["NJD", "-", "-", "50.0"]);
+    }
+
+    #[test]
+    fn test_team_summary_faceoffs_without_takers() {
+        let mut boxscore = create_test_boxscore();
+        let away = &mut boxscore.player_by_game_stats.away_team;
+        for skater in away.forwards.iter_mut().chain(away.defense.iter_mut()) {
+            skater.faceoff_winning_pctg = 0.0;
+        }
+        let doc = BoxscoreDocumentContent::new(123, boxscore, TeamView::Away);
+        let elements = doc.build(&FocusContext::default());
+        let table = find_team_summary(&elements).expect("team summary table");
+
+        assert_eq!(table_row(table, 0)[3], "-");
+    }
+
+    #[test]
+    fn test_team_faceoff_pct_counts_centers_without_wins() {
+        let mut players = create_test_boxscore().player_by_game_stats.away_team;
+        // The center lost every faceoff; the winger won every one they took
+        players.forwards[0].faceoff_winning_pctg = 0.0;
+        players.forwards[1].faceoff_winning_pctg = 1.0;
+        players.defense[0].faceoff_winning_pctg = 0.0;
+
+        assert_eq!(team_faceoff_pct(&players), Some(50.0));
+    }
+
+    #[test]
+    fn test_team_summary_and_comparison_share_faceoff_pct() {
+        let mut boxscore = create_test_boxscore();
+        boxscore.player_by_game_stats.away_team.forwards[0].faceoff_winning_pctg = 0.8;
+        boxscore.player_by_game_stats.away_team.forwards[0].shifts = 40;
+        let doc = BoxscoreDocumentContent::new(123, boxscore, TeamView::Away);
+        let elements = doc.build(&FocusContext::default());
+        let table = find_team_summary(&elements).expect("team summary table");
+
+        let faceoff_bar = doc
+            .build_team_comparison()
+            .into_iter()
+            .find_map(|element| match element {
+                DocumentElement::CompareBar {
+                    label,
+                    away_display,
+                    ..
+                } if label == "Faceoff %" => Some(away_display),
+                _ => None,
+            })
+            .expect("faceoff bar");
+        assert_eq!(faceoff_bar, format!("{}%", table_row(table, 0)[3]));
+    }
+
+    #[test]
+    fn test_team_summary_omitted_for_future_game() {
+        for state in [GameState::Future, GameState::PreGame] {
+            let mut boxscore = create_test_boxscore();
+            boxscore.game_state = state;
+            let doc = BoxscoreDocumentContent::new(123, boxscore, TeamView::Away)
+                .with_game_summary(Some(create_test_game_summary()));
+            let elements = doc.build(&FocusContext::default());
+
+            assert!(find_team_summary(&elements).is_none());
+        }
+    }
+
+    fn create_test_shots_by_period() -> Vec<PeriodShots> {
+        [("1", 12, 6), ("2", 8, 9), ("3", 10, 4)]
+            .into_iter()
+            .map(|(label, away, home)| PeriodShots {
+                label: label.to_string(),
+                away,
+                home,
+            })
+            .collect()
+    }
+
+    fn find_shots_by_period(elements: &[DocumentElement]) -> Option<&[PeriodShots]> {
+        elements.iter().find_map(|e| match e {
+            DocumentElement::ShotsByPeriod { periods, .. } => Some(periods.as_slice()),
+            _ => None,
+        })
+    }
+
+    #[test]
+    fn test_shots_by_period_chart_present() {
+        let doc = BoxscoreDocumentContent::new(123, create_test_boxscore(), TeamView::Away)
+            .with_shots_by_period(Some(create_test_shots_by_period()));
+        let elements = doc.build(&FocusContext::default());
+
+        assert_eq!(
+            find_shots_by_period(&elements),
+            Some(create_test_shots_by_period().as_slice())
+        );
+    }
+
+    #[test]
+    fn test_shots_by_period_skips_unplayed_periods() {
+        let mut boxscore = create_test_boxscore();
+        boxscore.game_state = GameState::Live;
+        boxscore.period_descriptor.number = 1;
+        let doc = BoxscoreDocumentContent::new(123, boxscore, TeamView::Away)
+            .with_shots_by_period(Some(create_test_shots_by_period()));
+        let elements = doc.build(&FocusContext::default());
+
+        let periods = find_shots_by_period(&elements).unwrap();
+        assert_eq!(periods.len(), 1);
+        assert_eq!(periods[0].label, "1");
+    }
+
+    #[test]
+    fn test_shots_by_period_omitted_without_data() {
+        let doc = BoxscoreDocumentContent::new(123, create_test_boxscore(), TeamView::Away);
+        let elements = doc.build(&FocusContext::default());
+
+        assert!(find_shots_by_period(&elements).is_none());
+    }
+
+    fn compare_bar_labels(elements: &[DocumentElement]) -> Vec<String> {
+        elements
+            .iter()
+            .filter_map(|e| match e {
+                DocumentElement::CompareBar { label, .. } => Some(label.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_team_comparison_bars_present() {
+        let doc = BoxscoreDocumentContent::new(123, create_test_boxscore(), TeamView::Away);
+        let elements = doc.build(&FocusContext::default());
+
+        assert_eq!(
+            compare_bar_labels(&elements),
+            vec!["Shots", "Hits", "PIM", "PP Goals", "Faceoff %"]
+        );
+        let shots = elements
+            .iter()
+            .find_map(|e| match e {
+                DocumentElement::CompareBar {
+                    label,
+                    away_display,
+                    home_display,
+                    ..
+                } if label == "Shots" => Some((away_display.clone(), home_display.clone())),
+                _ => None,
+            })
+            .unwrap();
+        let boxscore = create_test_boxscore();
+        assert_eq!(shots.0, boxscore.away_team.sog.to_string());
+        assert_eq!(shots.1, boxscore.home_team.sog.to_string());
+    }
+
+    #[test]
+    fn test_team_comparison_omitted_for_future_game() {
+        let mut boxscore = create_test_boxscore();
+        boxscore.game_state = GameState::Future;
+        let doc = BoxscoreDocumentContent::new(123, boxscore, TeamView::Away);
+        let elements = doc.build(&FocusContext::default());
+
+        assert!(compare_bar_labels(&elements).is_empty());
+    }
+
+    fn names(skaters: &[SkaterStats]) -> Vec<&str> {
+        skaters.iter().map(|s| s.name.default.as_str()).collect()
+    }
+
+    #[test]
+    fn test_boxscore_sort_cycle_wraps() {
+        let mut sort = None;
+        let mut headers = Vec::new();
+        for _ in 0..5 {
+            sort = next_boxscore_sort(sort);
+            headers.push(sort.and_then(sort_header));
+        }
+        assert_eq!(
+            headers,
+            vec![Some("PTS"), Some("G"), Some("TOI"), Some("+/-"), None]
+        );
+        assert!(sort.is_none());
+    }
+
+    #[test]
+    fn test_boxscore_table_sort() {
+        let points = boxscore_table_sort("points_desc").unwrap();
+        assert_eq!(sort_header(points), Some("PTS"));
+        assert!(!points.ascending);
+
+        let number = boxscore_table_sort("number_asc").unwrap();
+        assert_eq!(number.column, 0);
+        assert!(number.ascending);
+
+        assert_eq!(
+            boxscore_table_sort("toi_desc").and_then(sort_header),
+            Some("TOI")
+        );
+        assert!(boxscore_table_sort("fo_pct_desc").is_none());
+    }
+
+    #[test]
+    fn test_every_config_sort_column_has_a_table_column() {
+        for name in BOXSCORE_SORT_COLUMNS {
+            assert!(
+                boxscore_table_sort(&format!("{}_desc", name)).is_some(),
+                "{} has no skater column",
+                name
+            );
+        }
+    }
+
+    #[test]
+    fn test_skater_totals_sum_points() {
+        let mut skaters = vec![
+            create_test_skater("A. One", 10, Position::Center),
+            create_test_skater("B. Two", 20, Position::LeftWing),
+            create_test_skater("C. Three", 30, Position::RightWing),
+        ];
+        skaters[0].points = 4;
+        skaters[1].points = 0;
+        skaters[2].plus_minus = -3;
+
+        let columns = game_skater_columns(None, true, NumberFormat::default(), false);
+        let totals: Vec<String> = columns.iter().map(|c| c.total_text(&skaters)).collect();
+        let total_of = |header: &str| {
+            let idx = columns.iter().position(|c| c.header == header).unwrap();
+            totals[idx].clone()
+        };
+
+        assert_eq!(total_of("PTS"), "7");
+        assert_eq!(total_of("G"), "3");
+        assert_eq!(total_of("SOG"), "12");
+        assert_eq!(total_of("+/-"), "-1");
+        assert_eq!(total_of("TOI"), "46:30");
+        assert_eq!(total_of("Player"), "TOTAL");
+        assert_eq!(total_of("FO%"), "");
+    }
+
+    #[test]
+    fn test_toi_minutes() {
+        assert_eq!(toi_minutes("15:30"), 15.5);
+        assert_eq!(toi_minutes("0:45"), 0.75);
+        assert_eq!(toi_minutes("bad"), 0.0);
+    }
+
+    #[test]
+    fn test_per_60_rate() {
+        assert_eq!(per_60_rate(2, "20:00"), Some(6.0));
+        assert_eq!(per_60_rate(1, "2:00"), Some(30.0));
+        assert_eq!(per_60_rate(0, "15:30"), Some(0.0));
+        // Negligible or unparseable ice time has no rate
+        assert_eq!(per_60_rate(1, "1:59"), None);
+        assert_eq!(per_60_rate(1, "bad"), None);
+
+        assert_eq!(count_cell(3, "18:00", true).display_text(), "10.0");
+        assert_eq!(count_cell(3, "0:40", true).display_text(), "-");
+        assert_eq!(count_cell(3, "0:40", false).display_text(), "3");
+    }
+
+    #[test]
+    fn test_per_60_skater_columns() {
+        let mut fourth_liner = create_test_skater("B. Two", 20, Position::LeftWing);
+        fourth_liner.toi = "1:10".to_string();
+        // The first has 1 goal and 4 shots in 15:30
+        let skaters = vec![
+            create_test_skater("A. One", 10, Position::Center),
+            fourth_liner,
+        ];
+
+        let columns = game_skater_columns(None, true, NumberFormat::default(), true);
+        let table = TableWidget::from_data(&columns, &skaters);
+        let headers = header_texts(&table);
+        let cell = |row: usize, header: &str| {
+            let col = headers.iter().position(|h| h == header).unwrap();
+            table.cell_data[row][col].display_text().to_string()
+        };
+
+        assert_eq!(headers[1], "Player (per 60)");
+        assert_eq!(cell(0, "G"), "3.9");
+        assert_eq!(cell(0, "SOG"), "15.5");
+        // Not rates: +/-, FO% and TOI stay as they are
+        assert_eq!(cell(0, "+/-"), "+1");
+        assert_eq!(cell(0, "TOI"), "15:30");
+        assert_eq!(cell(1, "G"), "-");
+
+        let goals = columns.iter().find(|c| c.header == "G").unwrap();
+        assert_eq!(goals.total_text(&skaters), "");
+        assert_eq!(
+            goals.description.as_deref(),
+            Some("Goals per 60 minutes of ice time")
+        );
+    }
+
+    #[test]
+    fn test_sort_skaters_is_stable() {
+        let mut a = create_test_skater("A", 1, Position::Center);
+        let mut b = create_test_skater("B", 2, Position::Center);
+        let mut c = create_test_skater("C", 3, Position::Center);
+        a.points = 2;
+        b.points = 5;
+        c.points = 2;
+        let mut skaters = vec![a, b, c];
+
+        sort_skaters(&mut skaters, boxscore_table_sort("points_desc"));
+
+        assert_eq!(names(&skaters), vec!["B", "A", "C"]);
+    }
+
+    #[test]
+    fn test_sort_skaters_by_toi() {
+        let mut a = create_test_skater("A", 1, Position::Center);
+        let mut b = create_test_skater("B", 2, Position::Center);
+        a.toi = "9:59".to_string();
+        b.toi = "10:01".to_string();
+        let mut skaters = vec![a, b];
+
+        sort_skaters(&mut skaters, boxscore_table_sort("toi_desc"));
+
+        assert_eq!(names(&skaters), vec!["B", "A"]);
+        assert_eq!(toi_seconds("bad"), 0);
+    }
+
+    #[test]
+    fn test_sort_skaters_number_ascending() {
+        let mut skaters = vec![
+            create_test_skater("C", 30, Position::Center),
+            create_test_skater("A", 4, Position::Center),
+            create_test_skater("B", 12, Position::Center),
+        ];
+
+        sort_skaters(&mut skaters, boxscore_table_sort("number_asc"));
+
+        assert_eq!(names(&skaters), vec!["A", "B", "C"]);
+    }
+
+    fn forwards_table(doc: &BoxscoreDocumentContent) -> TableWidget {
+        doc.build(&FocusContext::default().with_unicode(true))
+            .into_iter()
+            .find_map(|e| match e {
+                DocumentElement::TeamBoxscore { forwards_table, .. } => Some(forwards_table),
+                _ => None,
+            })
+            .expect("team boxscore")
+    }
+
+    /// First focusable cell of the table whose name ends with `suffix`
+    fn table_cell_id(doc: &BoxscoreDocumentContent, suffix: &str) -> FocusableId {
+        doc.focusable_ids()
+            .into_iter()
+            .find(|id| matches!(id, FocusableId::TableCell { table_name, .. } if table_name.ends_with(suffix)))
+            .expect("table cell")
+    }
+
+    #[test]
+    fn test_column_legend_lists_focused_table_columns() {
+        let doc = BoxscoreDocumentContent::new(123, create_test_boxscore(), TeamView::Away);
+        let entry = |header: &str, description: &str| (header.to_string(), description.to_string());
+
+        let skaters = doc.column_legend(&table_cell_id(&doc, "_forwards"));
+        assert!(skaters.contains(&entry("SOG", "Shots on goal")));
+        assert!(skaters.contains(&entry("Blk", "Blocked shots")));
+        assert!(skaters.contains(&entry("FO%", "Faceoff win percentage")));
+        assert!(!skaters.iter().any(|(header, _)| header == "SV%"));
+        // Full-word headers need no legend
+        assert!(!skaters.iter().any(|(header, _)| header == "Player"));
+
+        let goalies = doc.column_legend(&table_cell_id(&doc, "_goalies"));
+        assert!(goalies.contains(&entry("GA", "Goals against")));
+        assert!(goalies.contains(&entry("SV%", "Save percentage")));
+        assert!(!goalies.iter().any(|(header, _)| header == "SOG"));
+
+        assert!(doc
+            .column_legend(&FocusableId::TeamLink("NJD".to_string()))
+            .is_empty());
+    }
+
+    #[test]
+    fn test_configured_points_desc_sorts_fixture() {
+        let mut boxscore = create_test_boxscore();
+        boxscore.player_by_game_stats.away_team.forwards[1].points = 7;
+        let doc = BoxscoreDocumentContent::new(123, boxscore, TeamView::Away)
+            .with_sort(boxscore_table_sort("points_desc"));
+
+        let table = forwards_table(&doc);
+
+        assert!(header_texts(&table).contains(&"PTS▼".to_string()));
+        assert_eq!(table.cell_data[0][1].display_text(), "A. Forward2");
+        assert_eq!(table.cell_data[1][1].display_text(), "A. Forward1");
+    }
+
+    #[test]
+    fn test_invalid_configured_sort_keeps_api_order() {
+        let mut boxscore = create_test_boxscore();
+        boxscore.player_by_game_stats.away_team.forwards[1].points = 7;
+        let doc = BoxscoreDocumentContent::new(123, boxscore, TeamView::Away)
+            .with_sort(boxscore_table_sort("bogus_desc"));
+
+        let table = forwards_table(&doc);
+
+        assert!(!header_texts(&table).iter().any(|h| h.contains('▼')));
+        assert_eq!(table.cell_data[0][1].display_text(), "A. Forward1");
+    }
+
+    /// Render a table and return its header and first data row
+    fn render_header_and_row(table: &TableWidget, width: u16) -> (String, String) {
+        let mut buf = Buffer::empty(Rect::new(0, 0, width, 3));
+        table.render(buf.area, &mut buf, &DisplayConfig::default());
+        let lines = buffer_lines(&buf);
+        (lines[0].clone(), lines[2].clone())
+    }
+
+    /// Assert `value` is shown whole, right-aligned under `header`
+    fn assert_right_aligned_under(header_line: &str, row_line: &str, header: &str, value: &str) {
+        let header_end = header_line.find(header).unwrap() + header.len();
+        let value_start = row_line
+            .find(value)
+            .unwrap_or_else(|| panic!("'{}' not shown in '{}'", value, row_line));
+        assert_eq!(value_start + value.len(), header_end);
+        // Separated from the previous column by the regular gap
+        assert_eq!(&row_line[value_start - 2..value_start], "  ");
+    }
+
+    #[test]
+    fn test_perfect_save_pctg_fits_column() {
+        let mut goalie = create_test_goalie("A. Goalie", 30);
+        goalie.save_pctg = Some(1.0);
+        goalie.goals_against = 0;
+        goalie.saves = goalie.shots_against;
+        let table = TableWidget::from_data(
+            &game_goalie_columns(None, false, false, NumberFormat::default()),
+            vec![goalie],
+        );
+
+        let (header, row) = render_header_and_row(&table, 80);
+        assert_right_aligned_under(&header, &row, "SV%", "1.000");
+    }
+
+    #[test]
+    fn test_format_save_pctg_diff() {
+        assert_eq!(format_save_pctg_diff(0.923), "+.023");
+        assert_eq!(format_save_pctg_diff(0.859), "-.041");
+        assert_eq!(format_save_pctg_diff(0.9), ".000");
+        assert_eq!(format_save_pctg_diff(1.0), "+.100");
+    }
+
+    #[test]
+    fn test_goalie_save_diff_column() {
+        let goalie = create_test_goalie("A. Goalie", 30);
+        let plain = TableWidget::from_data(
+            &game_goalie_columns(None, true, false, NumberFormat::default()),
+            vec![],
+        );
+        assert!((0..plain.column_count()).all(|col| plain.column_header(col) != Some("ΔSV%")));
+
+        let table = TableWidget::from_data(
+            &game_goalie_columns(None, false, true, NumberFormat::default()),
+            vec![goalie],
+        );
+        let (header, row) = render_header_and_row(&table, 80);
+        assert_right_aligned_under(&header, &row, "dSV%", "+.020");
+
+        let table = TableWidget::from_data(
+            &game_goalie_columns(None, true, true, NumberFormat::default()),
+            vec![],
+        );
+        assert_eq!(table.column_header(6), Some("ΔSV%"));
+    }
+
+    #[test]
+    fn test_perfect_faceoff_pctg_fits_column() {
+        let mut skater = create_test_skater("A. Forward1", 10, Position::Center);
+        skater.faceoff_winning_pctg = 1.0;
+        let table = TableWidget::from_data(
+            &game_skater_columns(None, false, NumberFormat::default(), false),
+            vec![skater],
+        );
+
+        let (header, row) = render_header_and_row(&table, 160);
+        assert_right_aligned_under(&header, &row, "FO%", "100.0");
+    }
+
+    #[test]
+    fn test_columns_follow_number_format() {
+        let numbers = NumberFormat {
+            save_pctg_decimals: 2,
+            percent_decimals: 0,
+            leading_zero: false,
+            ..NumberFormat::default()
+        };
+
+        let goalies = TableWidget::from_data(
+            &game_goalie_columns(None, false, false, numbers),
+            vec![create_test_goalie("A. Goalie", 30)],
+        );
+        let (header, row) = render_header_and_row(&goalies, 80);
+        assert_right_aligned_under(&header, &row, "SV%", ".92");
+
+        let skaters = TableWidget::from_data(
+            &game_skater_columns(None, false, numbers, false),
+            vec![create_test_skater("A. Forward1", 10, Position::Center)],
+        );
+        let (header, row) = render_header_and_row(&skaters, 160);
+        assert_right_aligned_under(&header, &row, "FO%", "50");
+    }
+
+    fn goalie_display(goalie: GoalieStats, use_unicode: bool) -> String {
+        let table = TableWidget::from_data(
+            &game_goalie_columns(None, use_unicode, false, NumberFormat::default()),
+            vec![goalie],
+        );
+        table
+            .get_cell_value(0, 1)
+            .unwrap()
+            .display_text()
+            .to_string()
+    }
+
+    #[test]
+    fn test_quality_start_marker_shown() {
+        // 23 saves on 25 shots = .920
+        let goalie = create_test_goalie("A. Goalie", 30);
+        assert!(is_quality_start(&goalie));
+        assert_eq!(goalie_display(goalie.clone(), true), "A. Goalie ★");
+        assert_eq!(goalie_display(goalie, false), "A. Goalie QS");
+    }
+
+    #[test]
+    fn test_quality_start_marker_hidden_below_threshold() {
+        let mut goalie = create_test_goalie("A. Goalie", 30);
+        goalie.goals_against = 4;
+        goalie.saves = 21;
+        goalie.save_pctg = Some(0.840);
+        assert!(!is_quality_start(&goalie));
+        assert_eq!(goalie_display(goalie, true), "A. Goalie");
+    }
+
+    #[test]
+    fn test_quality_start_light_workload_threshold() {
+        // 16 saves on 18 shots = .889: below .917 but enough on a light workload
+        let mut goalie = create_test_goalie("A. Goalie", 30);
+        goalie.shots_against = 18;
+        goalie.saves = 16;
+        goalie.goals_against = 2;
+        goalie.save_pctg = Some(16.0 / 18.0);
+        assert!(is_quality_start(&goalie));
+    }
+
+    #[test]
+    fn test_quality_start_excludes_too_few_shots() {
+        let mut goalie = create_test_goalie("A. Goalie", 30);
+        goalie.shots_against = 5;
+        goalie.saves = 5;
+        goalie.goals_against = 0;
+        goalie.save_pctg = Some(1.0);
+        assert!(!is_quality_start(&goalie));
+        assert_eq!(goalie_display(goalie, true), "A. Goalie");
+    }
+
+    #[test]
+    fn test_quality_start_computes_missing_save_pctg() {
+        let mut goalie = create_test_goalie("A. Goalie", 30);
+        goalie.save_pctg = None;
+        assert!(is_quality_start(&goalie));
     }
 
     #[test]
@@ -629,10 +3522,17 @@ mod tests {
         let widget = BoxscoreDocumentWidget {
             game_id: 2024020001,
             boxscore: None,
-            loading: false,
+            period_scores: None,
+            game_summary: None,
+            records: None,
+            time_format: DEFAULT_TIME_FORMAT.to_string(),
             team_view: TeamView::Away,
+            sort: None,
+            per_60: false,
+            column_offset: 0,
             selected_index: None,
             scroll_offset: 0,
+            find: None,
             focused: true,
             animation_frame: 0,
         };
@@ -652,11 +3552,18 @@ mod tests {
         let boxscore = create_test_boxscore();
         let widget = BoxscoreDocumentWidget {
             game_id: 2024020001,
-            boxscore: Some(boxscore),
-            loading: false,
+            boxscore: Some(Arc::new(boxscore)),
+            period_scores: None,
+            game_summary: None,
+            records: None,
+            time_format: DEFAULT_TIME_FORMAT.to_string(),
             team_view: TeamView::Away,
+            sort: None,
+            per_60: false,
+            column_offset: 0,
             selected_index: None,
             scroll_offset: 0,
+            find: None,
             focused: true,
             animation_frame: 0,
         };
@@ -2,6 +2,19 @@
 ///
 /// Displays a breadcrumb trail showing the user's current location in the document stack.
 /// Example: "Standings > Team: TOR > Player: Sidney Crosby"
+///
+/// The Scores segment includes the viewed date: "Scores — Today" or "Scores — Oct 1",
+/// followed by the game type when one is selected: "Scores — Today (Playoffs)".
+/// The Standings segment names the season when it isn't the current one:
+/// "Standings — 2022-2023".
+///
+/// When the trail is wider than the terminal, the oldest crumbs between the tab
+/// and the current document collapse into an ellipsis: "Scores ▶ … ▶ #87 Crosby".
+///
+/// Clicking an ancestor crumb, or pressing Alt with its position (Alt+1 for the
+/// tab), pops the document stack back to it.
+use chrono::{Local, NaiveDate};
+use nhl_api::GameDate;
 use ratatui::{
     buffer::Buffer,
     layout::Rect,
@@ -9,14 +22,25 @@ use ratatui::{
     text::{Line, Span},
 };
 
+use unicode_width::UnicodeWidthStr;
+
 use crate::config::DisplayConfig;
-use crate::tui::{component::ElementWidget, state::DocumentStackEntry, Tab};
+use crate::season::season_label;
+use crate::tui::hit_test::{HitRegion, HitTarget};
+use crate::tui::state::{DocumentStackEntry, SeasonSelection};
+use crate::tui::{component::ElementWidget, Tab};
 
 /// Breadcrumb widget that renders a navigation path
 #[derive(Clone)]
 pub struct BreadcrumbWidget {
     pub current_tab: Tab,
     pub document_stack: Vec<DocumentStackEntry>,
+    /// Date viewed on the Scores tab, shown next to the tab name
+    pub scores_date: Option<GameDate>,
+    /// Standings season and scores game type, shown when not the defaults
+    pub season: SeasonSelection,
+    /// Animation frame for the pulsing LIVE marker, set while viewing a live game
+    pub live_frame: Option<u8>,
 }
 
 impl BreadcrumbWidget {
@@ -24,12 +48,42 @@ impl BreadcrumbWidget {
         Self {
             current_tab,
             document_stack,
+            scores_date: None,
+            season: SeasonSelection::default(),
+            live_frame: None,
         }
     }
 
-    /// Build breadcrumb text from tab and document stack
-    fn build_breadcrumb_text(&self, config: &DisplayConfig) -> Vec<Span<'_>> {
-        let mut spans = Vec::new();
+    /// Set the date shown in the Scores segment
+    pub fn with_scores_date(mut self, date: GameDate) -> Self {
+        self.scores_date = Some(date);
+        self
+    }
+
+    /// Set the season and game type shown in the Standings and Scores segments
+    pub fn with_season(mut self, season: SeasonSelection) -> Self {
+        self.season = season;
+        self
+    }
+
+    /// Show a LIVE marker after the path, pulsing with the animation frame
+    pub fn with_live_indicator(mut self, animation_frame: u8) -> Self {
+        self.live_frame = Some(animation_frame);
+        self
+    }
+
+    /// Build breadcrumb text from tab and document stack, fitted to `width` columns
+    ///
+    /// Each span carries the stack depth its crumb returns to: 0 for the tab,
+    /// 1 for the first document, and so on. Separators, the ellipsis and the
+    /// LIVE marker have none.
+    fn build_breadcrumb_text(
+        &self,
+        config: &DisplayConfig,
+        width: usize,
+    ) -> Vec<(Span<'_>, Option<usize>)> {
+        let mut crumbs = Vec::new();
+        let mut tail = Vec::new();
 
         // Get styles from theme
         let (text_style, separator_style) = if let Some(theme) = &config.theme {
@@ -55,23 +109,107 @@ impl BreadcrumbWidget {
             Tab::Demo => "Demo",
         };
 
-        spans.push(Span::styled(tab_name.to_string(), text_style));
+        let dash = if config.use_unicode { "—" } else { "-" };
+        let mut tab_text = tab_name.to_string();
+        match self.current_tab {
+            Tab::Scores => {
+                if let Some(date) = &self.scores_date {
+                    let today = Local::now().date_naive();
+                    tab_text = format!("{} {} {}", tab_text, dash, date_label(date, today));
+                }
+                if let Some(game_type) = self.season.game_type {
+                    tab_text = format!("{} ({})", tab_text, game_type);
+                }
+            }
+            Tab::Standings => {
+                if let Some(season) = self.season.season {
+                    tab_text = format!("{} {} {}", tab_text, dash, season_label(season));
+                }
+            }
+            _ => {}
+        }
+        crumbs.push((Span::styled(tab_text, text_style), Some(0)));
 
         // Add each document in the stack
-        for doc_entry in &self.document_stack {
-            spans.push(Span::styled(separator.clone(), separator_style));
-
-            let doc_text = doc_entry.document.label();
+        for (i, doc_entry) in self.document_stack.iter().enumerate() {
+            crumbs.push((
+                Span::styled(doc_entry.document.label(), text_style),
+                Some(i + 1),
+            ));
+        }
 
-            spans.push(Span::styled(doc_text, text_style));
+        if let Some(frame) = self.live_frame {
+            // Bright for half of the animation cycle, dim for the other half
+            let pulse = if frame % 4 < 2 {
+                Modifier::BOLD
+            } else {
+                Modifier::DIM
+            };
+            let dot = if config.use_unicode { "●" } else { "*" };
+            tail.push((Span::raw("  "), None));
+            tail.push((
+                Span::styled(
+                    format!("{} LIVE", dot),
+                    config.emphasis_style().add_modifier(pulse),
+                ),
+                None,
+            ));
         }
 
+        let tail_width: usize = tail.iter().map(|(span, _)| span.width()).sum();
+        let ellipsis = if config.use_unicode { "…" } else { "..." };
+        collapse_middle_crumbs(
+            &mut crumbs,
+            (Span::styled(ellipsis, separator_style), None),
+            separator.width(),
+            width.saturating_sub(tail_width),
+        );
+
+        let mut spans = Vec::new();
+        for (i, crumb) in crumbs.into_iter().enumerate() {
+            if i > 0 {
+                spans.push((Span::styled(separator.clone(), separator_style), None));
+            }
+            spans.push(crumb);
+        }
+        spans.extend(tail);
         spans
     }
 }
 
-/// Box drawing character for horizontal divider
-const HORIZONTAL_LINE: char = '─';
+/// Replace the oldest crumbs between the first and the last with `ellipsis`
+/// until the trail fits in `width` columns
+///
+/// The first (tab) and last (current document) crumbs are always kept; if they
+/// alone don't fit, the line is clipped when rendered.
+fn collapse_middle_crumbs<'a>(
+    crumbs: &mut Vec<(Span<'a>, Option<usize>)>,
+    ellipsis: (Span<'a>, Option<usize>),
+    separator_width: usize,
+    width: usize,
+) {
+    let trail_width = |crumbs: &[(Span, Option<usize>)]| {
+        crumbs.iter().map(|(span, _)| span.width()).sum::<usize>()
+            + separator_width * crumbs.len().saturating_sub(1)
+    };
+
+    if crumbs.len() < 3 || trail_width(crumbs) <= width {
+        return;
+    }
+    crumbs[1] = ellipsis;
+    while crumbs.len() > 3 && trail_width(crumbs) > width {
+        crumbs.remove(2);
+    }
+}
+
+/// Relative label for today, otherwise a short date like "Oct 1"
+fn date_label(date: &GameDate, today: NaiveDate) -> String {
+    match date {
+        GameDate::Now => "Today".to_string(),
+        GameDate::Date(d) if *d == today => "Today".to_string(),
+        GameDate::Date(d) => d.format("%b %-d").to_string(),
+    }
+}
 
 impl ElementWidget for BreadcrumbWidget {
     fn render(&self, area: Rect, buf: &mut Buffer, config: &DisplayConfig) {
@@ -79,11 +217,13 @@ impl ElementWidget for BreadcrumbWidget {
             return;
         }
 
-        let spans = self.build_breadcrumb_text(config);
-        let line = Line::from(spans);
+        let margin = config.breadcrumb_margin.min(area.width);
+        let width = area.width - margin;
+        let spans = self.build_breadcrumb_text(config, width as usize);
+        let line = Line::from(spans.into_iter().map(|(span, _)| span).collect::<Vec<_>>());
 
         // Render the breadcrumb line
-        buf.set_line(area.x, area.y, &line, area.width);
+        buf.set_line(area.x + margin, area.y, &line, width);
 
         // Render the divider line on the second row
         if area.height >= 2 {
@@ -92,8 +232,7 @@ impl ElementWidget for BreadcrumbWidget {
             } else {
                 Style::default()
             };
-            let divider: String =
-                std::iter::repeat_n(HORIZONTAL_LINE, area.width as usize).collect();
+            let divider = config.box_chars.horizontal.repeat(area.width as usize);
             let divider_line = Line::from(Span::styled(divider, divider_style));
             buf.set_line(area.x, area.y + 1, &divider_line, area.width);
         }
@@ -103,6 +242,30 @@ impl ElementWidget for BreadcrumbWidget {
         Box::new(self.clone())
     }
 
+    /// Ancestor crumbs are clickable; the current document's crumb is not
+    fn hit_regions(&self, area: Rect, config: &DisplayConfig) -> Vec<HitRegion> {
+        if area.height == 0 || area.width == 0 {
+            return Vec::new();
+        }
+        let margin = config.breadcrumb_margin.min(area.width);
+        let mut x = area.x + margin;
+        let mut regions = Vec::new();
+        for (span, depth) in self.build_breadcrumb_text(config, (area.width - margin) as usize) {
+            let span_width = span.width() as u16;
+            if let Some(depth) = depth.filter(|depth| *depth < self.document_stack.len()) {
+                let rect = Rect::new(x, area.y, span_width, 1).intersection(area);
+                if !rect.is_empty() {
+                    regions.push(HitRegion {
+                        rect,
+                        target: HitTarget::Crumb(depth),
+                    });
+                }
+            }
+            x = x.saturating_add(span_width);
+        }
+        regions
+    }
+
     fn preferred_height(&self) -> Option<u16> {
         if self.document_stack.is_empty() {
             Some(0) // No breadcrumb if no documents are open
@@ -190,6 +353,150 @@ mod tests {
         );
     }
 
+    fn boxscore_stack() -> Vec<DocumentStackEntry> {
+        vec![DocumentStackEntry::with_selection(
+            StackedDocument::Boxscore {
+                game_id: 2024020001,
+                away_abbrev: "TOR".to_string(),
+                home_abbrev: "BOS".to_string(),
+                away_score: 3,
+                home_score: 2,
+            },
+            None,
+        )]
+    }
+
+    #[test]
+    fn test_breadcrumb_live_indicator() {
+        let config = DisplayConfig::default();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 40, 2));
+
+        BreadcrumbWidget::new(Tab::Scores, boxscore_stack())
+            .with_live_indicator(0)
+            .render(buf.area, &mut buf, &config);
+        assert_buffer(
+            &buf,
+            &[
+                "Scores ▶ TOR:3-BOS:2  ● LIVE",
+                "────────────────────────────────────────",
+            ],
+        );
+        assert!(buf[(22, 0)].modifier.contains(Modifier::BOLD));
+
+        BreadcrumbWidget::new(Tab::Scores, boxscore_stack())
+            .with_live_indicator(2)
+            .render(buf.area, &mut buf, &config);
+        assert!(buf[(22, 0)].modifier.contains(Modifier::DIM));
+    }
+
+    #[test]
+    fn test_breadcrumb_scores_date_today() {
+        let widget = BreadcrumbWidget::new(Tab::Scores, boxscore_stack())
+            .with_scores_date(GameDate::today());
+        let config = DisplayConfig::default();
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 80, 2));
+        widget.render(buf.area, &mut buf, &config);
+
+        assert_buffer(
+            &buf,
+            &[
+                "Scores — Today ▶ TOR:3-BOS:2",
+                "────────────────────────────────────────────────────────────────────────────────",
+            ],
+        );
+    }
+
+    #[test]
+    fn test_breadcrumb_scores_date_other_day() {
+        let widget = BreadcrumbWidget::new(Tab::Scores, boxscore_stack())
+            .with_scores_date(GameDate::from_ymd(2024, 10, 1).unwrap());
+        let config = DisplayConfig::default();
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 80, 2));
+        widget.render(buf.area, &mut buf, &config);
+
+        assert_buffer(
+            &buf,
+            &[
+                "Scores — Oct 1 ▶ TOR:3-BOS:2",
+                "────────────────────────────────────────────────────────────────────────────────",
+            ],
+        );
+    }
+
+    #[test]
+    fn test_breadcrumb_scores_date_ignored_on_other_tabs() {
+        let widget = BreadcrumbWidget::new(Tab::Standings, boxscore_stack())
+            .with_scores_date(GameDate::from_ymd(2024, 10, 1).unwrap());
+        let config = DisplayConfig::default();
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 80, 2));
+        widget.render(buf.area, &mut buf, &config);
+
+        assert_buffer(
+            &buf,
+            &[
+                "Standings ▶ TOR:3-BOS:2",
+                "────────────────────────────────────────────────────────────────────────────────",
+            ],
+        );
+    }
+
+    #[test]
+    fn test_breadcrumb_scores_game_type() {
+        let widget = BreadcrumbWidget::new(Tab::Scores, Vec::new())
+            .with_scores_date(GameDate::from_ymd(2024, 10, 1).unwrap())
+            .with_season(SeasonSelection {
+                season: None,
+                game_type: Some(nhl_api::GameType::Playoffs),
+            });
+        let config = DisplayConfig::default();
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 80, 2));
+        widget.render(buf.area, &mut buf, &config);
+
+        assert_buffer(
+            &buf,
+            &[
+                "Scores — Oct 1 (Playoffs)",
+                "────────────────────────────────────────────────────────────────────────────────",
+            ],
+        );
+    }
+
+    #[test]
+    fn test_breadcrumb_standings_season() {
+        let widget =
+            BreadcrumbWidget::new(Tab::Standings, Vec::new()).with_season(SeasonSelection {
+                season: Some(20222023),
+                game_type: None,
+            });
+        let config = DisplayConfig::default();
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 80, 2));
+        widget.render(buf.area, &mut buf, &config);
+
+        assert_buffer(
+            &buf,
+            &[
+                "Standings — 2022-2023",
+                "────────────────────────────────────────────────────────────────────────────────",
+            ],
+        );
+    }
+
+    #[test]
+    fn test_date_label() {
+        let today = NaiveDate::from_ymd_opt(2024, 11, 20).unwrap();
+        assert_eq!(date_label(&GameDate::Now, today), "Today");
+        assert_eq!(date_label(&GameDate::Date(today), today), "Today");
+        assert_eq!(
+            date_label(&GameDate::from_ymd(2024, 11, 19).unwrap(), today),
+            "Nov 19"
+        );
+    }
+
     #[test]
     fn test_breadcrumb_with_nested_documents() {
         let document_stack = vec![
@@ -228,6 +535,159 @@ mod tests {
         );
     }
 
+    fn deep_stack() -> Vec<DocumentStackEntry> {
+        vec![
+            DocumentStackEntry::with_selection(
+                StackedDocument::TeamDetail {
+                    abbrev: "TOR".to_string(),
+                },
+                None,
+            ),
+            DocumentStackEntry::with_selection(
+                StackedDocument::PlayerDetail {
+                    player_id: 8479318,
+                    sweater_number: Some(34),
+                    last_name: "Matthews".to_string(),
+                },
+                None,
+            ),
+            DocumentStackEntry::with_selection(
+                StackedDocument::Boxscore {
+                    game_id: 2024020001,
+                    away_abbrev: "TOR".to_string(),
+                    home_abbrev: "BOS".to_string(),
+                    away_score: 3,
+                    home_score: 2,
+                },
+                None,
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_breadcrumb_collapses_middle_crumbs_when_narrow() {
+        let config = DisplayConfig::default();
+
+        // "Standings ▶ TOR ▶ #34 Matthews ▶ TOR:3-BOS:2" is 44 columns wide
+        let mut buf = Buffer::empty(Rect::new(0, 0, 44, 2));
+        BreadcrumbWidget::new(Tab::Standings, deep_stack()).render(buf.area, &mut buf, &config);
+        assert_buffer(
+            &buf,
+            &[
+                "Standings ▶ TOR ▶ #34 Matthews ▶ TOR:3-BOS:2",
+                "────────────────────────────────────────────",
+            ],
+        );
+
+        // One column short: the oldest middle crumb goes first
+        let mut buf = Buffer::empty(Rect::new(0, 0, 43, 2));
+        BreadcrumbWidget::new(Tab::Standings, deep_stack()).render(buf.area, &mut buf, &config);
+        assert_buffer(
+            &buf,
+            &[
+                "Standings ▶ … ▶ #34 Matthews ▶ TOR:3-BOS:2",
+                "───────────────────────────────────────────",
+            ],
+        );
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 30, 2));
+        BreadcrumbWidget::new(Tab::Standings, deep_stack()).render(buf.area, &mut buf, &config);
+        assert_buffer(
+            &buf,
+            &[
+                "Standings ▶ … ▶ TOR:3-BOS:2",
+                "──────────────────────────────",
+            ],
+        );
+    }
+
+    #[test]
+    fn test_breadcrumb_collapse_leaves_room_for_live_indicator() {
+        let config = DisplayConfig::default();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 40, 2));
+
+        BreadcrumbWidget::new(Tab::Standings, deep_stack())
+            .with_live_indicator(0)
+            .render(buf.area, &mut buf, &config);
+        assert_buffer(
+            &buf,
+            &[
+                "Standings ▶ … ▶ TOR:3-BOS:2  ● LIVE",
+                "────────────────────────────────────────",
+            ],
+        );
+    }
+
+    #[test]
+    fn test_breadcrumb_hit_regions() {
+        let config = DisplayConfig::default();
+        let widget = BreadcrumbWidget::new(Tab::Standings, deep_stack());
+
+        // "Standings ▶ TOR ▶ #34 Matthews ▶ TOR:3-BOS:2"
+        let regions = widget.hit_regions(Rect::new(0, 5, 80, 2), &config);
+        assert_eq!(
+            regions,
+            vec![
+                HitRegion {
+                    rect: Rect::new(0, 5, 9, 1),
+                    target: HitTarget::Crumb(0),
+                },
+                HitRegion {
+                    rect: Rect::new(12, 5, 3, 1),
+                    target: HitTarget::Crumb(1),
+                },
+                HitRegion {
+                    rect: Rect::new(18, 5, 12, 1),
+                    target: HitTarget::Crumb(2),
+                },
+            ]
+        );
+
+        // "Standings ▶ … ▶ TOR:3-BOS:2": the ellipsis is not a target
+        let regions = widget.hit_regions(Rect::new(0, 0, 30, 2), &config);
+        assert_eq!(
+            regions,
+            vec![HitRegion {
+                rect: Rect::new(0, 0, 9, 1),
+                target: HitTarget::Crumb(0),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_breadcrumb_ascii_divider() {
+        let mut config = DisplayConfig {
+            use_unicode: false,
+            ..DisplayConfig::default()
+        };
+        config.rebuild_box_chars();
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 30, 2));
+        BreadcrumbWidget::new(Tab::Standings, deep_stack()).render(buf.area, &mut buf, &config);
+        assert_buffer(
+            &buf,
+            &[
+                "Standings > ... > TOR:3-BOS:2",
+                "------------------------------",
+            ],
+        );
+    }
+
+    #[test]
+    fn test_breadcrumb_margin() {
+        let config = DisplayConfig {
+            breadcrumb_margin: 2,
+            ..DisplayConfig::default()
+        };
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 24, 2));
+        BreadcrumbWidget::new(Tab::Scores, boxscore_stack()).render(buf.area, &mut buf, &config);
+        assert_buffer(
+            &buf,
+            &["  Scores ▶ TOR:3-BOS:2", "────────────────────────"],
+        );
+    }
+
     #[test]
     fn test_breadcrumb_standings_tab() {
         let widget = BreadcrumbWidget::new(Tab::Standings, Vec::new());
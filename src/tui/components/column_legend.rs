@@ -0,0 +1,108 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::Style,
+    widgets::{Block, Borders, Clear, Widget},
+};
+
+use crate::config::DisplayConfig;
+use crate::tui::component::ElementWidget;
+use crate::tui::{Alignment, CellValue, ColumnDef};
+
+use super::TableWidget;
+
+/// Full names of the focused table's column headers, drawn centered over the current view
+#[derive(Clone)]
+pub struct ColumnLegendWidget {
+    /// Header and description of each described column
+    pub entries: Vec<(String, String)>,
+}
+
+impl ColumnLegendWidget {
+    fn table(&self) -> TableWidget {
+        let columns = [
+            ColumnDef::new("Column", 6, Alignment::Left, |entry: &&(String, String)| {
+                CellValue::Text(entry.0.clone())
+            })
+            .auto_width(),
+            ColumnDef::new("", 0, Alignment::Left, |entry: &&(String, String)| {
+                CellValue::Text(entry.1.clone())
+            })
+            .auto_width(),
+        ];
+        TableWidget::from_data(&columns, self.entries.iter().collect::<Vec<_>>())
+    }
+}
+
+impl ElementWidget for ColumnLegendWidget {
+    fn render(&self, area: Rect, buf: &mut Buffer, config: &DisplayConfig) {
+        let table = self.table();
+        let width = (table.preferred_width().unwrap_or(0) + 3).min(area.width);
+        let height = (table.preferred_height().unwrap_or(0) + 2).min(area.height);
+        let modal = Rect {
+            x: area.x + (area.width - width) / 2,
+            y: area.y + (area.height - height) / 2,
+            width,
+            height,
+        };
+        if modal.width < 3 || modal.height < 3 {
+            return;
+        }
+
+        Clear.render(modal, buf);
+        let border_style = config
+            .theme
+            .as_ref()
+            .map_or_else(Style::default, |theme| Style::default().fg(theme.fg3));
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(border_style)
+            .title(" Legend ")
+            .render(modal, buf);
+
+        let inner = Rect {
+            x: modal.x + 1,
+            y: modal.y + 1,
+            width: modal.width - 2,
+            height: modal.height - 2,
+        };
+        table.render(inner, buf, config);
+    }
+
+    fn clone_box(&self) -> Box<dyn ElementWidget> {
+        Box::new(self.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tui::testing::assert_buffer;
+
+    #[test]
+    fn test_render_column_legend() {
+        let area = Rect::new(0, 0, 30, 7);
+        let mut buf = Buffer::empty(area);
+        let widget = ColumnLegendWidget {
+            entries: vec![
+                ("SOG".to_string(), "Shots on goal".to_string()),
+                ("Blk".to_string(), "Blocked shots".to_string()),
+            ],
+        };
+
+        widget.render(area, &mut buf, &DisplayConfig::default());
+
+        assert_buffer(
+            &buf,
+            &[
+                "  ┌ Legend ────────────────┐  ",
+                "  │  Column                │  ",
+                "  │  ───────────────────── │  ",
+                "  │  SOG     Shots on goal │  ",
+                "  │  Blk     Blocked shots │  ",
+                "  └────────────────────────┘  ",
+                "                              ",
+            ],
+        );
+    }
+}
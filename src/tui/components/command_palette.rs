@@ -0,0 +1,265 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::Style,
+    widgets::{Block, Borders, Clear, Widget},
+};
+
+use crate::config::DisplayConfig;
+use crate::team_abbrev::{abbrev_to_full_name, matching_teams};
+use crate::tui::component::ElementWidget;
+use crate::tui::state::DataState;
+use crate::tui::types::StackedDocument;
+
+/// Most results listed at once
+const MAX_RESULTS: usize = 10;
+
+/// Widest the palette gets, borders included
+const PALETTE_WIDTH: u16 = 50;
+
+/// Something the command palette can open
+#[derive(Debug, Clone, PartialEq)]
+pub enum PaletteResult {
+    Team(&'static str),
+    Player {
+        player_id: i64,
+        name: String,
+        last_name: String,
+        sweater_number: Option<i32>,
+    },
+}
+
+impl PaletteResult {
+    /// Line shown in the result list
+    pub fn label(&self) -> String {
+        match self {
+            Self::Team(abbrev) => {
+                format!(
+                    "{}  {}",
+                    abbrev,
+                    abbrev_to_full_name(abbrev).unwrap_or(abbrev)
+                )
+            }
+            Self::Player {
+                name,
+                sweater_number: Some(number),
+                ..
+            } => format!("#{} {}", number, name),
+            Self::Player { name, .. } => name.clone(),
+        }
+    }
+
+    /// Document pushed when the result is picked
+    pub fn document(&self) -> StackedDocument {
+        match self {
+            Self::Team(abbrev) => StackedDocument::TeamDetail {
+                abbrev: abbrev.to_string(),
+            },
+            Self::Player {
+                player_id,
+                last_name,
+                sweater_number,
+                ..
+            } => StackedDocument::PlayerDetail {
+                player_id: *player_id,
+                sweater_number: *sweater_number,
+                last_name: last_name.clone(),
+            },
+        }
+    }
+}
+
+/// Teams matching the query, then recently viewed players whose name contains it
+///
+/// An empty query lists every recently viewed player.
+pub fn palette_results(query: &str, data: &DataState) -> Vec<PaletteResult> {
+    let mut results: Vec<PaletteResult> = matching_teams(query)
+        .into_iter()
+        .map(PaletteResult::Team)
+        .collect();
+
+    let query = query.trim().to_lowercase();
+    let mut players: Vec<PaletteResult> = data
+        .player_data
+        .values()
+        .map(|player| PaletteResult::Player {
+            player_id: player.player_id,
+            name: format!("{} {}", player.first_name.default, player.last_name.default),
+            last_name: player.last_name.default.clone(),
+            sweater_number: player.sweater_number,
+        })
+        .filter(|result| match result {
+            PaletteResult::Player { name, .. } => name.to_lowercase().contains(&query),
+            PaletteResult::Team(_) => false,
+        })
+        .collect();
+    players.sort_by_key(PaletteResult::label);
+    results.extend(players);
+
+    results.truncate(MAX_RESULTS);
+    results
+}
+
+/// Search overlay drawn over the current tab
+#[derive(Clone)]
+pub struct CommandPaletteWidget {
+    pub query: String,
+    pub results: Vec<String>,
+    pub selected: usize,
+}
+
+impl ElementWidget for CommandPaletteWidget {
+    fn render(&self, area: Rect, buf: &mut Buffer, config: &DisplayConfig) {
+        // Borders, query line and a blank line around at least one result line
+        let rows = self.results.len().max(1) as u16;
+        let palette = Rect {
+            x: area.x + area.width.saturating_sub(PALETTE_WIDTH) / 2,
+            y: area.y + area.height.min(2),
+            width: PALETTE_WIDTH.min(area.width),
+            height: (rows + 4).min(area.height.saturating_sub(2)),
+        };
+        if palette.width < 3 || palette.height < 3 {
+            return;
+        }
+
+        Clear.render(palette, buf);
+        let border_style = config
+            .theme
+            .as_ref()
+            .map_or_else(Style::default, |theme| Style::default().fg(theme.fg3));
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(border_style)
+            .title(" Search ")
+            .render(palette, buf);
+
+        let inner = Rect {
+            x: palette.x + 1,
+            y: palette.y + 1,
+            width: palette.width - 2,
+            height: palette.height - 2,
+        };
+        let text_style = config.text_style();
+        buf.set_stringn(
+            inner.x,
+            inner.y,
+            format!("> {}█", self.query),
+            inner.width as usize,
+            text_style,
+        );
+
+        if self.results.is_empty() {
+            if inner.height > 2 {
+                buf.set_stringn(
+                    inner.x + 3,
+                    inner.y + 2,
+                    "No matches",
+                    inner.width.saturating_sub(3) as usize,
+                    text_style,
+                );
+            }
+            return;
+        }
+
+        for (idx, result) in self.results.iter().enumerate() {
+            let y = inner.y + 2 + idx as u16;
+            if y >= inner.bottom() {
+                break;
+            }
+            if idx == self.selected {
+                let selector = format!(" {} ", config.box_chars.selector);
+                buf.set_stringn(inner.x, y, selector, inner.width as usize, text_style);
+            }
+            buf.set_stringn(
+                inner.x + 3,
+                y,
+                result,
+                inner.width.saturating_sub(3) as usize,
+                text_style,
+            );
+        }
+    }
+
+    fn clone_box(&self) -> Box<dyn ElementWidget> {
+        Box::new(self.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::create_mock_player_landing;
+    use crate::tui::testing::assert_buffer;
+    use std::sync::Arc;
+
+    fn data_with_player(player_id: i64) -> DataState {
+        let mut data = DataState::default();
+        Arc::make_mut(&mut data.player_data)
+            .insert(player_id, create_mock_player_landing(player_id));
+        data
+    }
+
+    #[test]
+    fn test_results_list_teams_then_players() {
+        let data = data_with_player(8478402);
+
+        let results = palette_results("leafs", &data);
+        assert_eq!(results, vec![PaletteResult::Team("TOR")]);
+        assert_eq!(
+            results[0].document().label(),
+            StackedDocument::TeamDetail {
+                abbrev: "TOR".to_string()
+            }
+            .label()
+        );
+
+        let results = palette_results("", &data);
+        assert_eq!(results.len(), 1);
+        assert!(matches!(
+            results[0].document(),
+            StackedDocument::PlayerDetail {
+                player_id: 8478402,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_results_are_capped() {
+        // Every team name contains an "a" or an "e" somewhere
+        assert_eq!(
+            palette_results("e", &DataState::default()).len(),
+            MAX_RESULTS
+        );
+    }
+
+    #[test]
+    fn test_render_palette() {
+        let widget = CommandPaletteWidget {
+            query: "new".to_string(),
+            results: vec![
+                "NJD  New Jersey Devils".to_string(),
+                "NYI  New York Islanders".to_string(),
+            ],
+            selected: 1,
+        };
+        let area = Rect::new(0, 0, 54, 8);
+        let mut buf = Buffer::empty(area);
+
+        widget.render(area, &mut buf, &DisplayConfig::default());
+
+        assert_buffer(
+            &buf,
+            &[
+                "",
+                "",
+                "  ┌ Search ────────────────────────────────────────┐",
+                "  │> new█                                          │",
+                "  │                                                │",
+                "  │   NJD  New Jersey Devils                       │",
+                "  │ ▶ NYI  New York Islanders                      │",
+                "  └────────────────────────────────────────────────┘",
+            ],
+        );
+    }
+}
@@ -25,6 +25,7 @@ use crate::tui::document::{
 };
 use crate::tui::document_nav::{DocumentNavMsg, DocumentNavState};
 use crate::tui::helpers::StandingsSorting;
+use crate::tui::hit_test::HitRegion;
 use crate::tui::tab_component::{handle_common_message, CommonTabMessage, TabMessage};
 use crate::tui::types::StackedDocument;
 use crate::tui::{Alignment, CellValue, ColumnDef};
@@ -115,7 +116,7 @@ pub struct DemoTabProps {
     /// Whether the tab content is focused
     pub content_focused: bool,
     /// Standings data for demonstrating embedded tables
-    pub standings: Arc<Option<Vec<Standing>>>,
+    pub standings: Option<Arc<Vec<Standing>>>,
 }
 
 /// Messages that can be sent to the Demo tab
@@ -166,7 +167,7 @@ impl Component for DemoTab {
     fn init(props: &Self::Props) -> Self::State {
         use crate::tui::document::Document;
         // Build initial state with focusable metadata from the document
-        let standings = props.standings.as_ref().clone();
+        let standings = props.standings.as_deref().cloned();
         let doc = DemoDocument::new(standings);
         crate::tui::document_nav::DocumentNavState {
             focusable_positions: doc.focusable_positions(),
@@ -265,13 +266,13 @@ struct DemoTabWidget {
     content_focused: bool,
     focus_index: Option<usize>,
     scroll_offset: u16,
-    standings: Arc<Option<Vec<Standing>>>,
+    standings: Option<Arc<Vec<Standing>>>,
 }
 
-impl ElementWidget for DemoTabWidget {
-    fn render(&self, area: Rect, buf: &mut Buffer, config: &DisplayConfig) {
-        // Create document view with state from AppState
-        let standings = (*self.standings).clone();
+impl DemoTabWidget {
+    /// Document view with state from AppState applied
+    fn view(&self, area: Rect) -> DocumentView {
+        let standings = self.standings.as_deref().cloned();
         let doc = Arc::new(DemoDocument::new(standings));
         let mut view = DocumentView::new(doc, area.height);
 
@@ -282,8 +283,17 @@ impl ElementWidget for DemoTabWidget {
 
         // Apply scroll offset from AppState
         view.set_scroll_offset(self.scroll_offset);
+        view
+    }
+}
+
+impl ElementWidget for DemoTabWidget {
+    fn render(&self, area: Rect, buf: &mut Buffer, config: &DisplayConfig) {
+        self.view(area).render(area, buf, config);
+    }
 
-        view.render(area, buf, config);
+    fn hit_regions(&self, area: Rect, config: &DisplayConfig) -> Vec<HitRegion> {
+        self.view(area).hit_regions(area, config)
     }
 
     fn clone_box(&self) -> Box<dyn ElementWidget> {
@@ -485,7 +495,7 @@ mod tests {
     fn test_demo_tab_renders() {
         let props = DemoTabProps {
             content_focused: false,
-            standings: Arc::new(None),
+            standings: None,
         };
         let state = crate::tui::document_nav::DocumentNavState::default();
         let demo_tab = DemoTab;
@@ -514,7 +524,7 @@ mod tests {
             content_focused: true,
             focus_index: None,
             scroll_offset: 0,
-            standings: Arc::new(None),
+            standings: None,
         };
 
         let mut buf = Buffer::empty(Rect::new(0, 0, 60, 5));
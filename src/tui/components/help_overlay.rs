@@ -0,0 +1,193 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::Style,
+    widgets::{Block, Borders, Clear, Widget},
+};
+use unicode_width::UnicodeWidthStr;
+
+use crate::config::DisplayConfig;
+use crate::keybindings::KeyMap;
+use crate::tui::component::ElementWidget;
+use crate::tui::keymap::{KeyBinding, KEY_GROUPS};
+use crate::tui::{Alignment, CellValue, ColumnDef};
+
+use super::TableWidget;
+
+/// Key binding reference drawn centered over the current view
+#[derive(Clone)]
+pub struct HelpOverlayWidget {
+    /// Configured keys, listed next to the defaults they stand in for
+    pub keymap: KeyMap,
+}
+
+impl HelpOverlayWidget {
+    /// One two-column table per context, with columns sized to fit every group
+    fn tables(&self) -> Vec<TableWidget> {
+        let bindings = || KEY_GROUPS.iter().flat_map(|group| group.bindings);
+        let key_width = bindings()
+            .map(|binding| binding.label(&self.keymap).width())
+            .chain(KEY_GROUPS.iter().map(|group| group.context.width()))
+            .max()
+            .unwrap_or(0);
+        let description_width = bindings()
+            .map(|binding| binding.description.width())
+            .max()
+            .unwrap_or(0);
+
+        KEY_GROUPS
+            .iter()
+            .map(|group| {
+                let keymap = self.keymap.clone();
+                let columns = [
+                    ColumnDef::new(
+                        group.context,
+                        key_width,
+                        Alignment::Left,
+                        move |binding: &&KeyBinding| CellValue::Text(binding.label(&keymap)),
+                    ),
+                    ColumnDef::new(
+                        "",
+                        description_width,
+                        Alignment::Left,
+                        |binding: &&KeyBinding| CellValue::Text(binding.description.to_string()),
+                    ),
+                ];
+                TableWidget::from_data(&columns, group.bindings.iter().collect::<Vec<_>>())
+            })
+            .collect()
+    }
+}
+
+impl ElementWidget for HelpOverlayWidget {
+    fn render(&self, area: Rect, buf: &mut Buffer, config: &DisplayConfig) {
+        let tables = self.tables();
+        let content_width = tables
+            .iter()
+            .filter_map(|table| table.preferred_width())
+            .max()
+            .unwrap_or(0);
+        // Tables separated by a blank line
+        let content_height = tables
+            .iter()
+            .filter_map(|table| table.preferred_height())
+            .sum::<u16>()
+            + tables.len().saturating_sub(1) as u16;
+
+        let width = (content_width + 3).min(area.width);
+        let height = (content_height + 2).min(area.height);
+        let modal = Rect {
+            x: area.x + (area.width - width) / 2,
+            y: area.y + (area.height - height) / 2,
+            width,
+            height,
+        };
+        if modal.width < 3 || modal.height < 3 {
+            return;
+        }
+
+        Clear.render(modal, buf);
+        let border_style = config
+            .theme
+            .as_ref()
+            .map_or_else(Style::default, |theme| Style::default().fg(theme.fg3));
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(border_style)
+            .title(" Keys ")
+            .render(modal, buf);
+
+        let inner = Rect {
+            x: modal.x + 1,
+            y: modal.y + 1,
+            width: modal.width - 2,
+            height: modal.height - 2,
+        };
+        let mut y = inner.y;
+        for table in &tables {
+            if y >= inner.bottom() {
+                break;
+            }
+            let table_height = table.preferred_height().unwrap_or(0);
+            let table_area = Rect {
+                x: inner.x,
+                y,
+                width: inner.width,
+                height: table_height.min(inner.bottom() - y),
+            };
+            table.render(table_area, buf, config);
+            y += table_height + 1;
+        }
+    }
+
+    fn clone_box(&self) -> Box<dyn ElementWidget> {
+        Box::new(self.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tui::testing::assert_buffer;
+
+    #[test]
+    fn test_render_help_overlay() {
+        let area = Rect::new(0, 0, 40, 12);
+        let mut buf = Buffer::empty(area);
+
+        let widget = HelpOverlayWidget {
+            keymap: KeyMap::default(),
+        };
+        widget.render(area, &mut buf, &DisplayConfig::default());
+
+        // Too short for every group, so the list is cut off at the border and
+        // the first table shows its scroll indicator
+        assert_buffer(
+            &buf,
+            &[
+                "┌ Keys ────────────────────────────────┐",
+                "│  Global                              │",
+                "│  ─────────────────────────────────── │",
+                "│  q Q        Quit                    █│",
+                "│  ?          Show this help          █│",
+                "│  / :        Search teams and players█│",
+                "│  1 2 3      Jump to tab             █│",
+                "│  ← →        Switch tab              █│",
+                "│  ↓          Enter tab content       █│",
+                "│  r          Refresh data            ││",
+                "│  p          Pin or unpin data       ││",
+                "└──────────────────────────────────────┘",
+            ],
+        );
+    }
+
+    #[test]
+    fn test_render_help_overlay_lists_configured_keys() {
+        let area = Rect::new(0, 0, 40, 12);
+        let mut buf = Buffer::empty(area);
+        let entries = [("nav_down".to_string(), "j".to_string())]
+            .into_iter()
+            .collect();
+        let (keymap, _) = KeyMap::from_config(&entries);
+
+        HelpOverlayWidget { keymap }.render(area, &mut buf, &DisplayConfig::default());
+
+        assert_buffer(
+            &buf,
+            &[
+                "┌ Keys ────────────────────────────────┐",
+                "│  Global                              │",
+                "│  ─────────────────────────────────── │",
+                "│  q Q        Quit                    █│",
+                "│  ?          Show this help          █│",
+                "│  / :        Search teams and players█│",
+                "│  1 2 3      Jump to tab             █│",
+                "│  ← →        Switch tab              █│",
+                "│  ↓ j        Enter tab content       █│",
+                "│  r          Refresh data            ││",
+                "│  p          Pin or unpin data       ││",
+                "└──────────────────────────────────────┘",
+            ],
+        );
+    }
+}
@@ -3,8 +3,12 @@
 pub mod app;
 pub mod boxscore_document;
 pub mod breadcrumb;
+pub mod column_legend;
+pub mod command_palette;
 #[cfg(feature = "development")]
 pub mod demo_tab;
+pub mod help_overlay;
+pub mod player_compare_document;
 pub mod player_detail_document;
 pub mod score_boxes_document;
 pub mod scores_tab;
@@ -23,6 +27,7 @@ pub use boxscore_document::{BoxscoreDocument, BoxscoreDocumentProps};
 pub use breadcrumb::BreadcrumbWidget;
 #[cfg(feature = "development")]
 pub use demo_tab::{DemoTab, DemoTabProps};
+pub use player_compare_document::{PlayerCompareDocument, PlayerCompareDocumentProps};
 pub use player_detail_document::{PlayerDetailDocument, PlayerDetailDocumentProps};
 pub use score_boxes_document::ScoreBoxesDocument;
 pub use scores_tab::{ScoresTab, ScoresTabProps};
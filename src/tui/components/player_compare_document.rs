@@ -0,0 +1,332 @@
+use std::sync::Arc;
+
+use ratatui::{buffer::Buffer, layout::Rect};
+
+use nhl_api::{PlayerLanding, PlayerStats, Position};
+
+use super::table::TableWidget;
+use crate::config::DisplayConfig;
+use crate::tui::component::{Component, Element, ElementWidget};
+use crate::tui::document::{
+    Document, DocumentBuilder, DocumentElement, DocumentView, FindState, FocusContext,
+};
+use crate::tui::hit_test::HitRegion;
+use crate::tui::widgets::{LoadingAnimation, StandaloneWidget};
+use crate::tui::{Alignment, CellValue, ColumnDef};
+
+/// Props for PlayerCompareDocument component
+#[derive(Clone)]
+pub struct PlayerCompareDocumentProps {
+    pub player_a: i64,
+    pub player_b: i64,
+    pub player_a_data: Option<PlayerLanding>,
+    pub player_b_data: Option<PlayerLanding>,
+    pub loading: bool,
+    pub selected_index: Option<usize>,
+    pub scroll_offset: u16,
+    pub find: Option<FindState>,
+    pub animation_frame: u8,
+}
+
+/// PlayerCompareDocument component - renders two players' career totals side by side
+pub struct PlayerCompareDocument;
+
+impl Component for PlayerCompareDocument {
+    type Props = PlayerCompareDocumentProps;
+    type State = ();
+    type Message = ();
+
+    fn view(&self, props: &Self::Props, _state: &Self::State) -> Element {
+        Element::Widget(Box::new(PlayerCompareDocumentWidget {
+            content: PlayerCompareDocumentContent::new(
+                props.player_a,
+                props.player_b,
+                props.player_a_data.clone(),
+                props.player_b_data.clone(),
+            ),
+            loading: props.loading,
+            focus_index: props.selected_index,
+            scroll_offset: props.scroll_offset,
+            find: props.find.clone(),
+            animation_frame: props.animation_frame,
+        }))
+    }
+}
+
+/// One compared statistic
+struct CompareRow {
+    stat: &'static str,
+    a: String,
+    b: String,
+}
+
+/// Format a counting stat, treating missing values as zero
+fn count(value: Option<i32>) -> String {
+    value.unwrap_or(0).to_string()
+}
+
+/// Document comparing the regular season career totals of two players
+#[derive(Clone)]
+pub struct PlayerCompareDocumentContent {
+    pub player_a: i64,
+    pub player_b: i64,
+    pub player_a_data: Option<PlayerLanding>,
+    pub player_b_data: Option<PlayerLanding>,
+}
+
+impl PlayerCompareDocumentContent {
+    pub fn new(
+        player_a: i64,
+        player_b: i64,
+        player_a_data: Option<PlayerLanding>,
+        player_b_data: Option<PlayerLanding>,
+    ) -> Self {
+        Self {
+            player_a,
+            player_b,
+            player_a_data,
+            player_b_data,
+        }
+    }
+
+    fn full_name(player: &PlayerLanding) -> String {
+        format!("{} {}", player.first_name.default, player.last_name.default)
+    }
+
+    /// Skater rows, or goalie rows when both players are goalies
+    fn compare_rows(a: &PlayerLanding, b: &PlayerLanding) -> Vec<CompareRow> {
+        let goalies = a.position == Position::Goalie && b.position == Position::Goalie;
+        let stats = |player: &PlayerLanding| {
+            player
+                .career_totals
+                .as_ref()
+                .map(|career| career.regular_season.clone())
+        };
+        let (a, b) = (stats(a), stats(b));
+
+        let row = |stat: &'static str, value: fn(&PlayerStats) -> String| CompareRow {
+            stat,
+            a: a.as_ref().map(value).unwrap_or_else(|| "-".to_string()),
+            b: b.as_ref().map(value).unwrap_or_else(|| "-".to_string()),
+        };
+
+        if goalies {
+            vec![
+                row("GP", |s| count(s.games_played)),
+                row("W", |s| count(s.wins)),
+                row("L", |s| count(s.losses)),
+                row("OTL", |s| count(s.ot_losses)),
+                row("GAA", |s| {
+                    format!("{:.2}", s.goals_against_avg.unwrap_or(0.0))
+                }),
+                row("SV%", |s| format!("{:.3}", s.save_pctg.unwrap_or(0.0))),
+                row("SO", |s| count(s.shutouts)),
+            ]
+        } else {
+            vec![
+                row("GP", |s| count(s.games_played)),
+                row("G", |s| count(s.goals)),
+                row("A", |s| count(s.assists)),
+                row("PTS", |s| count(s.points)),
+                row("+/-", |s| format!("{:+}", s.plus_minus.unwrap_or(0))),
+                row("PIM", |s| count(s.pim)),
+            ]
+        }
+    }
+}
+
+impl Document for PlayerCompareDocumentContent {
+    fn build(&self, focus: &FocusContext) -> Vec<DocumentElement> {
+        let (Some(a), Some(b)) = (&self.player_a_data, &self.player_b_data) else {
+            return DocumentBuilder::new()
+                .text(format!(
+                    "No data available for players {} and {}",
+                    self.player_a, self.player_b
+                ))
+                .build();
+        };
+
+        let (name_a, name_b) = (Self::full_name(a), Self::full_name(b));
+        let columns = vec![
+            ColumnDef::new("Stat", 6, Alignment::Left, |r: &CompareRow| {
+                CellValue::Text(r.stat.to_string())
+            }),
+            ColumnDef::new(&name_a, 12, Alignment::Right, |r: &CompareRow| {
+                CellValue::Text(r.a.clone())
+            })
+            .auto_width(),
+            ColumnDef::new(&name_b, 12, Alignment::Right, |r: &CompareRow| {
+                CellValue::Text(r.b.clone())
+            })
+            .auto_width(),
+        ];
+        let table = TableWidget::from_data(&columns, Self::compare_rows(a, b));
+
+        DocumentBuilder::new()
+            .compact(focus.compact_spacing)
+            .heading(1, format!("{} vs {}", name_a, name_b))
+            .decorative_spacer(1)
+            .heading(2, "CAREER TOTALS - Regular Season")
+            .table("compare_stats", table)
+            .build()
+    }
+
+    fn title(&self) -> String {
+        match (&self.player_a_data, &self.player_b_data) {
+            (Some(a), Some(b)) => format!("{} vs {}", a.last_name.default, b.last_name.default),
+            _ => format!("Players {} vs {}", self.player_a, self.player_b),
+        }
+    }
+
+    fn id(&self) -> String {
+        format!("player_compare_{}_{}", self.player_a, self.player_b)
+    }
+}
+
+/// Widget for rendering the player compare document
+#[derive(Clone)]
+pub struct PlayerCompareDocumentWidget {
+    content: PlayerCompareDocumentContent,
+    loading: bool,
+    focus_index: Option<usize>,
+    scroll_offset: u16,
+    find: Option<FindState>,
+    animation_frame: u8,
+}
+
+impl PlayerCompareDocumentWidget {
+    /// Document view, or None while a player is still loading
+    fn view(&self, area: Rect) -> Option<DocumentView> {
+        // Both players are needed before there is anything to compare
+        if self.loading
+            || self.content.player_a_data.is_none()
+            || self.content.player_b_data.is_none()
+        {
+            return None;
+        }
+
+        let mut view = DocumentView::new(Arc::new(self.content.clone()), area.height);
+        if let Some(idx) = self.focus_index {
+            view.focus_by_index(idx);
+        }
+        view.set_scroll_offset(self.scroll_offset);
+        view.set_find(self.find.clone());
+        Some(view)
+    }
+}
+
+impl ElementWidget for PlayerCompareDocumentWidget {
+    fn render(&self, area: Rect, buf: &mut Buffer, config: &DisplayConfig) {
+        match self.view(area) {
+            Some(mut view) => view.render(area, buf, config),
+            None => LoadingAnimation::new(self.animation_frame).render(area, buf, config),
+        }
+    }
+
+    fn hit_regions(&self, area: Rect, config: &DisplayConfig) -> Vec<HitRegion> {
+        self.view(area)
+            .map(|view| view.hit_regions(area, config))
+            .unwrap_or_default()
+    }
+
+    fn clone_box(&self) -> Box<dyn ElementWidget> {
+        Box::new(self.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::create_mock_player_landing;
+    use crate::tui::testing::assert_buffer;
+    use nhl_api::{CareerTotals, LocalizedString};
+
+    fn skater_stats(games_played: i32, goals: i32, assists: i32, plus_minus: i32) -> PlayerStats {
+        PlayerStats {
+            games_played: Some(games_played),
+            goals: Some(goals),
+            assists: Some(assists),
+            points: Some(goals + assists),
+            plus_minus: Some(plus_minus),
+            pim: Some(10),
+            power_play_goals: None,
+            power_play_points: None,
+            short_handed_goals: None,
+            short_handed_points: None,
+            shots: None,
+            shooting_pctg: None,
+            faceoff_win_pctg: None,
+            avg_toi: None,
+            wins: None,
+            losses: None,
+            ot_losses: None,
+            shutouts: None,
+            goals_against_avg: None,
+            save_pctg: None,
+        }
+    }
+
+    fn player(player_id: i64, first: &str, last: &str, stats: PlayerStats) -> PlayerLanding {
+        let mut player = create_mock_player_landing(player_id);
+        player.first_name = LocalizedString {
+            default: first.to_string(),
+        };
+        player.last_name = LocalizedString {
+            default: last.to_string(),
+        };
+        player.career_totals = Some(CareerTotals {
+            regular_season: stats,
+            playoffs: None,
+        });
+        player
+    }
+
+    #[test]
+    fn test_compare_document_renders_side_by_side() {
+        let doc = PlayerCompareDocumentContent::new(
+            1,
+            2,
+            Some(player(
+                1,
+                "Connor",
+                "McDavid",
+                skater_stats(659, 335, 647, 126),
+            )),
+            Some(player(
+                2,
+                "Sidney",
+                "Crosby",
+                skater_stats(1272, 601, 1042, 198),
+            )),
+        );
+
+        let (buf, _) = doc.render_full(40, &DisplayConfig::default(), &FocusContext::default());
+
+        assert_buffer(
+            &buf,
+            &[
+                "Connor McDavid vs Sidney Crosby",
+                "═══════════════════════════════",
+                "",
+                "CAREER TOTALS - Regular Season",
+                "  Stat    Connor McDavid  Sidney Crosby",
+                "  ─────────────────────────────────────",
+                "  GP                 659           1272",
+                "  G                  335            601",
+                "  A                  647           1042",
+                "  PTS                982           1643",
+                "  +/-               +126           +198",
+                "  PIM                 10             10",
+            ],
+        );
+    }
+
+    #[test]
+    fn test_compare_document_without_data() {
+        let doc = PlayerCompareDocumentContent::new(1, 2, None, None);
+
+        assert_eq!(doc.title(), "Players 1 vs 2");
+        assert_eq!(doc.id(), "player_compare_1_2");
+        assert!(doc.focusable_ids().is_empty());
+    }
+}
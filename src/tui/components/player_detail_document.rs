@@ -1,28 +1,37 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
 use std::sync::Arc;
 
 use ratatui::{buffer::Buffer, layout::Rect};
 
-use nhl_api::{PlayerLanding, Position, SeasonTotal};
+use nhl_api::{GameLog, HomeRoad, PlayerLanding, Position, SeasonTotal};
 
 use super::table::TableWidget;
 use crate::config::DisplayConfig;
-use crate::team_abbrev::common_name_to_abbrev;
+use crate::formatting::format_count;
+use crate::team_abbrev::{abbrev_to_division, common_name_to_abbrev};
 use crate::tui::component::{Component, Element, ElementWidget};
 use crate::tui::document::{
-    Document, DocumentBuilder, DocumentElement, DocumentView, FocusContext,
+    Document, DocumentBuilder, DocumentElement, DocumentView, FindState, FocusContext,
 };
 use crate::tui::helpers::SeasonSorting;
+use crate::tui::hit_test::HitRegion;
 use crate::tui::widgets::{LoadingAnimation, StandaloneWidget};
 use crate::tui::{Alignment, CellValue, ColumnDef};
 
+/// Number of most recent games shown in the game log
+const GAME_LOG_GAMES: usize = 20;
+
 /// Props for PlayerDetailDocument component
 #[derive(Clone)]
 pub struct PlayerDetailDocumentProps {
     pub player_id: i64,
     pub player_data: Option<PlayerLanding>,
+    pub game_log: Option<Vec<GameLog>>,
     pub loading: bool,
     pub selected_index: Option<usize>,
     pub scroll_offset: u16,
+    pub find: Option<FindState>,
     pub animation_frame: u8,
 }
 
@@ -38,14 +47,49 @@ impl Component for PlayerDetailDocument {
         Element::Widget(Box::new(PlayerDetailDocumentWidget {
             player_id: props.player_id,
             player_data: props.player_data.clone(),
+            game_log: props.game_log.clone(),
             loading: props.loading,
             focus_index: props.selected_index,
             scroll_offset: props.scroll_offset,
+            find: props.find.clone(),
             animation_frame: props.animation_frame,
         }))
     }
 }
 
+/// Totals over the games matching one split (home, away, ...)
+struct SplitRow {
+    split: &'static str,
+    games: usize,
+    goals: i32,
+    assists: i32,
+    points: i32,
+    plus_minus: i32,
+}
+
+impl SplitRow {
+    fn new<'a>(split: &'static str, games: impl Iterator<Item = &'a GameLog>) -> Self {
+        games.fold(
+            Self {
+                split,
+                games: 0,
+                goals: 0,
+                assists: 0,
+                points: 0,
+                plus_minus: 0,
+            },
+            |mut row, game| {
+                row.games += 1;
+                row.goals += game.goals;
+                row.assists += game.assists;
+                row.points += game.points;
+                row.plus_minus += game.plus_minus;
+                row
+            },
+        )
+    }
+}
+
 /// Document implementation for player detail content
 ///
 /// This struct implements the Document trait, providing:
@@ -55,6 +99,10 @@ impl Component for PlayerDetailDocument {
 pub struct PlayerDetailDocumentContent {
     pub player_data: Option<PlayerLanding>,
     pub player_id: i64,
+    /// Current season games, most recent first (None until loaded, and for goalies)
+    pub game_log: Option<Vec<GameLog>>,
+    /// Thousands separator for season and career counts; None leaves them ungrouped
+    pub count_separator: Option<char>,
 }
 
 impl PlayerDetailDocumentContent {
@@ -62,9 +110,23 @@ impl PlayerDetailDocumentContent {
         Self {
             player_data,
             player_id,
+            game_log: None,
+            count_separator: None,
         }
     }
 
+    /// Show a game log section with the most recent of these games
+    pub fn with_game_log(mut self, game_log: Option<Vec<GameLog>>) -> Self {
+        self.game_log = game_log;
+        self
+    }
+
+    /// Group thousands in season and career counts with this separator
+    pub fn with_count_separator(mut self, separator: Option<char>) -> Self {
+        self.count_separator = separator;
+        self
+    }
+
     /// Get NHL regular season stats, sorted by season descending
     fn get_nhl_regular_seasons(player: &PlayerLanding) -> Vec<SeasonTotal> {
         let mut season_stats: Vec<SeasonTotal> = player
@@ -86,7 +148,7 @@ impl PlayerDetailDocumentContent {
     }
 
     /// Build skater season columns
-    fn skater_season_columns() -> Vec<ColumnDef<SeasonTotal>> {
+    fn skater_season_columns(separator: Option<char>) -> Vec<ColumnDef<SeasonTotal>> {
         vec![
             ColumnDef::new("Season", 9, Alignment::Left, |s: &SeasonTotal| {
                 let season_str = s.season.to_string();
@@ -104,17 +166,17 @@ impl PlayerDetailDocumentContent {
                 }
                 CellValue::Text(s.team_name.default.clone())
             }),
-            ColumnDef::new("GP", 4, Alignment::Right, |s: &SeasonTotal| {
-                CellValue::Text(s.games_played.to_string())
+            ColumnDef::new("GP", 4, Alignment::Right, move |s: &SeasonTotal| {
+                CellValue::Text(format_count(s.games_played, separator))
             }),
-            ColumnDef::new("G", 3, Alignment::Right, |s: &SeasonTotal| {
-                CellValue::Text(s.goals.unwrap_or(0).to_string())
+            ColumnDef::new("G", 3, Alignment::Right, move |s: &SeasonTotal| {
+                CellValue::Text(format_count(s.goals.unwrap_or(0), separator))
             }),
-            ColumnDef::new("A", 3, Alignment::Right, |s: &SeasonTotal| {
-                CellValue::Text(s.assists.unwrap_or(0).to_string())
+            ColumnDef::new("A", 3, Alignment::Right, move |s: &SeasonTotal| {
+                CellValue::Text(format_count(s.assists.unwrap_or(0), separator))
             }),
-            ColumnDef::new("PTS", 4, Alignment::Right, |s: &SeasonTotal| {
-                CellValue::Text(s.points.unwrap_or(0).to_string())
+            ColumnDef::new("PTS", 4, Alignment::Right, move |s: &SeasonTotal| {
+                CellValue::Text(format_count(s.points.unwrap_or(0), separator))
             }),
             ColumnDef::new("+/-", 4, Alignment::Right, |s: &SeasonTotal| {
                 CellValue::Text(
@@ -123,14 +185,14 @@ impl PlayerDetailDocumentContent {
                         .unwrap_or_else(|| "0".to_string()),
                 )
             }),
-            ColumnDef::new("PIM", 4, Alignment::Right, |s: &SeasonTotal| {
-                CellValue::Text(s.pim.unwrap_or(0).to_string())
+            ColumnDef::new("PIM", 4, Alignment::Right, move |s: &SeasonTotal| {
+                CellValue::Text(format_count(s.pim.unwrap_or(0), separator))
             }),
         ]
     }
 
     /// Build goalie season columns
-    fn goalie_season_columns() -> Vec<ColumnDef<SeasonTotal>> {
+    fn goalie_season_columns(separator: Option<char>) -> Vec<ColumnDef<SeasonTotal>> {
         vec![
             ColumnDef::new("Season", 9, Alignment::Left, |s: &SeasonTotal| {
                 let season_str = s.season.to_string();
@@ -148,39 +210,126 @@ impl PlayerDetailDocumentContent {
                 }
                 CellValue::Text(s.team_name.default.clone())
             }),
-            ColumnDef::new("GP", 4, Alignment::Right, |s: &SeasonTotal| {
-                CellValue::Text(s.games_played.to_string())
+            ColumnDef::new("GP", 4, Alignment::Right, move |s: &SeasonTotal| {
+                CellValue::Text(format_count(s.games_played, separator))
             }),
             // Note: SeasonTotal doesn't include goalie-specific stats (W, L, GAA, SV%)
             // Those would need to come from a different API endpoint
         ]
     }
 
+    /// Build game log columns, with the opponent linking to its team
+    fn game_log_columns() -> Vec<ColumnDef<GameLog>> {
+        vec![
+            ColumnDef::new("Date", 10, Alignment::Left, |g: &GameLog| {
+                CellValue::Text(g.game_date.clone())
+            }),
+            ColumnDef::new("Opp", 6, Alignment::Left, |g: &GameLog| {
+                let prefix = match g.home_road_flag {
+                    HomeRoad::Home => "vs",
+                    HomeRoad::Road => "@",
+                };
+                CellValue::TeamLink {
+                    display: format!("{} {}", prefix, g.opponent_abbrev),
+                    team_abbrev: g.opponent_abbrev.clone(),
+                }
+            }),
+            ColumnDef::new("G", 3, Alignment::Right, |g: &GameLog| {
+                CellValue::Text(g.goals.to_string())
+            }),
+            ColumnDef::new("A", 3, Alignment::Right, |g: &GameLog| {
+                CellValue::Text(g.assists.to_string())
+            }),
+            ColumnDef::new("P", 3, Alignment::Right, |g: &GameLog| {
+                CellValue::Text(g.points.to_string())
+            }),
+            ColumnDef::new("+/-", 4, Alignment::Right, |g: &GameLog| {
+                CellValue::Text(format!("{:+}", g.plus_minus))
+            }),
+            ColumnDef::new("TOI", 6, Alignment::Right, |g: &GameLog| {
+                CellValue::Text(g.toi.clone())
+            }),
+        ]
+    }
+
+    /// Home, away and vs-division totals over the whole game log
+    ///
+    /// Splits without games are left out, as is the division split when the
+    /// teams' divisions are unknown.
+    fn game_log_splits(game_log: &[GameLog], season: Option<i64>) -> Vec<SplitRow> {
+        let division_game = |game: &GameLog| {
+            let division = abbrev_to_division(&game.team_abbrev, season);
+            division.is_some() && division == abbrev_to_division(&game.opponent_abbrev, season)
+        };
+        [
+            SplitRow::new(
+                "Home",
+                game_log
+                    .iter()
+                    .filter(|g| g.home_road_flag == HomeRoad::Home),
+            ),
+            SplitRow::new(
+                "Away",
+                game_log
+                    .iter()
+                    .filter(|g| g.home_road_flag == HomeRoad::Road),
+            ),
+            SplitRow::new("vs Division", game_log.iter().filter(|g| division_game(g))),
+        ]
+        .into_iter()
+        .filter(|row| row.games > 0)
+        .collect()
+    }
+
+    fn split_columns() -> Vec<ColumnDef<SplitRow>> {
+        vec![
+            ColumnDef::new("Split", 12, Alignment::Left, |r: &SplitRow| {
+                CellValue::Text(r.split.to_string())
+            }),
+            ColumnDef::new("GP", 4, Alignment::Right, |r: &SplitRow| {
+                CellValue::Text(r.games.to_string())
+            }),
+            ColumnDef::new("G", 3, Alignment::Right, |r: &SplitRow| {
+                CellValue::Text(r.goals.to_string())
+            }),
+            ColumnDef::new("A", 3, Alignment::Right, |r: &SplitRow| {
+                CellValue::Text(r.assists.to_string())
+            }),
+            ColumnDef::new("P", 3, Alignment::Right, |r: &SplitRow| {
+                CellValue::Text(r.points.to_string())
+            }),
+            ColumnDef::new("+/-", 4, Alignment::Right, |r: &SplitRow| {
+                CellValue::Text(format!("{:+}", r.plus_minus))
+            }),
+        ]
+    }
+
     /// Format career stats as a string
-    fn format_career_stats(player: &PlayerLanding) -> Option<String> {
+    fn format_career_stats(player: &PlayerLanding, separator: Option<char>) -> Option<String> {
         let career = player.career_totals.as_ref()?;
         let rs = &career.regular_season;
+        let count = |value: Option<i32>| format_count(value.unwrap_or(0), separator);
 
         Some(if player.position == Position::Goalie {
             format!(
                 "GP: {} | W: {} | L: {} | OTL: {} | GAA: {:.2} | SV%: {:.3} | SO: {}",
-                rs.games_played.unwrap_or(0),
-                rs.wins.unwrap_or(0),
-                rs.losses.unwrap_or(0),
-                rs.ot_losses.unwrap_or(0),
+                count(rs.games_played),
+                count(rs.wins),
+                count(rs.losses),
+                count(rs.ot_losses),
                 rs.goals_against_avg.unwrap_or(0.0),
                 rs.save_pctg.unwrap_or(0.0),
-                rs.shutouts.unwrap_or(0)
+                count(rs.shutouts)
             )
         } else {
             format!(
                 "GP: {} | G: {} | A: {} | PTS: {} | +/-: {} | PIM: {}",
-                rs.games_played.unwrap_or(0),
-                rs.goals.unwrap_or(0),
-                rs.assists.unwrap_or(0),
-                rs.points.unwrap_or(0),
+                count(rs.games_played),
+                count(rs.goals),
+                count(rs.assists),
+                count(rs.points),
                 rs.plus_minus.unwrap_or(0),
-                rs.pim.unwrap_or(0)
+                count(rs.pim)
             )
         })
     }
@@ -194,7 +343,7 @@ impl Document for PlayerDetailDocumentContent {
                 .build();
         };
 
-        let mut builder = DocumentBuilder::new();
+        let mut builder = DocumentBuilder::new().compact(focus.compact_spacing);
 
         // Player name header
         let full_name = format!("{} {}", player.first_name.default, player.last_name.default);
@@ -229,7 +378,7 @@ impl Document for PlayerDetailDocumentContent {
             height_feet, height_inches, player.weight_in_pounds, player.birth_date
         );
         builder = builder.text(details2);
-        builder = builder.spacer(1);
+        builder = builder.decorative_spacer(1);
 
         // Draft info (if available)
         if let Some(ref draft) = player.draft_details {
@@ -238,23 +387,52 @@ impl Document for PlayerDetailDocumentContent {
                 draft.year, draft.round, draft.pick_in_round, draft.overall_pick, draft.team_abbrev
             );
             builder = builder.text(draft_info);
-            builder = builder.spacer(1);
+            builder = builder.decorative_spacer(1);
         }
 
         // Career totals
-        if let Some(career_stats) = Self::format_career_stats(player) {
+        if let Some(career_stats) = Self::format_career_stats(player, self.count_separator) {
             builder = builder.heading(2, "CAREER TOTALS - Regular Season");
             builder = builder.text(career_stats);
-            builder = builder.spacer(1);
+            builder = builder.decorative_spacer(1);
+        }
+
+        // Recent games
+        if let Some(ref game_log) = self.game_log {
+            if game_log.is_empty() {
+                builder = builder
+                    .element(DocumentElement::section_title("GAME LOG", true))
+                    .text("No games played this season")
+                    .decorative_spacer(1);
+            } else {
+                let games: Vec<GameLog> = game_log.iter().take(GAME_LOG_GAMES).cloned().collect();
+                let title = format!("GAME LOG (last {} games)", games.len());
+                let table = TableWidget::from_data(&Self::game_log_columns(), games)
+                    .with_focused_row(focus.focused_table_row("game_log"));
+                builder = builder
+                    .element(DocumentElement::section_title(title, true))
+                    .table("game_log", table)
+                    .decorative_spacer(1);
+            }
+
+            let season = player.featured_stats.as_ref().map(|f| i64::from(f.season));
+            let splits = Self::game_log_splits(game_log, season);
+            if !splits.is_empty() {
+                let table = TableWidget::from_data(&Self::split_columns(), splits);
+                builder = builder
+                    .element(DocumentElement::section_title("SPLITS", true))
+                    .table("splits", table)
+                    .decorative_spacer(1);
+            }
         }
 
         // Season-by-season table
         let seasons = Self::get_nhl_regular_seasons(player);
         if !seasons.is_empty() {
             let columns = if player.position == Position::Goalie {
-                Self::goalie_season_columns()
+                Self::goalie_season_columns(self.count_separator)
             } else {
-                Self::skater_season_columns()
+                Self::skater_season_columns(self.count_separator)
             };
 
             let focused_row = focus.focused_table_row("season_stats");
@@ -281,6 +459,15 @@ impl Document for PlayerDetailDocumentContent {
     fn id(&self) -> String {
         format!("player_detail_{}", self.player_id)
     }
+
+    /// Changes when the player or their game log arrives
+    fn content_version(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.player_data.is_some().hash(&mut hasher);
+        self.game_log.as_ref().map(Vec::len).hash(&mut hasher);
+        self.count_separator.hash(&mut hasher);
+        hasher.finish()
+    }
 }
 
 /// Widget for rendering the player detail document
@@ -291,33 +478,51 @@ impl Document for PlayerDetailDocumentContent {
 pub struct PlayerDetailDocumentWidget {
     player_id: i64,
     player_data: Option<PlayerLanding>,
+    game_log: Option<Vec<GameLog>>,
     loading: bool,
     focus_index: Option<usize>,
     scroll_offset: u16,
+    find: Option<FindState>,
     animation_frame: u8,
 }
 
-impl ElementWidget for PlayerDetailDocumentWidget {
-    fn render(&self, area: Rect, buf: &mut Buffer, config: &DisplayConfig) {
-        // Handle loading state - show animation if loading or data hasn't arrived yet
+impl PlayerDetailDocumentWidget {
+    /// Document view, or None while loading or before data has arrived
+    fn view(&self, area: Rect, config: &DisplayConfig) -> Option<DocumentView> {
         if self.loading || self.player_data.is_none() {
-            LoadingAnimation::new(self.animation_frame).render(area, buf, config);
-            return;
+            return None;
         }
 
         // Create document
-        let doc = Arc::new(PlayerDetailDocumentContent::new(
-            self.player_data.clone(),
-            self.player_id,
-        ));
+        let doc = Arc::new(
+            PlayerDetailDocumentContent::new(self.player_data.clone(), self.player_id)
+                .with_game_log(self.game_log.clone())
+                .with_count_separator(config.count_separator()),
+        );
 
-        // Create DocumentView and render
         let mut view = DocumentView::new(doc, area.height);
         if let Some(idx) = self.focus_index {
             view.focus_by_index(idx);
         }
         view.set_scroll_offset(self.scroll_offset);
-        view.render(area, buf, config);
+        view.set_find(self.find.clone());
+        Some(view)
+    }
+}
+
+impl ElementWidget for PlayerDetailDocumentWidget {
+    fn render(&self, area: Rect, buf: &mut Buffer, config: &DisplayConfig) {
+        // Handle loading state - show animation if loading or data hasn't arrived yet
+        match self.view(area, config) {
+            Some(mut view) => view.render(area, buf, config),
+            None => LoadingAnimation::new(self.animation_frame).render(area, buf, config),
+        }
+    }
+
+    fn hit_regions(&self, area: Rect, config: &DisplayConfig) -> Vec<HitRegion> {
+        self.view(area, config)
+            .map(|view| view.hit_regions(area, config))
+            .unwrap_or_default()
     }
 
     fn clone_box(&self) -> Box<dyn ElementWidget> {
@@ -328,7 +533,8 @@ impl ElementWidget for PlayerDetailDocumentWidget {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::tui::document::FocusableId;
+    use crate::fixtures::create_mock_player_game_log;
+    use crate::tui::document::{FocusableId, LinkTarget};
     use nhl_api::{Handedness, LocalizedString, SeasonTotal};
     use ratatui::buffer::Buffer;
 
@@ -408,6 +614,44 @@ mod tests {
 
     // === Document trait tests ===
 
+    #[test]
+    fn test_career_stats_group_thousands() {
+        let mut player = create_test_player(8471214, Position::LeftWing);
+        player.career_totals = Some(
+            serde_json::from_str(
+                r#"{"regularSeason": {"gamesPlayed": 1487, "goals": 894, "assists": 759,
+                    "points": 1653, "plusMinus": -12, "pim": 1034}}"#,
+            )
+            .unwrap(),
+        );
+
+        assert_eq!(
+            PlayerDetailDocumentContent::format_career_stats(&player, Some(',')).unwrap(),
+            "GP: 1,487 | G: 894 | A: 759 | PTS: 1,653 | +/-: -12 | PIM: 1,034"
+        );
+        assert_eq!(
+            PlayerDetailDocumentContent::format_career_stats(&player, None).unwrap(),
+            "GP: 1487 | G: 894 | A: 759 | PTS: 1653 | +/-: -12 | PIM: 1034"
+        );
+    }
+
+    #[test]
+    fn test_compact_spacing_uses_fewer_rows() {
+        let player = create_test_player(8479318, Position::Center);
+        let doc = PlayerDetailDocumentContent::new(Some(player), 8479318);
+        let height = |focus: FocusContext| -> u16 {
+            doc.build(&focus)
+                .iter()
+                .map(|element| element.height())
+                .sum()
+        };
+
+        let default = height(FocusContext::default());
+        let compact = height(FocusContext::default().with_compact_spacing(true));
+
+        assert!(compact < default, "{} >= {}", compact, default);
+    }
+
     #[test]
     fn test_document_build_with_player_data() {
         let player = create_test_player(8479318, Position::Center);
@@ -529,9 +773,11 @@ mod tests {
         let widget = PlayerDetailDocumentWidget {
             player_id: 8479318,
             player_data: Some(player),
+            game_log: None,
             loading: false,
             focus_index: None,
             scroll_offset: 0,
+            find: None,
             animation_frame: 0,
         };
 
@@ -550,9 +796,11 @@ mod tests {
         let widget = PlayerDetailDocumentWidget {
             player_id: 8479318,
             player_data: None,
+            game_log: None,
             loading: true,
             focus_index: None,
             scroll_offset: 0,
+            find: None,
             animation_frame: 0,
         };
 
@@ -570,9 +818,11 @@ mod tests {
         let widget = PlayerDetailDocumentWidget {
             player_id: 8479318,
             player_data: None,
+            game_log: None,
             loading: false,
             focus_index: None,
             scroll_offset: 0,
+            find: None,
             animation_frame: 0,
         };
 
@@ -592,9 +842,11 @@ mod tests {
         let widget = PlayerDetailDocumentWidget {
             player_id: 8479318,
             player_data: Some(player),
+            game_log: None,
             loading: false,
             focus_index: Some(0), // Focus on first focusable element
             scroll_offset: 0,
+            find: None,
             animation_frame: 0,
         };
 
@@ -615,9 +867,11 @@ mod tests {
         let widget = PlayerDetailDocumentWidget {
             player_id: 8479318,
             player_data: Some(player),
+            game_log: None,
             loading: false,
             focus_index: None,
             scroll_offset: 5, // Scroll down 5 lines
+            find: None,
             animation_frame: 0,
         };
 
@@ -641,8 +895,8 @@ mod tests {
         let skater = create_test_player(8479318, Position::Center);
         let doc_skater = PlayerDetailDocumentContent::new(Some(skater), 8479318);
 
-        let goalie_cols = PlayerDetailDocumentContent::goalie_season_columns();
-        let skater_cols = PlayerDetailDocumentContent::skater_season_columns();
+        let goalie_cols = PlayerDetailDocumentContent::goalie_season_columns(None);
+        let skater_cols = PlayerDetailDocumentContent::skater_season_columns(None);
 
         // Goalie columns should have fewer columns (no G, A, PTS, +/-, PIM)
         assert!(goalie_cols.len() < skater_cols.len());
@@ -654,4 +908,149 @@ mod tests {
         assert!(!goalie_elements.is_empty());
         assert!(!skater_elements.is_empty());
     }
+
+    fn rendered_lines(doc: &PlayerDetailDocumentContent) -> Vec<String> {
+        let (buf, _) = doc.render_full(50, &DisplayConfig::default(), &FocusContext::default());
+        (0..buf.area.height)
+            .map(|y| {
+                (0..buf.area.width)
+                    .map(|x| buf[(x, y)].symbol())
+                    .collect::<String>()
+                    .trim_end()
+                    .to_string()
+            })
+            .collect()
+    }
+
+    fn game_log_lines(doc: &PlayerDetailDocumentContent) -> Vec<String> {
+        rendered_lines(doc)
+            .into_iter()
+            .skip_while(|line| !line.contains("GAME LOG"))
+            .take_while(|line| !line.contains("SPLITS") && !line.contains("SEASON BY SEASON"))
+            .collect()
+    }
+
+    #[test]
+    fn test_game_log_section() {
+        let log = create_mock_player_game_log(8479318, 20242025, nhl_api::GameType::RegularSeason);
+        let doc = PlayerDetailDocumentContent::new(
+            Some(create_test_player(8479318, Position::Center)),
+            8479318,
+        )
+        .with_game_log(Some(log.game_log));
+
+        let lines = game_log_lines(&doc);
+
+        assert_eq!(
+            lines,
+            [
+                "GAME LOG (last 5 games)",
+                "═══════════════════════",
+                "",
+                "  Date        Opp       G    A    P   +/-     TOI",
+                "  ───────────────────────────────────────────────",
+                "  2024-11-20  vs TOR    0    0    0    -1   21:34",
+                "  2024-11-18  @ MTL     1    1    2    +0   21:34",
+                "  2024-11-16  vs BOS    0    2    2    -1   21:34",
+                "  2024-11-14  @ OTT     1    0    1    +0   21:34",
+                "  2024-11-12  vs NJD    0    1    1    -1   21:34",
+                "",
+            ]
+        );
+        assert!(doc
+            .focusable_link_targets()
+            .contains(&Some(LinkTarget::Action("team:MTL".to_string()))));
+    }
+
+    #[test]
+    fn test_game_log_limited_to_recent_games() {
+        let game = create_mock_player_game_log(1, 20242025, nhl_api::GameType::RegularSeason)
+            .game_log
+            .remove(0);
+        let doc =
+            PlayerDetailDocumentContent::new(Some(create_test_player(1, Position::Center)), 1)
+                .with_game_log(Some(vec![game; 30]));
+
+        let lines = game_log_lines(&doc);
+
+        assert!(lines[0].contains("GAME LOG (last 20 games)"));
+        assert_eq!(
+            lines.iter().filter(|line| line.contains("vs TOR")).count(),
+            GAME_LOG_GAMES
+        );
+    }
+
+    #[test]
+    fn test_game_log_without_games() {
+        let doc =
+            PlayerDetailDocumentContent::new(Some(create_test_player(1, Position::Center)), 1)
+                .with_game_log(Some(Vec::new()));
+
+        let lines = game_log_lines(&doc);
+
+        assert_eq!(
+            lines,
+            [
+                "GAME LOG",
+                "════════",
+                "",
+                "No games played this season",
+                ""
+            ]
+        );
+    }
+
+    #[test]
+    fn test_splits_section() {
+        let log = create_mock_player_game_log(8479318, 20242025, nhl_api::GameType::RegularSeason);
+        let doc = PlayerDetailDocumentContent::new(
+            Some(create_test_player(8479318, Position::Center)),
+            8479318,
+        )
+        .with_game_log(Some(log.game_log));
+
+        let lines: Vec<String> = rendered_lines(&doc)
+            .into_iter()
+            .skip_while(|line| line != "SPLITS")
+            .take(8)
+            .collect();
+
+        // EDM plays none of its mock opponents in the Pacific
+        assert_eq!(
+            lines,
+            [
+                "SPLITS",
+                "══════",
+                "",
+                "  Split           GP    G    A    P   +/-",
+                "  ───────────────────────────────────────",
+                "  Home             3    0    3    3    -3",
+                "  Away             2    2    1    3    +0",
+                "",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_division_split() {
+        let mut log =
+            create_mock_player_game_log(1, 20242025, nhl_api::GameType::RegularSeason).game_log;
+        log[0].opponent_abbrev = "VAN".to_string();
+
+        let splits = PlayerDetailDocumentContent::game_log_splits(&log, Some(20242025));
+
+        let names: Vec<_> = splits.iter().map(|row| row.split).collect();
+        assert_eq!(names, ["Home", "Away", "vs Division"]);
+        assert_eq!(splits[2].games, 1);
+    }
+
+    #[test]
+    fn test_splits_omitted_without_games() {
+        let doc =
+            PlayerDetailDocumentContent::new(Some(create_test_player(1, Position::Center)), 1)
+                .with_game_log(Some(Vec::new()));
+
+        assert!(PlayerDetailDocumentContent::game_log_splits(&[], None).is_empty());
+        assert!(!rendered_lines(&doc).iter().any(|line| line == "SPLITS"));
+    }
 }
@@ -1,24 +1,73 @@
 //! Document implementation for compact score boxes grid view
 //!
 //! This module provides a Document implementation that displays games in a
-//! Row-based grid layout using compact ScoreBox widgets.
+//! Row-based grid layout using compact ScoreBox widgets, or one line per game
+//! in the compact scores layout.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
-use nhl_api::{DailySchedule, GameDate, GameMatchup};
+use nhl_api::{DailySchedule, GameDate, GameMatchup, GameType};
 
 use crate::commands::scores_format::format_period_text;
+use crate::config::{LoadingStyle, DEFAULT_START_TIME_FORMAT};
 use crate::layout_constants::SCORE_BOX_WIDTH;
+use crate::team_abbrev::display_team_name;
+use crate::timezone::{format_start_time, LOCAL_TIMEZONE};
 use crate::tui::document::{Document, DocumentBuilder, DocumentElement, FocusContext, FocusableId};
 use crate::tui::widgets::{loading_animation::loading_animation_text, ScoreBox, ScoreBoxStatus};
 
 /// Gap between score boxes in characters
 const SCORE_BOX_GAP: u16 = 8;
 
+/// Most goal lines under a score box; past it the last line counts the rest
+const MAX_SCORER_LINES: usize = 5;
+
 /// Total width of a score box with gap
 const SCORE_BOX_WITH_GAP: u16 = SCORE_BOX_WIDTH + SCORE_BOX_GAP;
 
+/// Which games the scores tab shows, by game state
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum GameStateFilter {
+    #[default]
+    All,
+    Live,
+    Final,
+    Upcoming,
+}
+
+impl GameStateFilter {
+    /// The filter after this one: All → Live → Final → Upcoming → All
+    pub fn next(self) -> Self {
+        match self {
+            Self::All => Self::Live,
+            Self::Live => Self::Final,
+            Self::Final => Self::Upcoming,
+            Self::Upcoming => Self::All,
+        }
+    }
+
+    /// Name shown in the header, e.g. "live"
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::All => "all",
+            Self::Live => "live",
+            Self::Final => "final",
+            Self::Upcoming => "upcoming",
+        }
+    }
+
+    /// Whether a game in `state` passes the filter
+    pub fn matches(self, state: nhl_api::GameState) -> bool {
+        match self {
+            Self::All => true,
+            Self::Live => state.is_live(),
+            Self::Final => state.is_final(),
+            Self::Upcoming => state.is_scheduled(),
+        }
+    }
+}
+
 /// Document that displays games in a grid layout using ScoreBox widgets
 pub struct ScoreBoxesDocument {
     pub schedule: Arc<Option<DailySchedule>>,
@@ -26,6 +75,28 @@ pub struct ScoreBoxesDocument {
     pub boxes_per_row: u16,
     pub game_date: GameDate,
     pub animation_frame: u8,
+    /// When set, only games involving one of these team abbreviations are shown
+    pub favorites: Option<Vec<String>>,
+    /// Only games in this state are shown
+    pub state_filter: GameStateFilter,
+    /// When set, only games of this type are shown
+    pub game_type: Option<GameType>,
+    /// Games whose score just changed
+    pub highlighted: HashSet<i64>,
+    /// Teams whose lines get the favorite accent, whether or not games are filtered
+    pub accented_teams: Vec<String>,
+    /// One line per game instead of the grid of boxes
+    pub compact: bool,
+    /// Drop the blank lines between rows of games (`display.compact_spacing`)
+    pub compact_spacing: bool,
+    pub loading_style: LoadingStyle,
+    pub loading_frame_divisor: u8,
+    /// Why the schedule couldn't be loaded, shown instead of the loading animation
+    pub load_error: Option<String>,
+    /// Zone start times are shown in (`display.timezone`)
+    pub timezone: String,
+    /// Format for start times (`Config.start_time_format`)
+    pub start_time_format: String,
 }
 
 impl ScoreBoxesDocument {
@@ -42,9 +113,100 @@ impl ScoreBoxesDocument {
             boxes_per_row,
             game_date,
             animation_frame,
+            favorites: None,
+            state_filter: GameStateFilter::default(),
+            game_type: None,
+            highlighted: HashSet::new(),
+            accented_teams: Vec::new(),
+            compact: false,
+            compact_spacing: false,
+            loading_style: LoadingStyle::default(),
+            loading_frame_divisor: 1,
+            load_error: None,
+            timezone: LOCAL_TIMEZONE.to_string(),
+            start_time_format: DEFAULT_START_TIME_FORMAT.to_string(),
         }
     }
 
+    /// Only show games involving one of `favorites` (None shows every game)
+    pub fn with_favorites(mut self, favorites: Option<Vec<String>>) -> Self {
+        self.favorites = favorites;
+        self
+    }
+
+    /// Only show games whose state passes `filter`
+    pub fn with_state_filter(mut self, filter: GameStateFilter) -> Self {
+        self.state_filter = filter;
+        self
+    }
+
+    /// Only show games of `game_type` (None shows every game)
+    pub fn with_game_type(mut self, game_type: Option<GameType>) -> Self {
+        self.game_type = game_type;
+        self
+    }
+
+    /// Highlight the score boxes of these games
+    pub fn with_highlighted(mut self, highlighted: HashSet<i64>) -> Self {
+        self.highlighted = highlighted;
+        self
+    }
+
+    /// Accent the lines of these teams in their score boxes
+    pub fn with_accented_teams(mut self, teams: Vec<String>) -> Self {
+        self.accented_teams = teams;
+        self
+    }
+
+    /// Draw one line per game instead of the grid of boxes
+    pub fn with_compact(mut self, compact: bool) -> Self {
+        self.compact = compact;
+        self
+    }
+
+    /// Shrink the blank lines between rows of games
+    pub fn with_compact_spacing(mut self, compact_spacing: bool) -> Self {
+        self.compact_spacing = compact_spacing;
+        self
+    }
+
+    /// Animation shown until the schedule arrives, holding each frame for `divisor` ticks
+    pub fn with_loading_animation(mut self, loading_style: LoadingStyle, divisor: u8) -> Self {
+        self.loading_style = loading_style;
+        self.loading_frame_divisor = divisor;
+        self
+    }
+
+    /// Set the zone start times are shown in
+    pub fn with_timezone(mut self, timezone: impl Into<String>) -> Self {
+        self.timezone = timezone.into();
+        self
+    }
+
+    /// Set the format start times are shown with
+    pub fn with_start_time_format(mut self, start_time_format: impl Into<String>) -> Self {
+        self.start_time_format = start_time_format.into();
+        self
+    }
+
+    /// Show `error` instead of the loading animation while there is no schedule
+    pub fn with_load_error(mut self, error: Option<String>) -> Self {
+        self.load_error = error;
+        self
+    }
+
+    /// Whether a game passes the favorites, game state and game type filters
+    fn is_shown(&self, game: &nhl_api::ScheduleGame) -> bool {
+        self.state_filter.matches(game.game_state)
+            && self
+                .game_type
+                .is_none_or(|game_type| game.game_type == game_type)
+            && self.favorites.as_ref().is_none_or(|favorites| {
+                favorites.contains(&game.away_team.abbrev)
+                    || favorites.contains(&game.home_team.abbrev)
+            })
+    }
+
     /// Calculate how many score boxes fit in the given width
     pub fn boxes_per_row_for_width(width: u16) -> u16 {
         if width < SCORE_BOX_WIDTH {
@@ -56,18 +218,57 @@ impl ScoreBoxesDocument {
         }
     }
 
-    /// Get team display name from game_info or fallback to abbreviation
+    /// Get team display name, falling back to game_info's name for unknown abbreviations
     fn get_team_name(&self, game_id: i64, is_away: bool, abbrev: &str) -> String {
-        if let Some(info) = self.game_info.get(&game_id) {
+        let fallback = self.game_info.get(&game_id).map(|info| {
             let team = if is_away {
                 &info.away_team
             } else {
                 &info.home_team
             };
-            team.common_name.default.clone()
-        } else {
-            abbrev.to_string()
+            team.common_name.default.as_str()
+        });
+        display_team_name(abbrev, fallback)
+    }
+
+    /// One line per goal, e.g. "P2 12:34 TOR Matthews", from the game's scoring summary
+    fn scorer_lines(&self, game_id: i64) -> Vec<String> {
+        let Some(summary) = self
+            .game_info
+            .get(&game_id)
+            .and_then(|info| info.summary.as_ref())
+        else {
+            return Vec::new();
+        };
+
+        let mut lines: Vec<String> = summary
+            .scoring
+            .iter()
+            .flat_map(|period| {
+                let descriptor = &period.period_descriptor;
+                let label = match descriptor.period_type {
+                    nhl_api::PeriodType::Regulation => format!("P{}", descriptor.number),
+                    nhl_api::PeriodType::Overtime => "OT".to_string(),
+                    nhl_api::PeriodType::Shootout => "SO".to_string(),
+                };
+                period.goals.iter().map(move |goal| {
+                    format!(
+                        "{} {} {} {}",
+                        label,
+                        goal.time_in_period,
+                        goal.team_abbrev.default,
+                        goal.last_name.default
+                    )
+                })
+            })
+            .collect();
+
+        if lines.len() > MAX_SCORER_LINES {
+            let hidden = lines.len() - (MAX_SCORER_LINES - 1);
+            lines.truncate(MAX_SCORER_LINES - 1);
+            lines.push(format!("+{} more", hidden));
         }
+        lines
     }
 
     /// Create a ScoreBox widget for a given game
@@ -85,16 +286,14 @@ impl ScoreBoxesDocument {
 
         // Determine game status
         let status = if game.game_state.is_final() {
-            // Check for OT/SO from game_info
-            let (overtime, shootout) = if let Some(info) = self.game_info.get(&game.id) {
-                let is_ot = info.period_descriptor.number > 3
-                    || info.period_descriptor.period_type == nhl_api::PeriodType::Overtime;
-                let is_so = info.period_descriptor.period_type == nhl_api::PeriodType::Shootout;
-                (is_ot && !is_so, is_so)
-            } else {
-                (false, false)
-            };
-            ScoreBoxStatus::Final { overtime, shootout }
+            // OT/SO is only known from game_info
+            match self.game_info.get(&game.id) {
+                Some(info) => ScoreBoxStatus::final_for(&info.period_descriptor),
+                None => ScoreBoxStatus::Final {
+                    overtime: false,
+                    shootout: false,
+                },
+            }
         } else if game.game_state.has_started() {
             // Get period text and time from game_info
             if let Some(info) = self.game_info.get(&game.id) {
@@ -121,20 +320,23 @@ impl ScoreBoxesDocument {
             }
         } else {
             // Scheduled game - format start time
-            let start_time =
-                if let Ok(parsed) = chrono::DateTime::parse_from_rfc3339(&game.start_time_utc) {
-                    let local_time: chrono::DateTime<chrono::Local> = parsed.into();
-                    // Use compact format like "7PM" or "10PM"
-                    let hour = local_time.format("%l").to_string().trim().to_string();
-                    let ampm = local_time.format("%p").to_string();
-                    format!("{}{}", hour, ampm)
-                } else {
-                    game.start_time_utc.clone()
-                };
+            let start_time = format_start_time(
+                &game.start_time_utc,
+                &self.timezone,
+                &self.start_time_format,
+            )
+            .unwrap_or_else(|| game.start_time_utc.clone());
             ScoreBoxStatus::Scheduled { start_time }
         };
 
         ScoreBox::new(away_team, home_team, away_score, home_score, status)
+            .with_highlighted(self.highlighted.contains(&game.id))
+            .with_accented(
+                self.accented_teams.contains(&game.away_team.abbrev),
+                self.accented_teams.contains(&game.home_team.abbrev),
+            )
+            .with_compact(self.compact)
+            .with_scorers(self.scorer_lines(game.id))
     }
 }
 
@@ -142,42 +344,90 @@ impl Document for ScoreBoxesDocument {
     fn build(&self, focus: &FocusContext) -> Vec<DocumentElement> {
         // Return loading animation if no schedule data yet
         let Some(schedule) = self.schedule.as_ref() else {
+            if let Some(error) = &self.load_error {
+                return DocumentBuilder::new().text(error.clone()).build();
+            }
             return DocumentBuilder::new()
-                .text(loading_animation_text(self.animation_frame))
+                .text(loading_animation_text(
+                    self.animation_frame,
+                    self.loading_frame_divisor,
+                    self.loading_style,
+                    focus.use_unicode,
+                ))
                 .build();
         };
 
         if schedule.games.is_empty() {
             return DocumentBuilder::new()
-                .text("No games scheduled for this date")
+                .element(DocumentElement::empty_state(
+                    "No games scheduled",
+                    Some(schedule.date.clone()),
+                ))
+                .build();
+        }
+
+        let games: Vec<&nhl_api::ScheduleGame> = schedule
+            .games
+            .iter()
+            .filter(|game| self.is_shown(game))
+            .collect();
+        if games.is_empty() {
+            let message = if self.favorites.as_ref().is_some_and(Vec::is_empty) {
+                "No favorite teams configured (add favorite_teams to config.toml)".to_string()
+            } else if let Some(game_type) = self.game_type {
+                format!(
+                    "No {} games on this date (press t to change the game type)",
+                    game_type
+                )
+            } else if self.state_filter != GameStateFilter::All {
+                format!(
+                    "No {} games on this date (press s to change the filter)",
+                    self.state_filter.label()
+                )
+            } else {
+                "No games for your favorite teams on this date (press f to show all)".to_string()
+            };
+            return DocumentBuilder::new()
+                .element(DocumentElement::empty_state(
+                    message,
+                    Some(schedule.date.clone()),
+                ))
                 .build();
         }
 
-        let mut builder = DocumentBuilder::new();
+        let mut builder = DocumentBuilder::new().compact(self.compact_spacing).when(
+            self.state_filter != GameStateFilter::All,
+            |builder| {
+                builder.text(format!(
+                    "Showing {} games (s: next filter)",
+                    self.state_filter.label()
+                ))
+            },
+        );
+
+        let score_element = |game: &&nhl_api::ScheduleGame| {
+            // ScoreBoxElement uses FocusableId::GameLink(game_id)
+            let focused = focus.focused_id == Some(FocusableId::GameLink(game.id));
+            DocumentElement::score_box_element(game.id, self.create_score_box(game), focused)
+        };
+
+        if self.compact {
+            return builder
+                .decorative_spacer(1)
+                .elements(games.iter().map(score_element))
+                .build();
+        }
 
         // Group games into rows
-        let games: Vec<&nhl_api::ScheduleGame> = schedule.games.iter().collect();
         let chunks: Vec<&[&nhl_api::ScheduleGame]> =
             games.chunks(self.boxes_per_row as usize).collect();
 
         for chunk in chunks.iter() {
             // Add blank line before each row
-            builder = builder.spacer(1);
+            builder = builder.decorative_spacer(1);
 
             // Create ScoreBox elements for this row
-            let score_elements: Vec<DocumentElement> = chunk
-                .iter()
-                .map(|game| {
-                    // ScoreBoxElement uses FocusableId::GameLink(game_id)
-                    let focused = focus.focused_id == Some(FocusableId::GameLink(game.id));
-
-                    // Create the ScoreBox widget
-                    let score_box = self.create_score_box(game);
-
-                    // Use the ScoreBoxElement variant
-                    DocumentElement::score_box_element(game.id, score_box, focused)
-                })
-                .collect();
+            let score_elements: Vec<DocumentElement> = chunk.iter().map(score_element).collect();
 
             // Add row with custom gap to document
             builder = builder.row_with_gap(score_elements, SCORE_BOX_GAP);
@@ -198,6 +448,7 @@ impl Document for ScoreBoxesDocument {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::fixtures::{create_goal_summary, create_mock_game_matchup};
     use nhl_api::{GameState as ApiGameState, ScheduleGame, ScheduleTeam};
 
     fn create_test_game(id: i64, away: &str, home: &str) -> ScheduleGame {
@@ -241,6 +492,26 @@ mod tests {
         assert_eq!(elements.len(), 1);
     }
 
+    #[test]
+    fn test_load_error_replaces_loading_animation() {
+        let doc = ScoreBoxesDocument::new(
+            Arc::new(None),
+            Arc::new(HashMap::new()),
+            2,
+            GameDate::today(),
+            0,
+        )
+        .with_load_error(Some("Failed to load schedule: timeout".to_string()));
+
+        let elements = doc.build(&FocusContext::default());
+
+        assert_eq!(elements.len(), 1);
+        assert!(matches!(
+            &elements[0],
+            DocumentElement::Text { content, .. } if content == "Failed to load schedule: timeout"
+        ));
+    }
+
     #[test]
     fn test_single_game_layout() {
         let game = create_test_game(1, "TOR", "MTL");
@@ -322,6 +593,303 @@ mod tests {
         assert_eq!(elements.len(), 4);
     }
 
+    #[test]
+    fn test_compact_spacing_drops_row_spacers() {
+        let schedule = DailySchedule {
+            date: "2024-01-15".to_string(),
+            games: vec![
+                create_test_game(1, "TOR", "MTL"),
+                create_test_game(2, "BOS", "NYR"),
+                create_test_game(3, "EDM", "VAN"),
+            ],
+            next_start_date: None,
+            previous_start_date: None,
+            number_of_games: 3,
+        };
+
+        let doc = ScoreBoxesDocument::new(
+            Arc::new(Some(schedule)),
+            Arc::new(HashMap::new()),
+            2,
+            GameDate::today(),
+            0,
+        )
+        .with_compact_spacing(true);
+
+        let elements = doc.build(&FocusContext::default());
+
+        // Only the 2 rows remain
+        assert_eq!(elements.len(), 2);
+    }
+
+    fn favorites_doc(favorites: &[&str]) -> ScoreBoxesDocument {
+        let schedule = DailySchedule {
+            date: "2024-01-15".to_string(),
+            games: vec![
+                create_test_game(1, "TOR", "MTL"),
+                create_test_game(2, "BOS", "NYR"),
+                create_test_game(3, "EDM", "TOR"),
+            ],
+            next_start_date: None,
+            previous_start_date: None,
+            number_of_games: 3,
+        };
+        ScoreBoxesDocument::new(
+            Arc::new(Some(schedule)),
+            Arc::new(HashMap::new()),
+            2,
+            GameDate::today(),
+            0,
+        )
+        .with_favorites(Some(favorites.iter().map(|s| s.to_string()).collect()))
+    }
+
+    fn only_empty_state(elements: &[DocumentElement]) -> &str {
+        assert_eq!(elements.len(), 1);
+        match &elements[0] {
+            DocumentElement::EmptyStateElement { empty_state } => &empty_state.message,
+            _ => panic!("Expected empty state element"),
+        }
+    }
+
+    #[test]
+    fn test_accented_teams_mark_their_side_of_each_game() {
+        let doc = favorites_doc(&[])
+            .with_favorites(None)
+            .with_accented_teams(vec!["TOR".to_string()]);
+        let games = &doc.schedule.as_ref().as_ref().unwrap().games;
+
+        let accents: Vec<(bool, bool)> = games
+            .iter()
+            .map(|game| {
+                let score_box = doc.create_score_box(game);
+                (score_box.away_accented, score_box.home_accented)
+            })
+            .collect();
+        assert_eq!(accents, vec![(true, false), (false, false), (false, true)]);
+    }
+
+    #[test]
+    fn test_scheduled_start_time_uses_timezone_and_start_time_format() {
+        let mut game = create_test_game(1, "TOR", "MTL");
+        game.game_state = ApiGameState::Future;
+        let doc = |timezone: &str, start_time_format: &str| {
+            ScoreBoxesDocument::new(
+                Arc::new(None),
+                Arc::new(HashMap::new()),
+                2,
+                GameDate::today(),
+                0,
+            )
+            .with_timezone(timezone)
+            .with_start_time_format(start_time_format)
+        };
+        let start_time = |doc: ScoreBoxesDocument| doc.create_score_box(&game).status.display();
+
+        assert_eq!(start_time(doc("America/Toronto", "%H:%M")), "15:00");
+        assert_eq!(start_time(doc("America/Vancouver", "%I:%M %p")), "12:00 PM");
+        assert_eq!(
+            start_time(doc("America/Toronto", DEFAULT_START_TIME_FORMAT)),
+            "3:00 PM"
+        );
+    }
+
+    fn game_with_goals(goals_per_period: &[(nhl_api::PeriodType, i32, usize)]) -> GameMatchup {
+        let mut info = create_mock_game_matchup(2024020002);
+        info.summary.as_mut().unwrap().scoring = goals_per_period
+            .iter()
+            .map(|&(period_type, number, goals)| nhl_api::PeriodScoring {
+                period_descriptor: nhl_api::PeriodDescriptor {
+                    number,
+                    period_type,
+                    max_regulation_periods: 3,
+                },
+                goals: (0..goals)
+                    .map(|i| create_goal_summary("TOR", "Matthews", &format!("0{}:00", i)))
+                    .collect(),
+            })
+            .collect();
+        info
+    }
+
+    #[test]
+    fn test_scorer_lines_follow_scoring_summary() {
+        use nhl_api::PeriodType::{Overtime, Regulation};
+
+        let mut doc = favorites_doc(&[]);
+        doc.game_info = Arc::new(HashMap::from([
+            (1, game_with_goals(&[(Regulation, 1, 1), (Overtime, 4, 1)])),
+            (
+                2,
+                game_with_goals(&[(Regulation, 1, 3), (Regulation, 2, 4)]),
+            ),
+            (3, game_with_goals(&[])),
+        ]));
+
+        assert_eq!(
+            doc.scorer_lines(1),
+            vec!["P1 00:00 TOR Matthews", "OT 00:00 TOR Matthews"]
+        );
+        // Seven goals: the first four, then a count of the rest
+        let lines = doc.scorer_lines(2);
+        assert_eq!(lines.len(), MAX_SCORER_LINES);
+        assert_eq!(lines[3], "P2 00:00 TOR Matthews");
+        assert_eq!(lines[4], "+3 more");
+        // No goals yet, or no game info at all
+        assert!(doc.scorer_lines(3).is_empty());
+        assert!(doc.scorer_lines(4).is_empty());
+    }
+
+    #[test]
+    fn test_compact_layout_puts_one_game_per_line() {
+        let doc = favorites_doc(&[]).with_favorites(None).with_compact(true);
+
+        assert_eq!(
+            doc.focusable_ids(),
+            vec![
+                FocusableId::GameLink(1),
+                FocusableId::GameLink(2),
+                FocusableId::GameLink(3)
+            ]
+        );
+        assert_eq!(doc.focusable_positions(), vec![1, 2, 3]);
+        assert_eq!(doc.focusable_heights(), vec![1, 1, 1]);
+    }
+
+    #[test]
+    fn test_favorites_filter_keeps_matching_games() {
+        let doc = favorites_doc(&["TOR"]);
+
+        assert_eq!(
+            doc.focusable_ids(),
+            vec![FocusableId::GameLink(1), FocusableId::GameLink(3)]
+        );
+    }
+
+    #[test]
+    fn test_empty_schedule_shows_empty_state_with_date() {
+        let mut doc = favorites_doc(&[]).with_favorites(None);
+        if let Some(schedule) = Arc::get_mut(&mut doc.schedule).and_then(Option::as_mut) {
+            schedule.games.clear();
+        }
+        let elements = doc.build(&FocusContext::default());
+
+        match &elements[..] {
+            [DocumentElement::EmptyStateElement { empty_state }] => {
+                assert_eq!(empty_state.message, "No games scheduled");
+                assert_eq!(empty_state.detail.as_deref(), Some("2024-01-15"));
+            }
+            _ => panic!("Expected empty state, got {:?}", elements),
+        }
+    }
+
+    #[test]
+    fn test_favorites_filter_without_matches() {
+        let doc = favorites_doc(&["CHI"]);
+        let elements = doc.build(&FocusContext::default());
+
+        assert_eq!(
+            only_empty_state(&elements),
+            "No games for your favorite teams on this date (press f to show all)"
+        );
+    }
+
+    #[test]
+    fn test_favorites_filter_with_no_favorites_configured() {
+        let doc = favorites_doc(&[]);
+        let elements = doc.build(&FocusContext::default());
+
+        assert_eq!(
+            only_empty_state(&elements),
+            "No favorite teams configured (add favorite_teams to config.toml)"
+        );
+    }
+
+    fn state_filter_doc(filter: GameStateFilter) -> ScoreBoxesDocument {
+        let mut live = create_test_game(2, "BOS", "NYR");
+        live.game_state = ApiGameState::Live;
+        let mut upcoming = create_test_game(3, "EDM", "TOR");
+        upcoming.game_state = ApiGameState::Future;
+        let schedule = DailySchedule {
+            date: "2024-01-15".to_string(),
+            games: vec![create_test_game(1, "TOR", "MTL"), live, upcoming],
+            next_start_date: None,
+            previous_start_date: None,
+            number_of_games: 3,
+        };
+        ScoreBoxesDocument::new(
+            Arc::new(Some(schedule)),
+            Arc::new(HashMap::new()),
+            2,
+            GameDate::today(),
+            0,
+        )
+        .with_state_filter(filter)
+    }
+
+    #[test]
+    fn test_state_filter_keeps_matching_games() {
+        for (filter, expected) in [
+            (GameStateFilter::All, vec![1, 2, 3]),
+            (GameStateFilter::Live, vec![2]),
+            (GameStateFilter::Final, vec![1]),
+            (GameStateFilter::Upcoming, vec![3]),
+        ] {
+            let ids: Vec<FocusableId> = expected.into_iter().map(FocusableId::GameLink).collect();
+            assert_eq!(
+                state_filter_doc(filter).focusable_ids(),
+                ids,
+                "{:?}",
+                filter
+            );
+        }
+    }
+
+    #[test]
+    fn test_state_filter_shows_header() {
+        let elements = state_filter_doc(GameStateFilter::Live).build(&FocusContext::default());
+
+        match &elements[0] {
+            DocumentElement::Text { content, .. } => {
+                assert_eq!(content, "Showing live games (s: next filter)")
+            }
+            _ => panic!("Expected header text"),
+        }
+    }
+
+    #[test]
+    fn test_state_filter_without_matches() {
+        let doc = favorites_doc(&["TOR"]).with_state_filter(GameStateFilter::Upcoming);
+        let elements = doc.build(&FocusContext::default());
+
+        assert_eq!(
+            only_empty_state(&elements),
+            "No upcoming games on this date (press s to change the filter)"
+        );
+    }
+
+    #[test]
+    fn test_game_type_keeps_matching_games() {
+        let mut doc = state_filter_doc(GameStateFilter::All);
+        if let Some(schedule) = Arc::get_mut(&mut doc.schedule).and_then(Option::as_mut) {
+            schedule.games[1].game_type = GameType::Playoffs;
+        }
+
+        let doc = doc.with_game_type(Some(GameType::Playoffs));
+        assert_eq!(doc.focusable_ids(), vec![FocusableId::GameLink(2)]);
+    }
+
+    #[test]
+    fn test_game_type_without_matches() {
+        let doc = favorites_doc(&["TOR"]).with_game_type(Some(GameType::Preseason));
+        let elements = doc.build(&FocusContext::default());
+
+        assert_eq!(
+            only_empty_state(&elements),
+            "No Preseason games on this date (press t to change the game type)"
+        );
+    }
+
     #[test]
     fn test_title_and_id() {
         let doc = ScoreBoxesDocument::new(
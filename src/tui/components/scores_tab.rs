@@ -1,21 +1,34 @@
-use crossterm::event::{KeyCode, KeyEvent};
-use ratatui::{buffer::Buffer, layout::Rect};
-use std::collections::HashMap;
+use chrono::NaiveDate;
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::Style,
+    text::{Line, Span},
+    widgets::{Paragraph, Widget},
+};
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
-use nhl_api::{DailySchedule, GameDate, GameMatchup};
+use nhl_api::{DailySchedule, GameDate, GameMatchup, GameType};
 
 use crate::commands::scores_format::PeriodScores;
 use crate::component_message_impl;
-use crate::config::DisplayConfig;
+use crate::config::{DisplayConfig, ScoresLayout};
 use crate::tui::action::Action;
-use crate::tui::component::{Component, Effect, Element, ElementWidget};
+use crate::tui::component::{vertical, Component, Constraint, Effect, Element, ElementWidget};
 use crate::tui::document::DocumentView;
 use crate::tui::document_nav::{DocumentNavMsg, DocumentNavState};
+use crate::tui::hit_test::HitRegion;
 use crate::tui::tab_component::{handle_common_message, CommonTabMessage, TabMessage, TabState};
 
-use super::score_boxes_document::ScoreBoxesDocument;
+use super::score_boxes_document::{GameStateFilter, ScoreBoxesDocument};
 use super::{TabItem, TabbedPanel, TabbedPanelProps};
+
+/// Number of dates shown in the date selector
+const DATE_WINDOW_SIZE: usize = 5;
+
+/// Length of a YYYY-MM-DD date typed into the jump-to-date prompt
+const DATE_INPUT_LEN: usize = 10;
 //
 /// Component state for ScoresTab - managed by the component itself
 #[derive(Clone, Debug)]
@@ -26,18 +39,39 @@ pub struct ScoresTabState {
 
     // Document navigation (replaces browse_mode and selected_game_index)
     pub doc_nav: DocumentNavState,
+
+    /// Only show games involving the configured favorite teams
+    pub favorites_only: bool,
+    /// Only show games in this state; reset to All when the date changes
+    pub state_filter: GameStateFilter,
+
+    /// Text typed into the jump-to-date prompt, None when the prompt is closed
+    pub date_input: Option<String>,
+    /// Validation error for the last submitted date
+    pub date_input_error: Option<String>,
 }
 
 impl Default for ScoresTabState {
     fn default() -> Self {
         Self {
-            selected_date_index: 2, // Middle of 5-date window
+            selected_date_index: DATE_WINDOW_SIZE / 2, // Middle of the date window
             game_date: GameDate::today(),
             doc_nav: DocumentNavState::default(),
+            favorites_only: false,
+            state_filter: GameStateFilter::default(),
+            date_input: None,
+            date_input_error: None,
         }
     }
 }
 
+impl ScoresTabState {
+    /// Whether the jump-to-date prompt is capturing keystrokes
+    pub fn is_date_input_active(&self) -> bool {
+        self.date_input.is_some()
+    }
+}
+
 impl TabState for ScoresTabState {
     fn doc_nav(&self) -> &DocumentNavState {
         &self.doc_nav
@@ -51,9 +85,6 @@ impl TabState for ScoresTabState {
 /// Messages handled by ScoresTab component
 #[derive(Clone, Debug)]
 pub enum ScoresTabMsg {
-    /// Key event when this tab is focused
-    Key(KeyEvent),
-
     /// Navigate up request (ESC in browse mode, returns to tab bar otherwise)
     /// Returns Effect::Handled if consumed, Effect::None if should bubble up
     NavigateUp,
@@ -61,6 +92,15 @@ pub enum ScoresTabMsg {
     // Date navigation
     NavigateLeft,
     NavigateRight,
+    /// Jump back to today's date
+    NavigateToday,
+
+    // Jump-to-date prompt
+    StartDateInput,
+    DateInputChar(char),
+    DateInputBackspace,
+    SubmitDateInput,
+    CancelDateInput,
 
     // Browse mode (game selection)
     EnterBoxSelection,
@@ -74,6 +114,13 @@ pub enum ScoresTabMsg {
 
     // Game activation
     ActivateGame,
+
+    /// Show only favorite teams' games, or every game again
+    ToggleFavorites,
+    /// Step through All → Live → Final → Upcoming games
+    CycleStateFilter,
+    /// Step through every game type → Preseason → Regular Season → Playoffs
+    CycleGameType,
 }
 
 impl TabMessage for ScoresTabMsg {
@@ -101,12 +148,21 @@ pub struct ScoresTabProps {
     pub schedule: Arc<Option<DailySchedule>>,
     pub game_info: Arc<HashMap<i64, GameMatchup>>,
     pub period_scores: Arc<HashMap<i64, PeriodScores>>,
+    pub favorite_teams: Vec<String>,
+    /// Format for start times (`Config.start_time_format`)
+    pub start_time_format: String,
+    /// Games whose score just changed
+    pub highlighted_games: HashSet<i64>,
+    /// When set, only games of this type are listed
+    pub game_type: Option<GameType>,
 
     // Navigation state
     pub focused: bool,
 
     // Animation frame for loading indicator
     pub animation_frame: u8,
+    /// Why the schedule couldn't be loaded, shown instead of the loading animation
+    pub load_error: Option<String>,
 }
 //
 /// ScoresTab component - renders scores with date selector
@@ -130,36 +186,63 @@ impl Component for ScoresTab {
 
         // Handle tab-specific messages
         match msg {
-            ScoresTabMsg::Key(key) => self.handle_key(key, state),
-
             ScoresTabMsg::NavigateLeft => {
-                // Navigate left in the date window
-                if state.selected_date_index > 0 {
-                    // Move within the window
-                    state.selected_date_index -= 1;
-                    state.game_date = state.game_date.add_days(-1);
-                } else {
-                    // At left edge - shift window left
-                    state.game_date = state.game_date.add_days(-1);
-                    // selected_date_index stays at 0
-                }
-                // Refresh schedule for new date (also updates global state and clears old data)
-                Effect::Action(Action::RefreshSchedule(state.game_date.clone()))
+                // Move within the window, or shift the window at the left edge
+                state.selected_date_index = state.selected_date_index.saturating_sub(1);
+                self.change_date(state, state.game_date.add_days(-1))
             }
             ScoresTabMsg::NavigateRight => {
-                // Navigate right in the date window
-                const DATE_WINDOW_SIZE: usize = 5;
-                if state.selected_date_index < DATE_WINDOW_SIZE - 1 {
-                    // Move within the window
-                    state.selected_date_index += 1;
-                    state.game_date = state.game_date.add_days(1);
-                } else {
-                    // At right edge - shift window right
-                    state.game_date = state.game_date.add_days(1);
-                    // selected_date_index stays at 4
+                // Move within the window, or shift the window at the right edge
+                state.selected_date_index =
+                    (state.selected_date_index + 1).min(DATE_WINDOW_SIZE - 1);
+                self.change_date(state, state.game_date.add_days(1))
+            }
+            ScoresTabMsg::NavigateToday => {
+                state.selected_date_index = DATE_WINDOW_SIZE / 2;
+                self.change_date(state, GameDate::today())
+            }
+            ScoresTabMsg::StartDateInput => {
+                state.date_input = Some(String::new());
+                state.date_input_error = None;
+                Effect::None
+            }
+            ScoresTabMsg::DateInputChar(c) => {
+                if let Some(input) = state.date_input.as_mut() {
+                    if (c.is_ascii_digit() || c == '-') && input.len() < DATE_INPUT_LEN {
+                        input.push(c);
+                    }
                 }
-                // Refresh schedule for new date (also updates global state and clears old data)
-                Effect::Action(Action::RefreshSchedule(state.game_date.clone()))
+                Effect::None
+            }
+            ScoresTabMsg::DateInputBackspace => {
+                if let Some(input) = state.date_input.as_mut() {
+                    input.pop();
+                }
+                Effect::None
+            }
+            ScoresTabMsg::SubmitDateInput => {
+                let Some(input) = state.date_input.as_deref() else {
+                    return Effect::None;
+                };
+                match NaiveDate::parse_from_str(input, "%Y-%m-%d") {
+                    Ok(date) => {
+                        state.date_input = None;
+                        state.date_input_error = None;
+                        state.selected_date_index = DATE_WINDOW_SIZE / 2;
+                        self.change_date(state, GameDate::Date(date))
+                    }
+                    Err(_) => {
+                        state.date_input_error =
+                            Some(format!("Invalid date '{}', expected YYYY-MM-DD", input));
+                        Effect::None
+                    }
+                }
+            }
+            ScoresTabMsg::CancelDateInput => {
+                // The date only changes on submit, so closing the prompt keeps the prior date
+                state.date_input = None;
+                state.date_input_error = None;
+                Effect::None
             }
             ScoresTabMsg::EnterBoxSelection => {
                 state.enter_browse_mode();
@@ -169,6 +252,22 @@ impl Component for ScoresTab {
                 state.exit_browse_mode();
                 Effect::None
             }
+            ScoresTabMsg::ToggleFavorites => {
+                state.favorites_only = !state.favorites_only;
+                // The set of games changes, so the old focus no longer applies
+                state.exit_browse_mode();
+                Effect::Action(Action::RebuildScoresFocusable)
+            }
+            ScoresTabMsg::CycleStateFilter => {
+                state.state_filter = state.state_filter.next();
+                state.exit_browse_mode();
+                Effect::Action(Action::RebuildScoresFocusable)
+            }
+            ScoresTabMsg::CycleGameType => {
+                // The game type lives in global state so the breadcrumb can show it
+                state.exit_browse_mode();
+                Effect::Action(Action::CycleGameType)
+            }
 
             // Game activation
             ScoresTabMsg::ActivateGame => {
@@ -209,9 +308,17 @@ impl Component for ScoresTab {
 }
 
 impl ScoresTab {
+    /// Switch to another date, dropping focus and scroll from the previous day
+    fn change_date(&self, state: &mut ScoresTabState, date: GameDate) -> Effect {
+        state.game_date = date;
+        state.state_filter = GameStateFilter::All;
+        state.exit_browse_mode();
+        // Refresh schedule for new date (also updates global state and clears old data)
+        Effect::Action(Action::RefreshSchedule(state.game_date.clone()))
+    }
+
     /// Render date tabs using component state for UI, props for data
     fn render_date_tabs(&self, props: &ScoresTabProps, state: &ScoresTabState) -> Element {
-        const DATE_WINDOW_SIZE: usize = 5;
         //
         // Calculate the 5-date window using component state
         let window_base_date = state
@@ -273,61 +380,71 @@ impl ScoresTab {
         _date: &GameDate,
     ) -> Element {
         // Wrap in ScoreBoxesDocumentWidget which calculates boxes_per_row at render time
-        Element::Widget(Box::new(ScoreBoxesDocumentWidget {
+        let games = Element::Widget(Box::new(ScoreBoxesDocumentWidget {
             schedule: props.schedule.clone(),
             game_info: props.game_info.clone(),
             game_date: state.game_date.clone(),
+            favorites: state.favorites_only.then(|| props.favorite_teams.clone()),
+            state_filter: state.state_filter,
+            game_type: props.game_type,
+            favorite_teams: props.favorite_teams.clone(),
+            start_time_format: props.start_time_format.clone(),
+            highlighted: props.highlighted_games.clone(),
             focus_index: state.doc_nav.focus_index,
             scroll_offset: state.doc_nav.scroll_offset,
             animation_frame: props.animation_frame,
-        }))
-    }
-
-    /// Handle key events when this tab is focused
-    ///
-    /// This method handles all key logic that was previously in keys.rs.
-    /// Returns an Effect which may be an Action to dispatch.
-    fn handle_key(&mut self, key: KeyEvent, state: &mut ScoresTabState) -> Effect {
-        if state.is_browse_mode() {
-            // Box selection mode - arrow keys navigate games
-            match key.code {
-                KeyCode::Up => crate::tui::document_nav::handle_message(
-                    &mut state.doc_nav,
-                    &DocumentNavMsg::FocusPrev,
-                ),
-                KeyCode::Down => crate::tui::document_nav::handle_message(
-                    &mut state.doc_nav,
-                    &DocumentNavMsg::FocusNext,
-                ),
-                KeyCode::Left => crate::tui::document_nav::handle_message(
-                    &mut state.doc_nav,
-                    &DocumentNavMsg::FocusLeft,
-                ),
-                KeyCode::Right => crate::tui::document_nav::handle_message(
-                    &mut state.doc_nav,
-                    &DocumentNavMsg::FocusRight,
-                ),
-                KeyCode::Enter => {
-                    // Activate the focused game
-                    self.update(ScoresTabMsg::ActivateGame, state)
-                }
-                _ => Effect::None,
-            }
-        } else {
-            // Date navigation mode - arrow keys navigate dates
-            match key.code {
-                KeyCode::Left => self.update(ScoresTabMsg::NavigateLeft, state),
-                KeyCode::Right => self.update(ScoresTabMsg::NavigateRight, state),
-                KeyCode::Down | KeyCode::Enter => {
-                    // Enter box selection mode
-                    self.update(ScoresTabMsg::EnterBoxSelection, state)
-                }
-                _ => Effect::None,
-            }
+            load_error: props.load_error.clone(),
+        }));
+
+        match &state.date_input {
+            Some(input) => vertical(
+                [Constraint::Length(1), Constraint::Min(0)],
+                vec![
+                    Element::Widget(Box::new(DateInputWidget {
+                        input: input.clone(),
+                        error: state.date_input_error.clone(),
+                    })),
+                    games,
+                ],
+            ),
+            None => games,
         }
     }
 }
 
+/// One-line jump-to-date prompt with an inline validation error
+struct DateInputWidget {
+    input: String,
+    error: Option<String>,
+}
+
+impl ElementWidget for DateInputWidget {
+    fn render(&self, area: Rect, buf: &mut Buffer, display_config: &DisplayConfig) {
+        let mut spans = vec![Span::styled(
+            format!("Go to date (YYYY-MM-DD): {}█", self.input),
+            display_config.text_style(),
+        )];
+        if let Some(error) = &self.error {
+            spans.push(Span::styled(
+                format!("  {}", error),
+                Style::default().fg(display_config.error_fg),
+            ));
+        }
+        Paragraph::new(Line::from(spans)).render(area, buf);
+    }
+
+    fn clone_box(&self) -> Box<dyn ElementWidget> {
+        Box::new(DateInputWidget {
+            input: self.input.clone(),
+            error: self.error.clone(),
+        })
+    }
+
+    fn preferred_height(&self) -> Option<u16> {
+        Some(1)
+    }
+}
+
 /// Widget that renders ScoreBoxesDocument with DocumentView
 ///
 /// This widget creates the document at render time to calculate boxes_per_row
@@ -336,13 +453,21 @@ struct ScoreBoxesDocumentWidget {
     schedule: Arc<Option<DailySchedule>>,
     game_info: Arc<HashMap<i64, GameMatchup>>,
     game_date: GameDate,
+    favorites: Option<Vec<String>>,
+    state_filter: GameStateFilter,
+    game_type: Option<GameType>,
+    favorite_teams: Vec<String>,
+    start_time_format: String,
+    highlighted: HashSet<i64>,
     focus_index: Option<usize>,
     scroll_offset: u16,
     animation_frame: u8,
+    load_error: Option<String>,
 }
 
-impl ElementWidget for ScoreBoxesDocumentWidget {
-    fn render(&self, area: Rect, buf: &mut Buffer, display_config: &DisplayConfig) {
+impl ScoreBoxesDocumentWidget {
+    /// Document view sized to the area, with focus and scroll applied
+    fn view(&self, area: Rect, display_config: &DisplayConfig) -> DocumentView {
         // Calculate boxes_per_row based on actual viewport width
         let boxes_per_row = ScoreBoxesDocument::boxes_per_row_for_width(area.width);
 
@@ -353,7 +478,21 @@ impl ElementWidget for ScoreBoxesDocumentWidget {
             boxes_per_row,
             self.game_date.clone(),
             self.animation_frame,
-        );
+        )
+        .with_favorites(self.favorites.clone())
+        .with_state_filter(self.state_filter)
+        .with_game_type(self.game_type)
+        .with_accented_teams(self.favorite_teams.clone())
+        .with_compact(display_config.scores_layout == ScoresLayout::Compact)
+        .with_compact_spacing(display_config.compact_spacing)
+        .with_highlighted(self.highlighted.clone())
+        .with_loading_animation(
+            display_config.loading_style,
+            display_config.loading_frame_divisor,
+        )
+        .with_load_error(self.load_error.clone())
+        .with_timezone(display_config.timezone.clone())
+        .with_start_time_format(self.start_time_format.clone());
 
         // Create DocumentView with viewport height
         let mut view = DocumentView::new(Arc::new(doc), area.height);
@@ -365,9 +504,19 @@ impl ElementWidget for ScoreBoxesDocumentWidget {
 
         // Apply scroll offset
         view.set_scroll_offset(self.scroll_offset);
+        view
+    }
+}
+
+impl ElementWidget for ScoreBoxesDocumentWidget {
+    fn render(&self, area: Rect, buf: &mut Buffer, display_config: &DisplayConfig) {
+        self.view(area, display_config)
+            .render(area, buf, display_config);
+    }
 
-        // Render the document
-        view.render(area, buf, display_config);
+    fn hit_regions(&self, area: Rect, display_config: &DisplayConfig) -> Vec<HitRegion> {
+        self.view(area, display_config)
+            .hit_regions(area, display_config)
     }
 
     fn clone_box(&self) -> Box<dyn ElementWidget> {
@@ -375,9 +524,16 @@ impl ElementWidget for ScoreBoxesDocumentWidget {
             schedule: self.schedule.clone(),
             game_info: self.game_info.clone(),
             game_date: self.game_date.clone(),
+            favorites: self.favorites.clone(),
+            state_filter: self.state_filter,
+            game_type: self.game_type,
+            favorite_teams: self.favorite_teams.clone(),
+            start_time_format: self.start_time_format.clone(),
+            highlighted: self.highlighted.clone(),
             focus_index: self.focus_index,
             scroll_offset: self.scroll_offset,
             animation_frame: self.animation_frame,
+            load_error: self.load_error.clone(),
         })
     }
 
@@ -389,6 +545,15 @@ impl ElementWidget for ScoreBoxesDocumentWidget {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::DEFAULT_START_TIME_FORMAT;
+    use crate::tui::component_store::ComponentStateStore;
+    use crate::tui::constants::SCORES_TAB_PATH;
+    use crate::tui::keys::key_to_action;
+    use crate::tui::reducer::reduce;
+    use crate::tui::state::AppState;
+    use crate::tui::testing::assert_buffer;
+    use crate::tui::types::Tab;
+    use crossterm::event::{KeyCode, KeyEvent};
     //
     #[test]
     fn test_scores_tab_renders_with_no_schedule() {
@@ -397,8 +562,13 @@ mod tests {
             schedule: Arc::new(None),
             game_info: Arc::new(HashMap::new()),
             period_scores: Arc::new(HashMap::new()),
+            favorite_teams: Vec::new(),
+            start_time_format: DEFAULT_START_TIME_FORMAT.to_string(),
+            highlighted_games: HashSet::new(),
+            game_type: None,
             focused: false,
             animation_frame: 0,
+            load_error: None,
         };
         //
         let state = ScoresTabState::default();
@@ -411,5 +581,229 @@ mod tests {
             _ => panic!("Expected container element"),
         }
     }
-    //
+
+    fn refreshed_date(effect: Effect) -> GameDate {
+        match effect {
+            Effect::Action(Action::RefreshSchedule(date)) => date,
+            _ => panic!("Expected RefreshSchedule action"),
+        }
+    }
+
+    /// Press a key on the focused Scores tab, mapping and reducing it as the app does
+    fn press(state: &mut ScoresTabState, code: KeyCode) -> Effect {
+        let mut app_state = AppState::default();
+        app_state.navigation.current_tab = Tab::Scores;
+        app_state.navigation.content_focused = true;
+        let mut component_states = ComponentStateStore::new();
+        component_states.insert(SCORES_TAB_PATH.to_string(), state.clone());
+
+        let Some(action) = key_to_action(KeyEvent::from(code), &app_state, &component_states)
+        else {
+            return Effect::None;
+        };
+        let (_, effect) = reduce(app_state, action, &mut component_states);
+        *state = component_states
+            .get::<ScoresTabState>(SCORES_TAB_PATH)
+            .unwrap()
+            .clone();
+        effect
+    }
+
+    #[test]
+    fn test_bracket_keys_move_date_and_reset_focus() {
+        let mut state = ScoresTabState::default();
+        let today = state.game_date.clone();
+        state.doc_nav.focus_index = Some(1);
+        state.doc_nav.scroll_offset = 4;
+
+        let effect = press(&mut state, KeyCode::Char('['));
+        assert_eq!(refreshed_date(effect), today.add_days(-1));
+        assert_eq!(state.game_date, today.add_days(-1));
+        assert_eq!(state.selected_date_index, 1);
+        assert_eq!(state.doc_nav.focus_index, None);
+        assert_eq!(state.doc_nav.scroll_offset, 0);
+
+        let effect = press(&mut state, KeyCode::Char(']'));
+        assert_eq!(refreshed_date(effect), today);
+        assert_eq!(state.selected_date_index, 2);
+    }
+
+    #[test]
+    fn test_date_window_shifts_at_edges() {
+        let mut state = ScoresTabState::default();
+        let today = state.game_date.clone();
+
+        for _ in 0..4 {
+            press(&mut state, KeyCode::Right);
+        }
+
+        assert_eq!(state.selected_date_index, DATE_WINDOW_SIZE - 1);
+        assert_eq!(state.game_date, today.add_days(4));
+    }
+
+    #[test]
+    fn test_period_key_returns_to_today() {
+        let mut state = ScoresTabState::default();
+        let today = state.game_date.clone();
+        for _ in 0..10 {
+            press(&mut state, KeyCode::Left);
+        }
+
+        let effect = press(&mut state, KeyCode::Char('.'));
+
+        assert_eq!(refreshed_date(effect), today);
+        assert_eq!(state.game_date, today);
+        assert_eq!(state.selected_date_index, DATE_WINDOW_SIZE / 2);
+    }
+
+    fn type_date(state: &mut ScoresTabState, text: &str) -> Effect {
+        press(state, KeyCode::Char('g'));
+        for c in text.chars() {
+            press(state, KeyCode::Char(c));
+        }
+        press(state, KeyCode::Enter)
+    }
+
+    #[test]
+    fn test_jump_to_date() {
+        let mut state = ScoresTabState::default();
+
+        let effect = type_date(&mut state, "2024-11-15");
+
+        let expected = GameDate::Date(NaiveDate::from_ymd_opt(2024, 11, 15).unwrap());
+        assert_eq!(refreshed_date(effect), expected);
+        assert_eq!(state.game_date, expected);
+        assert!(!state.is_date_input_active());
+    }
+
+    #[test]
+    fn test_jump_to_invalid_date_keeps_prompt_open() {
+        let mut state = ScoresTabState::default();
+        let today = state.game_date.clone();
+
+        let effect = type_date(&mut state, "2024-13-45");
+
+        assert!(matches!(effect, Effect::None));
+        assert_eq!(state.game_date, today);
+        assert_eq!(state.date_input.as_deref(), Some("2024-13-45"));
+        assert_eq!(
+            state.date_input_error.as_deref(),
+            Some("Invalid date '2024-13-45', expected YYYY-MM-DD")
+        );
+    }
+
+    #[test]
+    fn test_date_input_ignores_non_date_characters() {
+        let mut state = ScoresTabState::default();
+        press(&mut state, KeyCode::Char('g'));
+
+        for c in "2024-x11-15-99".chars() {
+            press(&mut state, KeyCode::Char(c));
+        }
+        press(&mut state, KeyCode::Backspace);
+
+        assert_eq!(state.date_input.as_deref(), Some("2024-11-1"));
+    }
+
+    #[test]
+    fn test_escape_cancels_date_input() {
+        let mut state = ScoresTabState::default();
+        let today = state.game_date.clone();
+        press(&mut state, KeyCode::Char('g'));
+        press(&mut state, KeyCode::Char('2'));
+
+        let effect = press(&mut state, KeyCode::Esc);
+
+        assert!(matches!(effect, Effect::None));
+        assert!(!state.is_date_input_active());
+        assert_eq!(state.game_date, today);
+    }
+
+    #[test]
+    fn test_date_input_widget_shows_error() {
+        let widget = DateInputWidget {
+            input: "2024-1".to_string(),
+            error: Some("Invalid date '2024-1', expected YYYY-MM-DD".to_string()),
+        };
+        let config = DisplayConfig {
+            error_fg: ratatui::style::Color::Red,
+            ..DisplayConfig::default()
+        };
+        let mut buf = Buffer::empty(Rect::new(0, 0, 80, 1));
+        widget.render(buf.area, &mut buf, &config);
+
+        assert_buffer(
+            &buf,
+            &["Go to date (YYYY-MM-DD): 2024-1█  Invalid date '2024-1', expected YYYY-MM-DD"],
+        );
+        assert_eq!(buf[(35, 0)].fg, ratatui::style::Color::Red);
+    }
+
+    #[test]
+    fn test_f_toggles_favorites_and_rebuilds_focusable() {
+        let mut state = ScoresTabState::default();
+        state.doc_nav.focus_index = Some(2);
+        state.doc_nav.scroll_offset = 5;
+
+        let effect = press(&mut state, KeyCode::Char('f'));
+
+        assert!(state.favorites_only);
+        assert_eq!(state.doc_nav.focus_index, None);
+        assert_eq!(state.doc_nav.scroll_offset, 0);
+        assert!(matches!(
+            effect,
+            Effect::Action(Action::RebuildScoresFocusable)
+        ));
+
+        press(&mut state, KeyCode::Char('f'));
+        assert!(!state.favorites_only);
+    }
+
+    #[test]
+    fn test_s_cycles_state_filter_and_rebuilds_focusable() {
+        let mut state = ScoresTabState::default();
+        state.doc_nav.focus_index = Some(1);
+
+        let effect = press(&mut state, KeyCode::Char('s'));
+
+        assert_eq!(state.state_filter, GameStateFilter::Live);
+        assert_eq!(state.doc_nav.focus_index, None);
+        assert!(matches!(
+            effect,
+            Effect::Action(Action::RebuildScoresFocusable)
+        ));
+
+        for expected in [
+            GameStateFilter::Final,
+            GameStateFilter::Upcoming,
+            GameStateFilter::All,
+        ] {
+            press(&mut state, KeyCode::Char('s'));
+            assert_eq!(state.state_filter, expected);
+        }
+    }
+
+    #[test]
+    fn test_t_cycles_game_type() {
+        let mut state = ScoresTabState::default();
+        state.doc_nav.focus_index = Some(1);
+
+        let effect = press(&mut state, KeyCode::Char('t'));
+
+        assert_eq!(state.doc_nav.focus_index, None);
+        assert!(matches!(effect, Effect::Action(Action::CycleGameType)));
+    }
+
+    #[test]
+    fn test_date_change_resets_state_filter() {
+        let mut scores_tab = ScoresTab;
+        let mut state = ScoresTabState {
+            state_filter: GameStateFilter::Final,
+            ..Default::default()
+        };
+
+        scores_tab.update(ScoresTabMsg::NavigateRight, &mut state);
+
+        assert_eq!(state.state_filter, GameStateFilter::All);
+    }
 }
@@ -3,6 +3,8 @@
 //! This module provides the document-based implementation for settings display,
 //! replacing the old SettingsListWidget approach with the modern document system.
 
+use std::sync::Arc;
+
 use crate::config::Config;
 use crate::tui::document::{Document, DocumentBuilder, DocumentElement, FocusContext, LinkTarget};
 use crate::tui::SettingsCategory;
@@ -10,12 +12,15 @@ use crate::tui::SettingsCategory;
 /// Settings document for a specific category
 pub struct SettingsDocument {
     category: SettingsCategory,
-    config: Config,
+    config: Arc<Config>,
 }
 
 impl SettingsDocument {
-    pub fn new(category: SettingsCategory, config: Config) -> Self {
-        Self { category, config }
+    pub fn new(category: SettingsCategory, config: impl Into<Arc<Config>>) -> Self {
+        Self {
+            category,
+            config: config.into(),
+        }
     }
 
     /// Build the logging settings section
@@ -26,14 +31,14 @@ impl SettingsDocument {
     ) -> DocumentBuilder {
         builder
             .heading(2, "Logging Settings")
-            .spacer(1)
+            .decorative_spacer(1)
             .link_with_focus(
                 "log_level",
                 format!("Log Level: {}", self.config.log_level),
                 LinkTarget::Action("edit:log_level".to_string()),
                 focus,
             )
-            .spacer(1)
+            .decorative_spacer(1)
             .link_with_focus(
                 "log_file",
                 format!("Log File: {}", self.config.log_file),
@@ -58,25 +63,30 @@ impl SettingsDocument {
 
         builder
             .heading(2, "Display Settings")
-            .spacer(1)
+            .decorative_spacer(1)
             .link_with_focus(
                 "theme",
                 format!("Theme: {}", theme_name),
                 LinkTarget::Action("edit:theme".to_string()),
                 focus,
             )
-            .spacer(1)
+            .decorative_spacer(1)
             .link_with_focus(
                 "use_unicode",
                 format!("Use Unicode: {}", self.config.display.use_unicode),
                 LinkTarget::Action("toggle:use_unicode".to_string()),
                 focus,
             )
-            .spacer(1)
-            .text(format!(
-                "Error Color: {}",
-                format_color(&self.config.display.error_fg)
-            ))
+            .decorative_spacer(1)
+            .link_with_focus(
+                "error_fg",
+                format!(
+                    "Error Color: {}",
+                    format_color(&self.config.display.error_fg)
+                ),
+                LinkTarget::Action("edit:error_fg".to_string()),
+                focus,
+            )
     }
 
     /// Build the data settings section
@@ -87,14 +97,14 @@ impl SettingsDocument {
     ) -> DocumentBuilder {
         builder
             .heading(2, "Data Settings")
-            .spacer(1)
+            .decorative_spacer(1)
             .link_with_focus(
                 "refresh_interval",
                 format!("Refresh Interval: {} seconds", self.config.refresh_interval),
                 LinkTarget::Action("edit:refresh_interval".to_string()),
                 focus,
             )
-            .spacer(1)
+            .decorative_spacer(1)
             .link_with_focus(
                 "western_teams_first",
                 format!(
@@ -104,19 +114,26 @@ impl SettingsDocument {
                 LinkTarget::Action("toggle:western_teams_first".to_string()),
                 focus,
             )
-            .spacer(1)
+            .decorative_spacer(1)
             .link_with_focus(
                 "time_format",
                 format!("Time Format: {}", self.config.time_format),
                 LinkTarget::Action("edit:time_format".to_string()),
                 focus,
             )
+            .decorative_spacer(1)
+            .link_with_focus(
+                "restore_session",
+                format!("Restore Last Tab: {}", self.config.restore_session),
+                LinkTarget::Action("toggle:restore_session".to_string()),
+                focus,
+            )
     }
 }
 
 impl Document for SettingsDocument {
     fn build(&self, focus: &FocusContext) -> Vec<DocumentElement> {
-        let builder = DocumentBuilder::new();
+        let builder = DocumentBuilder::new().compact(self.config.display.compact_spacing);
 
         let builder = match self.category {
             SettingsCategory::Logging => self.build_logging_settings(builder, focus),
@@ -5,16 +5,21 @@
 
 use std::sync::Arc;
 
+use chrono::Local;
 use crossterm::event::{KeyCode, KeyEvent};
 use ratatui::buffer::Buffer;
 use ratatui::layout::Rect;
+use ratatui::style::{Color, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Paragraph, Widget};
 
 use crate::component_message_impl;
-use crate::config::{Config, DisplayConfig};
-use crate::tui::component::{Component, Effect, Element, ElementWidget};
+use crate::config::{format_time, parse_color, Config, DisplayConfig};
+use crate::tui::component::{vertical, Component, Constraint, Effect, Element, ElementWidget};
 use crate::tui::components::{SettingsDocument, TabItem, TabbedPanel, TabbedPanelProps};
 use crate::tui::document::{DocumentView, FocusableId};
 use crate::tui::document_nav::{DocumentNavMsg, DocumentNavState};
+use crate::tui::hit_test::HitRegion;
 use crate::tui::settings_helpers::ModalOption;
 use crate::tui::tab_component::{handle_common_message, CommonTabMessage, TabMessage, TabState};
 use crate::tui::SettingsCategory;
@@ -22,7 +27,7 @@ use crate::tui::SettingsCategory;
 /// Props for SettingsTab component
 #[derive(Clone)]
 pub struct SettingsTabProps {
-    pub config: Config,
+    pub config: Arc<Config>,
     pub selected_category: SettingsCategory,
     pub focused: bool,
 }
@@ -36,6 +41,24 @@ pub enum ModalMsg {
     Cancel,
 }
 
+/// Editor input messages
+#[derive(Clone, Debug, PartialEq)]
+pub enum EditMsg {
+    Char(char),
+    Backspace,
+    Submit,
+    Cancel,
+}
+
+/// Inline editor for free-text settings (log_file, refresh_interval, time_format, error_fg)
+#[derive(Debug, Clone, PartialEq)]
+pub struct EditState {
+    pub setting_key: String,
+    pub input: String,
+    /// Validation error for the last submitted value
+    pub error: Option<String>,
+}
+
 /// Modal state for list selections (log_level, theme)
 #[derive(Debug, Clone)]
 pub struct ModalState {
@@ -53,6 +76,15 @@ pub struct SettingsTabState {
     pub doc_nav: DocumentNavState,
     /// Modal state for list selections (log_level, theme)
     pub modal: Option<ModalState>,
+    /// Editor for a free-text setting, None when no value is being edited
+    pub edit: Option<EditState>,
+}
+
+impl SettingsTabState {
+    /// Whether the value editor is capturing keystrokes
+    pub fn is_editing(&self) -> bool {
+        self.edit.is_some()
+    }
 }
 
 impl TabState for SettingsTabState {
@@ -82,9 +114,11 @@ pub enum SettingsTabMsg {
     /// Change category (triggered by tab navigation)
     SetCategory(SettingsCategory),
     /// Activate the currently focused setting (includes config for modal initialization)
-    ActivateSetting(Config),
+    ActivateSetting(Arc<Config>),
     /// Modal navigation
     Modal(ModalMsg),
+    /// Value editor input
+    Edit(EditMsg),
 }
 
 impl TabMessage for SettingsTabMsg {
@@ -122,6 +156,7 @@ impl Component for SettingsTab {
         let doc = SettingsDocument::new(props.selected_category, props.config.clone());
         let mut state = SettingsTabState::default();
         state.doc_nav.focusable_positions = doc.focusable_positions();
+        state.doc_nav.content_height = doc.calculate_height();
         state.doc_nav.focusable_ids = doc.focusable_ids();
         state.doc_nav.focusable_row_positions = doc.focusable_row_positions();
         state
@@ -141,9 +176,10 @@ impl Component for SettingsTab {
             SettingsTabMsg::Key(key) => self.handle_key(key, state),
 
             SettingsTabMsg::NavigateUp => {
-                // Priority 1: Close modal if open
-                if state.modal.is_some() {
+                // Priority 1: Close modal or editor if open
+                if state.modal.is_some() || state.edit.is_some() {
                     state.modal = None;
+                    state.edit = None;
                     return Effect::Handled;
                 }
                 // Priority 2: Exit browse mode if active
@@ -166,7 +202,7 @@ impl Component for SettingsTab {
             }
             SettingsTabMsg::ActivateSetting(config) => {
                 use crate::tui::settings_helpers::{
-                    find_initial_modal_index, get_setting_modal_options,
+                    find_initial_modal_index, get_setting_modal_options, get_setting_text,
                 };
 
                 // Get the currently focused setting link
@@ -177,7 +213,7 @@ impl Component for SettingsTab {
                         // Parse the link ID which is the setting key (e.g., "log_level", "theme")
 
                         let effect = match link_id.as_str() {
-                            "use_unicode" | "western_teams_first" => {
+                            "use_unicode" | "western_teams_first" | "restore_session" => {
                                 Effect::Action(Action::SettingsAction(
                                     SettingsAction::ToggleBoolean(link_id.clone()),
                                 ))
@@ -204,6 +240,14 @@ impl Component for SettingsTab {
 
                                 Effect::None
                             }
+                            "log_file" | "refresh_interval" | "time_format" | "error_fg" => {
+                                state.edit = Some(EditState {
+                                    setting_key: link_id.clone(),
+                                    input: get_setting_text(&config, link_id).unwrap_or_default(),
+                                    error: None,
+                                });
+                                Effect::None
+                            }
                             _ => Effect::None,
                         };
                         return effect;
@@ -251,6 +295,46 @@ impl Component for SettingsTab {
                 }
             }
 
+            SettingsTabMsg::Edit(edit_msg) => {
+                use crate::tui::settings_helpers::validate_setting_text;
+
+                let Some(edit) = &mut state.edit else {
+                    return Effect::None;
+                };
+                match edit_msg {
+                    EditMsg::Char(c) => {
+                        if !c.is_control() {
+                            edit.input.push(c);
+                        }
+                        Effect::None
+                    }
+                    EditMsg::Backspace => {
+                        edit.input.pop();
+                        Effect::None
+                    }
+                    EditMsg::Submit => {
+                        match validate_setting_text(&edit.setting_key, &edit.input) {
+                            Ok(value) => {
+                                let key = edit.setting_key.clone();
+                                state.edit = None;
+                                Effect::Action(Action::SettingsAction(
+                                    SettingsAction::UpdateSetting { key, value },
+                                ))
+                            }
+                            Err(error) => {
+                                edit.error = Some(error);
+                                Effect::None
+                            }
+                        }
+                    }
+                    EditMsg::Cancel => {
+                        // Nothing is saved until submit, so closing keeps the prior value
+                        state.edit = None;
+                        Effect::None
+                    }
+                }
+            }
+
             // Common messages already handled above
             SettingsTabMsg::DocNav(_) | SettingsTabMsg::UpdateViewportHeight(_) => {
                 unreachable!("Common messages should be handled by handle_common_message")
@@ -291,6 +375,26 @@ impl Component for SettingsTab {
             &(),
         );
 
+        if let Some(edit) = &state.edit {
+            return vertical(
+                [Constraint::Length(1), Constraint::Min(0)],
+                vec![
+                    Element::Widget(Box::new(SettingEditWidget {
+                        label: edit_label(&edit.setting_key),
+                        input: edit.input.clone(),
+                        swatch: (edit.setting_key == "error_fg")
+                            .then(|| parse_color(&edit.input))
+                            .flatten(),
+                        preview: (edit.setting_key == "time_format")
+                            .then(|| format_time(&Local::now(), edit.input.trim()))
+                            .flatten(),
+                        error: edit.error.clone(),
+                    })),
+                    base_element,
+                ],
+            );
+        }
+
         // If modal is open, wrap in a widget that renders both the base and the modal
         if let Some(modal) = &state.modal {
             // Extract display names for the modal widget
@@ -319,6 +423,16 @@ impl SettingsTab {
         use crate::tui::action::{Action, SettingsAction};
         use crate::tui::nav_handler::key_to_nav_msg;
 
+        if state.is_editing() {
+            return match key.code {
+                KeyCode::Char(c) => self.update(SettingsTabMsg::Edit(EditMsg::Char(c)), state),
+                KeyCode::Backspace => self.update(SettingsTabMsg::Edit(EditMsg::Backspace), state),
+                KeyCode::Enter => self.update(SettingsTabMsg::Edit(EditMsg::Submit), state),
+                KeyCode::Esc => self.update(SettingsTabMsg::Edit(EditMsg::Cancel), state),
+                _ => Effect::None,
+            };
+        }
+
         // If modal is open, handle modal navigation
         if state.modal.is_some() {
             return match key.code {
@@ -399,6 +513,67 @@ impl SettingsTab {
     }
 }
 
+/// Prompt label for a setting's value editor
+fn edit_label(setting_key: &str) -> &'static str {
+    match setting_key {
+        "log_file" => "Log File",
+        "refresh_interval" => "Refresh Interval (seconds)",
+        "time_format" => "Time Format",
+        "error_fg" => "Error Color (name, #hex or r,g,b)",
+        _ => "Value",
+    }
+}
+
+/// One-line value editor with an inline validation error
+struct SettingEditWidget {
+    label: &'static str,
+    input: String,
+    /// Preview of the color typed so far, for color settings
+    swatch: Option<Color>,
+    /// The current time in the format typed so far, for time format settings
+    preview: Option<String>,
+    error: Option<String>,
+}
+
+impl ElementWidget for SettingEditWidget {
+    fn render(&self, area: Rect, buf: &mut Buffer, display_config: &DisplayConfig) {
+        let mut spans = vec![Span::styled(
+            format!("{}: {}█", self.label, self.input),
+            display_config.text_style(),
+        )];
+        if let Some(color) = self.swatch {
+            spans.push(Span::styled(" ██", Style::default().fg(color)));
+        }
+        if let Some(preview) = &self.preview {
+            spans.push(Span::styled(
+                format!("  → {}", preview),
+                display_config.text_style(),
+            ));
+        }
+        if let Some(error) = &self.error {
+            spans.push(Span::styled(
+                format!("  {}", error),
+                Style::default().fg(display_config.error_fg),
+            ));
+        }
+        Paragraph::new(Line::from(spans)).render(area, buf);
+    }
+
+    fn clone_box(&self) -> Box<dyn ElementWidget> {
+        Box::new(SettingEditWidget {
+            label: self.label,
+            input: self.input.clone(),
+            swatch: self.swatch,
+            preview: self.preview.clone(),
+            error: self.error.clone(),
+        })
+    }
+
+    fn preferred_height(&self) -> Option<u16> {
+        Some(1)
+    }
+}
+
 /// Widget for rendering the Settings tab with modal overlay
 struct SettingsTabWithModal {
     base_element: Element,
@@ -445,14 +620,15 @@ impl ElementWidget for SettingsTabWithModal {
 /// Widget for rendering the Settings tab content
 struct SettingsTabWidget {
     category: SettingsCategory,
-    config: Config,
+    config: Arc<Config>,
     focus_index: Option<usize>,
     scroll_offset: u16,
     viewport_height: u16,
 }
 
-impl ElementWidget for SettingsTabWidget {
-    fn render(&self, area: Rect, buf: &mut Buffer, config: &DisplayConfig) {
+impl SettingsTabWidget {
+    /// Document view for the current category with focus and scroll applied
+    fn view(&self, area: Rect) -> DocumentView {
         // Create document for the current category
         let doc = Arc::new(SettingsDocument::new(self.category, self.config.clone()));
         let mut view = DocumentView::new(doc, area.height);
@@ -464,8 +640,17 @@ impl ElementWidget for SettingsTabWidget {
 
         // Apply scroll offset
         view.set_scroll_offset(self.scroll_offset);
+        view
+    }
+}
 
-        view.render(area, buf, config);
+impl ElementWidget for SettingsTabWidget {
+    fn render(&self, area: Rect, buf: &mut Buffer, config: &DisplayConfig) {
+        self.view(area).render(area, buf, config);
+    }
+
+    fn hit_regions(&self, area: Rect, config: &DisplayConfig) -> Vec<HitRegion> {
+        self.view(area).hit_regions(area, config)
     }
 
     fn clone_box(&self) -> Box<dyn ElementWidget> {
@@ -494,11 +679,13 @@ fn get_focusable_ids_for_category(category: SettingsCategory) -> Vec<FocusableId
         SettingsCategory::Display => vec![
             FocusableId::Link("theme".to_string()),
             FocusableId::Link("use_unicode".to_string()),
+            FocusableId::Link("error_fg".to_string()),
         ],
         SettingsCategory::Data => vec![
             FocusableId::Link("refresh_interval".to_string()),
             FocusableId::Link("western_teams_first".to_string()),
             FocusableId::Link("time_format".to_string()),
+            FocusableId::Link("restore_session".to_string()),
         ],
     }
 }
@@ -510,7 +697,7 @@ mod tests {
     #[test]
     fn test_settings_tab_init() {
         let props = SettingsTabProps {
-            config: Config::default(),
+            config: Arc::new(Config::default()),
             selected_category: SettingsCategory::Logging,
             focused: false,
         };
@@ -524,7 +711,7 @@ mod tests {
     fn test_settings_tab_renders() {
         let settings_tab = SettingsTab;
         let props = SettingsTabProps {
-            config: Config::default(),
+            config: Arc::new(Config::default()),
             selected_category: SettingsCategory::Logging,
             focused: false,
         };
@@ -608,6 +795,133 @@ mod tests {
         assert!(matches!(effect, Effect::None));
     }
 
+    fn editing_state(input: &str) -> SettingsTabState {
+        SettingsTabState {
+            edit: Some(EditState {
+                setting_key: "refresh_interval".to_string(),
+                input: input.to_string(),
+                error: None,
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_activate_text_setting_opens_prefilled_editor() {
+        let mut component = SettingsTab;
+        let mut state = SettingsTabState::default();
+        state.doc_nav.focusable_ids = get_focusable_ids_for_category(SettingsCategory::Data);
+        state.doc_nav.focus_index = Some(2);
+        let config = Config {
+            time_format: "%H:%M".to_string(),
+            ..Config::default()
+        };
+
+        let effect = component.update(
+            SettingsTabMsg::ActivateSetting(Arc::new(config)),
+            &mut state,
+        );
+
+        assert!(matches!(effect, Effect::None));
+        assert_eq!(
+            state.edit,
+            Some(EditState {
+                setting_key: "time_format".to_string(),
+                input: "%H:%M".to_string(),
+                error: None,
+            })
+        );
+    }
+
+    #[test]
+    fn test_edit_submit_saves_validated_value() {
+        use crate::tui::action::{Action, SettingsAction};
+
+        let mut component = SettingsTab;
+        let mut state = editing_state("60");
+        for msg in [
+            EditMsg::Backspace,
+            EditMsg::Backspace,
+            EditMsg::Char('9'),
+            EditMsg::Char('0'),
+        ] {
+            component.update(SettingsTabMsg::Edit(msg), &mut state);
+        }
+
+        let effect = component.update(SettingsTabMsg::Edit(EditMsg::Submit), &mut state);
+
+        assert!(state.edit.is_none());
+        match effect {
+            Effect::Action(Action::SettingsAction(SettingsAction::UpdateSetting {
+                key,
+                value,
+            })) => {
+                assert_eq!(key, "refresh_interval");
+                assert_eq!(value, "90");
+            }
+            other => panic!("Expected UpdateSetting, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_edit_invalid_value_shows_error_until_cancelled() {
+        let mut component = SettingsTab;
+        let mut state = editing_state("soon");
+
+        let effect = component.update(SettingsTabMsg::Edit(EditMsg::Submit), &mut state);
+
+        assert!(matches!(effect, Effect::None));
+        assert_eq!(
+            state.edit.as_ref().and_then(|edit| edit.error.as_deref()),
+            Some("'soon' is not a number of seconds")
+        );
+
+        component.update(SettingsTabMsg::Edit(EditMsg::Cancel), &mut state);
+        assert!(!state.is_editing());
+    }
+
+    #[test]
+    fn test_setting_edit_widget_shows_error() {
+        use crate::tui::testing::assert_buffer;
+
+        let widget = SettingEditWidget {
+            label: edit_label("refresh_interval"),
+            input: "soon".to_string(),
+            swatch: None,
+            preview: None,
+            error: Some("'soon' is not a number of seconds".to_string()),
+        };
+        let config = DisplayConfig {
+            error_fg: ratatui::style::Color::Red,
+            ..DisplayConfig::default()
+        };
+        let mut buf = Buffer::empty(Rect::new(0, 0, 70, 1));
+        widget.render(buf.area, &mut buf, &config);
+
+        assert_buffer(
+            &buf,
+            &["Refresh Interval (seconds): soon█  'soon' is not a number of seconds"],
+        );
+        assert_eq!(buf[(35, 0)].fg, ratatui::style::Color::Red);
+    }
+
+    #[test]
+    fn test_setting_edit_widget_shows_preview() {
+        use crate::tui::testing::assert_buffer;
+
+        let widget = SettingEditWidget {
+            label: edit_label("time_format"),
+            input: "%I:%M %p".to_string(),
+            swatch: None,
+            preview: Some("07:05 PM".to_string()),
+            error: None,
+        };
+        let mut buf = Buffer::empty(Rect::new(0, 0, 40, 1));
+        widget.render(buf.area, &mut buf, &DisplayConfig::default());
+
+        assert_buffer(&buf, &["Time Format: %I:%M %p█  → 07:05 PM"]);
+    }
+
     #[test]
     fn test_get_focusable_ids_logging() {
         let ids = get_focusable_ids_for_category(SettingsCategory::Logging);
@@ -618,12 +932,12 @@ mod tests {
     #[test]
     fn test_get_focusable_ids_display() {
         let ids = get_focusable_ids_for_category(SettingsCategory::Display);
-        assert_eq!(ids.len(), 2);
+        assert_eq!(ids.len(), 3);
     }
 
     #[test]
     fn test_get_focusable_ids_data() {
         let ids = get_focusable_ids_for_category(SettingsCategory::Data);
-        assert_eq!(ids.len(), 3);
+        assert_eq!(ids.len(), 4);
     }
 }
@@ -6,20 +6,24 @@ use std::sync::Arc;
 use nhl_api::Standing;
 
 use crate::config::Config;
+use crate::team_abbrev::abbrev_to_conference;
 use crate::tui::document::{Document, DocumentBuilder, DocumentElement, FocusContext};
 use crate::tui::helpers::StandingsSorting;
 
-use super::{standings_columns, TableWidget};
+use super::{standings_columns, standings_version, TableWidget};
 
 /// Conference standings document - two tables side-by-side in a Row element
 pub struct ConferenceStandingsDocument {
     standings: Arc<Vec<Standing>>,
-    config: Config,
+    config: Arc<Config>,
 }
 
 impl ConferenceStandingsDocument {
-    pub fn new(standings: Arc<Vec<Standing>>, config: Config) -> Self {
-        Self { standings, config }
+    pub fn new(standings: Arc<Vec<Standing>>, config: impl Into<Arc<Config>>) -> Self {
+        Self {
+            standings,
+            config: config.into(),
+        }
     }
 
     /// Group standings by conference and return (Eastern, Western) sorted by points
@@ -29,6 +33,9 @@ impl ConferenceStandingsDocument {
             let conference = standing
                 .conference_name
                 .clone()
+                .or_else(|| {
+                    abbrev_to_conference(&standing.team_abbrev.default, None).map(str::to_string)
+                })
                 .unwrap_or_else(|| "Unknown".to_string());
             grouped
                 .entry(conference)
@@ -66,11 +73,13 @@ impl Document for ConferenceStandingsDocument {
 
         // Create left table
         let left_table = TableWidget::from_data(standings_columns(), left_teams)
-            .with_focused_row(focus.focused_table_row(LEFT_TABLE));
+            .with_focused_row(focus.focused_table_row(LEFT_TABLE))
+            .with_accented_teams(&self.config.known_favorite_teams());
 
         // Create right table
         let right_table = TableWidget::from_data(standings_columns(), right_teams)
-            .with_focused_row(focus.focused_table_row(RIGHT_TABLE));
+            .with_focused_row(focus.focused_table_row(RIGHT_TABLE))
+            .with_accented_teams(&self.config.known_favorite_teams());
 
         // Use Row element to place tables side-by-side with section titles
         // Section titles are indented by 2 to align with table content (after selector space)
@@ -102,4 +111,8 @@ impl Document for ConferenceStandingsDocument {
     fn id(&self) -> String {
         "conference_standings".to_string()
     }
+
+    fn content_version(&self) -> u64 {
+        standings_version(&self.standings, &self.config)
+    }
 }
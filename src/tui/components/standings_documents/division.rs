@@ -9,7 +9,7 @@ use crate::config::Config;
 use crate::tui::document::{Document, DocumentBuilder, DocumentElement, FocusContext};
 use crate::tui::helpers::StandingsSorting;
 
-use super::{standings_columns, TableWidget};
+use super::{standings_columns, standings_version, TableWidget};
 
 /// Division standings document - two columns with two divisions each
 ///
@@ -28,12 +28,15 @@ use super::{standings_columns, TableWidget};
 /// left column first, then down through right column).
 pub struct DivisionStandingsDocument {
     standings: Arc<Vec<Standing>>,
-    config: Config,
+    config: Arc<Config>,
 }
 
 impl DivisionStandingsDocument {
-    pub fn new(standings: Arc<Vec<Standing>>, config: Config) -> Self {
-        Self { standings, config }
+    pub fn new(standings: Arc<Vec<Standing>>, config: impl Into<Arc<Config>>) -> Self {
+        Self {
+            standings,
+            config: config.into(),
+        }
     }
 
     /// Group standings by division and return maps for each conference
@@ -74,34 +77,36 @@ impl DivisionStandingsDocument {
 
     /// Build a vertical group of division tables
     fn build_division_group(
+        &self,
         divisions: &[(&str, Vec<Standing>)],
         table_prefix: &str,
         focus: &FocusContext,
     ) -> DocumentElement {
         const MARGIN: u16 = 2;
-        let mut children = Vec::new();
+        let mut builder = DocumentBuilder::new().compact(self.config.display.compact_spacing);
 
         for (idx, (div_name, teams)) in divisions.iter().enumerate() {
             let table_name = format!("{}_{}", table_prefix, div_name.to_lowercase());
 
             // Add section title for division name (indented to align with table content)
-            children.push(DocumentElement::indented(
+            builder = builder.element(DocumentElement::indented(
                 DocumentElement::section_title(*div_name, false),
                 MARGIN,
             ));
 
             let table = TableWidget::from_data(standings_columns(), teams.clone())
-                .with_focused_row(focus.focused_table_row(&table_name));
+                .with_focused_row(focus.focused_table_row(&table_name))
+                .with_accented_teams(&self.config.known_favorite_teams());
 
-            children.push(DocumentElement::table(table_name, table));
+            builder = builder.table(table_name, table);
 
             // Add spacer between divisions (not after the last one)
             if idx < divisions.len() - 1 {
-                children.push(DocumentElement::spacer(1));
+                builder = builder.decorative_spacer(1);
             }
         }
 
-        DocumentElement::group(children)
+        DocumentElement::group(builder.build())
     }
 }
 
@@ -118,10 +123,10 @@ impl Document for DivisionStandingsDocument {
             };
 
         // Build left column (Group with 2 division tables)
-        let left_group = Self::build_division_group(&left_divs, left_prefix, focus);
+        let left_group = self.build_division_group(&left_divs, left_prefix, focus);
 
         // Build right column (Group with 2 division tables)
-        let right_group = Self::build_division_group(&right_divs, right_prefix, focus);
+        let right_group = self.build_division_group(&right_divs, right_prefix, focus);
 
         // Use Row element to place columns side-by-side
         DocumentBuilder::new()
@@ -136,4 +141,8 @@ impl Document for DivisionStandingsDocument {
     fn id(&self) -> String {
         "division_standings".to_string()
     }
+
+    fn content_version(&self) -> u64 {
+        standings_version(&self.standings, &self.config)
+    }
 }
@@ -7,18 +7,20 @@ use nhl_api::Standing;
 use crate::config::Config;
 use crate::tui::document::{Document, DocumentBuilder, DocumentElement, FocusContext};
 
-use super::{standings_columns, TableWidget};
+use super::{standings_columns, standings_version, TableWidget};
 
 /// League standings document - single table with all teams sorted by points
 pub struct LeagueStandingsDocument {
     standings: Arc<Vec<Standing>>,
-    #[allow(dead_code)] // Will be used for other standings views
-    config: Config,
+    config: Arc<Config>,
 }
 
 impl LeagueStandingsDocument {
-    pub fn new(standings: Arc<Vec<Standing>>, config: Config) -> Self {
-        Self { standings, config }
+    pub fn new(standings: Arc<Vec<Standing>>, config: impl Into<Arc<Config>>) -> Self {
+        Self {
+            standings,
+            config: config.into(),
+        }
     }
 }
 
@@ -26,8 +28,10 @@ impl Document for LeagueStandingsDocument {
     fn build(&self, focus: &FocusContext) -> Vec<DocumentElement> {
         let focused_row = focus.focused_table_row("league_standings");
 
-        let table = TableWidget::from_data(standings_columns(), self.standings.as_ref().clone())
-            .with_focused_row(focused_row);
+        let table = TableWidget::from_data(standings_columns(), self.standings.as_slice())
+            .with_focused_row(focused_row)
+            .with_zebra(true)
+            .with_accented_teams(&self.config.known_favorite_teams());
 
         DocumentBuilder::new()
             .table("league_standings", table)
@@ -41,4 +45,8 @@ impl Document for LeagueStandingsDocument {
     fn id(&self) -> String {
         "league_standings".to_string()
     }
+
+    fn content_version(&self) -> u64 {
+        standings_version(&self.standings, &self.config)
+    }
 }
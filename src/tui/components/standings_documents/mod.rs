@@ -8,6 +8,8 @@ mod division;
 mod league;
 mod wildcard;
 
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
 use std::sync::Arc;
 
 use nhl_api::Standing;
@@ -17,6 +19,7 @@ use ratatui::layout::Rect;
 use crate::config::{Config, DisplayConfig};
 use crate::tui::component::ElementWidget;
 use crate::tui::document::{Document, DocumentView};
+use crate::tui::hit_test::HitRegion;
 
 pub use conference::ConferenceStandingsDocument;
 pub use division::DivisionStandingsDocument;
@@ -25,6 +28,25 @@ pub use wildcard::WildcardStandingsDocument;
 
 use super::{standings_columns, TableWidget};
 
+/// Content version shared by the standings documents
+///
+/// Hashes the displayed cells of every row plus the grouping fields, the
+/// layout setting and the accented favorites, so any visible change produces
+/// a new version.
+fn standings_version(standings: &[Standing], config: &Config) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    for standing in standings {
+        for column in standings_columns() {
+            (column.cell_fn)(standing).display_text().hash(&mut hasher);
+        }
+        standing.conference_name.hash(&mut hasher);
+        standing.division_name.hash(&mut hasher);
+    }
+    config.display_standings_western_first.hash(&mut hasher);
+    config.favorite_teams.hash(&mut hasher);
+    hasher.finish()
+}
+
 /// Widget that renders a standings document with DocumentView
 ///
 /// This widget wraps DocumentView and applies focus/scroll state from AppState.
@@ -39,7 +61,7 @@ impl StandingsDocumentWidget {
     /// Create widget for League standings
     pub fn league(
         standings: Arc<Vec<Standing>>,
-        config: Config,
+        config: Arc<Config>,
         focus_index: Option<usize>,
         scroll_offset: u16,
     ) -> Self {
@@ -53,7 +75,7 @@ impl StandingsDocumentWidget {
     /// Create widget for Conference standings
     pub fn conference(
         standings: Arc<Vec<Standing>>,
-        config: Config,
+        config: Arc<Config>,
         focus_index: Option<usize>,
         scroll_offset: u16,
     ) -> Self {
@@ -67,7 +89,7 @@ impl StandingsDocumentWidget {
     /// Create widget for Division standings
     pub fn division(
         standings: Arc<Vec<Standing>>,
-        config: Config,
+        config: Arc<Config>,
         focus_index: Option<usize>,
         scroll_offset: u16,
     ) -> Self {
@@ -81,7 +103,7 @@ impl StandingsDocumentWidget {
     /// Create widget for Wildcard standings
     pub fn wildcard(
         standings: Arc<Vec<Standing>>,
-        config: Config,
+        config: Arc<Config>,
         focus_index: Option<usize>,
         scroll_offset: u16,
     ) -> Self {
@@ -93,8 +115,9 @@ impl StandingsDocumentWidget {
     }
 }
 
-impl ElementWidget for StandingsDocumentWidget {
-    fn render(&self, area: Rect, buf: &mut Buffer, display_config: &DisplayConfig) {
+impl StandingsDocumentWidget {
+    /// Document view with focus and scroll state from AppState applied
+    fn view(&self, area: Rect) -> DocumentView {
         // Create DocumentView with viewport height
         let mut view = DocumentView::new(self.doc.clone(), area.height);
 
@@ -105,9 +128,18 @@ impl ElementWidget for StandingsDocumentWidget {
 
         // Apply scroll offset from AppState
         view.set_scroll_offset(self.scroll_offset);
+        view
+    }
+}
 
+impl ElementWidget for StandingsDocumentWidget {
+    fn render(&self, area: Rect, buf: &mut Buffer, display_config: &DisplayConfig) {
         // Render the document
-        view.render(area, buf, display_config);
+        self.view(area).render(area, buf, display_config);
+    }
+
+    fn hit_regions(&self, area: Rect, display_config: &DisplayConfig) -> Vec<HitRegion> {
+        self.view(area).hit_regions(area, display_config)
     }
 
     fn clone_box(&self) -> Box<dyn ElementWidget> {
@@ -129,6 +161,7 @@ mod tests {
     use crate::config::DisplayConfig;
     use crate::tui::document::{DocumentElement, FocusContext};
     use crate::tui::testing::{assert_buffer, create_test_standings};
+    use ratatui::style::Modifier;
     use std::sync::Arc;
 
     #[test]
@@ -161,7 +194,7 @@ mod tests {
         let doc = LeagueStandingsDocument::new(standings, config);
 
         let display_config = DisplayConfig::default();
-        let (buf, height) = doc.render_full(60, &display_config, &FocusContext::default());
+        let (buf, height) = doc.render_full(70, &display_config, &FocusContext::default());
 
         // Height should be: column headers (1) + separator (1) + 32 teams = 34 lines
         assert_eq!(height, 34);
@@ -170,40 +203,40 @@ mod tests {
         assert_buffer(
             &buf,
             &[
-                "  Team                          GP     W    L   OT    PTS",
-                "  ───────────────────────────────────────────────────────",
-                "  Panthers                      19    14    3    2     30",
-                "  Bruins                        18    13    4    1     27",
-                "  Maple Leafs                   19    12    5    2     26",
-                "  Lightning                     18    11    6    1     23",
-                "  Canadiens                     18    10    5    3     23",
-                "  Senators                      18     9    7    2     20",
-                "  Red Wings                     18     8    8    2     18",
-                "  Sabres                        18     6   10    2     14",
-                "  Devils                        18    15    2    1     31",
-                "  Hurricanes                    19    14    3    2     30",
-                "  Rangers                       18    12    5    1     25",
-                "  Penguins                      19    11    6    2     24",
-                "  Capitals                      18    10    7    1     21",
-                "  Islanders                     18     9    7    2     20",
-                "  Flyers                        18     8    9    1     17",
-                "  Blue Jackets                  18     5   11    2     12",
-                "  Avalanche                     19    16    2    1     33",
-                "  Stars                         20    14    4    2     30",
-                "  Jets                          19    13    5    1     27",
-                "  Wild                          19    11    6    2     24",
-                "  Predators                     19    10    7    2     22",
-                "  Blues                         19     8    8    3     19",
-                "  Blackhawks                    18     7   10    1     15",
-                "  Coyotes                       18     4   13    1      9",
-                "  Golden Knights                19    15    3    1     31",
-                "  Oilers                        20    14    4    2     30",
-                "  Kings                         19    12    6    1     25",
-                "  Kraken                        19    11    6    2     24",
-                "  Canucks                       19    10    7    2     22",
-                "  Flames                        19     9    8    2     20",
-                "  Ducks                         19     7   10    2     16",
-                "  Sharks                        18     5   12    1     11",
+                "  Team                          GP     W    L   OT    PTS   PACE",
+                "  ──────────────────────────────────────────────────────────────",
+                "  Panthers                      19    14    3    2     30    129",
+                "  Bruins                        18    13    4    1     27    123",
+                "  Maple Leafs                   19    12    5    2     26    112",
+                "  Lightning                     18    11    6    1     23    105",
+                "  Canadiens                     18    10    5    3     23    105",
+                "  Senators                      18     9    7    2     20     91",
+                "  Red Wings                     18     8    8    2     18     82",
+                "  Sabres                        18     6   10    2     14     64",
+                "  Devils                        18    15    2    1     31    141",
+                "  Hurricanes                    19    14    3    2     30    129",
+                "  Rangers                       18    12    5    1     25    114",
+                "  Penguins                      19    11    6    2     24    104",
+                "  Capitals                      18    10    7    1     21     96",
+                "  Islanders                     18     9    7    2     20     91",
+                "  Flyers                        18     8    9    1     17     77",
+                "  Blue Jackets                  18     5   11    2     12     55",
+                "  Avalanche                     19    16    2    1     33    142",
+                "  Stars                         20    14    4    2     30    123",
+                "  Jets                          19    13    5    1     27    117",
+                "  Wild                          19    11    6    2     24    104",
+                "  Predators                     19    10    7    2     22     95",
+                "  Blues                         19     8    8    3     19     82",
+                "  Blackhawks                    18     7   10    1     15     68",
+                "  Coyotes                       18     4   13    1      9     41",
+                "  Golden Knights                19    15    3    1     31    134",
+                "  Oilers                        20    14    4    2     30    123",
+                "  Kings                         19    12    6    1     25    108",
+                "  Kraken                        19    11    6    2     24    104",
+                "  Canucks                       19    10    7    2     22     95",
+                "  Flames                        19     9    8    2     20     86",
+                "  Ducks                         19     7   10    2     16     69",
+                "  Sharks                        18     5   12    1     11     50",
             ],
         );
     }
@@ -239,6 +272,28 @@ mod tests {
         assert_eq!(doc.id(), "league_standings");
     }
 
+    #[test]
+    fn test_standings_cache_key_follows_rows() {
+        let config = Config::default();
+        let a = LeagueStandingsDocument::new(Arc::new(create_test_standings()), config.clone());
+        let b = LeagueStandingsDocument::new(Arc::new(create_test_standings()), config.clone());
+        assert_eq!(a.cache_key(), b.cache_key());
+
+        let mut changed = create_test_standings();
+        changed[0].points += 2;
+        let c = LeagueStandingsDocument::new(Arc::new(changed), config);
+        assert_ne!(a.cache_key(), c.cache_key());
+    }
+
+    #[test]
+    fn test_standings_cache_key_differs_between_views() {
+        let standings = Arc::new(create_test_standings());
+        let config = Config::default();
+        let league = LeagueStandingsDocument::new(standings.clone(), config.clone());
+        let conference = ConferenceStandingsDocument::new(standings, config);
+        assert_ne!(league.cache_key(), conference.cache_key());
+    }
+
     #[test]
     fn test_league_standings_focusable_positions() {
         let standings = Arc::new(create_test_standings());
@@ -481,6 +536,18 @@ mod tests {
         assert_eq!(doc.id(), "division_standings");
     }
 
+    #[test]
+    fn test_division_standings_compact_spacing_uses_fewer_rows() {
+        let standings = Arc::new(create_test_standings());
+        let mut config = Config::default();
+        let default = DivisionStandingsDocument::new(standings.clone(), config.clone());
+        config.display.compact_spacing = true;
+        let compact = DivisionStandingsDocument::new(standings, config);
+
+        assert!(compact.calculate_height() < default.calculate_height());
+        assert_eq!(compact.focusable_positions().len(), 32);
+    }
+
     #[test]
     fn test_division_standings_focusable_positions() {
         let standings = Arc::new(create_test_standings());
@@ -558,4 +625,72 @@ mod tests {
             _ => panic!("Expected Row element"),
         }
     }
+
+    #[test]
+    fn test_wildcard_standings_emphasizes_playoff_teams() {
+        let standings = Arc::new(create_test_standings());
+        let doc = WildcardStandingsDocument::new(standings, Config::default());
+
+        let mut display = DisplayConfig {
+            theme_name: Some("orange".to_string()),
+            ..DisplayConfig::default()
+        };
+        display.apply_theme();
+        let theme = display.theme.clone().unwrap();
+        let (buf, height) = doc.render_full(120, &display, &FocusContext::default());
+
+        let fg_of = |name: &str| {
+            (0..height)
+                .find_map(|y| {
+                    let line: String = (0..buf.area.width)
+                        .map(|x| buf[(x, y)].symbol().to_string())
+                        .collect();
+                    line.find(name)
+                        .map(|x| buf[(line[..x].chars().count() as u16, y)].fg)
+                })
+                .unwrap()
+        };
+
+        // Division leaders and the top two wild cards are in playoff position
+        assert_eq!(fg_of("Panthers"), theme.emphasis_fg());
+        assert_eq!(fg_of("Penguins"), theme.emphasis_fg());
+        assert_eq!(fg_of("Lightning"), theme.emphasis_fg());
+        // The rest of the wild card race is out
+        assert_eq!(fg_of("Canadiens"), theme.fg2);
+        assert_eq!(fg_of("Sharks"), theme.fg2);
+    }
+
+    #[test]
+    fn test_standings_accent_favorite_teams() {
+        let standings = Arc::new(create_test_standings());
+        let config = Config {
+            favorite_teams: vec!["TOR".to_string(), "MTL".to_string()],
+            ..Config::default()
+        };
+        let doc = WildcardStandingsDocument::new(standings.clone(), config.clone());
+        assert_ne!(
+            doc.cache_key(),
+            WildcardStandingsDocument::new(standings, Config::default()).cache_key()
+        );
+
+        let display = DisplayConfig::default();
+        let (buf, height) = doc.render_full(120, &display, &FocusContext::default());
+        let modifier_of = |name: &str| {
+            (0..height)
+                .find_map(|y| {
+                    let line: String = (0..buf.area.width)
+                        .map(|x| buf[(x, y)].symbol().to_string())
+                        .collect();
+                    line.find(name)
+                        .map(|x| buf[(line[..x].chars().count() as u16, y)].modifier)
+                })
+                .unwrap()
+        };
+
+        // Favorites are accented whether or not they hold a playoff spot
+        assert!(modifier_of("Maple Leafs").contains(Modifier::ITALIC));
+        assert!(modifier_of("Canadiens").contains(Modifier::ITALIC));
+        assert!(!modifier_of("Bruins").contains(Modifier::ITALIC));
+        assert!(!modifier_of("Senators").contains(Modifier::ITALIC));
+    }
 }
@@ -9,7 +9,13 @@ use crate::config::Config;
 use crate::tui::document::{Document, DocumentBuilder, DocumentElement, FocusContext};
 use crate::tui::helpers::StandingsSorting;
 
-use super::{standings_columns, TableWidget};
+use super::{standings_columns, standings_version, TableWidget};
+
+/// Guaranteed playoff spots per division
+const DIVISION_PLAYOFF_SPOTS: usize = 3;
+
+/// Wild card playoff spots per conference
+const WILDCARD_PLAYOFF_SPOTS: usize = 2;
 
 /// Wildcard standings document - two columns showing playoff picture
 ///
@@ -28,14 +34,19 @@ use super::{standings_columns, TableWidget};
 /// 1. Division 1 top 3 teams (guaranteed playoff spots)
 /// 2. Division 2 top 3 teams (guaranteed playoff spots)
 /// 3. Wildcard section with remaining teams sorted by points
+///
+/// Teams in playoff position are drawn with the theme's emphasis color.
 pub struct WildcardStandingsDocument {
     standings: Arc<Vec<Standing>>,
-    config: Config,
+    config: Arc<Config>,
 }
 
 impl WildcardStandingsDocument {
-    pub fn new(standings: Arc<Vec<Standing>>, config: Config) -> Self {
-        Self { standings, config }
+    pub fn new(standings: Arc<Vec<Standing>>, config: impl Into<Arc<Config>>) -> Self {
+        Self {
+            standings,
+            config: config.into(),
+        }
     }
 
     /// Group standings by division and return sorted teams for each division
@@ -63,6 +74,7 @@ impl WildcardStandingsDocument {
 
     /// Build a wildcard conference column (div1 top 3 + div2 top 3 + wildcards)
     fn build_wildcard_group(
+        &self,
         div1_name: &str,
         div1_teams: &[Standing],
         div2_name: &str,
@@ -71,55 +83,75 @@ impl WildcardStandingsDocument {
         focus: &FocusContext,
     ) -> DocumentElement {
         const MARGIN: u16 = 2;
-        let mut children = Vec::new();
+        let mut builder = DocumentBuilder::new().compact(self.config.display.compact_spacing);
 
         // Division 1 - top 3 teams
-        let div1_top3: Vec<_> = div1_teams.iter().take(3).cloned().collect();
+        let div1_top3: Vec<_> = div1_teams
+            .iter()
+            .take(DIVISION_PLAYOFF_SPOTS)
+            .cloned()
+            .collect();
         if !div1_top3.is_empty() {
             let table_name = format!("{}_{}", table_prefix, div1_name.to_lowercase());
-            children.push(DocumentElement::indented(
+            builder = builder.element(DocumentElement::indented(
                 DocumentElement::section_title(div1_name, false),
                 MARGIN,
             ));
             let table = TableWidget::from_data(standings_columns(), div1_top3)
-                .with_focused_row(focus.focused_table_row(&table_name));
-            children.push(DocumentElement::table(table_name, table));
-            children.push(DocumentElement::spacer(1));
+                .with_emphasized_rows(DIVISION_PLAYOFF_SPOTS)
+                .with_focused_row(focus.focused_table_row(&table_name))
+                .with_accented_teams(&self.config.known_favorite_teams());
+            builder = builder.table(table_name, table).decorative_spacer(1);
         }
 
         // Division 2 - top 3 teams
-        let div2_top3: Vec<_> = div2_teams.iter().take(3).cloned().collect();
+        let div2_top3: Vec<_> = div2_teams
+            .iter()
+            .take(DIVISION_PLAYOFF_SPOTS)
+            .cloned()
+            .collect();
         if !div2_top3.is_empty() {
             let table_name = format!("{}_{}", table_prefix, div2_name.to_lowercase());
-            children.push(DocumentElement::indented(
+            builder = builder.element(DocumentElement::indented(
                 DocumentElement::section_title(div2_name, false),
                 MARGIN,
             ));
             let table = TableWidget::from_data(standings_columns(), div2_top3)
-                .with_focused_row(focus.focused_table_row(&table_name));
-            children.push(DocumentElement::table(table_name, table));
-            children.push(DocumentElement::spacer(1));
+                .with_emphasized_rows(DIVISION_PLAYOFF_SPOTS)
+                .with_focused_row(focus.focused_table_row(&table_name))
+                .with_accented_teams(&self.config.known_favorite_teams());
+            builder = builder.table(table_name, table).decorative_spacer(1);
         }
 
         // Wildcard section - remaining teams from both divisions, sorted by points
-        let div1_remaining: Vec<_> = div1_teams.iter().skip(3).cloned().collect();
-        let div2_remaining: Vec<_> = div2_teams.iter().skip(3).cloned().collect();
+        let div1_remaining: Vec<_> = div1_teams
+            .iter()
+            .skip(DIVISION_PLAYOFF_SPOTS)
+            .cloned()
+            .collect();
+        let div2_remaining: Vec<_> = div2_teams
+            .iter()
+            .skip(DIVISION_PLAYOFF_SPOTS)
+            .cloned()
+            .collect();
 
         let mut wildcard_teams: Vec<_> = div1_remaining.into_iter().chain(div2_remaining).collect();
         wildcard_teams.sort_by_points_desc();
 
         if !wildcard_teams.is_empty() {
             let table_name = format!("{}_wildcard", table_prefix);
-            children.push(DocumentElement::indented(
+            builder = builder.element(DocumentElement::indented(
                 DocumentElement::section_title("Wildcard", false),
                 MARGIN,
             ));
             let table = TableWidget::from_data(standings_columns(), wildcard_teams)
-                .with_focused_row(focus.focused_table_row(&table_name));
-            children.push(DocumentElement::table(table_name, table));
+                .with_emphasized_rows(WILDCARD_PLAYOFF_SPOTS)
+                .with_focused_row(focus.focused_table_row(&table_name))
+                .with_accented_teams(&self.config.known_favorite_teams());
+            builder = builder.table(table_name, table);
         }
 
-        DocumentElement::group(children)
+        DocumentElement::group(builder.build())
     }
 }
 
@@ -130,7 +162,7 @@ impl Document for WildcardStandingsDocument {
         // Determine column order based on western_first config
         let (left_group, right_group) = if self.config.display_standings_western_first {
             // Western left, Eastern right
-            let western = Self::build_wildcard_group(
+            let western = self.build_wildcard_group(
                 "Central",
                 &central,
                 "Pacific",
@@ -138,7 +170,7 @@ impl Document for WildcardStandingsDocument {
                 "wildcard_left",
                 focus,
             );
-            let eastern = Self::build_wildcard_group(
+            let eastern = self.build_wildcard_group(
                 "Atlantic",
                 &atlantic,
                 "Metropolitan",
@@ -149,7 +181,7 @@ impl Document for WildcardStandingsDocument {
             (western, eastern)
         } else {
             // Eastern left, Western right
-            let eastern = Self::build_wildcard_group(
+            let eastern = self.build_wildcard_group(
                 "Atlantic",
                 &atlantic,
                 "Metropolitan",
@@ -157,7 +189,7 @@ impl Document for WildcardStandingsDocument {
                 "wildcard_left",
                 focus,
             );
-            let western = Self::build_wildcard_group(
+            let western = self.build_wildcard_group(
                 "Central",
                 &central,
                 "Pacific",
@@ -181,4 +213,8 @@ impl Document for WildcardStandingsDocument {
     fn id(&self) -> String {
         "wildcard_standings".to_string()
     }
+
+    fn content_version(&self) -> u64 {
+        standings_version(&self.standings, &self.config)
+    }
 }
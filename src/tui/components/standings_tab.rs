@@ -14,7 +14,7 @@ use crate::config::DisplayConfig;
 use crate::tui::{
     component::{Component, Element, ElementWidget},
     state::DocumentStackEntry,
-    widgets::{LoadingAnimation, StandaloneWidget},
+    widgets::{EmptyState, LoadingAnimation, StandaloneWidget},
 };
 
 use super::{TabItem, TabbedPanel, TabbedPanelProps};
@@ -76,6 +76,9 @@ pub enum StandingsTabMsg {
 
     // Activate the currently focused team (push TeamDetail document)
     ActivateTeam,
+
+    /// Show standings this many seasons back (negative) or forward
+    ShiftSeason(i64),
 }
 
 impl TabMessage for StandingsTabMsg {
@@ -100,14 +103,16 @@ component_message_impl!(StandingsTabMsg, StandingsTab, StandingsTabState);
 #[derive(Clone)]
 pub struct StandingsTabProps {
     // API data
-    pub standings: Arc<Option<Vec<Standing>>>,
+    pub standings: Option<Arc<Vec<Standing>>>,
     // Navigation state
     pub document_stack: Vec<DocumentStackEntry>,
     pub focused: bool,
     // Config
-    pub config: Config,
+    pub config: Arc<Config>,
     // Animation frame for loading indicator
     pub animation_frame: u8,
+    /// Why the standings couldn't be loaded, shown instead of the loading animation
+    pub load_error: Option<String>,
 }
 
 /// StandingsTab component - renders standings with view selector
@@ -165,6 +170,11 @@ impl Component for StandingsTab {
                 state.exit_browse_mode();
                 Effect::None
             }
+            StandingsTabMsg::ShiftSeason(delta) => {
+                // The teams are about to be replaced by another season's
+                state.exit_browse_mode();
+                Effect::Action(Action::ShiftSeason(delta))
+            }
 
             StandingsTabMsg::ActivateTeam => {
                 // Get the team abbreviation from the focused element's link target
@@ -220,6 +230,8 @@ impl StandingsTab {
             // Handle Enter to activate focused element
             match key.code {
                 KeyCode::Enter => self.update(StandingsTabMsg::ActivateTeam, state),
+                KeyCode::Char('[') => self.update(StandingsTabMsg::ShiftSeason(-1), state),
+                KeyCode::Char(']') => self.update(StandingsTabMsg::ShiftSeason(1), state),
                 _ => Effect::None,
             }
         } else {
@@ -230,6 +242,8 @@ impl StandingsTab {
                 KeyCode::Down | KeyCode::Enter => {
                     self.update(StandingsTabMsg::EnterBrowseMode, state)
                 }
+                KeyCode::Char('[') => self.update(StandingsTabMsg::ShiftSeason(-1), state),
+                KeyCode::Char(']') => self.update(StandingsTabMsg::ShiftSeason(1), state),
                 _ => Effect::None,
             }
         }
@@ -276,15 +290,20 @@ impl StandingsTab {
         view: &GroupBy,
     ) -> Element {
         // If no standings data, show loading animation
-        let Some(standings) = props.standings.as_ref().as_ref() else {
+        let Some(standings) = props.standings.as_ref() else {
+            if let Some(error) = &props.load_error {
+                return Element::Widget(Box::new(LoadingWidget {
+                    message: error.clone(),
+                }));
+            }
             return Element::Widget(Box::new(AnimatedLoadingWidget {
                 animation_frame: props.animation_frame,
             }));
         };
 
         if standings.is_empty() {
-            return Element::Widget(Box::new(LoadingWidget {
-                message: "No standings available".to_string(),
+            return Element::Widget(Box::new(EmptyStateWidget {
+                empty_state: EmptyState::new("No standings available"),
             }));
         }
 
@@ -300,12 +319,12 @@ impl StandingsTab {
         &self,
         props: &StandingsTabProps,
         state: &StandingsTabState,
-        standings: &[Standing],
+        standings: &Arc<Vec<Standing>>,
     ) -> Element {
         use super::StandingsDocumentWidget;
 
         Element::Widget(Box::new(StandingsDocumentWidget::league(
-            Arc::new(standings.to_vec()),
+            Arc::clone(standings),
             props.config.clone(),
             state.doc_nav.focus_index,
             state.doc_nav.scroll_offset,
@@ -316,13 +335,13 @@ impl StandingsTab {
         &self,
         props: &StandingsTabProps,
         state: &StandingsTabState,
-        standings: &[Standing],
+        standings: &Arc<Vec<Standing>>,
     ) -> Element {
         // Use the document system for Conference view (like League view)
         use super::StandingsDocumentWidget;
 
         Element::Widget(Box::new(StandingsDocumentWidget::conference(
-            Arc::new(standings.to_vec()),
+            Arc::clone(standings),
             props.config.clone(),
             state.doc_nav.focus_index,
             state.doc_nav.scroll_offset,
@@ -333,13 +352,13 @@ impl StandingsTab {
         &self,
         props: &StandingsTabProps,
         state: &StandingsTabState,
-        standings: &[Standing],
+        standings: &Arc<Vec<Standing>>,
     ) -> Element {
         // Use the document system for Division view
         use super::StandingsDocumentWidget;
 
         Element::Widget(Box::new(StandingsDocumentWidget::division(
-            Arc::new(standings.to_vec()),
+            Arc::clone(standings),
             props.config.clone(),
             state.doc_nav.focus_index,
             state.doc_nav.scroll_offset,
@@ -350,13 +369,13 @@ impl StandingsTab {
         &self,
         props: &StandingsTabProps,
         state: &StandingsTabState,
-        standings: &[Standing],
+        standings: &Arc<Vec<Standing>>,
     ) -> Element {
         // Use the document system for Wildcard view
         use super::StandingsDocumentWidget;
 
         Element::Widget(Box::new(StandingsDocumentWidget::wildcard(
-            Arc::new(standings.to_vec()),
+            Arc::clone(standings),
             props.config.clone(),
             state.doc_nav.focus_index,
             state.doc_nav.scroll_offset,
@@ -373,6 +392,11 @@ impl StandingsTab {
                 super::super::StackedDocument::PlayerDetail { player_id, .. } => {
                     format!("Player Detail: {}\n\n(Document rendering not yet implemented)\n\nPress ESC to go back", player_id)
                 }
+                super::super::StackedDocument::PlayerCompare {
+                    player_a, player_b, ..
+                } => {
+                    format!("Player Compare: {} vs {}\n\n(Document rendering not yet implemented)\n\nPress ESC to go back", player_a, player_b)
+                }
                 super::super::StackedDocument::Boxscore { game_id, .. } => {
                     format!("Boxscore: {}\n\n(Document rendering not yet implemented)\n\nPress ESC to go back", game_id)
                 }
@@ -424,6 +448,23 @@ impl ElementWidget for LoadingWidget {
     }
 }
 
+/// Empty state for standings that loaded without any teams
+struct EmptyStateWidget {
+    empty_state: EmptyState,
+}
+
+impl ElementWidget for EmptyStateWidget {
+    fn render(&self, area: Rect, buf: &mut Buffer, config: &DisplayConfig) {
+        self.empty_state.render(area, buf, config);
+    }
+
+    fn clone_box(&self) -> Box<dyn ElementWidget> {
+        Box::new(EmptyStateWidget {
+            empty_state: self.empty_state.clone(),
+        })
+    }
+}
+
 /// Stacked document widget placeholder
 struct StackedDocumentWidget {
     message: String,
@@ -450,7 +491,7 @@ impl ElementWidget for StackedDocumentWidget {
 mod tests {
     use super::*;
     use crate::tui::renderer::Renderer;
-    use crate::tui::testing::{assert_buffer, create_test_standings};
+    use crate::tui::testing::{assert_buffer, buffer_lines, create_test_standings};
     use ratatui::{buffer::Buffer, layout::Rect};
     const RENDER_WIDTH: u16 = 120;
     const RENDER_HEIGHT: u16 = 40;
@@ -459,11 +500,12 @@ mod tests {
     fn test_standings_tab_renders_with_no_standings() {
         let standings_tab = StandingsTab;
         let props = StandingsTabProps {
-            standings: Arc::new(None),
+            standings: None,
             document_stack: Vec::new(),
             focused: false,
-            config: Config::default(),
+            config: Arc::new(Config::default()),
             animation_frame: 0,
+            load_error: None,
         };
 
         let element = standings_tab.view(&props, &StandingsTabState::default());
@@ -476,17 +518,42 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_empty_standings_render_empty_state() {
+        let props = StandingsTabProps {
+            standings: Some(Arc::new(Vec::new())),
+            document_stack: Vec::new(),
+            focused: false,
+            config: Arc::new(Config::default()),
+            animation_frame: 0,
+            load_error: None,
+        };
+
+        let element = StandingsTab.view(&props, &StandingsTabState::default());
+        let buf = render_element_to_buffer(&element, 40, 10, &DisplayConfig::default());
+
+        let lines = buffer_lines(&buf);
+        assert!(
+            lines
+                .iter()
+                .any(|line| line.trim() == "No standings available" && line.starts_with("  ")),
+            "{:?}",
+            lines
+        );
+    }
+
     #[test]
     fn test_standings_tab_renders_league_view() {
         let standings_tab = StandingsTab;
         let standings = create_test_standings();
 
         let props = StandingsTabProps {
-            standings: Arc::new(Some(standings)),
+            standings: Some(Arc::new(standings)),
             document_stack: Vec::new(),
             focused: false,
-            config: Config::default(),
+            config: Arc::new(Config::default()),
             animation_frame: 0,
+            load_error: None,
         };
 
         // This should not panic - verifies TableWidget can be created
@@ -521,11 +588,12 @@ mod tests {
         let standings = create_test_standings();
 
         let props = StandingsTabProps {
-            standings: Arc::new(Some(standings)),
+            standings: Some(Arc::new(standings)),
             document_stack: Vec::new(),
             focused: false,
-            config: Config::default(),
+            config: Arc::new(Config::default()),
             animation_frame: 0,
+            load_error: None,
         };
 
         let state = StandingsTabState {
@@ -540,40 +608,40 @@ mod tests {
         assert_buffer(&buf, &[
             "Wildcard │ Division │ Conference │ League",
             "─────────┴──────────┴────────────┴──────────────────────────────────────────────────────────────────────────────────────",
-            "  Team                          GP     W    L   OT    PTS",
-            "  ───────────────────────────────────────────────────────",
-            "  Panthers                      19    14    3    2     30",
-            "  Bruins                        18    13    4    1     27",
-            "  Maple Leafs                   19    12    5    2     26",
-            "  Lightning                     18    11    6    1     23",
-            "  Canadiens                     18    10    5    3     23",
-            "  Senators                      18     9    7    2     20",
-            "  Red Wings                     18     8    8    2     18",
-            "  Sabres                        18     6   10    2     14",
-            "  Devils                        18    15    2    1     31",
-            "  Hurricanes                    19    14    3    2     30",
-            "  Rangers                       18    12    5    1     25",
-            "  Penguins                      19    11    6    2     24",
-            "  Capitals                      18    10    7    1     21",
-            "  Islanders                     18     9    7    2     20",
-            "  Flyers                        18     8    9    1     17",
-            "  Blue Jackets                  18     5   11    2     12",
-            "  Avalanche                     19    16    2    1     33",
-            "  Stars                         20    14    4    2     30",
-            "  Jets                          19    13    5    1     27",
-            "  Wild                          19    11    6    2     24",
-            "  Predators                     19    10    7    2     22",
-            "  Blues                         19     8    8    3     19",
-            "  Blackhawks                    18     7   10    1     15",
-            "  Coyotes                       18     4   13    1      9",
-            "  Golden Knights                19    15    3    1     31",
-            "  Oilers                        20    14    4    2     30",
-            "  Kings                         19    12    6    1     25",
-            "  Kraken                        19    11    6    2     24",
-            "  Canucks                       19    10    7    2     22",
-            "  Flames                        19     9    8    2     20",
-            "  Ducks                         19     7   10    2     16",
-            "  Sharks                        18     5   12    1     11",
+            "  Team                          GP     W    L   OT    PTS   PACE",
+            "  ──────────────────────────────────────────────────────────────",
+            "  Panthers                      19    14    3    2     30    129",
+            "  Bruins                        18    13    4    1     27    123",
+            "  Maple Leafs                   19    12    5    2     26    112",
+            "  Lightning                     18    11    6    1     23    105",
+            "  Canadiens                     18    10    5    3     23    105",
+            "  Senators                      18     9    7    2     20     91",
+            "  Red Wings                     18     8    8    2     18     82",
+            "  Sabres                        18     6   10    2     14     64",
+            "  Devils                        18    15    2    1     31    141",
+            "  Hurricanes                    19    14    3    2     30    129",
+            "  Rangers                       18    12    5    1     25    114",
+            "  Penguins                      19    11    6    2     24    104",
+            "  Capitals                      18    10    7    1     21     96",
+            "  Islanders                     18     9    7    2     20     91",
+            "  Flyers                        18     8    9    1     17     77",
+            "  Blue Jackets                  18     5   11    2     12     55",
+            "  Avalanche                     19    16    2    1     33    142",
+            "  Stars                         20    14    4    2     30    123",
+            "  Jets                          19    13    5    1     27    117",
+            "  Wild                          19    11    6    2     24    104",
+            "  Predators                     19    10    7    2     22     95",
+            "  Blues                         19     8    8    3     19     82",
+            "  Blackhawks                    18     7   10    1     15     68",
+            "  Coyotes                       18     4   13    1      9     41",
+            "  Golden Knights                19    15    3    1     31    134",
+            "  Oilers                        20    14    4    2     30    123",
+            "  Kings                         19    12    6    1     25    108",
+            "  Kraken                        19    11    6    2     24    104",
+            "  Canucks                       19    10    7    2     22     95",
+            "  Flames                        19     9    8    2     20     86",
+            "  Ducks                         19     7   10    2     16     69",
+            "  Sharks                        18     5   12    1     11     50",
             "",
             "",
             "",
@@ -587,11 +655,12 @@ mod tests {
         let standings = create_test_standings();
 
         let props = StandingsTabProps {
-            standings: Arc::new(Some(standings)),
+            standings: Some(Arc::new(standings)),
             document_stack: Vec::new(),
             focused: false,
-            config: Config::default(),
+            config: Arc::new(Config::default()),
             animation_frame: 0,
+            load_error: None,
         };
 
         let state = StandingsTabState {
@@ -612,7 +681,7 @@ mod tests {
             "─────────┴──────────┴────────────┴──────────────────────────────────────────────────────────────────────────────────────",
             "  Atlantic                                                     Central",
             "",
-            "  Team                          GP     W    L   OT    PTS      Team                          GP     W    L   OT    PTS",
+            "  Team                          GP     W    L   OT    PTS ▸    Team                          GP     W    L   OT    PTS ▸",
             "  ───────────────────────────────────────────────────────      ───────────────────────────────────────────────────────",
             "  Panthers                      19    14    3    2     30      Avalanche                     19    16    2    1     33",
             "  Bruins                        18    13    4    1     27      Stars                         20    14    4    2     30",
@@ -625,7 +694,7 @@ mod tests {
             "",
             "  Metropolitan                                                 Pacific",
             "",
-            "  Team                          GP     W    L   OT    PTS      Team                          GP     W    L   OT    PTS",
+            "  Team                          GP     W    L   OT    PTS ▸    Team                          GP     W    L   OT    PTS ▸",
             "  ───────────────────────────────────────────────────────      ───────────────────────────────────────────────────────",
             "  Devils                        18    15    2    1     31      Golden Knights                19    15    3    1     31",
             "  Hurricanes                    19    14    3    2     30      Oilers                        20    14    4    2     30",
@@ -657,11 +726,12 @@ mod tests {
         let standings = create_test_standings();
 
         let props = StandingsTabProps {
-            standings: Arc::new(Some(standings)),
+            standings: Some(Arc::new(standings)),
             document_stack: Vec::new(),
             focused: false,
-            config: Config::default(),
+            config: Arc::new(Config::default()),
             animation_frame: 0,
+            load_error: None,
         };
 
         let state = StandingsTabState {
@@ -679,7 +749,7 @@ mod tests {
             "─────────┴──────────┴────────────┴──────────────────────────────────────────────────────────────────────────────────────",
             "  Eastern                                                      Western",
             "",
-            "  Team                          GP     W    L   OT    PTS      Team                          GP     W    L   OT    PTS",
+            "  Team                          GP     W    L   OT    PTS ▸    Team                          GP     W    L   OT    PTS ▸",
             "  ───────────────────────────────────────────────────────      ───────────────────────────────────────────────────────",
             "  Devils                        18    15    2    1     31      Avalanche                     19    16    2    1     33",
             "  Panthers                      19    14    3    2     30      Golden Knights                19    15    3    1     31",
@@ -723,11 +793,12 @@ mod tests {
         let standings_tab = StandingsTab;
         let standings = create_test_standings();
         let props = StandingsTabProps {
-            standings: Arc::new(Some(standings)),
+            standings: Some(Arc::new(standings)),
             document_stack: Vec::new(),
             focused: false,
-            config: Config::default(),
+            config: Arc::new(Config::default()),
             animation_frame: 0,
+            load_error: None,
         };
 
         let element = standings_tab.view(&props, &StandingsTabState::default());
@@ -739,7 +810,7 @@ mod tests {
             "─────────┴──────────┴────────────┴──────────────────────────────────────────────────────────────────────────────────────",
             "  Atlantic                                                     Central",
             "",
-            "  Team                          GP     W    L   OT    PTS      Team                          GP     W    L   OT    PTS",
+            "  Team                          GP     W    L   OT    PTS ▸    Team                          GP     W    L   OT    PTS ▸",
             "  ───────────────────────────────────────────────────────      ───────────────────────────────────────────────────────",
             "  Panthers                      19    14    3    2     30      Avalanche                     19    16    2    1     33",
             "  Bruins                        18    13    4    1     27      Stars                         20    14    4    2     30",
@@ -747,7 +818,7 @@ mod tests {
             "",
             "  Metropolitan                                                 Pacific",
             "",
-            "  Team                          GP     W    L   OT    PTS      Team                          GP     W    L   OT    PTS",
+            "  Team                          GP     W    L   OT    PTS ▸    Team                          GP     W    L   OT    PTS ▸",
             "  ───────────────────────────────────────────────────────      ───────────────────────────────────────────────────────",
             "  Devils                        18    15    2    1     31      Golden Knights                19    15    3    1     31",
             "  Hurricanes                    19    14    3    2     30      Oilers                        20    14    4    2     30",
@@ -755,7 +826,7 @@ mod tests {
             "",
             "  Wildcard                                                     Wildcard",
             "",
-            "  Team                          GP     W    L   OT    PTS      Team                          GP     W    L   OT    PTS",
+            "  Team                          GP     W    L   OT    PTS ▸    Team                          GP     W    L   OT    PTS ▸",
             "  ───────────────────────────────────────────────────────      ───────────────────────────────────────────────────────",
             "  Penguins                      19    11    6    2     24      Wild                          19    11    6    2     24",
             "  Lightning                     18    11    6    1     23      Kraken                        19    11    6    2     24",
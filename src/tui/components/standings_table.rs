@@ -11,6 +11,9 @@ use crate::tui::{Alignment, CellValue, ColumnDef};
 
 use super::TableWidget;
 
+//TODO add L10 and STRK columns once nhl_api's Standing deserializes the
+// l10Wins/l10Losses/l10OtLosses and streakCode/streakCount fields
+
 /// Cached column definitions for standings table
 /// Uses LazyLock to initialize once and reuse across all calls
 static STANDINGS_COLUMNS: LazyLock<Vec<ColumnDef<Standing>>> = LazyLock::new(|| {
@@ -36,9 +39,25 @@ static STANDINGS_COLUMNS: LazyLock<Vec<ColumnDef<Standing>>> = LazyLock::new(||
         ColumnDef::new("PTS", 5, Alignment::Right, |s: &Standing| {
             CellValue::Text(s.points.to_string())
         }),
+        ColumnDef::new("PACE", 5, Alignment::Right, |s: &Standing| {
+            CellValue::Text(points_pace(s))
+        }),
     ]
 });
 
+/// Games in a regular season, used to project points pace
+const SEASON_GAMES: i32 = 82;
+
+/// Points projected over a full season at the current rate, "-" before any game
+fn points_pace(standing: &Standing) -> String {
+    let games_played = standing.games_played();
+    if games_played == 0 {
+        return "-".to_string();
+    }
+    let pace = standing.points as f64 / games_played as f64 * SEASON_GAMES as f64;
+    format!("{:.0}", pace)
+}
+
 /// Get the shared column definitions for standings tables
 pub fn standings_columns() -> &'static Vec<ColumnDef<Standing>> {
     &STANDINGS_COLUMNS
@@ -83,13 +102,28 @@ mod tests {
     #[test]
     fn test_standings_columns_initialized() {
         let columns = standings_columns();
-        assert_eq!(columns.len(), 6);
+        assert_eq!(columns.len(), 7);
         assert_eq!(columns[0].header, "Team");
         assert_eq!(columns[1].header, "GP");
         assert_eq!(columns[2].header, "W");
         assert_eq!(columns[3].header, "L");
         assert_eq!(columns[4].header, "OT");
         assert_eq!(columns[5].header, "PTS");
+        assert_eq!(columns[6].header, "PACE");
+    }
+
+    #[test]
+    fn test_points_pace() {
+        let standings = create_test_standings();
+        // 30 points in 19 games projects to 129.47 over 82
+        assert_eq!(points_pace(&standings[0]), "129");
+
+        let mut no_games = standings[0].clone();
+        no_games.wins = 0;
+        no_games.losses = 0;
+        no_games.ot_losses = 0;
+        no_games.points = 0;
+        assert_eq!(points_pace(&no_games), "-");
     }
 
     #[test]
@@ -109,17 +143,17 @@ mod tests {
         let table = create_standings_table(standings);
 
         let height = table.preferred_height().unwrap();
-        let buf = render_widget(&table, 60, height);
+        let buf = render_widget(&table, 70, height);
 
         assert_buffer(
             &buf,
             &[
-                "  Team                          GP     W    L   OT    PTS",
-                "  ───────────────────────────────────────────────────────",
-                "  Panthers                      19    14    3    2     30",
-                "  Bruins                        18    13    4    1     27",
-                "  Maple Leafs                   19    12    5    2     26",
-                "  Lightning                     18    11    6    1     23",
+                "  Team                          GP     W    L   OT    PTS   PACE",
+                "  ──────────────────────────────────────────────────────────────",
+                "  Panthers                      19    14    3    2     30    129",
+                "  Bruins                        18    13    4    1     27    123",
+                "  Maple Leafs                   19    12    5    2     26    112",
+                "  Lightning                     18    11    6    1     23    105",
             ],
         );
     }
@@ -131,17 +165,17 @@ mod tests {
         let table = create_standings_table_with_selection(standings, Some(1));
 
         let height = table.preferred_height().unwrap();
-        let buf = render_widget(&table, 60, height);
+        let buf = render_widget(&table, 70, height);
 
         // Row 1 should show the selector
         assert_buffer(
             &buf,
             &[
-                "  Team                          GP     W    L   OT    PTS",
-                "  ───────────────────────────────────────────────────────",
-                "  Panthers                      19    14    3    2     30",
-                "▶ Bruins                        18    13    4    1     27",
-                "  Maple Leafs                   19    12    5    2     26",
+                "  Team                          GP     W    L   OT    PTS   PACE",
+                "  ──────────────────────────────────────────────────────────────",
+                "  Panthers                      19    14    3    2     30    129",
+                "▶ Bruins                        18    13    4    1     27    123",
+                "  Maple Leafs                   19    12    5    2     26    112",
             ],
         );
     }
@@ -1,46 +1,68 @@
+use chrono::{DateTime, Local};
 use ratatui::{
     buffer::Buffer,
     layout::Rect,
-    style::{Color, Style},
+    style::Style,
     text::{Line, Span},
     widgets::Paragraph,
 };
 use std::time::SystemTime;
 use unicode_width::UnicodeWidthStr;
 
-use crate::config::DisplayConfig;
+use crate::config::{format_time, DisplayConfig, DEFAULT_TIME_FORMAT};
 use crate::tui::{
     component::{Component, Element, ElementWidget},
-    state::SystemState,
+    state::AppState,
 };
 
 /// StatusBar component - renders status bar with refresh countdown and error messages
 ///
-/// Left side: status/error messages
-/// Right side: refresh countdown
+/// Left side: status/error messages; while the last fetch failed, its reason
+/// replaces everything but a transient error
+/// Right side: refresh countdown, "Refreshing…" while a refresh is in flight,
+/// or the pin time while the data is pinned
 pub struct StatusBar;
 
 impl Component for StatusBar {
-    type Props = SystemState;
+    type Props = AppState;
     type State = ();
     type Message = ();
 
     fn view(&self, props: &Self::Props, _state: &Self::State) -> Element {
+        let system = &props.system;
+        let (status_message, is_error) = match &props.data.last_fetch_error {
+            Some(error) if !system.status_is_error => (Some(format!("Offline: {}", error)), true),
+            _ => (system.status_message.clone(), system.status_is_error),
+        };
         Element::Widget(Box::new(StatusBarWidget {
-            last_refresh: props.last_refresh,
-            refresh_interval: props.config.refresh_interval,
-            status_message: props.status_message.clone(),
-            is_error: props.status_is_error,
+            last_refresh: system.last_refresh,
+            refresh_interval: system.config.refresh_interval,
+            status_message,
+            is_error,
+            refreshing: props.data.refresh_in_flight(),
+            pinned_label: system
+                .pinned_at
+                .map(|pinned_at| format_pinned_at(pinned_at, &system.config.time_format)),
         }))
     }
 }
 
+/// Format the pin time with the configured time format, falling back to the default
+fn format_pinned_at(pinned_at: SystemTime, time_format: &str) -> String {
+    let local: DateTime<Local> = pinned_at.into();
+    let time = format_time(&local, time_format)
+        .unwrap_or_else(|| local.format(DEFAULT_TIME_FORMAT).to_string());
+    format!("Pinned {}", time)
+}
+
 /// Renderable widget for StatusBar
 struct StatusBarWidget {
     last_refresh: Option<SystemTime>,
     refresh_interval: u32,
     status_message: Option<String>,
     is_error: bool,
+    refreshing: bool,
+    pinned_label: Option<String>,
 }
 
 impl ElementWidget for StatusBarWidget {
@@ -55,7 +77,11 @@ impl ElementWidget for StatusBarWidget {
         };
 
         // Right side: countdown to next refresh
-        let right_text = if let Some(refresh_time) = self.last_refresh {
+        let right_text = if let Some(label) = &self.pinned_label {
+            label.clone()
+        } else if self.refreshing {
+            "Refreshing…".to_string()
+        } else if let Some(refresh_time) = self.last_refresh {
             if let Ok(elapsed) = SystemTime::now().duration_since(refresh_time) {
                 let elapsed_secs = elapsed.as_secs();
                 let remaining_secs = self.refresh_interval.saturating_sub(elapsed_secs as u32);
@@ -63,7 +89,7 @@ impl ElementWidget for StatusBarWidget {
                 if remaining_secs > 0 {
                     format!("Refresh in {}s", remaining_secs)
                 } else {
-                    "Refreshing...".to_string()
+                    "Refreshing…".to_string()
                 }
             } else {
                 "Refresh in ?s".to_string()
@@ -111,7 +137,10 @@ impl ElementWidget for StatusBarWidget {
         if !left_text.is_empty() {
             line2_spans.push(Span::raw(" "));
             if self.is_error {
-                line2_spans.push(Span::styled(&left_text, Style::default().fg(Color::Red)));
+                line2_spans.push(Span::styled(
+                    &left_text,
+                    Style::default().fg(config.error_fg),
+                ));
             } else {
                 line2_spans.push(Span::styled(&left_text, text_style));
             }
@@ -144,6 +173,8 @@ impl ElementWidget for StatusBarWidget {
             refresh_interval: self.refresh_interval,
             status_message: self.status_message.clone(),
             is_error: self.is_error,
+            refreshing: self.refreshing,
+            pinned_label: self.pinned_label.clone(),
         })
     }
 
@@ -156,22 +187,34 @@ impl ElementWidget for StatusBarWidget {
 mod tests {
     use super::*;
     use crate::config::Config;
+    use crate::tui::state::{FetchKind, SystemState};
     use crate::tui::testing::{assert_buffer, RENDER_WIDTH};
     use ratatui::buffer::Buffer;
+    use ratatui::style::Color;
+    use std::sync::Arc;
 
     #[test]
     fn test_status_bar_renders_loading() {
         let status_bar = StatusBar;
         let system_state = SystemState {
             last_refresh: None,
-            config: Config::default(),
+            config: Arc::new(Config::default()),
             status_message: None,
             status_is_error: false,
+            status_expires_at: None,
             terminal_width: 80,
             animation_frame: 0,
+            frame_count: 0,
+            pinned_at: None,
         };
 
-        let element = status_bar.view(&system_state, &());
+        let element = status_bar.view(
+            &AppState {
+                system: system_state,
+                ..AppState::default()
+            },
+            &(),
+        );
 
         match element {
             Element::Widget(widget) => {
@@ -190,19 +233,71 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_status_bar_shows_fetch_error_until_a_fetch_succeeds() {
+        let mut state = AppState::default();
+        state.system.reset_status_message();
+        state.data.record_fetch_error(
+            FetchKind::Standings,
+            "Failed to load standings: timeout".into(),
+        );
+
+        let render = |state: &AppState| {
+            let mut buf = Buffer::empty(Rect::new(0, 0, RENDER_WIDTH, 2));
+            let Element::Widget(widget) = StatusBar.view(state, &()) else {
+                panic!("Expected widget element");
+            };
+            widget.render(buf.area, &mut buf, &DisplayConfig::default());
+            buf
+        };
+
+        let buf = render(&state);
+        assert_buffer(
+            &buf,
+            &[
+                "────────────────────────────────────────────────────────────────────┬───────────",
+                " Offline: Failed to load standings: timeout                         │ Loading...",
+            ],
+        );
+        assert_eq!(buf[(1, 1)].fg, DisplayConfig::default().error_fg);
+
+        // A transient error, e.g. a failed config save, still gets through
+        state
+            .system
+            .set_status_error_message("Failed to save config".to_string());
+        assert!(buffer_line(&render(&state), 1).starts_with(" Failed to save config"));
+
+        state.system.reset_status_message();
+        state.data.record_fetch_success(FetchKind::Schedule);
+        assert!(buffer_line(&render(&state), 1).starts_with(" Keys:"));
+    }
+
+    fn buffer_line(buf: &Buffer, y: u16) -> String {
+        (0..buf.area.width).map(|x| buf[(x, y)].symbol()).collect()
+    }
+
     #[test]
     fn test_status_bar_renders() {
         let status_bar = StatusBar;
         let system_state = SystemState {
             last_refresh: Some(SystemTime::now() - std::time::Duration::from_secs(5)),
-            config: Config::default(),
+            config: Arc::new(Config::default()),
             status_message: None,
             status_is_error: false,
+            status_expires_at: None,
             terminal_width: 80,
             animation_frame: 0,
+            frame_count: 0,
+            pinned_at: None,
         };
 
-        let element = status_bar.view(&system_state, &());
+        let element = status_bar.view(
+            &AppState {
+                system: system_state,
+                ..AppState::default()
+            },
+            &(),
+        );
 
         match element {
             Element::Widget(widget) => {
@@ -228,6 +323,8 @@ mod tests {
             refresh_interval: 60,
             status_message: Some("ERROR: Network timeout".to_string()),
             is_error: true,
+            refreshing: false,
+            pinned_label: None,
         };
 
         let mut buf = Buffer::empty(Rect::new(0, 0, RENDER_WIDTH, 2));
@@ -256,6 +353,8 @@ mod tests {
             refresh_interval: 60,
             status_message: None,
             is_error: false,
+            refreshing: false,
+            pinned_label: None,
         };
 
         let mut buf = Buffer::empty(Rect::new(0, 0, RENDER_WIDTH, 2));
@@ -265,18 +364,81 @@ mod tests {
             &DisplayConfig::default(),
         );
 
-        // Should show "Refreshing..." when time has elapsed
+        // Should show "Refreshing…" when time has elapsed
         let line2 = (0..RENDER_WIDTH)
             .map(|x| buf.cell((x, 1)).map(|c| c.symbol()).unwrap_or(""))
             .collect::<String>();
 
         assert!(
-            line2.contains("Refreshing..."),
+            line2.contains("Refreshing…"),
             "Refreshing message not found in: {}",
             line2
         );
     }
 
+    #[test]
+    fn test_status_bar_shows_refresh_in_flight() {
+        let widget = StatusBarWidget {
+            last_refresh: Some(SystemTime::now()),
+            refresh_interval: 60,
+            status_message: None,
+            is_error: false,
+            refreshing: true,
+            pinned_label: None,
+        };
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, RENDER_WIDTH, 2));
+        widget.render(
+            Rect::new(0, 0, RENDER_WIDTH, 2),
+            &mut buf,
+            &DisplayConfig::default(),
+        );
+
+        let line2 = (0..RENDER_WIDTH)
+            .map(|x| buf.cell((x, 1)).map(|c| c.symbol()).unwrap_or(""))
+            .collect::<String>();
+        assert!(line2.contains("Refreshing…"), "got: {}", line2);
+        assert!(!line2.contains("Refresh in"), "got: {}", line2);
+    }
+
+    #[test]
+    fn test_status_bar_pinned_replaces_countdown() {
+        let widget = StatusBarWidget {
+            last_refresh: Some(SystemTime::now()),
+            refresh_interval: 60,
+            status_message: None,
+            is_error: false,
+            refreshing: false,
+            pinned_label: Some("Pinned 19:05:00".to_string()),
+        };
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, RENDER_WIDTH, 2));
+        widget.render(
+            Rect::new(0, 0, RENDER_WIDTH, 2),
+            &mut buf,
+            &DisplayConfig::default(),
+        );
+
+        assert_buffer(
+            &buf,
+            &[
+                "───────────────────────────────────────────────────────────────┬────────────────",
+                "                                                               │ Pinned 19:05:00",
+            ],
+        );
+    }
+
+    #[test]
+    fn test_format_pinned_at_falls_back_on_invalid_format() {
+        let pinned_at = SystemTime::now();
+        let local: DateTime<Local> = pinned_at.into();
+
+        assert_eq!(
+            format_pinned_at(pinned_at, "%Q"),
+            format!("Pinned {}", local.format("%H:%M:%S"))
+        );
+    }
+
     #[test]
     fn test_status_bar_future_time() {
         // Test with a future time (should handle time calculation error)
@@ -285,6 +447,8 @@ mod tests {
             refresh_interval: 60,
             status_message: None,
             is_error: false,
+            refreshing: false,
+            pinned_label: None,
         };
 
         let mut buf = Buffer::empty(Rect::new(0, 0, RENDER_WIDTH, 2));
@@ -313,6 +477,8 @@ mod tests {
             refresh_interval: 60,
             status_message: Some("Test".to_string()),
             is_error: false,
+            refreshing: false,
+            pinned_label: None,
         };
 
         let _cloned: Box<dyn ElementWidget> = widget.clone_box();
@@ -326,6 +492,8 @@ mod tests {
             refresh_interval: 60,
             status_message: None,
             is_error: false,
+            refreshing: false,
+            pinned_label: None,
         };
 
         assert_eq!(widget.preferred_height(), Some(2));
@@ -338,6 +506,8 @@ mod tests {
             refresh_interval: 60,
             status_message: Some("Configuration saved".to_string()),
             is_error: false,
+            refreshing: false,
+            pinned_label: None,
         };
 
         let mut buf = Buffer::empty(Rect::new(0, 0, RENDER_WIDTH, 2));
@@ -368,6 +538,8 @@ mod tests {
             refresh_interval: 60,
             status_message: Some("Failed to save config".to_string()),
             is_error: true,
+            refreshing: false,
+            pinned_label: None,
         };
 
         let mut buf = Buffer::empty(Rect::new(0, 0, RENDER_WIDTH, 2));
@@ -385,9 +557,13 @@ mod tests {
             ],
         );
 
-        // Verify error message IS styled red
+        // Verify error message IS styled with the error color
         if let Some(cell) = buf.cell((1, 1)) {
-            assert_eq!(cell.fg, Color::Red, "Error message should be red");
+            assert_eq!(
+                cell.fg,
+                DisplayConfig::default().error_fg,
+                "Error message should use error_fg"
+            );
         }
     }
 
@@ -398,6 +574,8 @@ mod tests {
             refresh_interval: 60,
             status_message: None,
             is_error: false,
+            refreshing: false,
+            pinned_label: None,
         };
 
         let mut buf = Buffer::empty(Rect::new(0, 0, RENDER_WIDTH, 2));
@@ -425,6 +603,8 @@ mod tests {
             refresh_interval: 60,
             status_message: None,
             is_error: false,
+            refreshing: false,
+            pinned_label: None,
         };
 
         let mut config = DisplayConfig::default();
@@ -463,6 +643,8 @@ mod tests {
             refresh_interval: 60,
             status_message: None,
             is_error: false,
+            refreshing: false,
+            pinned_label: None,
         };
 
         let config = DisplayConfig::default(); // No theme set
@@ -499,6 +681,8 @@ mod tests {
             refresh_interval: 60,
             status_message: Some("Configuration saved".to_string()),
             is_error: false,
+            refreshing: false,
+            pinned_label: None,
         };
 
         let mut config = DisplayConfig::default();
@@ -534,6 +718,8 @@ mod tests {
             refresh_interval: 60,
             status_message: Some("Failed to save config".to_string()),
             is_error: true,
+            refreshing: false,
+            pinned_label: None,
         };
 
         let mut config = DisplayConfig::default();
@@ -542,12 +728,11 @@ mod tests {
         let mut buf = Buffer::empty(Rect::new(0, 0, RENDER_WIDTH, 2));
         widget.render(Rect::new(0, 0, RENDER_WIDTH, 2), &mut buf, &config);
 
-        // Verify error message still uses Color::Red, not theme fg2
+        // Verify error message still uses error_fg, not theme fg2
         if let Some(cell) = buf.cell((1, 1)) {
             assert_eq!(
-                cell.fg,
-                Color::Red,
-                "Error message should use Color::Red even with theme set"
+                cell.fg, config.error_fg,
+                "Error message should use error_fg even with theme set"
             );
             assert_ne!(
                 cell.fg, THEME_ORANGE.fg2,
@@ -563,6 +748,8 @@ mod tests {
             refresh_interval: 60,
             status_message: Some("Configuration saved".to_string()),
             is_error: false,
+            refreshing: false,
+            pinned_label: None,
         };
 
         let config = DisplayConfig::default(); // No theme set
@@ -596,6 +783,8 @@ mod tests {
             refresh_interval: 60,
             status_message: Some("Updated 🏒".to_string()),
             is_error: false,
+            refreshing: false,
+            pinned_label: None,
         };
 
         let mut buf = Buffer::empty(Rect::new(0, 0, RENDER_WIDTH, 2));
@@ -625,6 +814,8 @@ mod tests {
             refresh_interval: 60,
             status_message: Some("更新完了".to_string()), // "Update complete" in Japanese
             is_error: false,
+            refreshing: false,
+            pinned_label: None,
         };
 
         let mut buf = Buffer::empty(Rect::new(0, 0, RENDER_WIDTH, 2));
@@ -656,6 +847,8 @@ mod tests {
             refresh_interval: 60,
             status_message: Some("Loading players データを読み込み中 🏒🥅".to_string()),
             is_error: false,
+            refreshing: false,
+            pinned_label: None,
         };
 
         let mut buf = Buffer::empty(Rect::new(0, 0, RENDER_WIDTH, 2));
@@ -267,6 +267,8 @@ mod tests {
             theme: None,
             error_fg: Color::Red,
             box_chars: BoxChars::unicode(),
+            boxscore_sort: None,
+            ..DisplayConfig::default()
         }
     }
 
@@ -277,6 +279,8 @@ mod tests {
             theme: None,
             error_fg: Color::Red,
             box_chars: BoxChars::ascii(),
+            boxscore_sort: None,
+            ..DisplayConfig::default()
         }
     }
 
@@ -4,6 +4,13 @@
 //! - Mixed cell types (Text, PlayerLink, TeamLink)
 //! - Column-based layout with customizable alignment
 //! - Selection highlighting (focused and unfocused states)
+//! - Stable, numeric-aware sorting by column (`sorted_by`)
+//! - Horizontal scrolling with frozen leading columns for wide tables
+//! - Vertical scrolling (`with_row_offset`) with a scroll indicator when rows overflow the area
+//! - Optional zebra striping of alternate rows (`with_zebra`)
+//! - Optional totals row aggregated per column (`with_totals`)
+//! - Auto-sized columns (`ColumnDef::auto_width`) that shrink to fit the area
+//! - Column priorities (`ColumnDef::with_priority`) deciding what to hide when the area is too narrow
 //! - Keyboard navigation (via parent component actions)
 //!
 //! # Architecture
@@ -156,6 +163,8 @@
 
 mod rendering;
 
+use std::cmp::Ordering;
+
 use crate::config::DisplayConfig;
 use crate::tui::component::ElementWidget;
 use crate::tui::{Alignment, CellValue, ColumnDef, Component, Element};
@@ -191,33 +200,89 @@ const SELECTOR_WIDTH: usize = 2;
 pub struct TableWidget {
     pub(super) column_headers: Vec<String>,
     pub(super) column_widths: Vec<usize>,
+    /// Widths auto-sized columns may shrink back to when space is short
+    pub(super) column_min_widths: Vec<usize>,
     pub(super) column_aligns: Vec<Alignment>,
+    /// Columns whose cells are colored by the sign of their value
+    pub(super) column_signed: Vec<bool>,
+    /// Hiding priority of each column; `None` columns are never hidden
+    pub(super) column_priorities: Vec<Option<u8>>,
+    /// Full names of abbreviated headers, for the column legend
+    pub(super) column_descriptions: Vec<Option<String>>,
     pub(super) cell_data: Vec<Vec<CellValue>>,
     /// Which row is focused (externally managed)
     pub(super) focused_row: Option<usize>,
+    /// Leading columns that stay visible when scrolling horizontally
+    pub(super) frozen_columns: usize,
+    /// Horizontal scroll offset, in columns after the frozen ones
+    pub(super) column_offset: usize,
+    /// Vertical scroll offset, in rows, when the rows overflow the area
+    pub(super) row_offset: usize,
+    /// Whether odd rows get the stripe background
+    pub(super) zebra: bool,
+    /// Aggregated totals shown below the rows (not focusable)
+    pub(super) totals: Option<Vec<String>>,
+    /// Number of leading rows drawn with the emphasis style
+    pub(super) emphasized_rows: usize,
+    /// Rows linking to one of these teams get the favorite accent
+    pub(super) accented_teams: Vec<String>,
 }
 
 impl TableWidget {
     /// Create a table widget with builder pattern
-    /// Extracts all cell data upfront from the rows using column definitions
-    pub fn from_data<T: Send + Sync>(columns: &[ColumnDef<T>], rows: Vec<T>) -> Self {
+    /// Extracts all cell data upfront from the rows using column definitions,
+    /// so rows can be borrowed (e.g. from shared API data) instead of cloned
+    pub fn from_data<T: Send + Sync>(columns: &[ColumnDef<T>], rows: impl AsRef<[T]>) -> Self {
         // Extract cell data upfront
         let cell_data: Vec<Vec<CellValue>> = rows
+            .as_ref()
             .iter()
             .map(|row| columns.iter().map(|col| (col.cell_fn)(row)).collect())
             .collect();
 
         // Extract column metadata
         let column_headers = columns.iter().map(|c| c.header.clone()).collect();
-        let column_widths = columns.iter().map(|c| c.width).collect();
+        let column_min_widths: Vec<usize> = columns.iter().map(|c| c.width).collect();
+        let column_widths = columns
+            .iter()
+            .enumerate()
+            .map(|(idx, col)| {
+                if !col.auto_width {
+                    return col.width;
+                }
+                let content = cell_data
+                    .iter()
+                    .map(|row| row[idx].display_text().chars().count())
+                    .chain(std::iter::once(col.header.chars().count()))
+                    .max()
+                    .unwrap_or(0);
+                let width = content.max(col.width);
+                col.max_width
+                    .map_or(width, |max| width.min(max.max(col.width)))
+            })
+            .collect();
         let column_aligns = columns.iter().map(|c| c.align).collect();
+        let column_signed = columns.iter().map(|c| c.signed).collect();
+        let column_descriptions = columns.iter().map(|c| c.description.clone()).collect();
+        let column_priorities = columns.iter().map(|c| c.priority).collect();
 
         Self {
             column_headers,
             column_widths,
+            column_min_widths,
             column_aligns,
+            column_signed,
+            column_priorities,
+            column_descriptions,
             cell_data,
             focused_row: None,
+            frozen_columns: 0,
+            column_offset: 0,
+            row_offset: 0,
+            zebra: false,
+            totals: None,
+            emphasized_rows: 0,
+            accented_teams: Vec::new(),
         }
     }
 
@@ -227,6 +292,214 @@ impl TableWidget {
         self
     }
 
+    /// Give alternate rows a stripe background to make long tables easier to scan
+    pub fn with_zebra(mut self, zebra: bool) -> Self {
+        self.zebra = zebra;
+        self
+    }
+
+    /// Draw the first `count` rows with the emphasis style
+    ///
+    /// Applies to row positions, so set it after any `sorted_by`.
+    pub fn with_emphasized_rows(mut self, count: usize) -> Self {
+        self.emphasized_rows = count;
+        self
+    }
+
+    /// Accent rows with a team link to one of `teams`, wherever sorting puts them
+    pub fn with_accented_teams(mut self, teams: &[String]) -> Self {
+        self.accented_teams = teams.to_vec();
+        self
+    }
+
+    /// Add a totals row computed from each column's `ColumnTotal` over `rows`
+    ///
+    /// `columns` must be the same definitions the table was built from.
+    pub fn with_totals<T>(mut self, columns: &[ColumnDef<T>], rows: &[T]) -> Self {
+        self.totals = Some(columns.iter().map(|col| col.total_text(rows)).collect());
+        self
+    }
+
+    /// Keep the first `count` columns in place when scrolling horizontally
+    pub fn with_frozen_columns(mut self, count: usize) -> Self {
+        self.frozen_columns = count;
+        self
+    }
+
+    /// Scroll horizontally by `offset` columns (clamped when rendering)
+    pub fn with_column_offset(mut self, offset: usize) -> Self {
+        self.column_offset = offset;
+        self
+    }
+
+    /// Scroll vertically by `offset` rows (clamped when rendering)
+    ///
+    /// The focused row is always scrolled into view.
+    pub fn with_row_offset(mut self, offset: usize) -> Self {
+        self.row_offset = offset;
+        self
+    }
+
+    /// First row shown when `visible` rows fit, keeping the focused row on screen
+    pub(super) fn visible_row_offset(&self, visible: usize) -> usize {
+        let max_offset = self.cell_data.len().saturating_sub(visible);
+        let offset = self.row_offset.min(max_offset);
+        match self.focused_row {
+            Some(row) if visible > 0 && row < offset => row,
+            Some(row) if visible > 0 && row >= offset + visible => {
+                (row + 1 - visible).min(max_offset)
+            }
+            _ => offset,
+        }
+    }
+
+    /// Whether the given columns fit in `available` width
+    fn columns_fit(
+        widths: &[usize],
+        columns: impl Iterator<Item = usize>,
+        available: usize,
+    ) -> bool {
+        let mut used = SELECTOR_WIDTH;
+        for (i, col) in columns.enumerate() {
+            used += widths[col] + if i > 0 { 2 } else { 0 };
+            if used > available {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Column widths for `available` width
+    ///
+    /// When the table is too wide, auto-sized columns give back their extra
+    /// width proportionally, never going below their declared width.
+    pub(super) fn fitted_widths(&self, available: usize) -> Vec<usize> {
+        let mut widths = self.column_widths.clone();
+        let total =
+            SELECTOR_WIDTH + widths.iter().sum::<usize>() + widths.len().saturating_sub(1) * 2;
+        let shrinkable: usize = widths
+            .iter()
+            .zip(&self.column_min_widths)
+            .map(|(width, min)| width - min)
+            .sum();
+        if total <= available || shrinkable == 0 {
+            return widths;
+        }
+
+        let excess = total - available;
+        for (width, &min) in widths.iter_mut().zip(&self.column_min_widths) {
+            let extra = *width - min;
+            let cut = (excess * extra).div_ceil(shrinkable).min(extra);
+            *width -= cut;
+        }
+        widths
+    }
+
+    /// Columns left after hiding the lowest-priority ones until the rest fit
+    ///
+    /// Among equal priorities the rightmost column goes first.
+    fn shown_columns(&self, widths: &[usize], available: usize) -> Vec<usize> {
+        let mut shown: Vec<usize> = (0..widths.len()).collect();
+        while !Self::columns_fit(widths, shown.iter().copied(), available) {
+            let lowest = shown
+                .iter()
+                .enumerate()
+                .rev()
+                .filter_map(|(position, &col)| Some((self.column_priorities[col]?, position)))
+                .min_by_key(|&(priority, _)| priority);
+            let Some((_, position)) = lowest else {
+                break;
+            };
+            shown.remove(position);
+        }
+        shown
+    }
+
+    /// Columns to draw with the given widths within `available` width
+    ///
+    /// Columns with a priority are hidden first, lowest first. Of the rest, frozen columns come
+    /// first, then scrollable columns from the offset, which is clamped so it
+    /// never scrolls past the point where the last column is visible. Returns
+    /// the column indices and whether content is clipped on the left and on
+    /// the right.
+    pub(super) fn visible_columns(
+        &self,
+        widths: &[usize],
+        available: usize,
+    ) -> (Vec<usize>, bool, bool) {
+        let shown = self.shown_columns(widths, available);
+        let frozen = shown
+            .iter()
+            .take_while(|&&col| col < self.frozen_columns)
+            .count();
+        let (pinned, scrollable) = shown.split_at(frozen);
+
+        let max_offset = (0..scrollable.len())
+            .find(|&k| {
+                Self::columns_fit(
+                    widths,
+                    pinned.iter().chain(&scrollable[k..]).copied(),
+                    available,
+                )
+            })
+            .unwrap_or(scrollable.len().saturating_sub(1));
+        let offset = self.column_offset.min(max_offset);
+
+        let mut visible = Vec::new();
+        let mut used = SELECTOR_WIDTH;
+        for &col in pinned.iter().chain(&scrollable[offset..]) {
+            let needed = widths[col] + if visible.is_empty() { 0 } else { 2 };
+            if used + needed > available {
+                break;
+            }
+            used += needed;
+            visible.push(col);
+        }
+
+        let clipped_right = visible.len() < shown.len() - offset;
+        (visible, offset > 0, clipped_right)
+    }
+
+    /// Drawn columns as (column index, x offset, width) for a table `width` wide
+    ///
+    /// Mirrors the layout used when rendering, including horizontal scrolling.
+    pub fn column_spans(&self, width: u16) -> Vec<(usize, u16, u16)> {
+        let widths = self.fitted_widths(width as usize);
+        let (columns, _, _) = self.visible_columns(&widths, width as usize);
+        let mut x = SELECTOR_WIDTH as u16;
+        columns
+            .into_iter()
+            .map(|col| {
+                let span = (col, x, widths[col] as u16);
+                x += widths[col] as u16 + 2;
+                span
+            })
+            .collect()
+    }
+
+    /// Reorder rows by the values in `column`
+    ///
+    /// Cells are compared by their display text, numerically when both parse
+    /// as numbers (so "10" sorts after "9"). The sort is stable; an
+    /// out-of-range column leaves the rows unchanged.
+    pub fn sorted_by(mut self, column: usize, ascending: bool) -> Self {
+        if column >= self.column_headers.len() {
+            return self;
+        }
+        self.cell_data.sort_by(|a, b| {
+            let ordering = match (a.get(column), b.get(column)) {
+                (Some(a), Some(b)) => compare_cells(a, b),
+                _ => Ordering::Equal,
+            };
+            if ascending {
+                ordering
+            } else {
+                ordering.reverse()
+            }
+        });
+        self
+    }
+
     /// Format a cell with alignment
     pub(super) fn format_cell(&self, text: &str, width: usize, align: Alignment) -> String {
         let text_len = text.chars().count(); // Unicode-aware length
@@ -320,6 +593,20 @@ impl TableWidget {
         self.cell_data.get(row)?.get(col).cloned()
     }
 
+    /// Get the cells of the focused row, if a row is focused
+    pub fn focused_row_cells(&self) -> Option<&[CellValue]> {
+        self.cell_data.get(self.focused_row?).map(Vec::as_slice)
+    }
+
+    /// Header and description of each described column, in column order
+    pub fn column_legend(&self) -> Vec<(String, String)> {
+        self.column_headers
+            .iter()
+            .zip(&self.column_descriptions)
+            .filter_map(|(header, description)| Some((header.clone(), description.clone()?)))
+            .collect()
+    }
+
     /// Get the number of rows in the table
     pub fn row_count(&self) -> usize {
         self.cell_data.len()
@@ -329,6 +616,64 @@ impl TableWidget {
     pub fn column_count(&self) -> usize {
         self.column_headers.len()
     }
+
+    /// Get the header text of a column
+    pub fn column_header(&self, col: usize) -> Option<&str> {
+        self.column_headers.get(col).map(String::as_str)
+    }
+
+    /// Get the alignment of a column
+    pub fn column_align(&self, col: usize) -> Option<Alignment> {
+        self.column_aligns.get(col).copied()
+    }
+
+    /// Get the totals row, if the table has one
+    pub fn totals(&self) -> Option<&[String]> {
+        self.totals.as_deref()
+    }
+
+    /// Serialize the headers and rows to CSV, one line per row
+    ///
+    /// Uses each cell's display text, independent of rendering state
+    /// (focus, sort indicators, horizontal scroll).
+    pub fn to_csv(&self) -> String {
+        if self.column_headers.is_empty() {
+            return String::new();
+        }
+        let mut csv = csv_line(self.column_headers.iter().map(String::as_str));
+        for row in &self.cell_data {
+            csv.push_str(&csv_line(row.iter().map(CellValue::display_text)));
+        }
+        csv
+    }
+}
+
+fn csv_line<'a>(fields: impl Iterator<Item = &'a str>) -> String {
+    let fields: Vec<String> = fields.map(csv_field).collect();
+    format!("{}\n", fields.join(","))
+}
+
+/// Quote a field containing a comma, quote or line break, doubling inner quotes
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Compare two cells numerically when both parse as numbers, lexically otherwise
+///
+/// Numbers sort before text so the ordering stays total on mixed columns.
+fn compare_cells(a: &CellValue, b: &CellValue) -> Ordering {
+    let a = a.display_text().trim();
+    let b = b.display_text().trim();
+    match (a.parse::<f64>(), b.parse::<f64>()) {
+        (Ok(x), Ok(y)) => x.partial_cmp(&y).unwrap_or(Ordering::Equal),
+        (Ok(_), Err(_)) => Ordering::Less,
+        (Err(_), Ok(_)) => Ordering::Greater,
+        (Err(_), Err(_)) => a.cmp(b),
+    }
 }
 
 impl ElementWidget for TableWidget {
@@ -352,7 +697,9 @@ impl ElementWidget for TableWidget {
             0
         };
         let rows_height = self.cell_data.len() as u16;
-        Some(col_header_height + separator_height + rows_height)
+        // Separator plus the totals row
+        let totals_height = if self.totals.is_some() { 2 } else { 0 };
+        Some(col_header_height + separator_height + rows_height + totals_height)
     }
 
     fn preferred_width(&self) -> Option<u16> {
@@ -369,10 +716,12 @@ impl ElementWidget for TableWidget {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::config::DisplayConfig;
-    use crate::tui::testing::{assert_buffer, RENDER_WIDTH};
+    use crate::config::{DisplayConfig, SELECTION_STYLE_MODIFIER};
+    use crate::tui::testing::{assert_buffer, buffer_lines, RENDER_WIDTH};
+    use crate::tui::ColumnTotal;
     use ratatui::buffer::Buffer;
     use ratatui::layout::Rect;
+    use ratatui::style::{Color, Modifier};
 
     // Helper to render ElementWidget for testing
     fn render_framework_widget(
@@ -431,6 +780,79 @@ mod tests {
         ]
     }
 
+    #[test]
+    fn test_focused_row_cells() {
+        let widget = TableWidget::from_data(&create_test_columns(), create_test_rows());
+        assert!(widget.focused_row_cells().is_none());
+
+        let widget = widget.with_focused_row(Some(1));
+        let cells = widget.focused_row_cells().unwrap();
+        assert_eq!(
+            cells[0],
+            CellValue::PlayerLink {
+                display: "Mitchell Marner".to_string(),
+                player_id: 8478483,
+            }
+        );
+        assert_eq!(cells[1].display_text(), "18");
+
+        let widget = widget.with_focused_row(Some(10));
+        assert!(widget.focused_row_cells().is_none());
+    }
+
+    #[test]
+    fn test_to_csv() {
+        let widget = TableWidget::from_data(&create_test_columns(), create_test_rows());
+
+        assert_eq!(
+            widget.to_csv(),
+            "Player,G\n\
+             Auston Matthews,42\n\
+             Mitchell Marner,18\n\
+             William Nylander,28\n"
+        );
+    }
+
+    #[test]
+    fn test_to_csv_quotes_fields() {
+        let rows = vec![
+            TestRow {
+                name: "Smith, John".to_string(),
+                id: 1,
+                value: 1,
+            },
+            TestRow {
+                name: "John \"Johnny\" Smith".to_string(),
+                id: 2,
+                value: 2,
+            },
+        ];
+        let columns = vec![
+            ColumnDef::new("Name, Full", 20, Alignment::Left, |r: &TestRow| {
+                CellValue::Text(r.name.clone())
+            }),
+            ColumnDef::new("G", 4, Alignment::Right, |r: &TestRow| {
+                CellValue::Text(r.value.to_string())
+            }),
+        ];
+        let widget = TableWidget::from_data(&columns, rows);
+
+        assert_eq!(
+            widget.to_csv(),
+            "\"Name, Full\",G\n\
+             \"Smith, John\",1\n\
+             \"John \"\"Johnny\"\" Smith\",2\n"
+        );
+    }
+
+    #[test]
+    fn test_to_csv_empty_table() {
+        let columns: Vec<ColumnDef<TestRow>> = vec![];
+        let widget = TableWidget::from_data(&columns, Vec::new());
+
+        assert_eq!(widget.to_csv(), "");
+    }
+
     #[test]
     fn test_empty_table() {
         let columns: Vec<ColumnDef<TestRow>> = vec![];
@@ -597,6 +1019,184 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_table_zebra_stripes_odd_rows() {
+        let rows: Vec<TestRow> = (0..4)
+            .map(|i| TestRow {
+                name: format!("Row{}", i),
+                id: i,
+                value: 0,
+            })
+            .collect();
+        let columns = vec![ColumnDef::new("Name", 6, Alignment::Left, |r: &TestRow| {
+            CellValue::Text(r.name.clone())
+        })];
+        let widget = TableWidget::from_data(&columns, rows)
+            .with_zebra(true)
+            .with_focused_row(Some(3));
+
+        let mut config = test_config();
+        config.theme_name = Some("orange".to_string());
+        config.apply_theme();
+        let stripe = config.stripe_style().bg;
+        assert!(stripe.is_some());
+
+        let buf = render_framework_widget(&widget, RENDER_WIDTH, 6, &config);
+        assert_buffer(
+            &buf,
+            &["  Name", "  ──────", "  Row0", "  Row1", "  Row2", "▶ Row3"],
+        );
+        // Rows 0 and 2 are plain, row 1 is striped across the table width only
+        assert_ne!(Some(buf[(0, 2)].bg), stripe);
+        assert_eq!(Some(buf[(0, 3)].bg), stripe);
+        assert_eq!(Some(buf[(7, 3)].bg), stripe);
+        assert_ne!(Some(buf[(8, 3)].bg), stripe);
+        assert_ne!(Some(buf[(2, 4)].bg), stripe);
+        // Focused odd row keeps the selection styling instead of the stripe
+        assert_ne!(Some(buf[(2, 5)].bg), stripe);
+    }
+
+    #[test]
+    fn test_table_emphasized_rows() {
+        let widget = TableWidget::from_data(&create_test_columns(), create_test_rows())
+            .with_emphasized_rows(2)
+            .with_focused_row(Some(1));
+
+        let mut config = test_config();
+        config.theme_name = Some("orange".to_string());
+        config.apply_theme();
+        let theme = config.theme.clone().unwrap();
+
+        let buf = render_framework_widget(&widget, RENDER_WIDTH, 5, &config);
+        assert_eq!(buf[(2, 2)].fg, theme.emphasis_fg());
+        assert_eq!(buf[(24, 2)].fg, theme.emphasis_fg());
+        // The focused link keeps the selection styling, the rest of its row stays emphasized
+        assert!(buf[(2, 3)].modifier.contains(SELECTION_STYLE_MODIFIER));
+        assert_eq!(buf[(24, 3)].fg, theme.emphasis_fg());
+        assert_eq!(buf[(2, 4)].fg, theme.fg2);
+    }
+
+    #[test]
+    fn test_table_accented_teams_follow_sorting() {
+        let rows = vec![("MTL", 1), ("TOR", 3), ("BOS", 2)];
+        let columns = vec![
+            ColumnDef::new("Team", 4, Alignment::Left, |r: &(&str, i32)| {
+                CellValue::TeamLink {
+                    display: r.0.to_string(),
+                    team_abbrev: r.0.to_string(),
+                }
+            }),
+            ColumnDef::new("Pts", 3, Alignment::Right, |r: &(&str, i32)| {
+                CellValue::Text(r.1.to_string())
+            }),
+        ];
+        let widget = TableWidget::from_data(&columns, rows)
+            .with_accented_teams(&["TOR".to_string()])
+            .sorted_by(1, false)
+            .with_emphasized_rows(1);
+
+        let mut config = test_config();
+        config.theme_name = Some("orange".to_string());
+        config.apply_theme();
+        let theme = config.theme.clone().unwrap();
+
+        let buf = render_framework_widget(&widget, RENDER_WIDTH, 5, &config);
+        assert_buffer(
+            &buf,
+            &[
+                "  Team  Pts",
+                "  ─────────",
+                "  TOR     3",
+                "  BOS     2",
+                "  MTL     1",
+            ],
+        );
+        // The favorite is italic on top of its emphasis; other emphasized rows are not
+        assert!(buf[(2, 2)].modifier.contains(Modifier::ITALIC));
+        assert!(buf[(10, 2)].modifier.contains(Modifier::ITALIC));
+        assert_eq!(buf[(2, 2)].fg, theme.emphasis_fg());
+        assert!(!buf[(2, 3)].modifier.contains(Modifier::ITALIC));
+        assert_eq!(buf[(2, 3)].fg, theme.fg2);
+
+        // The focused favorite link keeps the plain selection styling
+        let focused = widget.clone().with_focused_row(Some(0));
+        let buf = render_framework_widget(&focused, RENDER_WIDTH, 5, &config);
+        assert!(buf[(2, 2)].modifier.contains(SELECTION_STYLE_MODIFIER));
+        assert!(!buf[(2, 2)].modifier.contains(Modifier::ITALIC));
+
+        config.accent_favorites = false;
+        let buf = render_framework_widget(&widget, RENDER_WIDTH, 5, &config);
+        assert!(!buf[(2, 2)].modifier.contains(Modifier::ITALIC));
+    }
+
+    #[test]
+    fn test_table_signed_column_colors() {
+        let columns = vec![ColumnDef::new("Diff", 5, Alignment::Right, |r: &&str| {
+            CellValue::Text(r.to_string())
+        })
+        .signed()];
+        let widget = TableWidget::from_data(&columns, vec!["+.023", "-.041", "-", "0"]);
+        let config = test_config();
+
+        let buf = render_framework_widget(&widget, RENDER_WIDTH, 6, &config);
+        assert_eq!(buf[(6, 2)].fg, Color::Green);
+        assert_eq!(buf[(6, 3)].fg, config.error_fg);
+        // A lone dash means no value, not a negative one
+        assert_eq!(buf[(6, 4)].fg, Color::Reset);
+        assert_eq!(buf[(6, 5)].fg, Color::Reset);
+    }
+
+    #[test]
+    fn test_table_totals_row() {
+        let columns = vec![
+            ColumnDef::new("Player", 16, Alignment::Left, |r: &TestRow| {
+                CellValue::PlayerLink {
+                    display: r.name.clone(),
+                    player_id: r.id,
+                }
+            })
+            .with_total(ColumnTotal::Label("TOTAL".to_string())),
+            ColumnDef::new("G", 4, Alignment::Right, |r: &TestRow| {
+                CellValue::Text(r.value.to_string())
+            })
+            .with_total(ColumnTotal::Sum),
+        ];
+        let rows = create_test_rows();
+        let widget = TableWidget::from_data(&columns, rows.clone())
+            .with_totals(&columns, &rows)
+            .with_focused_row(Some(2));
+
+        let config = test_config();
+        let height = widget.preferred_height().unwrap();
+        assert_eq!(height, 7);
+        let buf = render_framework_widget(&widget, RENDER_WIDTH, height, &config);
+        assert_buffer(
+            &buf,
+            &[
+                "  Player               G",
+                "  ──────────────────────",
+                "  Auston Matthews     42",
+                "  Mitchell Marner     18",
+                "▶ William Nylander    28",
+                "  ──────────────────────",
+                "  TOTAL               88",
+            ],
+        );
+        // The totals row is not part of the navigable rows
+        assert_eq!(widget.row_count(), 3);
+        assert_eq!(widget.get_cell_value(3, 0), None);
+    }
+
+    #[test]
+    fn test_table_zebra_off_by_default() {
+        let mut config = test_config();
+        config.theme_name = Some("orange".to_string());
+        config.apply_theme();
+        let widget = TableWidget::from_data(&create_test_columns(), create_test_rows());
+        let buf = render_framework_widget(&widget, RENDER_WIDTH, 5, &config);
+        assert_ne!(Some(buf[(2, 3)].bg), config.stripe_style().bg);
+    }
+
     // === Navigation Tests ===
 
     #[test]
@@ -893,4 +1493,382 @@ mod tests {
         // Navigate left from col 3 (Link) should jump to col 0 (Link), skipping cols 1-2 (Text)
         assert_eq!(widget.find_prev_link_column(3), Some(0));
     }
+
+    fn column_texts(table: &TableWidget, col: usize) -> Vec<String> {
+        (0..table.row_count())
+            .map(|row| {
+                table
+                    .get_cell_value(row, col)
+                    .unwrap()
+                    .display_text()
+                    .to_string()
+            })
+            .collect()
+    }
+
+    fn text_table(values: &[&str]) -> TableWidget {
+        let columns = vec![ColumnDef::new("V", 5, Alignment::Right, |v: &String| {
+            CellValue::Text(v.clone())
+        })];
+        TableWidget::from_data(
+            &columns,
+            values.iter().map(|v| v.to_string()).collect::<Vec<_>>(),
+        )
+    }
+
+    #[test]
+    fn test_sorted_by_numeric_values() {
+        let table = text_table(&["9", "10", "2", "-1", "3.5"]).sorted_by(0, true);
+        assert_eq!(column_texts(&table, 0), vec!["-1", "2", "3.5", "9", "10"]);
+    }
+
+    #[test]
+    fn test_sorted_by_lexical_values() {
+        let table = text_table(&["b", "C", "a"]).sorted_by(0, true);
+        assert_eq!(column_texts(&table, 0), vec!["C", "a", "b"]);
+    }
+
+    #[test]
+    fn test_sorted_by_mixed_values_puts_numbers_first() {
+        let table = text_table(&["b", "10", "-", "9"]).sorted_by(0, true);
+        assert_eq!(column_texts(&table, 0), vec!["9", "10", "-", "b"]);
+    }
+
+    #[test]
+    fn test_sorted_by_descending() {
+        let table =
+            TableWidget::from_data(&create_test_columns(), create_test_rows()).sorted_by(1, false);
+        assert_eq!(column_texts(&table, 1), vec!["42", "28", "18"]);
+    }
+
+    #[test]
+    fn test_sorted_by_link_column() {
+        let table =
+            TableWidget::from_data(&create_test_columns(), create_test_rows()).sorted_by(0, false);
+        assert_eq!(
+            column_texts(&table, 0),
+            vec!["William Nylander", "Mitchell Marner", "Auston Matthews"]
+        );
+        // Links stay intact after sorting
+        assert!(table.get_cell_value(0, 0).unwrap().is_link());
+    }
+
+    #[test]
+    fn test_sorted_by_is_stable() {
+        let columns = vec![
+            ColumnDef::new("Name", 5, Alignment::Left, |r: &(&str, i32)| {
+                CellValue::Text(r.0.to_string())
+            }),
+            ColumnDef::new("N", 3, Alignment::Right, |r: &(&str, i32)| {
+                CellValue::Text(r.1.to_string())
+            }),
+        ];
+        let rows = vec![("a", 1), ("b", 2), ("c", 1), ("d", 2)];
+
+        let asc = TableWidget::from_data(&columns, rows.clone()).sorted_by(1, true);
+        assert_eq!(column_texts(&asc, 0), vec!["a", "c", "b", "d"]);
+
+        let desc = TableWidget::from_data(&columns, rows).sorted_by(1, false);
+        assert_eq!(column_texts(&desc, 0), vec!["b", "d", "a", "c"]);
+    }
+
+    #[test]
+    fn test_sorted_by_out_of_range_column_keeps_order() {
+        let table =
+            TableWidget::from_data(&create_test_columns(), create_test_rows()).sorted_by(5, true);
+        assert_eq!(column_texts(&table, 1), vec!["42", "18", "28"]);
+    }
+
+    fn wide_table_columns() -> Vec<ColumnDef<(i32, &'static str, &'static str)>> {
+        vec![
+            ColumnDef::new("#", 2, Alignment::Right, |r: &(i32, &str, &str)| {
+                CellValue::Text(r.0.to_string())
+            }),
+            ColumnDef::new("Name", 6, Alignment::Left, |r: &(i32, &str, &str)| {
+                CellValue::Text(r.1.to_string())
+            }),
+            ColumnDef::new("G", 3, Alignment::Right, |_: &(i32, &str, &str)| {
+                CellValue::Text("1".to_string())
+            }),
+            ColumnDef::new("A", 3, Alignment::Right, |_: &(i32, &str, &str)| {
+                CellValue::Text("2".to_string())
+            }),
+            ColumnDef::new("PTS", 3, Alignment::Right, |_: &(i32, &str, &str)| {
+                CellValue::Text("3".to_string())
+            }),
+            ColumnDef::new("TOI", 5, Alignment::Right, |r: &(i32, &str, &str)| {
+                CellValue::Text(r.2.to_string())
+            }),
+        ]
+    }
+
+    fn wide_table() -> TableWidget {
+        TableWidget::from_data(&wide_table_columns(), vec![(34, "Matts", "18:05")])
+            .with_frozen_columns(2)
+    }
+
+    #[test]
+    fn test_narrow_table_clips_trailing_columns() {
+        let config = test_config();
+        let buf = render_framework_widget(&wide_table(), 24, 3, &config);
+        assert_buffer(
+            &buf,
+            &[
+                "   #  Name      G    A ▸",
+                "  ────────────────────",
+                "  34  Matts     1    2",
+            ],
+        );
+    }
+
+    #[test]
+    fn test_column_offset_reveals_later_columns() {
+        let config = test_config();
+        let widget = wide_table().with_column_offset(2);
+        let buf = render_framework_widget(&widget, 24, 3, &config);
+        assert_buffer(
+            &buf,
+            &[
+                "   #  Name  ◂ PTS    TOI",
+                "  ──────────────────────",
+                "  34  Matts     3  18:05",
+            ],
+        );
+    }
+
+    #[test]
+    fn test_column_offset_is_clamped() {
+        let config = test_config();
+        let clamped = render_framework_widget(&wide_table().with_column_offset(9), 24, 3, &config);
+        let max = render_framework_widget(&wide_table().with_column_offset(2), 24, 3, &config);
+        assert_eq!(clamped, max);
+    }
+
+    #[test]
+    fn test_column_offset_ignored_when_table_fits() {
+        let config = test_config();
+        let widget = wide_table().with_column_offset(3);
+        let buf = render_framework_widget(&widget, RENDER_WIDTH, 1, &config);
+        assert_buffer(&buf, &["   #  Name      G    A  PTS    TOI"]);
+    }
+
+    fn numbered_columns() -> Vec<ColumnDef<u8>> {
+        vec![ColumnDef::new("#", 2, Alignment::Right, |n: &u8| {
+            CellValue::Text(n.to_string())
+        })
+        .with_total(ColumnTotal::Sum)]
+    }
+
+    fn numbered_table(count: u8) -> TableWidget {
+        TableWidget::from_data(&numbered_columns(), (1..=count).collect::<Vec<_>>())
+    }
+
+    #[test]
+    fn test_overflowing_rows_show_scroll_indicator() {
+        let config = test_config();
+        let buf = render_framework_widget(&numbered_table(6), 6, 6, &config);
+        assert_buffer(
+            &buf,
+            &["   #  ", "  ──  ", "   1 █", "   2 █", "   3 █", "   4 │"],
+        );
+    }
+
+    #[test]
+    fn test_row_offset_reveals_later_rows() {
+        let config = test_config();
+        let widget = numbered_table(6).with_row_offset(2);
+        let buf = render_framework_widget(&widget, 6, 6, &config);
+        assert_buffer(
+            &buf,
+            &["   #  ", "  ──  ", "   3 │", "   4 █", "   5 █", "   6 █"],
+        );
+    }
+
+    #[test]
+    fn test_row_offset_is_clamped() {
+        let config = test_config();
+        let clamped = render_framework_widget(&numbered_table(6).with_row_offset(9), 6, 6, &config);
+        let max = render_framework_widget(&numbered_table(6).with_row_offset(2), 6, 6, &config);
+        assert_eq!(clamped, max);
+    }
+
+    #[test]
+    fn test_focused_row_scrolls_into_view() {
+        let config = test_config();
+        let widget = numbered_table(6).with_focused_row(Some(4));
+        let buf = render_framework_widget(&widget, 6, 6, &config);
+        assert_eq!(buffer_lines(&buf)[5], "▶  5 █");
+
+        // Scrolled past the focused row, it comes back to the top
+        let widget = numbered_table(6)
+            .with_row_offset(2)
+            .with_focused_row(Some(0));
+        let buf = render_framework_widget(&widget, 6, 6, &config);
+        assert_eq!(buffer_lines(&buf)[2], "▶  1 █");
+    }
+
+    #[test]
+    fn test_rows_leave_room_for_totals() {
+        let config = test_config();
+        let rows: Vec<u8> = (1..=6).collect();
+        let widget = numbered_table(6).with_totals(&numbered_columns(), &rows);
+        let buf = render_framework_widget(&widget, 6, 6, &config);
+        assert_buffer(
+            &buf,
+            &["   #  ", "  ──  ", "   1 █", "   2 │", "  ──  ", "  21  "],
+        );
+    }
+
+    #[test]
+    fn test_no_scroll_indicator_when_rows_fit() {
+        let config = test_config();
+        let buf = render_framework_widget(&numbered_table(3), 6, 6, &config);
+        assert_buffer(
+            &buf,
+            &["   #  ", "  ──  ", "   1  ", "   2  ", "   3  ", "      "],
+        );
+    }
+
+    #[test]
+    fn test_equal_priority_columns_hidden_right_to_left() {
+        let config = test_config();
+        let mut columns = wide_table_columns();
+        columns[3].priority = Some(1);
+        columns[4].priority = Some(1);
+        let widget =
+            TableWidget::from_data(&columns, vec![(34, "Matts", "18:05")]).with_frozen_columns(2);
+
+        // PTS alone is enough to make room
+        let buf = render_framework_widget(&widget, 30, 3, &config);
+        assert_buffer(
+            &buf,
+            &[
+                "   #  Name      G    A    TOI",
+                "  ───────────────────────────",
+                "  34  Matts     1    2  18:05",
+            ],
+        );
+
+        // Narrower, A goes too; nothing left to scroll to
+        let buf = render_framework_widget(&widget, 24, 3, &config);
+        assert_buffer(
+            &buf,
+            &[
+                "   #  Name      G    TOI",
+                "  ──────────────────────",
+                "  34  Matts     1  18:05",
+            ],
+        );
+        assert_eq!(
+            widget.column_spans(24),
+            vec![(0, 2, 2), (1, 6, 6), (2, 14, 3), (5, 19, 5)]
+        );
+    }
+
+    #[test]
+    fn test_lowest_priority_columns_hidden_first() {
+        let config = test_config();
+        let columns = wide_table_columns()
+            .into_iter()
+            .zip([None, None, Some(3), Some(1), Some(4), Some(2)])
+            .map(|(column, priority)| ColumnDef { priority, ..column })
+            .collect::<Vec<_>>();
+        let widget = TableWidget::from_data(&columns, vec![(34, "Matts", "18:05")]);
+
+        // A (1) then TOI (2) go before the G and PTS to their left
+        let buf = render_framework_widget(&widget, 24, 3, &config);
+        assert_buffer(
+            &buf,
+            &[
+                "   #  Name      G  PTS",
+                "  ────────────────────",
+                "  34  Matts     1    3",
+            ],
+        );
+
+        // Only the columns without a priority are left
+        let buf = render_framework_widget(&widget, 14, 3, &config);
+        assert_buffer(&buf, &["   #  Name", "  ──────────", "  34  Matts"]);
+    }
+
+    #[test]
+    fn test_prioritized_columns_shown_when_table_fits() {
+        let mut columns = wide_table_columns();
+        columns[3].priority = Some(1);
+        let widget = TableWidget::from_data(&columns, vec![(34, "Matts", "18:05")]);
+        let buf = render_framework_widget(&widget, RENDER_WIDTH, 1, &test_config());
+        assert_buffer(&buf, &["   #  Name      G    A  PTS    TOI"]);
+    }
+
+    fn auto_width_columns(max_width: Option<usize>) -> Vec<ColumnDef<TestRow>> {
+        let mut player = ColumnDef::new("Player", 6, Alignment::Left, |r: &TestRow| {
+            CellValue::PlayerLink {
+                display: r.name.clone(),
+                player_id: r.id,
+            }
+        })
+        .auto_width();
+        if let Some(max) = max_width {
+            player = player.with_max_width(max);
+        }
+        vec![
+            player,
+            ColumnDef::new("G", 3, Alignment::Right, |r: &TestRow| {
+                CellValue::Text(r.value.to_string())
+            }),
+        ]
+    }
+
+    #[test]
+    fn test_auto_width_fits_longest_cell() {
+        let widget = TableWidget::from_data(&auto_width_columns(None), create_test_rows());
+        // "William Nylander" is 16 chars: 2 selector + 16 + 2 spacing + 3
+        assert_eq!(widget.preferred_width(), Some(23));
+
+        let buf = render_framework_widget(&widget, RENDER_WIDTH, 5, &test_config());
+        assert_buffer(
+            &buf,
+            &[
+                "  Player              G",
+                "  ─────────────────────",
+                "  Auston Matthews    42",
+                "  Mitchell Marner    18",
+                "  William Nylander   28",
+            ],
+        );
+    }
+
+    #[test]
+    fn test_auto_width_respects_cap_and_minimum() {
+        let capped = TableWidget::from_data(&auto_width_columns(Some(10)), create_test_rows());
+        assert_eq!(capped.column_widths, vec![10, 3]);
+
+        let short = vec![TestRow {
+            name: "Bo".to_string(),
+            id: 1,
+            value: 1,
+        }];
+        let minimum = TableWidget::from_data(&auto_width_columns(None), short);
+        assert_eq!(minimum.column_widths, vec![6, 3]);
+    }
+
+    #[test]
+    fn test_auto_width_shrinks_to_fit_area() {
+        let widget = TableWidget::from_data(&auto_width_columns(None), create_test_rows());
+        assert_eq!(widget.fitted_widths(23), vec![16, 3]);
+        // 3 columns too wide: the auto-sized column gives them back
+        assert_eq!(widget.fitted_widths(20), vec![13, 3]);
+        // Never below the declared width
+        assert_eq!(widget.fitted_widths(5), vec![6, 3]);
+
+        let buf = render_framework_widget(&widget, 20, 3, &test_config());
+        assert_buffer(
+            &buf,
+            &[
+                "  Player           G",
+                "  ──────────────────",
+                "  Auston Mat...   42",
+            ],
+        );
+    }
 }
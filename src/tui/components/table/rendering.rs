@@ -4,21 +4,44 @@
 
 use ratatui::buffer::Buffer;
 use ratatui::layout::Rect;
-use ratatui::style::{Modifier, Style};
+use ratatui::style::{Color, Modifier, Style};
 
 use crate::config::DisplayConfig;
+use crate::tui::document::Viewport;
 use crate::tui::CellValue;
 
 use super::{TableWidget, SELECTOR_WIDTH};
 
+/// Color of positive values in signed columns
+const POSITIVE_FG: Color = Color::Green;
+
+/// Color for a signed column's cell text, or None when it has no sign
+fn sign_color(text: &str, config: &DisplayConfig) -> Option<Color> {
+    match text.chars().next() {
+        Some('+') => Some(POSITIVE_FG),
+        Some('-') if text.chars().count() > 1 => Some(config.error_fg),
+        _ => None,
+    }
+}
+
 impl TableWidget {
+    /// Whether a row links to one of the accented teams
+    fn is_row_accented(&self, row_cells: &[CellValue]) -> bool {
+        row_cells.iter().any(|cell| match cell {
+            CellValue::TeamLink { team_abbrev, .. } => self.accented_teams.contains(team_abbrev),
+            _ => false,
+        })
+    }
+
     /// Get the style for a cell based on whether it's the focused link cell
     ///
     /// Only link cells in focused rows get the selection style.
-    /// Other cells use normal styling.
+    /// Other cells use normal styling, with the favorite accent over it.
     pub(super) fn get_cell_style(
         &self,
         is_row_focused: bool,
+        is_row_emphasized: bool,
+        is_row_accented: bool,
         cell_value: &CellValue,
         config: &DisplayConfig,
     ) -> Style {
@@ -34,15 +57,45 @@ impl TableWidget {
                 Style::default().add_modifier(crate::config::SELECTION_STYLE_MODIFIER)
             }
         } else {
-            // Not focused or not a link: use fg2 from theme (or default if no theme)
-            if let Some(theme) = &config.theme {
+            let style = if is_row_emphasized {
+                config.emphasis_style()
+            } else if let Some(theme) = &config.theme {
+                // Not focused or not a link: use fg2 from theme (or default if no theme)
                 Style::default().fg(theme.fg2)
             } else {
                 Style::default()
+            };
+            if is_row_accented {
+                style.patch(config.favorite_style())
+            } else {
+                style
             }
         }
     }
 
+    /// Draw the scroll indicator for rows that overflow, in a one-column `area`
+    fn render_row_scrollbar(
+        &self,
+        area: Rect,
+        row_offset: usize,
+        buf: &mut Buffer,
+        config: &DisplayConfig,
+    ) {
+        let content_height = self.cell_data.len().min(u16::MAX as usize) as u16;
+        let viewport = Viewport::new(row_offset as u16, area.height, content_height);
+        let Some((top, length)) = viewport.scrollbar_thumb() else {
+            return;
+        };
+        for y in 0..area.height {
+            let (symbol, style) = if (top..top + length).contains(&y) {
+                (&config.box_chars.scrollbar_thumb, config.text_style())
+            } else {
+                (&config.box_chars.scrollbar_track, config.muted_style())
+            };
+            buf.set_string(area.x, area.y + y, symbol, style);
+        }
+    }
+
     /// Internal render implementation
     pub(super) fn render_internal(&self, area: Rect, buf: &mut Buffer, config: &DisplayConfig) {
         if area.height == 0 || area.width == 0 {
@@ -50,6 +103,9 @@ impl TableWidget {
         }
 
         let mut y = area.y;
+        let widths = self.fitted_widths(area.width as usize);
+        let (columns, clipped_left, clipped_right) =
+            self.visible_columns(&widths, area.width as usize);
 
         // Render column headers
         if y < area.bottom() {
@@ -61,41 +117,78 @@ impl TableWidget {
                 Style::default().add_modifier(Modifier::BOLD)
             };
 
-            for (col_idx, header) in self.column_headers.iter().enumerate() {
-                let width = self.column_widths[col_idx];
+            let frozen = self.frozen_columns.min(columns.len());
+            for (position, &col_idx) in columns.iter().enumerate() {
+                // Marker in the gap where scrolled-off columns were skipped
+                if clipped_left && position == frozen && x > area.x + 1 {
+                    buf.set_string(x - 2, y, &config.box_chars.clip_left, config.muted_style());
+                }
+                let width = widths[col_idx];
                 let align = self.column_aligns[col_idx];
-                let formatted = self.format_cell(header, width, align);
+                let formatted = self.format_cell(&self.column_headers[col_idx], width, align);
                 buf.set_string(x, y, &formatted, col_header_style);
                 x += width as u16 + 2;
             }
+            if clipped_right {
+                buf.set_string(
+                    area.right() - 1,
+                    y,
+                    &config.box_chars.clip_right,
+                    config.muted_style(),
+                );
+            }
             y += 1;
         }
 
-        // Render separator line under headers
-        if y < area.bottom() {
-            let total_width: usize = self.column_widths.iter().sum::<usize>()
-                + (self.column_widths.len().saturating_sub(1) * 2);
-
-            let separator = config.box_chars.horizontal.repeat(total_width);
-            let separator_line = format!("{}{}", " ".repeat(SELECTOR_WIDTH), separator);
+        let total_width: usize = columns.iter().map(|&col| widths[col]).sum::<usize>()
+            + (columns.len().saturating_sub(1) * 2);
 
-            let separator_style = if let Some(theme) = &config.theme {
-                Style::default().fg(theme.fg3)
-            } else {
-                Style::default()
-            };
+        let separator = config.box_chars.horizontal.repeat(total_width);
+        let separator_line = format!("{}{}", " ".repeat(SELECTOR_WIDTH), separator);
+        let separator_style = if let Some(theme) = &config.theme {
+            Style::default().fg(theme.fg3)
+        } else {
+            Style::default()
+        };
 
+        // Render separator line under headers
+        if y < area.bottom() {
             buf.set_string(area.x, y, &separator_line, separator_style);
             y += 1;
         }
 
+        // Rows get what the totals leave, scrolling when they don't all fit
+        let totals_height = if self.totals.is_some() { 2 } else { 0 };
+        let rows_area_height = area
+            .bottom()
+            .saturating_sub(y)
+            .saturating_sub(totals_height);
+        let row_offset = self.visible_row_offset(rows_area_height as usize);
+        if self.cell_data.len() > rows_area_height as usize {
+            self.render_row_scrollbar(
+                Rect::new(area.right() - 1, y, 1, rows_area_height),
+                row_offset,
+                buf,
+                config,
+            );
+        }
+        let rows_bottom = y + rows_area_height;
+
         // Render rows
-        for (row_idx, row_cells) in self.cell_data.iter().enumerate() {
-            if y >= area.bottom() {
+        for (row_idx, row_cells) in self.cell_data.iter().enumerate().skip(row_offset) {
+            if y >= rows_bottom {
                 break;
             }
 
             let is_row_focused = self.focused_row == Some(row_idx);
+            let is_row_emphasized = row_idx < self.emphasized_rows;
+            let is_row_accented = self.is_row_accented(row_cells);
+
+            // Stripe odd rows; the focused row keeps the plain selection styling
+            if self.zebra && row_idx % 2 == 1 && !is_row_focused {
+                let row_width = (SELECTOR_WIDTH + total_width).min(area.width as usize) as u16;
+                buf.set_style(Rect::new(area.x, y, row_width, 1), config.stripe_style());
+            }
 
             // Render selector indicator
             let selector = if is_row_focused {
@@ -114,13 +207,27 @@ impl TableWidget {
 
             // Render cells
             let mut x = area.x + SELECTOR_WIDTH as u16;
-            for (col_idx, cell_value) in row_cells.iter().enumerate() {
-                let width = self.column_widths[col_idx];
+            for &col_idx in &columns {
+                let Some(cell_value) = row_cells.get(col_idx) else {
+                    continue;
+                };
+                let width = widths[col_idx];
                 let align = self.column_aligns[col_idx];
                 let cell_text = cell_value.display_text();
                 let formatted = self.format_cell(cell_text, width, align);
 
-                let style = self.get_cell_style(is_row_focused, cell_value, config);
+                let mut style = self.get_cell_style(
+                    is_row_focused,
+                    is_row_emphasized,
+                    is_row_accented,
+                    cell_value,
+                    config,
+                );
+                if self.column_signed[col_idx] {
+                    if let Some(color) = sign_color(cell_text, config) {
+                        style = style.fg(color);
+                    }
+                }
 
                 buf.set_string(x, y, &formatted, style);
                 x += width as u16 + 2;
@@ -128,5 +235,25 @@ impl TableWidget {
 
             y += 1;
         }
+
+        // Render totals row below its own separator
+        if let Some(totals) = &self.totals {
+            if y < area.bottom() {
+                buf.set_string(area.x, y, &separator_line, separator_style);
+                y += 1;
+            }
+            if y < area.bottom() {
+                let totals_style = config.text_style().add_modifier(Modifier::BOLD);
+                let mut x = area.x + SELECTOR_WIDTH as u16;
+                for &col_idx in &columns {
+                    let width = widths[col_idx];
+                    let align = self.column_aligns[col_idx];
+                    let text = totals.get(col_idx).map(String::as_str).unwrap_or("");
+                    let formatted = self.format_cell(text, width, align);
+                    buf.set_string(x, y, &formatted, totals_style);
+                    x += width as u16 + 2;
+                }
+            }
+        }
     }
 }
@@ -1,16 +1,25 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
 use std::sync::Arc;
 
 use ratatui::{buffer::Buffer, layout::Rect};
 
-use nhl_api::{ClubGoalieStats, ClubSkaterStats, ClubStats, Standing};
+use nhl_api::{
+    ClubGoalieStats, ClubSkaterStats, ClubStats, Position, Roster, ScheduleGame, Standing,
+};
 
 use super::table::TableWidget;
-use crate::config::DisplayConfig;
+use crate::config::{DisplayConfig, LoadingStyle, DEFAULT_START_TIME_FORMAT};
+use crate::team_abbrev::abbrev_to_full_name;
+use crate::timezone::{format_start_time, LOCAL_TIMEZONE};
 use crate::tui::helpers::{ClubGoalieStatsSorting, ClubSkaterStatsSorting};
+use crate::tui::hit_test::HitRegion;
+use crate::tui::widgets::loading_animation::loading_animation_text;
 use crate::tui::widgets::{LoadingAnimation, StandaloneWidget};
 use crate::tui::{
     component::{Component, Element, ElementWidget},
-    document::{Document, DocumentBuilder, DocumentElement, DocumentView, FocusContext},
+    document::{Document, DocumentBuilder, DocumentElement, DocumentView, FindState, FocusContext},
     Alignment, CellValue, ColumnDef,
 };
 
@@ -20,9 +29,14 @@ pub struct TeamDetailDocumentProps {
     pub team_abbrev: String,
     pub standing: Option<Standing>,
     pub club_stats: Option<ClubStats>,
+    pub roster: Option<Roster>,
+    pub schedule: Option<Vec<ScheduleGame>>,
+    /// Format for the start times of upcoming games (`Config.start_time_format`)
+    pub start_time_format: String,
     pub loading: bool,
     pub selected_index: Option<usize>,
     pub scroll_offset: u16,
+    pub find: Option<FindState>,
     pub animation_frame: u8,
 }
 
@@ -39,19 +53,45 @@ impl Component for TeamDetailDocument {
             team_abbrev: props.team_abbrev.clone(),
             standing: props.standing.clone(),
             club_stats: props.club_stats.clone(),
+            roster: props.roster.clone(),
+            schedule: props.schedule.clone(),
+            start_time_format: props.start_time_format.clone(),
             loading: props.loading,
             selected_index: props.selected_index,
             scroll_offset: props.scroll_offset,
+            find: props.find.clone(),
             animation_frame: props.animation_frame,
         }))
     }
 }
 
+/// Sweater numbers by player id
+type SweaterNumbers = Arc<HashMap<i64, i32>>;
+
+/// One game of the schedule, from the team's point of view
+struct ScheduleRow {
+    date: String,
+    home: bool,
+    opponent: String,
+    /// Score for completed games (linking to the boxscore), otherwise status or start time
+    result: CellValue,
+}
+
 /// Document content for team detail view
 pub struct TeamDetailDocumentContent {
     pub team_abbrev: String,
     pub standing: Option<Standing>,
     pub club_stats: Option<ClubStats>,
+    pub roster: Option<Roster>,
+    /// This week's games; None while loading
+    pub schedule: Option<Vec<ScheduleGame>>,
+    pub animation_frame: u8,
+    pub loading_style: LoadingStyle,
+    pub loading_frame_divisor: u8,
+    /// Zone start times are shown in (`display.timezone`)
+    pub timezone: String,
+    /// Format for start times (`Config.start_time_format`)
+    pub start_time_format: String,
 }
 
 impl TeamDetailDocumentContent {
@@ -64,32 +104,180 @@ impl TeamDetailDocumentContent {
             team_abbrev,
             standing,
             club_stats,
+            roster: None,
+            schedule: None,
+            animation_frame: 0,
+            loading_style: LoadingStyle::default(),
+            loading_frame_divisor: 1,
+            timezone: LOCAL_TIMEZONE.to_string(),
+            start_time_format: DEFAULT_START_TIME_FORMAT.to_string(),
+        }
+    }
+
+    /// Set this week's games (None while they load)
+    pub fn with_schedule(mut self, schedule: Option<Vec<ScheduleGame>>) -> Self {
+        self.schedule = schedule;
+        self
+    }
+
+    /// Animation shown until the schedule arrives, holding each frame for `divisor` ticks
+    pub fn with_loading_animation(
+        mut self,
+        animation_frame: u8,
+        loading_style: LoadingStyle,
+        divisor: u8,
+    ) -> Self {
+        self.animation_frame = animation_frame;
+        self.loading_style = loading_style;
+        self.loading_frame_divisor = divisor;
+        self
+    }
+
+    /// Set the zone start times are shown in
+    pub fn with_timezone(mut self, timezone: impl Into<String>) -> Self {
+        self.timezone = timezone.into();
+        self
+    }
+
+    /// Set the format start times are shown with
+    pub fn with_start_time_format(mut self, start_time_format: impl Into<String>) -> Self {
+        self.start_time_format = start_time_format.into();
+        self
+    }
+
+    /// Set the current roster, which supplies sweater numbers
+    pub fn with_roster(mut self, roster: Option<Roster>) -> Self {
+        self.roster = roster;
+        self
+    }
+
+    /// Title used until the standings have loaded
+    fn fallback_title(&self) -> String {
+        abbrev_to_full_name(&self.team_abbrev)
+            .map(str::to_string)
+            .unwrap_or_else(|| self.team_abbrev.clone())
+    }
+
+    fn sweater_numbers(&self) -> SweaterNumbers {
+        let numbers = self
+            .roster
+            .iter()
+            .flat_map(|r| r.forwards.iter().chain(&r.defensemen).chain(&r.goalies))
+            .map(|p| (p.id, p.sweater_number))
+            .collect();
+        Arc::new(numbers)
+    }
+
+    fn schedule_row(&self, game: &ScheduleGame) -> ScheduleRow {
+        let home = game.home_team.abbrev == self.team_abbrev;
+        let (team, opponent) = if home {
+            (&game.home_team, &game.away_team)
+        } else {
+            (&game.away_team, &game.home_team)
+        };
+        let score = || {
+            format!(
+                "{}-{}",
+                team.score.unwrap_or(0),
+                opponent.score.unwrap_or(0)
+            )
+        };
+
+        let result = if game.game_state.is_final() {
+            let outcome = if team.score > opponent.score {
+                "W"
+            } else {
+                "L"
+            };
+            CellValue::GameLink {
+                display: format!("{} {}", outcome, score()),
+                game_id: game.id,
+            }
+        } else if game.game_state.is_live() {
+            CellValue::Text(format!("LIVE {}", score()))
+        } else {
+            let start_time = format_start_time(
+                &game.start_time_utc,
+                &self.timezone,
+                &self.start_time_format,
+            )
+            .unwrap_or_else(|| game.start_time_utc.clone());
+            CellValue::Text(start_time)
+        };
+
+        ScheduleRow {
+            date: game.game_date.clone().unwrap_or_default(),
+            home,
+            opponent: opponent.abbrev.clone(),
+            result,
         }
     }
 
-    /// Build skater stats table
-    fn build_skaters_table(&self, focus: &FocusContext) -> Option<DocumentElement> {
+    /// Build the schedule section, animated until the games arrive
+    fn build_schedule(&self, focus: &FocusContext) -> DocumentElement {
+        let body = match &self.schedule {
+            None => DocumentElement::text(loading_animation_text(
+                self.animation_frame,
+                self.loading_frame_divisor,
+                self.loading_style,
+                focus.use_unicode,
+            )),
+            Some(games) if games.is_empty() => DocumentElement::text("No games this week"),
+            Some(games) => {
+                let rows: Vec<ScheduleRow> = games.iter().map(|g| self.schedule_row(g)).collect();
+                let table = TableWidget::from_data(&schedule_columns(), rows)
+                    .with_focused_row(focus.focused_table_row("schedule"));
+                DocumentElement::table("schedule", table)
+            }
+        };
+
+        DocumentElement::group(vec![
+            DocumentElement::section_title("SCHEDULE - This Week", true),
+            body,
+        ])
+    }
+
+    /// Build the forwards or defense stats table
+    fn build_skaters_table(
+        &self,
+        focus: &FocusContext,
+        numbers: &SweaterNumbers,
+        defense: bool,
+    ) -> Option<DocumentElement> {
         let stats = self.club_stats.as_ref()?;
-        if stats.skaters.is_empty() {
+        let mut skaters: Vec<ClubSkaterStats> = stats
+            .skaters
+            .iter()
+            .filter(|s| (s.position == Position::Defense) == defense)
+            .cloned()
+            .collect();
+        if skaters.is_empty() {
             return None;
         }
+        skaters.sort_by_points_desc();
 
-        let mut sorted_skaters = stats.skaters.clone();
-        sorted_skaters.sort_by_points_desc();
-
-        let title = format!("SKATERS ({}) - Regular Season", stats.skaters.len());
-        let columns = skater_columns();
-        let table = TableWidget::from_data(&columns, sorted_skaters)
-            .with_focused_row(focus.focused_table_row("skaters"));
+        let (name, group) = if defense {
+            ("defense", "DEFENSE")
+        } else {
+            ("forwards", "FORWARDS")
+        };
+        let title = format!("{} ({}) - Regular Season", group, skaters.len());
+        let columns = skater_columns(numbers);
+        let table = TableWidget::from_data(&columns, skaters)
+            .with_focused_row(focus.focused_table_row(name));
 
         Some(DocumentElement::group(vec![
             DocumentElement::section_title(title, true),
-            DocumentElement::table("skaters", table),
+            DocumentElement::table(name, table),
         ]))
     }
 
     /// Build goalie stats table
-    fn build_goalies_table(&self, focus: &FocusContext) -> Option<DocumentElement> {
+    fn build_goalies_table(
+        &self,
+        focus: &FocusContext,
+        numbers: &SweaterNumbers,
+    ) -> Option<DocumentElement> {
         let stats = self.club_stats.as_ref()?;
         if stats.goalies.is_empty() {
             return None;
@@ -99,7 +287,7 @@ impl TeamDetailDocumentContent {
         sorted_goalies.sort_by_games_played_desc();
 
         let title = format!("GOALIES ({}) - Regular Season", stats.goalies.len());
-        let columns = goalie_columns();
+        let columns = goalie_columns(numbers);
         let table = TableWidget::from_data(&columns, sorted_goalies)
             .with_focused_row(focus.focused_table_row("goalies"));
 
@@ -112,7 +300,7 @@ impl TeamDetailDocumentContent {
 
 impl Document for TeamDetailDocumentContent {
     fn build(&self, focus: &FocusContext) -> Vec<DocumentElement> {
-        let mut builder = DocumentBuilder::new();
+        let mut builder = DocumentBuilder::new().compact(focus.compact_spacing);
 
         // Team header
         if let Some(ref standing) = self.standing {
@@ -132,19 +320,24 @@ impl Document for TeamDetailDocumentContent {
             );
             builder = builder.text(&record);
         } else {
-            builder = builder.heading(1, &self.team_abbrev);
+            builder = builder.heading(1, self.fallback_title());
         }
 
-        builder = builder.spacer(1);
+        builder = builder.decorative_spacer(1);
+
+        builder = builder
+            .element(self.build_schedule(focus))
+            .decorative_spacer(1);
 
-        // Skaters table
-        if let Some(skaters_table) = self.build_skaters_table(focus) {
-            builder = builder.element(skaters_table);
-            builder = builder.spacer(1);
+        let numbers = self.sweater_numbers();
+        for defense in [false, true] {
+            if let Some(skaters_table) = self.build_skaters_table(focus, &numbers, defense) {
+                builder = builder.element(skaters_table);
+                builder = builder.decorative_spacer(1);
+            }
         }
 
-        // Goalies table
-        if let Some(goalies_table) = self.build_goalies_table(focus) {
+        if let Some(goalies_table) = self.build_goalies_table(focus, &numbers) {
             builder = builder.element(goalies_table);
         }
 
@@ -158,18 +351,62 @@ impl Document for TeamDetailDocumentContent {
                 standing.team_name.default, standing.team_common_name.default
             )
         } else {
-            self.team_abbrev.clone()
+            self.fallback_title()
         }
     }
 
     fn id(&self) -> String {
         format!("team_detail_{}", self.team_abbrev)
     }
+
+    fn content_version(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.club_stats.is_some().hash(&mut hasher);
+        self.roster.is_some().hash(&mut hasher);
+        match &self.schedule {
+            Some(games) => games.len().hash(&mut hasher),
+            None => self.animation_frame.hash(&mut hasher),
+        }
+        hasher.finish()
+    }
+}
+
+/// Sweater number column, blank until the roster has loaded
+fn number_column<T: 'static>(numbers: &SweaterNumbers, player_id: fn(&T) -> i64) -> ColumnDef<T> {
+    let numbers = Arc::clone(numbers);
+    ColumnDef::new("#", 2, Alignment::Right, move |row: &T| {
+        CellValue::Text(
+            numbers
+                .get(&player_id(row))
+                .map(ToString::to_string)
+                .unwrap_or_default(),
+        )
+    })
+}
+
+/// Define columns for the schedule table
+fn schedule_columns() -> Vec<ColumnDef<ScheduleRow>> {
+    vec![
+        ColumnDef::new("Date", 10, Alignment::Left, |r: &ScheduleRow| {
+            CellValue::Text(r.date.clone())
+        }),
+        ColumnDef::new("Opp", 6, Alignment::Left, |r: &ScheduleRow| {
+            let prefix = if r.home { "vs" } else { "@" };
+            CellValue::TeamLink {
+                display: format!("{} {}", prefix, r.opponent),
+                team_abbrev: r.opponent.clone(),
+            }
+        }),
+        ColumnDef::new("Result", 8, Alignment::Left, |r: &ScheduleRow| {
+            r.result.clone()
+        }),
+    ]
 }
 
 /// Define columns for skater stats table
-fn skater_columns() -> Vec<ColumnDef<ClubSkaterStats>> {
+fn skater_columns(numbers: &SweaterNumbers) -> Vec<ColumnDef<ClubSkaterStats>> {
     vec![
+        number_column(numbers, |s: &ClubSkaterStats| s.player_id),
         ColumnDef::new("Player", 20, Alignment::Left, |s: &ClubSkaterStats| {
             CellValue::PlayerLink {
                 display: format!("{} {}", s.first_name.default, s.last_name.default),
@@ -201,8 +438,9 @@ fn skater_columns() -> Vec<ColumnDef<ClubSkaterStats>> {
 }
 
 /// Define columns for goalie stats table
-fn goalie_columns() -> Vec<ColumnDef<ClubGoalieStats>> {
+fn goalie_columns(numbers: &SweaterNumbers) -> Vec<ColumnDef<ClubGoalieStats>> {
     vec![
+        number_column(numbers, |g: &ClubGoalieStats| g.player_id),
         ColumnDef::new("Player", 20, Alignment::Left, |g: &ClubGoalieStats| {
             CellValue::PlayerLink {
                 display: format!("{} {}", g.first_name.default, g.last_name.default),
@@ -238,30 +476,37 @@ struct TeamDetailDocumentWidget {
     team_abbrev: String,
     standing: Option<Standing>,
     club_stats: Option<ClubStats>,
+    roster: Option<Roster>,
+    schedule: Option<Vec<ScheduleGame>>,
+    start_time_format: String,
     loading: bool,
     selected_index: Option<usize>,
     scroll_offset: u16,
+    find: Option<FindState>,
     animation_frame: u8,
 }
 
-impl ElementWidget for TeamDetailDocumentWidget {
-    fn render(&self, area: Rect, buf: &mut Buffer, config: &DisplayConfig) {
-        // Show animation if loading or data hasn't arrived yet
+impl TeamDetailDocumentWidget {
+    /// Document view, or None while loading or before data has arrived
+    fn view(&self, area: Rect, config: &DisplayConfig) -> Option<DocumentView> {
         if self.loading || self.club_stats.is_none() {
-            LoadingAnimation::new(self.animation_frame).render(area, buf, config);
-            return;
-        }
-
-        if area.width == 0 || area.height == 0 {
-            return;
+            return None;
         }
 
-        // Create document and render with DocumentView
         let doc = TeamDetailDocumentContent::new(
             self.team_abbrev.clone(),
             self.standing.clone(),
             self.club_stats.clone(),
-        );
+        )
+        .with_roster(self.roster.clone())
+        .with_schedule(self.schedule.clone())
+        .with_loading_animation(
+            self.animation_frame,
+            config.loading_style,
+            config.loading_frame_divisor,
+        )
+        .with_timezone(config.timezone.clone())
+        .with_start_time_format(self.start_time_format.clone());
 
         let mut view = DocumentView::new(Arc::new(doc), area.height);
 
@@ -272,19 +517,45 @@ impl ElementWidget for TeamDetailDocumentWidget {
 
         // Apply scroll offset
         view.set_scroll_offset(self.scroll_offset);
+        view.set_find(self.find.clone());
+        Some(view)
+    }
+}
+
+impl ElementWidget for TeamDetailDocumentWidget {
+    fn render(&self, area: Rect, buf: &mut Buffer, config: &DisplayConfig) {
+        // Show animation if loading or data hasn't arrived yet
+        let Some(mut view) = self.view(area, config) else {
+            LoadingAnimation::new(self.animation_frame).render(area, buf, config);
+            return;
+        };
+
+        if area.width == 0 || area.height == 0 {
+            return;
+        }
 
         // Render the document
         view.render(area, buf, config);
     }
 
+    fn hit_regions(&self, area: Rect, config: &DisplayConfig) -> Vec<HitRegion> {
+        self.view(area, config)
+            .map(|view| view.hit_regions(area, config))
+            .unwrap_or_default()
+    }
+
     fn clone_box(&self) -> Box<dyn ElementWidget> {
         Box::new(TeamDetailDocumentWidget {
             team_abbrev: self.team_abbrev.clone(),
             standing: self.standing.clone(),
             club_stats: self.club_stats.clone(),
+            roster: self.roster.clone(),
+            schedule: self.schedule.clone(),
+            start_time_format: self.start_time_format.clone(),
             loading: self.loading,
             selected_index: self.selected_index,
             scroll_offset: self.scroll_offset,
+            find: self.find.clone(),
             animation_frame: self.animation_frame,
         })
     }
@@ -293,8 +564,11 @@ impl ElementWidget for TeamDetailDocumentWidget {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::tui::document::FocusContext;
-    use nhl_api::{ClubGoalieStats, ClubSkaterStats, LocalizedString, Position};
+    use crate::tui::document::{FocusContext, LinkTarget};
+    use crate::tui::testing::assert_buffer;
+    use nhl_api::{
+        ClubGoalieStats, ClubSkaterStats, Handedness, LocalizedString, Position, RosterPlayer,
+    };
     use ratatui::{buffer::Buffer, layout::Rect};
 
     fn create_test_skater(
@@ -370,6 +644,32 @@ mod tests {
         }
     }
 
+    fn create_test_roster_player(id: i64, sweater_number: i32, position: Position) -> RosterPlayer {
+        RosterPlayer {
+            id,
+            headshot: String::new(),
+            first_name: LocalizedString {
+                default: "Test".to_string(),
+            },
+            last_name: LocalizedString {
+                default: format!("Player{}", id),
+            },
+            sweater_number,
+            position,
+            shoots_catches: Handedness::Left,
+            height_in_inches: 72,
+            weight_in_pounds: 200,
+            height_in_centimeters: 183,
+            weight_in_kilograms: 91,
+            birth_date: "2000-01-01".to_string(),
+            birth_city: LocalizedString {
+                default: "Toronto".to_string(),
+            },
+            birth_country: "CAN".to_string(),
+            birth_state_province: None,
+        }
+    }
+
     fn create_test_standing() -> Standing {
         Standing {
             conference_abbrev: Some("Eastern".to_string()),
@@ -440,6 +740,13 @@ mod tests {
         assert_eq!(doc.id(), "team_detail_TST");
     }
 
+    #[test]
+    fn test_known_team_without_standing_uses_full_name() {
+        let doc = TeamDetailDocumentContent::new("TOR".to_string(), None, None);
+
+        assert_eq!(doc.title(), "Toronto Maple Leafs");
+    }
+
     #[test]
     fn test_focusable_positions() {
         let standing = create_test_standing();
@@ -468,6 +775,135 @@ mod tests {
         assert_eq!(ids.len(), 3);
     }
 
+    #[test]
+    fn test_roster_grouped_by_position() {
+        let mut club_stats = create_test_club_stats();
+        club_stats.skaters.push(create_test_skater(
+            4,
+            "Sam",
+            "Blue",
+            Position::Defense,
+            20,
+            2,
+            8,
+            10,
+        ));
+        // Jane Smith (2) has no roster entry, so her number stays blank
+        let roster = Roster {
+            forwards: vec![create_test_roster_player(1, 91, Position::Center)],
+            defensemen: vec![create_test_roster_player(4, 44, Position::Defense)],
+            goalies: vec![create_test_roster_player(3, 1, Position::Goalie)],
+        };
+
+        let doc = TeamDetailDocumentContent::new("TST".to_string(), None, Some(club_stats))
+            .with_roster(Some(roster))
+            .with_schedule(Some(Vec::new()));
+        let (buf, _) = doc.render_full(70, &DisplayConfig::default(), &FocusContext::default());
+
+        assert_buffer(
+            &buf,
+            &[
+                "TST",
+                "═══",
+                "",
+                "SCHEDULE - This Week",
+                "════════════════════",
+                "",
+                "No games this week",
+                "",
+                "FORWARDS (2) - Regular Season",
+                "═════════════════════════════",
+                "",
+                "   #  Player                Pos    GP    G    A   PTS   +/-   PIM",
+                "  ───────────────────────────────────────────────────────────────",
+                "  91  John Doe              C      20   10   15    25    +5    10",
+                "      Jane Smith            LW     18    8   12    20    +5    10",
+                "",
+                "DEFENSE (1) - Regular Season",
+                "════════════════════════════",
+                "",
+                "   #  Player                Pos    GP    G    A   PTS   +/-   PIM",
+                "  ───────────────────────────────────────────────────────────────",
+                "  44  Sam Blue              D      20    2    8    10    +5    10",
+                "",
+                "GOALIES (1) - Regular Season",
+                "════════════════════════════",
+                "",
+                "   #  Player                  GP    W    L  OTL    GAA    SV%   SO",
+                "  ────────────────────────────────────────────────────────────────",
+                "   1  Bob Johnson             15    8    5    2   2.50  0.915    2",
+            ],
+        );
+        // Focus runs through forwards, defense, then goalies
+        assert_eq!(doc.focusable_ids().len(), 4);
+    }
+
+    #[test]
+    fn test_schedule_section() {
+        let schedule = crate::fixtures::create_mock_team_schedule("TST").games;
+        let doc = TeamDetailDocumentContent::new("TST".to_string(), None, None)
+            .with_schedule(Some(schedule))
+            .with_timezone("America/Toronto")
+            .with_start_time_format("%H:%M");
+
+        let (buf, _) = doc.render_full(40, &DisplayConfig::default(), &FocusContext::default());
+
+        assert_buffer(
+            &buf,
+            &[
+                "TST",
+                "═══",
+                "",
+                "SCHEDULE - This Week",
+                "════════════════════",
+                "",
+                "  Date        Opp     Result",
+                "  ────────────────────────────",
+                "  2024-11-18  vs MTL  W 3-2",
+                "  2024-11-20  @ TOR   L 2-3",
+                "  2024-11-22  vs OTT  LIVE 3-2",
+                "  2024-11-24  @ NYR   19:00",
+                "",
+            ],
+        );
+        // Only completed games link to their boxscore
+        let game_links: Vec<_> = doc
+            .focusable_link_targets()
+            .into_iter()
+            .flatten()
+            .filter(|target| matches!(target, LinkTarget::Action(a) if a.starts_with("game:")))
+            .collect();
+        assert_eq!(
+            game_links,
+            vec![
+                LinkTarget::Action("game:2024020100".to_string()),
+                LinkTarget::Action("game:2024020101".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_schedule_loading_shows_animation() {
+        let doc = TeamDetailDocumentContent::new("TST".to_string(), None, None)
+            .with_loading_animation(0, LoadingStyle::Ascii, 1);
+
+        let (buf, _) = doc.render_full(40, &DisplayConfig::default(), &FocusContext::default());
+
+        assert_buffer(
+            &buf,
+            &[
+                "TST",
+                "═══",
+                "",
+                "SCHEDULE - This Week",
+                "════════════════════",
+                "",
+                "-",
+                "",
+            ],
+        );
+    }
+
     /// Regression test for buffer overflow when rendering tables with limited height.
     #[test]
     fn test_rendering_with_limited_height_does_not_panic() {
@@ -509,9 +945,13 @@ mod tests {
             team_abbrev: "TST".to_string(),
             standing: Some(standing),
             club_stats: Some(club_stats),
+            roster: None,
+            schedule: None,
+            start_time_format: DEFAULT_START_TIME_FORMAT.to_string(),
             loading: false,
             selected_index: None,
             scroll_offset: 0,
+            find: None,
             animation_frame: 0,
         };
 
@@ -553,9 +993,13 @@ mod tests {
             team_abbrev: "TST".to_string(),
             standing: Some(standing),
             club_stats: Some(club_stats),
+            roster: None,
+            schedule: None,
+            start_time_format: DEFAULT_START_TIME_FORMAT.to_string(),
             loading: false,
             selected_index: None,
             scroll_offset: 0,
+            find: None,
             animation_frame: 0,
         };
 
@@ -574,9 +1018,13 @@ mod tests {
             team_abbrev: "TST".to_string(),
             standing: None,
             club_stats: None,
+            roster: None,
+            schedule: None,
+            start_time_format: DEFAULT_START_TIME_FORMAT.to_string(),
             loading: true,
             selected_index: None,
             scroll_offset: 0,
+            find: None,
             animation_frame: 0,
         };
 
@@ -596,9 +1044,13 @@ mod tests {
             team_abbrev: "TST".to_string(),
             standing: None,
             club_stats: None,
+            roster: None,
+            schedule: None,
+            start_time_format: DEFAULT_START_TIME_FORMAT.to_string(),
             loading: false,
             selected_index: None,
             scroll_offset: 0,
+            find: None,
             animation_frame: 0,
         };
 
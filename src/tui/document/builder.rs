@@ -13,6 +13,8 @@ use super::link::LinkTarget;
 #[derive(Debug, Default)]
 pub struct DocumentBuilder {
     elements: Vec<DocumentElement>,
+    /// Shrink decorative spacers (see `decorative_spacer`)
+    compact: bool,
 }
 
 impl DocumentBuilder {
@@ -20,9 +22,16 @@ impl DocumentBuilder {
     pub fn new() -> Self {
         Self {
             elements: Vec::new(),
+            compact: false,
         }
     }
 
+    /// Set whether decorative spacers are shrunk to save rows
+    pub fn compact(mut self, compact: bool) -> Self {
+        self.compact = compact;
+        self
+    }
+
     /// Add a heading element
     ///
     /// # Arguments
@@ -106,6 +115,18 @@ impl DocumentBuilder {
         self
     }
 
+    /// Add vertical spacing that only separates sections visually
+    ///
+    /// Halved (rounding down) when compact, so a one-line gap disappears.
+    /// Use `spacer` where the layout relies on the blank lines.
+    pub fn decorative_spacer(self, height: u16) -> Self {
+        let height = if self.compact { height / 2 } else { height };
+        if height == 0 {
+            return self;
+        }
+        self.spacer(height)
+    }
+
     /// Add a pre-built element
     pub fn element(mut self, element: DocumentElement) -> Self {
         self.elements.push(element);
@@ -337,6 +358,29 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_builder_decorative_spacer() {
+        let elements = DocumentBuilder::new().decorative_spacer(3).build();
+        assert!(matches!(
+            elements[..],
+            [DocumentElement::Spacer { height: 3 }]
+        ));
+
+        let elements = DocumentBuilder::new()
+            .compact(true)
+            .decorative_spacer(3)
+            .decorative_spacer(1)
+            .spacer(1)
+            .build();
+        assert!(matches!(
+            elements[..],
+            [
+                DocumentElement::Spacer { height: 1 },
+                DocumentElement::Spacer { height: 1 }
+            ]
+        ));
+    }
+
     #[test]
     fn test_builder_element() {
         let elem = DocumentElement::text("Direct element");
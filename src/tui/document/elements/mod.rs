@@ -8,13 +8,16 @@ mod render;
 use ratatui::buffer::Buffer;
 use ratatui::layout::Rect;
 use ratatui::style::Style;
+use unicode_width::UnicodeWidthStr;
 
 use crate::big_digits::BIG_DIGIT_HEIGHT;
 use crate::config::DisplayConfig;
 use crate::tui::component::ElementWidget;
 use crate::tui::components::TableWidget;
-use crate::tui::widgets::{BigScore, ScoreBox, StandaloneWidget};
+use crate::tui::widgets::{BigScore, EmptyState, ScoreBox, StandaloneWidget};
+use crate::tui::CellValue;
 
+use super::find::SearchableSpan;
 use super::focus::{FocusableElement, FocusableId, RowPosition};
 use super::link::LinkTarget;
 
@@ -29,15 +32,26 @@ pub enum RowAlignment {
 }
 
 use render::{
-    render_group, render_heading, render_link, render_row, render_section_title, render_separator,
-    render_team_boxscore, render_text,
+    render_banner, render_compare_bar, render_group, render_heading, render_link, render_row,
+    render_section_title, render_separator, render_shots_by_period, render_team_boxscore,
+    render_text, row_child_areas,
 };
 
-pub use render::TEAM_BOXSCORE_SIDE_BY_SIDE_WIDTH;
+pub use render::team_boxscore_side_by_side_width;
+use render::team_boxscore_width;
 
 /// Height of column headers section (column names + separator)
 pub(crate) const TABLE_COLUMN_HEADER_HEIGHT: u16 = 2;
 
+/// Shots on goal for one period of a game
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PeriodShots {
+    /// Column label, e.g. "1" or "OT"
+    pub label: String,
+    pub away: i32,
+    pub home: i32,
+}
+
 /// Elements that can be part of a document
 #[derive(Clone)]
 #[allow(clippy::large_enum_variant)]
@@ -65,6 +79,12 @@ pub enum DocumentElement {
     /// Height is 2 (no underline) or 3 (with underline).
     SectionTitle { content: String, underline: bool },
 
+    /// One line centered across the width in the emphasis style
+    ///
+    /// Used to call out something about the whole document, such as a
+    /// special event game.
+    Banner { content: String },
+
     /// A link that can be focused and activated
     Link {
         display: String,
@@ -152,7 +172,8 @@ pub enum DocumentElement {
     /// Team boxscore with decorative borders
     ///
     /// Wraps three tables (forwards, defense, goalies) with section headers
-    /// and decorative box borders. Fixed width of 85 characters.
+    /// and decorative box borders. As wide as its widest table, shrinking to
+    /// the available width (tables then hide their low-priority columns).
     TeamBoxscore {
         /// Team name for section headers
         team_name: String,
@@ -180,6 +201,43 @@ pub enum DocumentElement {
         /// The BigScore widget
         big_score: BigScore,
     },
+
+    /// Centered icon and message for a list that loaded with nothing in it
+    ///
+    /// Distinct from the loading animation so an empty day doesn't look like
+    /// data that is still on its way.
+    EmptyStateElement { empty_state: EmptyState },
+
+    /// Side-by-side comparison of one stat between two teams
+    ///
+    /// Renders the label, the away value, a bar split proportionally between
+    /// the two amounts, and the home value:
+    /// ```text
+    /// Shots         30 ██████████████████████░░░░░░░░░░░░░░░░░░ 25
+    /// ```
+    /// Without unicode the bar is omitted: `Shots         30 - 25`.
+    CompareBar {
+        label: String,
+        away_display: String,
+        home_display: String,
+        away_amount: f64,
+        home_amount: f64,
+    },
+
+    /// Shots on goal per period as a small bar chart, one row per team
+    ///
+    /// Bars are scaled to the most shots either team had in any period:
+    /// ```text
+    ///       1             2             3
+    /// NJD  12 ████████    8 █████▍     10 ██████▋
+    /// BUF   6 ████        9 ██████      4 ██▋
+    /// ```
+    /// Without unicode the bars are omitted, leaving a numeric table.
+    ShotsByPeriod {
+        away_abbrev: String,
+        home_abbrev: String,
+        periods: Vec<PeriodShots>,
+    },
 }
 
 impl std::fmt::Debug for DocumentElement {
@@ -200,6 +258,7 @@ impl std::fmt::Debug for DocumentElement {
                 .field("content", content)
                 .field("underline", underline)
                 .finish(),
+            Self::Banner { content } => f.debug_struct("Banner").field("content", content).finish(),
             Self::Link {
                 display,
                 target,
@@ -272,11 +331,132 @@ impl std::fmt::Debug for DocumentElement {
                 .field("away", &big_score.away_abbrev)
                 .field("home", &big_score.home_abbrev)
                 .finish(),
+            Self::EmptyStateElement { empty_state } => f
+                .debug_struct("EmptyStateElement")
+                .field("message", &empty_state.message)
+                .finish(),
+            Self::CompareBar {
+                label,
+                away_display,
+                home_display,
+                ..
+            } => f
+                .debug_struct("CompareBar")
+                .field("label", label)
+                .field("away", away_display)
+                .field("home", home_display)
+                .finish(),
+            Self::ShotsByPeriod {
+                away_abbrev,
+                home_abbrev,
+                periods,
+            } => f
+                .debug_struct("ShotsByPeriod")
+                .field("away", away_abbrev)
+                .field("home", home_abbrev)
+                .field("periods", &periods.len())
+                .finish(),
         }
     }
 }
 
 impl DocumentElement {
+    /// Value of the focused table cell `id`, if this element contains it
+    ///
+    /// Only finds the cell when the element was built with `id` focused,
+    /// since tables learn their focused row at build time.
+    pub fn focused_cell(&self, id: &FocusableId) -> Option<CellValue> {
+        let FocusableId::TableCell { col, .. } = id else {
+            return None;
+        };
+        self.table_containing(id)?
+            .focused_row_cells()?
+            .get(*col)
+            .cloned()
+    }
+
+    /// Header and description of each described column of the table holding `id`
+    pub fn column_legend(&self, id: &FocusableId) -> Option<Vec<(String, String)>> {
+        Some(self.table_containing(id)?.column_legend())
+    }
+
+    /// Table that the table cell `id` belongs to, if this element contains it
+    fn table_containing(&self, id: &FocusableId) -> Option<&TableWidget> {
+        let FocusableId::TableCell { table_name, .. } = id else {
+            return None;
+        };
+        let contains = |focusable: &[FocusableElement]| focusable.iter().any(|f| &f.id == id);
+
+        match self {
+            Self::Table { widget, focusable } if contains(focusable) => Some(widget),
+            Self::TeamBoxscore {
+                forwards_table,
+                defense_table,
+                goalies_table,
+                focusable,
+                ..
+            } if contains(focusable) => Some(if table_name.ends_with("_forwards") {
+                forwards_table
+            } else if table_name.ends_with("_defense") {
+                defense_table
+            } else {
+                goalies_table
+            }),
+            Self::Group { children, .. } | Self::Row { children, .. } => {
+                children.iter().find_map(|child| child.table_containing(id))
+            }
+            Self::Indented { element, .. } => element.table_containing(id),
+            _ => None,
+        }
+    }
+
+    /// Collect the text find can match, with the line each piece is drawn on
+    ///
+    /// Covers text, headings and links; tables contribute their link cells.
+    pub fn collect_searchable(&self, out: &mut Vec<SearchableSpan>, y_offset: u16) {
+        match self {
+            Self::Text { content, .. } => {
+                for (i, line) in content.lines().enumerate() {
+                    out.push(SearchableSpan::new(y_offset + i as u16, line));
+                }
+            }
+            Self::Heading { content, .. } => out.push(SearchableSpan::new(y_offset, content)),
+            Self::Link { display, .. } => out.push(SearchableSpan::new(y_offset, display)),
+            Self::Group { children, .. } => {
+                let mut child_offset = y_offset;
+                for child in children {
+                    child.collect_searchable(out, child_offset);
+                    child_offset += child.height();
+                }
+            }
+            Self::Row { children, .. } => {
+                for child in children {
+                    child.collect_searchable(out, y_offset);
+                }
+            }
+            Self::Indented { element, .. } => element.collect_searchable(out, y_offset),
+            Self::Table { widget, focusable } => {
+                searchable_cells(out, focusable, y_offset, |_| widget)
+            }
+            Self::TeamBoxscore {
+                forwards_table,
+                defense_table,
+                goalies_table,
+                focusable,
+                ..
+            } => searchable_cells(out, focusable, y_offset, |table_name| {
+                if table_name.ends_with("_forwards") {
+                    forwards_table
+                } else if table_name.ends_with("_defense") {
+                    defense_table
+                } else {
+                    goalies_table
+                }
+            }),
+            _ => {}
+        }
+    }
+
     /// Calculate the height this element needs
     pub fn height(&self) -> u16 {
         match self {
@@ -300,6 +480,7 @@ impl DocumentElement {
                     2
                 }
             }
+            Self::Banner { .. } => 1,
             Self::Link { .. } => 1,
             Self::Separator => 1,
             Self::Spacer { height } => *height,
@@ -311,7 +492,7 @@ impl DocumentElement {
                 children.iter().map(|c| c.height()).max().unwrap_or(0)
             }
             Self::ScoreBoxElement { score_box, .. } => {
-                // ScoreBox has fixed height of 6
+                // Six rows for a box plus its scorer lines, or one compact line
                 score_box.preferred_height().unwrap_or(6)
             }
             Self::Indented { element, .. } => {
@@ -349,6 +530,16 @@ impl DocumentElement {
             Self::BigScoreElement { big_score } => {
                 big_score.preferred_height().unwrap_or(BIG_DIGIT_HEIGHT + 1)
             }
+            Self::EmptyStateElement { empty_state } => empty_state.preferred_height().unwrap_or(2),
+            Self::CompareBar { .. } => 1,
+            Self::ShotsByPeriod { periods, .. } => {
+                // Period labels plus one row per team
+                if periods.is_empty() {
+                    0
+                } else {
+                    3
+                }
+            }
         }
     }
 
@@ -446,6 +637,93 @@ impl DocumentElement {
         }
     }
 
+    /// Collect where each focusable element is drawn when rendered into `area`
+    ///
+    /// Yields one rect per element, in the same order as `collect_focusable`,
+    /// using the same layout as `render` so clicks can be mapped back to focus.
+    pub fn collect_hit_rects(&self, out: &mut Vec<Rect>, area: Rect, config: &DisplayConfig) {
+        match self {
+            Self::Link { display, .. } => {
+                // The selector column in front of the link is clickable too
+                let prefix_width = config.box_chars.selector.width() + 1;
+                let width = ((prefix_width + display.width()) as u16).min(area.width);
+                out.push(Rect::new(area.x, area.y, width, 1));
+            }
+            Self::Group { children, .. } => {
+                let mut y = area.y;
+                for child in children {
+                    let height = child.height();
+                    child.collect_hit_rects(out, Rect::new(area.x, y, area.width, height), config);
+                    y += height;
+                }
+            }
+            Self::Custom { focusable, .. } => {
+                for elem in focusable {
+                    out.push(Rect {
+                        x: area.x + elem.rect.x,
+                        y: area.y + elem.rect.y,
+                        ..elem.rect
+                    });
+                }
+            }
+            Self::Table { widget, focusable } => {
+                table_cell_rects(widget, focusable, area, out);
+            }
+            Self::Row {
+                children,
+                gap,
+                align,
+            } => {
+                for (child, child_area) in children
+                    .iter()
+                    .zip(row_child_areas(children, *gap, *align, area))
+                {
+                    child.collect_hit_rects(out, child_area, config);
+                }
+            }
+            Self::ScoreBoxElement { score_box, .. } => {
+                let width = score_box.preferred_width().unwrap_or(25);
+                let height = score_box.preferred_height().unwrap_or(6);
+                out.push(Rect::new(area.x, area.y, width, height));
+            }
+            Self::Indented { element, margin } => {
+                let indented = Rect::new(
+                    area.x + margin,
+                    area.y,
+                    area.width.saturating_sub(*margin),
+                    area.height,
+                );
+                element.collect_hit_rects(out, indented, config);
+            }
+            Self::TeamBoxscore {
+                team_name,
+                forwards_table,
+                defense_table,
+                goalies_table,
+                focusable,
+            } => {
+                // Tables sit inside the side borders
+                let width =
+                    team_boxscore_width(team_name, [forwards_table, defense_table, goalies_table]);
+                let inner = Rect::new(
+                    area.x + 1,
+                    area.y,
+                    width.min(area.width).saturating_sub(2),
+                    area.height,
+                );
+                for elem in focusable {
+                    let table = match elem.id.table_name() {
+                        Some(name) if name.ends_with("_defense") => defense_table,
+                        Some(name) if name.ends_with("_goalies") => goalies_table,
+                        _ => forwards_table,
+                    };
+                    table_cell_rects(table, std::slice::from_ref(elem), inner, out);
+                }
+            }
+            _ => {}
+        }
+    }
+
     /// Collect focusable element IDs from this element (simpler version for display)
     ///
     /// # Arguments
@@ -500,6 +778,9 @@ impl DocumentElement {
             Self::SectionTitle { content, underline } => {
                 render_section_title(content, *underline, area, buf, config);
             }
+            Self::Banner { content } => {
+                render_banner(content, area, buf, config);
+            }
             Self::Link {
                 display, focused, ..
             } => {
@@ -563,6 +844,34 @@ impl DocumentElement {
             Self::BigScoreElement { big_score } => {
                 big_score.render(area, buf, config);
             }
+            Self::EmptyStateElement { empty_state } => {
+                empty_state.render(area, buf, config);
+            }
+            Self::CompareBar {
+                label,
+                away_display,
+                home_display,
+                away_amount,
+                home_amount,
+            } => {
+                render_compare_bar(
+                    label,
+                    away_display,
+                    home_display,
+                    *away_amount,
+                    *home_amount,
+                    area,
+                    buf,
+                    config,
+                );
+            }
+            Self::ShotsByPeriod {
+                away_abbrev,
+                home_abbrev,
+                periods,
+            } => {
+                render_shots_by_period(away_abbrev, home_abbrev, periods, area, buf, config);
+            }
         }
     }
 
@@ -600,6 +909,13 @@ impl DocumentElement {
         }
     }
 
+    /// Create a banner element, centered in the emphasis style
+    pub fn banner(content: impl Into<String>) -> Self {
+        Self::Banner {
+            content: content.into(),
+        }
+    }
+
     /// Create a link element
     pub fn link(id: impl Into<String>, display: impl Into<String>, target: LinkTarget) -> Self {
         Self::Link {
@@ -693,6 +1009,9 @@ impl DocumentElement {
                         CellValue::TeamLink { team_abbrev, .. } => {
                             Some(LinkTarget::Action(format!("team:{}", team_abbrev)))
                         }
+                        CellValue::GameLink { game_id, .. } => {
+                            Some(LinkTarget::Action(format!("game:{}", game_id)))
+                        }
                         _ => continue, // Skip non-link cells
                     };
 
@@ -910,12 +1229,106 @@ impl DocumentElement {
             big_score: BigScore::new(away_abbrev, home_abbrev, away_score, home_score),
         }
     }
+
+    /// Create an empty state element with the message and optional detail centered
+    pub fn empty_state(message: impl Into<String>, detail: Option<String>) -> Self {
+        let empty_state = EmptyState::new(message);
+        Self::EmptyStateElement {
+            empty_state: match detail {
+                Some(detail) => empty_state.with_detail(detail),
+                None => empty_state,
+            },
+        }
+    }
+
+    /// Create a comparison bar element
+    ///
+    /// # Arguments
+    /// - `label`: Stat name (e.g., "Shots")
+    /// - `away_display` / `home_display`: Formatted values shown on each side
+    /// - `away_amount` / `home_amount`: Numeric values used to split the bar
+    pub fn compare_bar(
+        label: impl Into<String>,
+        away_display: impl Into<String>,
+        home_display: impl Into<String>,
+        away_amount: f64,
+        home_amount: f64,
+    ) -> Self {
+        Self::CompareBar {
+            label: label.into(),
+            away_display: away_display.into(),
+            home_display: home_display.into(),
+            away_amount,
+            home_amount,
+        }
+    }
+
+    /// Create a shots-by-period chart element
+    ///
+    /// # Arguments
+    /// - `away_abbrev` / `home_abbrev`: Team abbreviations labelling each row
+    /// - `periods`: Shots for each period played, in order
+    pub fn shots_by_period(
+        away_abbrev: impl Into<String>,
+        home_abbrev: impl Into<String>,
+        periods: Vec<PeriodShots>,
+    ) -> Self {
+        Self::ShotsByPeriod {
+            away_abbrev: away_abbrev.into(),
+            home_abbrev: home_abbrev.into(),
+            periods,
+        }
+    }
+}
+
+/// Where each focusable cell of a table is drawn when rendered into `area`
+fn table_cell_rects(
+    table: &TableWidget,
+    focusable: &[FocusableElement],
+    area: Rect,
+    out: &mut Vec<Rect>,
+) {
+    let spans = table.column_spans(area.width);
+    for elem in focusable {
+        let y = area.y + elem.rect.y;
+        let span = match &elem.id {
+            FocusableId::TableCell { col, .. } => spans.iter().find(|(c, _, _)| c == col),
+            _ => None,
+        };
+        // Cells scrolled out of view get an empty rect
+        out.push(span.map_or(Rect::new(area.x, y, 0, 1), |(_, x, width)| {
+            Rect::new(area.x + x, y, *width, 1)
+        }));
+    }
+}
+
+/// Add the text of a table's link cells, looking up each cell's table by name
+fn searchable_cells<'a>(
+    out: &mut Vec<SearchableSpan>,
+    focusable: &[FocusableElement],
+    y_offset: u16,
+    table_for: impl Fn(&str) -> &'a TableWidget,
+) {
+    for elem in focusable {
+        let FocusableId::TableCell {
+            table_name,
+            row,
+            col,
+        } = &elem.id
+        else {
+            continue;
+        };
+        if let Some(cell) = table_for(table_name).get_cell_value(*row, *col) {
+            out.push(SearchableSpan::new(y_offset + elem.y, cell.display_text()));
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::tui::document::link::DocumentLink;
+    use crate::tui::testing::assert_buffer;
     use ratatui::style::Color;
 
     #[test]
@@ -1028,6 +1441,51 @@ mod tests {
         assert_eq!(focusable[1].y, 6);
     }
 
+    #[test]
+    fn test_collect_searchable() {
+        use crate::tui::{Alignment, ColumnDef};
+
+        let columns: Vec<ColumnDef<(&str, &str)>> = vec![
+            ColumnDef::new("Name", 10, Alignment::Left, |row: &(&str, &str)| {
+                CellValue::Text(row.0.to_string())
+            }),
+            ColumnDef::new("Team", 5, Alignment::Left, |row: &(&str, &str)| {
+                CellValue::TeamLink {
+                    display: row.1.to_string(),
+                    team_abbrev: row.1.to_string(),
+                }
+            }),
+        ];
+        let table = TableWidget::from_data(&columns, vec![("Bruins", "BOS"), ("Leafs", "TOR")]);
+        let doc = DocumentElement::group(vec![
+            DocumentElement::heading(1, "Atlantic"),
+            DocumentElement::text("First\nSecond"),
+            DocumentElement::separator(),
+            DocumentElement::row(vec![
+                DocumentElement::link("a", "Left", LinkTarget::Action("a".to_string())),
+                DocumentElement::link("b", "Right", LinkTarget::Action("b".to_string())),
+            ]),
+            DocumentElement::indented(DocumentElement::table("teams", table), 2),
+        ]);
+
+        let mut spans = Vec::new();
+        doc.collect_searchable(&mut spans, 1);
+
+        assert_eq!(
+            spans,
+            vec![
+                SearchableSpan::new(1, "Atlantic"),
+                SearchableSpan::new(3, "First"),
+                SearchableSpan::new(4, "Second"),
+                SearchableSpan::new(6, "Left"),
+                SearchableSpan::new(6, "Right"),
+                // Only link cells, below the table header
+                SearchableSpan::new(9, "BOS"),
+                SearchableSpan::new(10, "TOR"),
+            ]
+        );
+    }
+
     #[test]
     fn test_render_text() {
         let elem = DocumentElement::text("Hello");
@@ -1044,6 +1502,23 @@ mod tests {
         assert_eq!(buf.cell((4, 0)).unwrap().symbol(), "o");
     }
 
+    #[test]
+    fn test_render_banner_centered_in_emphasis() {
+        let elem = DocumentElement::banner("★ Winter Classic ★");
+        let mut buf = Buffer::empty(Rect::new(0, 0, 24, 1));
+        let mut config = DisplayConfig {
+            theme_name: Some("orange".to_string()),
+            ..DisplayConfig::default()
+        };
+        config.apply_theme();
+
+        assert_eq!(elem.height(), 1);
+        elem.render(Rect::new(0, 0, 24, 1), &mut buf, &config);
+
+        assert_buffer(&buf, &["   ★ Winter Classic ★   "]);
+        assert_eq!(buf[(3, 0)].fg, config.theme.unwrap().emphasis_fg());
+    }
+
     #[test]
     fn test_render_heading_level_1() {
         let elem = DocumentElement::heading(1, "Title");
@@ -1076,6 +1551,33 @@ mod tests {
         // No specific color assertion since we use theme.fg2
     }
 
+    #[test]
+    fn test_render_link_with_wide_selector_keeps_alignment() {
+        use crate::tui::testing::assert_buffer;
+
+        let mut config = DisplayConfig::default();
+        config.box_chars.selector = "=>".to_string();
+        let target = LinkTarget::Action("test".to_string());
+        let focused = DocumentElement::focused_link("a", "Click", target.clone());
+        let unfocused = DocumentElement::link("b", "Click", target);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 20, 2));
+        focused.render(Rect::new(0, 0, 20, 1), &mut buf, &config);
+        unfocused.render(Rect::new(0, 1, 20, 1), &mut buf, &config);
+
+        assert_buffer(&buf, &["=> Click", "   Click"]);
+    }
+
+    #[test]
+    fn test_render_link_truncates_to_area() {
+        use crate::tui::testing::assert_buffer;
+
+        let elem = DocumentElement::link("a", "Click here", LinkTarget::Action("x".to_string()));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 1));
+        elem.render(Rect::new(0, 0, 7, 1), &mut buf, &DisplayConfig::default());
+        assert_buffer(&buf, &["  Click"]);
+    }
+
     #[test]
     fn test_render_focused_link() {
         let elem = DocumentElement::focused_link(
@@ -1390,6 +1892,66 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_collect_hit_rects_follow_focus_order() {
+        use crate::tui::components::TableWidget;
+        use crate::tui::widgets::{ScoreBox, ScoreBoxStatus};
+        use crate::tui::{Alignment, CellValue, ColumnDef};
+
+        let score_box = ScoreBox::new(
+            "Team A",
+            "Team B",
+            Some(3),
+            Some(2),
+            ScoreBoxStatus::Final {
+                overtime: false,
+                shootout: false,
+            },
+        );
+        let columns: Vec<ColumnDef<(&str, &str)>> = vec![
+            ColumnDef::new("Name", 10, Alignment::Left, |row: &(&str, &str)| {
+                CellValue::Text(row.0.to_string())
+            }),
+            ColumnDef::new("Team", 5, Alignment::Left, |row: &(&str, &str)| {
+                CellValue::TeamLink {
+                    display: row.1.to_string(),
+                    team_abbrev: row.1.to_string(),
+                }
+            }),
+        ];
+        let table = TableWidget::from_data(&columns, vec![("Bruins", "BOS"), ("Leafs", "TOR")]);
+        let doc = DocumentElement::group(vec![
+            DocumentElement::link("home", "Accueil é", LinkTarget::Action("home".to_string())),
+            DocumentElement::row(vec![
+                DocumentElement::score_box_element(1, score_box.clone(), false),
+                DocumentElement::score_box_element(2, score_box, false),
+            ]),
+            DocumentElement::indented(DocumentElement::table("teams", table), 2),
+        ]);
+
+        let mut focusable = Vec::new();
+        doc.collect_focusable(&mut focusable, 0);
+        let mut rects = Vec::new();
+        doc.collect_hit_rects(
+            &mut rects,
+            Rect::new(0, 0, 60, doc.height()),
+            &DisplayConfig::default(),
+        );
+
+        assert_eq!(rects.len(), focusable.len());
+        assert_eq!(
+            rects,
+            vec![
+                Rect::new(0, 0, 11, 1),
+                Rect::new(0, 1, 25, 6),
+                Rect::new(35, 1, 25, 6),
+                // Past the margin, selector and the Name column
+                Rect::new(16, 9, 5, 1),
+                Rect::new(16, 10, 5, 1),
+            ]
+        );
+    }
+
     #[test]
     fn test_row_left_alignment_uses_minimum_gap() {
         use crate::tui::widgets::{ScoreBox, ScoreBoxStatus};
@@ -1509,4 +2071,257 @@ mod tests {
             _ => panic!("Expected Row variant"),
         }
     }
+
+    #[test]
+    fn test_compare_bar_height() {
+        let elem = DocumentElement::compare_bar("Shots", "30", "25", 30.0, 25.0);
+        assert_eq!(elem.height(), 1);
+    }
+
+    #[test]
+    fn test_render_compare_bar() {
+        use crate::tui::testing::assert_buffer;
+
+        let elem = DocumentElement::compare_bar("Shots", "3", "1", 3.0, 1.0);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 46, 1));
+        let config = DisplayConfig::default();
+
+        elem.render(Rect::new(0, 0, 46, 1), &mut buf, &config);
+
+        assert_buffer(&buf, &["Shots            3 ███████████████░░░░░ 1"]);
+    }
+
+    #[test]
+    fn test_render_compare_bar_zero_values_split_evenly() {
+        use crate::tui::testing::assert_buffer;
+
+        let elem = DocumentElement::compare_bar("PIM", "0", "0", 0.0, 0.0);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 46, 1));
+        let config = DisplayConfig::default();
+
+        elem.render(Rect::new(0, 0, 46, 1), &mut buf, &config);
+
+        assert_buffer(&buf, &["PIM              0 ██████████░░░░░░░░░░ 0"]);
+    }
+
+    fn shots(periods: &[(&str, i32, i32)]) -> DocumentElement {
+        let periods = periods
+            .iter()
+            .map(|&(label, away, home)| PeriodShots {
+                label: label.to_string(),
+                away,
+                home,
+            })
+            .collect();
+        DocumentElement::shots_by_period("NJD", "BUF", periods)
+    }
+
+    #[test]
+    fn test_shots_by_period_height() {
+        assert_eq!(shots(&[("1", 12, 6)]).height(), 3);
+        assert_eq!(shots(&[]).height(), 0);
+    }
+
+    #[test]
+    fn test_render_shots_by_period() {
+        use crate::tui::testing::assert_buffer;
+
+        let elem = shots(&[("1", 12, 6), ("2", 8, 9), ("3", 10, 4)]);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 46, 3));
+        let config = DisplayConfig::default();
+
+        elem.render(Rect::new(0, 0, 46, 3), &mut buf, &config);
+
+        assert_buffer(
+            &buf,
+            &[
+                "      1             2             3",
+                "NJD  12 ████████    8 █████▍     10 ██████▋",
+                "BUF   6 ████        9 ██████      4 ██▋",
+            ],
+        );
+    }
+
+    #[test]
+    fn test_render_shots_by_period_single_period() {
+        use crate::tui::testing::assert_buffer;
+
+        let elem = shots(&[("1", 3, 5)]);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 20, 3));
+        let config = DisplayConfig::default();
+
+        elem.render(Rect::new(0, 0, 20, 3), &mut buf, &config);
+
+        assert_buffer(&buf, &["      1", "NJD   3 ████▊", "BUF   5 ████████"]);
+    }
+
+    #[test]
+    fn test_render_shots_by_period_ascii_is_numeric() {
+        use crate::formatting::BoxChars;
+        use crate::tui::testing::assert_buffer;
+
+        let elem = shots(&[("1", 12, 6), ("2", 8, 9)]);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 20, 3));
+        let config = DisplayConfig {
+            use_unicode: false,
+            box_chars: BoxChars::ascii(),
+            ..Default::default()
+        };
+
+        elem.render(Rect::new(0, 0, 20, 3), &mut buf, &config);
+
+        assert_buffer(&buf, &["      1    2", "NJD  12    8", "BUF   6    9"]);
+    }
+
+    #[test]
+    fn test_render_compare_bar_ascii_is_text_only() {
+        use crate::formatting::BoxChars;
+        use crate::tui::testing::assert_buffer;
+
+        let elem = DocumentElement::compare_bar("Hits", "12", "20", 12.0, 20.0);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 46, 1));
+        let config = DisplayConfig {
+            use_unicode: false,
+            box_chars: BoxChars::ascii(),
+            ..Default::default()
+        };
+
+        elem.render(Rect::new(0, 0, 46, 1), &mut buf, &config);
+
+        assert_buffer(&buf, &["Hits            12 - 20"]);
+    }
+
+    #[test]
+    fn test_render_team_boxscore_heavy_borders() {
+        use crate::config::BoxStyle;
+        use crate::formatting::BoxChars;
+        use crate::tui::components::TableWidget;
+        use crate::tui::{Alignment, CellValue, ColumnDef};
+
+        // Table 26 + selector 2 + borders 2: a box 30 wide
+        let columns: Vec<ColumnDef<&str>> =
+            vec![ColumnDef::new("Name", 26, Alignment::Left, |row: &&str| {
+                CellValue::Text(row.to_string())
+            })];
+        let table = |rows: Vec<&'static str>| TableWidget::from_data(&columns, rows);
+        let elem = DocumentElement::team_boxscore(
+            "away",
+            "Devils",
+            table(vec!["Hughes"]),
+            table(vec![]),
+            table(vec![]),
+        );
+        let area = Rect::new(0, 0, 30, elem.height());
+        let mut buf = Buffer::empty(area);
+        let config = DisplayConfig {
+            box_chars: BoxChars::from_use_unicode(true, BoxStyle::Heavy),
+            ..Default::default()
+        };
+
+        elem.render(area, &mut buf, &config);
+
+        let bottom = area.height - 1;
+        assert_eq!(buf[(0, 0)].symbol(), "┏");
+        assert_eq!(buf[(29, 0)].symbol(), "┓");
+        assert_eq!(buf[(3, 0)].symbol(), "┫");
+        assert_eq!(buf[(0, 1)].symbol(), "┃");
+        assert_eq!(buf[(29, 1)].symbol(), "┃");
+        assert_eq!(buf[(0, bottom)].symbol(), "┗");
+        assert_eq!(buf[(29, bottom)].symbol(), "┛");
+    }
+
+    fn responsive_team_boxscore() -> DocumentElement {
+        use crate::tui::components::TableWidget;
+        use crate::tui::{Alignment, CellValue, ColumnDef};
+
+        let columns: Vec<ColumnDef<&str>> = vec![
+            ColumnDef::new("Name", 14, Alignment::Left, |row: &&str| {
+                CellValue::Text(row.to_string())
+            }),
+            ColumnDef::new("G", 3, Alignment::Right, |_: &&str| {
+                CellValue::Text("1".to_string())
+            }),
+            ColumnDef::new("Hits", 4, Alignment::Right, |_: &&str| {
+                CellValue::Text("3".to_string())
+            })
+            .with_priority(1),
+        ];
+        let table = |rows: Vec<&'static str>| TableWidget::from_data(&columns, rows);
+        DocumentElement::team_boxscore(
+            "away",
+            "Devils",
+            table(vec!["Hughes"]),
+            table(vec![]),
+            table(vec![]),
+        )
+    }
+
+    #[test]
+    fn test_team_boxscore_takes_natural_width_on_wide_screens() {
+        let elem = responsive_team_boxscore();
+        // Table 27 wide inside the side borders
+        assert_eq!(render::get_preferred_width(&elem), Some(29));
+
+        let area = Rect::new(0, 0, 60, elem.height());
+        let mut buf = Buffer::empty(area);
+        elem.render(area, &mut buf, &DisplayConfig::default());
+
+        assert_buffer(
+            &buf,
+            &[
+                "╒══╡ Devils - Forwards ╞════╕",
+                "│                           │",
+                "│  Name              G  Hits│",
+                "│  ─────────────────────────│",
+                "│  Hughes            1     3│",
+                "│                           │",
+                "╘═══════════════════════════╛",
+            ],
+        );
+    }
+
+    #[test]
+    fn test_team_boxscore_hides_low_priority_columns_on_narrow_screens() {
+        let elem = responsive_team_boxscore();
+        let area = Rect::new(0, 0, 26, elem.height());
+        let mut buf = Buffer::empty(area);
+        elem.render(area, &mut buf, &DisplayConfig::default());
+
+        assert_buffer(
+            &buf,
+            &[
+                "╒══╡ Devils - Forwards ╞═╕",
+                "│                        │",
+                "│  Name              G   │",
+                "│  ───────────────────   │",
+                "│  Hughes            1   │",
+                "│                        │",
+                "╘════════════════════════╛",
+            ],
+        );
+    }
+
+    #[test]
+    fn test_team_boxscore_width_fits_long_team_names() {
+        use crate::tui::components::TableWidget;
+        use crate::tui::{Alignment, CellValue, ColumnDef};
+
+        let columns: Vec<ColumnDef<&str>> =
+            vec![ColumnDef::new("Name", 4, Alignment::Left, |row: &&str| {
+                CellValue::Text(row.to_string())
+            })];
+        let table = || TableWidget::from_data(&columns, vec!["Bo"]);
+        let elem =
+            DocumentElement::team_boxscore("home", "Golden Knights", table(), table(), table());
+
+        // "Golden Knights - Forwards" plus the header decoration
+        assert_eq!(render::get_preferred_width(&elem), Some(34));
+    }
+
+    #[test]
+    fn test_team_boxscore_side_by_side_width() {
+        let away = responsive_team_boxscore();
+        let home = responsive_team_boxscore();
+        assert_eq!(team_boxscore_side_by_side_width(&away, &home), 29 + 2 + 29);
+    }
 }
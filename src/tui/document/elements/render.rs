@@ -5,22 +5,44 @@
 use ratatui::buffer::Buffer;
 use ratatui::layout::Rect;
 use ratatui::style::Style;
+use unicode_width::UnicodeWidthStr;
 
 use crate::config::DisplayConfig;
 use crate::tui::component::ElementWidget;
 use crate::tui::components::TableWidget;
 use crate::tui::widgets::StandaloneWidget;
 
-use super::{DocumentElement, RowAlignment};
-
-/// Fixed width for team boxscore
-pub const TEAM_BOXSCORE_WIDTH: u16 = 85;
+use super::{DocumentElement, PeriodShots, RowAlignment};
 
 /// Gap between two team boxscores when displayed side by side
 pub const TEAM_BOXSCORE_GAP: u16 = 2;
 
-/// Minimum width needed to display two team boxscores side by side
-pub const TEAM_BOXSCORE_SIDE_BY_SIDE_WIDTH: u16 = TEAM_BOXSCORE_WIDTH * 2 + TEAM_BOXSCORE_GAP;
+/// Columns a section header adds around its title: "╒══╡ " before, " ╞═╕" after
+const SECTION_HEADER_DECORATION_WIDTH: usize = 9;
+
+/// Width of the label column in a comparison bar
+const COMPARE_BAR_LABEL_WIDTH: usize = 12;
+
+/// Width of each value column in a comparison bar
+const COMPARE_BAR_VALUE_WIDTH: usize = 6;
+
+/// Maximum width of the bar itself
+const COMPARE_BAR_MAX_WIDTH: usize = 40;
+
+/// Width of the team label column in a shots-by-period chart
+const SHOTS_LABEL_WIDTH: usize = 4;
+
+/// Width of the shot count in each period column
+const SHOTS_COUNT_WIDTH: usize = 3;
+
+/// Width of the longest bar in a shots-by-period chart
+const SHOTS_BAR_WIDTH: usize = 8;
+
+/// Gap between period columns of a shots-by-period chart
+const SHOTS_COLUMN_GAP: usize = 2;
+
+/// Blocks one to seven eighths wide, for the fractional end of a bar
+const PARTIAL_BLOCKS: [&str; 7] = ["▏", "▎", "▍", "▌", "▋", "▊", "▉"];
 
 /// Render a horizontal row of elements
 pub(super) fn render_row(
@@ -31,9 +53,28 @@ pub(super) fn render_row(
     buf: &mut Buffer,
     config: &DisplayConfig,
 ) {
-    if children.is_empty() || area.width == 0 {
+    if area.width == 0 {
         return;
     }
+    for (child, child_area) in children
+        .iter()
+        .zip(row_child_areas(children, gap, align, area))
+    {
+        child.render(child_area, buf, config);
+    }
+}
+
+/// Areas of each child in a horizontal row
+pub(super) fn row_child_areas(
+    children: &[DocumentElement],
+    gap: u16,
+    align: RowAlignment,
+    area: Rect,
+) -> Vec<Rect> {
+    let mut areas = Vec::new();
+    if children.is_empty() {
+        return areas;
+    }
 
     // Check if children have preferred widths (e.g., ScoreBoxElement, TeamBoxscore)
     let has_preferred_widths = children.iter().all(|c| get_preferred_width(c).is_some());
@@ -60,8 +101,7 @@ pub(super) fn render_row(
         let mut x_offset = area.x;
         for child in children {
             let child_width = get_preferred_width(child).unwrap_or(0);
-            let child_area = Rect::new(x_offset, area.y, child_width, area.height);
-            child.render(child_area, buf, config);
+            areas.push(Rect::new(x_offset, area.y, child_width, area.height));
             x_offset += child_width + actual_gap;
         }
     } else {
@@ -72,23 +112,60 @@ pub(super) fn render_row(
         let child_width = available_width / num_children;
 
         let mut x_offset = area.x;
-        for child in children {
-            let child_area = Rect::new(x_offset, area.y, child_width, area.height);
-            child.render(child_area, buf, config);
+        for _ in children {
+            areas.push(Rect::new(x_offset, area.y, child_width, area.height));
             x_offset += child_width + gap;
         }
     }
+    areas
 }
 
 /// Get preferred width for elements that have fixed dimensions
 pub(super) fn get_preferred_width(element: &DocumentElement) -> Option<u16> {
     match element {
         DocumentElement::ScoreBoxElement { score_box, .. } => score_box.preferred_width(),
-        DocumentElement::TeamBoxscore { .. } => Some(TEAM_BOXSCORE_WIDTH),
+        DocumentElement::TeamBoxscore {
+            team_name,
+            forwards_table,
+            defense_table,
+            goalies_table,
+            ..
+        } => Some(team_boxscore_width(
+            team_name,
+            [forwards_table, defense_table, goalies_table],
+        )),
         _ => None,
     }
 }
 
+/// Natural width of a team boxscore: its widest table inside the side borders,
+/// widened if needed so every section title fits in its header
+pub(super) fn team_boxscore_width(team_name: &str, tables: [&TableWidget; 3]) -> u16 {
+    let tables_width = tables
+        .iter()
+        .filter_map(|table| table.preferred_width())
+        .max()
+        .unwrap_or(0)
+        + 2;
+    let title_width = ["Forwards", "Defense", "Goalies"]
+        .iter()
+        .map(|section| {
+            format!("{} - {}", team_name, section).chars().count() + SECTION_HEADER_DECORATION_WIDTH
+        })
+        .max()
+        .unwrap_or(0) as u16;
+    tables_width.max(title_width)
+}
+
+/// Width needed to display two team boxscores side by side at their natural widths
+///
+/// Narrower than this they are stacked, each shrinking to the available width.
+pub fn team_boxscore_side_by_side_width(away: &DocumentElement, home: &DocumentElement) -> u16 {
+    get_preferred_width(away).unwrap_or(0)
+        + TEAM_BOXSCORE_GAP
+        + get_preferred_width(home).unwrap_or(0)
+}
+
 /// Render a text element
 pub(super) fn render_text(
     content: &str,
@@ -187,6 +264,13 @@ pub(super) fn render_section_title(
     }
 }
 
+/// Render a banner element, centered and clipped to the area
+pub(super) fn render_banner(content: &str, area: Rect, buf: &mut Buffer, config: &DisplayConfig) {
+    let width = (content.width() as u16).min(area.width);
+    let x = area.x + (area.width - width) / 2;
+    buf.set_stringn(x, area.y, content, width as usize, config.emphasis_style());
+}
+
 /// Render a link element
 pub(super) fn render_link(
     display: &str,
@@ -199,19 +283,27 @@ pub(super) fn render_link(
 
     let base_style = config.text_style();
 
+    // Selector plus a space, measured in columns so wide selectors keep rows aligned
+    let prefix_width = config.box_chars.selector.width() + 1;
+
     let (prefix, link_style) = if focused {
         let prefix = format!("{} ", config.box_chars.selector);
         let style = base_style.add_modifier(SELECTION_STYLE_MODIFIER);
         (prefix, style)
     } else {
         // Use spaces to align with focused items
-        ("  ".to_string(), base_style)
+        (" ".repeat(prefix_width), base_style)
     };
 
-    let prefix_len = prefix.chars().count() as u16;
-
-    buf.set_string(area.x, area.y, &prefix, base_style);
-    buf.set_string(area.x + prefix_len, area.y, display, link_style);
+    let prefix_width = prefix_width.min(area.width as usize);
+    buf.set_stringn(area.x, area.y, &prefix, prefix_width, base_style);
+    buf.set_stringn(
+        area.x + prefix_width as u16,
+        area.y,
+        display,
+        area.width as usize - prefix_width,
+        link_style,
+    );
 }
 
 /// Render a separator element
@@ -291,8 +383,9 @@ pub(super) fn render_team_boxscore(
     let bc = &config.box_chars;
     let border_style = config.muted_style();
 
-    // Use fixed width but respect area constraints
-    let width = TEAM_BOXSCORE_WIDTH.min(area.width);
+    // Natural width, shrunk to the area; narrow tables hide low-priority columns
+    let width = team_boxscore_width(team_name, [forwards_table, defense_table, goalies_table])
+        .min(area.width);
     let inner_width = width.saturating_sub(2); // Subtract 2 for side borders
 
     let mut y = area.y;
@@ -430,3 +523,145 @@ fn render_bottom_border(x: u16, y: u16, width: u16, buf: &mut Buffer, config: &D
         buf.set_string(x + width - 1, y, &bc.mixed_dh_bottom_right, border_style);
     }
 }
+
+/// Render a comparison bar: label, away value, proportional bar, home value
+///
+/// The bar is omitted when unicode is disabled, leaving `away - home`.
+#[allow(clippy::too_many_arguments)]
+pub(super) fn render_compare_bar(
+    label: &str,
+    away_display: &str,
+    home_display: &str,
+    away_amount: f64,
+    home_amount: f64,
+    area: Rect,
+    buf: &mut Buffer,
+    config: &DisplayConfig,
+) {
+    if area.width == 0 || area.height == 0 {
+        return;
+    }
+    let text_style = config.text_style();
+    let muted_style = config.muted_style();
+    let bc = &config.box_chars;
+
+    let prefix = format!(
+        "{:<label_w$}{:>value_w$} ",
+        label,
+        away_display,
+        label_w = COMPARE_BAR_LABEL_WIDTH,
+        value_w = COMPARE_BAR_VALUE_WIDTH
+    );
+    let mut x = area.x;
+    buf.set_stringn(x, area.y, &prefix, area.width as usize, text_style);
+    x += prefix.chars().count() as u16;
+
+    if config.use_unicode {
+        let fixed_width = prefix.chars().count() + 1 + COMPARE_BAR_VALUE_WIDTH;
+        let bar_width = (area.width as usize)
+            .saturating_sub(fixed_width)
+            .min(COMPARE_BAR_MAX_WIDTH);
+        let total = away_amount + home_amount;
+        let away_cells = if total > 0.0 {
+            ((away_amount / total) * bar_width as f64).round() as usize
+        } else {
+            bar_width / 2
+        };
+        let home_cells = bar_width - away_cells.min(bar_width);
+
+        buf.set_string(x, area.y, bc.bar_primary.repeat(away_cells), text_style);
+        x += away_cells as u16;
+        buf.set_string(x, area.y, bc.bar_secondary.repeat(home_cells), muted_style);
+        x += home_cells as u16;
+    } else {
+        buf.set_string(x, area.y, "-", muted_style);
+        x += 1;
+    }
+
+    if x + 1 < area.x + area.width {
+        let remaining = (area.x + area.width - x - 1) as usize;
+        buf.set_stringn(x + 1, area.y, home_display, remaining, text_style);
+    }
+}
+
+/// A bar `eighths` eighths of a cell long, from full blocks and one partial block
+fn eighths_bar(eighths: usize) -> String {
+    let mut bar = "█".repeat(eighths / 8);
+    let partial = eighths % 8;
+    if partial > 0 {
+        bar.push_str(PARTIAL_BLOCKS[partial - 1]);
+    }
+    bar
+}
+
+/// Render shots on goal per period: a label row, then one row per team
+///
+/// Each period column holds the count followed by a bar scaled to the
+/// busiest period. The bars are omitted when unicode is disabled.
+pub(super) fn render_shots_by_period(
+    away_abbrev: &str,
+    home_abbrev: &str,
+    periods: &[PeriodShots],
+    area: Rect,
+    buf: &mut Buffer,
+    config: &DisplayConfig,
+) {
+    if area.width == 0 || area.height == 0 || periods.is_empty() {
+        return;
+    }
+    let text_style = config.text_style();
+    let muted_style = config.muted_style();
+    let bar_width = if config.use_unicode {
+        1 + SHOTS_BAR_WIDTH
+    } else {
+        0
+    };
+    let column_width = SHOTS_COUNT_WIDTH + bar_width + SHOTS_COLUMN_GAP;
+    let max_shots = periods
+        .iter()
+        .map(|p| p.away.max(p.home))
+        .max()
+        .unwrap_or(0)
+        .max(0) as usize;
+    let right = area.x + area.width;
+
+    let set = |buf: &mut Buffer, x: u16, y: u16, text: &str, style: Style| {
+        if x < right {
+            buf.set_stringn(x, y, text, (right - x) as usize, style);
+        }
+    };
+
+    let header_y = area.y;
+    let mut x = area.x + SHOTS_LABEL_WIDTH as u16;
+    for period in periods {
+        let label = format!("{:>width$}", period.label, width = SHOTS_COUNT_WIDTH);
+        set(buf, x, header_y, &label, muted_style);
+        x = x.saturating_add(column_width as u16);
+    }
+
+    let rows = [(away_abbrev, text_style), (home_abbrev, muted_style)];
+    for (offset, (abbrev, bar_style)) in rows.into_iter().enumerate() {
+        let y = area.y + 1 + offset as u16;
+        if y >= area.y + area.height {
+            break;
+        }
+        set(buf, area.x, y, abbrev, text_style);
+        let mut x = area.x + SHOTS_LABEL_WIDTH as u16;
+        for period in periods {
+            let shots = if offset == 0 {
+                period.away
+            } else {
+                period.home
+            };
+            let count = shots.max(0) as usize;
+            let count_text = format!("{:>width$}", count, width = SHOTS_COUNT_WIDTH);
+            set(buf, x, y, &count_text, text_style);
+            if config.use_unicode && max_shots > 0 {
+                let eighths = (count * SHOTS_BAR_WIDTH * 8 + max_shots / 2) / max_shots;
+                let bar_x = x.saturating_add(SHOTS_COUNT_WIDTH as u16 + 1);
+                set(buf, bar_x, y, &eighths_bar(eighths), bar_style);
+            }
+            x = x.saturating_add(column_width as u16);
+        }
+    }
+}
@@ -0,0 +1,239 @@
+//! Find within a document
+//!
+//! `/` opens a find prompt on the top stacked document. Matching is
+//! case-insensitive over the text of text, heading and link elements (including
+//! link cells in tables); `n`/`N` jump between matching lines and the view
+//! highlights every occurrence on screen.
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::Style;
+use unicode_width::UnicodeWidthStr;
+
+/// Searchable text and the document line it is drawn on
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchableSpan {
+    pub y: u16,
+    pub text: String,
+}
+
+impl SearchableSpan {
+    pub fn new(y: u16, text: impl Into<String>) -> Self {
+        Self {
+            y,
+            text: text.into(),
+        }
+    }
+}
+
+/// Query and matches of an open find in a document
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FindState {
+    pub query: String,
+    /// Whether keys go to the prompt (until Enter)
+    pub editing: bool,
+    /// Document lines with a match, top to bottom
+    pub matches: Vec<u16>,
+    /// Index into `matches` of the line jumped to
+    pub current: Option<usize>,
+}
+
+impl FindState {
+    /// An empty prompt waiting for a query
+    pub fn prompt() -> Self {
+        Self {
+            editing: true,
+            ..Self::default()
+        }
+    }
+
+    /// Line of the current match
+    pub fn current_line(&self) -> Option<u16> {
+        self.matches.get(self.current?).copied()
+    }
+
+    /// Replace the matches, making the first one at or below `from_line` current
+    pub fn set_matches(&mut self, matches: Vec<u16>, from_line: u16) {
+        self.current = (!matches.is_empty())
+            .then(|| matches.iter().position(|&y| y >= from_line).unwrap_or(0));
+        self.matches = matches;
+    }
+
+    /// Move to the next match (or the previous one), wrapping around
+    pub fn step(&mut self, forward: bool) {
+        let count = self.matches.len();
+        if count == 0 {
+            return;
+        }
+        self.current = Some(match (self.current, forward) {
+            (None, true) => 0,
+            (None, false) => count - 1,
+            (Some(i), true) => (i + 1) % count,
+            (Some(i), false) => (i + count - 1) % count,
+        });
+    }
+
+    /// Status bar text, e.g. "/mat" while typing or "Find 'mat': 2 of 5"
+    pub fn status(&self) -> String {
+        if self.editing {
+            return format!("/{}", self.query);
+        }
+        match self.current {
+            Some(i) => format!(
+                "Find '{}': {} of {} (n/N for next/previous, Esc to clear)",
+                self.query,
+                i + 1,
+                self.matches.len()
+            ),
+            None => format!("No matches for '{}'", self.query),
+        }
+    }
+}
+
+/// Lines whose searchable text contains `query`, ignoring case
+pub fn matching_lines(spans: &[SearchableSpan], query: &str) -> Vec<u16> {
+    if query.is_empty() {
+        return Vec::new();
+    }
+    let query = query.to_lowercase();
+    let mut lines: Vec<u16> = spans
+        .iter()
+        .filter(|span| span.text.to_lowercase().contains(&query))
+        .map(|span| span.y)
+        .collect();
+    lines.sort_unstable();
+    lines.dedup();
+    lines
+}
+
+/// Patch `style` over every occurrence of `query` drawn on the first row of `area`
+///
+/// Compares cell by cell, so a match is found wherever the text landed
+/// (indented, in a row, in a table column).
+pub fn highlight_line(buf: &mut Buffer, area: Rect, query: &str, style: Style) {
+    let query: Vec<String> = query.chars().map(|c| c.to_lowercase().collect()).collect();
+    if query.is_empty() || area.height == 0 {
+        return;
+    }
+
+    // One entry per drawn symbol, skipping the cells covered by wide ones
+    let mut cells = Vec::new();
+    let mut x = area.x;
+    while x < area.right() {
+        let symbol = buf[(x, area.y)].symbol();
+        cells.push((x, symbol.to_lowercase()));
+        x += symbol.width().max(1) as u16;
+    }
+
+    let mut start = 0;
+    while start + query.len() <= cells.len() {
+        let found = cells[start..start + query.len()]
+            .iter()
+            .zip(&query)
+            .all(|((_, symbol), c)| symbol == c);
+        if !found {
+            start += 1;
+            continue;
+        }
+        for (x, symbol) in &cells[start..start + query.len()] {
+            let width = symbol.width().max(1) as u16;
+            buf.set_style(Rect::new(*x, area.y, width, 1), style);
+        }
+        start += query.len();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::style::Modifier;
+
+    #[test]
+    fn test_matching_lines_ignore_case_and_dedupe() {
+        let spans = vec![
+            SearchableSpan::new(4, "Auston Matthews"),
+            SearchableSpan::new(1, "Roster"),
+            SearchableSpan::new(4, "Matt Murray"),
+            SearchableSpan::new(9, "MATTIAS Ekholm"),
+        ];
+
+        assert_eq!(matching_lines(&spans, "matt"), vec![4, 9]);
+        assert_eq!(matching_lines(&spans, "zzz"), Vec::<u16>::new());
+        assert_eq!(matching_lines(&spans, ""), Vec::<u16>::new());
+    }
+
+    #[test]
+    fn test_set_matches_starts_at_line() {
+        let mut find = FindState::prompt();
+        find.set_matches(vec![3, 10, 20], 8);
+        assert_eq!(find.current_line(), Some(10));
+
+        // Past the last match, wraps to the first
+        find.set_matches(vec![3, 10, 20], 25);
+        assert_eq!(find.current_line(), Some(3));
+
+        find.set_matches(Vec::new(), 0);
+        assert_eq!(find.current_line(), None);
+    }
+
+    #[test]
+    fn test_step_wraps_both_ways() {
+        let mut find = FindState::default();
+        find.set_matches(vec![3, 10, 20], 0);
+
+        find.step(true);
+        assert_eq!(find.current_line(), Some(10));
+        find.step(true);
+        find.step(true);
+        assert_eq!(find.current_line(), Some(3));
+        find.step(false);
+        assert_eq!(find.current_line(), Some(20));
+    }
+
+    #[test]
+    fn test_status() {
+        let mut find = FindState::prompt();
+        find.query = "mat".to_string();
+        assert_eq!(find.status(), "/mat");
+
+        find.editing = false;
+        assert_eq!(find.status(), "No matches for 'mat'");
+
+        find.set_matches(vec![3, 10], 5);
+        assert_eq!(
+            find.status(),
+            "Find 'mat': 2 of 2 (n/N for next/previous, Esc to clear)"
+        );
+    }
+
+    #[test]
+    fn test_highlight_line_marks_each_occurrence() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 20, 2));
+        buf.set_string(0, 0, "Matt  MATTHEWS mat", Style::default());
+        buf.set_string(0, 1, "matt", Style::default());
+        let style = Style::default().add_modifier(Modifier::REVERSED);
+
+        highlight_line(&mut buf, Rect::new(0, 0, 20, 1), "matt", style);
+
+        let marked: Vec<u16> = (0..20)
+            .filter(|&x| buf[(x, 0)].modifier.contains(Modifier::REVERSED))
+            .collect();
+        assert_eq!(marked, vec![0, 1, 2, 3, 6, 7, 8, 9]);
+        // Other rows are left alone
+        assert!(!buf[(0, 1)].modifier.contains(Modifier::REVERSED));
+    }
+
+    #[test]
+    fn test_highlight_line_after_wide_characters() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 12, 1));
+        buf.set_string(0, 0, "★ Gaudreau", Style::default());
+        let style = Style::default().add_modifier(Modifier::REVERSED);
+
+        highlight_line(&mut buf, Rect::new(0, 0, 12, 1), "gau", style);
+
+        let marked: Vec<u16> = (0..12)
+            .filter(|&x| buf[(x, 0)].modifier.contains(Modifier::REVERSED))
+            .collect();
+        assert_eq!(marked, vec![2, 3, 4]);
+    }
+}
@@ -1,15 +1,48 @@
 //! Handler implementations for stacked documents
 //!
 //! This module contains the concrete implementations of `StackedDocumentHandler`
-//! for each stacked document type (Boxscore, TeamDetail, PlayerDetail).
+//! for each stacked document type (Boxscore, TeamDetail, PlayerDetail, PlayerCompare).
+
+use crossterm::event::{KeyCode, KeyEvent};
 
 use crate::tui::action::Action;
 use crate::tui::component::Effect;
-use crate::tui::document_nav::DocumentNavState;
+use crate::tui::components::boxscore_document::{
+    max_column_offset, next_boxscore_sort, sort_goalies, sort_skaters, team_records,
+    BoxscoreDocumentContent, TeamView,
+};
+use crate::tui::components::player_compare_document::PlayerCompareDocumentContent;
+use crate::tui::components::player_detail_document::PlayerDetailDocumentContent;
+use crate::tui::components::team_detail_document::TeamDetailDocumentContent;
+use crate::tui::document_nav::{
+    autoscroll_to_focus, find_row_sibling, handle_message, DocumentNavMsg, DocumentNavState,
+    RowDirection,
+};
+use crate::tui::nav_handler::key_to_nav_msg;
 use crate::tui::state::DataState;
+use crate::tui::table::TableSort;
 use crate::tui::types::StackedDocument;
 
-use super::{Document, StackedDocumentHandler};
+use super::{Document, FocusContext, LinkTarget, StackedDocumentHandler};
+
+/// Store the focus metadata of `doc`, laid out with `context`, in `nav`
+///
+/// Spacing and width follow the context so positions match what is rendered.
+fn populate_from_document(nav: &mut DocumentNavState, doc: &dyn Document, context: &FocusContext) {
+    let mut focusable = Vec::new();
+    let mut y_offset = 0u16;
+    for elem in &doc.build(context) {
+        elem.collect_focusable(&mut focusable, y_offset);
+        y_offset += elem.height();
+    }
+
+    nav.focusable_positions = focusable.iter().map(|f| f.y).collect();
+    nav.content_height = y_offset;
+    nav.focusable_heights = focusable.iter().map(|f| f.height).collect();
+    nav.focusable_ids = focusable.iter().map(|f| f.id.clone()).collect();
+    nav.link_targets = focusable.iter().map(|f| f.link_target.clone()).collect();
+    nav.focusable_row_positions = focusable.iter().map(|f| f.row_position).collect();
+}
 
 /// Handler for Boxscore documents
 pub(super) struct BoxscoreDocumentHandler {
@@ -18,108 +51,193 @@ pub(super) struct BoxscoreDocumentHandler {
 
 impl StackedDocumentHandler for BoxscoreDocumentHandler {
     fn activate(&self, nav: &DocumentNavState, data: &DataState) -> Effect {
-        if let Some(idx) = nav.focus_index {
-            if let Some((player_id, sweater_number, last_name)) =
-                self.get_player_info_at_index(idx, data)
-            {
-                return Effect::Action(Action::PushDocument(StackedDocument::PlayerDetail {
-                    player_id,
-                    sweater_number,
-                    last_name,
-                }));
-            }
+        if let Some((player_id, sweater_number, last_name)) = self.focused_player(nav, data) {
+            return Effect::Action(Action::PushDocument(StackedDocument::PlayerDetail {
+                player_id,
+                sweater_number,
+                last_name,
+            }));
         }
         Effect::None
     }
 
+    fn focused_player(
+        &self,
+        nav: &DocumentNavState,
+        data: &DataState,
+    ) -> Option<(i64, Option<i32>, String)> {
+        self.get_player_info_at_index(nav.focus_index?, nav.sort, data)
+            .or_else(|| {
+                // Game leaders and three stars link to players from the roster tables
+                let LinkTarget::Action(action) = nav.focused_link_target()? else {
+                    return None;
+                };
+                let player_id: i64 = action.strip_prefix("player:")?.parse().ok()?;
+                self.player_order(None, data)
+                    .into_iter()
+                    .find(|(id, _, _)| *id == player_id)
+                    .map(|(id, sweater_number, name)| (id, Some(sweater_number), name))
+            })
+    }
+
     fn populate_focusable_metadata(
         &self,
         nav: &mut DocumentNavState,
         data: &DataState,
-        width: u16,
+        context: &FocusContext,
     ) {
-        use crate::tui::components::boxscore_document::{BoxscoreDocumentContent, TeamView};
-        use crate::tui::document::FocusContext;
-
-        if let Some(boxscore) = data.boxscores.get(&self.game_id) {
-            let doc = BoxscoreDocumentContent::new(self.game_id, boxscore.clone(), TeamView::Away);
-            // Build with width so layout (side-by-side vs stacked) is correct
-            let focus = FocusContext::default().with_width(width);
-            let elements = doc.build(&focus);
-
-            // Extract metadata from built elements
-            let mut focusable = Vec::new();
-            let mut y_offset = 0u16;
-            for elem in &elements {
-                elem.collect_focusable(&mut focusable, y_offset);
-                y_offset += elem.height();
-            }
+        if let Some(doc) = self.content(nav, data) {
+            // Build with the context's width so layout (side-by-side vs stacked) is correct
+            populate_from_document(nav, &doc, context);
+        }
+    }
 
-            nav.focusable_positions = focusable.iter().map(|f| f.y).collect();
-            nav.focusable_heights = focusable.iter().map(|f| f.height).collect();
-            nav.focusable_ids = focusable.iter().map(|f| f.id.clone()).collect();
-            nav.link_targets = focusable.iter().map(|f| f.link_target.clone()).collect();
-            nav.focusable_row_positions = focusable.iter().map(|f| f.row_position).collect();
+    fn document(&self, nav: &DocumentNavState, data: &DataState) -> Option<Box<dyn Document>> {
+        self.content(nav, data)
+            .map(|doc| Box::new(doc) as Box<dyn Document>)
+    }
+
+    fn handle_key(
+        &self,
+        key: KeyEvent,
+        nav: &mut DocumentNavState,
+        data: &DataState,
+        context: &FocusContext,
+    ) -> Effect {
+        if key.code == KeyCode::Char('s') {
+            self.cycle_sort(nav, data, context);
+            return Effect::None;
+        }
+        if key.code == KeyCode::Char('m') {
+            // Rates can widen the skater tables, so positions are refreshed
+            nav.per_60 = !nav.per_60;
+            self.populate_focusable_metadata(nav, data, context);
+            autoscroll_to_focus(nav);
+            return Effect::None;
         }
+        self.populate_focusable_metadata(nav, data, context);
+        if let Some(nav_msg) = key_to_nav_msg(key) {
+            // Left/Right scroll wide tables when there is no row sibling to move to
+            match nav_msg {
+                DocumentNavMsg::FocusLeft
+                    if find_row_sibling(nav, RowDirection::Left).is_none() =>
+                {
+                    nav.column_offset = nav.column_offset.saturating_sub(1);
+                    return Effect::None;
+                }
+                DocumentNavMsg::FocusRight
+                    if find_row_sibling(nav, RowDirection::Right).is_none() =>
+                {
+                    nav.column_offset = (nav.column_offset + 1).min(max_column_offset());
+                    return Effect::None;
+                }
+                _ => {}
+            }
+            return handle_message(nav, &nav_msg);
+        }
+        if key.code == KeyCode::Enter {
+            return self.activate(nav, data);
+        }
+        Effect::None
     }
 }
 
 impl BoxscoreDocumentHandler {
+    /// Boxscore content for the current data and view options
+    fn content(&self, nav: &DocumentNavState, data: &DataState) -> Option<BoxscoreDocumentContent> {
+        let boxscore = data.boxscores.get(&self.game_id)?;
+        let doc = BoxscoreDocumentContent::new(self.game_id, boxscore.clone(), TeamView::Away)
+            .with_period_scores(data.period_scores.get(&self.game_id).cloned())
+            .with_game_summary(
+                data.game_info
+                    .get(&self.game_id)
+                    .and_then(|info| info.summary.clone()),
+            )
+            .with_records(team_records(
+                data.standings
+                    .as_ref()
+                    .map(|standings| standings.as_slice()),
+                &boxscore.away_team.abbrev,
+                &boxscore.home_team.abbrev,
+            ))
+            .with_sort(nav.sort)
+            .with_per_60(nav.per_60)
+            .with_column_offset(nav.column_offset);
+        Some(doc)
+    }
+
+    /// Advance to the next sort key, keeping focus on the same player
+    fn cycle_sort(&self, nav: &mut DocumentNavState, data: &DataState, context: &FocusContext) {
+        let focused_player = nav
+            .focus_index
+            .and_then(|idx| self.get_player_info_at_index(idx, nav.sort, data))
+            .map(|(player_id, _, _)| player_id);
+
+        let next = next_boxscore_sort(nav.sort);
+        nav.sort = next;
+
+        if let Some(player_id) = focused_player {
+            nav.focus_index = self.index_of_player(player_id, next, data);
+        }
+        self.populate_focusable_metadata(nav, data, context);
+        autoscroll_to_focus(nav);
+    }
+
+    /// Player ids in focus order (away forwards, defense, goalies, then home)
+    fn player_order(&self, sort: Option<TableSort>, data: &DataState) -> Vec<(i64, i32, String)> {
+        let Some(boxscore) = data.boxscores.get(&self.game_id) else {
+            return Vec::new();
+        };
+        let mut order = Vec::new();
+        for team in [
+            &boxscore.player_by_game_stats.away_team,
+            &boxscore.player_by_game_stats.home_team,
+        ] {
+            let mut forwards = team.forwards.clone();
+            sort_skaters(&mut forwards, sort);
+            let mut defense = team.defense.clone();
+            sort_skaters(&mut defense, sort);
+            let mut goalies = team.goalies.clone();
+            sort_goalies(&mut goalies, sort);
+
+            order.extend(
+                forwards
+                    .iter()
+                    .chain(defense.iter())
+                    .map(|p| (p.player_id, p.sweater_number, p.name.default.clone())),
+            );
+            order.extend(
+                goalies
+                    .iter()
+                    .map(|g| (g.player_id, g.sweater_number, g.name.default.clone())),
+            );
+        }
+        order
+    }
+
+    /// Focus index of the given player under the given sort
+    fn index_of_player(
+        &self,
+        player_id: i64,
+        sort: Option<TableSort>,
+        data: &DataState,
+    ) -> Option<usize> {
+        self.player_order(sort, data)
+            .iter()
+            .position(|(id, _, _)| *id == player_id)
+    }
+
     /// Get the player info (id, sweater_number, last_name) at the given focus index
     pub(super) fn get_player_info_at_index(
         &self,
         index: usize,
+        sort: Option<TableSort>,
         data: &DataState,
     ) -> Option<(i64, Option<i32>, String)> {
-        let boxscore = data.boxscores.get(&self.game_id)?;
-        let away_stats = &boxscore.player_by_game_stats.away_team;
-        let home_stats = &boxscore.player_by_game_stats.home_team;
-
-        // Calculate section boundaries
-        let away_forwards_count = away_stats.forwards.len();
-        let away_defense_count = away_stats.defense.len();
-        let away_goalies_count = away_stats.goalies.len();
-        let away_total = away_forwards_count + away_defense_count + away_goalies_count;
-
-        let home_forwards_count = home_stats.forwards.len();
-        let home_defense_count = home_stats.defense.len();
-
-        if index < away_forwards_count {
-            away_stats
-                .forwards
-                .get(index)
-                .map(|p| (p.player_id, Some(p.sweater_number), p.name.default.clone()))
-        } else if index < away_forwards_count + away_defense_count {
-            let defense_idx = index - away_forwards_count;
-            away_stats
-                .defense
-                .get(defense_idx)
-                .map(|p| (p.player_id, Some(p.sweater_number), p.name.default.clone()))
-        } else if index < away_total {
-            let goalie_idx = index - away_forwards_count - away_defense_count;
-            away_stats
-                .goalies
-                .get(goalie_idx)
-                .map(|p| (p.player_id, Some(p.sweater_number), p.name.default.clone()))
-        } else if index < away_total + home_forwards_count {
-            let forward_idx = index - away_total;
-            home_stats
-                .forwards
-                .get(forward_idx)
-                .map(|p| (p.player_id, Some(p.sweater_number), p.name.default.clone()))
-        } else if index < away_total + home_forwards_count + home_defense_count {
-            let defense_idx = index - away_total - home_forwards_count;
-            home_stats
-                .defense
-                .get(defense_idx)
-                .map(|p| (p.player_id, Some(p.sweater_number), p.name.default.clone()))
-        } else {
-            let goalie_idx = index - away_total - home_forwards_count - home_defense_count;
-            home_stats
-                .goalies
-                .get(goalie_idx)
-                .map(|p| (p.player_id, Some(p.sweater_number), p.name.default.clone()))
-        }
+        self.player_order(sort, data)
+            .into_iter()
+            .nth(index)
+            .map(|(player_id, sweater_number, name)| (player_id, Some(sweater_number), name))
     }
 }
 
@@ -129,70 +247,109 @@ pub(super) struct TeamDetailDocumentHandler {
 }
 
 impl StackedDocumentHandler for TeamDetailDocumentHandler {
+    /// Player rows open the player, schedule rows the opponent or the game's boxscore
     fn activate(&self, nav: &DocumentNavState, data: &DataState) -> Effect {
-        use crate::tui::helpers::{ClubGoalieStatsSorting, ClubSkaterStatsSorting};
+        if let Some((player_id, sweater_number, last_name)) = self.focused_player(nav, data) {
+            return Effect::Action(Action::PushDocument(StackedDocument::PlayerDetail {
+                player_id,
+                sweater_number,
+                last_name,
+            }));
+        }
 
-        let Some(idx) = nav.focus_index else {
-            return Effect::None;
-        };
-        let Some(roster) = data.team_roster_stats.get(&self.abbrev) else {
+        let Some(LinkTarget::Action(action)) = nav.focused_link_target() else {
             return Effect::None;
         };
+        if let Some(abbrev) = action.strip_prefix("team:") {
+            return Effect::Action(Action::PushDocument(StackedDocument::TeamDetail {
+                abbrev: abbrev.to_string(),
+            }));
+        }
+        let game = action
+            .strip_prefix("game:")
+            .and_then(|id| id.parse::<i64>().ok())
+            .and_then(|game_id| {
+                data.team_schedules
+                    .get(&self.abbrev)?
+                    .iter()
+                    .find(|g| g.id == game_id)
+            });
+        match game {
+            Some(game) => Effect::Action(Action::PushDocument(StackedDocument::Boxscore {
+                game_id: game.id,
+                away_abbrev: game.away_team.abbrev.clone(),
+                home_abbrev: game.home_team.abbrev.clone(),
+                away_score: game.away_team.score.unwrap_or(0),
+                home_score: game.home_team.score.unwrap_or(0),
+            })),
+            None => Effect::None,
+        }
+    }
 
-        // Sort the same way as display
-        let mut sorted_skaters = roster.skaters.clone();
-        sorted_skaters.sort_by_points_desc();
-
-        let mut sorted_goalies = roster.goalies.clone();
-        sorted_goalies.sort_by_games_played_desc();
-
-        let num_skaters = sorted_skaters.len();
-
-        // Note: ClubStats doesn't have sweater_number, so we pass None
-        let player_info: Option<(i64, Option<i32>, String)> = if idx < num_skaters {
-            sorted_skaters
-                .get(idx)
-                .map(|p| (p.player_id, None, p.last_name.default.clone()))
-        } else {
-            let goalie_idx = idx - num_skaters;
-            sorted_goalies
-                .get(goalie_idx)
-                .map(|g| (g.player_id, None, g.last_name.default.clone()))
+    /// Every row links to its player, so the link target identifies them
+    fn focused_player(
+        &self,
+        nav: &DocumentNavState,
+        data: &DataState,
+    ) -> Option<(i64, Option<i32>, String)> {
+        let Some(LinkTarget::Action(action)) = nav.focused_link_target() else {
+            return None;
         };
+        let player_id: i64 = action.strip_prefix("player:")?.parse().ok()?;
 
-        match player_info {
-            Some((player_id, sweater_number, last_name)) => {
-                Effect::Action(Action::PushDocument(StackedDocument::PlayerDetail {
-                    player_id,
-                    sweater_number,
-                    last_name,
-                }))
-            }
-            None => Effect::None,
-        }
+        let stats = data.team_roster_stats.get(&self.abbrev)?;
+        let last_name = stats
+            .skaters
+            .iter()
+            .find(|s| s.player_id == player_id)
+            .map(|s| s.last_name.default.clone())
+            .or_else(|| {
+                stats
+                    .goalies
+                    .iter()
+                    .find(|g| g.player_id == player_id)
+                    .map(|g| g.last_name.default.clone())
+            })?;
+        let sweater_number = data.team_rosters.get(&self.abbrev).and_then(|roster| {
+            roster
+                .forwards
+                .iter()
+                .chain(&roster.defensemen)
+                .chain(&roster.goalies)
+                .find(|p| p.id == player_id)
+                .map(|p| p.sweater_number)
+        });
+
+        Some((player_id, sweater_number, last_name))
     }
 
     fn populate_focusable_metadata(
         &self,
         nav: &mut DocumentNavState,
         data: &DataState,
-        _width: u16,
+        context: &FocusContext,
     ) {
-        use crate::tui::components::team_detail_document::TeamDetailDocumentContent;
+        populate_from_document(nav, &self.content(data), context);
+    }
 
+    fn document(&self, _nav: &DocumentNavState, data: &DataState) -> Option<Box<dyn Document>> {
+        Some(Box::new(self.content(data)))
+    }
+}
+
+impl TeamDetailDocumentHandler {
+    /// Team detail content from the roster and standings data
+    fn content(&self, data: &DataState) -> TeamDetailDocumentContent {
         let roster = data.team_roster_stats.get(&self.abbrev);
-        let standing = data.standings.as_ref().as_ref().and_then(|standings| {
+        let standing = data.standings.as_ref().and_then(|standings| {
             standings
                 .iter()
                 .find(|s| s.team_abbrev.default == self.abbrev)
                 .cloned()
         });
-
-        let doc = TeamDetailDocumentContent::new(self.abbrev.clone(), standing, roster.cloned());
-        nav.focusable_positions = doc.focusable_positions();
-        nav.focusable_heights = doc.focusable_heights();
-        nav.focusable_ids = doc.focusable_ids();
-        nav.link_targets = doc.focusable_link_targets();
+        TeamDetailDocumentContent::new(self.abbrev.clone(), standing, roster.cloned())
+            .with_roster(data.team_rosters.get(&self.abbrev).cloned())
+            .with_schedule(data.team_schedules.get(&self.abbrev).cloned())
     }
 }
 
@@ -201,34 +358,23 @@ pub(super) struct PlayerDetailDocumentHandler {
     pub(super) player_id: i64,
 }
 
-impl StackedDocumentHandler for PlayerDetailDocumentHandler {
-    fn activate(&self, nav: &DocumentNavState, data: &DataState) -> Effect {
-        use crate::tui::helpers::SeasonSorting;
-
-        let Some(idx) = nav.focus_index else {
-            return Effect::None;
-        };
-        let Some(player) = data.player_data.get(&self.player_id) else {
-            return Effect::None;
-        };
-        let Some(seasons) = &player.season_totals else {
-            return Effect::None;
-        };
-
-        // Filter and sort same as display
-        let mut nhl_seasons: Vec<_> = seasons
-            .iter()
-            .filter(|s| s.game_type == nhl_api::GameType::RegularSeason && s.league_abbrev == "NHL")
-            .collect();
-        nhl_seasons.sort_by_season_desc();
+impl PlayerDetailDocumentHandler {
+    fn content(&self, data: &DataState) -> PlayerDetailDocumentContent {
+        PlayerDetailDocumentContent::new(
+            data.player_data.get(&self.player_id).cloned(),
+            self.player_id,
+        )
+        .with_game_log(data.player_game_logs.get(&self.player_id).cloned())
+    }
+}
 
-        let Some(season) = nhl_seasons.get(idx) else {
-            return Effect::None;
-        };
-        let Some(ref common_name) = season.team_common_name else {
+impl StackedDocumentHandler for PlayerDetailDocumentHandler {
+    /// Season and game log rows both link to a team
+    fn activate(&self, nav: &DocumentNavState, _data: &DataState) -> Effect {
+        let Some(LinkTarget::Action(action)) = nav.focused_link_target() else {
             return Effect::None;
         };
-        let Some(abbrev) = crate::team_abbrev::common_name_to_abbrev(&common_name.default) else {
+        let Some(abbrev) = action.strip_prefix("team:") else {
             return Effect::None;
         };
 
@@ -241,15 +387,318 @@ impl StackedDocumentHandler for PlayerDetailDocumentHandler {
         &self,
         nav: &mut DocumentNavState,
         data: &DataState,
-        _width: u16,
+        context: &FocusContext,
     ) {
-        use crate::tui::components::player_detail_document::PlayerDetailDocumentContent;
-
-        let player_data = data.player_data.get(&self.player_id).cloned();
-        let doc = PlayerDetailDocumentContent::new(player_data, self.player_id);
-        nav.focusable_positions = doc.focusable_positions();
-        nav.focusable_heights = doc.focusable_heights();
-        nav.focusable_ids = doc.focusable_ids();
-        nav.link_targets = doc.focusable_link_targets();
+        populate_from_document(nav, &self.content(data), context);
+    }
+
+    fn document(&self, _nav: &DocumentNavState, data: &DataState) -> Option<Box<dyn Document>> {
+        Some(Box::new(self.content(data)))
+    }
+
+    /// The player this document is about, whatever row has focus
+    fn focused_player(
+        &self,
+        _nav: &DocumentNavState,
+        data: &DataState,
+    ) -> Option<(i64, Option<i32>, String)> {
+        let player = data.player_data.get(&self.player_id)?;
+        Some((
+            self.player_id,
+            player.sweater_number,
+            player.last_name.default.clone(),
+        ))
+    }
+}
+
+/// Handler for PlayerCompare documents
+pub(super) struct PlayerCompareDocumentHandler {
+    pub(super) player_a: i64,
+    pub(super) player_b: i64,
+}
+
+impl PlayerCompareDocumentHandler {
+    fn content(&self, data: &DataState) -> PlayerCompareDocumentContent {
+        PlayerCompareDocumentContent::new(
+            self.player_a,
+            self.player_b,
+            data.player_data.get(&self.player_a).cloned(),
+            data.player_data.get(&self.player_b).cloned(),
+        )
+    }
+}
+
+impl StackedDocumentHandler for PlayerCompareDocumentHandler {
+    fn activate(&self, _nav: &DocumentNavState, _data: &DataState) -> Effect {
+        Effect::None
+    }
+
+    fn populate_focusable_metadata(
+        &self,
+        nav: &mut DocumentNavState,
+        data: &DataState,
+        context: &FocusContext,
+    ) {
+        populate_from_document(nav, &self.content(data), context);
+    }
+
+    fn document(&self, _nav: &DocumentNavState, data: &DataState) -> Option<Box<dyn Document>> {
+        Some(Box::new(self.content(data)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::{
+        create_mock_boxscore, create_mock_game_matchup, create_mock_player_game_log,
+        create_mock_player_landing, create_mock_team_schedule,
+    };
+    use crate::tui::components::boxscore_document::boxscore_table_sort;
+    use crossterm::event::KeyModifiers;
+    use nhl_api::{GameSummary, GameType, LocalizedString, Position, SkaterStats, ThreeStar};
+    use std::sync::Arc;
+
+    const GAME_ID: i64 = 2024020001;
+
+    fn skater(player_id: i64, name: &str, points: i32) -> SkaterStats {
+        SkaterStats {
+            player_id,
+            sweater_number: player_id as i32,
+            name: LocalizedString {
+                default: name.to_string(),
+            },
+            position: Position::Center,
+            goals: 0,
+            assists: points,
+            points,
+            plus_minus: 0,
+            pim: 0,
+            hits: 0,
+            power_play_goals: 0,
+            sog: 0,
+            faceoff_winning_pctg: 0.0,
+            toi: "10:00".to_string(),
+            blocked_shots: 0,
+            shifts: 10,
+            giveaways: 0,
+            takeaways: 0,
+        }
+    }
+
+    fn data_with_forwards(forwards: Vec<SkaterStats>) -> DataState {
+        let mut boxscore = create_mock_boxscore(GAME_ID);
+        boxscore.player_by_game_stats.away_team.forwards = forwards;
+        let mut data = DataState::default();
+        Arc::make_mut(&mut data.boxscores).insert(GAME_ID, Arc::new(boxscore));
+        data
+    }
+
+    fn sort_key() -> KeyEvent {
+        KeyEvent::new(KeyCode::Char('s'), KeyModifiers::NONE)
+    }
+
+    fn width(width: u16) -> FocusContext {
+        FocusContext::default().with_width(width)
+    }
+
+    #[test]
+    fn test_three_star_row_focuses_player() {
+        let handler = BoxscoreDocumentHandler { game_id: GAME_ID };
+        let mut data = data_with_forwards(vec![skater(1, "Low", 1), skater(2, "High", 5)]);
+        let mut matchup = create_mock_game_matchup(GAME_ID);
+        matchup.summary = Some(GameSummary {
+            scoring: vec![],
+            shootout: None,
+            three_stars: Some(vec![ThreeStar {
+                star: 1,
+                player_id: 2,
+                team_abbrev: "NJD".to_string(),
+                headshot: String::new(),
+                name: LocalizedString {
+                    default: "High".to_string(),
+                },
+                sweater_no: 2,
+                position: Position::Center,
+                goals: Some(0),
+                assists: Some(5),
+                points: Some(5),
+                goals_against_average: None,
+                save_pctg: None,
+            }]),
+            penalties: vec![],
+        });
+        Arc::make_mut(&mut data.game_info).insert(GAME_ID, matchup);
+        let mut nav = DocumentNavState::default();
+
+        handler.populate_focusable_metadata(&mut nav, &data, &width(120));
+        nav.focus_index = Some(nav.focusable_ids.len() - 1);
+
+        assert_eq!(
+            handler.focused_player(&nav, &data),
+            Some((2, Some(2), "High".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_player_order_follows_sort() {
+        let handler = BoxscoreDocumentHandler { game_id: GAME_ID };
+        let data = data_with_forwards(vec![
+            skater(1, "Low", 1),
+            skater(2, "High", 5),
+            skater(3, "Mid", 3),
+        ]);
+
+        let points_desc = boxscore_table_sort("points_desc");
+        let roster = handler.get_player_info_at_index(0, None, &data);
+        let points = handler.get_player_info_at_index(0, points_desc, &data);
+
+        assert_eq!(roster.map(|p| p.0), Some(1));
+        assert_eq!(points.map(|p| p.0), Some(2));
+    }
+
+    #[test]
+    fn test_sort_key_cycles_and_focus_follows_player() {
+        let handler = BoxscoreDocumentHandler { game_id: GAME_ID };
+        let data = data_with_forwards(vec![
+            skater(1, "Low", 1),
+            skater(2, "High", 5),
+            skater(3, "Mid", 3),
+        ]);
+        let mut nav = DocumentNavState {
+            focus_index: Some(0),
+            viewport_height: 30,
+            ..Default::default()
+        };
+
+        handler.handle_key(sort_key(), &mut nav, &data, &width(120));
+
+        assert_eq!(nav.sort, boxscore_table_sort("points_desc"));
+        // "Low" (roster index 0) moves to the bottom when sorted by points
+        assert_eq!(nav.focus_index, Some(2));
+        assert_eq!(
+            handler
+                .get_player_info_at_index(2, nav.sort, &data)
+                .map(|p| p.0),
+            Some(1)
+        );
+    }
+
+    #[test]
+    fn test_per_60_key_toggles_rates() {
+        let handler = BoxscoreDocumentHandler { game_id: GAME_ID };
+        let data = data_with_forwards(vec![skater(1, "Low", 1), skater(2, "High", 5)]);
+        let mut nav = DocumentNavState {
+            focus_index: Some(1),
+            viewport_height: 30,
+            ..Default::default()
+        };
+        let per_60_key = KeyEvent::new(KeyCode::Char('m'), KeyModifiers::NONE);
+
+        handler.handle_key(per_60_key, &mut nav, &data, &width(120));
+        assert!(nav.per_60);
+        // Rates don't reorder the table, so focus stays on the player
+        assert_eq!(nav.focus_index, Some(1));
+        assert_eq!(handler.focused_player(&nav, &data).map(|p| p.0), Some(2));
+
+        handler.handle_key(per_60_key, &mut nav, &data, &width(120));
+        assert!(!nav.per_60);
+    }
+
+    #[test]
+    fn test_left_right_scroll_columns_in_stacked_layout() {
+        let handler = BoxscoreDocumentHandler { game_id: GAME_ID };
+        let data = data_with_forwards(vec![skater(1, "Low", 1), skater(2, "High", 5)]);
+        let mut nav = DocumentNavState {
+            focus_index: Some(0),
+            viewport_height: 30,
+            ..Default::default()
+        };
+        let right = KeyEvent::new(KeyCode::Right, KeyModifiers::NONE);
+        let left = KeyEvent::new(KeyCode::Left, KeyModifiers::NONE);
+
+        handler.handle_key(right, &mut nav, &data, &width(60));
+        handler.handle_key(right, &mut nav, &data, &width(60));
+        assert_eq!(nav.column_offset, 2);
+        assert_eq!(nav.focus_index, Some(0));
+
+        handler.handle_key(left, &mut nav, &data, &width(60));
+        handler.handle_key(left, &mut nav, &data, &width(60));
+        handler.handle_key(left, &mut nav, &data, &width(60));
+        assert_eq!(nav.column_offset, 0);
+    }
+
+    #[test]
+    fn test_right_scroll_is_bounded() {
+        let handler = BoxscoreDocumentHandler { game_id: GAME_ID };
+        let data = data_with_forwards(vec![skater(1, "Low", 1)]);
+        let mut nav = DocumentNavState {
+            focus_index: Some(0),
+            viewport_height: 30,
+            ..Default::default()
+        };
+        let right = KeyEvent::new(KeyCode::Right, KeyModifiers::NONE);
+
+        for _ in 0..50 {
+            handler.handle_key(right, &mut nav, &data, &width(60));
+        }
+        assert_eq!(nav.column_offset, max_column_offset());
+    }
+
+    #[test]
+    fn test_player_game_log_row_opens_opponent() {
+        let handler = PlayerDetailDocumentHandler { player_id: 97 };
+        let mut data = DataState::default();
+        Arc::make_mut(&mut data.player_data).insert(97, create_mock_player_landing(97));
+        Arc::make_mut(&mut data.player_game_logs).insert(
+            97,
+            create_mock_player_game_log(97, 20242025, GameType::RegularSeason).game_log,
+        );
+        let mut nav = DocumentNavState::default();
+
+        handler.populate_focusable_metadata(&mut nav, &data, &width(80));
+        nav.focus_index = Some(1);
+
+        match handler.activate(&nav, &data) {
+            Effect::Action(Action::PushDocument(StackedDocument::TeamDetail { abbrev })) => {
+                assert_eq!(abbrev, "MTL")
+            }
+            other => panic!("Expected TeamDetail push, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_team_schedule_result_opens_boxscore() {
+        let handler = TeamDetailDocumentHandler {
+            abbrev: "BOS".to_string(),
+        };
+        let mut data = DataState::default();
+        Arc::make_mut(&mut data.team_schedules)
+            .insert("BOS".to_string(), create_mock_team_schedule("BOS").games);
+        let mut nav = DocumentNavState::default();
+
+        handler.populate_focusable_metadata(&mut nav, &data, &width(80));
+        // First row: opponent link, then the result link
+        nav.focus_index = Some(1);
+
+        match handler.activate(&nav, &data) {
+            Effect::Action(Action::PushDocument(StackedDocument::Boxscore {
+                game_id,
+                away_abbrev,
+                home_abbrev,
+                ..
+            })) => {
+                assert_eq!(game_id, 2024020100);
+                assert_eq!((away_abbrev.as_str(), home_abbrev.as_str()), ("MTL", "BOS"));
+            }
+            other => panic!("Expected Boxscore push, got {:?}", other),
+        }
+
+        nav.focus_index = Some(0);
+        assert!(matches!(
+            handler.activate(&nav, &data),
+            Effect::Action(Action::PushDocument(StackedDocument::TeamDetail { ref abbrev }))
+                if abbrev == "MTL"
+        ));
     }
 }
@@ -0,0 +1,282 @@
+//! Markdown export for documents
+//!
+//! Serializes a document's element tree (as built with no focus) to Markdown:
+//! headings map to `#`, section titles to bold text, and tables to pipe tables
+//! built from the column headers and each cell's display text.
+
+use std::path::Path;
+
+use crate::error::Result;
+use crate::tui::components::TableWidget;
+use crate::tui::Alignment;
+
+use super::{Document, DocumentElement, FocusContext, PeriodShots};
+
+/// Serialize a document to Markdown
+pub fn document_to_markdown(doc: &dyn Document) -> String {
+    let focus = FocusContext {
+        use_unicode: true,
+        ..Default::default()
+    };
+    let mut blocks = Vec::new();
+    for element in doc.build(&focus) {
+        collect_blocks(&element, &mut blocks);
+    }
+    let mut markdown = blocks.join("\n\n");
+    markdown.push('\n');
+    markdown
+}
+
+/// Write exported Markdown to `path`
+pub fn write_markdown(path: &Path, markdown: &str) -> Result<()> {
+    std::fs::write(path, markdown)?;
+    Ok(())
+}
+
+/// Append the Markdown blocks for an element (paragraphs, headings, tables)
+fn collect_blocks(element: &DocumentElement, blocks: &mut Vec<String>) {
+    match element {
+        DocumentElement::Text { content, .. } => {
+            if !content.trim().is_empty() {
+                blocks.push(content.trim_end().to_string());
+            }
+        }
+        DocumentElement::Heading { level, content } => {
+            let level = (*level).clamp(1, 6) as usize;
+            blocks.push(format!("{} {}", "#".repeat(level), content));
+        }
+        DocumentElement::SectionTitle { content, .. } | DocumentElement::Banner { content } => {
+            blocks.push(format!("**{}**", content))
+        }
+        DocumentElement::Link { display, .. } => blocks.push(display.clone()),
+        DocumentElement::Separator => blocks.push("---".to_string()),
+        DocumentElement::Spacer { .. } | DocumentElement::Custom { .. } => {}
+        DocumentElement::Group { children, .. } | DocumentElement::Row { children, .. } => {
+            for child in children {
+                collect_blocks(child, blocks);
+            }
+        }
+        DocumentElement::Indented { element, .. } => collect_blocks(element, blocks),
+        DocumentElement::Table { widget, .. } => {
+            if widget.row_count() > 0 {
+                blocks.push(table_to_markdown(widget));
+            }
+        }
+        DocumentElement::TeamBoxscore {
+            team_name,
+            forwards_table,
+            defense_table,
+            goalies_table,
+            ..
+        } => {
+            blocks.push(format!("**{}**", team_name));
+            for (title, table) in [
+                ("Forwards", forwards_table),
+                ("Defense", defense_table),
+                ("Goalies", goalies_table),
+            ] {
+                if table.row_count() > 0 {
+                    blocks.push(format!("*{}*", title));
+                    blocks.push(table_to_markdown(table));
+                }
+            }
+        }
+        DocumentElement::ScoreBoxElement { score_box, .. } => {
+            let score = |s: Option<i32>| s.map_or("-".to_string(), |s| s.to_string());
+            blocks.push(format!(
+                "{} {} - {} {}",
+                score_box.away_team,
+                score(score_box.away_score),
+                score(score_box.home_score),
+                score_box.home_team
+            ));
+        }
+        DocumentElement::BigScoreElement { big_score } => blocks.push(format!(
+            "**{} {} - {} {}**",
+            big_score.away_abbrev,
+            big_score.away_score,
+            big_score.home_score,
+            big_score.home_abbrev
+        )),
+        DocumentElement::EmptyStateElement { empty_state } => {
+            blocks.push(format!("*{}*", empty_state.message));
+            blocks.extend(empty_state.detail.clone());
+        }
+        DocumentElement::CompareBar {
+            label,
+            away_display,
+            home_display,
+            ..
+        } => blocks.push(format!("{}: {} - {}", label, away_display, home_display)),
+        DocumentElement::ShotsByPeriod {
+            away_abbrev,
+            home_abbrev,
+            periods,
+        } => {
+            if periods.is_empty() {
+                return;
+            }
+            let row = |first: &str, cell: fn(&PeriodShots) -> String| {
+                let mut cells = vec![first.to_string()];
+                cells.extend(periods.iter().map(cell));
+                pipe_row(&cells)
+            };
+            let mut rules = vec!["---"];
+            rules.extend(periods.iter().map(|_| "---:"));
+            blocks.push(
+                [
+                    row("SOG", |p| p.label.clone()),
+                    pipe_row(&rules),
+                    row(away_abbrev, |p| p.away.to_string()),
+                    row(home_abbrev, |p| p.home.to_string()),
+                ]
+                .join("\n"),
+            );
+        }
+    }
+}
+
+/// Render a table as a Markdown pipe table, including its totals row
+fn table_to_markdown(table: &TableWidget) -> String {
+    let columns = 0..table.column_count();
+    let headers: Vec<String> = columns
+        .clone()
+        .map(|col| escape_cell(table.column_header(col).unwrap_or("")))
+        .collect();
+    let rules: Vec<&str> = columns
+        .clone()
+        .map(|col| match table.column_align(col) {
+            Some(Alignment::Right) => "---:",
+            Some(Alignment::Center) => ":---:",
+            _ => "---",
+        })
+        .collect();
+
+    let mut lines = vec![pipe_row(&headers), pipe_row(&rules)];
+    for row in 0..table.row_count() {
+        let cells: Vec<String> = columns
+            .clone()
+            .map(|col| {
+                table
+                    .get_cell_value(row, col)
+                    .map(|cell| escape_cell(cell.display_text()))
+                    .unwrap_or_default()
+            })
+            .collect();
+        lines.push(pipe_row(&cells));
+    }
+    if let Some(totals) = table.totals() {
+        let cells: Vec<String> = totals
+            .iter()
+            .map(|total| {
+                if total.is_empty() {
+                    String::new()
+                } else {
+                    format!("**{}**", escape_cell(total))
+                }
+            })
+            .collect();
+        lines.push(pipe_row(&cells));
+    }
+    lines.join("\n")
+}
+
+fn pipe_row<S: AsRef<str>>(cells: &[S]) -> String {
+    let cells: Vec<&str> = cells.iter().map(AsRef::as_ref).collect();
+    format!("| {} |", cells.join(" | "))
+}
+
+/// Escape characters that would break a pipe table cell
+fn escape_cell(text: &str) -> String {
+    text.replace('|', "\\|")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tui::{CellValue, ColumnDef, ColumnTotal};
+
+    struct Row {
+        name: &'static str,
+        goals: i32,
+    }
+
+    fn rows() -> Vec<Row> {
+        vec![
+            Row {
+                name: "A | B",
+                goals: 2,
+            },
+            Row {
+                name: "C",
+                goals: 1,
+            },
+        ]
+    }
+
+    struct TestDocument;
+
+    impl Document for TestDocument {
+        fn build(&self, _focus: &FocusContext) -> Vec<DocumentElement> {
+            let columns = vec![
+                ColumnDef::new("Player", 12, Alignment::Left, |r: &Row| {
+                    CellValue::Text(r.name.to_string())
+                })
+                .with_total(ColumnTotal::Label("TOTAL".to_string())),
+                ColumnDef::new("G", 3, Alignment::Right, |r: &Row| {
+                    CellValue::Text(r.goals.to_string())
+                })
+                .with_total(ColumnTotal::Sum),
+            ];
+            let table = TableWidget::from_data(&columns, rows()).with_totals(&columns, &rows());
+
+            vec![
+                DocumentElement::heading(2, "Scoring"),
+                DocumentElement::spacer(1),
+                DocumentElement::section_title("Skaters", true),
+                DocumentElement::table("skaters", table),
+                DocumentElement::separator(),
+                DocumentElement::text("Final"),
+            ]
+        }
+
+        fn title(&self) -> String {
+            "Test".to_string()
+        }
+
+        fn id(&self) -> String {
+            "test".to_string()
+        }
+    }
+
+    #[test]
+    fn test_document_to_markdown() {
+        let markdown = document_to_markdown(&TestDocument);
+
+        assert_eq!(
+            markdown,
+            "## Scoring\n\n\
+             **Skaters**\n\n\
+             | Player | G |\n\
+             | --- | ---: |\n\
+             | A \\| B | 2 |\n\
+             | C | 1 |\n\
+             | **TOTAL** | **3** |\n\n\
+             ---\n\n\
+             Final\n"
+        );
+    }
+
+    #[test]
+    fn test_empty_table_is_omitted() {
+        let columns = vec![ColumnDef::new("G", 3, Alignment::Right, |r: &Row| {
+            CellValue::Text(r.goals.to_string())
+        })];
+        let table = TableWidget::from_data(&columns, Vec::new());
+        let mut blocks = Vec::new();
+
+        collect_blocks(&DocumentElement::table("empty", table), &mut blocks);
+
+        assert!(blocks.is_empty());
+    }
+}
@@ -11,26 +11,35 @@
 
 pub mod builder;
 pub mod elements;
+pub mod find;
 pub mod focus;
 mod handlers;
 pub mod link;
+pub mod markdown;
 pub mod viewport;
 pub mod widget;
 
 use crossterm::event::{KeyCode, KeyEvent};
 use ratatui::buffer::Buffer;
 use ratatui::layout::Rect;
+use ratatui::style::Modifier;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
 use std::sync::Arc;
 
 use crate::config::DisplayConfig;
 use crate::tui::component::Effect;
 use crate::tui::document_nav::{handle_message, DocumentNavState};
+use crate::tui::hit_test::{HitRegion, HitTarget};
 use crate::tui::nav_handler::key_to_nav_msg;
 use crate::tui::state::DataState;
 use crate::tui::types::StackedDocument;
+use crate::tui::CellValue;
+use find::{highlight_line, matching_lines};
 
 pub use builder::DocumentBuilder;
-pub use elements::{DocumentElement, RowAlignment, TEAM_BOXSCORE_SIDE_BY_SIDE_WIDTH};
+pub use elements::{team_boxscore_side_by_side_width, DocumentElement, PeriodShots, RowAlignment};
+pub use find::{FindState, SearchableSpan};
 pub use focus::{FocusManager, FocusableElement, FocusableId, RowPosition};
 pub use link::{DocumentLink, DocumentType, LinkParams, LinkTarget};
 pub use viewport::Viewport;
@@ -45,6 +54,8 @@ pub struct FocusContext {
     pub available_width: Option<u16>,
     /// Whether to use unicode characters for rendering
     pub use_unicode: bool,
+    /// Whether decorative spacers should be shrunk to save rows
+    pub compact_spacing: bool,
 }
 
 impl FocusContext {
@@ -54,6 +65,7 @@ impl FocusContext {
             focused_id: Some(id.clone()),
             available_width: None,
             use_unicode: true,
+            compact_spacing: false,
         }
     }
 
@@ -63,6 +75,7 @@ impl FocusContext {
             focused_id: Some(FocusableId::link(id)),
             available_width: None,
             use_unicode: true,
+            compact_spacing: false,
         }
     }
 
@@ -72,6 +85,7 @@ impl FocusContext {
             focused_id: Some(FocusableId::table_cell(table_name, row, col)),
             available_width: None,
             use_unicode: true,
+            compact_spacing: false,
         }
     }
 
@@ -87,6 +101,12 @@ impl FocusContext {
         self
     }
 
+    /// Set whether decorative spacers should be shrunk
+    pub fn with_compact_spacing(mut self, compact_spacing: bool) -> Self {
+        self.compact_spacing = compact_spacing;
+        self
+    }
+
     /// Get the focused table row (if focus is on a table cell)
     pub fn focused_table_row(&self, table_name: &str) -> Option<usize> {
         match &self.focused_id {
@@ -124,6 +144,24 @@ pub trait Document: Send + Sync {
     /// Get the document's unique ID
     fn id(&self) -> String;
 
+    /// Version of the document's content, folded into the default cache key
+    ///
+    /// Documents whose content can change while their id stays the same
+    /// should return a value that changes with that content.
+    fn content_version(&self) -> u64 {
+        0
+    }
+
+    /// Key identifying the document's inputs
+    ///
+    /// Two documents with the same key render the same content.
+    fn cache_key(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.id().hash(&mut hasher);
+        self.content_version().hash(&mut hasher);
+        hasher.finish()
+    }
+
     /// Calculate the total height needed to render all elements
     fn calculate_height(&self) -> u16 {
         self.build(&FocusContext::default())
@@ -175,6 +213,37 @@ pub trait Document: Send + Sync {
         FocusManager::from_elements(&elements).link_targets()
     }
 
+    /// Get the value of a focused table cell
+    ///
+    /// Builds the document with `id` focused so tables report their focused row.
+    fn focused_cell(&self, id: &FocusableId) -> Option<CellValue> {
+        self.build(&FocusContext::from_id(id))
+            .iter()
+            .find_map(|element| element.focused_cell(id))
+    }
+
+    /// Column legend of the table holding the focused cell `id`
+    ///
+    /// Lists the header and description of each described column; empty when
+    /// `id` isn't a table cell or the table has no descriptions.
+    fn column_legend(&self, id: &FocusableId) -> Vec<(String, String)> {
+        self.build(&FocusContext::from_id(id))
+            .iter()
+            .find_map(|element| element.column_legend(id))
+            .unwrap_or_default()
+    }
+
+    /// Text find can match, with the document line each piece is drawn on
+    fn searchable_spans(&self, focus: &FocusContext) -> Vec<SearchableSpan> {
+        let mut spans = Vec::new();
+        let mut y_offset = 0;
+        for element in self.build(focus) {
+            element.collect_searchable(&mut spans, y_offset);
+            y_offset += element.height();
+        }
+        spans
+    }
+
     /// Render the document to a buffer at full height
     /// Returns the buffer and the actual height used
     fn render_full(
@@ -220,8 +289,30 @@ pub trait StackedDocumentHandler: Send + Sync {
     ///
     /// Called before navigation to ensure metadata is current.
     /// Builds the document from data and extracts focusable positions/heights.
-    /// Width is used to determine layout (e.g., side-by-side vs stacked).
-    fn populate_focusable_metadata(&self, nav: &mut DocumentNavState, data: &DataState, width: u16);
+    /// The context carries layout inputs (width, compact spacing) so positions
+    /// match what is rendered.
+    fn populate_focusable_metadata(
+        &self,
+        nav: &mut DocumentNavState,
+        data: &DataState,
+        context: &FocusContext,
+    );
+
+    /// Build the document from current data (None while its data is missing)
+    ///
+    /// Used for features that work on any document, such as Markdown export.
+    fn document(&self, nav: &DocumentNavState, data: &DataState) -> Option<Box<dyn Document>>;
+
+    /// The focused player as (player_id, sweater_number, last_name), if any
+    ///
+    /// Used to pick players for comparison. Documents without player rows keep the default.
+    fn focused_player(
+        &self,
+        _nav: &DocumentNavState,
+        _data: &DataState,
+    ) -> Option<(i64, Option<i32>, String)> {
+        None
+    }
 
     /// Handle a key event for this document
     ///
@@ -232,10 +323,10 @@ pub trait StackedDocumentHandler: Send + Sync {
         key: KeyEvent,
         nav: &mut DocumentNavState,
         data: &DataState,
-        width: u16,
+        context: &FocusContext,
     ) -> Effect {
         // Populate focusable metadata on-demand before navigation
-        self.populate_focusable_metadata(nav, data, width);
+        self.populate_focusable_metadata(nav, data, context);
 
         // Try navigation first (Tab, arrows, Page keys, etc.)
         if let Some(nav_msg) = key_to_nav_msg(key) {
@@ -256,7 +347,8 @@ pub trait StackedDocumentHandler: Send + Sync {
 /// that understands how to navigate and activate elements within it.
 pub fn get_stacked_document_handler(doc: &StackedDocument) -> Box<dyn StackedDocumentHandler> {
     use handlers::{
-        BoxscoreDocumentHandler, PlayerDetailDocumentHandler, TeamDetailDocumentHandler,
+        BoxscoreDocumentHandler, PlayerCompareDocumentHandler, PlayerDetailDocumentHandler,
+        TeamDetailDocumentHandler,
     };
 
     match doc {
@@ -269,6 +361,12 @@ pub fn get_stacked_document_handler(doc: &StackedDocument) -> Box<dyn StackedDoc
         StackedDocument::PlayerDetail { player_id, .. } => Box::new(PlayerDetailDocumentHandler {
             player_id: *player_id,
         }),
+        StackedDocument::PlayerCompare {
+            player_a, player_b, ..
+        } => Box::new(PlayerCompareDocumentHandler {
+            player_a: *player_a,
+            player_b: *player_b,
+        }),
     }
 }
 
@@ -281,6 +379,12 @@ pub struct DocumentView {
     full_buffer: Option<Buffer>,
     /// Cached document height
     cached_height: u16,
+    /// Whether manual scrolling moves focus onto a visible element
+    focus_follows_scroll: bool,
+    /// Open find, whose matches are highlighted
+    find: Option<FindState>,
+    /// Whether the panel showing the document has focus (dims the scrollbar when not)
+    focused: bool,
 }
 
 impl DocumentView {
@@ -303,9 +407,24 @@ impl DocumentView {
             focus_manager,
             full_buffer: None,
             cached_height: doc_height,
+            focus_follows_scroll: false,
+            find: None,
+            focused: true,
         }
     }
 
+    /// Move focus to the nearest visible element when scrolling leaves it off-screen
+    pub fn with_focus_follows_scroll(mut self, enabled: bool) -> Self {
+        self.focus_follows_scroll = enabled;
+        self
+    }
+
+    /// Dim the scrollbar when the panel showing the document is not focused
+    pub fn with_focused(mut self, focused: bool) -> Self {
+        self.focused = focused;
+        self
+    }
+
     /// Update viewport height (e.g., on terminal resize)
     pub fn set_viewport_height(&mut self, height: u16) {
         self.viewport.set_height(height);
@@ -316,6 +435,11 @@ impl DocumentView {
         self.viewport.offset()
     }
 
+    /// Total height of the document in lines
+    pub fn content_height(&self) -> u16 {
+        self.cached_height
+    }
+
     /// Get the viewport
     pub fn viewport(&self) -> &Viewport {
         &self.viewport
@@ -331,35 +455,71 @@ impl DocumentView {
     /// Scroll the viewport up by a number of lines
     pub fn scroll_up(&mut self, lines: u16) {
         self.viewport.scroll_up(lines);
+        self.follow_scroll();
     }
 
     /// Scroll the viewport down by a number of lines
     pub fn scroll_down(&mut self, lines: u16) {
         self.viewport.scroll_down(lines);
+        self.follow_scroll();
     }
 
     /// Scroll to the top of the document
     pub fn scroll_to_top(&mut self) {
         self.viewport.scroll_to_top();
+        self.follow_scroll();
     }
 
     /// Scroll to the bottom of the document
     pub fn scroll_to_bottom(&mut self) {
         self.viewport.scroll_to_bottom();
+        self.follow_scroll();
     }
 
     /// Page up (scroll by viewport height - overlap)
     pub fn page_up(&mut self) {
         const PAGE_OVERLAP_LINES: u16 = 2;
         let page_size = self.viewport.height().saturating_sub(PAGE_OVERLAP_LINES);
-        self.viewport.scroll_up(page_size);
+        self.scroll_up(page_size);
     }
 
     /// Page down (scroll by viewport height - overlap)
     pub fn page_down(&mut self) {
         const PAGE_OVERLAP_LINES: u16 = 2;
         let page_size = self.viewport.height().saturating_sub(PAGE_OVERLAP_LINES);
-        self.viewport.scroll_down(page_size);
+        self.scroll_down(page_size);
+    }
+
+    /// With focus-follows-scroll, move an off-screen focus to the nearest visible element
+    ///
+    /// Focus that scrolled off the top goes to the first visible element, focus
+    /// below the viewport to the last one. Nothing changes when no element is visible.
+    fn follow_scroll(&mut self) {
+        if !self.focus_follows_scroll {
+            return;
+        }
+        let Some(focused) = self.focus_manager.get_focused_rect() else {
+            return;
+        };
+        if self.viewport.is_rect_visible(&focused) {
+            return;
+        }
+
+        let mut visible = self
+            .focus_manager
+            .elements()
+            .iter()
+            .enumerate()
+            .filter(|(_, element)| self.viewport.is_rect_visible(&element.rect))
+            .map(|(index, _)| index);
+        let target = if focused.y < self.viewport.offset() {
+            visible.next()
+        } else {
+            visible.next_back()
+        };
+        if let Some(index) = target {
+            self.focus_manager.focus_by_index(index);
+        }
     }
 
     // === Focus Navigation with Autoscrolling ===
@@ -442,9 +602,14 @@ impl DocumentView {
         self.focus_manager.clear_focus();
     }
 
-    /// Focus a specific element by index
-    pub fn focus_by_index(&mut self, index: usize) {
-        self.focus_manager.focus_by_index(index);
+    /// Focus a specific element by index with autoscrolling
+    pub fn focus_by_index(&mut self, index: usize) -> bool {
+        if self.focus_manager.focus_by_index(index) {
+            self.autoscroll_to_focused();
+            true
+        } else {
+            false
+        }
     }
 
     /// Set the scroll offset directly
@@ -452,6 +617,11 @@ impl DocumentView {
         self.viewport.set_offset(offset);
     }
 
+    /// Highlight the matches of an open find
+    pub fn set_find(&mut self, find: Option<FindState>) {
+        self.find = find;
+    }
+
     // === Link Activation ===
 
     /// Activate the currently focused element (Enter)
@@ -477,7 +647,8 @@ impl DocumentView {
             .map(|id| FocusContext::from_id(id).with_width(area.width))
             .unwrap_or_default()
             .with_width(area.width)
-            .with_unicode(config.use_unicode);
+            .with_unicode(config.use_unicode)
+            .with_compact_spacing(config.compact_spacing);
 
         let (full_buf, height) = self.document.render_full(area.width, config, &focus);
         self.full_buffer = Some(full_buf);
@@ -514,6 +685,99 @@ impl DocumentView {
             // Focus highlighting is now handled by the elements themselves
             // (Link.focused, TableWidget.focused_row)
         }
+
+        self.highlight_find_matches(area, buf, config, &focus);
+        self.render_scrollbar(area, buf, config);
+    }
+
+    /// Draw the scrollbar over the rightmost column when the document overflows
+    fn render_scrollbar(&self, area: Rect, buf: &mut Buffer, config: &DisplayConfig) {
+        let height = area.height.min(self.viewport.height());
+        let Some((top, length)) = self.viewport.scrollbar_thumb() else {
+            return;
+        };
+        if area.width == 0 {
+            return;
+        }
+
+        let (track_style, thumb_style) = if self.focused {
+            (config.muted_style(), config.text_style())
+        } else {
+            let dimmed = config.muted_style().add_modifier(Modifier::DIM);
+            (dimmed, dimmed)
+        };
+        let x = area.right() - 1;
+        for y in 0..height {
+            let (symbol, style) = if (top..top + length).contains(&y) {
+                (&config.box_chars.scrollbar_thumb, thumb_style)
+            } else {
+                (&config.box_chars.scrollbar_track, track_style)
+            };
+            buf.set_string(x, area.y + y, symbol, style);
+        }
+    }
+
+    /// Mark find matches on the visible lines copied into `area`
+    fn highlight_find_matches(
+        &self,
+        area: Rect,
+        buf: &mut Buffer,
+        config: &DisplayConfig,
+        focus: &FocusContext,
+    ) {
+        let Some(find) = self.find.as_ref().filter(|find| !find.query.is_empty()) else {
+            return;
+        };
+        let visible = self.viewport.visible_range();
+        let spans = self.document.searchable_spans(focus);
+
+        for y in matching_lines(&spans, &find.query) {
+            if !visible.contains(&y) || y - visible.start >= area.height {
+                continue;
+            }
+            let line = Rect::new(area.x, area.y + y - visible.start, area.width, 1);
+            let style = config.find_match_style(find.current_line() == Some(y));
+            highlight_line(buf, line, &find.query, style);
+        }
+    }
+
+    /// Screen regions of the focusable elements visible when rendered into `area`
+    ///
+    /// Regions are clipped to `area` and carry their index in focus order.
+    pub fn hit_regions(&self, area: Rect, config: &DisplayConfig) -> Vec<HitRegion> {
+        let focus = FocusContext::default()
+            .with_width(area.width)
+            .with_unicode(config.use_unicode)
+            .with_compact_spacing(config.compact_spacing);
+
+        let mut rects = Vec::new();
+        let mut y_offset = 0;
+        for element in self.document.build(&focus) {
+            let element_height = element.height();
+            element.collect_hit_rects(
+                &mut rects,
+                Rect::new(0, y_offset, area.width, element_height),
+                config,
+            );
+            y_offset += element_height;
+        }
+
+        // Document rows above the viewport scroll off the top of `area`
+        let scroll = self.viewport.offset();
+        rects
+            .into_iter()
+            .enumerate()
+            .filter_map(|(focus_index, rect)| {
+                let bottom = rect.bottom().checked_sub(scroll)?;
+                let top = rect.y.saturating_sub(scroll);
+                let rect = Rect::new(area.x + rect.x, area.y + top, rect.width, bottom - top)
+                    .intersection(area);
+                (!rect.is_empty()).then_some(HitRegion {
+                    rect,
+                    target: HitTarget::Focus(focus_index),
+                })
+            })
+            .collect()
     }
 
     /// Invalidate the render cache (call when document content changes)
@@ -588,6 +852,65 @@ mod tests {
         assert_eq!(view.focus_manager().len(), 3); // 3 links
     }
 
+    #[test]
+    fn test_document_view_paging_clamps_to_content() {
+        let doc = Arc::new(TestDocument::new(10, 3));
+        let mut view = DocumentView::new(doc, 5);
+        assert_eq!(view.content_height(), 15);
+
+        view.page_down();
+        view.page_down();
+        view.page_down();
+        view.page_down();
+        assert_eq!(view.viewport_offset(), 10);
+
+        view.page_up();
+        view.page_up();
+        view.page_up();
+        view.page_up();
+        assert_eq!(view.viewport_offset(), 0);
+    }
+
+    #[test]
+    fn test_focus_by_index_scrolls_below_the_fold() {
+        let doc = Arc::new(TestDocument::new(50, 3));
+        let mut view = DocumentView::new(doc, 10);
+
+        assert!(view.focus_by_index(1));
+
+        let rect = view.focus_manager().get_focused_rect().unwrap();
+        assert!(view.viewport_offset() > 0);
+        assert!(view.viewport().is_rect_visible(&rect));
+        assert!(!view.focus_by_index(3));
+    }
+
+    #[test]
+    fn test_focus_follows_scroll() {
+        // Heading (2 lines), then 30 links on lines 2..32
+        let doc = Arc::new(TestDocument::new(0, 30));
+        let mut view = DocumentView::new(doc, 10).with_focus_follows_scroll(true);
+
+        view.focus_by_index(0);
+        view.scroll_down(10);
+        // Focus left above the viewport moves to the first visible link
+        assert_eq!(view.focus_manager().current_index(), Some(8));
+
+        view.focus_by_index(25);
+        view.scroll_to_top();
+        // Focus left below the viewport moves to the last visible link
+        assert_eq!(view.focus_manager().current_index(), Some(7));
+    }
+
+    #[test]
+    fn test_scroll_keeps_focus_without_follow() {
+        let doc = Arc::new(TestDocument::new(0, 30));
+        let mut view = DocumentView::new(doc, 10);
+
+        view.focus_by_index(0);
+        view.scroll_down(10);
+        assert_eq!(view.focus_manager().current_index(), Some(0));
+    }
+
     #[test]
     fn test_focus_next_basic() {
         let doc = Arc::new(TestDocument::new(5, 3));
@@ -844,8 +1167,9 @@ mod tests {
 
         view.render(area, &mut buf, &config);
 
-        // Should show lines starting from offset 2 (after title + underline)
-        assert_buffer(&buf, &["Line 1", "Line 2", "Line 3"]);
+        // Should show lines starting from offset 2 (after title + underline),
+        // with the scrollbar thumb halfway down
+        assert_buffer(&buf, &["Line 1   │", "Line 2   █", "Line 3   │"]);
     }
 
     #[test]
@@ -865,8 +1189,8 @@ mod tests {
         view.render(area, &mut buf, &config);
 
         // Total height is 5 (title + underline + 3 lines), viewport is 2
-        // Scrolled to bottom shows last 2 lines
-        assert_buffer(&buf, &["Line 2", "Line 3"]);
+        // Scrolled to bottom shows last 2 lines, and the thumb at the bottom
+        assert_buffer(&buf, &["Line 2   │", "Line 3   █"]);
     }
 
     /// Test document with a link for focus rendering
@@ -935,4 +1259,89 @@ mod tests {
         // Focused link has "▶ " prefix
         assert_buffer(&buf, &["Before", "▶ Click Me", "After"]);
     }
+
+    #[test]
+    fn test_hit_regions_follow_scroll() {
+        // Heading (2 lines) and 5 text lines put the links at rows 7, 8 and 9
+        let doc = Arc::new(TestDocument::new(5, 3));
+        let mut view = DocumentView::new(doc, 3);
+        view.set_scroll_offset(6);
+        let area = Rect::new(4, 10, 20, 3);
+
+        let regions = view.hit_regions(area, &DisplayConfig::default());
+
+        assert_eq!(
+            regions,
+            vec![
+                HitRegion {
+                    rect: Rect::new(4, 11, 8, 1),
+                    target: HitTarget::Focus(0),
+                },
+                HitRegion {
+                    rect: Rect::new(4, 12, 8, 1),
+                    target: HitTarget::Focus(1),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_render_highlights_find_matches() {
+        use ratatui::style::Modifier;
+
+        // Lines 6-8 show "Line 4", "Link 0" and "Link 1"
+        let doc = Arc::new(TestDocument::new(5, 3));
+        let mut view = DocumentView::new(doc, 3);
+        view.set_scroll_offset(6);
+        let mut find = FindState {
+            query: "LINK".to_string(),
+            ..FindState::default()
+        };
+        find.set_matches(vec![7, 8, 9], 8);
+        view.set_find(Some(find));
+        let area = Rect::new(0, 0, 10, 3);
+        let mut buf = Buffer::empty(area);
+
+        view.render(area, &mut buf, &DisplayConfig::default());
+
+        let marked = |y: u16, modifier: Modifier| -> Vec<u16> {
+            (0..10)
+                .filter(|&x| buf[(x, y)].modifier.contains(modifier))
+                .collect()
+        };
+        assert_eq!(marked(0, Modifier::REVERSED), Vec::<u16>::new());
+        assert_eq!(marked(1, Modifier::REVERSED), vec![2, 3, 4, 5]);
+        assert_eq!(marked(1, Modifier::UNDERLINED), Vec::<u16>::new());
+        // The current match is underlined too
+        assert_eq!(marked(2, Modifier::UNDERLINED), vec![2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_scrollbar_hidden_when_document_fits() {
+        let doc = Arc::new(RenderTestDocument::new("Title", vec!["Line 1"]));
+        let mut view = DocumentView::new(doc, 4);
+        let area = Rect::new(0, 0, 10, 4);
+        let mut buf = Buffer::empty(area);
+
+        view.render(area, &mut buf, &DisplayConfig::default());
+
+        assert_buffer(&buf, &["Title", "═════", "Line 1", ""]);
+    }
+
+    #[test]
+    fn test_scrollbar_dims_when_unfocused() {
+        let lines = vec!["Line"; 18];
+        let doc = Arc::new(RenderTestDocument::new("Title", lines));
+        let mut view = DocumentView::new(doc, 5).with_focused(false);
+        view.set_scroll_offset(10);
+        let area = Rect::new(0, 0, 8, 5);
+        let mut buf = Buffer::empty(area);
+
+        view.render(area, &mut buf, &DisplayConfig::default());
+
+        // 20 lines in a 5-line viewport: a 1-line thumb, 10 of 15 lines down
+        let scrollbar: Vec<&str> = (0..5).map(|y| buf[(7, y)].symbol()).collect();
+        assert_eq!(scrollbar, vec!["│", "│", "│", "█", "│"]);
+        assert!((0..5).all(|y| buf[(7, y)].modifier.contains(Modifier::DIM)));
+    }
 }
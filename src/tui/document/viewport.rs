@@ -187,6 +187,24 @@ impl Viewport {
         self.offset >= self.content_height.saturating_sub(self.height)
     }
 
+    /// Scrollbar thumb as `(top, length)` within a track as tall as the viewport
+    ///
+    /// The thumb's length is the visible share of the content (at least one
+    /// line) and it reaches the bottom of the track at the last offset. None
+    /// when the content fits, or when the viewport is too short for a track.
+    pub fn scrollbar_thumb(&self) -> Option<(u16, u16)> {
+        let max_offset = self.content_height.checked_sub(self.height)?;
+        if max_offset == 0 || self.height < 2 {
+            return None;
+        }
+        let (height, content) = (u32::from(self.height), u32::from(self.content_height));
+        let length = ((height * height + content / 2) / content).clamp(1, height - 1);
+        let travel = height - length;
+        let max_offset = u32::from(max_offset);
+        let top = (travel * u32::from(self.offset) + max_offset / 2) / max_offset;
+        Some((top as u16, length as u16))
+    }
+
     /// Calculate smart padding based on viewport height
     ///
     /// Returns appropriate padding for autoscrolling:
@@ -488,4 +506,25 @@ mod tests {
         // element_bottom = 29, desired = 29 + 2 = 31, offset = 31 - 20 = 11
         assert_eq!(viewport.offset(), 11);
     }
+
+    #[test]
+    fn test_scrollbar_thumb() {
+        // A tenth of the content is visible, so the thumb is 2 of 20 lines
+        assert_eq!(Viewport::new(0, 20, 200).scrollbar_thumb(), Some((0, 2)));
+        assert_eq!(Viewport::new(90, 20, 200).scrollbar_thumb(), Some((9, 2)));
+        assert_eq!(Viewport::new(180, 20, 200).scrollbar_thumb(), Some((18, 2)));
+
+        // Never shorter than a line, and always leaves room to move
+        assert_eq!(Viewport::new(45, 10, 1000).scrollbar_thumb(), Some((0, 1)));
+        assert_eq!(Viewport::new(0, 10, 11).scrollbar_thumb(), Some((0, 9)));
+        assert_eq!(Viewport::new(1, 10, 11).scrollbar_thumb(), Some((1, 9)));
+    }
+
+    #[test]
+    fn test_scrollbar_thumb_hidden_when_content_fits() {
+        assert_eq!(Viewport::new(0, 20, 20).scrollbar_thumb(), None);
+        assert_eq!(Viewport::new(0, 20, 5).scrollbar_thumb(), None);
+        // No room for a track
+        assert_eq!(Viewport::new(0, 1, 5).scrollbar_thumb(), None);
+    }
 }
@@ -11,6 +11,7 @@ use ratatui::layout::Rect;
 
 use crate::config::DisplayConfig;
 use crate::tui::component::ElementWidget;
+use crate::tui::hit_test::HitRegion;
 
 use super::{Document, DocumentView};
 
@@ -59,10 +60,9 @@ impl DocumentElementWidget {
             scroll_offset,
         }
     }
-}
 
-impl ElementWidget for DocumentElementWidget {
-    fn render(&self, area: Rect, buf: &mut Buffer, config: &DisplayConfig) {
+    /// Document view with focus and scroll state applied
+    fn view(&self, area: Rect) -> DocumentView {
         let mut view = DocumentView::new(self.document.clone(), area.height);
 
         // Apply focus state
@@ -72,9 +72,18 @@ impl ElementWidget for DocumentElementWidget {
 
         // Apply scroll offset
         view.set_scroll_offset(self.scroll_offset);
+        view
+    }
+}
 
+impl ElementWidget for DocumentElementWidget {
+    fn render(&self, area: Rect, buf: &mut Buffer, config: &DisplayConfig) {
         // Render the document to the buffer
-        view.render(area, buf, config);
+        self.view(area).render(area, buf, config);
+    }
+
+    fn hit_regions(&self, area: Rect, config: &DisplayConfig) -> Vec<HitRegion> {
+        self.view(area).hit_regions(area, config)
     }
 
     fn clone_box(&self) -> Box<dyn ElementWidget> {
@@ -150,8 +159,8 @@ mod tests {
 
         widget.render(area, &mut buf, &config);
 
-        // Scrolled past title + underline, showing lines A, B, C
-        assert_buffer(&buf, &["A", "B", "C"]);
+        // Scrolled past title + underline, showing lines A, B, C beside the scrollbar
+        assert_buffer(&buf, &["A        │", "B        █", "C        █"]);
     }
 
     #[test]
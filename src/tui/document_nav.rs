@@ -4,7 +4,8 @@
 //! scrollable, focusable document-like content (e.g., StandingsTab, DemoTab).
 
 use crate::tui::component::Effect;
-use crate::tui::document::{FocusableId, LinkTarget, RowPosition};
+use crate::tui::document::{FindState, FocusableId, LinkTarget, RowPosition};
+use crate::tui::table::TableSort;
 
 /// Minimum viewport height - if smaller than this, autoscroll may behave oddly
 const MIN_VIEWPORT_HEIGHT: u16 = 5;
@@ -36,6 +37,18 @@ pub struct DocumentNavState {
     pub focusable_ids: Vec<FocusableId>,
     pub focusable_row_positions: Vec<Option<RowPosition>>,
     pub link_targets: Vec<Option<LinkTarget>>,
+    /// Active sort for documents with sortable tables (None = natural order)
+    pub sort: Option<TableSort>,
+    /// Horizontal scroll offset, in columns, for tables too wide to fit
+    pub column_offset: usize,
+    /// Show boxscore skater stats per 60 minutes of ice time instead of totals
+    pub per_60: bool,
+    /// Total document height in lines, used to clamp scrolling (0 when unknown)
+    pub content_height: u16,
+    /// Wrap focus from the last element to the first (and back) instead of stopping
+    pub focus_wrap: bool,
+    /// Open find within the document, if any
+    pub find: Option<FindState>,
 }
 
 impl DocumentNavState {
@@ -56,6 +69,8 @@ pub enum DocumentNavMsg {
     FocusPrev,
     FocusLeft,
     FocusRight,
+    /// Focus an element by its index in focus order (e.g. a clicked element)
+    FocusIndex(usize),
     ScrollUp(u16),
     ScrollDown(u16),
     ScrollToTop,
@@ -91,6 +106,12 @@ pub fn handle_message(state: &mut DocumentNavState, msg: &DocumentNavMsg) -> Eff
                 autoscroll_to_focus(state);
             }
         }
+        DocumentNavMsg::FocusIndex(index) => {
+            if *index < state.focusable_positions.len() {
+                state.focus_index = Some(*index);
+                autoscroll_to_focus(state);
+            }
+        }
         DocumentNavMsg::ScrollUp(lines) => {
             scroll_up(state, *lines);
         }
@@ -120,7 +141,7 @@ pub fn handle_message(state: &mut DocumentNavState, msg: &DocumentNavMsg) -> Eff
 // Focus Navigation
 // ============================================================================
 
-/// Move focus to next element, wrapping from last to first
+/// Move focus to next element, wrapping from last to first when `focus_wrap` is set
 /// Returns true if wrapped around
 pub fn focus_next(state: &mut DocumentNavState) -> bool {
     let focusable_count = state.focusable_positions.len();
@@ -134,6 +155,9 @@ pub fn focus_next(state: &mut DocumentNavState) -> bool {
             false // didn't wrap
         }
         Some(idx) if idx + 1 >= focusable_count => {
+            if !state.focus_wrap {
+                return false;
+            }
             state.focus_index = Some(0);
             state.scroll_offset = 0;
             true // wrapped
@@ -145,7 +169,7 @@ pub fn focus_next(state: &mut DocumentNavState) -> bool {
     }
 }
 
-/// Move focus to previous element, wrapping from first to last
+/// Move focus to previous element, wrapping from first to last when `focus_wrap` is set
 /// Returns true if wrapped around
 pub fn focus_prev(state: &mut DocumentNavState) -> bool {
     let focusable_count = state.focusable_positions.len();
@@ -160,6 +184,9 @@ pub fn focus_prev(state: &mut DocumentNavState) -> bool {
             true // wrapped
         }
         Some(0) => {
+            if !state.focus_wrap {
+                return false;
+            }
             state.focus_index = Some(focusable_count - 1);
             state.scroll_offset = u16::MAX;
             true // wrapped
@@ -250,14 +277,30 @@ pub fn find_row_sibling(state: &DocumentNavState, direction: RowDirection) -> Op
 // Scrolling
 // ============================================================================
 
+/// Largest scroll offset that still fills the viewport
+///
+/// Unbounded until the content height is known; rendering clamps then.
+fn max_scroll_offset(state: &DocumentNavState) -> u16 {
+    if state.content_height == 0 {
+        return u16::MAX;
+    }
+    state.content_height.saturating_sub(state.viewport_height)
+}
+
 /// Scroll up by N lines
 pub fn scroll_up(state: &mut DocumentNavState, lines: u16) {
-    state.scroll_offset = state.scroll_offset.saturating_sub(lines);
+    state.scroll_offset = state
+        .scroll_offset
+        .min(max_scroll_offset(state))
+        .saturating_sub(lines);
 }
 
 /// Scroll down by N lines
 pub fn scroll_down(state: &mut DocumentNavState, lines: u16) {
-    state.scroll_offset = state.scroll_offset.saturating_add(lines);
+    state.scroll_offset = state
+        .scroll_offset
+        .saturating_add(lines)
+        .min(max_scroll_offset(state));
 }
 
 /// Scroll to top
@@ -267,19 +310,32 @@ pub fn scroll_to_top(state: &mut DocumentNavState) {
 
 /// Scroll to bottom
 pub fn scroll_to_bottom(state: &mut DocumentNavState) {
-    state.scroll_offset = u16::MAX;
+    state.scroll_offset = max_scroll_offset(state);
 }
 
 /// Page up (scroll by viewport height)
 pub fn page_up(state: &mut DocumentNavState) {
     let page_size = state.viewport_height.max(MIN_PAGE_SIZE);
-    state.scroll_offset = state.scroll_offset.saturating_sub(page_size);
+    scroll_up(state, page_size);
 }
 
 /// Page down (scroll by viewport height)
 pub fn page_down(state: &mut DocumentNavState) {
     let page_size = state.viewport_height.max(MIN_PAGE_SIZE);
-    state.scroll_offset = state.scroll_offset.saturating_add(page_size);
+    scroll_down(state, page_size);
+}
+
+/// Scroll so `line` is visible, leaving the autoscroll padding above it
+///
+/// Does nothing when the line is already on screen.
+pub fn scroll_to_line(state: &mut DocumentNavState, line: u16) {
+    let viewport_height = state.viewport_height.max(MIN_VIEWPORT_HEIGHT);
+    if line >= state.scroll_offset && line < state.scroll_offset.saturating_add(viewport_height) {
+        return;
+    }
+    state.scroll_offset = line
+        .saturating_sub(AUTOSCROLL_PADDING)
+        .min(max_scroll_offset(state));
 }
 
 /// Autoscroll to keep focused element visible
@@ -342,6 +398,7 @@ mod tests {
             focusable_positions: vec![0, 5, 10],
             focusable_heights: vec![1, 1, 1],
             focusable_row_positions: vec![None, None, None],
+            focus_wrap: true,
             ..Default::default()
         };
 
@@ -351,6 +408,42 @@ mod tests {
         assert_eq!(state.scroll_offset, 0);
     }
 
+    #[test]
+    fn test_focus_next_clamps_at_last() {
+        let mut state = DocumentNavState {
+            focus_index: Some(2),
+            scroll_offset: 4,
+            viewport_height: 20,
+            focusable_positions: vec![0, 5, 10],
+            focusable_heights: vec![1, 1, 1],
+            focusable_row_positions: vec![None, None, None],
+            ..Default::default()
+        };
+
+        let wrapped = focus_next(&mut state);
+        assert!(!wrapped);
+        assert_eq!(state.focus_index, Some(2));
+        assert_eq!(state.scroll_offset, 4);
+    }
+
+    #[test]
+    fn test_focus_prev_clamps_at_first() {
+        let mut state = DocumentNavState {
+            focus_index: Some(0),
+            scroll_offset: 5,
+            viewport_height: 20,
+            focusable_positions: vec![0, 5, 10],
+            focusable_heights: vec![1, 1, 1],
+            focusable_row_positions: vec![None, None, None],
+            ..Default::default()
+        };
+
+        let wrapped = focus_prev(&mut state);
+        assert!(!wrapped);
+        assert_eq!(state.focus_index, Some(0));
+        assert_eq!(state.scroll_offset, 5);
+    }
+
     #[test]
     fn test_focus_next_advances() {
         let mut state = DocumentNavState {
@@ -377,6 +470,7 @@ mod tests {
             focusable_positions: vec![0, 5, 10],
             focusable_heights: vec![1, 1, 1],
             focusable_row_positions: vec![None, None, None],
+            focus_wrap: true,
             ..Default::default()
         };
 
@@ -455,6 +549,30 @@ mod tests {
         assert_eq!(state.scroll_offset, u16::MAX);
     }
 
+    #[test]
+    fn test_scroll_to_line() {
+        let mut state = DocumentNavState {
+            scroll_offset: 10,
+            viewport_height: 20,
+            content_height: 60,
+            ..Default::default()
+        };
+
+        // Already visible
+        scroll_to_line(&mut state, 25);
+        assert_eq!(state.scroll_offset, 10);
+
+        scroll_to_line(&mut state, 35);
+        assert_eq!(state.scroll_offset, 32);
+
+        scroll_to_line(&mut state, 4);
+        assert_eq!(state.scroll_offset, 1);
+
+        // Near the end, stops where the viewport is full
+        scroll_to_line(&mut state, 58);
+        assert_eq!(state.scroll_offset, 40);
+    }
+
     #[test]
     fn test_page_up() {
         let mut state = DocumentNavState {
@@ -479,6 +597,64 @@ mod tests {
         assert_eq!(state.scroll_offset, 50);
     }
 
+    #[test]
+    fn test_paging_clamps_to_content() {
+        let mut state = DocumentNavState {
+            scroll_offset: 0,
+            viewport_height: 20,
+            content_height: 50,
+            ..Default::default()
+        };
+
+        page_down(&mut state);
+        assert_eq!(state.scroll_offset, 20);
+        page_down(&mut state);
+        assert_eq!(state.scroll_offset, 30);
+        page_down(&mut state);
+        assert_eq!(state.scroll_offset, 30);
+
+        page_up(&mut state);
+        assert_eq!(state.scroll_offset, 10);
+        page_up(&mut state);
+        assert_eq!(state.scroll_offset, 0);
+    }
+
+    #[test]
+    fn test_home_end_clamp_to_content() {
+        let mut state = DocumentNavState {
+            scroll_offset: 5,
+            viewport_height: 20,
+            content_height: 50,
+            ..Default::default()
+        };
+
+        scroll_to_bottom(&mut state);
+        assert_eq!(state.scroll_offset, 30);
+        scroll_to_top(&mut state);
+        assert_eq!(state.scroll_offset, 0);
+
+        // A document shorter than the viewport never scrolls
+        state.content_height = 12;
+        scroll_to_bottom(&mut state);
+        assert_eq!(state.scroll_offset, 0);
+        page_down(&mut state);
+        assert_eq!(state.scroll_offset, 0);
+    }
+
+    #[test]
+    fn test_page_up_from_stale_offset() {
+        // An offset from before the content shrank pages up from the real bottom
+        let mut state = DocumentNavState {
+            scroll_offset: u16::MAX,
+            viewport_height: 20,
+            content_height: 50,
+            ..Default::default()
+        };
+
+        page_up(&mut state);
+        assert_eq!(state.scroll_offset, 10);
+    }
+
     #[test]
     fn test_autoscroll_to_focus_scrolls_down() {
         let mut state = DocumentNavState {
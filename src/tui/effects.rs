@@ -1,12 +1,18 @@
+use std::future::Future;
 use std::sync::Arc;
 
-use nhl_api::GameDate;
+use nhl_api::{GameDate, NHLApiError};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use tracing::warn;
 
 use super::action::Action;
 use super::component::Effect;
-use super::state::AppState;
 use crate::cache;
 use crate::data_provider::NHLDataProvider;
+use crate::disk_cache::{
+    boxscore_key, schedule_key, season_standings_key, DiskCache, STANDINGS_KEY,
+};
 
 /// Regular season game type identifier
 const REGULAR_SEASON: nhl_api::GameType = nhl_api::GameType::RegularSeason;
@@ -18,34 +24,74 @@ const REGULAR_SEASON: nhl_api::GameType = nhl_api::GameType::RegularSeason;
 /// *Loaded action when complete.
 pub struct DataEffects {
     client: Arc<dyn NHLDataProvider>,
+    disk_cache: Option<DiskCache>,
 }
 
 impl DataEffects {
     /// Create a new DataEffects handler with an NHL data provider
     pub fn new(client: Arc<dyn NHLDataProvider>) -> Self {
-        Self { client }
+        Self {
+            client,
+            disk_cache: None,
+        }
+    }
+
+    /// Serve standings, schedules and boxscores from a disk cache when possible
+    pub fn with_disk_cache(mut self, disk_cache: DiskCache) -> Self {
+        self.disk_cache = Some(disk_cache);
+        self
     }
 
-    /// Handle a refresh request - fetches all necessary data based on current state
-    pub fn handle_refresh(&self, state: &AppState) -> Effect {
-        let mut effects = vec![
-            self.fetch_standings(),
-            self.fetch_schedule(state.ui.scores.game_date.clone()),
-        ];
-
-        // Add game detail fetches for started games
-        if let Some(schedule) = state.data.schedule.as_ref().as_ref() {
-            for game in &schedule.games {
-                // Only fetch details for games that have started
-                if game.game_state != nhl_api::GameState::Future
-                    && game.game_state != nhl_api::GameState::PreGame
-                {
-                    effects.push(self.fetch_game_details(game.id));
+    /// Wrap a fetch with the disk cache, if one is set and the request has a key
+    ///
+    /// A fresh entry is dispatched without fetching. A stale one is dispatched
+    /// right away as [`Action::CachedDataLoaded`], so the request stays loading,
+    /// and followed by the fetched copy, which replaces it on disk. A refetch
+    /// skips the read and only writes the fetched copy.
+    fn fetch_through_disk_cache<T, Fut, F>(
+        &self,
+        key: Option<String>,
+        refetch: bool,
+        fetch: Fut,
+        loaded: F,
+    ) -> Effect
+    where
+        T: Serialize + DeserializeOwned + Send + 'static,
+        Fut: Future<Output = Result<T, NHLApiError>> + Send + 'static,
+        F: Fn(Result<T, String>) -> Action + Send + 'static,
+    {
+        let (Some(disk_cache), Some(key)) = (self.disk_cache.clone(), key) else {
+            return Effect::Async(Box::pin(async move {
+                let result = fetch.await;
+                loaded(result.map_err(|e| e.to_string()))
+            }));
+        };
+
+        let cached = if refetch {
+            None
+        } else {
+            disk_cache
+                .get::<T>(&key)
+                .map(|entry| (entry.fresh, loaded(Ok(entry.data))))
+        };
+        let network = Effect::Async(Box::pin(async move {
+            let result = fetch.await;
+            if let Ok(data) = &result {
+                if let Err(e) = disk_cache.put(&key, data) {
+                    warn!("Failed to write {} to disk cache: {}", key, e);
                 }
             }
+            loaded(result.map_err(|e| e.to_string()))
+        }));
+
+        match cached {
+            Some((true, action)) => Effect::Action(action),
+            Some((false, action)) => Effect::Batch(vec![
+                Effect::Action(Action::CachedDataLoaded(Box::new(action))),
+                network,
+            ]),
+            None => network,
         }
-
-        Effect::Batch(effects)
     }
 
     /// Handle a schedule refresh for a specific date
@@ -53,22 +99,63 @@ impl DataEffects {
         self.fetch_schedule(date)
     }
 
-    /// Fetch current league standings (with caching)
-    pub fn fetch_standings(&self) -> Effect {
+    /// Fetch league standings for a season, or the current ones (with caching)
+    pub fn fetch_standings(&self, season: Option<i64>) -> Effect {
+        self.standings(season, false)
+    }
+
+    /// Fetch league standings past the in-memory and disk caches, then cache them
+    pub fn refetch_standings(&self, season: Option<i64>) -> Effect {
+        self.standings(season, true)
+    }
+
+    fn standings(&self, season: Option<i64>, refetch: bool) -> Effect {
         let client = self.client.clone();
-        Effect::Async(Box::pin(async move {
-            let result = cache::fetch_standings_cached(client.as_ref()).await;
-            Action::StandingsLoaded(result.map_err(|e| e.to_string()))
-        }))
+        let key = season.map_or_else(|| STANDINGS_KEY.to_string(), season_standings_key);
+        self.fetch_through_disk_cache(
+            Some(key),
+            refetch,
+            async move {
+                let client = client.as_ref();
+                match (season, refetch) {
+                    (Some(season), false) => {
+                        cache::fetch_season_standings_cached(client, season).await
+                    }
+                    (Some(season), true) => {
+                        cache::fetch_season_standings_cached_prime_cache(client, season).await
+                    }
+                    (None, false) => cache::fetch_standings_cached(client).await,
+                    (None, true) => cache::fetch_standings_cached_prime_cache(client).await,
+                }
+            },
+            move |result| Action::StandingsLoaded(season, result),
+        )
     }
 
     /// Fetch daily schedule for a specific date (with caching)
     pub fn fetch_schedule(&self, date: GameDate) -> Effect {
+        self.schedule(date, false)
+    }
+
+    /// Fetch the schedule for a date past the in-memory and disk caches, then cache it
+    pub fn refetch_schedule(&self, date: GameDate) -> Effect {
+        self.schedule(date, true)
+    }
+
+    fn schedule(&self, date: GameDate, refetch: bool) -> Effect {
         let client = self.client.clone();
-        Effect::Async(Box::pin(async move {
-            let result = cache::fetch_schedule_cached(client.as_ref(), date).await;
-            Action::ScheduleLoaded(result.map_err(|e| e.to_string()))
-        }))
+        self.fetch_through_disk_cache(
+            schedule_key(&date),
+            refetch,
+            async move {
+                if refetch {
+                    cache::fetch_schedule_cached_prime_cache(client.as_ref(), date).await
+                } else {
+                    cache::fetch_schedule_cached(client.as_ref(), date).await
+                }
+            },
+            Action::ScheduleLoaded,
+        )
     }
 
     /// Fetch game details for a specific game (with caching)
@@ -122,6 +209,29 @@ impl DataEffects {
         }))
     }
 
+    /// Fetch a team's current roster (with caching)
+    pub fn fetch_team_roster(&self, team_abbrev: String) -> Effect {
+        let client = self.client.clone();
+        Effect::Async(Box::pin(async move {
+            let result = cache::fetch_roster_cached(client.as_ref(), &team_abbrev).await;
+            Action::TeamRosterLoaded(team_abbrev, result.map_err(|e| e.to_string()))
+        }))
+    }
+
+    /// Fetch a team's schedule for the current week (with caching)
+    pub fn fetch_team_schedule(&self, team_abbrev: String) -> Effect {
+        let client = self.client.clone();
+        Effect::Async(Box::pin(async move {
+            let result = cache::fetch_team_schedule_cached(client.as_ref(), &team_abbrev).await;
+            Action::TeamScheduleLoaded(
+                team_abbrev,
+                result
+                    .map(|schedule| schedule.games)
+                    .map_err(|e| e.to_string()),
+            )
+        }))
+    }
+
     /// Fetch player landing data (career stats, season stats, etc.)
     pub fn fetch_player_stats(&self, player_id: i64) -> Effect {
         let client = self.client.clone();
@@ -131,95 +241,146 @@ impl DataEffects {
         }))
     }
 
-    /// Fetch boxscore for a specific game (with caching)
-    pub fn fetch_boxscore(&self, game_id: i64) -> Effect {
+    /// Fetch a player's regular season game log (with caching)
+    pub fn fetch_player_game_log(&self, player_id: i64, season: i32) -> Effect {
         let client = self.client.clone();
         Effect::Async(Box::pin(async move {
-            let result = cache::fetch_boxscore_cached(client.as_ref(), game_id).await;
-            Action::BoxscoreLoaded(game_id, result.map_err(|e| e.to_string()))
+            let result = cache::fetch_player_game_log_cached(client.as_ref(), player_id, season)
+                .await
+                .map(|log| log.game_log);
+            Action::PlayerGameLogLoaded(player_id, result.map_err(|e| e.to_string()))
         }))
     }
+
+    /// Fetch boxscore for a specific game (with caching)
+    pub fn fetch_boxscore(&self, game_id: i64) -> Effect {
+        self.boxscore(game_id, false)
+    }
+
+    /// Fetch a boxscore past the in-memory and disk caches, then cache it
+    pub fn refetch_boxscore(&self, game_id: i64) -> Effect {
+        self.boxscore(game_id, true)
+    }
+
+    fn boxscore(&self, game_id: i64, refetch: bool) -> Effect {
+        let client = self.client.clone();
+        self.fetch_through_disk_cache(
+            Some(boxscore_key(game_id)),
+            refetch,
+            async move {
+                if refetch {
+                    cache::fetch_boxscore_cached_prime_cache(client.as_ref(), game_id).await
+                } else {
+                    cache::fetch_boxscore_cached(client.as_ref(), game_id).await
+                }
+            },
+            move |result| Action::BoxscoreLoaded(game_id, result),
+        )
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::tui::state::{NavigationState, SystemState, UiState};
-    use crate::tui::types::Tab;
-
-    fn create_test_state() -> AppState {
-        AppState {
-            navigation: NavigationState {
-                current_tab: Tab::Scores,
-                document_stack: Vec::new(),
-                content_focused: false,
-            },
-            data: Default::default(),
-            ui: UiState::default(),
-            system: SystemState::default(),
-        }
-    }
 
     #[test]
     fn test_fetch_standings_returns_async_effect() {
         let client = crate::tui::testing::create_client();
         let effects = DataEffects::new(client);
 
-        let effect = effects.fetch_standings();
+        let effect = effects.fetch_standings(None);
 
         // Verify it returns an Async effect
         assert!(matches!(effect, Effect::Async(_)));
     }
 
     #[test]
-    fn test_fetch_schedule_returns_async_effect() {
+    fn test_fetch_standings_uses_disk_cache() {
+        let dir = std::env::temp_dir().join(format!(
+            "nhl_effects_disk_cache_test_{}",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_dir_all(&dir);
+        let fresh = DiskCache::new(dir.clone(), std::time::Duration::from_secs(60));
+        fresh
+            .put(STANDINGS_KEY, &Vec::<nhl_api::Standing>::new())
+            .unwrap();
         let client = crate::tui::testing::create_client();
-        let effects = DataEffects::new(client);
 
-        let date = GameDate::default();
-        let effect = effects.fetch_schedule(date);
-
-        // Verify it returns an Async effect
-        assert!(matches!(effect, Effect::Async(_)));
+        // Fresh: dispatched without fetching
+        let effect = DataEffects::new(client.clone())
+            .with_disk_cache(fresh)
+            .fetch_standings(None);
+        assert!(matches!(
+            effect,
+            Effect::Action(Action::StandingsLoaded(None, Ok(ref standings))) if standings.is_empty()
+        ));
+
+        // Stale: dispatched, then fetched again
+        let stale = DiskCache::new(dir.clone(), std::time::Duration::ZERO);
+        let effect = DataEffects::new(client)
+            .with_disk_cache(stale)
+            .fetch_standings(None);
+        let Effect::Batch(effects) = effect else {
+            panic!("Expected Batch effect");
+        };
+        assert!(matches!(
+            effects.as_slice(),
+            [
+                Effect::Action(Action::CachedDataLoaded(_)),
+                Effect::Async(_)
+            ]
+        ));
+        let Effect::Action(Action::CachedDataLoaded(cached)) = &effects[0] else {
+            unreachable!();
+        };
+        assert!(matches!(
+            cached.as_ref(),
+            Action::StandingsLoaded(None, Ok(_))
+        ));
+        let _ = std::fs::remove_dir_all(&dir);
     }
 
     #[test]
-    fn test_fetch_game_details_returns_async_effect() {
+    fn test_refetch_standings_skips_disk_cache() {
+        let dir =
+            std::env::temp_dir().join(format!("nhl_effects_refetch_test_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let fresh = DiskCache::new(dir.clone(), std::time::Duration::from_secs(60));
+        fresh
+            .put(STANDINGS_KEY, &Vec::<nhl_api::Standing>::new())
+            .unwrap();
         let client = crate::tui::testing::create_client();
-        let effects = DataEffects::new(client);
 
-        let effect = effects.fetch_game_details(2024020001);
+        let effect = DataEffects::new(client)
+            .with_disk_cache(fresh)
+            .refetch_standings(None);
 
-        // Verify it returns an Async effect
         assert!(matches!(effect, Effect::Async(_)));
+        let _ = std::fs::remove_dir_all(&dir);
     }
 
     #[test]
-    fn test_handle_refresh_returns_batch_effect() {
+    fn test_fetch_schedule_returns_async_effect() {
         let client = crate::tui::testing::create_client();
         let effects = DataEffects::new(client);
-        let state = create_test_state();
 
-        let effect = effects.handle_refresh(&state);
+        let date = GameDate::default();
+        let effect = effects.fetch_schedule(date);
 
-        // Verify it returns a Batch effect
-        assert!(matches!(effect, Effect::Batch(_)));
+        // Verify it returns an Async effect
+        assert!(matches!(effect, Effect::Async(_)));
     }
 
     #[test]
-    fn test_handle_refresh_includes_standings_and_schedule() {
+    fn test_fetch_game_details_returns_async_effect() {
         let client = crate::tui::testing::create_client();
         let effects = DataEffects::new(client);
-        let state = create_test_state();
 
-        let effect = effects.handle_refresh(&state);
+        let effect = effects.fetch_game_details(2024020001);
 
-        // Verify batch contains at least 2 effects (standings + schedule)
-        if let Effect::Batch(effects) = effect {
-            assert!(effects.len() >= 2);
-        } else {
-            panic!("Expected Batch effect");
-        }
+        // Verify it returns an Async effect
+        assert!(matches!(effect, Effect::Async(_)));
     }
 
     #[tokio::test]
@@ -234,7 +395,7 @@ mod tests {
         let effects = DataEffects::new(client);
 
         // First fetch - should hit the API and cache
-        let effect1 = effects.fetch_standings();
+        let effect1 = effects.fetch_standings(None);
         if let Effect::Async(future) = effect1 {
             let _action = future.await;
         }
@@ -244,7 +405,7 @@ mod tests {
         assert_eq!(stats.standings_entries, 1);
 
         // Second fetch - should hit the cache
-        let effect2 = effects.fetch_standings();
+        let effect2 = effects.fetch_standings(None);
         if let Effect::Async(future) = effect2 {
             let _action = future.await;
         }
@@ -0,0 +1,92 @@
+//! Screen regions of focusable elements, for resolving mouse clicks
+//!
+//! Widgets that render documents report where each focusable element was
+//! drawn, and the breadcrumb where each ancestor crumb was drawn. The renderer
+//! collects these into a `HitMap` each frame so the event loop can turn a
+//! click position back into a focus index or a stack depth.
+use ratatui::layout::Rect;
+
+/// What a click on a region selects
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HitTarget {
+    /// Index of the element in the document's focus order
+    Focus(usize),
+    /// Document stack depth a breadcrumb crumb returns to (0 is the tab itself)
+    Crumb(usize),
+}
+
+/// Where a clickable element was drawn on screen
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HitRegion {
+    pub rect: Rect,
+    pub target: HitTarget,
+}
+
+/// Focusable regions of the current frame
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HitMap {
+    regions: Vec<HitRegion>,
+}
+
+impl HitMap {
+    pub fn new(regions: Vec<HitRegion>) -> Self {
+        Self { regions }
+    }
+
+    /// Target of the element drawn at a screen position, if any
+    pub fn target_at(&self, column: u16, row: u16) -> Option<HitTarget> {
+        self.regions
+            .iter()
+            .find(|region| {
+                let rect = region.rect;
+                column >= rect.x && column < rect.right() && row >= rect.y && row < rect.bottom()
+            })
+            .map(|region| region.target)
+    }
+
+    /// Focus index of the element drawn at a screen position, if any
+    pub fn focus_at(&self, column: u16, row: u16) -> Option<usize> {
+        match self.target_at(column, row)? {
+            HitTarget::Focus(index) => Some(index),
+            HitTarget::Crumb(_) => None,
+        }
+    }
+
+    pub fn regions(&self) -> &[HitRegion] {
+        &self.regions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_focus_at() {
+        let map = HitMap::new(vec![
+            HitRegion {
+                rect: Rect::new(2, 1, 5, 1),
+                target: HitTarget::Focus(0),
+            },
+            HitRegion {
+                rect: Rect::new(10, 1, 20, 6),
+                target: HitTarget::Focus(1),
+            },
+            HitRegion {
+                rect: Rect::new(0, 0, 9, 1),
+                target: HitTarget::Crumb(0),
+            },
+        ]);
+
+        assert_eq!(map.focus_at(2, 1), Some(0));
+        assert_eq!(map.focus_at(6, 1), Some(0));
+        assert_eq!(map.focus_at(7, 1), None);
+        assert_eq!(map.focus_at(29, 6), Some(1));
+        assert_eq!(map.focus_at(10, 7), None);
+        assert_eq!(HitMap::default().focus_at(0, 0), None);
+
+        // Crumbs are targets but not focusable elements
+        assert_eq!(map.target_at(3, 0), Some(HitTarget::Crumb(0)));
+        assert_eq!(map.focus_at(3, 0), None);
+    }
+}
@@ -0,0 +1,337 @@
+//! Documented key bindings, grouped by where they apply
+//!
+//! The help overlay renders these groups, and the tests check every listed
+//! key against `key_to_action` so the two cannot drift apart.
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use crate::keybindings::KeyMap;
+
+/// One or more keys sharing a description
+pub struct KeyBinding {
+    pub codes: &'static [KeyCode],
+    pub description: &'static str,
+}
+
+impl KeyBinding {
+    /// Keys as shown to the user, e.g. "← →", followed by any configured keys
+    /// that stand in for them
+    pub fn label(&self, keymap: &KeyMap) -> String {
+        self.codes
+            .iter()
+            .map(|code| key_label(*code))
+            .chain(
+                self.codes
+                    .iter()
+                    .flat_map(|code| keymap.keys_for(*code))
+                    .map(key_event_label),
+            )
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+/// Bindings active in one context
+pub struct KeyGroup {
+    pub context: &'static str,
+    pub bindings: &'static [KeyBinding],
+}
+
+pub const KEY_GROUPS: &[KeyGroup] = &[
+    KeyGroup {
+        context: "Global",
+        bindings: &[
+            KeyBinding {
+                codes: &[KeyCode::Char('q'), KeyCode::Char('Q')],
+                description: "Quit",
+            },
+            KeyBinding {
+                codes: &[KeyCode::Char('?')],
+                description: "Show this help",
+            },
+            KeyBinding {
+                codes: &[KeyCode::Char('/'), KeyCode::Char(':')],
+                description: "Search teams and players",
+            },
+            KeyBinding {
+                codes: &[KeyCode::Char('1'), KeyCode::Char('2'), KeyCode::Char('3')],
+                description: "Jump to tab",
+            },
+            KeyBinding {
+                codes: &[KeyCode::Left, KeyCode::Right],
+                description: "Switch tab",
+            },
+            KeyBinding {
+                codes: &[KeyCode::Down],
+                description: "Enter tab content",
+            },
+            KeyBinding {
+                codes: &[KeyCode::Char('r')],
+                description: "Refresh data",
+            },
+            KeyBinding {
+                codes: &[KeyCode::Char('p')],
+                description: "Pin or unpin data",
+            },
+            KeyBinding {
+                codes: &[KeyCode::Char('E')],
+                description: "Export document",
+            },
+            KeyBinding {
+                codes: &[KeyCode::Char('y')],
+                description: "Copy focused link",
+            },
+            KeyBinding {
+                codes: &[KeyCode::Char('Y')],
+                description: "Copy game summary",
+            },
+        ],
+    },
+    KeyGroup {
+        context: "Scores",
+        bindings: &[
+            KeyBinding {
+                codes: &[KeyCode::Left, KeyCode::Right],
+                description: "Previous or next day",
+            },
+            KeyBinding {
+                codes: &[KeyCode::Char('['), KeyCode::Char(']')],
+                description: "Previous or next day",
+            },
+            KeyBinding {
+                codes: &[KeyCode::Char('.')],
+                description: "Today",
+            },
+            KeyBinding {
+                codes: &[KeyCode::Char('g')],
+                description: "Jump to date",
+            },
+            KeyBinding {
+                codes: &[KeyCode::Char('f')],
+                description: "Favorite teams only",
+            },
+            KeyBinding {
+                codes: &[KeyCode::Char('s')],
+                description: "Filter by game state",
+            },
+            KeyBinding {
+                codes: &[KeyCode::Char('t')],
+                description: "Filter by game type",
+            },
+            KeyBinding {
+                codes: &[KeyCode::Down],
+                description: "Select games",
+            },
+            KeyBinding {
+                codes: &[KeyCode::Enter],
+                description: "Open boxscore",
+            },
+        ],
+    },
+    KeyGroup {
+        context: "Standings",
+        bindings: &[
+            KeyBinding {
+                codes: &[KeyCode::Left, KeyCode::Right],
+                description: "Change view",
+            },
+            KeyBinding {
+                codes: &[KeyCode::Char('['), KeyCode::Char(']')],
+                description: "Previous or next season",
+            },
+            KeyBinding {
+                codes: &[KeyCode::Down],
+                description: "Select teams",
+            },
+            KeyBinding {
+                codes: &[KeyCode::Enter],
+                description: "Open team",
+            },
+            KeyBinding {
+                codes: &[KeyCode::PageUp, KeyCode::PageDown],
+                description: "Scroll a page",
+            },
+        ],
+    },
+    KeyGroup {
+        context: "Document",
+        bindings: &[
+            KeyBinding {
+                codes: &[KeyCode::Up, KeyCode::Down],
+                description: "Move between links",
+            },
+            KeyBinding {
+                codes: &[KeyCode::Left, KeyCode::Right],
+                description: "Move across or scroll",
+            },
+            KeyBinding {
+                codes: &[KeyCode::Enter],
+                description: "Open link",
+            },
+            KeyBinding {
+                codes: &[KeyCode::PageUp, KeyCode::PageDown],
+                description: "Scroll a page",
+            },
+            KeyBinding {
+                codes: &[KeyCode::Home, KeyCode::End],
+                description: "Top or bottom",
+            },
+            KeyBinding {
+                codes: &[KeyCode::Char('s')],
+                description: "Cycle table sort",
+            },
+            KeyBinding {
+                codes: &[KeyCode::Char('m')],
+                description: "Totals or per-60 stats",
+            },
+            KeyBinding {
+                codes: &[KeyCode::Char('c')],
+                description: "Compare players",
+            },
+            KeyBinding {
+                codes: &[KeyCode::Char('/')],
+                description: "Find in document",
+            },
+            KeyBinding {
+                codes: &[KeyCode::Char('n'), KeyCode::Char('N')],
+                description: "Next or previous match",
+            },
+            KeyBinding {
+                codes: &[KeyCode::Char('L')],
+                description: "Column legend",
+            },
+            KeyBinding {
+                codes: &[KeyCode::Esc],
+                description: "Back",
+            },
+        ],
+    },
+];
+
+/// A configured key with its modifiers, e.g. "Ctrl+r"
+fn key_event_label(key: KeyEvent) -> String {
+    let mut label = String::new();
+    for (modifier, name) in [
+        (KeyModifiers::CONTROL, "Ctrl+"),
+        (KeyModifiers::ALT, "Alt+"),
+        (KeyModifiers::SHIFT, "Shift+"),
+    ] {
+        if key.modifiers.contains(modifier) {
+            label.push_str(name);
+        }
+    }
+    label.push_str(&key_label(key.code));
+    label
+}
+
+fn key_label(code: KeyCode) -> String {
+    match code {
+        KeyCode::Char(c) => c.to_string(),
+        KeyCode::Up => "↑".to_string(),
+        KeyCode::Down => "↓".to_string(),
+        KeyCode::Left => "←".to_string(),
+        KeyCode::Right => "→".to_string(),
+        KeyCode::PageUp => "PgUp".to_string(),
+        KeyCode::PageDown => "PgDn".to_string(),
+        other => other.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::create_mock_schedule;
+    use crate::tui::component_store::ComponentStateStore;
+    use crate::tui::components::scores_tab::ScoresTabState;
+    use crate::tui::components::standings_tab::StandingsTabState;
+    use crate::tui::constants::{SCORES_TAB_PATH, STANDINGS_TAB_PATH};
+    use crate::tui::key_to_action;
+    use crate::tui::state::{AppState, DocumentStackEntry};
+    use crate::tui::types::{StackedDocument, Tab};
+    use crossterm::event::KeyEvent;
+    use std::sync::Arc;
+
+    /// State and component states in which a group's bindings apply
+    fn context_state(context: &str) -> (AppState, ComponentStateStore) {
+        let mut state = AppState::default();
+        let mut component_states = ComponentStateStore::new();
+        match context {
+            "Global" => {}
+            "Scores" => {
+                state.navigation.current_tab = Tab::Scores;
+                state.navigation.content_focused = true;
+                state.data.schedule = Arc::new(Some(create_mock_schedule(None)));
+                component_states.insert(SCORES_TAB_PATH.to_string(), ScoresTabState::default());
+            }
+            "Standings" => {
+                state.navigation.current_tab = Tab::Standings;
+                state.navigation.content_focused = true;
+                let mut standings = StandingsTabState::default();
+                standings.doc_nav.focus_index = Some(0);
+                component_states.insert(STANDINGS_TAB_PATH.to_string(), standings);
+            }
+            "Document" => {
+                state.navigation.content_focused = true;
+                state
+                    .navigation
+                    .document_stack
+                    .push(DocumentStackEntry::new(StackedDocument::TeamDetail {
+                        abbrev: "TOR".to_string(),
+                    }));
+            }
+            other => panic!("No test state for context {}", other),
+        }
+        (state, component_states)
+    }
+
+    #[test]
+    fn test_every_listed_key_is_handled() {
+        for group in KEY_GROUPS {
+            let (state, component_states) = context_state(group.context);
+            for binding in group.bindings {
+                for code in binding.codes {
+                    assert!(
+                        key_to_action(KeyEvent::from(*code), &state, &component_states).is_some(),
+                        "{} key {:?} ({}) is not handled",
+                        group.context,
+                        code,
+                        binding.description
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_binding_label() {
+        let binding = KeyBinding {
+            codes: &[
+                KeyCode::Left,
+                KeyCode::Right,
+                KeyCode::Enter,
+                KeyCode::Char('q'),
+            ],
+            description: "",
+        };
+        assert_eq!(binding.label(&KeyMap::default()), "← → Enter q");
+    }
+
+    #[test]
+    fn test_binding_label_lists_configured_keys() {
+        let entries = [("nav_down", "j"), ("go_back", "ctrl+h")]
+            .iter()
+            .map(|(name, spec)| (name.to_string(), spec.to_string()))
+            .collect();
+        let (keymap, _) = KeyMap::from_config(&entries);
+
+        let down = KeyBinding {
+            codes: &[KeyCode::Down],
+            description: "",
+        };
+        let back = KeyBinding {
+            codes: &[KeyCode::Esc],
+            description: "",
+        };
+        assert_eq!(down.label(&keymap), "↓ j");
+        assert_eq!(back.label(&keymap), "Esc Ctrl+h");
+    }
+}
@@ -7,7 +7,7 @@ use tracing::{debug, trace};
 
 use crossterm::event::KeyModifiers;
 
-use super::action::{Action, SettingsAction};
+use super::action::{Action, CommandPaletteAction, FindAction, SettingsAction};
 use super::component_store::ComponentStateStore;
 use super::components::scores_tab::ScoresTabMsg;
 use super::components::scores_tab::ScoresTabState;
@@ -16,6 +16,7 @@ use super::components::standings_tab::StandingsTabState;
 #[cfg(feature = "development")]
 use super::constants::DEMO_TAB_PATH;
 use super::constants::{SCORES_TAB_PATH, SETTINGS_TAB_PATH, STANDINGS_TAB_PATH};
+use super::document::FindState;
 use super::state::AppState;
 use super::tab_component::TabState;
 use super::types::Tab;
@@ -28,6 +29,23 @@ fn is_scores_browse_mode_active(component_states: &ComponentStateStore) -> bool
         .unwrap_or(false)
 }
 
+/// Helper to check if the scores tab jump-to-date prompt is open
+pub(super) fn is_scores_date_input_active(component_states: &ComponentStateStore) -> bool {
+    component_states
+        .get::<ScoresTabState>(SCORES_TAB_PATH)
+        .map(|s| s.is_date_input_active())
+        .unwrap_or(false)
+}
+
+/// Helper to check if a settings value is being edited
+pub(super) fn is_settings_edit_active(component_states: &ComponentStateStore) -> bool {
+    use super::components::settings_tab::SettingsTabState;
+    component_states
+        .get::<SettingsTabState>(SETTINGS_TAB_PATH)
+        .map(|s| s.is_editing())
+        .unwrap_or(false)
+}
+
 /// Helper to check if standings tab is in browse mode
 fn is_standings_browse_mode_active(component_states: &ComponentStateStore) -> bool {
     component_states
@@ -37,7 +55,7 @@ fn is_standings_browse_mode_active(component_states: &ComponentStateStore) -> bo
 }
 
 /// Helper to check if settings tab has modal open
-fn is_settings_modal_open(component_states: &ComponentStateStore) -> bool {
+pub(super) fn is_settings_modal_open(component_states: &ComponentStateStore) -> bool {
     use super::components::settings_tab::SettingsTabState;
     component_states
         .get::<SettingsTabState>(SETTINGS_TAB_PATH)
@@ -45,11 +63,22 @@ fn is_settings_modal_open(component_states: &ComponentStateStore) -> bool {
         .unwrap_or(false)
 }
 
+/// Find open in the top stacked document, if any
+fn active_find(state: &AppState) -> Option<&FindState> {
+    state.navigation.document_stack.last()?.nav.find.as_ref()
+}
+
 /// Handle global keys that work regardless of tab or focus state
 fn handle_global_keys(key_code: KeyCode) -> Option<Action> {
     match key_code {
         KeyCode::Char('q') | KeyCode::Char('Q') => Some(Action::Quit),
-        KeyCode::Char('/') => Some(Action::ToggleCommandPalette),
+        KeyCode::Char('/') | KeyCode::Char(':') => Some(Action::ToggleCommandPalette),
+        KeyCode::Char('?') => Some(Action::ToggleHelp),
+        KeyCode::Char('r') => Some(Action::RefreshData),
+        KeyCode::Char('E') => Some(Action::ExportDocument),
+        KeyCode::Char('p') => Some(Action::TogglePin),
+        KeyCode::Char('y') => Some(Action::CopyFocusedLink),
+        KeyCode::Char('Y') => Some(Action::CopyGameSummary),
         _ => None,
     }
 }
@@ -58,6 +87,16 @@ fn handle_global_keys(key_code: KeyCode) -> Option<Action> {
 fn handle_esc_key(state: &AppState, component_states: &ComponentStateStore) -> Option<Action> {
     use crate::tui::components::settings_tab::{ModalMsg, SettingsTabMsg};
 
+    // Priority 0: Close an open find, or forget the player marked for comparison
+    if active_find(state).is_some() {
+        debug!("KEY: ESC pressed with find open - clearing it");
+        return Some(Action::Find(FindAction::Clear));
+    }
+    if state.navigation.compare_pending.is_some() {
+        debug!("KEY: ESC pressed with compare pending - clearing it");
+        return Some(Action::ClearComparePending);
+    }
+
     // Priority 1: If there's a document on the stack, close it
     if !state.navigation.document_stack.is_empty() {
         debug!("KEY: ESC pressed with document open - popping document");
@@ -114,6 +153,21 @@ fn handle_number_keys(key_code: KeyCode) -> Option<Action> {
     }
 }
 
+/// Jump back to an ancestor breadcrumb with Alt+1-9
+///
+/// Alt+1 is the tab itself, Alt+2 the first stacked document, and so on.
+/// The current (deepest) document is not a target.
+fn handle_breadcrumb_keys(key: KeyEvent, stack_len: usize) -> Option<Action> {
+    if !key.modifiers.contains(KeyModifiers::ALT) {
+        return None;
+    }
+    let KeyCode::Char(c) = key.code else {
+        return None;
+    };
+    let depth = c.to_digit(10)?.checked_sub(1)? as usize;
+    (depth < stack_len).then_some(Action::PopToDepth(depth))
+}
+
 /// Handle navigation when tab bar is focused (Left/Right/Down)
 fn handle_tab_bar_navigation(key_code: KeyCode) -> Option<Action> {
     match key_code {
@@ -135,6 +189,24 @@ fn handle_scores_tab_keys(
 ) -> Option<Action> {
     use crate::tui::document_nav::DocumentNavMsg;
 
+    // Keys that work in both date and box selection modes
+    let shared_msg = match key_code {
+        KeyCode::Char('f') => Some(ScoresTabMsg::ToggleFavorites),
+        KeyCode::Char('s') => Some(ScoresTabMsg::CycleStateFilter),
+        KeyCode::Char('t') => Some(ScoresTabMsg::CycleGameType),
+        KeyCode::Char('[') => Some(ScoresTabMsg::NavigateLeft),
+        KeyCode::Char(']') => Some(ScoresTabMsg::NavigateRight),
+        KeyCode::Char('.') => Some(ScoresTabMsg::NavigateToday),
+        KeyCode::Char('g') => Some(ScoresTabMsg::StartDateInput),
+        _ => None,
+    };
+    if let Some(msg) = shared_msg {
+        return Some(Action::ComponentMessage {
+            path: SCORES_TAB_PATH.to_string(),
+            message: Box::new(msg),
+        });
+    }
+
     if is_scores_browse_mode_active(component_states) {
         // Box selection mode - use document navigation
         match key_code {
@@ -159,7 +231,7 @@ fn handle_scores_tab_keys(
                 if let Some(scores_state) = component_states.get::<ScoresTabState>(SCORES_TAB_PATH)
                 {
                     if let Some(selected_index) = scores_state.doc_nav.focus_index {
-                        if let Some(schedule) = state.data.schedule.as_ref().as_ref() {
+                        if let Some(schedule) = state.data.displayed().schedule.as_ref().as_ref() {
                             if let Some(game) = schedule.games.get(selected_index) {
                                 return Some(Action::SelectGame(game.id));
                             }
@@ -187,7 +259,7 @@ fn handle_scores_tab_keys(
             }),
             KeyCode::Enter => {
                 // Look up game_id from component state and schedule (first game)
-                if let Some(schedule) = state.data.schedule.as_ref().as_ref() {
+                if let Some(schedule) = state.data.displayed().schedule.as_ref().as_ref() {
                     if let Some(game) = schedule.games.first() {
                         return Some(Action::SelectGame(game.id));
                     }
@@ -199,6 +271,47 @@ fn handle_scores_tab_keys(
     }
 }
 
+/// Handle typing into the scores tab jump-to-date prompt
+fn handle_scores_date_input_keys(key_code: KeyCode) -> Option<Action> {
+    let msg = match key_code {
+        KeyCode::Char(c) => ScoresTabMsg::DateInputChar(c),
+        KeyCode::Backspace => ScoresTabMsg::DateInputBackspace,
+        KeyCode::Enter => ScoresTabMsg::SubmitDateInput,
+        KeyCode::Esc => ScoresTabMsg::CancelDateInput,
+        _ => return None,
+    };
+    Some(Action::ComponentMessage {
+        path: SCORES_TAB_PATH.to_string(),
+        message: Box::new(msg),
+    })
+}
+
+/// Handle keys while the command palette is open
+fn handle_command_palette_keys(key_code: KeyCode) -> Option<Action> {
+    let palette_action = match key_code {
+        KeyCode::Esc => return Some(Action::ToggleCommandPalette),
+        KeyCode::Char(c) => CommandPaletteAction::Input(c),
+        KeyCode::Backspace => CommandPaletteAction::Backspace,
+        KeyCode::Down => CommandPaletteAction::SelectNext,
+        KeyCode::Up => CommandPaletteAction::SelectPrev,
+        KeyCode::Enter => CommandPaletteAction::Submit,
+        _ => return None,
+    };
+    Some(Action::CommandPalette(palette_action))
+}
+
+/// Handle keys while typing a find query in a document
+fn handle_find_prompt_keys(key_code: KeyCode) -> Option<Action> {
+    let find_action = match key_code {
+        KeyCode::Esc => FindAction::Clear,
+        KeyCode::Char(c) => FindAction::Input(c),
+        KeyCode::Backspace => FindAction::Backspace,
+        KeyCode::Enter => FindAction::Submit,
+        _ => return None,
+    };
+    Some(Action::Find(find_action))
+}
+
 /// Handle League standings navigation with document system
 fn handle_standings_league_keys(key: KeyEvent, _state: &AppState) -> Option<Action> {
     use crate::tui::document_nav::DocumentNavMsg;
@@ -430,6 +543,23 @@ fn handle_demo_tab_keys(key: KeyEvent, _state: &AppState) -> Option<Action> {
     })
 }
 
+/// Handle typing into the settings tab value editor
+fn handle_settings_edit_keys(key_code: KeyCode) -> Option<Action> {
+    use crate::tui::components::settings_tab::{EditMsg, SettingsTabMsg};
+
+    let msg = match key_code {
+        KeyCode::Char(c) => EditMsg::Char(c),
+        KeyCode::Backspace => EditMsg::Backspace,
+        KeyCode::Enter => EditMsg::Submit,
+        KeyCode::Esc => EditMsg::Cancel,
+        _ => return None,
+    };
+    Some(Action::ComponentMessage {
+        path: SETTINGS_TAB_PATH.to_string(),
+        message: Box::new(SettingsTabMsg::Edit(msg)),
+    })
+}
+
 /// Convert a KeyEvent into an Action based on current application state
 ///
 /// This function implements all keyboard navigation:
@@ -458,7 +588,42 @@ pub fn key_to_action(
         state.navigation.document_stack.len()
     );
 
-    // 1. Check global keys (q/Q, /)
+    // 0. Overlays and text prompts capture every key, including global shortcuts
+    if state.navigation.help_open {
+        return Some(Action::ToggleHelp);
+    }
+    if state.navigation.column_legend.is_some() {
+        return Some(Action::ToggleColumnLegend);
+    }
+    if state.navigation.command_palette.is_some() {
+        return handle_command_palette_keys(key.code);
+    }
+    if current_tab == Tab::Scores
+        && content_focused
+        && state.navigation.document_stack.is_empty()
+        && is_scores_date_input_active(component_states)
+    {
+        return handle_scores_date_input_keys(key.code);
+    }
+    if current_tab == Tab::Settings
+        && content_focused
+        && state.navigation.document_stack.is_empty()
+        && is_settings_edit_active(component_states)
+    {
+        return handle_settings_edit_keys(key.code);
+    }
+    if active_find(state).is_some_and(|find| find.editing) {
+        return handle_find_prompt_keys(key.code);
+    }
+
+    // Configured key bindings stand in for their action's default key from here on,
+    // so text prompts above still receive the typed character
+    let key = state.system.config.keymap.translate(key);
+
+    // 1. Check global keys (q/Q, /, :, ?, r, E, p, y); in a document, / finds instead
+    if key.code == KeyCode::Char('/') && !state.navigation.document_stack.is_empty() {
+        return Some(Action::Find(FindAction::Start));
+    }
     if let Some(action) = handle_global_keys(key.code) {
         return Some(action);
     }
@@ -470,6 +635,22 @@ pub fn key_to_action(
 
     // 3. Route key events to stacked documents (when stacked document is open)
     if !state.navigation.document_stack.is_empty() {
+        if let Some(action) = handle_breadcrumb_keys(key, state.navigation.document_stack.len()) {
+            return Some(action);
+        }
+
+        if key.code == KeyCode::Char('L') {
+            return Some(Action::ToggleColumnLegend);
+        }
+
+        if active_find(state).is_some() {
+            match key.code {
+                KeyCode::Char('n') => return Some(Action::Find(FindAction::Next)),
+                KeyCode::Char('N') => return Some(Action::Find(FindAction::Prev)),
+                _ => {}
+            }
+        }
+
         // Delegate key handling to the stacked document handler
         return Some(Action::StackedDocumentKey(key));
     }
@@ -538,6 +719,18 @@ pub fn key_to_action(
     match current_tab {
         Tab::Scores => handle_scores_tab_keys(state, key.code, component_states),
         Tab::Standings => {
+            let season_delta = match key.code {
+                KeyCode::Char('[') => Some(-1),
+                KeyCode::Char(']') => Some(1),
+                _ => None,
+            };
+            if let Some(delta) = season_delta {
+                return Some(Action::ComponentMessage {
+                    path: STANDINGS_TAB_PATH.to_string(),
+                    message: Box::new(StandingsTabMsg::ShiftSeason(delta)),
+                });
+            }
+
             // All standings views use document navigation in browse mode
             if is_standings_browse_mode_active(component_states) {
                 handle_standings_league_keys(key, state)
@@ -550,3 +743,387 @@ pub fn key_to_action(
         Tab::Demo => handle_demo_tab_keys(key, state),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    fn scores_key(code: KeyCode) -> Option<Action> {
+        let mut state = AppState::default();
+        state.navigation.current_tab = Tab::Scores;
+        state.navigation.content_focused = true;
+        key_to_action(KeyEvent::from(code), &state, &ComponentStateStore::new())
+    }
+
+    #[test]
+    fn test_f_toggles_scores_favorites() {
+        match scores_key(KeyCode::Char('f')) {
+            Some(Action::ComponentMessage { path, message }) => {
+                assert_eq!(path, SCORES_TAB_PATH);
+                assert!(format!("{:?}", message).contains("ToggleFavorites"));
+            }
+            other => panic!("Expected scores component message, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_s_cycles_scores_state_filter() {
+        match scores_key(KeyCode::Char('s')) {
+            Some(Action::ComponentMessage { path, message }) => {
+                assert_eq!(path, SCORES_TAB_PATH);
+                assert!(format!("{:?}", message).contains("CycleStateFilter"));
+            }
+            other => panic!("Expected scores component message, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_t_cycles_scores_game_type() {
+        match scores_key(KeyCode::Char('t')) {
+            Some(Action::ComponentMessage { path, message }) => {
+                assert_eq!(path, SCORES_TAB_PATH);
+                assert!(format!("{:?}", message).contains("CycleGameType"));
+            }
+            other => panic!("Expected scores component message, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_bracket_keys_shift_standings_season() {
+        let mut state = AppState::default();
+        state.navigation.current_tab = Tab::Standings;
+        state.navigation.content_focused = true;
+
+        for (code, expected) in [
+            (KeyCode::Char('['), "ShiftSeason(-1)"),
+            (KeyCode::Char(']'), "ShiftSeason(1)"),
+        ] {
+            match key_to_action(KeyEvent::from(code), &state, &ComponentStateStore::new()) {
+                Some(Action::ComponentMessage { path, message }) => {
+                    assert_eq!(path, STANDINGS_TAB_PATH);
+                    assert!(format!("{:?}", message).contains(expected));
+                }
+                other => panic!("Expected standings component message, got {:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_date_keys_navigate_scores_dates() {
+        for (code, expected) in [
+            (KeyCode::Char('['), "NavigateLeft"),
+            (KeyCode::Char(']'), "NavigateRight"),
+            (KeyCode::Char('.'), "NavigateToday"),
+        ] {
+            match scores_key(code) {
+                Some(Action::ComponentMessage { message, .. }) => {
+                    assert!(format!("{:?}", message).contains(expected));
+                }
+                other => panic!("Expected scores component message, got {:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_date_input_captures_global_keys() {
+        let mut state = AppState::default();
+        state.navigation.current_tab = Tab::Scores;
+        state.navigation.content_focused = true;
+        let mut component_states = ComponentStateStore::new();
+        component_states.insert(
+            SCORES_TAB_PATH.to_string(),
+            ScoresTabState {
+                date_input: Some(String::new()),
+                ..ScoresTabState::default()
+            },
+        );
+
+        for (code, expected) in [
+            (KeyCode::Char('q'), "DateInputChar('q')"),
+            (KeyCode::Enter, "SubmitDateInput"),
+            (KeyCode::Esc, "CancelDateInput"),
+        ] {
+            match key_to_action(KeyEvent::from(code), &state, &component_states) {
+                Some(Action::ComponentMessage { message, .. }) => {
+                    assert!(format!("{:?}", message).contains(expected));
+                }
+                other => panic!("Expected date input message, got {:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_alt_number_pops_to_breadcrumb() {
+        use crate::tui::state::DocumentStackEntry;
+        use crate::tui::types::StackedDocument;
+
+        let mut state = AppState::default();
+        for abbrev in ["TOR", "MTL"] {
+            state
+                .navigation
+                .document_stack
+                .push(DocumentStackEntry::new(StackedDocument::TeamDetail {
+                    abbrev: abbrev.to_string(),
+                }));
+        }
+        let component_states = ComponentStateStore::new();
+        let alt = |c| KeyEvent::new(KeyCode::Char(c), KeyModifiers::ALT);
+
+        assert!(matches!(
+            key_to_action(alt('1'), &state, &component_states),
+            Some(Action::PopToDepth(0))
+        ));
+        assert!(matches!(
+            key_to_action(alt('2'), &state, &component_states),
+            Some(Action::PopToDepth(1))
+        ));
+        // The current document and crumbs past it go to the document as usual
+        assert!(matches!(
+            key_to_action(alt('3'), &state, &component_states),
+            Some(Action::StackedDocumentKey(_))
+        ));
+        assert!(matches!(
+            key_to_action(alt('0'), &state, &component_states),
+            Some(Action::StackedDocumentKey(_))
+        ));
+        assert!(matches!(
+            key_to_action(
+                KeyEvent::from(KeyCode::Char('1')),
+                &state,
+                &component_states
+            ),
+            Some(Action::StackedDocumentKey(_))
+        ));
+    }
+
+    #[test]
+    fn test_settings_edit_captures_global_keys() {
+        use crate::tui::components::settings_tab::{EditState, SettingsTabState};
+
+        let mut state = AppState::default();
+        state.navigation.current_tab = Tab::Settings;
+        state.navigation.content_focused = true;
+        let mut component_states = ComponentStateStore::new();
+        component_states.insert(
+            SETTINGS_TAB_PATH.to_string(),
+            SettingsTabState {
+                edit: Some(EditState {
+                    setting_key: "log_file".to_string(),
+                    input: String::new(),
+                    error: None,
+                }),
+                ..SettingsTabState::default()
+            },
+        );
+
+        for (code, expected) in [
+            (KeyCode::Char('q'), "Char('q')"),
+            (KeyCode::Char('3'), "Char('3')"),
+            (KeyCode::Enter, "Submit"),
+            (KeyCode::Esc, "Cancel"),
+        ] {
+            match key_to_action(KeyEvent::from(code), &state, &component_states) {
+                Some(Action::ComponentMessage { message, .. }) => {
+                    assert!(format!("{:?}", message).contains(expected));
+                }
+                other => panic!("Expected settings edit message, got {:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_esc_clears_compare_pending_before_popping_document() {
+        use crate::tui::state::ComparePending;
+
+        let mut state = AppState::default();
+        state.navigation.compare_pending = Some(ComparePending {
+            player_id: 1,
+            last_name: "McDavid".to_string(),
+        });
+
+        let action = key_to_action(
+            KeyEvent::from(KeyCode::Esc),
+            &state,
+            &ComponentStateStore::new(),
+        );
+
+        assert!(matches!(action, Some(Action::ClearComparePending)));
+    }
+
+    #[test]
+    fn test_find_keys_in_document() {
+        use crate::tui::document::FindState;
+        use crate::tui::state::DocumentStackEntry;
+        use crate::tui::types::StackedDocument;
+
+        let mut state = AppState::default();
+        state
+            .navigation
+            .document_stack
+            .push(DocumentStackEntry::new(StackedDocument::TeamDetail {
+                abbrev: "TOR".to_string(),
+            }));
+        let key = |state: &AppState, code| {
+            key_to_action(KeyEvent::from(code), state, &ComponentStateStore::new())
+        };
+
+        assert!(matches!(
+            key(&state, KeyCode::Char('/')),
+            Some(Action::Find(FindAction::Start))
+        ));
+        // The command palette is still on ':'
+        assert!(matches!(
+            key(&state, KeyCode::Char(':')),
+            Some(Action::ToggleCommandPalette)
+        ));
+        // n goes to the document until a find is open
+        assert!(matches!(
+            key(&state, KeyCode::Char('n')),
+            Some(Action::StackedDocumentKey(_))
+        ));
+
+        // The prompt captures global keys
+        state.navigation.document_stack[0].nav.find = Some(FindState::prompt());
+        assert!(matches!(
+            key(&state, KeyCode::Char('q')),
+            Some(Action::Find(FindAction::Input('q')))
+        ));
+        assert!(matches!(
+            key(&state, KeyCode::Enter),
+            Some(Action::Find(FindAction::Submit))
+        ));
+
+        state.navigation.document_stack[0]
+            .nav
+            .find
+            .as_mut()
+            .unwrap()
+            .editing = false;
+        assert!(matches!(
+            key(&state, KeyCode::Char('n')),
+            Some(Action::Find(FindAction::Next))
+        ));
+        assert!(matches!(
+            key(&state, KeyCode::Char('N')),
+            Some(Action::Find(FindAction::Prev))
+        ));
+        // Esc clears the find before popping the document
+        assert!(matches!(
+            key(&state, KeyCode::Esc),
+            Some(Action::Find(FindAction::Clear))
+        ));
+    }
+
+    #[test]
+    fn test_column_legend_keys() {
+        use crate::tui::state::DocumentStackEntry;
+        use crate::tui::types::StackedDocument;
+
+        let mut state = AppState::default();
+        state
+            .navigation
+            .document_stack
+            .push(DocumentStackEntry::new(StackedDocument::TeamDetail {
+                abbrev: "TOR".to_string(),
+            }));
+        let key = |state: &AppState, code| {
+            key_to_action(KeyEvent::from(code), state, &ComponentStateStore::new())
+        };
+
+        assert!(matches!(
+            key(&state, KeyCode::Char('L')),
+            Some(Action::ToggleColumnLegend)
+        ));
+
+        // Any key closes the open legend
+        state.navigation.column_legend = Some(vec![("G".to_string(), "Goals".to_string())]);
+        assert!(matches!(
+            key(&state, KeyCode::Char('q')),
+            Some(Action::ToggleColumnLegend)
+        ));
+        assert!(matches!(
+            key(&state, KeyCode::Esc),
+            Some(Action::ToggleColumnLegend)
+        ));
+    }
+
+    #[test]
+    fn test_command_palette_captures_global_keys() {
+        use crate::tui::state::CommandPaletteState;
+
+        let mut state = AppState::default();
+        state.navigation.command_palette = Some(CommandPaletteState::default());
+        let key = |code| key_to_action(KeyEvent::from(code), &state, &ComponentStateStore::new());
+
+        assert!(matches!(
+            key(KeyCode::Char('q')),
+            Some(Action::CommandPalette(CommandPaletteAction::Input('q')))
+        ));
+        assert!(matches!(
+            key(KeyCode::Down),
+            Some(Action::CommandPalette(CommandPaletteAction::SelectNext))
+        ));
+        assert!(matches!(
+            key(KeyCode::Esc),
+            Some(Action::ToggleCommandPalette)
+        ));
+    }
+
+    #[test]
+    fn test_configured_keys_act_as_defaults() {
+        let mut state = AppState::default();
+        let config = Arc::make_mut(&mut state.system.config);
+        config
+            .keybindings
+            .insert("nav_right".to_string(), "l".to_string());
+        config.rebuild_keymap();
+
+        let action = key_to_action(
+            KeyEvent::from(KeyCode::Char('l')),
+            &state,
+            &ComponentStateStore::new(),
+        );
+        assert!(matches!(action, Some(Action::NavigateTabRight)));
+
+        // Typing into a prompt is not remapped
+        state.navigation.command_palette = Some(Default::default());
+        let action = key_to_action(
+            KeyEvent::from(KeyCode::Char('l')),
+            &state,
+            &ComponentStateStore::new(),
+        );
+        assert!(matches!(
+            action,
+            Some(Action::CommandPalette(CommandPaletteAction::Input('l')))
+        ));
+    }
+
+    #[test]
+    fn test_configured_refresh_key() {
+        let mut state = AppState::default();
+        let config = Arc::make_mut(&mut state.system.config);
+        config
+            .keybindings
+            .insert("refresh".to_string(), "ctrl+r".to_string());
+        config.rebuild_keymap();
+
+        let action = key_to_action(
+            KeyEvent::new(KeyCode::Char('r'), KeyModifiers::CONTROL),
+            &state,
+            &ComponentStateStore::new(),
+        );
+        assert!(matches!(action, Some(Action::RefreshData)));
+    }
+
+    #[test]
+    fn test_any_key_closes_help() {
+        let mut state = AppState::default();
+        state.navigation.help_open = true;
+
+        for code in [KeyCode::Char('q'), KeyCode::Esc, KeyCode::Down] {
+            let action = key_to_action(KeyEvent::from(code), &state, &ComponentStateStore::new());
+            assert!(matches!(action, Some(Action::ToggleHelp)));
+        }
+    }
+}
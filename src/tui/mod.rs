@@ -4,6 +4,7 @@ pub mod widgets;
 
 // Core modules
 pub mod action;
+pub mod clipboard;
 pub mod component;
 pub mod component_store;
 pub mod constants;
@@ -12,13 +13,18 @@ pub mod document_nav;
 pub mod effects;
 pub mod focus_helpers;
 pub mod helpers;
+pub mod hit_test;
+pub mod keymap;
 pub mod keys;
+pub mod mouse;
 pub mod nav_handler;
 pub mod reducer;
 pub mod reducers;
 pub mod renderer;
 pub mod runtime;
+pub mod session;
 pub mod settings_helpers;
+pub mod share;
 pub mod state;
 pub mod tab_component;
 pub mod table;
@@ -37,13 +43,19 @@ pub use reducer::reduce;
 pub use renderer::Renderer;
 pub use runtime::Runtime;
 pub use state::AppState;
-pub use table::{Alignment, CellValue, ColumnDef};
+pub use table::{Alignment, CellValue, ColumnDef, ColumnTotal};
 pub use types::{SettingsCategory, StackedDocument, Tab};
 
+use hit_test::HitMap;
+use mouse::{is_click, mouse_to_actions};
+use session::Session;
+use state::SeasonSelection;
+
 use crate::config::Config;
 use crate::data_provider::NHLDataProvider;
+use crate::disk_cache::DiskCache;
 use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event},
+    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEvent},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
@@ -99,18 +111,29 @@ pub async fn run(client: Arc<dyn NHLDataProvider>, config: Config) -> Result<(),
     let mut terminal = Terminal::new(backend)?;
 
     // Create DataEffects handler
-    let data_effects = Arc::new(DataEffects::new(client));
+    let mut data_effects = DataEffects::new(client);
+    if config.disk_cache {
+        let ttl = Duration::from_secs(config.refresh_interval.into());
+        if let Some(disk_cache) = DiskCache::open(ttl) {
+            data_effects = data_effects.with_disk_cache(disk_cache);
+        }
+    }
+    let data_effects = Arc::new(data_effects);
 
     // Create initial AppState with config
     let mut initial_state = AppState::default();
-    initial_state.system.config = config.clone();
+    initial_state.system.config = Arc::new(config.clone());
     initial_state.system.reset_status_message();
+    initial_state.ui.season = SeasonSelection::from_config(&config);
 
     // Create runtime with DataEffects
     let mut runtime = Runtime::new(initial_state, data_effects);
+    if config.restore_session {
+        runtime.restore_session(&session::read());
+    }
 
     // Trigger initial data load
-    runtime.dispatch(Action::RefreshData);
+    runtime.dispatch(Action::LoadData);
 
     #[cfg(feature = "development")]
     let mut screenshot_requested = false;
@@ -128,6 +151,7 @@ pub async fn run(client: Arc<dyn NHLDataProvider>, config: Config) -> Result<(),
         let mut screenshot_buffer: Option<ratatui::buffer::Buffer> = None;
 
         let mut terminal_width = 80u16; // Default
+        let mut hit_map = HitMap::default();
 
         terminal.draw(|f| {
             let area = f.area();
@@ -135,15 +159,18 @@ pub async fn run(client: Arc<dyn NHLDataProvider>, config: Config) -> Result<(),
 
             // Build virtual tree from current state
             // This creates component states if they don't exist yet
-            let element = runtime.build();
+            let element = runtime.build(area);
 
             // Update viewport heights for document-based components
             // Called after build() to ensure component states exist
             runtime.update_viewport_heights(area.height);
+            runtime.update_focus_wrap();
 
             // Render virtual tree to ratatui buffer
             let config = &runtime.state().system.config.display;
             let mut renderer = Renderer::new();
+            // Record where focusable elements land so mouse clicks can find them
+            hit_map = renderer.hit_map(&element, area, config);
             renderer.render(element, area, f.buffer_mut(), config);
 
             // Clone buffer if screenshot requested
@@ -193,22 +220,9 @@ pub async fn run(client: Arc<dyn NHLDataProvider>, config: Config) -> Result<(),
         // Check if we need animation (loading or waiting for data)
         let state = runtime.state();
         let needs_animation = !state.data.loading.is_empty()
-            || state.data.standings.is_none()
-            || state.data.schedule.is_none()
-            || state.navigation.document_stack.iter().any(|doc| {
-                use crate::tui::types::StackedDocument;
-                match &doc.document {
-                    StackedDocument::Boxscore { game_id, .. } => {
-                        state.data.boxscores.get(game_id).is_none()
-                    }
-                    StackedDocument::TeamDetail { abbrev } => {
-                        state.data.team_roster_stats.get(abbrev).is_none()
-                    }
-                    StackedDocument::PlayerDetail { player_id, .. } => {
-                        state.data.player_data.get(player_id).is_none()
-                    }
-                }
-            });
+            || !state.ui.scores.score_changes.is_empty()
+            || state.viewing_live_game()
+            || state.waiting_for_data();
 
         // Dispatch Tick for loading animation
         if needs_animation {
@@ -218,10 +232,30 @@ pub async fn run(client: Arc<dyn NHLDataProvider>, config: Config) -> Result<(),
         // Poll for keyboard events - use shorter timeout when animating for smoother animation
         let poll_timeout = if needs_animation { 50 } else { 100 };
         if event::poll(Duration::from_millis(poll_timeout))? {
-            if let Event::Key(key) = event::read()? {
+            let event = event::read()?;
+            if let Event::Mouse(mouse) = event {
+                let actions =
+                    mouse_to_actions(mouse, &hit_map, runtime.state(), runtime.component_states());
+                if !actions.is_empty() {
+                    for action in actions {
+                        runtime.dispatch(action);
+                    }
+                    // A click activates what it focused, as Enter would
+                    if is_click(&mouse, &hit_map) {
+                        let enter = KeyEvent::from(KeyCode::Enter);
+                        if let Some(action) =
+                            key_to_action(enter, runtime.state(), runtime.component_states())
+                        {
+                            runtime.dispatch(action);
+                        }
+                    }
+                    continue;
+                }
+            }
+            if let Event::Key(key) = event {
                 #[cfg(feature = "development")]
                 {
-                    use crossterm::event::{KeyCode, KeyModifiers};
+                    use crossterm::event::KeyModifiers;
                     if key.code == KeyCode::Char('S') && key.modifiers.contains(KeyModifiers::SHIFT)
                     {
                         tracing::info!("Screenshot requested via Shift-S");
@@ -249,6 +283,12 @@ pub async fn run(client: Arc<dyn NHLDataProvider>, config: Config) -> Result<(),
 
                 if should_quit {
                     tracing::debug!("ACTION: Quitting application");
+                    if runtime.state().system.config.restore_session {
+                        let saved = Session::capture(runtime.state(), runtime.component_states());
+                        if let Err(e) = session::write(&saved) {
+                            tracing::warn!("Failed to save session: {}", e);
+                        }
+                    }
                     break;
                 }
             }
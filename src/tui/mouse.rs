@@ -0,0 +1,271 @@
+/// Mouse event to action mapping
+///
+/// Clicks focus the element under the cursor, found through the frame's
+/// `HitMap`; the event loop then activates it like Enter. A click on an
+/// ancestor breadcrumb pops the document stack back to it. The scroll wheel
+/// scrolls the active document: the top stacked document, or the current tab.
+use crossterm::event::{MouseButton, MouseEvent, MouseEventKind};
+use tracing::debug;
+
+use super::action::Action;
+use super::component_store::ComponentStateStore;
+use super::components::scores_tab::ScoresTabMsg;
+use super::components::settings_tab::SettingsTabMsg;
+use super::components::standings_tab::StandingsTabMsg;
+#[cfg(feature = "development")]
+use super::constants::DEMO_TAB_PATH;
+use super::constants::{SCORES_TAB_PATH, SETTINGS_TAB_PATH, STANDINGS_TAB_PATH};
+use super::document_nav::DocumentNavMsg;
+use super::hit_test::{HitMap, HitTarget};
+use super::keys::{is_scores_date_input_active, is_settings_edit_active, is_settings_modal_open};
+use super::state::AppState;
+use super::types::Tab;
+
+/// Lines scrolled per mouse wheel step
+const SCROLL_LINES: u16 = 3;
+
+/// Whether a mouse event is a click on a focusable element, which should activate it
+pub fn is_click(mouse: &MouseEvent, hit_map: &HitMap) -> bool {
+    mouse.kind == MouseEventKind::Down(MouseButton::Left)
+        && hit_map.focus_at(mouse.column, mouse.row).is_some()
+}
+
+/// Convert a mouse event into actions based on current application state
+///
+/// Returns no actions for clicks outside focusable elements, for other mouse
+/// events, and while an overlay or prompt is capturing input.
+pub fn mouse_to_actions(
+    mouse: MouseEvent,
+    hit_map: &HitMap,
+    state: &AppState,
+    component_states: &ComponentStateStore,
+) -> Vec<Action> {
+    if is_input_captured(state, component_states) {
+        return Vec::new();
+    }
+
+    let nav_msg = match mouse.kind {
+        MouseEventKind::ScrollDown => DocumentNavMsg::ScrollDown(SCROLL_LINES),
+        MouseEventKind::ScrollUp => DocumentNavMsg::ScrollUp(SCROLL_LINES),
+        MouseEventKind::Down(MouseButton::Left) => {
+            match hit_map.target_at(mouse.column, mouse.row) {
+                Some(HitTarget::Focus(index)) => {
+                    debug!(
+                        "MOUSE: Click at ({}, {}) focuses element {}",
+                        mouse.column, mouse.row, index
+                    );
+                    DocumentNavMsg::FocusIndex(index)
+                }
+                Some(HitTarget::Crumb(depth)) => {
+                    debug!("MOUSE: Click on breadcrumb pops to depth {}", depth);
+                    return vec![Action::PopToDepth(depth)];
+                }
+                None => return Vec::new(),
+            }
+        }
+        _ => return Vec::new(),
+    };
+
+    let mut actions = Vec::new();
+    if !state.navigation.content_focused && state.navigation.document_stack.is_empty() {
+        actions.push(Action::EnterContentFocus);
+    }
+    actions.push(document_nav_action(nav_msg, state));
+    actions
+}
+
+/// Overlays, prompts and modals take all input, so the mouse does nothing
+fn is_input_captured(state: &AppState, component_states: &ComponentStateStore) -> bool {
+    state.navigation.help_open
+        || state.navigation.column_legend.is_some()
+        || state.navigation.command_palette.is_some()
+        || (state.navigation.current_tab == Tab::Scores
+            && is_scores_date_input_active(component_states))
+        || (state.navigation.current_tab == Tab::Settings
+            && (is_settings_modal_open(component_states)
+                || is_settings_edit_active(component_states)))
+}
+
+/// Route a navigation message to the document currently shown
+fn document_nav_action(nav_msg: DocumentNavMsg, state: &AppState) -> Action {
+    if !state.navigation.document_stack.is_empty() {
+        return Action::StackedDocumentNav(nav_msg);
+    }
+
+    match state.navigation.current_tab {
+        Tab::Scores => Action::ComponentMessage {
+            path: SCORES_TAB_PATH.to_string(),
+            message: Box::new(ScoresTabMsg::DocNav(nav_msg)),
+        },
+        Tab::Standings => Action::ComponentMessage {
+            path: STANDINGS_TAB_PATH.to_string(),
+            message: Box::new(StandingsTabMsg::DocNav(nav_msg)),
+        },
+        Tab::Settings => Action::ComponentMessage {
+            path: SETTINGS_TAB_PATH.to_string(),
+            message: Box::new(SettingsTabMsg::DocNav(nav_msg)),
+        },
+        #[cfg(feature = "development")]
+        Tab::Demo => {
+            use super::components::demo_tab::DemoTabMsg;
+            Action::ComponentMessage {
+                path: DEMO_TAB_PATH.to_string(),
+                message: Box::new(DemoTabMsg::DocNav(nav_msg)),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tui::components::standings_tab::StandingsTabState;
+    use crate::tui::hit_test::HitRegion;
+    use crate::tui::state::DocumentStackEntry;
+    use crate::tui::types::StackedDocument;
+    use crossterm::event::KeyModifiers;
+    use ratatui::layout::Rect;
+
+    fn mouse(kind: MouseEventKind, column: u16, row: u16) -> MouseEvent {
+        MouseEvent {
+            kind,
+            column,
+            row,
+            modifiers: KeyModifiers::NONE,
+        }
+    }
+
+    fn click(column: u16, row: u16) -> MouseEvent {
+        mouse(MouseEventKind::Down(MouseButton::Left), column, row)
+    }
+
+    fn hit_map() -> HitMap {
+        HitMap::new(vec![HitRegion {
+            rect: Rect::new(4, 5, 10, 1),
+            target: HitTarget::Focus(2),
+        }])
+    }
+
+    fn standings_state() -> (AppState, ComponentStateStore) {
+        let mut state = AppState::default();
+        state.navigation.current_tab = Tab::Standings;
+        let mut component_states = ComponentStateStore::new();
+        let mut standings = StandingsTabState::default();
+        standings.doc_nav.focusable_positions = vec![0, 1, 2, 3];
+        component_states.insert(STANDINGS_TAB_PATH.to_string(), standings);
+        (state, component_states)
+    }
+
+    fn standings_nav(component_states: &ComponentStateStore) -> (Option<usize>, u16) {
+        let nav = &component_states
+            .get::<StandingsTabState>(STANDINGS_TAB_PATH)
+            .unwrap()
+            .doc_nav;
+        (nav.focus_index, nav.scroll_offset)
+    }
+
+    #[test]
+    fn test_click_focuses_element_in_tab() {
+        let (state, mut component_states) = standings_state();
+
+        let actions = mouse_to_actions(click(6, 5), &hit_map(), &state, &component_states);
+
+        assert!(matches!(actions[0], Action::EnterContentFocus));
+        let Action::ComponentMessage { path, message } = &actions[1] else {
+            panic!("Expected a component message, got {:?}", actions[1]);
+        };
+        assert_eq!(path, STANDINGS_TAB_PATH);
+        let standings = component_states
+            .get_mut::<StandingsTabState>(STANDINGS_TAB_PATH)
+            .unwrap();
+        message.apply(standings);
+        assert_eq!(standings_nav(&component_states), (Some(2), 0));
+    }
+
+    #[test]
+    fn test_click_in_empty_area_does_nothing() {
+        let (state, component_states) = standings_state();
+
+        assert!(mouse_to_actions(click(3, 5), &hit_map(), &state, &component_states).is_empty());
+        assert!(mouse_to_actions(click(6, 6), &hit_map(), &state, &component_states).is_empty());
+    }
+
+    #[test]
+    fn test_wheel_scrolls_active_document() {
+        let (mut state, mut component_states) = standings_state();
+        state.navigation.content_focused = true;
+
+        let actions = mouse_to_actions(
+            mouse(MouseEventKind::ScrollDown, 0, 0),
+            &HitMap::default(),
+            &state,
+            &component_states,
+        );
+        assert_eq!(actions.len(), 1);
+        let Action::ComponentMessage { message, .. } = &actions[0] else {
+            panic!("Expected a component message, got {:?}", actions[0]);
+        };
+        message.apply(
+            component_states
+                .get_mut::<StandingsTabState>(STANDINGS_TAB_PATH)
+                .unwrap(),
+        );
+        assert_eq!(standings_nav(&component_states), (None, SCROLL_LINES));
+
+        // With a document open, the wheel scrolls it instead of the tab
+        state
+            .navigation
+            .document_stack
+            .push(DocumentStackEntry::new(StackedDocument::TeamDetail {
+                abbrev: "TOR".to_string(),
+            }));
+        let actions = mouse_to_actions(
+            mouse(MouseEventKind::ScrollUp, 0, 0),
+            &HitMap::default(),
+            &state,
+            &component_states,
+        );
+        assert!(matches!(
+            actions.as_slice(),
+            [Action::StackedDocumentNav(DocumentNavMsg::ScrollUp(
+                SCROLL_LINES
+            ))]
+        ));
+    }
+
+    #[test]
+    fn test_click_on_breadcrumb_pops_stack() {
+        let (mut state, component_states) = standings_state();
+        state
+            .navigation
+            .document_stack
+            .push(DocumentStackEntry::new(StackedDocument::TeamDetail {
+                abbrev: "TOR".to_string(),
+            }));
+        let crumbs = HitMap::new(vec![HitRegion {
+            rect: Rect::new(0, 2, 9, 1),
+            target: HitTarget::Crumb(0),
+        }]);
+
+        let actions = mouse_to_actions(click(4, 2), &crumbs, &state, &component_states);
+        assert!(matches!(actions.as_slice(), [Action::PopToDepth(0)]));
+        // Crumbs are not activated like focused elements
+        assert!(!is_click(&click(4, 2), &crumbs));
+        assert!(is_click(&click(6, 5), &hit_map()));
+    }
+
+    #[test]
+    fn test_overlays_capture_mouse() {
+        let (mut state, component_states) = standings_state();
+        state.navigation.help_open = true;
+
+        assert!(mouse_to_actions(click(6, 5), &hit_map(), &state, &component_states).is_empty());
+        assert!(mouse_to_actions(
+            mouse(MouseEventKind::ScrollDown, 0, 0),
+            &hit_map(),
+            &state,
+            &component_states
+        )
+        .is_empty());
+    }
+}
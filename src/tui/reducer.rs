@@ -1,3 +1,5 @@
+use std::time::SystemTime;
+
 use tracing::debug;
 
 use super::action::Action;
@@ -5,9 +7,11 @@ use super::component::Effect;
 use super::component_store::ComponentStateStore;
 use super::state::AppState;
 use super::types::StackedDocument;
+use super::widgets::loading_animation::animation_cycle;
 
 // Import sub-reducers from the parent framework module
 use crate::tui::reducers::{
+    copy_focused_link, copy_game_summary, export_document, rebuild_scores_focusable_metadata,
     rebuild_standings_focusable_metadata, reduce_data_loading, reduce_document_stack,
     reduce_navigation, reduce_settings,
 };
@@ -72,7 +76,7 @@ pub fn reduce(
         Action::SelectGame(game_id) => {
             // Look up game data from schedule to get team abbrevs and scores
             let (away_abbrev, home_abbrev, away_score, home_score) =
-                if let Some(schedule) = &*state.data.schedule {
+                if let Some(schedule) = &*state.data.displayed().schedule {
                     schedule
                         .games
                         .iter()
@@ -103,6 +107,20 @@ pub fn reduce(
             .unwrap_or_else(|s| (s, Effect::None))
         }
 
+        // Scores: Rebuild focusable metadata after the favorites filter changes
+        Action::RebuildScoresFocusable => {
+            rebuild_scores_focusable_metadata(&state, component_states);
+            (state, Effect::None)
+        }
+
+        // Scores: Filter the listed games by game type
+        Action::CycleGameType => {
+            let mut new_state = state;
+            new_state.ui.season.cycle_game_type();
+            rebuild_scores_focusable_metadata(&new_state, component_states);
+            (new_state, Effect::None)
+        }
+
         // Standings: Rebuild focusable metadata after view change
         Action::RebuildStandingsFocusable => {
             rebuild_standings_focusable_metadata(&state, component_states);
@@ -119,6 +137,30 @@ pub fn reduce(
             (new_state, Effect::None)
         }
 
+        Action::CopyFocusedLink => copy_focused_link(state, component_states),
+        Action::ExportDocument => export_document(state, component_states),
+        Action::CopyGameSummary => copy_game_summary(state, component_states),
+
+        Action::TogglePin => {
+            let mut new_state = state;
+            if new_state.data.is_pinned() {
+                new_state.data.unpin();
+                new_state.system.pinned_at = None;
+                new_state
+                    .system
+                    .set_status_message("Unpinned - showing latest data".to_string());
+            } else {
+                new_state.data.pin();
+                new_state.system.pinned_at = Some(SystemTime::now());
+                new_state
+                    .system
+                    .set_status_message("Pinned - press p to show latest data".to_string());
+            }
+            // Focusable metadata must match the data being displayed
+            rebuild_standings_focusable_metadata(&new_state, component_states);
+            (new_state, Effect::None)
+        }
+
         Action::UpdateTerminalWidth(width) => {
             let mut new_state = state;
             new_state.system.terminal_width = width;
@@ -127,8 +169,14 @@ pub fn reduce(
 
         Action::Tick => {
             let mut new_state = state;
-            // Wrap at 4 to match the 4-frame animation
-            new_state.system.animation_frame = (new_state.system.animation_frame + 1) % 4;
+            let cycle = animation_cycle(new_state.system.config.display.loading_frame_divisor);
+            new_state.system.animation_frame = (new_state.system.animation_frame + 1) % cycle;
+            new_state.system.frame_count += 1;
+            new_state
+                .ui
+                .scores
+                .expire_score_changes(new_state.system.frame_count);
+            new_state.system.expire_status_message();
             (new_state, Effect::None)
         }
 
@@ -142,7 +190,10 @@ pub fn reduce(
 mod tests {
     use super::*;
     use crate::tui::action::SettingsAction;
+    use crate::tui::state::DEFAULT_STATUS_MESSAGE;
     use crate::tui::types::{SettingsCategory, Tab};
+    use ratatui::style::Color;
+    use std::sync::Arc;
 
     // Test helper that creates a ComponentStateStore for each test
     fn test_reduce(state: AppState, action: Action) -> (AppState, Effect) {
@@ -162,6 +213,22 @@ mod tests {
         assert!(!new_state.navigation.content_focused);
     }
 
+    #[test]
+    fn test_tick_expires_score_highlights() {
+        let mut state = AppState::default();
+        state.ui.scores.mark_score_change(1, 0);
+        state.ui.scores.mark_score_change(2, 30);
+
+        for _ in 0..40 {
+            state = test_reduce(state, Action::Tick).0;
+        }
+
+        assert_eq!(state.system.frame_count, 40);
+        assert_eq!(state.system.animation_frame, 0);
+        assert!(!state.ui.scores.score_changes.contains_key(&1));
+        assert!(state.ui.scores.score_changes.contains_key(&2));
+    }
+
     #[test]
     fn test_document_stack_actions_are_handled() {
         let state = AppState::default();
@@ -193,6 +260,52 @@ mod tests {
         assert!(matches!(effect, Effect::FetchBoxscore(12345)));
     }
 
+    #[test]
+    fn test_pinning_freezes_displayed_standings() {
+        use crate::tui::testing::create_test_standings_with_count;
+
+        let state = AppState::default();
+        let (state, _) = test_reduce(
+            state,
+            Action::StandingsLoaded(None, Ok(create_test_standings_with_count(2))),
+        );
+        let (state, _) = test_reduce(state, Action::TogglePin);
+        assert!(state.system.pinned_at.is_some());
+
+        let (state, _) = test_reduce(
+            state,
+            Action::StandingsLoaded(None, Ok(create_test_standings_with_count(3))),
+        );
+
+        // Live data updates in the background while the display stays frozen
+        assert_eq!(state.data.standings.as_ref().as_ref().unwrap().len(), 3);
+        assert_eq!(
+            state
+                .data
+                .displayed()
+                .standings
+                .as_ref()
+                .as_ref()
+                .unwrap()
+                .len(),
+            2
+        );
+
+        let (state, _) = test_reduce(state, Action::TogglePin);
+        assert!(state.system.pinned_at.is_none());
+        assert_eq!(
+            state
+                .data
+                .displayed()
+                .standings
+                .as_ref()
+                .as_ref()
+                .unwrap()
+                .len(),
+            3
+        );
+    }
+
     #[test]
     fn test_rebuild_standings_focusable_returns_none() {
         let state = AppState::default();
@@ -362,6 +475,27 @@ mod tests {
         assert!(matches!(effect, Effect::None));
     }
 
+    #[test]
+    fn test_status_error_message_expires() {
+        let action = Action::SetStatusMessage {
+            message: "Failed to save config: permission denied".to_string(),
+            is_error: true,
+        };
+        let mut state = test_reduce(AppState::default(), action).0;
+
+        for _ in 0..159 {
+            state = test_reduce(state, Action::Tick).0;
+        }
+        assert!(state.system.status_is_error);
+
+        state = test_reduce(state, Action::Tick).0;
+        assert!(!state.system.status_is_error);
+        assert_eq!(
+            state.system.status_message,
+            Some(DEFAULT_STATUS_MESSAGE.to_string())
+        );
+    }
+
     #[test]
     fn test_set_status_message_without_error() {
         let state = AppState::default();
@@ -401,7 +535,7 @@ mod tests {
     #[test]
     fn test_toggle_boolean_use_unicode_updates_box_chars() {
         let mut state = AppState::default();
-        state.system.config.display.use_unicode = true;
+        Arc::make_mut(&mut state.system.config).display.use_unicode = true;
 
         let action =
             Action::SettingsAction(SettingsAction::ToggleBoolean("use_unicode".to_string()));
@@ -432,6 +566,42 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_toggle_boolean_restore_session() {
+        let state = AppState::default();
+        let action =
+            Action::SettingsAction(SettingsAction::ToggleBoolean("restore_session".to_string()));
+
+        let (new_state, _) = test_reduce(state, action);
+
+        assert!(!new_state.system.config.restore_session);
+    }
+
+    #[test]
+    fn test_update_setting_text_values() {
+        let mut state = AppState::default();
+        for (key, value) in [
+            ("refresh_interval", "90"),
+            ("time_format", "%I:%M %p"),
+            ("log_file", "/tmp/nhl.log"),
+            ("error_fg", "#ff8800"),
+        ] {
+            let action = Action::SettingsAction(SettingsAction::UpdateSetting {
+                key: key.to_string(),
+                value: value.to_string(),
+            });
+            state = test_reduce(state, action).0;
+        }
+
+        assert_eq!(state.system.config.refresh_interval, 90);
+        assert_eq!(state.system.config.time_format, "%I:%M %p");
+        assert_eq!(state.system.config.log_file, "/tmp/nhl.log");
+        assert_eq!(
+            state.system.config.display.error_fg,
+            Color::Rgb(255, 136, 0)
+        );
+    }
+
     #[test]
     fn test_toggle_boolean_unknown_setting() {
         let state = AppState::default();
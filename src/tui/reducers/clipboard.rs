@@ -0,0 +1,325 @@
+use std::path::Path;
+
+use tracing::debug;
+
+use crate::config::{active_profile, profile_dir};
+use crate::tui::action::Action;
+use crate::tui::clipboard;
+use crate::tui::component::Effect;
+use crate::tui::component_store::ComponentStateStore;
+use crate::tui::components::scores_tab::ScoresTabState;
+use crate::tui::components::standings_tab::StandingsTabState;
+use crate::tui::constants::{SCORES_TAB_PATH, STANDINGS_TAB_PATH};
+use crate::tui::document::{get_stacked_document_handler, Document, FocusableId};
+use crate::tui::reducers::standings::standings_document;
+use crate::tui::share::{boxscore_summary, schedule_game_summary};
+use crate::tui::state::{AppState, DataState};
+use crate::tui::types::{StackedDocument, Tab};
+use crate::tui::CellValue;
+
+/// File the game summary is written to when the clipboard is unavailable
+const SUMMARY_FALLBACK_FILE: &str = "game-summary.txt";
+
+/// Copy the focused player link (name and id), team link (abbrev) or game link (id) to the clipboard
+pub fn copy_focused_link(
+    state: AppState,
+    component_states: &ComponentStateStore,
+) -> (AppState, Effect) {
+    let mut new_state = state;
+
+    let text = focused_link_cell(&new_state, component_states).and_then(|cell| match cell {
+        CellValue::PlayerLink { display, player_id } => {
+            Some(format!("{} ({})", display.trim(), player_id))
+        }
+        CellValue::TeamLink { team_abbrev, .. } => Some(team_abbrev),
+        CellValue::GameLink { game_id, .. } => Some(game_id.to_string()),
+        CellValue::Text(_) => None,
+    });
+    let Some(text) = text else {
+        new_state
+            .system
+            .set_status_message("Nothing to copy".to_string());
+        return (new_state, Effect::None);
+    };
+
+    debug!("CLIPBOARD: Copying '{}'", text);
+    let effect = Effect::Async(Box::pin(async move {
+        match clipboard::copy(&text) {
+            Ok(()) => Action::SetStatusMessage {
+                message: format!("Copied {}", text),
+                is_error: false,
+            },
+            Err(e) => Action::SetStatusMessage {
+                message: format!("Failed to copy: {}", e),
+                is_error: true,
+            },
+        }
+    }));
+    (new_state, effect)
+}
+
+/// Copy a summary line of the open boxscore or the focused scores card to the clipboard
+///
+/// Falls back to writing the line to a file in the config directory when the
+/// clipboard can't be reached.
+pub fn copy_game_summary(
+    state: AppState,
+    component_states: &ComponentStateStore,
+) -> (AppState, Effect) {
+    let mut new_state = state;
+
+    let use_unicode = new_state.system.config.display.use_unicode;
+    let text = focused_game_id(&new_state, component_states)
+        .and_then(|game_id| game_summary_text(new_state.data.displayed(), game_id, use_unicode));
+    let Some(text) = text else {
+        new_state
+            .system
+            .set_status_message("No game to summarize".to_string());
+        return (new_state, Effect::None);
+    };
+
+    debug!("CLIPBOARD: Copying game summary '{}'", text);
+    let fallback = profile_dir(active_profile()).map(|dir| dir.join(SUMMARY_FALLBACK_FILE));
+    let effect = Effect::Async(Box::pin(async move {
+        let Err(e) = clipboard::copy(&text) else {
+            return Action::SetStatusMessage {
+                message: format!("Copied {}", text),
+                is_error: false,
+            };
+        };
+        debug!("CLIPBOARD: Copy failed ({}), writing summary to a file", e);
+        match fallback.map(|path| write_summary(&path, &text).map(|()| path)) {
+            Some(Ok(path)) => Action::SetStatusMessage {
+                message: format!("Clipboard unavailable, wrote summary to {}", path.display()),
+                is_error: false,
+            },
+            Some(Err(e)) => Action::SetStatusMessage {
+                message: format!("Failed to save summary: {}", e),
+                is_error: true,
+            },
+            None => Action::SetStatusMessage {
+                message: format!("Failed to copy: {}", e),
+                is_error: true,
+            },
+        }
+    }));
+    (new_state, effect)
+}
+
+/// The game of the open boxscore, or of the focused card on the scores tab
+fn focused_game_id(state: &AppState, component_states: &ComponentStateStore) -> Option<i64> {
+    if let Some(entry) = state.navigation.document_stack.last() {
+        return match entry.document {
+            StackedDocument::Boxscore { game_id, .. } => Some(game_id),
+            _ => None,
+        };
+    }
+    if state.navigation.current_tab != Tab::Scores {
+        return None;
+    }
+
+    let nav = &component_states
+        .get::<ScoresTabState>(SCORES_TAB_PATH)?
+        .doc_nav;
+    match nav.focusable_ids.get(nav.focus_index?)? {
+        FocusableId::GameLink(game_id) => Some(*game_id),
+        _ => None,
+    }
+}
+
+/// Summary line from the boxscore when loaded, otherwise from the schedule
+fn game_summary_text(data: &DataState, game_id: i64, use_unicode: bool) -> Option<String> {
+    let info = data.game_info.get(&game_id);
+    if let Some(boxscore) = data.boxscores.get(&game_id) {
+        let stars = info
+            .and_then(|info| info.summary.as_ref())
+            .and_then(|summary| summary.three_stars.as_deref());
+        return Some(boxscore_summary(boxscore, stars, use_unicode));
+    }
+
+    let game = data
+        .schedule
+        .as_ref()
+        .as_ref()?
+        .games
+        .iter()
+        .find(|game| game.id == game_id)?;
+    Some(schedule_game_summary(game, info, use_unicode))
+}
+
+fn write_summary(path: &Path, text: &str) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(path, format!("{}\n", text))
+}
+
+/// The focused table cell of the top stacked document, or of the standings tab
+fn focused_link_cell(
+    state: &AppState,
+    component_states: &ComponentStateStore,
+) -> Option<CellValue> {
+    let data = state.data.displayed();
+
+    let (document, focused_id): (Box<dyn Document>, FocusableId) = if let Some(entry) =
+        state.navigation.document_stack.last()
+    {
+        let document = get_stacked_document_handler(&entry.document).document(&entry.nav, data)?;
+        let focused_id = entry.nav.focusable_ids.get(entry.nav.focus_index?)?;
+        (document, focused_id.clone())
+    } else if state.navigation.current_tab == Tab::Standings {
+        let standings_state = component_states.get::<StandingsTabState>(STANDINGS_TAB_PATH)?;
+        let standings = data.standings.as_ref()?;
+        let nav = &standings_state.doc_nav;
+        let focused_id = nav.focusable_ids.get(nav.focus_index?)?;
+        (
+            standings_document(state, standings_state.view, standings),
+            focused_id.clone(),
+        )
+    } else {
+        return None;
+    };
+
+    document.focused_cell(&focused_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::commands::standings::GroupBy;
+    use crate::fixtures::{create_mock_boxscore, create_mock_schedule};
+    use crate::tui::state::DocumentStackEntry;
+    use crate::tui::testing::create_test_standings;
+
+    fn standings_state(focus_index: Option<usize>) -> (AppState, ComponentStateStore) {
+        let mut state = AppState::default();
+        state.navigation.current_tab = Tab::Standings;
+        let standings = Arc::new(create_test_standings());
+        state.data.standings = Some(Arc::clone(&standings));
+
+        let document = standings_document(&state, GroupBy::League, &standings);
+        let mut tab_state = StandingsTabState {
+            view: GroupBy::League,
+            ..Default::default()
+        };
+        tab_state.doc_nav.focusable_ids = document.focusable_ids();
+        tab_state.doc_nav.focus_index = focus_index;
+
+        let mut component_states = ComponentStateStore::new();
+        component_states.insert(STANDINGS_TAB_PATH.to_string(), tab_state);
+        (state, component_states)
+    }
+
+    #[test]
+    fn test_focused_link_cell_in_standings() {
+        let (state, component_states) = standings_state(Some(0));
+
+        let cell = focused_link_cell(&state, &component_states);
+
+        assert!(matches!(
+            cell,
+            Some(CellValue::TeamLink { ref team_abbrev, .. }) if team_abbrev == "FLA"
+        ));
+    }
+
+    #[test]
+    fn test_copy_without_focus_sets_status() {
+        let (state, component_states) = standings_state(None);
+
+        let (new_state, effect) = copy_focused_link(state, &component_states);
+
+        assert!(matches!(effect, Effect::None));
+        assert_eq!(
+            new_state.system.status_message.as_deref(),
+            Some("Nothing to copy")
+        );
+    }
+
+    #[test]
+    fn test_copy_focused_link_returns_clipboard_effect() {
+        let (state, component_states) = standings_state(Some(0));
+
+        let (_, effect) = copy_focused_link(state, &component_states);
+
+        assert!(matches!(effect, Effect::Async(_)));
+    }
+
+    fn boxscore_entry(game_id: i64) -> DocumentStackEntry {
+        DocumentStackEntry::new(StackedDocument::Boxscore {
+            game_id,
+            away_abbrev: "TOR".to_string(),
+            home_abbrev: "BOS".to_string(),
+            away_score: 0,
+            home_score: 0,
+        })
+    }
+
+    #[test]
+    fn test_game_summary_of_open_boxscore() {
+        let mut state = AppState::default();
+        let boxscore = create_mock_boxscore(2024020005);
+        state.data.boxscores = Arc::new(HashMap::from([(2024020005, Arc::new(boxscore))]));
+        state
+            .navigation
+            .document_stack
+            .push(boxscore_entry(2024020005));
+        let component_states = ComponentStateStore::new();
+
+        let game_id = focused_game_id(&state, &component_states);
+        let text = game_id.and_then(|id| game_summary_text(&state.data, id, true));
+
+        assert_eq!(game_id, Some(2024020005));
+        assert!(text.unwrap().ends_with("(F)"));
+    }
+
+    #[test]
+    fn test_game_summary_of_focused_scores_card() {
+        let mut state = AppState::default();
+        state.navigation.current_tab = Tab::Scores;
+        state.data.schedule = Arc::new(Some(create_mock_schedule(None)));
+        let mut scores_state = ScoresTabState::default();
+        scores_state.doc_nav.focusable_ids = vec![
+            FocusableId::GameLink(2024020001),
+            FocusableId::GameLink(2024020003),
+        ];
+        scores_state.doc_nav.focus_index = Some(1);
+        let mut component_states = ComponentStateStore::new();
+        component_states.insert(SCORES_TAB_PATH.to_string(), scores_state);
+
+        let game_id = focused_game_id(&state, &component_states);
+        let text = game_id.and_then(|id| game_summary_text(&state.data, id, true));
+
+        assert_eq!(text.as_deref(), Some("NYR 2 @ NJD 3 (F)"));
+    }
+
+    #[test]
+    fn test_copy_game_summary_without_game_sets_status() {
+        let (state, component_states) = standings_state(Some(0));
+
+        let (new_state, effect) = copy_game_summary(state, &component_states);
+
+        assert!(matches!(effect, Effect::None));
+        assert_eq!(
+            new_state.system.status_message.as_deref(),
+            Some("No game to summarize")
+        );
+    }
+
+    #[test]
+    fn test_write_summary_creates_parent_dirs() {
+        let dir = std::env::temp_dir().join(format!("nhl-summary-{}", std::process::id()));
+        let path = dir.join("nested").join(SUMMARY_FALLBACK_FILE);
+
+        write_summary(&path, "NJD 3 @ BUF 2 (F/OT)").unwrap();
+
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "NJD 3 @ BUF 2 (F/OT)\n"
+        );
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
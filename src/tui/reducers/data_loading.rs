@@ -1,16 +1,22 @@
 use std::sync::Arc;
 use std::time::SystemTime;
+
+use chrono::Local;
+use nhl_api::{GameLog, GameType, Position};
 use tracing::debug;
 
+use crate::season::current_season_id;
 use crate::tui::action::Action;
 use crate::tui::component::Effect;
 #[cfg(feature = "development")]
 use crate::tui::constants::DEMO_TAB_PATH;
-use crate::tui::constants::{SCORES_TAB_PATH, STANDINGS_TAB_PATH};
+use crate::tui::constants::STANDINGS_TAB_PATH;
 #[cfg(feature = "development")]
 use crate::tui::document::Document;
+use crate::tui::reducers::scores::rebuild_scores_focusable_metadata;
 use crate::tui::reducers::standings::rebuild_standings_focusable_metadata;
-use crate::tui::state::{AppState, LoadingKey};
+use crate::tui::state::{AppState, FetchKind, LoadingKey};
+use crate::tui::types::StackedDocument;
 
 /// Handle all data loading actions (API responses)
 ///
@@ -25,11 +31,17 @@ pub fn reduce_data_loading(
     component_states: &mut crate::tui::component_store::ComponentStateStore,
 ) -> Result<(AppState, Effect), AppState> {
     match action {
-        Action::StandingsLoaded(result) => Ok(handle_standings_loaded(
-            state,
-            result.clone(),
-            component_states,
-        )),
+        Action::StandingsLoaded(season, result) => {
+            if *season != state.ui.season.season {
+                debug!("DATA: Ignoring standings for season {:?}", season);
+                return Ok((state, Effect::None));
+            }
+            Ok(handle_standings_loaded(
+                state,
+                result.clone(),
+                component_states,
+            ))
+        }
         Action::ScheduleLoaded(result) => Ok(handle_schedule_loaded(
             state,
             result.clone(),
@@ -46,16 +58,52 @@ pub fn reduce_data_loading(
             team_abbrev.clone(),
             result.clone(),
         )),
+        Action::TeamRosterLoaded(team_abbrev, result) => Ok(handle_current_roster_loaded(
+            state,
+            team_abbrev.clone(),
+            result.clone(),
+        )),
+        Action::TeamScheduleLoaded(team_abbrev, result) => Ok(handle_team_schedule_loaded(
+            state,
+            team_abbrev.clone(),
+            result.clone(),
+        )),
         Action::PlayerStatsLoaded(player_id, result) => Ok(handle_player_stats_loaded(
             state,
             *player_id,
             result.clone(),
         )),
-        Action::RefreshData => Ok(handle_refresh_data(state)),
+        Action::PlayerGameLogLoaded(player_id, result) => Ok(handle_player_game_log_loaded(
+            state,
+            *player_id,
+            result.clone(),
+        )),
+        Action::CachedDataLoaded(loaded) => {
+            handle_cached_data_loaded(state, loaded, component_states)
+        }
+        Action::LoadData => Ok(handle_refresh_data(state, false)),
+        Action::RefreshData => Ok(handle_refresh_data(state, true)),
+        Action::ShiftSeason(delta) => Ok(handle_shift_season(state, *delta)),
         _ => Err(state),
     }
 }
 
+/// Apply cached data without ending the fetch that is still on its way
+///
+/// Loading keys the wrapped action clears are put back, so the refresh
+/// indicator stays up until the network result arrives.
+#[allow(clippy::result_large_err)]
+fn handle_cached_data_loaded(
+    state: AppState,
+    loaded: &Action,
+    component_states: &mut crate::tui::component_store::ComponentStateStore,
+) -> Result<(AppState, Effect), AppState> {
+    let loading = state.data.loading.clone();
+    let (mut new_state, effect) = reduce_data_loading(state, loaded, component_states)?;
+    new_state.data.loading.extend(loading);
+    Ok((new_state, effect))
+}
+
 fn handle_standings_loaded(
     state: AppState,
     result: Result<Vec<nhl_api::Standing>, String>,
@@ -66,8 +114,9 @@ fn handle_standings_loaded(
     match result {
         Ok(standings) => {
             debug!("DATA: Loaded {} standings", standings.len());
-            new_state.data.standings = Arc::new(Some(standings.clone()));
-            new_state.data.errors.clear();
+            new_state.data.standings = Some(Arc::new(standings.clone()));
+            new_state.data.fill_pinned();
+            new_state.data.record_fetch_success(FetchKind::Standings);
             new_state.data.loading.remove(&LoadingKey::Standings);
 
             // Rebuild demo document focusable data in component state
@@ -80,6 +129,7 @@ fn handle_standings_loaded(
                 {
                     let demo_doc = DemoDocument::new(Some(standings.clone()));
                     demo_state.focusable_positions = demo_doc.focusable_positions();
+                    demo_state.content_height = demo_doc.calculate_height();
                     demo_state.focusable_ids = demo_doc.focusable_ids();
                     demo_state.focusable_row_positions = demo_doc.focusable_row_positions();
                     demo_state.link_targets = demo_doc.focusable_link_targets();
@@ -91,8 +141,8 @@ fn handle_standings_loaded(
         }
         Err(e) => {
             debug!("DATA: Failed to load standings: {}", e);
-            new_state.data.errors.insert(
-                "standings".to_string(),
+            new_state.data.record_fetch_error(
+                FetchKind::Standings,
                 format!("Failed to load standings: {}", e),
             );
             new_state.data.loading.remove(&LoadingKey::Standings);
@@ -107,6 +157,7 @@ fn handle_standings_loaded(
                 {
                     let demo_doc = DemoDocument::new(None);
                     demo_state.focusable_positions = demo_doc.focusable_positions();
+                    demo_state.content_height = demo_doc.calculate_height();
                     demo_state.focusable_ids = demo_doc.focusable_ids();
                     demo_state.focusable_row_positions = demo_doc.focusable_row_positions();
                     demo_state.link_targets = demo_doc.focusable_link_targets();
@@ -139,37 +190,23 @@ fn handle_schedule_loaded(
     match result {
         Ok(schedule) => {
             debug!("DATA: Loaded schedule with {} games", schedule.games.len());
+            if let Some(previous) = new_state.data.schedule.as_ref() {
+                // A different date is a navigation, not a refresh
+                if previous.date == schedule.date {
+                    for game_id in changed_schedule_games(previous, &schedule) {
+                        new_state
+                            .ui
+                            .scores
+                            .mark_score_change(game_id, new_state.system.frame_count);
+                    }
+                }
+            }
             new_state.data.schedule = Arc::new(Some(schedule.clone()));
-            new_state.data.errors.clear();
-            // TODO: Remove Schedule loading key - needs date string
+            new_state.data.fill_pinned();
+            new_state.data.record_fetch_success(FetchKind::Schedule);
+            new_state.data.clear_schedule_loading();
 
-            // Rebuild scores tab focusable metadata from the document
-            use crate::tui::components::score_boxes_document::ScoreBoxesDocument;
-            use crate::tui::components::scores_tab::ScoresTabState;
-            use crate::tui::document::Document;
-
-            if let Some(scores_state) = component_states.get_mut::<ScoresTabState>(SCORES_TAB_PATH)
-            {
-                // Calculate boxes_per_row from terminal width
-                let boxes_per_row =
-                    ScoreBoxesDocument::boxes_per_row_for_width(new_state.system.terminal_width);
-
-                // Create the document to extract focusable metadata
-                // animation_frame doesn't affect focusable positions, so use 0
-                let doc = ScoreBoxesDocument::new(
-                    Arc::new(Some(schedule.clone())),
-                    new_state.data.game_info.clone(),
-                    boxes_per_row,
-                    scores_state.game_date.clone(),
-                    0,
-                );
-
-                // Use document methods to get focusable metadata
-                scores_state.doc_nav.focusable_positions = doc.focusable_positions();
-                scores_state.doc_nav.focusable_heights = doc.focusable_heights();
-                scores_state.doc_nav.focusable_ids = doc.focusable_ids();
-                scores_state.doc_nav.focusable_row_positions = doc.focusable_row_positions();
-            }
+            rebuild_scores_focusable_metadata(&new_state, component_states);
 
             // Return fetch effects for started games
             // This eliminates the need for runtime to compare old/new state
@@ -197,17 +234,39 @@ fn handle_schedule_loaded(
         }
         Err(e) => {
             debug!("DATA: Failed to load schedule: {}", e);
-            new_state.data.errors.insert(
-                "error".to_string(),
+            new_state.data.record_fetch_error(
+                FetchKind::Schedule,
                 format!("Failed to load schedule: {}", e),
             );
-            // TODO: Remove Schedule loading key - needs date string
+            new_state.data.clear_schedule_loading();
         }
     }
 
     (new_state, Effect::None)
 }
 
+/// Games in `current` whose score differs from `previous`, or that `previous` lacked
+fn changed_schedule_games(
+    previous: &nhl_api::DailySchedule,
+    current: &nhl_api::DailySchedule,
+) -> Vec<i64> {
+    current
+        .games
+        .iter()
+        .filter(|game| {
+            previous
+                .games
+                .iter()
+                .find(|old| old.id == game.id)
+                .is_none_or(|old| {
+                    (old.away_team.score, old.home_team.score)
+                        != (game.away_team.score, game.home_team.score)
+                })
+        })
+        .map(|game| game.id)
+        .collect()
+}
+
 fn handle_game_details_loaded(
     state: AppState,
     game_id: i64,
@@ -225,8 +284,21 @@ fn handle_game_details_loaded(
                 Arc::make_mut(&mut new_state.data.period_scores).insert(game_id, period_scores);
             }
 
+            if let Some(previous) = new_state.data.game_info.get(&game_id) {
+                if (previous.away_team.score, previous.home_team.score)
+                    != (game_matchup.away_team.score, game_matchup.home_team.score)
+                {
+                    new_state
+                        .ui
+                        .scores
+                        .mark_score_change(game_id, new_state.system.frame_count);
+                }
+            }
+
             // Store game info
             Arc::make_mut(&mut new_state.data.game_info).insert(game_id, game_matchup);
+            new_state.data.fill_pinned();
+            new_state.data.record_fetch_success(FetchKind::GameDetails);
 
             new_state
                 .data
@@ -235,6 +307,10 @@ fn handle_game_details_loaded(
         }
         Err(e) => {
             debug!("DATA: Failed to load game details for {}: {}", game_id, e);
+            new_state.data.record_fetch_error(
+                FetchKind::GameDetails,
+                format!("Failed to load game details: {}", e),
+            );
             new_state
                 .data
                 .loading
@@ -256,7 +332,9 @@ fn handle_boxscore_loaded(
         Ok(boxscore) => {
             debug!("DATA: Loaded boxscore for game {}", game_id);
             // Focusable metadata is populated on-demand by StackedDocumentHandler
-            Arc::make_mut(&mut new_state.data.boxscores).insert(game_id, boxscore);
+            Arc::make_mut(&mut new_state.data.boxscores).insert(game_id, Arc::new(boxscore));
+            new_state.data.fill_pinned();
+            new_state.data.record_fetch_success(FetchKind::Boxscore);
             new_state
                 .data
                 .loading
@@ -264,8 +342,8 @@ fn handle_boxscore_loaded(
         }
         Err(e) => {
             debug!("DATA: Failed to load boxscore for {}: {}", game_id, e);
-            new_state.data.errors.insert(
-                "error".to_string(),
+            new_state.data.record_fetch_error(
+                FetchKind::Boxscore,
                 format!("Failed to load boxscore: {}", e),
             );
             new_state
@@ -291,6 +369,10 @@ fn handle_team_roster_loaded(
             // Focusable metadata is populated on-demand by StackedDocumentHandler
             Arc::make_mut(&mut new_state.data.team_roster_stats)
                 .insert(team_abbrev.clone(), roster);
+            new_state.data.fill_pinned();
+            new_state
+                .data
+                .record_fetch_success(FetchKind::TeamRosterStats);
             new_state
                 .data
                 .loading
@@ -301,8 +383,8 @@ fn handle_team_roster_loaded(
                 "DATA: Failed to load team roster for {}: {}",
                 team_abbrev, e
             );
-            new_state.data.errors.insert(
-                "error".to_string(),
+            new_state.data.record_fetch_error(
+                FetchKind::TeamRosterStats,
                 format!("Failed to load team roster: {}", e),
             );
             new_state
@@ -315,6 +397,71 @@ fn handle_team_roster_loaded(
     (new_state, Effect::None)
 }
 
+fn handle_current_roster_loaded(
+    state: AppState,
+    team_abbrev: String,
+    result: Result<nhl_api::Roster, String>,
+) -> (AppState, Effect) {
+    let mut new_state = state;
+
+    match result {
+        Ok(roster) => {
+            debug!("DATA: Loaded current roster for team {}", team_abbrev);
+            Arc::make_mut(&mut new_state.data.team_rosters).insert(team_abbrev.clone(), roster);
+            new_state.data.fill_pinned();
+        }
+        // Only sweater numbers are lost, so this isn't surfaced in the status bar
+        Err(e) => debug!(
+            "DATA: Failed to load current roster for {}: {}",
+            team_abbrev, e
+        ),
+    }
+    new_state
+        .data
+        .loading
+        .remove(&LoadingKey::TeamRoster(team_abbrev));
+
+    (new_state, Effect::None)
+}
+
+fn handle_team_schedule_loaded(
+    state: AppState,
+    team_abbrev: String,
+    result: Result<Vec<nhl_api::ScheduleGame>, String>,
+) -> (AppState, Effect) {
+    let mut new_state = state;
+
+    let mut games = match result {
+        Ok(games) => {
+            debug!(
+                "DATA: Loaded {} scheduled games for {}",
+                games.len(),
+                team_abbrev
+            );
+            new_state.data.record_fetch_success(FetchKind::TeamSchedule);
+            games
+        }
+        Err(e) => {
+            debug!("DATA: Failed to load schedule for {}: {}", team_abbrev, e);
+            new_state.data.record_fetch_error(
+                FetchKind::TeamSchedule,
+                format!("Failed to load team schedule: {}", e),
+            );
+            // Stored empty so the schedule section stops showing the loading animation
+            Vec::new()
+        }
+    };
+    games.sort_by(|a, b| a.start_time_utc.cmp(&b.start_time_utc));
+    Arc::make_mut(&mut new_state.data.team_schedules).insert(team_abbrev.clone(), games);
+    new_state.data.fill_pinned();
+    new_state
+        .data
+        .loading
+        .remove(&LoadingKey::TeamSchedule(team_abbrev));
+
+    (new_state, Effect::None)
+}
+
 fn handle_player_stats_loaded(
     state: AppState,
     player_id: i64,
@@ -327,15 +474,29 @@ fn handle_player_stats_loaded(
             debug!("DATA: Loaded stats for player {}", player_id);
             // Focusable metadata is populated on-demand by StackedDocumentHandler
             Arc::make_mut(&mut new_state.data.player_data).insert(player_id, stats);
+            new_state.data.fill_pinned();
+            new_state.data.record_fetch_success(FetchKind::PlayerStats);
             new_state
                 .data
                 .loading
                 .remove(&LoadingKey::PlayerStats(player_id));
+
+            // Player compare documents don't show the game log, so only detail documents fetch it
+            let showing_detail = new_state.navigation.document_stack.iter().any(|entry| {
+                matches!(
+                    entry.document,
+                    StackedDocument::PlayerDetail { player_id: id, .. } if id == player_id
+                )
+            });
+            if showing_detail {
+                let effect = player_game_log_effect(&new_state, player_id);
+                return (new_state, effect);
+            }
         }
         Err(e) => {
             debug!("DATA: Failed to load player stats for {}: {}", player_id, e);
-            new_state.data.errors.insert(
-                "error".to_string(),
+            new_state.data.record_fetch_error(
+                FetchKind::PlayerStats,
                 format!("Failed to load player stats: {}", e),
             );
             new_state
@@ -348,15 +509,175 @@ fn handle_player_stats_loaded(
     (new_state, Effect::None)
 }
 
-fn handle_refresh_data(state: AppState) -> (AppState, Effect) {
+fn handle_player_game_log_loaded(
+    state: AppState,
+    player_id: i64,
+    result: Result<Vec<GameLog>, String>,
+) -> (AppState, Effect) {
     let mut new_state = state;
-    new_state.system.last_refresh = Some(SystemTime::now());
+
+    match result {
+        Ok(games) => {
+            debug!(
+                "DATA: Loaded {} game log entries for player {}",
+                games.len(),
+                player_id
+            );
+            Arc::make_mut(&mut new_state.data.player_game_logs).insert(player_id, games);
+            new_state.data.fill_pinned();
+            new_state
+                .data
+                .record_fetch_success(FetchKind::PlayerGameLog);
+        }
+        Err(e) => {
+            debug!("DATA: Failed to load game log for {}: {}", player_id, e);
+            new_state.data.record_fetch_error(
+                FetchKind::PlayerGameLog,
+                format!("Failed to load player game log: {}", e),
+            );
+        }
+    }
+    new_state
+        .data
+        .loading
+        .remove(&LoadingKey::PlayerGameLog(player_id));
+
     (new_state, Effect::None)
 }
 
+/// Fetch the current season game log of a loaded skater, unless already loaded or loading
+///
+/// Goalies are skipped: their game log entries have a different shape.
+pub fn player_game_log_effect(state: &AppState, player_id: i64) -> Effect {
+    let Some(player) = state.data.player_data.get(&player_id) else {
+        return Effect::None;
+    };
+    if player.position == Position::Goalie
+        || state.data.player_game_logs.contains_key(&player_id)
+        || state
+            .data
+            .loading
+            .contains(&LoadingKey::PlayerGameLog(player_id))
+    {
+        return Effect::None;
+    }
+
+    let latest_nhl_season = || {
+        player
+            .season_totals
+            .as_ref()?
+            .iter()
+            .filter(|s| s.game_type == GameType::RegularSeason && s.league_abbrev == "NHL")
+            .map(|s| s.season)
+            .max()
+    };
+    match player
+        .featured_stats
+        .as_ref()
+        .map(|stats| stats.season)
+        .or_else(latest_nhl_season)
+    {
+        Some(season) => {
+            debug!(
+                "DATA: Requesting game log for player {} season {}",
+                player_id, season
+            );
+            Effect::FetchPlayerGameLog(player_id, season)
+        }
+        None => Effect::None,
+    }
+}
+
+/// Move the standings to another season and fetch it
+fn handle_shift_season(state: AppState, delta: i64) -> (AppState, Effect) {
+    let mut new_state = state;
+    let previous = new_state.ui.season.season;
+    new_state
+        .ui
+        .season
+        .shift_season(delta, current_season_id(Local::now().date_naive()));
+    let season = new_state.ui.season.season;
+    if season == previous {
+        return (new_state, Effect::None);
+    }
+
+    debug!("DATA: Standings season changed to {:?}", season);
+    new_state.data.standings = None;
+    new_state.data.errors.remove(&FetchKind::Standings);
+    new_state.data.loading.insert(LoadingKey::Standings);
+    (new_state, Effect::FetchStandings(season))
+}
+
+/// Refetch what is on screen: standings, the scores date and the open boxscore
+///
+/// With `refetch`, the requests skip the in-memory and disk caches. Requests
+/// already in flight are not repeated; if all of them are, this is a no-op.
+fn handle_refresh_data(state: AppState, refetch: bool) -> (AppState, Effect) {
+    let mut new_state = state;
+    let date = new_state.ui.scores.game_date.clone();
+    let open_boxscore = match new_state.navigation.document_stack.last() {
+        Some(entry) => match &entry.document {
+            StackedDocument::Boxscore { game_id, .. } => Some(*game_id),
+            _ => None,
+        },
+        None => None,
+    };
+
+    let season = new_state.ui.season.season;
+    let mut requests = vec![
+        (
+            LoadingKey::Standings,
+            if refetch {
+                Effect::RefetchStandings(season)
+            } else {
+                Effect::FetchStandings(season)
+            },
+        ),
+        (
+            LoadingKey::Schedule(date.to_api_string()),
+            if refetch {
+                Effect::RefetchSchedule(date)
+            } else {
+                Effect::FetchSchedule(date)
+            },
+        ),
+    ];
+    if let Some(game_id) = open_boxscore {
+        requests.push((
+            LoadingKey::Boxscore(game_id),
+            if refetch {
+                Effect::RefetchBoxscore(game_id)
+            } else {
+                Effect::FetchBoxscore(game_id)
+            },
+        ));
+    }
+
+    let mut effects = Vec::new();
+    for (key, effect) in requests {
+        if new_state.data.loading.insert(key) {
+            effects.push(effect);
+        }
+    }
+    if effects.is_empty() {
+        debug!("DATA: Refresh already in flight, ignoring");
+        return (new_state, Effect::None);
+    }
+
+    new_state.system.last_refresh = Some(SystemTime::now());
+    (new_state, Effect::Batch(effects))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::fixtures::{
+        create_mock_game_matchup, create_mock_player_game_log, create_mock_player_landing,
+        create_mock_roster, create_mock_schedule, create_mock_team_schedule,
+    };
+    use crate::tui::component_store::ComponentStateStore;
+    use crate::tui::state::DocumentStackEntry;
+    use nhl_api::GameDate;
 
     #[test]
     fn test_game_details_loaded_stores_game_info() {
@@ -388,4 +709,293 @@ mod tests {
         //
         // This is verified by the code review showing lines 88-92 extract period_scores
     }
+
+    #[test]
+    fn test_schedule_refresh_marks_changed_and_new_games() {
+        let mut component_states = ComponentStateStore::new();
+        let mut previous = create_mock_schedule(None);
+        let new_game = previous.games.pop().unwrap();
+        let mut state = AppState::default();
+        state.data.schedule = Arc::new(Some(previous.clone()));
+        state.system.frame_count = 7;
+
+        let mut current = previous;
+        current.games[1].home_team.score = Some(4);
+        current.games.push(new_game.clone());
+
+        let (state, _) = handle_schedule_loaded(state, Ok(current), &mut component_states);
+
+        let mut changed: Vec<_> = state.ui.scores.score_changes.into_iter().collect();
+        changed.sort();
+        assert_eq!(changed, vec![(2024020002, 7), (new_game.id, 7)]);
+    }
+
+    #[test]
+    fn test_schedule_for_another_date_marks_nothing() {
+        let mut component_states = ComponentStateStore::new();
+        let mut state = AppState::default();
+        state.data.schedule = Arc::new(Some(create_mock_schedule(None)));
+
+        let other_date = create_mock_schedule(Some(GameDate::today().add_days(1)));
+        let (state, _) = handle_schedule_loaded(state, Ok(other_date), &mut component_states);
+
+        assert!(state.ui.scores.score_changes.is_empty());
+    }
+
+    #[test]
+    fn test_game_details_refresh_marks_score_change() {
+        const GAME_ID: i64 = 2024020002;
+        let (state, _) = handle_game_details_loaded(
+            AppState::default(),
+            GAME_ID,
+            Ok(create_mock_game_matchup(GAME_ID)),
+        );
+        // The first fetch has nothing to compare against
+        assert!(state.ui.scores.score_changes.is_empty());
+
+        let (state, _) =
+            handle_game_details_loaded(state, GAME_ID, Ok(create_mock_game_matchup(GAME_ID)));
+        assert!(state.ui.scores.score_changes.is_empty());
+
+        let mut scored = create_mock_game_matchup(GAME_ID);
+        scored.home_team.score += 1;
+        let (state, _) = handle_game_details_loaded(state, GAME_ID, Ok(scored));
+        assert!(state.ui.scores.score_changes.contains_key(&GAME_ID));
+    }
+
+    fn with_player_detail(player_id: i64) -> AppState {
+        let mut state = AppState::default();
+        state
+            .navigation
+            .document_stack
+            .push(DocumentStackEntry::new(StackedDocument::PlayerDetail {
+                player_id,
+                sweater_number: None,
+                last_name: "McDavid".to_string(),
+            }));
+        state
+    }
+
+    #[test]
+    fn test_player_stats_loaded_fetches_game_log_for_detail() {
+        let (state, effect) = handle_player_stats_loaded(
+            with_player_detail(97),
+            97,
+            Ok(create_mock_player_landing(97)),
+        );
+        assert!(matches!(effect, Effect::FetchPlayerGameLog(97, 20242025)));
+
+        // Not fetched again once loaded
+        let mut state = state;
+        Arc::make_mut(&mut state.data.player_game_logs).insert(97, Vec::new());
+        assert!(matches!(player_game_log_effect(&state, 97), Effect::None));
+    }
+
+    #[test]
+    fn test_player_stats_loaded_skips_game_log_for_goalies_and_compare() {
+        let mut goalie = create_mock_player_landing(31);
+        goalie.position = Position::Goalie;
+        let (_, effect) = handle_player_stats_loaded(with_player_detail(31), 31, Ok(goalie));
+        assert!(matches!(effect, Effect::None));
+
+        // Loaded for a compare document only
+        let (_, effect) =
+            handle_player_stats_loaded(AppState::default(), 97, Ok(create_mock_player_landing(97)));
+        assert!(matches!(effect, Effect::None));
+    }
+
+    #[test]
+    fn test_player_game_log_loaded_stores_games() {
+        let games = create_mock_player_game_log(97, 20242025, GameType::RegularSeason).game_log;
+        let mut state = AppState::default();
+        state.data.loading.insert(LoadingKey::PlayerGameLog(97));
+
+        let (state, _) = handle_player_game_log_loaded(state, 97, Ok(games.clone()));
+
+        assert_eq!(state.data.player_game_logs.get(&97), Some(&games));
+        assert!(!state.data.loading.contains(&LoadingKey::PlayerGameLog(97)));
+    }
+
+    #[test]
+    fn test_team_roster_loaded_stores_roster() {
+        let mut state = AppState::default();
+        state
+            .data
+            .loading
+            .insert(LoadingKey::TeamRoster("BOS".to_string()));
+
+        let (state, _) =
+            handle_current_roster_loaded(state, "BOS".to_string(), Ok(create_mock_roster("BOS")));
+
+        assert!(state.data.team_rosters.contains_key("BOS"));
+        assert!(!state
+            .data
+            .loading
+            .contains(&LoadingKey::TeamRoster("BOS".to_string())));
+    }
+
+    #[test]
+    fn test_team_schedule_loaded_sorts_games() {
+        let mut games = create_mock_team_schedule("BOS").games;
+        games.reverse();
+
+        let (state, _) =
+            handle_team_schedule_loaded(AppState::default(), "BOS".to_string(), Ok(games));
+
+        let ids: Vec<i64> = state.data.team_schedules["BOS"]
+            .iter()
+            .map(|g| g.id)
+            .collect();
+        assert_eq!(ids, vec![2024020100, 2024020101, 2024020102, 2024020103]);
+    }
+
+    #[test]
+    fn test_team_schedule_error_stores_empty_schedule() {
+        let (state, _) =
+            handle_team_schedule_loaded(AppState::default(), "BOS".to_string(), Err("boom".into()));
+
+        assert_eq!(state.data.team_schedules.get("BOS"), Some(&Vec::new()));
+        assert!(state.data.errors.contains_key(&FetchKind::TeamSchedule));
+    }
+
+    #[test]
+    fn test_refresh_fetches_standings_schedule_and_open_boxscore() {
+        let mut state = AppState::default();
+        state
+            .navigation
+            .document_stack
+            .push(DocumentStackEntry::new(StackedDocument::Boxscore {
+                game_id: 2024020001,
+                away_abbrev: "BOS".to_string(),
+                home_abbrev: "TOR".to_string(),
+                away_score: 2,
+                home_score: 1,
+            }));
+
+        let (state, effect) = handle_refresh_data(state, true);
+
+        let Effect::Batch(effects) = effect else {
+            panic!("Expected Batch effect, got {:?}", effect);
+        };
+        assert!(matches!(
+            effects.as_slice(),
+            [
+                Effect::RefetchStandings(None),
+                Effect::RefetchSchedule(_),
+                Effect::RefetchBoxscore(2024020001)
+            ]
+        ));
+        assert!(state
+            .data
+            .loading
+            .contains(&LoadingKey::Boxscore(2024020001)));
+        assert!(state.data.refresh_in_flight());
+        assert!(state.system.last_refresh.is_some());
+    }
+
+    #[test]
+    fn test_load_data_goes_through_the_caches() {
+        let (_, effect) = handle_refresh_data(AppState::default(), false);
+
+        let Effect::Batch(effects) = effect else {
+            panic!("Expected Batch effect, got {:?}", effect);
+        };
+        assert!(matches!(
+            effects.as_slice(),
+            [Effect::FetchStandings(None), Effect::FetchSchedule(_)]
+        ));
+    }
+
+    #[test]
+    fn test_refresh_while_loading_is_noop() {
+        let (state, _) = handle_refresh_data(AppState::default(), true);
+        let loading = state.data.loading.clone();
+
+        let (state, effect) = handle_refresh_data(state, true);
+
+        assert!(matches!(effect, Effect::None));
+        assert_eq!(state.data.loading, loading);
+    }
+
+    #[test]
+    fn test_shift_season_fetches_past_standings() {
+        let mut state = AppState::default();
+        state.data.standings = Some(Arc::new(vec![]));
+
+        let (state, effect) = handle_shift_season(state, -1);
+
+        let season = state.ui.season.season.expect("a past season is selected");
+        assert!(matches!(effect, Effect::FetchStandings(Some(s)) if s == season));
+        assert!(state.data.standings.is_none());
+        assert!(state.data.loading.contains(&LoadingKey::Standings));
+    }
+
+    #[test]
+    fn test_shift_season_past_current_is_noop() {
+        let (state, effect) = handle_shift_season(AppState::default(), 1);
+
+        assert!(matches!(effect, Effect::None));
+        assert_eq!(state.ui.season.season, None);
+    }
+
+    #[test]
+    fn test_standings_for_other_season_are_ignored() {
+        let mut state = AppState::default();
+        state.ui.season.season = Some(20222023);
+        let mut component_states = ComponentStateStore::new();
+
+        let (state, _) = reduce_data_loading(
+            state,
+            &Action::StandingsLoaded(None, Ok(vec![])),
+            &mut component_states,
+        )
+        .unwrap_or_else(|_| panic!("StandingsLoaded should be handled"));
+        assert!(state.data.standings.is_none());
+
+        let (state, _) = reduce_data_loading(
+            state,
+            &Action::StandingsLoaded(Some(20222023), Ok(vec![])),
+            &mut component_states,
+        )
+        .unwrap_or_else(|_| panic!("StandingsLoaded should be handled"));
+        assert!(state.data.standings.is_some());
+    }
+
+    #[test]
+    fn test_schedule_loaded_clears_refresh_in_flight() {
+        let (state, _) = handle_refresh_data(AppState::default(), true);
+        let mut component_states = ComponentStateStore::new();
+
+        let (state, _) = handle_schedule_loaded(state, Err("boom".into()), &mut component_states);
+
+        assert!(!state
+            .data
+            .loading
+            .iter()
+            .any(|key| matches!(key, LoadingKey::Schedule(_))));
+    }
+
+    #[test]
+    fn test_cached_data_keeps_loading_until_fetch_arrives() {
+        let mut state = AppState::default();
+        state.data.loading.insert(LoadingKey::Standings);
+        let mut component_states = ComponentStateStore::new();
+
+        let (state, _) = reduce_data_loading(
+            state,
+            &Action::CachedDataLoaded(Box::new(Action::StandingsLoaded(None, Ok(vec![])))),
+            &mut component_states,
+        )
+        .unwrap_or_else(|_| panic!("CachedDataLoaded should be handled"));
+        assert!(state.data.standings.is_some());
+        assert!(state.data.loading.contains(&LoadingKey::Standings));
+
+        let (state, _) = reduce_data_loading(
+            state,
+            &Action::StandingsLoaded(None, Ok(vec![])),
+            &mut component_states,
+        )
+        .unwrap_or_else(|_| panic!("StandingsLoaded should be handled"));
+        assert!(!state.data.loading.contains(&LoadingKey::Standings));
+    }
 }
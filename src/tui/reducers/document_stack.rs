@@ -1,10 +1,24 @@
+use std::path::PathBuf;
+
+use crossterm::event::{KeyCode, KeyEvent};
 use tracing::debug;
 
-use crate::tui::action::Action;
+use crate::tui::action::{Action, FindAction};
 use crate::tui::component::Effect;
-use crate::tui::document::get_stacked_document_handler;
-use crate::tui::state::{AppState, DocumentStackEntry, LoadingKey};
-use crate::tui::types::StackedDocument;
+use crate::tui::component_store::ComponentStateStore;
+use crate::tui::components::boxscore_document::boxscore_table_sort;
+use crate::tui::components::standings_tab::StandingsTabState;
+use crate::tui::components::SettingsDocument;
+use crate::tui::constants::STANDINGS_TAB_PATH;
+use crate::tui::document::find::matching_lines;
+use crate::tui::document::markdown::{document_to_markdown, write_markdown};
+use crate::tui::document::{get_stacked_document_handler, Document, FindState, FocusContext};
+use crate::tui::document_nav::{handle_message, scroll_to_line, DocumentNavMsg};
+use crate::tui::reducers::data_loading::player_game_log_effect;
+use crate::tui::reducers::standings::standings_document;
+use crate::tui::state::{AppState, ComparePending, DocumentStackEntry, LoadingKey};
+use crate::tui::table::TableSort;
+use crate::tui::types::{StackedDocument, Tab};
 
 /// Handle all document stack management actions
 ///
@@ -18,32 +32,277 @@ pub fn reduce_document_stack(
     match action {
         Action::PushDocument(doc) => Ok(push_document(state, doc.clone())),
         Action::PopDocument => Ok(pop_document(state)),
+        Action::PopToDepth(depth) => Ok(pop_to_depth(state, *depth)),
         Action::StackedDocumentKey(key) => Ok(stacked_document_key(state, *key)),
+        Action::StackedDocumentNav(msg) => Ok(stacked_document_nav(state, msg)),
+        Action::Find(action) => Ok(find_in_document(state, action)),
+        Action::ToggleColumnLegend => Ok(toggle_column_legend(state)),
+        Action::ClearComparePending => Ok(clear_compare_pending(state)),
         _ => Err(state),
     }
 }
 
 /// Handle key events routed to stacked documents
-fn stacked_document_key(state: AppState, key: crossterm::event::KeyEvent) -> (AppState, Effect) {
+fn stacked_document_key(state: AppState, key: KeyEvent) -> (AppState, Effect) {
+    if key.code == KeyCode::Char('c') {
+        return compare_focused_player(state);
+    }
+
     let mut new_state = state;
-    let width = new_state.system.terminal_width;
+    let context = FocusContext::default()
+        .with_width(new_state.system.terminal_width)
+        .with_compact_spacing(new_state.system.config.display.compact_spacing);
 
     if let Some(entry) = new_state.navigation.document_stack.last_mut() {
         let handler = get_stacked_document_handler(&entry.document);
-        let effect = handler.handle_key(key, &mut entry.nav, &new_state.data, width);
+        let effect = handler.handle_key(key, &mut entry.nav, new_state.data.displayed(), &context);
         return (new_state, effect);
     }
 
     (new_state, Effect::None)
 }
 
-fn push_document(state: AppState, doc: StackedDocument) -> (AppState, Effect) {
-    debug!("DOCUMENT_STACK: Pushing document onto stack: {:?}", doc);
+/// Apply a navigation message to the top stacked document
+fn stacked_document_nav(state: AppState, msg: &DocumentNavMsg) -> (AppState, Effect) {
+    let mut new_state = state;
+    let context = FocusContext::default()
+        .with_width(new_state.system.terminal_width)
+        .with_compact_spacing(new_state.system.config.display.compact_spacing);
+
+    if let Some(entry) = new_state.navigation.document_stack.last_mut() {
+        // Focus targets and autoscroll need current metadata, as in handle_key
+        get_stacked_document_handler(&entry.document).populate_focusable_metadata(
+            &mut entry.nav,
+            new_state.data.displayed(),
+            &context,
+        );
+        let effect = handle_message(&mut entry.nav, msg);
+        return (new_state, effect);
+    }
+
+    (new_state, Effect::None)
+}
+
+/// Open the legend of the focused table's columns, or close it
+///
+/// Explains in the status bar when nothing focused has a legend.
+fn toggle_column_legend(state: AppState) -> (AppState, Effect) {
+    let mut new_state = state;
+    if new_state.navigation.column_legend.take().is_some() {
+        return (new_state, Effect::None);
+    }
+    let context = FocusContext::default()
+        .with_width(new_state.system.terminal_width)
+        .with_compact_spacing(new_state.system.config.display.compact_spacing);
+
+    let Some(entry) = new_state.navigation.document_stack.last_mut() else {
+        return (new_state, Effect::None);
+    };
+    let nav = &mut entry.nav;
+    let handler = get_stacked_document_handler(&entry.document);
+    let data = new_state.data.displayed();
+    handler.populate_focusable_metadata(nav, data, &context);
+    let legend = nav
+        .focus_index
+        .and_then(|index| nav.focusable_ids.get(index))
+        .zip(handler.document(nav, data))
+        .map(|(id, document)| document.column_legend(id))
+        .unwrap_or_default();
+
+    if legend.is_empty() {
+        new_state
+            .system
+            .set_status_message("No column legend here; focus a row of a stats table".to_string());
+    } else {
+        new_state.navigation.column_legend = Some(legend);
+    }
+    (new_state, Effect::None)
+}
+
+/// Edit the find on the top stacked document and jump to its matches
+///
+/// Matches are recomputed on every query change, starting from the top of the
+/// viewport. Jumping focuses the first focusable element on the match line, so
+/// Enter opens a matched player or team.
+fn find_in_document(state: AppState, action: &FindAction) -> (AppState, Effect) {
     let mut new_state = state;
-    new_state
+    let context = FocusContext::default()
+        .with_width(new_state.system.terminal_width)
+        .with_compact_spacing(new_state.system.config.display.compact_spacing);
+
+    let Some(entry) = new_state.navigation.document_stack.last_mut() else {
+        return (new_state, Effect::None);
+    };
+    let nav = &mut entry.nav;
+
+    let mut find = match (action, nav.find.take()) {
+        (FindAction::Start, _) => FindState::prompt(),
+        (FindAction::Clear, find) | (_, find @ None) => {
+            if find.is_some() {
+                new_state.system.reset_status_message();
+            }
+            return (new_state, Effect::None);
+        }
+        (_, Some(find)) => find,
+    };
+    match action {
+        FindAction::Input(c) => find.query.push(*c),
+        FindAction::Backspace => {
+            find.query.pop();
+        }
+        FindAction::Submit => find.editing = false,
+        FindAction::Next => find.step(true),
+        FindAction::Prev => find.step(false),
+        FindAction::Start | FindAction::Clear => {}
+    }
+
+    let handler = get_stacked_document_handler(&entry.document);
+    let data = new_state.data.displayed();
+    if matches!(action, FindAction::Input(_) | FindAction::Backspace) {
+        let spans = handler
+            .document(nav, data)
+            .map(|document| document.searchable_spans(&context))
+            .unwrap_or_default();
+        find.set_matches(matching_lines(&spans, &find.query), nav.scroll_offset);
+    }
+    if let Some(line) = find.current_line() {
+        handler.populate_focusable_metadata(nav, data, &context);
+        if let Some(index) = nav.focusable_positions.iter().position(|&y| y == line) {
+            nav.focus_index = Some(index);
+        }
+        scroll_to_line(nav, line);
+    }
+
+    let status = find.status();
+    nav.find = Some(find);
+    new_state.system.set_status_message(status);
+
+    (new_state, Effect::None)
+}
+
+/// Mark the focused player for comparison, or compare them with the marked one
+fn compare_focused_player(state: AppState) -> (AppState, Effect) {
+    let mut new_state = state;
+
+    let focused = new_state
         .navigation
         .document_stack
-        .push(DocumentStackEntry::new(doc.clone()));
+        .last()
+        .and_then(|entry| {
+            get_stacked_document_handler(&entry.document)
+                .focused_player(&entry.nav, new_state.data.displayed())
+        });
+    let Some((player_id, _, last_name)) = focused else {
+        return (new_state, Effect::None);
+    };
+
+    match new_state.navigation.compare_pending.take() {
+        Some(pending) if pending.player_id != player_id => {
+            debug!(
+                "DOCUMENT_STACK: Comparing players {} and {}",
+                pending.player_id, player_id
+            );
+            new_state.system.reset_status_message();
+            push_document(
+                new_state,
+                StackedDocument::PlayerCompare {
+                    player_a: pending.player_id,
+                    player_b: player_id,
+                    last_name_a: pending.last_name,
+                    last_name_b: last_name,
+                },
+            )
+        }
+        _ => {
+            new_state.system.set_status_message(format!(
+                "Compare A: {} - press c on another player (Esc to cancel)",
+                last_name
+            ));
+            new_state.navigation.compare_pending = Some(ComparePending {
+                player_id,
+                last_name,
+            });
+            (new_state, Effect::None)
+        }
+    }
+}
+
+fn clear_compare_pending(state: AppState) -> (AppState, Effect) {
+    let mut new_state = state;
+    if new_state.navigation.compare_pending.take().is_some() {
+        new_state
+            .system
+            .set_status_message("Compare cancelled".to_string());
+    }
+    (new_state, Effect::None)
+}
+
+/// Write the active document to `<document id>.md` in the current directory
+pub fn export_document(
+    state: AppState,
+    component_states: &ComponentStateStore,
+) -> (AppState, Effect) {
+    let mut new_state = state;
+
+    let Some(document) = active_document(&new_state, component_states) else {
+        new_state
+            .system
+            .set_status_message("Nothing to export".to_string());
+        return (new_state, Effect::None);
+    };
+
+    let path = PathBuf::from(format!("{}.md", document.id()));
+    let markdown = document_to_markdown(document.as_ref());
+    debug!("DOCUMENT_STACK: Exporting document to {}", path.display());
+
+    let effect = Effect::Async(Box::pin(async move {
+        match write_markdown(&path, &markdown) {
+            Ok(()) => Action::SetStatusMessage {
+                message: format!("Exported to {}", path.display()),
+                is_error: false,
+            },
+            Err(e) => Action::SetStatusMessage {
+                message: format!("Failed to export {}: {}", path.display(), e),
+                is_error: true,
+            },
+        }
+    }));
+    (new_state, effect)
+}
+
+/// Document on top of the stack, or the current tab's own document when the stack is empty
+fn active_document(
+    state: &AppState,
+    component_states: &ComponentStateStore,
+) -> Option<Box<dyn Document>> {
+    let data = state.data.displayed();
+    if let Some(entry) = state.navigation.document_stack.last() {
+        return get_stacked_document_handler(&entry.document).document(&entry.nav, data);
+    }
+    match state.navigation.current_tab {
+        Tab::Standings => {
+            let view = component_states
+                .get::<StandingsTabState>(STANDINGS_TAB_PATH)?
+                .view;
+            let standings = data.standings.as_ref()?;
+            Some(standings_document(state, view, standings))
+        }
+        Tab::Settings => Some(Box::new(SettingsDocument::new(
+            state.ui.settings.selected_category,
+            state.system.config.clone(),
+        ))),
+        _ => None,
+    }
+}
+
+fn push_document(state: AppState, doc: StackedDocument) -> (AppState, Effect) {
+    debug!("DOCUMENT_STACK: Pushing document onto stack: {:?}", doc);
+    let mut new_state = state;
+    let mut entry = DocumentStackEntry::new(doc.clone());
+    if let StackedDocument::Boxscore { .. } = &doc {
+        entry.nav.sort = default_boxscore_sort(&new_state);
+    }
+    new_state.navigation.document_stack.push(entry);
 
     // Return fetch effect directly based on document type
     // This eliminates the need for runtime to compare old/new state
@@ -66,19 +325,32 @@ fn push_document(state: AppState, doc: StackedDocument) -> (AppState, Effect) {
             }
         }
         StackedDocument::TeamDetail { abbrev } => {
+            let loading = &new_state.data.loading;
+            let mut fetches = Vec::new();
             if !new_state.data.team_roster_stats.contains_key(abbrev)
-                && !new_state
-                    .data
-                    .loading
-                    .contains(&LoadingKey::TeamRosterStats(abbrev.clone()))
+                && !loading.contains(&LoadingKey::TeamRosterStats(abbrev.clone()))
             {
                 debug!(
                     "DOCUMENT_STACK: Requesting team roster stats fetch for team={}",
                     abbrev
                 );
-                Effect::FetchTeamRosterStats(abbrev.clone())
-            } else {
-                Effect::None
+                fetches.push(Effect::FetchTeamRosterStats(abbrev.clone()));
+            }
+            // The roster only adds sweater numbers, so it is fetched alongside the stats
+            if !new_state.data.team_rosters.contains_key(abbrev)
+                && !loading.contains(&LoadingKey::TeamRoster(abbrev.clone()))
+            {
+                fetches.push(Effect::FetchTeamRoster(abbrev.clone()));
+            }
+            if !new_state.data.team_schedules.contains_key(abbrev)
+                && !loading.contains(&LoadingKey::TeamSchedule(abbrev.clone()))
+            {
+                fetches.push(Effect::FetchTeamSchedule(abbrev.clone()));
+            }
+            match fetches.len() {
+                0 => Effect::None,
+                1 => fetches.remove(0),
+                _ => Effect::Batch(fetches),
             }
         }
         StackedDocument::PlayerDetail { player_id, .. } => {
@@ -94,7 +366,27 @@ fn push_document(state: AppState, doc: StackedDocument) -> (AppState, Effect) {
                 );
                 Effect::FetchPlayerStats(*player_id)
             } else {
+                player_game_log_effect(&new_state, *player_id)
+            }
+        }
+        StackedDocument::PlayerCompare {
+            player_a, player_b, ..
+        } => {
+            let fetches: Vec<Effect> = [*player_a, *player_b]
+                .into_iter()
+                .filter(|id| {
+                    !new_state.data.player_data.contains_key(id)
+                        && !new_state
+                            .data
+                            .loading
+                            .contains(&LoadingKey::PlayerStats(*id))
+                })
+                .map(Effect::FetchPlayerStats)
+                .collect();
+            if fetches.is_empty() {
                 Effect::None
+            } else {
+                Effect::Batch(fetches)
             }
         }
     };
@@ -102,6 +394,14 @@ fn push_document(state: AppState, doc: StackedDocument) -> (AppState, Effect) {
     (new_state, fetch_effect)
 }
 
+/// Sort from `display.boxscore_sort`, or None (API order) when unset or invalid
+///
+/// Invalid values are reported when the config is read.
+fn default_boxscore_sort(state: &AppState) -> Option<TableSort> {
+    let value = state.system.config.display.boxscore_sort.as_deref()?;
+    boxscore_table_sort(value)
+}
+
 fn pop_document(state: AppState) -> (AppState, Effect) {
     debug!("DOCUMENT_STACK: Popping document from stack");
     let mut new_state = state;
@@ -116,16 +416,22 @@ fn pop_document(state: AppState) -> (AppState, Effect) {
                     .remove(&LoadingKey::Boxscore(*game_id));
             }
             StackedDocument::TeamDetail { abbrev } => {
-                new_state
-                    .data
-                    .loading
-                    .remove(&LoadingKey::TeamRosterStats(abbrev.clone()));
+                let loading = &mut new_state.data.loading;
+                loading.remove(&LoadingKey::TeamRosterStats(abbrev.clone()));
+                loading.remove(&LoadingKey::TeamRoster(abbrev.clone()));
+                loading.remove(&LoadingKey::TeamSchedule(abbrev.clone()));
             }
             StackedDocument::PlayerDetail { player_id, .. } => {
-                new_state
-                    .data
-                    .loading
-                    .remove(&LoadingKey::PlayerStats(*player_id));
+                let loading = &mut new_state.data.loading;
+                loading.remove(&LoadingKey::PlayerStats(*player_id));
+                loading.remove(&LoadingKey::PlayerGameLog(*player_id));
+            }
+            StackedDocument::PlayerCompare {
+                player_a, player_b, ..
+            } => {
+                let loading = &mut new_state.data.loading;
+                loading.remove(&LoadingKey::PlayerStats(*player_a));
+                loading.remove(&LoadingKey::PlayerStats(*player_b));
             }
         }
 
@@ -143,10 +449,23 @@ fn pop_document(state: AppState) -> (AppState, Effect) {
     (new_state, Effect::None)
 }
 
+/// Pop documents until `depth` remain, as when an ancestor breadcrumb is chosen
+fn pop_to_depth(state: AppState, depth: usize) -> (AppState, Effect) {
+    let mut new_state = state;
+    while new_state.navigation.document_stack.len() > depth {
+        new_state = pop_document(new_state).0;
+    }
+    (new_state, Effect::None)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::fixtures::create_mock_boxscore;
     use crate::tui::document_nav::DocumentNavState;
+    use crate::tui::state::DEFAULT_STATUS_MESSAGE;
+    use crate::tui::types::SettingsCategory;
+    use std::sync::Arc;
 
     fn make_entry(document: StackedDocument, focus_index: Option<usize>) -> DocumentStackEntry {
         DocumentStackEntry {
@@ -172,8 +491,18 @@ mod tests {
             new_state.navigation.document_stack[0].nav.focus_index,
             Some(0)
         );
-        // Should return fetch effect since we don't have the data
-        assert!(matches!(effect, Effect::FetchTeamRosterStats(ref abbrev) if abbrev == "BOS"));
+        // Should return fetch effects since we don't have the data
+        match effect {
+            Effect::Batch(effects) => assert!(matches!(
+                &effects[..],
+                [
+                    Effect::FetchTeamRosterStats(stats),
+                    Effect::FetchTeamRoster(roster),
+                    Effect::FetchTeamSchedule(schedule),
+                ] if stats == "BOS" && roster == "BOS" && schedule == "BOS"
+            )),
+            other => panic!("Expected batch of team fetches, got {:?}", other),
+        }
     }
 
     fn test_boxscore(game_id: i64) -> StackedDocument {
@@ -198,6 +527,33 @@ mod tests {
         assert!(matches!(effect, Effect::FetchBoxscore(id) if id == game_id));
     }
 
+    #[test]
+    fn test_push_boxscore_applies_configured_sort() {
+        let mut state = AppState::default();
+        Arc::make_mut(&mut state.system.config)
+            .display
+            .boxscore_sort = Some("points_desc".to_string());
+
+        let (new_state, _) = push_document(state, test_boxscore(2024020001));
+
+        assert_eq!(
+            new_state.navigation.document_stack[0].nav.sort,
+            boxscore_table_sort("points_desc")
+        );
+    }
+
+    #[test]
+    fn test_push_boxscore_ignores_invalid_configured_sort() {
+        let mut state = AppState::default();
+        Arc::make_mut(&mut state.system.config)
+            .display
+            .boxscore_sort = Some("sideways".to_string());
+
+        let (new_state, _) = push_document(state, test_boxscore(2024020001));
+
+        assert_eq!(new_state.navigation.document_stack[0].nav.sort, None);
+    }
+
     #[test]
     fn test_push_document_no_fetch_if_already_loading() {
         let mut state = AppState::default();
@@ -213,6 +569,64 @@ mod tests {
         assert!(matches!(effect, Effect::None));
     }
 
+    #[test]
+    fn test_export_document_without_stack_sets_status() {
+        let (new_state, effect) = export_document(AppState::default(), &ComponentStateStore::new());
+
+        assert!(matches!(effect, Effect::None));
+        assert_eq!(
+            new_state.system.status_message.as_deref(),
+            Some("Nothing to export")
+        );
+    }
+
+    #[test]
+    fn test_export_document_without_data_sets_status() {
+        let mut state = AppState::default();
+        state
+            .navigation
+            .document_stack
+            .push(make_entry(test_boxscore(2024020001), None));
+
+        let (_, effect) = export_document(state, &ComponentStateStore::new());
+
+        assert!(matches!(effect, Effect::None));
+    }
+
+    #[test]
+    fn test_active_document_resolves_settings_tab() {
+        let mut state = AppState::default();
+        state.navigation.current_tab = Tab::Settings;
+        state.ui.settings.selected_category = SettingsCategory::Display;
+        let component_states = ComponentStateStore::new();
+
+        let document = active_document(&state, &component_states).unwrap();
+        assert_eq!(document.id(), "settings_display");
+        let markdown = document_to_markdown(document.as_ref());
+        assert!(markdown.starts_with("## Display Settings\n\nTheme: none\n\nUse Unicode: true"));
+
+        let (_, effect) = export_document(state, &component_states);
+        assert!(matches!(effect, Effect::Async(_)));
+    }
+
+    #[test]
+    fn test_active_document_prefers_stacked_document() {
+        let mut state = AppState::default();
+        state.navigation.current_tab = Tab::Settings;
+        state.data.boxscores = Arc::new(
+            [(2024020001, Arc::new(create_mock_boxscore(2024020001)))]
+                .into_iter()
+                .collect(),
+        );
+        state
+            .navigation
+            .document_stack
+            .push(make_entry(test_boxscore(2024020001), None));
+
+        let document = active_document(&state, &ComponentStateStore::new()).unwrap();
+        assert_eq!(document.id(), "boxscore_2024020001");
+    }
+
     #[test]
     fn test_pop_document_clears_loading_state() {
         let mut state = AppState::default();
@@ -233,4 +647,299 @@ mod tests {
             .loading
             .contains(&LoadingKey::Boxscore(game_id)));
     }
+
+    #[test]
+    fn test_pop_to_depth() {
+        let mut state = AppState::default();
+        for document in [
+            StackedDocument::TeamDetail {
+                abbrev: "TOR".to_string(),
+            },
+            player_detail(8479318, "Matthews"),
+            test_boxscore(2024020001),
+        ] {
+            state
+                .navigation
+                .document_stack
+                .push(make_entry(document, None));
+        }
+        state.data.loading.insert(LoadingKey::Boxscore(2024020001));
+        state.data.loading.insert(LoadingKey::PlayerStats(8479318));
+
+        let (state, _) = pop_to_depth(state, 1);
+        assert_eq!(state.navigation.document_stack.len(), 1);
+        assert!(matches!(
+            state.navigation.document_stack[0].document,
+            StackedDocument::TeamDetail { .. }
+        ));
+        assert!(state.data.loading.is_empty());
+
+        // Deeper than the stack is a no-op; 0 returns to the tab
+        let (state, _) = pop_to_depth(state, 3);
+        assert_eq!(state.navigation.document_stack.len(), 1);
+        let (state, _) = pop_to_depth(state, 0);
+        assert!(state.navigation.document_stack.is_empty());
+    }
+
+    fn player_detail(player_id: i64, last_name: &str) -> StackedDocument {
+        StackedDocument::PlayerDetail {
+            player_id,
+            sweater_number: None,
+            last_name: last_name.to_string(),
+        }
+    }
+
+    fn press_c(state: AppState) -> (AppState, Effect) {
+        stacked_document_key(state, KeyEvent::from(KeyCode::Char('c')))
+    }
+
+    #[test]
+    fn test_compare_flow() {
+        use crate::fixtures::create_mock_player_landing;
+        use std::sync::Arc;
+
+        let mut state = AppState::default();
+        state.data.player_data = Arc::new(
+            [1, 2]
+                .into_iter()
+                .map(|id| (id, create_mock_player_landing(id)))
+                .collect(),
+        );
+        state
+            .navigation
+            .document_stack
+            .push(make_entry(player_detail(1, "McDavid"), None));
+
+        // First c marks player A
+        let (mut state, effect) = press_c(state);
+        assert!(matches!(effect, Effect::None));
+        assert_eq!(
+            state.navigation.compare_pending,
+            Some(ComparePending {
+                player_id: 1,
+                last_name: "McDavid".to_string(),
+            })
+        );
+        assert!(state
+            .system
+            .status_message
+            .as_deref()
+            .unwrap()
+            .starts_with("Compare A: McDavid"));
+
+        // Second c on another player opens the compare document
+        state
+            .navigation
+            .document_stack
+            .push(make_entry(player_detail(2, "McDavid"), None));
+        let (state, effect) = press_c(state);
+        assert!(state.navigation.compare_pending.is_none());
+        assert!(matches!(effect, Effect::None));
+        match &state.navigation.document_stack.last().unwrap().document {
+            StackedDocument::PlayerCompare {
+                player_a, player_b, ..
+            } => assert_eq!((*player_a, *player_b), (1, 2)),
+            other => panic!("Expected PlayerCompare, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_stacked_document_nav_focuses_by_index() {
+        use crate::fixtures::{create_mock_player_game_log, create_mock_player_landing};
+        use nhl_api::GameType;
+        use std::sync::Arc;
+
+        let mut state = AppState::default();
+        state.data.player_data = Arc::new([(1, create_mock_player_landing(1))].into());
+        let log = create_mock_player_game_log(1, 20242025, GameType::RegularSeason);
+        state.data.player_game_logs = Arc::new([(1, log.game_log)].into());
+        state
+            .navigation
+            .document_stack
+            .push(make_entry(player_detail(1, "McDavid"), None));
+
+        // Metadata is populated on demand, so the index can be focused right away
+        let (state, effect) = stacked_document_nav(state, &DocumentNavMsg::FocusIndex(3));
+        assert!(matches!(effect, Effect::None));
+        assert_eq!(state.navigation.document_stack[0].nav.focus_index, Some(3));
+
+        // Out of range indexes leave focus alone
+        let (state, _) = stacked_document_nav(state, &DocumentNavMsg::FocusIndex(usize::MAX));
+        assert_eq!(state.navigation.document_stack[0].nav.focus_index, Some(3));
+    }
+
+    #[test]
+    fn test_compare_fetches_missing_players() {
+        let (state, effect) = push_document(
+            AppState::default(),
+            StackedDocument::PlayerCompare {
+                player_a: 1,
+                player_b: 2,
+                last_name_a: "A".to_string(),
+                last_name_b: "B".to_string(),
+            },
+        );
+
+        assert_eq!(state.navigation.document_stack.len(), 1);
+        match effect {
+            Effect::Batch(effects) => {
+                assert!(matches!(
+                    effects[..],
+                    [Effect::FetchPlayerStats(1), Effect::FetchPlayerStats(2)]
+                ))
+            }
+            _ => panic!("Expected batch of player fetches"),
+        }
+    }
+
+    #[test]
+    fn test_clear_compare_pending() {
+        let mut state = AppState::default();
+        state.navigation.compare_pending = Some(ComparePending {
+            player_id: 1,
+            last_name: "McDavid".to_string(),
+        });
+
+        let (state, _) = reduce_document_stack(state, &Action::ClearComparePending).unwrap();
+
+        assert!(state.navigation.compare_pending.is_none());
+        assert_eq!(
+            state.system.status_message.as_deref(),
+            Some("Compare cancelled")
+        );
+    }
+
+    #[test]
+    fn test_c_without_focused_player_does_nothing() {
+        let mut state = AppState::default();
+        state
+            .navigation
+            .document_stack
+            .push(make_entry(player_detail(1, "McDavid"), None));
+
+        // No player data loaded yet
+        let (state, _) = press_c(state);
+
+        assert!(state.navigation.compare_pending.is_none());
+    }
+
+    fn find(state: AppState, actions: &[FindAction]) -> AppState {
+        actions.iter().fold(state, |state, action| {
+            reduce_document_stack(state, &Action::Find(action.clone()))
+                .unwrap()
+                .0
+        })
+    }
+
+    #[test]
+    fn test_find_jumps_to_matches() {
+        use crate::fixtures::{create_mock_player_game_log, create_mock_player_landing};
+        use nhl_api::GameType;
+
+        let mut state = AppState::default();
+        state.data.player_data = Arc::new([(1, create_mock_player_landing(1))].into());
+        let log = create_mock_player_game_log(1, 20242025, GameType::RegularSeason);
+        state.data.player_game_logs = Arc::new([(1, log.game_log)].into());
+        let mut entry = make_entry(player_detail(1, "McDavid"), None);
+        entry.nav.viewport_height = 5;
+        state.navigation.document_stack.push(entry);
+
+        // Game log opponents are on lines 10-14: vs TOR, @ MTL, vs BOS, @ OTT, vs NJD
+        let state = find(
+            state,
+            &[
+                FindAction::Start,
+                FindAction::Input('V'),
+                FindAction::Input('s'),
+            ],
+        );
+        let nav = &state.navigation.document_stack[0].nav;
+        let found = nav.find.as_ref().unwrap();
+        assert_eq!(found.matches, vec![10, 12, 14]);
+        assert_eq!(found.current_line(), Some(10));
+        assert_eq!(nav.scroll_offset, 7);
+        assert_eq!(nav.focusable_positions[nav.focus_index.unwrap()], 10);
+        assert_eq!(state.system.status_message.as_deref(), Some("/Vs"));
+
+        let state = find(state, &[FindAction::Submit, FindAction::Prev]);
+        let nav = &state.navigation.document_stack[0].nav;
+        assert_eq!(nav.find.as_ref().unwrap().current_line(), Some(14));
+        assert_eq!(nav.focusable_positions[nav.focus_index.unwrap()], 14);
+        assert!(state
+            .system
+            .status_message
+            .as_deref()
+            .unwrap()
+            .starts_with("Find 'Vs': 3 of 3"));
+
+        let state = find(state, &[FindAction::Clear]);
+        assert!(state.navigation.document_stack[0].nav.find.is_none());
+        assert_eq!(
+            state.system.status_message.as_deref(),
+            Some(DEFAULT_STATUS_MESSAGE)
+        );
+    }
+
+    #[test]
+    fn test_find_without_matches() {
+        let mut state = AppState::default();
+        state
+            .navigation
+            .document_stack
+            .push(make_entry(player_detail(1, "McDavid"), None));
+
+        let state = find(
+            state,
+            &[
+                FindAction::Start,
+                FindAction::Input('x'),
+                FindAction::Submit,
+                FindAction::Next,
+            ],
+        );
+        let nav = &state.navigation.document_stack[0].nav;
+        assert_eq!(nav.find.as_ref().unwrap().current_line(), None);
+        assert_eq!(nav.scroll_offset, 0);
+        assert_eq!(
+            state.system.status_message.as_deref(),
+            Some("No matches for 'x'")
+        );
+    }
+
+    fn toggle_legend(state: AppState) -> AppState {
+        reduce_document_stack(state, &Action::ToggleColumnLegend)
+            .unwrap()
+            .0
+    }
+
+    #[test]
+    fn test_toggle_closes_open_column_legend() {
+        let mut state = AppState::default();
+        state
+            .navigation
+            .document_stack
+            .push(make_entry(test_boxscore(2024020001), None));
+        state.navigation.column_legend = Some(vec![("G".to_string(), "Goals".to_string())]);
+
+        let state = toggle_legend(state);
+
+        assert!(state.navigation.column_legend.is_none());
+    }
+
+    #[test]
+    fn test_column_legend_without_focused_table() {
+        let mut state = AppState::default();
+        state
+            .navigation
+            .document_stack
+            .push(make_entry(player_detail(1, "McDavid"), None));
+
+        let state = toggle_legend(state);
+
+        assert!(state.navigation.column_legend.is_none());
+        assert_eq!(
+            state.system.status_message.as_deref(),
+            Some("No column legend here; focus a row of a stats table")
+        );
+    }
 }
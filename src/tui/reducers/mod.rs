@@ -1,11 +1,15 @@
+pub mod clipboard;
 pub mod data_loading;
 pub mod document_stack;
 pub mod navigation;
+pub mod scores;
 pub mod settings;
 pub mod standings;
 
+pub use clipboard::{copy_focused_link, copy_game_summary};
 pub use data_loading::reduce_data_loading;
-pub use document_stack::reduce_document_stack;
+pub use document_stack::{export_document, reduce_document_stack};
 pub use navigation::reduce_navigation;
+pub use scores::rebuild_scores_focusable_metadata;
 pub use settings::reduce_settings;
 pub use standings::rebuild_standings_focusable_metadata;
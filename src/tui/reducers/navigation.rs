@@ -1,8 +1,9 @@
 use tracing::{debug, trace};
 
-use crate::tui::action::Action;
+use crate::tui::action::{Action, CommandPaletteAction};
 use crate::tui::component::Effect;
-use crate::tui::state::AppState;
+use crate::tui::components::command_palette::palette_results;
+use crate::tui::state::{AppState, CommandPaletteState};
 use crate::tui::types::Tab;
 
 /// Handle all navigation-related actions
@@ -18,7 +19,13 @@ pub fn reduce_navigation(state: AppState, action: &Action) -> Result<(AppState,
         Action::EnterContentFocus => Ok(enter_content_focus(state)),
         Action::ExitContentFocus => Ok(exit_content_focus(state)),
         Action::NavigateUp => Ok(navigate_up(state)),
-        Action::ToggleCommandPalette => Ok((state, Effect::None)),
+        Action::ToggleCommandPalette => Ok(toggle_command_palette(state)),
+        Action::CommandPalette(palette_action) => Ok(reduce_command_palette(state, palette_action)),
+        Action::ToggleHelp => {
+            let mut new_state = state;
+            new_state.navigation.help_open = !new_state.navigation.help_open;
+            Ok((new_state, Effect::None))
+        }
         _ => Err(state),
     }
 }
@@ -139,9 +146,58 @@ fn navigate_up(state: AppState) -> (AppState, Effect) {
     (new_state, Effect::None)
 }
 
+fn toggle_command_palette(state: AppState) -> (AppState, Effect) {
+    let mut new_state = state;
+    new_state.navigation.command_palette = match new_state.navigation.command_palette {
+        Some(_) => None,
+        None => Some(CommandPaletteState::default()),
+    };
+    (new_state, Effect::None)
+}
+
+fn reduce_command_palette(state: AppState, action: &CommandPaletteAction) -> (AppState, Effect) {
+    let mut new_state = state;
+    let Some(mut palette) = new_state.navigation.command_palette.take() else {
+        return (new_state, Effect::None);
+    };
+    let results = palette_results(&palette.query, &new_state.data);
+
+    match action {
+        CommandPaletteAction::Input(c) => {
+            palette.query.push(*c);
+            palette.selected = 0;
+        }
+        CommandPaletteAction::Backspace => {
+            palette.query.pop();
+            palette.selected = 0;
+        }
+        CommandPaletteAction::SelectNext => {
+            if palette.selected + 1 < results.len() {
+                palette.selected += 1;
+            }
+        }
+        CommandPaletteAction::SelectPrev => {
+            palette.selected = palette.selected.saturating_sub(1);
+        }
+        CommandPaletteAction::Submit => {
+            // Closing happens either way; an empty result list just dismisses the palette
+            let effect = results
+                .get(palette.selected)
+                .map_or(Effect::None, |result| {
+                    Effect::Action(Action::PushDocument(result.document()))
+                });
+            return (new_state, effect);
+        }
+    }
+
+    new_state.navigation.command_palette = Some(palette);
+    (new_state, Effect::None)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::tui::types::StackedDocument;
 
     #[test]
     fn test_navigate_to_tab() {
@@ -248,4 +304,65 @@ mod tests {
         assert!(!new_state.navigation.content_focused);
         assert!(new_state.navigation.document_stack.is_empty());
     }
+
+    fn type_query(state: AppState, query: &str) -> AppState {
+        query.chars().fold(state, |state, c| {
+            reduce_command_palette(state, &CommandPaletteAction::Input(c)).0
+        })
+    }
+
+    #[test]
+    fn test_toggle_command_palette() {
+        let (state, _) = toggle_command_palette(AppState::default());
+        assert_eq!(
+            state.navigation.command_palette,
+            Some(CommandPaletteState::default())
+        );
+
+        let (state, _) = toggle_command_palette(state);
+        assert!(state.navigation.command_palette.is_none());
+    }
+
+    #[test]
+    fn test_command_palette_typing_resets_selection() {
+        let (state, _) = toggle_command_palette(AppState::default());
+        let state = type_query(state, "new");
+        let (state, _) = reduce_command_palette(state, &CommandPaletteAction::SelectNext);
+        assert_eq!(
+            state.navigation.command_palette.as_ref().unwrap().selected,
+            1
+        );
+
+        let (state, _) = reduce_command_palette(state, &CommandPaletteAction::Backspace);
+        let palette = state.navigation.command_palette.unwrap();
+        assert_eq!(palette.query, "ne");
+        assert_eq!(palette.selected, 0);
+    }
+
+    #[test]
+    fn test_command_palette_selection_stays_in_results() {
+        let (state, _) = toggle_command_palette(AppState::default());
+        let state = type_query(state, "leafs");
+        let (state, _) = reduce_command_palette(state, &CommandPaletteAction::SelectNext);
+        assert_eq!(
+            state.navigation.command_palette.as_ref().unwrap().selected,
+            0
+        );
+    }
+
+    #[test]
+    fn test_command_palette_submit_pushes_document() {
+        let (state, _) = toggle_command_palette(AppState::default());
+        let state = type_query(state, "canadiens");
+
+        let (state, effect) = reduce_command_palette(state, &CommandPaletteAction::Submit);
+
+        assert!(state.navigation.command_palette.is_none());
+        match effect {
+            Effect::Action(Action::PushDocument(StackedDocument::TeamDetail { abbrev })) => {
+                assert_eq!(abbrev, "MTL");
+            }
+            other => panic!("Expected TeamDetail push, got {:?}", other),
+        }
+    }
 }
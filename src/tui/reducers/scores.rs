@@ -0,0 +1,117 @@
+use crate::config::ScoresLayout;
+use crate::tui::component_store::ComponentStateStore;
+use crate::tui::components::score_boxes_document::ScoreBoxesDocument;
+use crate::tui::components::scores_tab::ScoresTabState;
+use crate::tui::constants::SCORES_TAB_PATH;
+use crate::tui::document::Document;
+use crate::tui::state::AppState;
+
+/// Rebuild focusable metadata for the scores tab
+///
+/// Called when the schedule loads or the favorites or game state filter changes, so that
+/// focus positions match the games actually shown.
+pub fn rebuild_scores_focusable_metadata(
+    state: &AppState,
+    component_states: &mut ComponentStateStore,
+) {
+    if let Some(scores_state) = component_states.get_mut::<ScoresTabState>(SCORES_TAB_PATH) {
+        // Calculate boxes_per_row from terminal width
+        let boxes_per_row =
+            ScoreBoxesDocument::boxes_per_row_for_width(state.system.terminal_width);
+        let favorites = scores_state
+            .favorites_only
+            .then(|| state.system.config.known_favorite_teams());
+
+        // Create the document to extract focusable metadata
+        // animation_frame doesn't affect focusable positions, so use 0
+        let displayed = state.data.displayed();
+        let doc = ScoreBoxesDocument::new(
+            displayed.schedule.clone(),
+            displayed.game_info.clone(),
+            boxes_per_row,
+            scores_state.game_date.clone(),
+            0,
+        )
+        .with_favorites(favorites)
+        .with_state_filter(scores_state.state_filter)
+        .with_game_type(state.ui.season.game_type)
+        .with_compact(state.system.config.display.scores_layout == ScoresLayout::Compact)
+        .with_compact_spacing(state.system.config.display.compact_spacing);
+
+        // Use document methods to get focusable metadata
+        scores_state.doc_nav.focusable_positions = doc.focusable_positions();
+        scores_state.doc_nav.content_height = doc.calculate_height();
+        scores_state.doc_nav.focusable_heights = doc.focusable_heights();
+        scores_state.doc_nav.focusable_ids = doc.focusable_ids();
+        scores_state.doc_nav.focusable_row_positions = doc.focusable_row_positions();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use nhl_api::{DailySchedule, GameState, GameType, ScheduleGame, ScheduleTeam};
+
+    use super::*;
+    use crate::tui::document::FocusableId;
+
+    fn team(abbrev: &str) -> ScheduleTeam {
+        ScheduleTeam {
+            id: 1,
+            abbrev: abbrev.to_string(),
+            score: None,
+            logo: String::new(),
+            place_name: None,
+        }
+    }
+
+    fn game(id: i64, away: &str, home: &str) -> ScheduleGame {
+        ScheduleGame {
+            id,
+            game_type: GameType::RegularSeason,
+            game_date: Some("2024-01-15".to_string()),
+            start_time_utc: "2024-01-15T20:00:00Z".to_string(),
+            game_state: GameState::Future,
+            away_team: team(away),
+            home_team: team(home),
+        }
+    }
+
+    #[test]
+    fn test_rebuild_applies_favorites_filter() {
+        let mut state = AppState::default();
+        Arc::make_mut(&mut state.system.config).favorite_teams = vec!["MTL".to_string()];
+        state.data.schedule = Arc::new(Some(DailySchedule {
+            date: "2024-01-15".to_string(),
+            games: vec![game(1, "TOR", "MTL"), game(2, "BOS", "NYR")],
+            next_start_date: None,
+            previous_start_date: None,
+            number_of_games: 2,
+        }));
+
+        let mut component_states = ComponentStateStore::new();
+        component_states.insert(SCORES_TAB_PATH.to_string(), ScoresTabState::default());
+
+        rebuild_scores_focusable_metadata(&state, &mut component_states);
+        let ids = |store: &ComponentStateStore| {
+            store
+                .get::<ScoresTabState>(SCORES_TAB_PATH)
+                .unwrap()
+                .doc_nav
+                .focusable_ids
+                .clone()
+        };
+        assert_eq!(
+            ids(&component_states),
+            vec![FocusableId::GameLink(1), FocusableId::GameLink(2)]
+        );
+
+        component_states
+            .get_mut::<ScoresTabState>(SCORES_TAB_PATH)
+            .unwrap()
+            .favorites_only = true;
+        rebuild_scores_focusable_metadata(&state, &mut component_states);
+        assert_eq!(ids(&component_states), vec![FocusableId::GameLink(1)]);
+    }
+}
@@ -1,6 +1,8 @@
+use std::sync::Arc;
+
 use tracing::debug;
 
-use crate::config::Config;
+use crate::config::{parse_color, Config};
 use crate::tui::action::{Action, SettingsAction};
 use crate::tui::component::Effect;
 use crate::tui::component_store::ComponentStateStore;
@@ -34,6 +36,7 @@ pub fn reduce_settings(
                 );
                 settings_state.doc_nav = Default::default();
                 settings_state.doc_nav.focusable_positions = doc.focusable_positions();
+                settings_state.doc_nav.content_height = doc.calculate_height();
                 settings_state.doc_nav.focusable_ids = doc.focusable_ids();
                 settings_state.doc_nav.focusable_row_positions = doc.focusable_row_positions();
             }
@@ -61,6 +64,7 @@ pub fn reduce_settings(
                 );
                 settings_state.doc_nav = Default::default();
                 settings_state.doc_nav.focusable_positions = doc.focusable_positions();
+                settings_state.doc_nav.content_height = doc.calculate_height();
                 settings_state.doc_nav.focusable_ids = doc.focusable_ids();
                 settings_state.doc_nav.focusable_row_positions = doc.focusable_row_positions();
             }
@@ -71,86 +75,158 @@ pub fn reduce_settings(
         SettingsAction::ToggleBoolean(key) => {
             debug!("SETTINGS: Toggling boolean setting: {}", key);
             let mut new_state = state;
+            let config = Arc::make_mut(&mut new_state.system.config);
             match key.as_str() {
                 "use_unicode" => {
-                    new_state.system.config.display.use_unicode =
-                        !new_state.system.config.display.use_unicode;
-                    new_state.system.config.display.box_chars =
-                        crate::formatting::BoxChars::from_use_unicode(
-                            new_state.system.config.display.use_unicode,
-                        );
+                    config.display.use_unicode = !config.display.use_unicode;
+                    // Override warnings were already reported when the config was read
+                    config.display.rebuild_box_chars();
                 }
                 "western_teams_first" => {
-                    new_state.system.config.display_standings_western_first =
-                        !new_state.system.config.display_standings_western_first;
+                    config.display_standings_western_first =
+                        !config.display_standings_western_first;
                     // Rebuild standings focusable metadata so team selection uses the new order
-                    let config = new_state.system.config.clone();
-                    let save_effect = save_config_effect(config);
+                    let save_effect = save_config_effect(new_state.system.config.clone(), None);
                     let rebuild_effect = Effect::Action(Action::RebuildStandingsFocusable);
                     return (new_state, Effect::Batch(vec![save_effect, rebuild_effect]));
                 }
+                "restore_session" => {
+                    config.restore_session = !config.restore_session;
+                }
                 _ => {
                     debug!("SETTINGS: Unknown boolean setting: {}", key);
                 }
             }
-            let config = new_state.system.config.clone();
-            let effect = save_config_effect(config);
+            let effect = save_config_effect(new_state.system.config.clone(), None);
             (new_state, effect)
         }
 
         SettingsAction::UpdateSetting { key, value } => {
             debug!("SETTINGS: Updating setting: {} = {}", key, value);
             let mut new_state = state;
+            let config = Arc::make_mut(&mut new_state.system.config);
+            let mut warning = None;
             match key.as_str() {
                 "log_level" => {
-                    new_state.system.config.log_level = value;
+                    config.log_level = value;
+                }
+                "log_file" => {
+                    config.log_file = value;
+                }
+                "refresh_interval" => match value.parse() {
+                    Ok(seconds) => config.refresh_interval = seconds,
+                    Err(_) => debug!("SETTINGS: Invalid refresh interval: {}", value),
+                },
+                "time_format" => {
+                    config.time_format = value;
                 }
+                "error_fg" => match parse_color(&value) {
+                    Some(color) => {
+                        config.display.error_fg = color;
+                        warning = config.display.contrast_warning();
+                    }
+                    None => debug!("SETTINGS: Invalid error color: {}", value),
+                },
                 "theme" => {
                     if value == "none" {
-                        new_state.system.config.display.theme_name = None;
-                        new_state.system.config.display.theme = None;
+                        config.display.theme_name = None;
+                        config.display.theme = None;
                     } else {
                         use crate::config::THEMES;
-                        let theme = THEMES.get(value.as_str()).map(|t| (*t).clone());
-                        new_state.system.config.display.theme_name = Some(value);
-                        new_state.system.config.display.theme = theme;
+                        config.display.theme = THEMES.get(value.as_str()).map(|t| (*t).clone());
+                        config.display.theme_name = Some(value);
+                        warning = config.display.contrast_warning();
                     }
                 }
                 _ => {
                     debug!("SETTINGS: Unknown setting key: {}", key);
                 }
             }
-            let config = new_state.system.config.clone();
-            let effect = save_config_effect(config);
+            let effect = save_config_effect(new_state.system.config.clone(), warning);
             (new_state, effect)
         }
 
         SettingsAction::UpdateConfig(config) => {
             debug!("SETTINGS: Updating config");
             let mut new_state = state;
-            new_state.system.config = *config;
+            new_state.system.config = Arc::new(*config);
             (new_state, Effect::None)
         }
     }
 }
 
-fn save_config_effect(config: Config) -> Effect {
+/// Save the config in the background, reporting the outcome (and any warning) in the status bar
+fn save_config_effect(config: Arc<Config>, warning: Option<String>) -> Effect {
     Effect::Async(Box::pin(async move {
-        match crate::config::write(&config) {
-            Ok(_) => {
-                debug!("CONFIG: Successfully saved to disk");
-                Action::SetStatusMessage {
-                    message: "Configuration saved".to_string(),
-                    is_error: false,
-                }
+        save_result_action(crate::config::write(&config), warning)
+    }))
+}
+
+/// Turn the outcome of a config write into a status bar message
+fn save_result_action(
+    result: Result<(), Box<dyn std::error::Error>>,
+    warning: Option<String>,
+) -> Action {
+    match result {
+        Ok(_) => {
+            debug!("CONFIG: Successfully saved to disk");
+            let message = match warning {
+                Some(warning) => format!("Configuration saved (warning: {})", warning),
+                None => "Configuration saved".to_string(),
+            };
+            Action::SetStatusMessage {
+                message,
+                is_error: false,
             }
-            Err(e) => {
-                debug!("CONFIG: Failed to save: {}", e);
-                Action::SetStatusMessage {
-                    message: format!("Failed to save config: {}", e),
-                    is_error: true,
-                }
+        }
+        Err(e) => {
+            debug!("CONFIG: Failed to save: {}", e);
+            Action::SetStatusMessage {
+                message: format!("Failed to save config: {}", e),
+                is_error: true,
             }
         }
-    }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::write_to;
+    use std::env;
+    use std::fs;
+
+    #[test]
+    fn test_write_failure_becomes_error_message() {
+        // A regular file where the config directory should be
+        let blocker = env::temp_dir().join(format!("nhl_config_blocker_{}", std::process::id()));
+        fs::write(&blocker, "").unwrap();
+
+        let result = write_to(&Config::default(), &blocker.join("config.toml"));
+        let _ = fs::remove_file(&blocker);
+
+        match save_result_action(result, None) {
+            Action::SetStatusMessage { message, is_error } => {
+                assert!(is_error);
+                assert!(
+                    message.starts_with("Failed to save config: "),
+                    "unexpected message: {}",
+                    message
+                );
+                assert!(message.len() > "Failed to save config: ".len());
+            }
+            other => panic!("Expected SetStatusMessage, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_successful_write_reports_warning() {
+        match save_result_action(Ok(()), Some("low contrast".to_string())) {
+            Action::SetStatusMessage { message, is_error } => {
+                assert!(!is_error);
+                assert_eq!(message, "Configuration saved (warning: low contrast)");
+            }
+            other => panic!("Expected SetStatusMessage, got {:?}", other),
+        }
+    }
 }
@@ -1,5 +1,7 @@
 use std::sync::Arc;
 
+use nhl_api::Standing;
+
 use crate::commands::standings::GroupBy;
 use crate::tui::components::standings_tab::StandingsTabState;
 use crate::tui::components::{
@@ -8,8 +10,25 @@ use crate::tui::components::{
 };
 use crate::tui::constants::STANDINGS_TAB_PATH;
 use crate::tui::document::Document;
+use crate::tui::document_nav::autoscroll_to_focus;
 use crate::tui::state::AppState;
 
+/// Build the standings document for a view
+pub fn standings_document(
+    state: &AppState,
+    view: GroupBy,
+    standings: &Arc<Vec<Standing>>,
+) -> Box<dyn Document> {
+    let standings = Arc::clone(standings);
+    let config = state.system.config.clone();
+    match view {
+        GroupBy::Conference => Box::new(ConferenceStandingsDocument::new(standings, config)),
+        GroupBy::Division => Box::new(DivisionStandingsDocument::new(standings, config)),
+        GroupBy::League => Box::new(LeagueStandingsDocument::new(standings, config)),
+        GroupBy::Wildcard => Box::new(WildcardStandingsDocument::new(standings, config)),
+    }
+}
+
 /// Rebuild focusable metadata for document-based views
 ///
 /// Called from reducer when standings data changes or view changes.
@@ -19,7 +38,7 @@ pub fn rebuild_standings_focusable_metadata(
     state: &AppState,
     component_states: &mut crate::tui::component_store::ComponentStateStore,
 ) {
-    if let Some(standings) = state.data.standings.as_ref().as_ref() {
+    if let Some(standings) = state.data.displayed().standings.as_ref() {
         // Get current view from component state
         let view = component_states
             .get::<StandingsTabState>(STANDINGS_TAB_PATH)
@@ -27,65 +46,27 @@ pub fn rebuild_standings_focusable_metadata(
             .unwrap_or(GroupBy::Wildcard);
 
         // Build document for current view and extract metadata
-        let (positions, ids, row_positions, link_targets) = match view {
-            GroupBy::Conference => {
-                let doc = ConferenceStandingsDocument::new(
-                    Arc::new(standings.clone()),
-                    state.system.config.clone(),
-                );
-                (
-                    doc.focusable_positions(),
-                    doc.focusable_ids(),
-                    doc.focusable_row_positions(),
-                    doc.focusable_link_targets(),
-                )
-            }
-            GroupBy::Division => {
-                let doc = DivisionStandingsDocument::new(
-                    Arc::new(standings.clone()),
-                    state.system.config.clone(),
-                );
-                (
-                    doc.focusable_positions(),
-                    doc.focusable_ids(),
-                    doc.focusable_row_positions(),
-                    doc.focusable_link_targets(),
-                )
-            }
-            GroupBy::League => {
-                let doc = LeagueStandingsDocument::new(
-                    Arc::new(standings.clone()),
-                    state.system.config.clone(),
-                );
-                (
-                    doc.focusable_positions(),
-                    doc.focusable_ids(),
-                    doc.focusable_row_positions(),
-                    doc.focusable_link_targets(),
-                )
-            }
-            GroupBy::Wildcard => {
-                let doc = WildcardStandingsDocument::new(
-                    Arc::new(standings.clone()),
-                    state.system.config.clone(),
-                );
-                (
-                    doc.focusable_positions(),
-                    doc.focusable_ids(),
-                    doc.focusable_row_positions(),
-                    doc.focusable_link_targets(),
-                )
-            }
-        };
+        let doc = standings_document(state, view, standings);
 
         // Update component state with new metadata
         if let Some(standings_state) =
             component_states.get_mut::<StandingsTabState>(STANDINGS_TAB_PATH)
         {
-            standings_state.doc_nav.focusable_positions = positions;
-            standings_state.doc_nav.focusable_ids = ids;
-            standings_state.doc_nav.focusable_row_positions = row_positions;
-            standings_state.doc_nav.link_targets = link_targets;
+            standings_state.doc_nav.focusable_positions = doc.focusable_positions();
+            standings_state.doc_nav.content_height = doc.calculate_height();
+            standings_state.doc_nav.focusable_ids = doc.focusable_ids();
+            standings_state.doc_nav.focusable_row_positions = doc.focusable_row_positions();
+            standings_state.doc_nav.link_targets = doc.focusable_link_targets();
+
+            // A focus restored from the last session may no longer exist or be on screen
+            let nav = &mut standings_state.doc_nav;
+            if let Some(idx) = nav.focus_index {
+                if idx < nav.focusable_positions.len() {
+                    autoscroll_to_focus(nav);
+                } else {
+                    nav.focus_index = None;
+                }
+            }
         }
     }
 }
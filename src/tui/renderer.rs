@@ -4,6 +4,7 @@ use ratatui::{
 };
 
 use super::component::{Constraint, ContainerLayout, Element};
+use super::hit_test::{HitMap, HitRegion};
 use crate::config::DisplayConfig;
 
 /// Renders virtual element tree to ratatui buffer
@@ -52,6 +53,44 @@ impl Renderer {
         self.previous_tree = Some(element);
     }
 
+    /// Screen regions of the focusable elements in an element tree laid out in `area`
+    ///
+    /// Uses the same layout as `render`, so the regions match what was drawn.
+    pub fn hit_map(&self, element: &Element, area: Rect, config: &DisplayConfig) -> HitMap {
+        let mut regions = Vec::new();
+        self.collect_hit_regions(element, area, config, &mut regions);
+        HitMap::new(regions)
+    }
+
+    fn collect_hit_regions(
+        &self,
+        element: &Element,
+        area: Rect,
+        config: &DisplayConfig,
+        regions: &mut Vec<HitRegion>,
+    ) {
+        match element {
+            Element::Widget(widget) => regions.extend(widget.hit_regions(area, config)),
+            Element::Container { children, layout } => {
+                let chunks = self.calculate_layout(layout, area);
+                for (child, chunk) in children.iter().zip(chunks) {
+                    self.collect_hit_regions(child, chunk, config, regions);
+                }
+            }
+            Element::Fragment(children) => {
+                for child in children {
+                    self.collect_hit_regions(child, area, config, regions);
+                }
+            }
+            // An open overlay captures input, so the base underneath is not clickable
+            Element::Overlay { base, overlay } => match overlay.as_ref() {
+                Element::None => self.collect_hit_regions(base, area, config, regions),
+                overlay => self.collect_hit_regions(overlay, area, config, regions),
+            },
+            Element::Component(_) | Element::None => {}
+        }
+    }
+
     /// Calculate layout constraints and split the area
     fn calculate_layout(&self, layout: &ContainerLayout, area: Rect) -> Vec<Rect> {
         match layout {
@@ -304,6 +343,7 @@ impl Default for Renderer {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::tui::hit_test::HitTarget;
     use crate::tui::testing::assert_buffer;
     use ratatui::{
         buffer::Buffer,
@@ -328,6 +368,64 @@ mod tests {
         }
     }
 
+    /// Test widget whose whole area is one focusable element
+    #[derive(Clone)]
+    struct ClickableWidget;
+
+    impl super::super::component::ElementWidget for ClickableWidget {
+        fn render(&self, _area: Rect, _buf: &mut Buffer, _config: &DisplayConfig) {}
+
+        fn clone_box(&self) -> Box<dyn super::super::component::ElementWidget> {
+            Box::new(self.clone())
+        }
+
+        fn hit_regions(&self, area: Rect, _config: &DisplayConfig) -> Vec<HitRegion> {
+            vec![HitRegion {
+                rect: area,
+                target: HitTarget::Focus(0),
+            }]
+        }
+    }
+
+    #[test]
+    fn test_hit_map_uses_layout() {
+        let renderer = Renderer::new();
+        let area = Rect::new(0, 0, 10, 6);
+        let config = DisplayConfig::default();
+        let element = || Element::Container {
+            layout: ContainerLayout::Vertical(vec![Constraint::Length(2), Constraint::Min(0)]),
+            children: vec![
+                Element::Widget(Box::new(TestWidget {
+                    text: "TOP".to_string(),
+                })),
+                Element::Widget(Box::new(ClickableWidget)),
+            ],
+        };
+
+        let hit_map = renderer.hit_map(&element(), area, &config);
+        assert_eq!(
+            hit_map.regions(),
+            &[HitRegion {
+                rect: Rect::new(0, 2, 10, 4),
+                target: HitTarget::Focus(0),
+            }]
+        );
+
+        // A closed overlay leaves the base clickable; an open one covers it
+        let closed = Element::Overlay {
+            base: Box::new(element()),
+            overlay: Box::new(Element::None),
+        };
+        assert_eq!(renderer.hit_map(&closed, area, &config), hit_map);
+        let open = Element::Overlay {
+            base: Box::new(element()),
+            overlay: Box::new(Element::Widget(Box::new(TestWidget {
+                text: "MODAL".to_string(),
+            }))),
+        };
+        assert!(renderer.hit_map(&open, area, &config).regions().is_empty());
+    }
+
     #[test]
     fn test_render_none() {
         let mut renderer = Renderer::new();
@@ -1,3 +1,4 @@
+use ratatui::layout::Rect;
 use std::sync::Arc;
 use tokio::sync::mpsc;
 use tracing::{debug, trace};
@@ -10,6 +11,7 @@ use super::constants::DEMO_TAB_PATH;
 use super::constants::{SCORES_TAB_PATH, SETTINGS_TAB_PATH, STANDINGS_TAB_PATH};
 use super::effects::DataEffects;
 use super::reducer::reduce;
+use super::session::Session;
 use super::state::AppState;
 
 /// Component runtime - manages component lifecycle and action processing
@@ -70,6 +72,13 @@ impl Runtime {
         &self.component_states
     }
 
+    /// Apply a saved session to the state and component states
+    ///
+    /// Call before the first build so the restored tab is the first one drawn.
+    pub fn restore_session(&mut self, session: &Session) {
+        session.restore(&mut self.state, &mut self.component_states);
+    }
+
     /// Dispatch an action to be processed by the reducer
     ///
     /// Uses mem::take to avoid cloning AppState. Reducers now return fetch effects
@@ -77,19 +86,8 @@ impl Runtime {
     pub fn dispatch(&mut self, action: Action) {
         trace!("ACTION: Dispatching {:?}", action);
 
-        // Handle RefreshData and RefreshSchedule actions specially - generate data fetch effects
-        let effect = if matches!(action, Action::RefreshData) {
-            debug!("ACTION: RefreshData - generating fetch effects");
-
-            // Take ownership temporarily, run reducer, put back
-            let state = std::mem::take(&mut self.state);
-            let (new_state, _reducer_effect) =
-                reduce(state, action.clone(), &mut self.component_states);
-            self.state = new_state;
-
-            // Then generate data fetch effects
-            self.data_effects.handle_refresh(&self.state)
-        } else if let Action::RefreshSchedule(date) = &action {
+        // Handle RefreshSchedule actions specially - generate data fetch effects
+        let effect = if let Action::RefreshSchedule(date) = &action {
             debug!(
                 "ACTION: RefreshSchedule({:?}) - generating fetch effects",
                 date
@@ -123,6 +121,16 @@ impl Runtime {
             Effect::None | Effect::Handled => {
                 // Nothing to do
             }
+            Effect::FetchStandings(season) => {
+                debug!("EFFECT: Executing standings fetch for season={:?}", season);
+                let fetch_effect = self.data_effects.fetch_standings(season);
+                let _ = self.effect_tx.send(fetch_effect);
+            }
+            Effect::FetchSchedule(date) => {
+                debug!("EFFECT: Executing schedule fetch for date={:?}", date);
+                let fetch_effect = self.data_effects.fetch_schedule(date);
+                let _ = self.effect_tx.send(fetch_effect);
+            }
             Effect::FetchBoxscore(game_id) => {
                 debug!("EFFECT: Executing boxscore fetch for game_id={}", game_id);
                 let fetch_effect = self.data_effects.fetch_boxscore(game_id);
@@ -136,6 +144,16 @@ impl Runtime {
                 let fetch_effect = self.data_effects.fetch_team_roster_stats(abbrev);
                 let _ = self.effect_tx.send(fetch_effect);
             }
+            Effect::FetchTeamRoster(abbrev) => {
+                debug!("EFFECT: Executing roster fetch for team={}", abbrev);
+                let fetch_effect = self.data_effects.fetch_team_roster(abbrev);
+                let _ = self.effect_tx.send(fetch_effect);
+            }
+            Effect::FetchTeamSchedule(abbrev) => {
+                debug!("EFFECT: Executing schedule fetch for team={}", abbrev);
+                let fetch_effect = self.data_effects.fetch_team_schedule(abbrev);
+                let _ = self.effect_tx.send(fetch_effect);
+            }
             Effect::FetchPlayerStats(player_id) => {
                 debug!(
                     "EFFECT: Executing player stats fetch for player_id={}",
@@ -144,6 +162,14 @@ impl Runtime {
                 let fetch_effect = self.data_effects.fetch_player_stats(player_id);
                 let _ = self.effect_tx.send(fetch_effect);
             }
+            Effect::FetchPlayerGameLog(player_id, season) => {
+                debug!(
+                    "EFFECT: Executing player game log fetch for player_id={} season={}",
+                    player_id, season
+                );
+                let fetch_effect = self.data_effects.fetch_player_game_log(player_id, season);
+                let _ = self.effect_tx.send(fetch_effect);
+            }
             Effect::FetchGameDetails(game_id) => {
                 debug!(
                     "EFFECT: Executing game details fetch for game_id={}",
@@ -152,6 +178,24 @@ impl Runtime {
                 let fetch_effect = self.data_effects.fetch_game_details(game_id);
                 let _ = self.effect_tx.send(fetch_effect);
             }
+            Effect::RefetchStandings(season) => {
+                debug!(
+                    "EFFECT: Executing standings refetch for season={:?}",
+                    season
+                );
+                let fetch_effect = self.data_effects.refetch_standings(season);
+                let _ = self.effect_tx.send(fetch_effect);
+            }
+            Effect::RefetchSchedule(date) => {
+                debug!("EFFECT: Executing schedule refetch for date={:?}", date);
+                let fetch_effect = self.data_effects.refetch_schedule(date);
+                let _ = self.effect_tx.send(fetch_effect);
+            }
+            Effect::RefetchBoxscore(game_id) => {
+                debug!("EFFECT: Executing boxscore refetch for game_id={}", game_id);
+                let fetch_effect = self.data_effects.refetch_boxscore(game_id);
+                let _ = self.effect_tx.send(fetch_effect);
+            }
             Effect::Batch(effects) => {
                 // Execute each effect in the batch
                 for e in effects {
@@ -187,13 +231,13 @@ impl Runtime {
     /// Note: Currently needs &mut self to manage component states, but the build itself
     /// is logically a read operation. In the future, we might use RefCell or similar
     /// for interior mutability if needed.
-    pub fn build(&mut self) -> Element {
+    pub fn build(&mut self, area: Rect) -> Element {
         use crate::tui::components::App;
 
         let app = App;
         // App needs access to component_states to get child component states,
         // so we call a special method instead of the normal view()
-        app.build_with_component_states(&self.state, &mut self.component_states)
+        app.build_for_area(&self.state, &mut self.component_states, area)
     }
 
     /// Get a sender for dispatching actions from external sources
@@ -253,6 +297,11 @@ impl Runtime {
             }
         }
 
+        // Stacked documents sit under the breadcrumb, as tall as a subtab bar
+        for entry in &mut self.state.navigation.document_stack {
+            entry.nav.viewport_height = subtab_viewport;
+        }
+
         // Update DemoTab viewport (no subtabs, uses base chrome)
         // DemoTab uses DocumentNavState directly as its state type
         #[cfg(feature = "development")]
@@ -266,13 +315,56 @@ impl Runtime {
         }
     }
 
+    /// Apply the configured focus wrap mode to every document navigation state
+    ///
+    /// Like viewport heights, this is synced each frame so config changes take
+    /// effect without rebuilding component states.
+    pub fn update_focus_wrap(&mut self) {
+        use crate::tui::components::scores_tab::ScoresTabState;
+        use crate::tui::components::settings_tab::SettingsTabState;
+        use crate::tui::components::standings_tab::StandingsTabState;
+        #[cfg(feature = "development")]
+        use crate::tui::document_nav::DocumentNavState;
+
+        let focus_wrap = self.state.system.config.display.focus_wrap;
+
+        if let Some(state) = self
+            .component_states
+            .get_mut::<StandingsTabState>(STANDINGS_TAB_PATH)
+        {
+            state.doc_nav.focus_wrap = focus_wrap;
+        }
+        if let Some(state) = self
+            .component_states
+            .get_mut::<ScoresTabState>(SCORES_TAB_PATH)
+        {
+            state.doc_nav.focus_wrap = focus_wrap;
+        }
+        if let Some(state) = self
+            .component_states
+            .get_mut::<SettingsTabState>(SETTINGS_TAB_PATH)
+        {
+            state.doc_nav.focus_wrap = focus_wrap;
+        }
+        for entry in &mut self.state.navigation.document_stack {
+            entry.nav.focus_wrap = focus_wrap;
+        }
+        #[cfg(feature = "development")]
+        if let Some(state) = self
+            .component_states
+            .get_mut::<DocumentNavState>(DEMO_TAB_PATH)
+        {
+            state.focus_wrap = focus_wrap;
+        }
+    }
+
     /// Execute effects asynchronously
     ///
     /// This runs in a separate tokio task and processes effects as they come in.
     /// Effects can dispatch new actions which feed back into the runtime.
     ///
-    /// Note: FetchBoxscore, FetchTeamRosterStats, FetchPlayerStats, FetchGameDetails
-    /// are handled synchronously by execute_effect() and should never reach here.
+    /// Note: FetchStandings, FetchSchedule, FetchBoxscore, FetchTeamRosterStats, FetchTeamRoster, FetchTeamSchedule,
+    /// FetchPlayerStats, FetchGameDetails, FetchPlayerGameLog and the Refetch effects are handled synchronously by execute_effect() and should never reach here.
     /// They are converted to Effect::Async before being sent to this channel.
     async fn run_effect_executor(
         effect_rx: &mut mpsc::UnboundedReceiver<Effect>,
@@ -309,10 +401,18 @@ impl Runtime {
             }
             // Fetch effects should never reach here - they're handled by execute_effect()
             // before being queued. Log a warning if they somehow slip through.
-            Effect::FetchBoxscore(_)
+            Effect::FetchStandings(_)
+            | Effect::FetchSchedule(_)
+            | Effect::FetchBoxscore(_)
             | Effect::FetchTeamRosterStats(_)
+            | Effect::FetchTeamRoster(_)
+            | Effect::FetchTeamSchedule(_)
             | Effect::FetchPlayerStats(_)
-            | Effect::FetchGameDetails(_) => {
+            | Effect::FetchGameDetails(_)
+            | Effect::FetchPlayerGameLog(..)
+            | Effect::RefetchStandings(_)
+            | Effect::RefetchSchedule(_)
+            | Effect::RefetchBoxscore(_) => {
                 tracing::warn!(
                     "Fetch effect reached async executor - this should be handled by execute_effect()"
                 );
@@ -417,7 +517,7 @@ mod tests {
         let mut runtime = Runtime::new(state, data_effects);
 
         // build() should return the App component tree
-        let element = runtime.build();
+        let element = runtime.build(Rect::new(0, 0, 80, 24));
 
         // Should be a container with 2 children (TabbedPanel, StatusBar)
         match element {
@@ -428,6 +528,24 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_update_focus_wrap_follows_config() {
+        use crate::tui::components::scores_tab::ScoresTabState;
+
+        let mut state = AppState::default();
+        Arc::make_mut(&mut state.system.config).display.focus_wrap = true;
+        let mut runtime = Runtime::new(state, create_test_data_effects());
+        runtime.build(Rect::new(0, 0, 80, 24));
+
+        runtime.update_focus_wrap();
+
+        let scores = runtime
+            .component_states()
+            .get::<ScoresTabState>(SCORES_TAB_PATH)
+            .unwrap();
+        assert!(scores.doc_nav.focus_wrap);
+    }
+
     #[tokio::test]
     async fn test_refresh_data_triggers_data_effects() {
         let state = AppState::default();
@@ -496,6 +614,42 @@ mod tests {
         assert!(matches!(action, Some(Action::Quit)));
     }
 
+    #[tokio::test]
+    async fn test_export_key() {
+        let runtime = create_test_runtime();
+        let state = runtime.state();
+        let component_states = runtime.component_states();
+
+        let key = KeyEvent::new(KeyCode::Char('E'), KeyModifiers::SHIFT);
+        let action = key_to_action(key, state, component_states);
+
+        assert!(matches!(action, Some(Action::ExportDocument)));
+    }
+
+    #[tokio::test]
+    async fn test_copy_key() {
+        let runtime = create_test_runtime();
+        let state = runtime.state();
+        let component_states = runtime.component_states();
+
+        let key = KeyEvent::new(KeyCode::Char('y'), KeyModifiers::empty());
+        let action = key_to_action(key, state, component_states);
+
+        assert!(matches!(action, Some(Action::CopyFocusedLink)));
+    }
+
+    #[tokio::test]
+    async fn test_copy_game_summary_key() {
+        let runtime = create_test_runtime();
+        let state = runtime.state();
+        let component_states = runtime.component_states();
+
+        let key = KeyEvent::new(KeyCode::Char('Y'), KeyModifiers::SHIFT);
+        let action = key_to_action(key, state, component_states);
+
+        assert!(matches!(action, Some(Action::CopyGameSummary)));
+    }
+
     #[tokio::test]
     async fn test_focus_level_keys() {
         let mut runtime = create_test_runtime();
@@ -0,0 +1,155 @@
+//! Last viewed tab and standings selection, kept in `session.toml`
+//!
+//! The session is saved when the app quits and restored at startup unless
+//! `restore_session` is turned off. It lives next to the config file but
+//! separately, so user settings never mix with state the app writes itself.
+//! Unknown or out-of-range values are ignored: a missing or stale file only
+//! means a clean start.
+use std::fs;
+
+use serde::{Deserialize, Serialize};
+
+use crate::commands::standings::GroupBy;
+use crate::config::get_session_path;
+use crate::error::{Error, Result};
+
+use super::component_store::ComponentStateStore;
+use super::components::standings_tab::StandingsTabState;
+use super::constants::STANDINGS_TAB_PATH;
+use super::state::AppState;
+use super::types::Tab;
+
+/// Navigation state carried over between runs
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct Session {
+    /// Key of the active tab, e.g. "standings"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tab: Option<String>,
+    /// Name of the standings view, e.g. "Division"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub standings_view: Option<String>,
+    /// Index of the focused team in the standings view
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub standings_focus: Option<usize>,
+}
+
+impl Session {
+    /// Capture the session from the running app
+    pub fn capture(state: &AppState, component_states: &ComponentStateStore) -> Self {
+        let standings = component_states.get::<StandingsTabState>(STANDINGS_TAB_PATH);
+        Self {
+            tab: Some(state.navigation.current_tab.key().to_string()),
+            standings_view: standings.map(|s| s.view.name().to_string()),
+            standings_focus: standings.and_then(|s| s.doc_nav.focus_index),
+        }
+    }
+
+    /// Apply the session to a fresh app before its first frame
+    ///
+    /// The standings focus is checked against the teams once standings load.
+    pub fn restore(&self, state: &mut AppState, component_states: &mut ComponentStateStore) {
+        if let Some(tab) = self.tab.as_deref().and_then(Tab::from_key) {
+            state.navigation.current_tab = tab;
+        }
+
+        let Some(view) = self
+            .standings_view
+            .as_deref()
+            .and_then(|name| GroupBy::all().into_iter().find(|view| view.name() == name))
+        else {
+            return;
+        };
+        let mut standings = StandingsTabState {
+            view,
+            ..Default::default()
+        };
+        standings.doc_nav.focus_index = self.standings_focus;
+        component_states.insert(STANDINGS_TAB_PATH.to_string(), standings);
+
+        // Land back in browse mode on the restored team
+        if state.navigation.current_tab == Tab::Standings && self.standings_focus.is_some() {
+            state.navigation.content_focused = true;
+        }
+    }
+}
+
+/// Read the session file, falling back to an empty session
+pub fn read() -> Session {
+    let Some(content) = get_session_path().and_then(|path| fs::read_to_string(path).ok()) else {
+        return Session::default();
+    };
+    toml::from_str(&content).unwrap_or_default()
+}
+
+/// Write the session file
+pub fn write(session: &Session) -> Result<()> {
+    let session_path = get_session_path().ok_or(Error::NoConfigDir)?;
+
+    if let Some(parent) = session_path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(&session_path, toml::to_string_pretty(session)?)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_capture_and_restore() {
+        let mut state = AppState::default();
+        state.navigation.current_tab = Tab::Standings;
+        let mut component_states = ComponentStateStore::new();
+        let mut standings = StandingsTabState {
+            view: GroupBy::Division,
+            ..Default::default()
+        };
+        standings.doc_nav.focus_index = Some(5);
+        component_states.insert(STANDINGS_TAB_PATH.to_string(), standings);
+
+        let session = Session::capture(&state, &component_states);
+        assert_eq!(
+            toml::to_string_pretty(&session).unwrap(),
+            "tab = \"standings\"\nstandings_view = \"Division\"\nstandings_focus = 5\n"
+        );
+
+        let mut restored_state = AppState::default();
+        let mut restored_components = ComponentStateStore::new();
+        session.restore(&mut restored_state, &mut restored_components);
+
+        assert_eq!(restored_state.navigation.current_tab, Tab::Standings);
+        assert!(restored_state.navigation.content_focused);
+        let standings = restored_components
+            .get::<StandingsTabState>(STANDINGS_TAB_PATH)
+            .unwrap();
+        assert_eq!(standings.view, GroupBy::Division);
+        assert_eq!(standings.doc_nav.focus_index, Some(5));
+    }
+
+    #[test]
+    fn test_restore_ignores_stale_values() {
+        let session: Session =
+            toml::from_str("tab = \"playoffs\"\nstandings_view = \"Bracket\"\nstandings_focus = 3")
+                .unwrap();
+
+        let mut state = AppState::default();
+        let mut component_states = ComponentStateStore::new();
+        session.restore(&mut state, &mut component_states);
+
+        assert_eq!(state.navigation.current_tab, Tab::Scores);
+        assert!(!state.navigation.content_focused);
+        assert!(component_states.is_empty());
+    }
+
+    #[test]
+    fn test_invalid_session_file_is_empty_session() {
+        assert_eq!(
+            toml::from_str::<Session>("tab = 3").unwrap_or_default(),
+            Session::default()
+        );
+        assert_eq!(toml::from_str::<Session>("").unwrap(), Session::default());
+    }
+}
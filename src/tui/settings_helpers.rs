@@ -1,12 +1,14 @@
 /// Shared helper functions for settings management
 ///
 /// This module provides common functionality used by the settings tab
-/// for managing settings modals.
+/// for managing settings modals and the free-text setting editor.
+use chrono::Local;
+
 use crate::config::{
-    Config, THEMES, THEME_ID_BLUE, THEME_ID_BRUINS, THEME_ID_CYAN, THEME_ID_FLAMES, THEME_ID_GREEN,
-    THEME_ID_HABS, THEME_ID_ISLANDERS, THEME_ID_NORTH_STARS, THEME_ID_ORANGE, THEME_ID_PURPLE,
-    THEME_ID_RED, THEME_ID_RED_WINGS, THEME_ID_SABRES, THEME_ID_SHARKS, THEME_ID_WHITE,
-    THEME_ID_YELLOW,
+    format_color, format_time, parse_color, Config, LOG_LEVELS, THEMES, THEME_ID_BLUE,
+    THEME_ID_BRUINS, THEME_ID_CYAN, THEME_ID_FLAMES, THEME_ID_GREEN, THEME_ID_HABS,
+    THEME_ID_ISLANDERS, THEME_ID_NORTH_STARS, THEME_ID_ORANGE, THEME_ID_PURPLE, THEME_ID_RED,
+    THEME_ID_RED_WINGS, THEME_ID_SABRES, THEME_ID_SHARKS, THEME_ID_WHITE, THEME_ID_YELLOW,
 };
 
 const THEME_IDS: &[&str] = &[
@@ -82,7 +84,7 @@ pub fn get_setting_modal_options(key: &str) -> Vec<ModalOption> {
 /// Get the list of valid values for a setting that has a fixed set of options
 fn get_setting_values(key: &str) -> Vec<&'static str> {
     match key {
-        "log_level" => vec!["trace", "debug", "info", "warn", "error"],
+        "log_level" => LOG_LEVELS.to_vec(),
         "theme" => {
             let mut values = vec!["none"];
             values.extend(THEME_IDS.iter().copied());
@@ -114,10 +116,124 @@ pub fn find_initial_modal_index(config: &Config, key: &str) -> usize {
     values.iter().position(|&v| v == current_value).unwrap_or(0)
 }
 
+/// Shortest refresh interval accepted from the settings editor, in seconds
+pub const MIN_REFRESH_INTERVAL: u32 = 10;
+/// Longest refresh interval accepted from the settings editor, in seconds
+pub const MAX_REFRESH_INTERVAL: u32 = 3600;
+
+/// Current value of a free-text setting, used to pre-fill its editor
+///
+/// Returns None for settings that are not edited as text.
+pub fn get_setting_text(config: &Config, key: &str) -> Option<String> {
+    match key {
+        "log_file" => Some(config.log_file.clone()),
+        "refresh_interval" => Some(config.refresh_interval.to_string()),
+        "time_format" => Some(config.time_format.clone()),
+        "error_fg" => Some(format_color(&config.display.error_fg)),
+        _ => None,
+    }
+}
+
+/// Validate text typed into a setting's editor, returning the value to store
+///
+/// The refresh interval is clamped to `MIN_REFRESH_INTERVAL..=MAX_REFRESH_INTERVAL`.
+pub fn validate_setting_text(key: &str, input: &str) -> Result<String, String> {
+    let input = input.trim();
+    match key {
+        "refresh_interval" => input
+            .parse::<u32>()
+            .map(|seconds| {
+                seconds
+                    .clamp(MIN_REFRESH_INTERVAL, MAX_REFRESH_INTERVAL)
+                    .to_string()
+            })
+            .map_err(|_| format!("'{}' is not a number of seconds", input)),
+        "time_format" if input.is_empty() => Err("Time format can't be empty".to_string()),
+        "time_format" => format_time(&Local::now(), input)
+            .map(|_| input.to_string())
+            .ok_or_else(|| format!("Invalid time format '{}'", input)),
+        "log_file" if input.is_empty() => Err("Log file can't be empty".to_string()),
+        "error_fg" => parse_color(input)
+            .map(|_| input.to_string())
+            .ok_or_else(|| format!("Unknown color '{}'", input)),
+        _ => Ok(input.to_string()),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_get_setting_text() {
+        let config = Config::default();
+        assert_eq!(
+            get_setting_text(&config, "refresh_interval"),
+            Some("60".to_string())
+        );
+        assert_eq!(
+            get_setting_text(&config, "time_format"),
+            Some("%H:%M:%S".to_string())
+        );
+        assert_eq!(get_setting_text(&config, "theme"), None);
+    }
+
+    #[test]
+    fn test_validate_refresh_interval() {
+        assert_eq!(
+            validate_setting_text("refresh_interval", " 30 "),
+            Ok("30".to_string())
+        );
+        assert_eq!(
+            validate_setting_text("refresh_interval", "1"),
+            Ok(MIN_REFRESH_INTERVAL.to_string())
+        );
+        assert_eq!(
+            validate_setting_text("refresh_interval", "99999"),
+            Ok(MAX_REFRESH_INTERVAL.to_string())
+        );
+        assert_eq!(
+            validate_setting_text("refresh_interval", "-5"),
+            Err("'-5' is not a number of seconds".to_string())
+        );
+    }
+
+    #[test]
+    fn test_validate_error_color() {
+        for input in ["red", "#e24a4a", "#f60", "226,74,74"] {
+            assert_eq!(
+                validate_setting_text("error_fg", input),
+                Ok(input.to_string())
+            );
+        }
+        assert_eq!(
+            validate_setting_text("error_fg", "reddish"),
+            Err("Unknown color 'reddish'".to_string())
+        );
+        assert_eq!(
+            get_setting_text(&Config::default(), "error_fg"),
+            Some("255,0,0".to_string())
+        );
+    }
+
+    #[test]
+    fn test_validate_time_format_and_log_file() {
+        assert_eq!(
+            validate_setting_text("time_format", "%I:%M %p"),
+            Ok("%I:%M %p".to_string())
+        );
+        assert_eq!(
+            validate_setting_text("time_format", "%Q"),
+            Err("Invalid time format '%Q'".to_string())
+        );
+        assert!(validate_setting_text("time_format", "").is_err());
+        assert!(validate_setting_text("log_file", "  ").is_err());
+        assert_eq!(
+            validate_setting_text("log_file", "/tmp/nhl.log"),
+            Ok("/tmp/nhl.log".to_string())
+        );
+    }
+
     #[test]
     fn test_find_initial_modal_index_theme_none() {
         let config = Config::default();
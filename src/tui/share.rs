@@ -0,0 +1,251 @@
+//! Shareable one-line game summaries
+//!
+//! Builds text like "NJD 3 @ BUF 2 (F/OT) — stars: J. Hughes, T. Thompson, D. Hamilton"
+//! from a boxscore or a schedule entry, for copying to the clipboard.
+
+use nhl_api::{
+    Boxscore, GameClock, GameMatchup, GameState, PeriodDescriptor, PeriodType, ScheduleGame,
+    ThreeStar,
+};
+
+/// Compact status suffix for a boxscore, e.g. "F", "F/OT", "P2 12:34" or "P3 INT"
+pub fn boxscore_to_status(boxscore: &Boxscore) -> String {
+    game_status(
+        boxscore.game_state,
+        Some(&boxscore.period_descriptor),
+        Some(&boxscore.clock),
+    )
+}
+
+/// Summary line for a game with a loaded boxscore, naming the three stars when known
+pub fn boxscore_summary(
+    boxscore: &Boxscore,
+    stars: Option<&[ThreeStar]>,
+    use_unicode: bool,
+) -> String {
+    summary_line(
+        (&boxscore.away_team.abbrev, Some(boxscore.away_team.score)),
+        (&boxscore.home_team.abbrev, Some(boxscore.home_team.score)),
+        &boxscore_to_status(boxscore),
+        stars,
+        use_unicode,
+    )
+}
+
+/// Summary line for a scores card, using the landing data for the score and status when loaded
+pub fn schedule_game_summary(
+    game: &ScheduleGame,
+    info: Option<&GameMatchup>,
+    use_unicode: bool,
+) -> String {
+    let (scores, status) = match info {
+        Some(info) => (
+            (Some(info.away_team.score), Some(info.home_team.score)),
+            game_status(
+                info.game_state,
+                Some(&info.period_descriptor),
+                info.clock.as_ref(),
+            ),
+        ),
+        None => (
+            (game.away_team.score, game.home_team.score),
+            game_status(game.game_state, None, None),
+        ),
+    };
+    let stars = info
+        .and_then(|info| info.summary.as_ref())
+        .and_then(|summary| summary.three_stars.as_deref());
+
+    summary_line(
+        (&game.away_team.abbrev, scores.0),
+        (&game.home_team.abbrev, scores.1),
+        &status,
+        stars,
+        use_unicode,
+    )
+}
+
+/// Status suffix; without a period descriptor only the game state is known
+fn game_status(
+    state: GameState,
+    period: Option<&PeriodDescriptor>,
+    clock: Option<&GameClock>,
+) -> String {
+    if state.is_final() {
+        return match period.map(|period| (period.period_type, period.number)) {
+            Some((PeriodType::Shootout, _)) => "F/SO".to_string(),
+            Some((PeriodType::Overtime, _)) => "F/OT".to_string(),
+            Some((PeriodType::Regulation, number)) if number > 3 => "F/OT".to_string(),
+            _ => "F".to_string(),
+        };
+    }
+    if !state.has_started() {
+        return "Scheduled".to_string();
+    }
+
+    let label = match period.map(|period| (period.period_type, period.number)) {
+        Some((PeriodType::Regulation, number)) => format!("P{}", number),
+        Some((PeriodType::Overtime, _)) => "OT".to_string(),
+        Some((PeriodType::Shootout, _)) => "SO".to_string(),
+        None => "Live".to_string(),
+    };
+    match clock {
+        Some(clock) if clock.in_intermission => format!("{} INT", label),
+        Some(clock) if !clock.time_remaining.is_empty() => {
+            format!("{} {}", label, clock.time_remaining)
+        }
+        _ => label,
+    }
+}
+
+fn summary_line(
+    away: (&str, Option<i32>),
+    home: (&str, Option<i32>),
+    status: &str,
+    stars: Option<&[ThreeStar]>,
+    use_unicode: bool,
+) -> String {
+    let team = |(abbrev, score): (&str, Option<i32>)| match score {
+        Some(score) => format!("{} {}", abbrev, score),
+        None => abbrev.to_string(),
+    };
+    let mut line = format!("{} @ {} ({})", team(away), team(home), status);
+
+    let mut stars: Vec<&ThreeStar> = stars.unwrap_or_default().iter().collect();
+    if !stars.is_empty() {
+        stars.sort_by_key(|star| star.star);
+        let names: Vec<&str> = stars
+            .iter()
+            .map(|star| star.name.default.as_str())
+            .collect();
+        let dash = if use_unicode { "—" } else { "-" };
+        line.push_str(&format!(" {} stars: {}", dash, names.join(", ")));
+    }
+    line
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::{create_mock_boxscore, create_mock_game_matchup, create_mock_schedule};
+    use nhl_api::{LocalizedString, Position};
+
+    fn star(star: i32, name: &str) -> ThreeStar {
+        ThreeStar {
+            star,
+            player_id: star as i64,
+            team_abbrev: "NJD".to_string(),
+            headshot: String::new(),
+            name: LocalizedString {
+                default: name.to_string(),
+            },
+            sweater_no: 1,
+            position: Position::Center,
+            goals: Some(1),
+            assists: Some(1),
+            points: Some(2),
+            goals_against_average: None,
+            save_pctg: None,
+        }
+    }
+
+    fn final_boxscore(period_type: PeriodType, number: i32) -> Boxscore {
+        let mut boxscore = create_mock_boxscore(2024020005);
+        boxscore.game_state = GameState::Final;
+        boxscore.period_descriptor = PeriodDescriptor {
+            number,
+            period_type,
+            max_regulation_periods: 3,
+        };
+        boxscore.away_team.abbrev = "NJD".to_string();
+        boxscore.away_team.score = 3;
+        boxscore.home_team.abbrev = "BUF".to_string();
+        boxscore.home_team.score = 2;
+        boxscore
+    }
+
+    #[test]
+    fn test_boxscore_to_status_final() {
+        assert_eq!(
+            boxscore_to_status(&final_boxscore(PeriodType::Regulation, 3)),
+            "F"
+        );
+        assert_eq!(
+            boxscore_to_status(&final_boxscore(PeriodType::Overtime, 4)),
+            "F/OT"
+        );
+        assert_eq!(
+            boxscore_to_status(&final_boxscore(PeriodType::Shootout, 5)),
+            "F/SO"
+        );
+    }
+
+    #[test]
+    fn test_boxscore_to_status_live() {
+        let mut boxscore = create_mock_boxscore(2024020003);
+        boxscore.clock.time_remaining = "12:34".to_string();
+        boxscore.clock.in_intermission = false;
+        assert_eq!(boxscore_to_status(&boxscore), "P2 12:34");
+
+        boxscore.clock.in_intermission = true;
+        assert_eq!(boxscore_to_status(&boxscore), "P2 INT");
+    }
+
+    #[test]
+    fn test_boxscore_summary_with_stars() {
+        let boxscore = final_boxscore(PeriodType::Overtime, 4);
+        // Out of order to check they are listed by star
+        let stars = [
+            star(2, "T. Thompson"),
+            star(1, "J. Hughes"),
+            star(3, "D. Hamilton"),
+        ];
+
+        assert_eq!(
+            boxscore_summary(&boxscore, Some(&stars), true),
+            "NJD 3 @ BUF 2 (F/OT) — stars: J. Hughes, T. Thompson, D. Hamilton"
+        );
+        assert_eq!(
+            boxscore_summary(&boxscore, Some(&stars), false),
+            "NJD 3 @ BUF 2 (F/OT) - stars: J. Hughes, T. Thompson, D. Hamilton"
+        );
+    }
+
+    #[test]
+    fn test_boxscore_summary_without_stars() {
+        let boxscore = final_boxscore(PeriodType::Regulation, 3);
+
+        assert_eq!(boxscore_summary(&boxscore, None, true), "NJD 3 @ BUF 2 (F)");
+        assert_eq!(
+            boxscore_summary(&boxscore, Some(&[]), true),
+            "NJD 3 @ BUF 2 (F)"
+        );
+    }
+
+    #[test]
+    fn test_schedule_game_summary_prefers_landing_data() {
+        let schedule = create_mock_schedule(None);
+        let game = &schedule.games[3];
+        // Overtime final, 3-4
+        let info = create_mock_game_matchup(2024020006);
+
+        assert_eq!(
+            schedule_game_summary(game, Some(&info), true),
+            "VGK 3 @ LA 4 (F/OT)"
+        );
+    }
+
+    #[test]
+    fn test_schedule_game_summary_from_schedule_only() {
+        let schedule = create_mock_schedule(None);
+
+        assert_eq!(
+            schedule_game_summary(&schedule.games[2], None, true),
+            "NYR 2 @ NJD 3 (F)"
+        );
+        assert_eq!(
+            schedule_game_summary(&schedule.games[0], None, true),
+            "BOS @ MTL (Scheduled)"
+        );
+    }
+}
@@ -1,11 +1,16 @@
 use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
 use std::sync::Arc;
 use std::time::SystemTime;
 
-use nhl_api::{Boxscore, ClubStats, DailySchedule, GameDate, GameMatchup, PlayerLanding, Standing};
+use nhl_api::{
+    Boxscore, ClubStats, DailySchedule, GameDate, GameLog, GameMatchup, GameState, GameType,
+    PlayerLanding, Roster, ScheduleGame, Standing,
+};
 
 use crate::commands::scores_format::PeriodScores;
 use crate::config::Config;
+use crate::season::FIRST_SEASON;
 
 use super::document_nav::DocumentNavState;
 use super::types::{SettingsCategory, StackedDocument, Tab};
@@ -30,12 +35,88 @@ pub struct AppState {
     pub system: SystemState,
 }
 
+impl AppState {
+    /// Whether the top stacked document is the boxscore of a game in progress
+    pub fn viewing_live_game(&self) -> bool {
+        match self
+            .navigation
+            .document_stack
+            .last()
+            .map(|entry| &entry.document)
+        {
+            Some(StackedDocument::Boxscore { game_id, .. }) => self
+                .data
+                .displayed()
+                .game_state(*game_id)
+                .is_some_and(|game_state| game_state.is_live()),
+            _ => false,
+        }
+    }
+
+    /// Whether data on screen is still on its way, so the loading animation should run
+    ///
+    /// Data whose last fetch failed is not waited for: animating would imply progress.
+    pub fn waiting_for_data(&self) -> bool {
+        let data = &self.data;
+        let missing = |loaded: bool, kind: FetchKind| !loaded && !data.errors.contains_key(&kind);
+
+        missing(data.standings.is_some(), FetchKind::Standings)
+            || missing(data.schedule.is_some(), FetchKind::Schedule)
+            || self
+                .navigation
+                .document_stack
+                .iter()
+                .any(|doc| match &doc.document {
+                    StackedDocument::Boxscore { game_id, .. } => {
+                        missing(data.boxscores.contains_key(game_id), FetchKind::Boxscore)
+                    }
+                    StackedDocument::TeamDetail { abbrev } => missing(
+                        data.team_roster_stats.contains_key(abbrev),
+                        FetchKind::TeamRosterStats,
+                    ),
+                    StackedDocument::PlayerDetail { player_id, .. } => missing(
+                        data.player_data.contains_key(player_id),
+                        FetchKind::PlayerStats,
+                    ),
+                    StackedDocument::PlayerCompare {
+                        player_a, player_b, ..
+                    } => missing(
+                        data.player_data.contains_key(player_a)
+                            && data.player_data.contains_key(player_b),
+                        FetchKind::PlayerStats,
+                    ),
+                })
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct NavigationState {
     pub current_tab: Tab,
     pub document_stack: Vec<DocumentStackEntry>,
     /// Whether focus is on content (true) or tab bar (false)
     pub content_focused: bool,
+    /// Player marked with `c`, waiting for a second player to compare against
+    pub compare_pending: Option<ComparePending>,
+    /// Search overlay opened with `/` or `:`; captures keys while open
+    pub command_palette: Option<CommandPaletteState>,
+    /// Key binding overlay opened with `?`; any key closes it
+    pub help_open: bool,
+    /// Header and description pairs of the focused table, shown with `L`; any key closes it
+    pub column_legend: Option<Vec<(String, String)>>,
+}
+
+/// Text typed into the command palette and the highlighted result
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CommandPaletteState {
+    pub query: String,
+    pub selected: usize,
+}
+
+/// First player picked for a comparison
+#[derive(Debug, Clone, PartialEq)]
+pub struct ComparePending {
+    pub player_id: i64,
+    pub last_name: String,
 }
 
 impl Default for NavigationState {
@@ -44,6 +125,10 @@ impl Default for NavigationState {
             current_tab: Tab::Scores,
             document_stack: Vec::new(),
             content_focused: false, // Start with tab bar focused
+            compare_pending: None,
+            command_palette: None,
+            help_open: false,
+            column_legend: None,
         }
     }
 }
@@ -51,6 +136,12 @@ impl Default for NavigationState {
 /// Default viewport height for stacked documents before terminal size is known
 const DEFAULT_VIEWPORT_HEIGHT: u16 = 30;
 
+/// How many ticks a changed score stays highlighted (about two seconds)
+const SCORE_HIGHLIGHT_FRAMES: u64 = 40;
+
+/// How many ticks an error message stays in the status bar (about eight seconds)
+const STATUS_ERROR_FRAMES: u64 = 160;
+
 /// Entry in the document stack
 ///
 /// Each stacked document (boxscore, team detail, player detail) has its own
@@ -91,19 +182,152 @@ impl DocumentStackEntry {
 #[derive(Debug, Clone, Default)]
 pub struct DataState {
     // API data - wrapped in Arc to avoid deep clones on every reducer call
-    pub standings: Arc<Option<Vec<Standing>>>,
+    pub standings: Option<Arc<Vec<Standing>>>,
     pub schedule: Arc<Option<DailySchedule>>,
     pub game_info: Arc<HashMap<i64, GameMatchup>>,
     pub period_scores: Arc<HashMap<i64, PeriodScores>>,
-    pub boxscores: Arc<HashMap<i64, Boxscore>>,
+    /// Each boxscore is shared with the documents rendering it, not cloned per frame
+    pub boxscores: Arc<HashMap<i64, Arc<Boxscore>>>,
     pub team_roster_stats: Arc<HashMap<String, ClubStats>>,
+    /// Current rosters, used for sweater numbers
+    pub team_rosters: Arc<HashMap<String, Roster>>,
+    /// Current week of games for each team, in start order
+    pub team_schedules: Arc<HashMap<String, Vec<ScheduleGame>>>,
     pub player_data: Arc<HashMap<i64, PlayerLanding>>,
+    /// Current season regular season game logs, most recent game first
+    pub player_game_logs: Arc<HashMap<i64, Vec<GameLog>>>,
 
     // Loading states
     pub loading: HashSet<LoadingKey>,
 
     // Errors
-    pub errors: HashMap<String, String>,
+    /// Last failure of each request type, cleared when that request next succeeds
+    pub errors: HashMap<FetchKind, String>,
+    /// Most recent fetch failure, shown in the status bar until any fetch succeeds
+    pub last_fetch_error: Option<String>,
+
+    /// Frozen copy of the API data, rendered instead of the live data while pinned
+    pub pinned: Option<Box<DataState>>,
+}
+
+impl DataState {
+    /// The data to render: the pinned snapshot while pinned, otherwise the live data
+    pub fn displayed(&self) -> &DataState {
+        self.pinned.as_deref().unwrap_or(self)
+    }
+
+    /// State of a game, from its boxscore when loaded, otherwise from the schedule
+    pub fn game_state(&self, game_id: i64) -> Option<GameState> {
+        if let Some(boxscore) = self.boxscores.get(&game_id) {
+            return Some(boxscore.game_state);
+        }
+        self.schedule
+            .as_ref()
+            .as_ref()?
+            .games
+            .iter()
+            .find(|game| game.id == game_id)
+            .map(|game| game.game_state)
+    }
+
+    /// Record a failed fetch
+    pub fn record_fetch_error(&mut self, kind: FetchKind, message: String) {
+        self.errors.insert(kind, message.clone());
+        self.last_fetch_error = Some(message);
+    }
+
+    /// Record a successful fetch; reaching the API at all clears the status bar error
+    pub fn record_fetch_success(&mut self, kind: FetchKind) {
+        self.errors.remove(&kind);
+        self.last_fetch_error = None;
+    }
+
+    /// Whether a refresh of standings or the schedule is still on its way
+    pub fn refresh_in_flight(&self) -> bool {
+        self.loading
+            .iter()
+            .any(|key| matches!(key, LoadingKey::Standings | LoadingKey::Schedule(_)))
+    }
+
+    /// Forget pending schedule fetches; a failed load does not say which date it was for
+    pub fn clear_schedule_loading(&mut self) {
+        self.loading
+            .retain(|key| !matches!(key, LoadingKey::Schedule(_)));
+    }
+
+    pub fn is_pinned(&self) -> bool {
+        self.pinned.is_some()
+    }
+
+    /// Freeze the current data; later fetches only update the live data
+    pub fn pin(&mut self) {
+        let snapshot = DataState {
+            pinned: None,
+            ..self.clone()
+        };
+        self.pinned = Some(Box::new(snapshot));
+    }
+
+    pub fn unpin(&mut self) {
+        self.pinned = None;
+    }
+
+    /// Copy live data the pinned snapshot doesn't have yet
+    ///
+    /// Pinning freezes what is already on screen, but documents opened (or dates
+    /// navigated to) after pinning still need their data to show up.
+    pub fn fill_pinned(&mut self) {
+        let Some(pinned) = self.pinned.as_deref_mut() else {
+            return;
+        };
+
+        if pinned.standings.is_none() {
+            pinned.standings = self.standings.clone();
+        }
+        let schedule_date = |schedule: &Option<DailySchedule>| {
+            schedule.as_ref().map(|schedule| schedule.date.clone())
+        };
+        if self.schedule.is_some()
+            && schedule_date(&pinned.schedule) != schedule_date(&self.schedule)
+        {
+            pinned.schedule = self.schedule.clone();
+        }
+        fill_missing(&mut pinned.game_info, &self.game_info);
+        fill_missing(&mut pinned.period_scores, &self.period_scores);
+        fill_missing(&mut pinned.boxscores, &self.boxscores);
+        fill_missing(&mut pinned.team_roster_stats, &self.team_roster_stats);
+        fill_missing(&mut pinned.team_rosters, &self.team_rosters);
+        fill_missing(&mut pinned.team_schedules, &self.team_schedules);
+        fill_missing(&mut pinned.player_data, &self.player_data);
+        fill_missing(&mut pinned.player_game_logs, &self.player_game_logs);
+    }
+}
+
+fn fill_missing<K, V>(pinned: &mut Arc<HashMap<K, V>>, live: &HashMap<K, V>)
+where
+    K: Clone + Eq + Hash,
+    V: Clone,
+{
+    if live.keys().all(|key| pinned.contains_key(key)) {
+        return;
+    }
+    let pinned = Arc::make_mut(pinned);
+    for (key, value) in live {
+        pinned.entry(key.clone()).or_insert_with(|| value.clone());
+    }
+}
+
+/// Kind of API request, for tracking failures
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FetchKind {
+    Standings,
+    Schedule,
+    GameDetails,
+    Boxscore,
+    TeamRosterStats,
+    TeamSchedule,
+    PlayerStats,
+    PlayerGameLog,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -113,13 +337,55 @@ pub enum LoadingKey {
     GameDetails(i64),
     Boxscore(i64),
     TeamRosterStats(String), // Team abbreviation
+    TeamRoster(String),      // Team abbreviation
+    TeamSchedule(String),    // Team abbreviation
     PlayerStats(i64),
+    PlayerGameLog(i64),
 }
 
 #[derive(Debug, Clone, Default)]
 pub struct UiState {
     pub scores: ScoresUiState,
     pub settings: SettingsUiState,
+    pub season: SeasonSelection,
+}
+
+/// Season and game type the standings and scores show
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SeasonSelection {
+    /// Standings season, e.g. 20232024; None follows the current season
+    pub season: Option<i64>,
+    /// Only games of this type are listed on the scores tab; None lists every game
+    pub game_type: Option<GameType>,
+}
+
+impl SeasonSelection {
+    /// The selection configured in config.toml
+    pub fn from_config(config: &Config) -> Self {
+        Self {
+            season: config.season,
+            game_type: config.game_type,
+        }
+    }
+
+    /// Move `delta` seasons back (negative) or forward from the selected season
+    ///
+    /// Reaching `current` or beyond follows the current season again.
+    pub fn shift_season(&mut self, delta: i64, current: i64) {
+        let start_year = self.season.unwrap_or(current) / 10000 + delta;
+        let season = (start_year * 10000 + start_year + 1).max(FIRST_SEASON);
+        self.season = (season < current).then_some(season);
+    }
+
+    /// Step through every game → Preseason → Regular Season → Playoffs → every game
+    pub fn cycle_game_type(&mut self) {
+        self.game_type = match self.game_type {
+            None => Some(GameType::Preseason),
+            Some(GameType::Preseason) => Some(GameType::RegularSeason),
+            Some(GameType::RegularSeason) => Some(GameType::Playoffs),
+            Some(GameType::Playoffs) | Some(GameType::AllStar) => None,
+        };
+    }
 }
 
 /// UI state for Scores tab (minimal - most state in component-local ScoresTabState)
@@ -135,16 +401,33 @@ pub struct UiState {
 #[derive(Debug, Clone, PartialEq)]
 pub struct ScoresUiState {
     pub game_date: GameDate,
+    /// Games whose score changed, or that newly appeared, on the latest fetch,
+    /// keyed by game id with the `frame_count` at which the change was seen
+    pub score_changes: HashMap<i64, u64>,
 }
 
 impl Default for ScoresUiState {
     fn default() -> Self {
         Self {
             game_date: GameDate::today(),
+            score_changes: HashMap::new(),
         }
     }
 }
 
+impl ScoresUiState {
+    /// Record a score change for a game at the given frame
+    pub fn mark_score_change(&mut self, game_id: i64, frame: u64) {
+        self.score_changes.insert(game_id, frame);
+    }
+
+    /// Drop score changes that have been highlighted for long enough
+    pub fn expire_score_changes(&mut self, frame: u64) {
+        self.score_changes
+            .retain(|_, changed_at| frame.saturating_sub(*changed_at) < SCORE_HIGHLIGHT_FRAMES);
+    }
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct SettingsUiState {
     pub selected_category: SettingsCategory,
@@ -152,35 +435,56 @@ pub struct SettingsUiState {
 
 /// Default help message shown in the status bar
 pub const DEFAULT_STATUS_MESSAGE: &str =
-    "Keys: ←→ navigate | ↓ enter | ↑/ESC back | q quit | 1-6 jump to tab | / command palette";
+    "Keys: ←→ navigate | ↓ enter | ↑/ESC back | q quit | 1-6 jump to tab | / command palette | ? help";
 
 #[derive(Debug, Clone, Default)]
 pub struct SystemState {
     pub last_refresh: Option<SystemTime>,
-    pub config: Config,
+    /// Shared with tab props so rendering doesn't copy it; changed with `Arc::make_mut`
+    pub config: Arc<Config>,
     pub status_message: Option<String>,
     pub status_is_error: bool,
+    /// `frame_count` at which the current error message is cleared
+    pub status_expires_at: Option<u64>,
     /// Cached terminal width for calculating game grid layout
     /// Updated during render, used by key handlers
     pub terminal_width: u16,
-    /// Animation frame counter for loading animations (0-8, wraps)
+    /// Animation frame counter for loading animations (wraps at `animation_cycle`)
     pub animation_frame: u8,
+    /// Ticks since startup; unlike `animation_frame` it never wraps
+    pub frame_count: u64,
+    /// When the displayed data was pinned (None while showing live data)
+    pub pinned_at: Option<SystemTime>,
 }
 
 impl SystemState {
     pub fn set_status_message(&mut self, message: String) {
         self.status_message = Some(message);
         self.status_is_error = false;
+        self.status_expires_at = None;
     }
 
+    /// Show an error that clears itself after a few seconds
     pub fn set_status_error_message(&mut self, message: String) {
         self.status_message = Some(message);
         self.status_is_error = true;
+        self.status_expires_at = Some(self.frame_count + STATUS_ERROR_FRAMES);
     }
 
     pub fn reset_status_message(&mut self) {
         self.status_message = Some(DEFAULT_STATUS_MESSAGE.to_string());
         self.status_is_error = false;
+        self.status_expires_at = None;
+    }
+
+    /// Restore the default message once a transient error has been shown long enough
+    pub fn expire_status_message(&mut self) {
+        if self
+            .status_expires_at
+            .is_some_and(|expires_at| self.frame_count >= expires_at)
+        {
+            self.reset_status_message();
+        }
     }
 }
 
@@ -188,6 +492,91 @@ impl SystemState {
 mod tests {
     use super::*;
 
+    fn schedule(date: &str) -> DailySchedule {
+        DailySchedule {
+            next_start_date: None,
+            previous_start_date: None,
+            date: date.to_string(),
+            games: Vec::new(),
+            number_of_games: 0,
+        }
+    }
+
+    #[test]
+    fn test_pinned_data_keeps_snapshot() {
+        let mut data = DataState {
+            schedule: Arc::new(Some(schedule("2024-11-01"))),
+            ..Default::default()
+        };
+        data.pin();
+
+        data.schedule = Arc::new(Some(schedule("2024-11-01")));
+        data.standings = Some(Arc::new(Vec::new()));
+        data.fill_pinned();
+
+        assert!(data.is_pinned());
+        // Data the snapshot lacked is filled in, data it had stays frozen
+        assert!(data.displayed().standings.is_some());
+        assert!(Arc::ptr_eq(
+            &data.displayed().schedule,
+            &data.pinned.as_ref().unwrap().schedule
+        ));
+
+        data.unpin();
+        assert!(!data.is_pinned());
+        assert!(Arc::ptr_eq(&data.displayed().schedule, &data.schedule));
+    }
+
+    #[test]
+    fn test_pinned_data_takes_schedule_for_new_date() {
+        let mut data = DataState {
+            schedule: Arc::new(Some(schedule("2024-11-01"))),
+            ..Default::default()
+        };
+        data.pin();
+
+        data.schedule = Arc::new(Some(schedule("2024-11-02")));
+        data.fill_pinned();
+
+        let displayed = data.displayed().schedule.as_ref().as_ref().unwrap();
+        assert_eq!(displayed.date, "2024-11-02");
+    }
+
+    #[test]
+    fn test_failed_fetch_stops_waiting_for_data() {
+        let mut state = AppState::default();
+        state.data.schedule = Arc::new(Some(schedule("2024-10-08")));
+        assert!(state.waiting_for_data());
+
+        state.data.record_fetch_error(
+            FetchKind::Standings,
+            "Failed to load standings: timeout".into(),
+        );
+        assert!(!state.waiting_for_data());
+        assert_eq!(
+            state.data.last_fetch_error.as_deref(),
+            Some("Failed to load standings: timeout")
+        );
+
+        // A boxscore still on its way keeps the animation going
+        state
+            .navigation
+            .document_stack
+            .push(DocumentStackEntry::new(StackedDocument::Boxscore {
+                game_id: 1,
+                away_abbrev: "TOR".to_string(),
+                home_abbrev: "MTL".to_string(),
+                away_score: 0,
+                home_score: 0,
+            }));
+        assert!(state.waiting_for_data());
+
+        // Any success clears the status bar error, but not other kinds' errors
+        state.data.record_fetch_success(FetchKind::Boxscore);
+        assert_eq!(state.data.last_fetch_error, None);
+        assert!(state.data.errors.contains_key(&FetchKind::Standings));
+    }
+
     #[test]
     fn test_set_status_message() {
         let mut state = SystemState::default();
@@ -265,4 +654,39 @@ mod tests {
         state.reset_status_message();
         assert!(!state.status_is_error);
     }
+
+    #[test]
+    fn test_shift_season() {
+        let current = 20242025;
+        let mut selection = SeasonSelection::default();
+
+        selection.shift_season(-1, current);
+        assert_eq!(selection.season, Some(20232024));
+        selection.shift_season(-2, current);
+        assert_eq!(selection.season, Some(20212022));
+        selection.shift_season(3, current);
+        assert_eq!(selection.season, None);
+
+        selection.shift_season(-200, current);
+        assert_eq!(selection.season, Some(FIRST_SEASON));
+    }
+
+    #[test]
+    fn test_cycle_game_type() {
+        let mut selection = SeasonSelection::default();
+        let mut seen = Vec::new();
+        for _ in 0..4 {
+            selection.cycle_game_type();
+            seen.push(selection.game_type);
+        }
+        assert_eq!(
+            seen,
+            vec![
+                Some(GameType::Preseason),
+                Some(GameType::RegularSeason),
+                Some(GameType::Playoffs),
+                None
+            ]
+        );
+    }
 }
@@ -3,6 +3,7 @@
 /// This module provides core types for building tables with mixed cell types:
 /// - CellValue: Text or clickable links (Player, Team)
 /// - ColumnDef: Column definition with cell extraction function
+/// - ColumnTotal: How a column is aggregated in a totals row
 /// - Alignment: Text alignment for cells
 /// - TableProps: Props for Table component
 use std::fmt;
@@ -24,12 +25,18 @@ pub enum CellValue {
         display: String,
         team_abbrev: String,
     },
+
+    /// Link to a game's boxscore (focusable)
+    GameLink { display: String, game_id: i64 },
 }
 
 impl CellValue {
     /// Returns true if this cell is a link (focusable)
     pub fn is_link(&self) -> bool {
-        matches!(self, Self::PlayerLink { .. } | Self::TeamLink { .. })
+        matches!(
+            self,
+            Self::PlayerLink { .. } | Self::TeamLink { .. } | Self::GameLink { .. }
+        )
     }
 
     /// Get the display text for this cell
@@ -38,6 +45,7 @@ impl CellValue {
             Self::Text(s) => s,
             Self::PlayerLink { display, .. } => display,
             Self::TeamLink { display, .. } => display,
+            Self::GameLink { display, .. } => display,
         }
     }
 
@@ -54,10 +62,20 @@ impl CellValue {
             } => {
                 format!("TeamLink(display='{}', abbrev='{}')", display, team_abbrev)
             }
+            Self::GameLink { display, game_id } => {
+                format!("GameLink(display='{}', id={})", display, game_id)
+            }
         }
     }
 }
 
+/// Sort applied to a table: column index and direction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TableSort {
+    pub column: usize,
+    pub ascending: bool,
+}
+
 /// Text alignment for table cells
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Alignment {
@@ -104,14 +122,59 @@ pub struct ColumnDef<T> {
     /// Column header text
     pub header: String,
 
-    /// Column width in characters
+    /// Column width in characters (the minimum width when auto-sized)
     pub width: usize,
 
+    /// Whether the width grows to fit the header and cell contents
+    pub auto_width: bool,
+
+    /// Upper bound for an auto-sized width
+    pub max_width: Option<usize>,
+
     /// Text alignment
     pub align: Alignment,
 
     /// Function to extract cell value from row data
     pub cell_fn: Box<dyn Fn(&T) -> CellValue + Send + Sync>,
+
+    /// How this column is aggregated in a totals row
+    pub total: ColumnTotal<T>,
+
+    /// Whether cells are colored by the sign of their value
+    pub signed: bool,
+
+    /// Priority for hiding the column when the table doesn't fit its area
+    ///
+    /// Lower priorities are hidden first; `None` keeps the column, scrolling instead.
+    pub priority: Option<u8>,
+
+    /// Full name of an abbreviated header, shown in the column legend
+    pub description: Option<String>,
+}
+
+/// Aggregate function over the typed rows of a table
+pub type TotalFn<T> = Box<dyn Fn(&[T]) -> String + Send + Sync>;
+
+/// How a column is aggregated in a table's totals row
+pub enum ColumnTotal<T> {
+    /// Leave the totals cell empty
+    Blank,
+    /// Fixed text, e.g. "TOTAL"
+    Label(String),
+    /// Sum of the column's integer cells (other cells are skipped)
+    Sum,
+    /// Custom aggregate computed from the typed rows
+    Custom(TotalFn<T>),
+}
+
+impl<T> ColumnTotal<T> {
+    /// Custom aggregate over the typed rows
+    pub fn custom<F>(f: F) -> Self
+    where
+        F: Fn(&[T]) -> String + Send + Sync + 'static,
+    {
+        Self::Custom(Box::new(f))
+    }
 }
 
 impl<T> ColumnDef<T> {
@@ -131,6 +194,67 @@ impl<T> ColumnDef<T> {
             width,
             align,
             cell_fn: Box::new(cell_fn),
+            total: ColumnTotal::Blank,
+            auto_width: false,
+            max_width: None,
+            signed: false,
+            priority: None,
+            description: None,
+        }
+    }
+
+    /// Size the column to its widest header or cell instead of the fixed width
+    ///
+    /// The fixed width becomes the minimum.
+    pub fn auto_width(mut self) -> Self {
+        self.auto_width = true;
+        self
+    }
+
+    /// Cap the width of an auto-sized column
+    pub fn with_max_width(mut self, max_width: usize) -> Self {
+        self.max_width = Some(max_width);
+        self
+    }
+
+    /// Color cells by sign: "+" values positive, "-" values with `error_fg`
+    pub fn signed(mut self) -> Self {
+        self.signed = true;
+        self
+    }
+
+    /// Let the column be hidden when the table is too narrow, before resorting to scrolling
+    ///
+    /// Columns are dropped lowest priority first (right to left among equal
+    /// priorities) until the rest fit.
+    pub fn with_priority(mut self, priority: u8) -> Self {
+        self.priority = Some(priority);
+        self
+    }
+
+    /// Spell out an abbreviated header (e.g. "SOG" as "Shots on goal") in the column legend
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Set how this column is aggregated in a totals row
+    pub fn with_total(mut self, total: ColumnTotal<T>) -> Self {
+        self.total = total;
+        self
+    }
+
+    /// Totals-row text for this column over the given rows
+    pub fn total_text(&self, rows: &[T]) -> String {
+        match &self.total {
+            ColumnTotal::Blank => String::new(),
+            ColumnTotal::Label(label) => label.clone(),
+            ColumnTotal::Sum => rows
+                .iter()
+                .filter_map(|row| (self.cell_fn)(row).display_text().parse::<i64>().ok())
+                .sum::<i64>()
+                .to_string(),
+            ColumnTotal::Custom(f) => f(rows),
         }
     }
 }
@@ -149,7 +273,11 @@ impl<T> fmt::Debug for ColumnDef<T> {
         f.debug_struct("ColumnDef")
             .field("header", &self.header)
             .field("width", &self.width)
+            .field("auto_width", &self.auto_width)
             .field("align", &self.align)
+            .field("signed", &self.signed)
+            .field("priority", &self.priority)
+            .field("description", &self.description)
             .field("cell_fn", &"<function>")
             .finish()
     }
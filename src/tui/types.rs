@@ -13,6 +13,31 @@ pub enum Tab {
     Demo,
 }
 
+impl Tab {
+    /// Stable identifier, used as the tab bar key and in the session file
+    pub fn key(&self) -> &'static str {
+        match self {
+            Self::Scores => "scores",
+            Self::Standings => "standings",
+            Self::Settings => "settings",
+            #[cfg(feature = "development")]
+            Self::Demo => "demo",
+        }
+    }
+
+    /// Tab with the given key, if it exists in this build
+    pub fn from_key(key: &str) -> Option<Self> {
+        match key {
+            "scores" => Some(Self::Scores),
+            "standings" => Some(Self::Standings),
+            "settings" => Some(Self::Settings),
+            #[cfg(feature = "development")]
+            "demo" => Some(Self::Demo),
+            _ => None,
+        }
+    }
+}
+
 /// Document types for drill-down views (pushed onto document stack)
 #[derive(Debug, Clone)]
 pub enum StackedDocument {
@@ -33,6 +58,13 @@ pub enum StackedDocument {
         /// Player last name (e.g., "Crosby")
         last_name: String,
     },
+    PlayerCompare {
+        player_a: i64,
+        player_b: i64,
+        /// Last names of both players, for the breadcrumb
+        last_name_a: String,
+        last_name_b: String,
+    },
 }
 
 /// Settings category enum
@@ -70,6 +102,11 @@ impl StackedDocument {
                     last_name.clone()
                 }
             }
+            Self::PlayerCompare {
+                last_name_a,
+                last_name_b,
+                ..
+            } => format!("{} vs {}", last_name_a, last_name_b),
         }
     }
 }
@@ -7,13 +7,16 @@
 //! █  █  ───  █  █  ───    ▗▛
 //! █  █        ▄▄▛        ▗▛
 //! ▜▄▄▛       ▜▄▄▛       ▄█▄▄
+//!         Final (SO)
 //! ```
 
 use crate::big_digits::{BIG_DIGITS, BIG_DIGIT_HEIGHT, BIG_DIGIT_WIDTH};
 use crate::config::DisplayConfig;
-use ratatui::{buffer::Buffer, layout::Rect};
+use crate::team_abbrev::abbrev_to_colors;
+use ratatui::{buffer::Buffer, layout::Rect, style::Style};
+use unicode_width::UnicodeWidthStr;
 
-use super::StandaloneWidget;
+use super::{ScoreBoxStatus, StandaloneWidget};
 
 /// Separator between score digits (dash)
 const SEPARATOR: [&str; 4] = ["    ", " ── ", "    ", "    "];
@@ -24,6 +27,8 @@ const SEPARATOR_WIDTH: u16 = 4;
 /// Layout:
 /// - Row 0: Team abbreviations (left-aligned away, right-aligned home)
 /// - Rows 1-4: Big digit score with separator
+/// - Next row: Game status, when set (centered)
+/// - Last row: Team records, when set (placed like the abbreviations)
 #[derive(Debug, Clone)]
 pub struct BigScore {
     /// Away team abbreviation (e.g., "NJD")
@@ -34,6 +39,12 @@ pub struct BigScore {
     pub away_score: i32,
     /// Home team score
     pub home_score: i32,
+    /// Away and home records (e.g., "24-10-3"), shown below the score
+    pub records: Option<(String, String)>,
+    /// Game status shown below the score
+    pub status: Option<ScoreBoxStatus>,
+    /// Away and home shootout goals, added to a shootout final's status
+    pub shootout_goals: Option<(usize, usize)>,
 }
 
 impl BigScore {
@@ -49,9 +60,41 @@ impl BigScore {
             home_abbrev: home_abbrev.into(),
             away_score,
             home_score,
+            records: None,
+            status: None,
+            shootout_goals: None,
         }
     }
 
+    /// Show the teams' records below the score
+    pub fn with_records(mut self, records: Option<(String, String)>) -> Self {
+        self.records = records;
+        self
+    }
+
+    /// Show the game status below the score
+    pub fn with_status(mut self, status: Option<ScoreBoxStatus>) -> Self {
+        self.status = status;
+        self
+    }
+
+    /// Show shootout goal tallies in a shootout final's status, e.g. "Final (SO 2-1)"
+    pub fn with_shootout_goals(mut self, shootout_goals: Option<(usize, usize)>) -> Self {
+        self.shootout_goals = shootout_goals;
+        self
+    }
+
+    /// Status line text, with the shootout tallies for shootout finals
+    fn status_text(&self) -> Option<String> {
+        let status = self.status.as_ref()?;
+        Some(match (status, self.shootout_goals) {
+            (ScoreBoxStatus::Final { shootout: true, .. }, Some((away, home))) => {
+                format!("Final (SO {}-{})", away, home)
+            }
+            _ => status.display(),
+        })
+    }
+
     /// Get the digits for a score (handles 0-99, returns vec of digit indices)
     fn score_digits(score: i32) -> Vec<usize> {
         if score < 0 {
@@ -76,6 +119,44 @@ impl BigScore {
     fn total_score_width(&self) -> u16 {
         Self::score_width(self.away_score) + SEPARATOR_WIDTH + Self::score_width(self.home_score)
     }
+
+    /// Style for a team's digits: its primary color when colored digits are
+    /// enabled and the team is known, the regular text style otherwise
+    fn digit_style(abbrev: &str, config: &DisplayConfig) -> Style {
+        if !config.colored_score_digits {
+            return config.text_style();
+        }
+        abbrev_to_colors(abbrev)
+            .map(|(primary, _)| Style::default().fg(primary))
+            .unwrap_or_else(|| config.text_style())
+    }
+
+    /// Write a pair of team labels on one row: away right-aligned above the
+    /// away digits, home left-aligned above the home digits
+    ///
+    /// Labels wider than the digits are clipped to the area.
+    fn render_team_labels(
+        &self,
+        area: Rect,
+        y: u16,
+        (away, home): (&str, &str),
+        buf: &mut Buffer,
+        style: Style,
+    ) {
+        let score_start_x = area.x + (area.width.saturating_sub(self.total_score_width())) / 2;
+        let away_end_x = score_start_x + Self::score_width(self.away_score);
+        let away_x = away_end_x.saturating_sub(away.width() as u16).max(area.x);
+        buf.set_stringn(away_x, y, away, (away_end_x - away_x) as usize, style);
+
+        let home_x = away_end_x + SEPARATOR_WIDTH;
+        buf.set_stringn(
+            home_x,
+            y,
+            home,
+            area.right().saturating_sub(home_x) as usize,
+            style,
+        );
+    }
 }
 
 impl StandaloneWidget for BigScore {
@@ -94,18 +175,17 @@ impl StandaloneWidget for BigScore {
         let score_start_x = x + (area.width.saturating_sub(score_width)) / 2;
 
         // Row 0: Team abbreviations positioned above their respective scores
-        let away_digits_width = Self::score_width(self.away_score);
-        let home_digits_start = score_start_x + away_digits_width + SEPARATOR_WIDTH;
-
-        // Away abbrev: right-aligned above away score digits
-        let away_abbrev_x =
-            score_start_x + away_digits_width - self.away_abbrev.chars().count() as u16;
-        buf.set_string(away_abbrev_x, y, &self.away_abbrev, text_style);
-
-        // Home abbrev: left-aligned above home score digits
-        buf.set_string(home_digits_start, y, &self.home_abbrev, text_style);
+        self.render_team_labels(
+            area,
+            y,
+            (&self.away_abbrev, &self.home_abbrev),
+            buf,
+            text_style,
+        );
 
         // Rows 1-4: Big digits
+        let away_style = Self::digit_style(&self.away_abbrev, config);
+        let home_style = Self::digit_style(&self.home_abbrev, config);
         let away_digits = Self::score_digits(self.away_score);
         let home_digits = Self::score_digits(self.home_score);
 
@@ -115,7 +195,7 @@ impl StandaloneWidget for BigScore {
             // Away score digits
             for &digit in &away_digits {
                 let line = BIG_DIGITS[digit][row as usize];
-                buf.set_string(current_x, y + 1 + row, line, text_style);
+                buf.set_string(current_x, y + 1 + row, line, away_style);
                 current_x += BIG_DIGIT_WIDTH;
             }
 
@@ -126,15 +206,48 @@ impl StandaloneWidget for BigScore {
             // Home score digits
             for &digit in &home_digits {
                 let line = BIG_DIGITS[digit][row as usize];
-                buf.set_string(current_x, y + 1 + row, line, text_style);
+                buf.set_string(current_x, y + 1 + row, line, home_style);
                 current_x += BIG_DIGIT_WIDTH;
             }
         }
+
+        let mut row_y = y + 1 + BIG_DIGIT_HEIGHT;
+
+        // Status centered below the digits, if there is room for it
+        if let (Some(status), Some(text)) = (&self.status, self.status_text()) {
+            if row_y < area.bottom() {
+                let text_width = (text.width() as u16).min(area.width);
+                let status_x = x + (area.width - text_width) / 2;
+                buf.set_stringn(
+                    status_x,
+                    row_y,
+                    &text,
+                    text_width as usize,
+                    status.style(config),
+                );
+                row_y += 1;
+            }
+        }
+
+        // Records, if there is room for them
+        if let Some((away_record, home_record)) = &self.records {
+            if row_y < area.bottom() {
+                self.render_team_labels(
+                    area,
+                    row_y,
+                    (away_record, home_record),
+                    buf,
+                    config.muted_style(),
+                );
+            }
+        }
     }
 
     fn preferred_height(&self) -> Option<u16> {
-        // 1 row for abbrevs + 4 rows for digits
-        Some(BIG_DIGIT_HEIGHT + 1)
+        // 1 row for abbrevs + 4 rows for digits (+ 1 row each for status and records)
+        let status_height = u16::from(self.status.is_some());
+        let records_height = u16::from(self.records.is_some());
+        Some(BIG_DIGIT_HEIGHT + 1 + status_height + records_height)
     }
 
     fn preferred_width(&self) -> Option<u16> {
@@ -186,6 +299,127 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_colored_score_digits() {
+        let widget = BigScore::new("NJD", "BUF", 3, 2);
+        let mut config = test_config();
+        config.theme_name = Some("orange".to_string());
+        config.apply_theme();
+        config.colored_score_digits = true;
+        let neutral = config.theme.clone().unwrap().fg2;
+        let buf = render_widget_with_config(&widget, 20, 5, &config);
+
+        let (njd, _) = abbrev_to_colors("NJD").unwrap();
+        let (buf_color, _) = abbrev_to_colors("BUF").unwrap();
+        // Away digits at x=4..8, separator at 8..12, home digits at 12..16
+        assert_eq!(buf[(4, 1)].fg, njd);
+        assert_eq!(buf[(7, 4)].fg, njd);
+        assert_eq!(buf[(9, 2)].fg, neutral);
+        assert_eq!(buf[(12, 1)].fg, buf_color);
+        assert_eq!(buf[(15, 4)].fg, buf_color);
+        // Abbreviations keep the regular text style
+        assert_eq!(buf[(5, 0)].fg, neutral);
+    }
+
+    #[test]
+    fn test_unknown_team_digits_use_text_style() {
+        let widget = BigScore::new("XXX", "BUF", 3, 2);
+        let mut config = test_config();
+        config.theme_name = Some("orange".to_string());
+        config.apply_theme();
+        config.colored_score_digits = true;
+        let buf = render_widget_with_config(&widget, 20, 5, &config);
+
+        assert_eq!(buf[(4, 1)].fg, config.theme.clone().unwrap().fg2);
+    }
+
+    #[test]
+    fn test_records_below_score() {
+        let widget = BigScore::new("NJD", "BUF", 0, 0)
+            .with_records(Some(("24-10-3".to_string(), "9-12-1".to_string())));
+        let config = test_config();
+        let buf = render_widget_with_config(&widget, 20, 6, &config);
+
+        assert_eq!(widget.preferred_height(), Some(6));
+        assert_buffer(
+            &buf,
+            &[
+                "     NJD    BUF     ",
+                "    ▟▀▀▙    ▟▀▀▙    ",
+                "    █  █ ── █  █    ",
+                "    █  █    █  █    ",
+                "    ▜▄▄▛    ▜▄▄▛    ",
+                " 24-10-3    9-12-1  ",
+            ],
+        );
+    }
+
+    #[test]
+    fn test_shootout_final_status_below_score() {
+        let widget = BigScore::new("NJD", "BUF", 3, 2)
+            .with_status(Some(ScoreBoxStatus::Final {
+                overtime: false,
+                shootout: true,
+            }))
+            .with_shootout_goals(Some((2, 1)));
+        let config = test_config();
+        let buf = render_widget_with_config(&widget, 20, 6, &config);
+
+        assert_eq!(widget.preferred_height(), Some(6));
+        assert_buffer(
+            &buf,
+            &[
+                "     NJD    BUF     ",
+                "    ▟▀▀▙    ▟▀▀▙    ",
+                "     ▄▄▛ ──   ▗▛    ",
+                "       █     ▗▛     ",
+                "    ▜▄▄▛    ▄█▄▄    ",
+                "   Final (SO 2-1)   ",
+            ],
+        );
+    }
+
+    #[test]
+    fn test_status_without_shootout_goals() {
+        let status = |overtime, shootout| {
+            BigScore::new("NJD", "BUF", 3, 2)
+                .with_status(Some(ScoreBoxStatus::Final { overtime, shootout }))
+                .status_text()
+        };
+
+        assert_eq!(status(true, false).as_deref(), Some("Final (OT)"));
+        assert_eq!(status(false, true).as_deref(), Some("Final (SO)"));
+        assert_eq!(status(false, false).as_deref(), Some("Final"));
+        // Tallies only apply to shootouts
+        let overtime = BigScore::new("NJD", "BUF", 3, 2)
+            .with_status(Some(ScoreBoxStatus::Final {
+                overtime: true,
+                shootout: false,
+            }))
+            .with_shootout_goals(Some((1, 0)));
+        assert_eq!(overtime.status_text().as_deref(), Some("Final (OT)"));
+    }
+
+    #[test]
+    fn test_records_clipped_to_area() {
+        let widget = BigScore::new("NJD", "BUF", 0, 0)
+            .with_records(Some(("124-110-13".to_string(), "119-112-11".to_string())));
+        let config = test_config();
+        let buf = render_widget_with_config(&widget, 12, 6, &config);
+
+        assert_buffer(
+            &buf,
+            &[
+                " NJD    BUF",
+                "▟▀▀▙    ▟▀▀▙",
+                "█  █ ── █  █",
+                "█  █    █  █",
+                "▜▄▄▛    ▜▄▄▛",
+                "124-    119-",
+            ],
+        );
+    }
+
     #[test]
     fn test_score_digits() {
         assert_eq!(BigScore::score_digits(0), vec![0]);
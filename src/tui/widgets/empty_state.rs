@@ -0,0 +1,133 @@
+//! Empty state widget for lists that loaded but have nothing to show
+//!
+//! Renders a small icon above a message and an optional detail line, all centered,
+//! so an empty day reads differently from the loading animation:
+//!
+//! ```text
+//!           ∅
+//!   No games scheduled
+//!       2024-01-15
+//! ```
+
+use ratatui::{buffer::Buffer, layout::Rect, style::Style};
+use unicode_width::UnicodeWidthStr;
+
+use super::StandaloneWidget;
+use crate::config::DisplayConfig;
+
+const UNICODE_ICON: &str = "∅";
+const ASCII_ICON: &str = "--";
+
+/// Centered icon, message and optional detail (such as the date)
+#[derive(Debug, Clone, PartialEq)]
+pub struct EmptyState {
+    pub message: String,
+    pub detail: Option<String>,
+}
+
+impl EmptyState {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            detail: None,
+        }
+    }
+
+    /// Set the line shown under the message
+    pub fn with_detail(mut self, detail: impl Into<String>) -> Self {
+        self.detail = Some(detail.into());
+        self
+    }
+
+    fn lines<'a>(&'a self, config: &DisplayConfig) -> Vec<(&'a str, Style)> {
+        let icon = if config.use_unicode {
+            UNICODE_ICON
+        } else {
+            ASCII_ICON
+        };
+        let mut lines = vec![
+            (icon, config.muted_style()),
+            (self.message.as_str(), config.emphasis_style()),
+        ];
+        if let Some(detail) = &self.detail {
+            lines.push((detail.as_str(), config.muted_style()));
+        }
+        lines
+    }
+}
+
+impl StandaloneWidget for EmptyState {
+    fn render(&self, area: Rect, buf: &mut Buffer, config: &DisplayConfig) {
+        let lines = self.lines(config);
+        let top = area.y + area.height.saturating_sub(lines.len() as u16) / 2;
+
+        for (y, (line, style)) in (top..area.bottom()).zip(lines) {
+            let width = (line.width() as u16).min(area.width);
+            let x = area.x + (area.width - width) / 2;
+            buf.set_stringn(x, y, line, width as usize, style);
+        }
+    }
+
+    fn preferred_height(&self) -> Option<u16> {
+        Some(if self.detail.is_some() { 3 } else { 2 })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tui::testing::assert_buffer;
+
+    #[test]
+    fn test_renders_centered_in_area() {
+        let empty = EmptyState::new("No games scheduled").with_detail("2024-01-15");
+        let area = Rect::new(0, 0, 24, 5);
+        let mut buf = Buffer::empty(area);
+
+        empty.render(area, &mut buf, &DisplayConfig::default());
+
+        assert_buffer(
+            &buf,
+            &[
+                "                        ",
+                "           ∅            ",
+                "   No games scheduled   ",
+                "       2024-01-15       ",
+                "                        ",
+            ],
+        );
+    }
+
+    #[test]
+    fn test_renders_at_area_offset_without_detail() {
+        let empty = EmptyState::new("Nothing");
+        let config = DisplayConfig {
+            use_unicode: false,
+            ..DisplayConfig::default()
+        };
+        let mut buf = Buffer::empty(Rect::new(0, 0, 12, 4));
+
+        empty.render(Rect::new(2, 1, 10, 2), &mut buf, &config);
+
+        assert_buffer(
+            &buf,
+            &[
+                "            ",
+                "      --    ",
+                "   Nothing  ",
+                "            ",
+            ],
+        );
+    }
+
+    #[test]
+    fn test_preferred_height() {
+        assert_eq!(EmptyState::new("Nothing").preferred_height(), Some(2));
+        assert_eq!(
+            EmptyState::new("Nothing")
+                .with_detail("today")
+                .preferred_height(),
+            Some(3)
+        );
+    }
+}
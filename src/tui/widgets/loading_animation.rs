@@ -1,6 +1,7 @@
 //! Loading animation widget for display during data fetching
 //!
-//! Renders a pulsing dots animation that cycles quickly to indicate loading state.
+//! Renders a pulsing dots animation (or the spinner chosen by `display.loading_style`)
+//! that cycles quickly to indicate loading state.
 
 use ratatui::{
     buffer::Buffer,
@@ -9,27 +10,54 @@ use ratatui::{
 };
 
 use super::StandaloneWidget;
-use crate::config::DisplayConfig;
+use crate::config::{DisplayConfig, LoadingStyle};
 
-/// Number of dots in the animation
-const DOT_COUNT: usize = 3;
+const DOT_FRAMES: &[&str] = &["●○○", "○●○", "○○●", "○●○"];
+const BRAILLE_FRAMES: &[&str] = &["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+const ASCII_FRAMES: &[&str] = &["-", "\\", "|", "/"];
 
-/// Get the animation string for the given frame
-pub fn loading_animation_text(frame: u8) -> &'static str {
-    match frame % 4 {
-        0 => "●○○",
-        1 => "○●○",
-        2 => "○○●",
-        _ => "○●○",
+/// Width of the widest animation frame
+const MAX_FRAME_WIDTH: u16 = 3;
+
+/// Number of frames after which every style is back at its first frame
+const ANIMATION_CYCLE: u8 = 20;
+
+/// Number of ticks after which `animation_frame` wraps for the given frame divisor
+///
+/// Keeping the wrap a multiple of the divisor stops the animation from skipping
+/// frames when the counter wraps (very large divisors saturate at `u8::MAX`).
+pub fn animation_cycle(divisor: u8) -> u8 {
+    ANIMATION_CYCLE.saturating_mul(divisor.max(1))
+}
+
+/// Frames for the given style; non-unicode terminals always get the ASCII spinner
+fn frames(style: LoadingStyle, use_unicode: bool) -> &'static [&'static str] {
+    match style {
+        _ if !use_unicode => ASCII_FRAMES,
+        LoadingStyle::Dots => DOT_FRAMES,
+        LoadingStyle::Braille => BRAILLE_FRAMES,
+        LoadingStyle::Ascii => ASCII_FRAMES,
     }
 }
 
-/// A loading animation widget that displays pulsing dots
+/// Get the animation string for the given tick, holding each frame for `divisor` ticks
+pub fn loading_animation_text(
+    frame: u8,
+    divisor: u8,
+    style: LoadingStyle,
+    use_unicode: bool,
+) -> &'static str {
+    let frames = frames(style, use_unicode);
+    frames[(frame / divisor.max(1)) as usize % frames.len()]
+}
+
+/// A loading animation widget that displays pulsing dots or a spinner
 ///
-/// The animation cycles through 4 frames with a dot bouncing left to right.
+/// With the default style the animation cycles through 4 frames with a dot
+/// bouncing left to right.
 #[derive(Debug, Clone)]
 pub struct LoadingAnimation {
-    /// Current animation frame (0-3, wraps automatically)
+    /// Current animation frame (wraps automatically)
     pub frame: u8,
 }
 
@@ -45,8 +73,13 @@ impl StandaloneWidget for LoadingAnimation {
             return;
         }
 
-        let text = loading_animation_text(self.frame);
-        let text_width = DOT_COUNT as u16;
+        let text = loading_animation_text(
+            self.frame,
+            config.loading_frame_divisor,
+            config.loading_style,
+            config.use_unicode,
+        );
+        let text_width = text.chars().count() as u16;
 
         // Center horizontally and vertically
         let x = area.x + (area.width.saturating_sub(text_width)) / 2;
@@ -68,7 +101,7 @@ impl StandaloneWidget for LoadingAnimation {
     }
 
     fn preferred_width(&self) -> Option<u16> {
-        Some(DOT_COUNT as u16)
+        Some(MAX_FRAME_WIDTH)
     }
 }
 
@@ -77,14 +110,74 @@ mod tests {
     use super::*;
     use crate::tui::testing::assert_buffer;
 
+    fn text(frame: u8, style: LoadingStyle) -> &'static str {
+        loading_animation_text(frame, 1, style, true)
+    }
+
     #[test]
     fn test_animation_frames() {
-        assert_eq!(loading_animation_text(0), "●○○");
-        assert_eq!(loading_animation_text(1), "○●○");
-        assert_eq!(loading_animation_text(2), "○○●");
-        assert_eq!(loading_animation_text(3), "○●○");
+        assert_eq!(text(0, LoadingStyle::Dots), "●○○");
+        assert_eq!(text(1, LoadingStyle::Dots), "○●○");
+        assert_eq!(text(2, LoadingStyle::Dots), "○○●");
+        assert_eq!(text(3, LoadingStyle::Dots), "○●○");
         // Wraps around
-        assert_eq!(loading_animation_text(4), "●○○");
+        assert_eq!(text(4, LoadingStyle::Dots), "●○○");
+    }
+
+    #[test]
+    fn test_braille_frames() {
+        assert_eq!(text(0, LoadingStyle::Braille), "⠋");
+        assert_eq!(text(1, LoadingStyle::Braille), "⠙");
+        assert_eq!(text(9, LoadingStyle::Braille), "⠏");
+        assert_eq!(text(10, LoadingStyle::Braille), "⠋");
+    }
+
+    #[test]
+    fn test_ascii_frames() {
+        assert_eq!(text(0, LoadingStyle::Ascii), "-");
+        assert_eq!(text(1, LoadingStyle::Ascii), "\\");
+        assert_eq!(text(2, LoadingStyle::Ascii), "|");
+        assert_eq!(text(3, LoadingStyle::Ascii), "/");
+        assert_eq!(text(4, LoadingStyle::Ascii), "-");
+    }
+
+    #[test]
+    fn test_non_unicode_forces_ascii() {
+        for style in [LoadingStyle::Dots, LoadingStyle::Braille] {
+            assert_eq!(loading_animation_text(1, 1, style, false), "\\");
+        }
+    }
+
+    #[test]
+    fn test_every_style_repeats_after_cycle() {
+        for style in [
+            LoadingStyle::Dots,
+            LoadingStyle::Braille,
+            LoadingStyle::Ascii,
+        ] {
+            assert_eq!(text(ANIMATION_CYCLE, style), text(0, style));
+        }
+    }
+
+    #[test]
+    fn test_divisor_holds_each_frame() {
+        let held: Vec<_> = (0..6)
+            .map(|frame| loading_animation_text(frame, 2, LoadingStyle::Ascii, true))
+            .collect();
+        assert_eq!(held, vec!["-", "-", "\\", "\\", "|", "|"]);
+        // A divisor of 0 is treated as 1
+        assert_eq!(
+            loading_animation_text(1, 0, LoadingStyle::Ascii, true),
+            "\\"
+        );
+    }
+
+    #[test]
+    fn test_animation_cycle_scales_with_divisor() {
+        assert_eq!(animation_cycle(1), 20);
+        assert_eq!(animation_cycle(0), 20);
+        assert_eq!(animation_cycle(3), 60);
+        assert_eq!(animation_cycle(100), u8::MAX);
     }
 
     #[test]
@@ -112,6 +205,36 @@ mod tests {
         assert_buffer(&buf, &["  ○○●  "]);
     }
 
+    #[test]
+    fn test_render_braille_centered() {
+        let widget = LoadingAnimation::new(1);
+        let area = Rect::new(0, 0, 7, 1);
+        let mut buf = Buffer::empty(area);
+        let config = DisplayConfig {
+            loading_style: LoadingStyle::Braille,
+            ..DisplayConfig::default()
+        };
+
+        widget.render(area, &mut buf, &config);
+
+        assert_buffer(&buf, &["   ⠙   "]);
+    }
+
+    #[test]
+    fn test_render_ascii_without_unicode() {
+        let widget = LoadingAnimation::new(2);
+        let area = Rect::new(0, 0, 5, 1);
+        let mut buf = Buffer::empty(area);
+        let config = DisplayConfig {
+            use_unicode: false,
+            ..DisplayConfig::default()
+        };
+
+        widget.render(area, &mut buf, &config);
+
+        assert_buffer(&buf, &["  |  "]);
+    }
+
     #[test]
     fn test_preferred_dimensions() {
         let widget = LoadingAnimation::new(0);
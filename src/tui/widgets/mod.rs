@@ -31,6 +31,9 @@ pub use score_box::{ScoreBox, ScoreBoxStatus};
 pub mod big_score;
 pub use big_score::BigScore;
 
+pub mod empty_state;
+pub use empty_state::EmptyState;
+
 pub mod loading_animation;
 pub use loading_animation::LoadingAnimation;
 
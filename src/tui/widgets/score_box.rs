@@ -3,12 +3,15 @@
 //! Displays a game score in a compact box format with:
 //! - Status line above (e.g., "Final", "1st 09:27", "9PM")
 //! - Double-line bordered box with team names and scores
+//! - One line per goal below the box, when scorers are known
 //!
-//! Width: 25 characters, Height: 6 rows
+//! Width: 25 characters, Height: 6 rows plus the scorer lines; the compact layout is a single
+//! 58 character line instead
 
 use crate::config::{DisplayConfig, SELECTION_STYLE_MODIFIER};
-use crate::layout_constants::{SCORE_BOX_HEIGHT, SCORE_BOX_WIDTH};
-use ratatui::{buffer::Buffer, layout::Rect};
+use crate::layout_constants::{SCORE_BOX_HEIGHT, SCORE_BOX_WIDTH, SCORE_LINE_WIDTH};
+use nhl_api::{PeriodDescriptor, PeriodType};
+use ratatui::{buffer::Buffer, layout::Rect, style::Style};
 
 use super::StandaloneWidget;
 
@@ -28,6 +31,16 @@ pub enum ScoreBoxStatus {
 }
 
 impl ScoreBoxStatus {
+    /// Final status for a game that ended in the given period
+    ///
+    /// Past the third period without a shootout counts as overtime.
+    pub fn final_for(period: &PeriodDescriptor) -> Self {
+        let shootout = period.period_type == PeriodType::Shootout;
+        let overtime =
+            !shootout && (period.number > 3 || period.period_type == PeriodType::Overtime);
+        ScoreBoxStatus::Final { overtime, shootout }
+    }
+
     /// Format the status as a display string (no leading space - render adds it)
     pub fn display(&self) -> String {
         match self {
@@ -56,6 +69,20 @@ impl ScoreBoxStatus {
             }
         }
     }
+
+    /// Style for the status text: live games stand out, finals recede
+    ///
+    /// Scheduled games, and every state with `status_accent` off, use the normal text style.
+    pub fn style(&self, config: &DisplayConfig) -> Style {
+        if !config.status_accent {
+            return config.text_style();
+        }
+        match self {
+            ScoreBoxStatus::Scheduled { .. } => config.text_style(),
+            ScoreBoxStatus::Live { .. } => config.emphasis_style(),
+            ScoreBoxStatus::Final { .. } => config.muted_style(),
+        }
+    }
 }
 
 /// Compact score box widget
@@ -69,6 +96,11 @@ impl ScoreBoxStatus {
 /// ║ Avalanche        │  3 ║
 /// ╚══════════════════╧════╝
 /// ```
+///
+/// or, when compact, as one line:
+/// ```text
+///  Golden Knights    10 @ Avalanche          3  Final
+/// ```
 #[derive(Debug, Clone)]
 pub struct ScoreBox {
     /// Away team name (displayed first/top)
@@ -83,6 +115,16 @@ pub struct ScoreBox {
     pub status: ScoreBoxStatus,
     /// Whether this box is selected/focused
     pub selected: bool,
+    /// Whether the score just changed (shown in the emphasis color)
+    pub highlighted: bool,
+    /// Whether the away team is a favorite (drawn with the favorite accent)
+    pub away_accented: bool,
+    /// Whether the home team is a favorite (drawn with the favorite accent)
+    pub home_accented: bool,
+    /// Draw the game as a single line instead of a box
+    pub compact: bool,
+    /// Goal lines drawn under the box (not in the compact layout)
+    pub scorers: Vec<String>,
 }
 
 impl ScoreBox {
@@ -101,6 +143,11 @@ impl ScoreBox {
             home_score,
             status,
             selected: false,
+            highlighted: false,
+            away_accented: false,
+            home_accented: false,
+            compact: false,
+            scorers: Vec::new(),
         }
     }
 
@@ -110,6 +157,47 @@ impl ScoreBox {
         self
     }
 
+    /// Set highlighted state
+    pub fn with_highlighted(mut self, highlighted: bool) -> Self {
+        self.highlighted = highlighted;
+        self
+    }
+
+    /// Accent the away and/or home team's line
+    pub fn with_accented(mut self, away: bool, home: bool) -> Self {
+        self.away_accented = away;
+        self.home_accented = home;
+        self
+    }
+
+    /// Draw the game as a single line instead of a box
+    pub fn with_compact(mut self, compact: bool) -> Self {
+        self.compact = compact;
+        self
+    }
+
+    /// List goals under the box, one line each
+    pub fn with_scorers(mut self, scorers: Vec<String>) -> Self {
+        self.scorers = scorers;
+        self
+    }
+
+    fn width(&self) -> u16 {
+        if self.compact {
+            SCORE_LINE_WIDTH
+        } else {
+            SCORE_BOX_WIDTH
+        }
+    }
+
+    fn height(&self) -> u16 {
+        if self.compact {
+            1
+        } else {
+            SCORE_BOX_HEIGHT + self.scorers.len() as u16
+        }
+    }
+
     /// Format a score value for display (right-aligned in 3 chars with trailing space)
     fn format_score(score: Option<i32>) -> String {
         match score {
@@ -132,7 +220,8 @@ impl ScoreBox {
 impl StandaloneWidget for ScoreBox {
     fn render(&self, area: Rect, buf: &mut Buffer, config: &DisplayConfig) {
         // Ensure we have enough space
-        if area.width < SCORE_BOX_WIDTH || area.height < SCORE_BOX_HEIGHT {
+        let (width, height) = (self.width(), self.height());
+        if area.width < width || area.height < height {
             return;
         }
 
@@ -142,15 +231,61 @@ impl StandaloneWidget for ScoreBox {
 
         // Styles: fg3 for box chars, fg2 for team names and scores
         // When selected, both box and text use fg2 with reverse video
-        let status_style = config.text_style(); // Status line never changes
+        let status_style = self.status.style(config);
         let (box_style, text_style) = if self.selected {
             let selected = config.text_style().add_modifier(SELECTION_STYLE_MODIFIER);
             (selected, selected)
+        } else if self.highlighted {
+            (config.muted_style(), config.emphasis_style())
         } else {
             (config.muted_style(), config.text_style()) // fg3 for box, fg2 for text
         };
+        // The selection already stands out, so favorites are only accented outside it
+        let team_style = |accented: bool| {
+            if accented && !self.selected {
+                text_style.patch(config.favorite_style())
+            } else {
+                text_style
+            }
+        };
+        let away_style = team_style(self.away_accented);
+        let home_style = team_style(self.home_accented);
+
+        if self.compact {
+            // Status width left after the teams, scores and separators
+            const STATUS_WIDTH: usize = 12;
+            let status_style = if self.selected {
+                text_style
+            } else {
+                status_style
+            };
+            let status: String = self.status.display().chars().take(STATUS_WIDTH).collect();
+            buf.set_string(x, y, " ", text_style);
+            buf.set_string(
+                x + 1,
+                y,
+                Self::format_team_name(&self.away_team),
+                away_style,
+            );
+            buf.set_string(x + 18, y, Self::format_score(self.away_score), away_style);
+            buf.set_string(x + 22, y, "@ ", box_style);
+            buf.set_string(
+                x + 24,
+                y,
+                Self::format_team_name(&self.home_team),
+                home_style,
+            );
+            buf.set_string(x + 41, y, Self::format_score(self.home_score), home_style);
+            buf.set_string(
+                x + 45,
+                y,
+                format!(" {:<width$}", status, width = STATUS_WIDTH),
+                status_style,
+            );
+            return;
+        }
 
-        // Row 0: Status line with leading space (never reversed)
+        // Row 0: Status line with leading space (never reversed or highlighted)
         let status_text = format!(" {}", self.status.display());
         buf.set_string(x, y, &status_text, status_style);
 
@@ -174,14 +309,14 @@ impl StandaloneWidget for ScoreBox {
             x + 2,
             y + 2,
             Self::format_team_name(&self.away_team),
-            text_style,
+            away_style,
         );
         buf.set_string(x + 19, y + 2, &bc.vertical, box_style);
         buf.set_string(
             x + 20,
             y + 2,
             Self::format_score(self.away_score),
-            text_style,
+            away_style,
         );
         buf.set_string(x + 24, y + 2, &bc.double_vertical, box_style);
 
@@ -203,14 +338,14 @@ impl StandaloneWidget for ScoreBox {
             x + 2,
             y + 4,
             Self::format_team_name(&self.home_team),
-            text_style,
+            home_style,
         );
         buf.set_string(x + 19, y + 4, &bc.vertical, box_style);
         buf.set_string(
             x + 20,
             y + 4,
             Self::format_score(self.home_score),
-            text_style,
+            home_style,
         );
         buf.set_string(x + 24, y + 4, &bc.double_vertical, box_style);
 
@@ -224,14 +359,20 @@ impl StandaloneWidget for ScoreBox {
             bc.double_bottom_right
         );
         buf.set_string(x, y + 5, &bottom_border, box_style);
+
+        // Rows 6+: Scorers, indented like the status line
+        for (line_y, scorer) in (y + SCORE_BOX_HEIGHT..).zip(&self.scorers) {
+            let line: String = scorer.chars().take(SCORE_BOX_WIDTH as usize - 1).collect();
+            buf.set_string(x, line_y, format!(" {}", line), config.muted_style());
+        }
     }
 
     fn preferred_width(&self) -> Option<u16> {
-        Some(SCORE_BOX_WIDTH)
+        Some(self.width())
     }
 
     fn preferred_height(&self) -> Option<u16> {
-        Some(SCORE_BOX_HEIGHT)
+        Some(self.height())
     }
 }
 
@@ -240,6 +381,7 @@ mod tests {
     use super::*;
     use crate::tui::testing::assert_buffer;
     use crate::tui::widgets::testing::{render_widget_with_config, test_config};
+    use ratatui::style::{Color, Modifier};
 
     #[test]
     fn test_score_box_final() {
@@ -270,6 +412,73 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_score_box_scorers_below_box() {
+        let score_box = ScoreBox::new(
+            "Maple Leafs",
+            "Bruins",
+            Some(1),
+            Some(1),
+            ScoreBoxStatus::Live {
+                period: "2nd".to_string(),
+                time: Some("12:01".to_string()),
+                intermission: false,
+            },
+        )
+        .with_scorers(vec![
+            "P1 04:12 TOR Matthews".to_string(),
+            "P2 10:55 BOS Pastrnak-Longername".to_string(),
+        ]);
+
+        assert_eq!(score_box.preferred_height(), Some(8));
+
+        let config = test_config();
+        let buf = render_widget_with_config(&score_box, 25, 8, &config);
+
+        assert_buffer(
+            &buf,
+            &[
+                " 2nd 12:01               ",
+                "╔══════════════════╤════╗",
+                "║ Maple Leafs      │  1 ║",
+                "╟──────────────────┼────╢",
+                "║ Bruins           │  1 ║",
+                "╚══════════════════╧════╝",
+                " P1 04:12 TOR Matthews   ",
+                " P2 10:55 BOS Pastrnak-Lo",
+            ],
+        );
+
+        // The compact line has no room for scorers
+        assert_eq!(score_box.with_compact(true).preferred_height(), Some(1));
+    }
+
+    #[test]
+    fn test_score_box_compact_line() {
+        let score_box = ScoreBox::new(
+            "Golden Knights",
+            "Avalanche",
+            Some(10),
+            Some(3),
+            ScoreBoxStatus::Final {
+                overtime: true,
+                shootout: false,
+            },
+        )
+        .with_compact(true);
+
+        assert_eq!(score_box.preferred_width(), Some(SCORE_LINE_WIDTH));
+        assert_eq!(score_box.preferred_height(), Some(1));
+
+        let config = test_config();
+        let buf = render_widget_with_config(&score_box, SCORE_LINE_WIDTH, 1, &config);
+
+        assert_buffer(
+            &buf,
+            &[" Golden Knights    10 @ Avalanche          3  Final (OT)  "],
+        );
+    }
+
     #[test]
     fn test_score_box_live() {
         let score_box = ScoreBox::new(
@@ -445,6 +654,161 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_score_box_highlighted_uses_emphasis() {
+        let score_box = ScoreBox::new(
+            "Maple Leafs",
+            "Bruins",
+            Some(3),
+            Some(0),
+            ScoreBoxStatus::Live {
+                period: "2nd".to_string(),
+                time: Some("12:01".to_string()),
+                intermission: false,
+            },
+        )
+        .with_highlighted(true);
+
+        let config = test_config();
+        let buf = render_widget_with_config(&score_box, 25, 6, &config);
+
+        // Without a theme, emphasis falls back to bold
+        assert!(buf[(21, 2)].modifier.contains(Modifier::BOLD));
+        assert!(buf[(2, 4)].modifier.contains(Modifier::BOLD));
+        assert!(!buf[(0, 2)].modifier.contains(Modifier::BOLD));
+        // The status keeps its own style (bold here because the game is live)
+        assert_eq!(
+            buf[(1, 0)].style(),
+            render_widget_with_config(&score_box.clone().with_highlighted(false), 25, 6, &config)
+                [(1, 0)]
+                .style()
+        );
+    }
+
+    fn themed_config() -> DisplayConfig {
+        let mut config = DisplayConfig {
+            theme_name: Some("orange".to_string()),
+            ..test_config()
+        };
+        config.apply_theme();
+        config
+    }
+
+    fn status_fg(status: ScoreBoxStatus, config: &DisplayConfig) -> Color {
+        let score_box = ScoreBox::new("Maple Leafs", "Bruins", Some(2), Some(1), status);
+        render_widget_with_config(&score_box, 25, 6, config)[(1, 0)].fg
+    }
+
+    #[test]
+    fn test_score_box_live_status_uses_emphasis() {
+        let config = themed_config();
+        let theme = config.theme.clone().unwrap();
+        let live = ScoreBoxStatus::Live {
+            period: "1st".to_string(),
+            time: Some("09:27".to_string()),
+            intermission: false,
+        };
+
+        assert_eq!(status_fg(live, &config), theme.emphasis_fg());
+    }
+
+    #[test]
+    fn test_score_box_final_status_is_muted() {
+        let config = themed_config();
+        let theme = config.theme.clone().unwrap();
+        let final_status = ScoreBoxStatus::Final {
+            overtime: true,
+            shootout: false,
+        };
+
+        assert_eq!(status_fg(final_status, &config), theme.fg3);
+    }
+
+    #[test]
+    fn test_score_box_scheduled_status_uses_text_style() {
+        let config = themed_config();
+        let theme = config.theme.clone().unwrap();
+        let scheduled = ScoreBoxStatus::Scheduled {
+            start_time: "9PM".to_string(),
+        };
+
+        assert_eq!(status_fg(scheduled, &config), theme.fg2);
+    }
+
+    #[test]
+    fn test_score_box_status_accent_disabled() {
+        let config = DisplayConfig {
+            status_accent: false,
+            ..themed_config()
+        };
+        let theme = config.theme.clone().unwrap();
+        let live = ScoreBoxStatus::Live {
+            period: "OT".to_string(),
+            time: None,
+            intermission: false,
+        };
+
+        assert_eq!(status_fg(live, &config), theme.fg2);
+        let compact = ScoreBox::new(
+            "Maple Leafs",
+            "Bruins",
+            Some(2),
+            Some(1),
+            ScoreBoxStatus::Final {
+                overtime: false,
+                shootout: false,
+            },
+        )
+        .with_compact(true);
+        let buf = render_widget_with_config(&compact, 58, 1, &config);
+        assert_eq!(buf[(46, 0)].fg, theme.fg2);
+    }
+
+    #[test]
+    fn test_score_box_accents_favorite_team_line() {
+        let score_box = ScoreBox::new(
+            "Maple Leafs",
+            "Bruins",
+            Some(3),
+            Some(0),
+            ScoreBoxStatus::Final {
+                overtime: false,
+                shootout: false,
+            },
+        )
+        .with_accented(false, true);
+
+        let config = test_config();
+        let buf = render_widget_with_config(&score_box, 25, 6, &config);
+
+        assert!(buf[(2, 4)].modifier.contains(Modifier::ITALIC));
+        assert!(buf[(21, 4)].modifier.contains(Modifier::ITALIC));
+        assert!(!buf[(2, 2)].modifier.contains(Modifier::ITALIC));
+        assert!(!buf[(0, 4)].modifier.contains(Modifier::ITALIC));
+
+        // The selection style is left alone
+        let buf = render_widget_with_config(&score_box.with_selected(true), 25, 6, &config);
+        assert!(!buf[(2, 4)].modifier.contains(Modifier::ITALIC));
+
+        let config = DisplayConfig {
+            accent_favorites: false,
+            ..test_config()
+        };
+        let score_box = ScoreBox::new(
+            "A",
+            "B",
+            None,
+            None,
+            ScoreBoxStatus::Final {
+                overtime: false,
+                shootout: false,
+            },
+        )
+        .with_accented(true, true);
+        let buf = render_widget_with_config(&score_box, 25, 6, &config);
+        assert!(!buf[(2, 2)].modifier.contains(Modifier::ITALIC));
+    }
+
     #[test]
     fn test_format_score() {
         assert_eq!(ScoreBox::format_score(Some(0)), "  0 ");
@@ -466,6 +830,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_final_for_overtime_and_shootout() {
+        let period = |number, period_type| PeriodDescriptor {
+            number,
+            period_type,
+            max_regulation_periods: 3,
+        };
+
+        let overtime = ScoreBoxStatus::final_for(&period(4, PeriodType::Overtime));
+        assert_eq!(overtime.display(), "Final (OT)");
+        // A period past the third counts as overtime whatever its type
+        let second_overtime = ScoreBoxStatus::final_for(&period(5, PeriodType::Regulation));
+        assert_eq!(second_overtime.display(), "Final (OT)");
+        let shootout = ScoreBoxStatus::final_for(&period(5, PeriodType::Shootout));
+        assert_eq!(shootout.display(), "Final (SO)");
+        let regulation = ScoreBoxStatus::final_for(&period(3, PeriodType::Regulation));
+        assert_eq!(regulation.display(), "Final");
+    }
+
     #[test]
     fn test_status_display() {
         assert_eq!(
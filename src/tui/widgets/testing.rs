@@ -16,6 +16,8 @@ pub fn test_config() -> DisplayConfig {
         theme: None,
         error_fg: Color::Red,
         box_chars: BoxChars::unicode(),
+        boxscore_sort: None,
+        ..DisplayConfig::default()
     }
 }
 
@@ -29,6 +31,8 @@ pub fn test_config_ascii() -> DisplayConfig {
         theme: None,
         error_fg: Color::Red,
         box_chars: BoxChars::ascii(),
+        boxscore_sort: None,
+        ..DisplayConfig::default()
     }
 }
 